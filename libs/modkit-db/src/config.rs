@@ -50,6 +50,10 @@ pub struct GlobalDatabaseConfig {
     #[serde(default)]
     pub servers: HashMap<String, DbConnConfig>,
     /// Optional dev-only flag to auto-provision DB/schema when missing.
+    ///
+    /// For `PostgreSQL`/`MySQL` servers, `true` creates the module's database
+    /// before connecting (requires `CREATE DATABASE` privilege); unset means off.
+    /// For `SQLite`, missing parent directories are created unless set to `false`.
     #[serde(default)]
     pub auto_provision: Option<bool>,
 }
@@ -76,6 +80,7 @@ pub struct DbConnConfig {
     pub port: Option<u16>,
     pub user: Option<String>,
     pub password: Option<String>, // literal password or ${VAR} for env expansion
+    pub password_file: Option<PathBuf>, // file holding the password (mounted secrets)
     pub dbname: Option<String>,   // MUST be present in final for server-based DBs
     #[serde(default)]
     pub params: Option<HashMap<String, String>>,
@@ -91,6 +96,11 @@ pub struct DbConnConfig {
     // Module-level only: reference to a global server by name.
     // If absent, this module config must be fully self-sufficient (dsn or fields).
     pub server: Option<String>,
+
+    // Module-level only: read replicas. Each entry is resolved like the primary
+    // (its own `server`, then `dsn`, then fields); `dbname` defaults to the primary's.
+    #[serde(default)]
+    pub replicas: Option<Vec<DbConnConfig>>,
 }
 
 /// Serializable engine selector for configuration.
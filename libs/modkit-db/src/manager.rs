@@ -177,8 +177,9 @@ impl DbManager {
         if module_cfg.user.is_none() {
             module_cfg.user = server_cfg.user;
         }
-        if module_cfg.password.is_none() {
+        if module_cfg.password.is_none() && module_cfg.password_file.is_none() {
             module_cfg.password = server_cfg.password;
+            module_cfg.password_file = server_cfg.password_file;
         }
         if module_cfg.dbname.is_none() {
             module_cfg.dbname = server_cfg.dbname;
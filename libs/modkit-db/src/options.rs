@@ -141,6 +141,94 @@ impl DbConnectOptions {
     }
 }
 
+impl DbConnectOptions {
+    /// Create the target database when it does not exist yet (`auto_provision`).
+    ///
+    /// Connects to the server's maintenance database (`postgres` for `PostgreSQL`,
+    /// `information_schema` for `MySQL`) with the configured credentials and issues
+    /// `CREATE DATABASE` for the configured name. `SQLite` creates its file on
+    /// connect, so this is a no-op there.
+    ///
+    /// # Errors
+    /// Returns an error if the maintenance connection or `CREATE DATABASE` fails.
+    #[cfg_attr(
+        not(any(feature = "pg", feature = "mysql")),
+        allow(clippy::unused_async)
+    )]
+    pub async fn provision_database(&self) -> Result<()> {
+        match self {
+            #[cfg(feature = "sqlite")]
+            DbConnectOptions::Sqlite(_) => Ok(()),
+            #[cfg(feature = "pg")]
+            DbConnectOptions::Postgres(opts) => {
+                use sqlx::Connection;
+
+                let Some(dbname) = opts.get_database() else {
+                    return Ok(());
+                };
+                let mut conn =
+                    sqlx::PgConnection::connect_with(&opts.clone().database("postgres")).await?;
+
+                let exists: Option<i32> =
+                    sqlx::query_scalar("SELECT 1 FROM pg_database WHERE datname = $1")
+                        .bind(dbname)
+                        .fetch_optional(&mut conn)
+                        .await?;
+                if exists.is_none() {
+                    let create = format!("CREATE DATABASE {}", quote_identifier(dbname, '"'));
+                    match sqlx::query(&create).execute(&mut conn).await {
+                        Ok(_) => {
+                            tracing::info!(
+                                database = dbname,
+                                "Auto-provisioned PostgreSQL database"
+                            );
+                        }
+                        // 42P04 duplicate_database: another instance won the race.
+                        Err(sqlx::Error::Database(e)) if e.code().as_deref() == Some("42P04") => {}
+                        Err(e) => return Err(e.into()),
+                    }
+                }
+
+                conn.close().await?;
+                Ok(())
+            }
+            #[cfg(feature = "mysql")]
+            DbConnectOptions::MySql(opts) => {
+                use sqlx::Connection;
+
+                let Some(dbname) = opts.get_database() else {
+                    return Ok(());
+                };
+                let mut conn = sqlx::MySqlConnection::connect_with(
+                    &opts.clone().database("information_schema"),
+                )
+                .await?;
+
+                let create = format!(
+                    "CREATE DATABASE IF NOT EXISTS {}",
+                    quote_identifier(dbname, '`')
+                );
+                sqlx::query(&create).execute(&mut conn).await?;
+                tracing::info!(database = dbname, "Ensured MySQL database exists");
+
+                conn.close().await?;
+                Ok(())
+            }
+            #[cfg(not(any(feature = "sqlite", feature = "pg", feature = "mysql")))]
+            _ => {
+                unreachable!("No database features enabled")
+            }
+        }
+    }
+}
+
+/// Quote an SQL identifier, doubling any embedded quote characters.
+#[cfg(any(feature = "pg", feature = "mysql"))]
+fn quote_identifier(name: &str, quote: char) -> String {
+    let escaped = name.replace(quote, &format!("{quote}{quote}"));
+    format!("{quote}{escaped}{quote}")
+}
+
 /// `SQLite` PRAGMA whitelist and validation.
 #[cfg(feature = "sqlite")]
 pub mod sqlite_pragma {
@@ -256,7 +344,7 @@ pub mod sqlite_pragma {
 /// Returns an error if the database connection fails or configuration is invalid.
 pub(crate) async fn build_db_handle(
     mut cfg: DbConnConfig,
-    _global: Option<&GlobalDatabaseConfig>,
+    global: Option<&GlobalDatabaseConfig>,
 ) -> Result<DbHandle> {
     // Expand environment variables in DSN and password
     if let Some(dsn) = &cfg.dsn {
@@ -265,6 +353,14 @@ pub(crate) async fn build_db_handle(
     if let Some(password) = &cfg.password {
         cfg.password = Some(resolve_password(password)?);
     }
+    if let Some(path) = cfg.password_file.take() {
+        if cfg.password.is_some() {
+            return Err(DbError::ConfigConflict(
+                "'password' and 'password_file' are mutually exclusive".to_owned(),
+            ));
+        }
+        cfg.password = Some(read_password_file(&path)?);
+    }
 
     // Expand environment variables in params
     if let Some(ref mut params) = cfg.params {
@@ -292,6 +388,11 @@ pub(crate) async fn build_db_handle(
     let log_dsn = redact_credentials_in_dsn(cfg.dsn.as_deref());
     tracing::debug!(dsn = log_dsn, engine = ?engine, "Building database connection");
 
+    // Create the database first when auto-provisioning is enabled
+    if global.and_then(|g| g.auto_provision).unwrap_or(false) {
+        connect_options.provision_database().await?;
+    }
+
     // Connect to database
     let handle = connect_options.connect(pool_cfg).await?;
 
@@ -681,7 +782,12 @@ fn parse_sqlite_path_from_dsn(dsn: &str) -> Result<std::path::PathBuf> {
             path_part
         };
 
-        Ok(std::path::PathBuf::from(path_part))
+        // Paths with spaces, `#`, `?` etc. are percent-encoded in the DSN
+        let path_part = urlencoding::decode(path_part).map_err(|e| {
+            DbError::InvalidParameter(format!("Invalid SQLite DSN path encoding: {e}"))
+        })?;
+
+        Ok(std::path::PathBuf::from(path_part.as_ref()))
     } else {
         Err(DbError::InvalidParameter(format!(
             "Invalid SQLite DSN: {dsn}"
@@ -689,6 +795,18 @@ fn parse_sqlite_path_from_dsn(dsn: &str) -> Result<std::path::PathBuf> {
     }
 }
 
+/// Read a password from a secret file, trimming surrounding whitespace.
+fn read_password_file(path: &std::path::Path) -> Result<String> {
+    std::fs::read_to_string(path)
+        .map(|contents| contents.trim().to_owned())
+        .map_err(|e| {
+            DbError::InvalidConfig(format!(
+                "failed to read password file '{}': {e}",
+                path.display()
+            ))
+        })
+}
+
 /// Resolve password from environment variable if it starts with ${VAR}.
 fn resolve_password(password: &str) -> Result<String> {
     if password.starts_with("${") && password.ends_with('}') {
@@ -887,4 +1005,12 @@ mod tests {
         let err = determine_engine(&cfg).unwrap_err();
         assert!(matches!(err, DbError::UnknownDsn(_)));
     }
+
+    #[cfg(any(feature = "pg", feature = "mysql"))]
+    #[test]
+    fn quote_identifier_escapes_embedded_quotes() {
+        assert_eq!(quote_identifier("app", '"'), "\"app\"");
+        assert_eq!(quote_identifier("a\"b", '"'), "\"a\"\"b\"");
+        assert_eq!(quote_identifier("a`b", '`'), "`a``b`");
+    }
 }
@@ -65,7 +65,9 @@ fn extract_file_path_from_dsn(dsn: &str) -> Option<std::path::PathBuf> {
     if let Ok(url) = url::Url::parse(dsn)
         && url.scheme() == "sqlite"
     {
-        let path_str = url.path();
+        // The URL path stays percent-encoded; decode it to get the real file name
+        let decoded = urlencoding::decode(url.path()).ok()?;
+        let path_str = decoded.as_ref();
 
         // Handle empty path
         if path_str.is_empty() || path_str == "/" {
@@ -145,6 +147,12 @@ mod tests {
             Some(PathBuf::from("/path/to/db.sqlite"))
         );
 
+        // Percent-encoded path segments
+        assert_eq!(
+            extract_file_path_from_dsn("sqlite:///data/my%20dbs%20%231/db%3F.sqlite?wal=true"),
+            Some(PathBuf::from("/data/my dbs #1/db?.sqlite"))
+        );
+
         // Memory databases
         assert_eq!(extract_file_path_from_dsn("sqlite::memory:"), None);
         assert_eq!(extract_file_path_from_dsn("sqlite://memory:"), None);
@@ -0,0 +1,62 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+#![cfg(feature = "integration")]
+
+//! Integration tests for `auto_provision` against containerized servers.
+//!
+//! The module points at a database that does not exist on the server; the
+//! manager must create it before connecting.
+
+mod common;
+
+use figment::{Figment, providers::Serialized};
+use modkit_db::DbManager;
+
+fn manager_for(server_dsn: &str, dbname: &str, auto_provision: bool) -> DbManager {
+    let figment = Figment::new().merge(Serialized::defaults(serde_json::json!({
+        "database": {
+            "servers": { "main": { "dsn": server_dsn } },
+            "auto_provision": auto_provision
+        },
+        "modules": {
+            "provisioned": {
+                "database": { "server": "main", "dbname": dbname }
+            }
+        }
+    })));
+    let home = tempfile::tempdir().unwrap();
+    DbManager::from_figment(figment, home.path().to_path_buf()).unwrap()
+}
+
+#[cfg(feature = "pg")]
+#[tokio::test]
+async fn auto_provision_creates_postgres_database() {
+    let db = common::bring_up_postgres().await.unwrap();
+
+    let disabled = manager_for(&db.url, "provisioned_pg", false);
+    assert!(
+        disabled.get("provisioned").await.is_err(),
+        "connecting to a missing database must fail without auto_provision"
+    );
+
+    let enabled = manager_for(&db.url, "provisioned_pg", true);
+    let handle = enabled.get("provisioned").await.unwrap();
+    assert!(handle.is_some());
+
+    // A second manager sees the existing database and provisions idempotently.
+    let again = manager_for(&db.url, "provisioned_pg", true);
+    assert!(again.get("provisioned").await.unwrap().is_some());
+}
+
+#[cfg(feature = "mysql")]
+#[tokio::test]
+async fn auto_provision_creates_mysql_database() {
+    let db = common::bring_up_mysql().await.unwrap();
+    // Only root may create arbitrary databases in the test image.
+    let root_dsn = db.url.replacen("user:pass@", "root:root@", 1);
+
+    let enabled = manager_for(&root_dsn, "provisioned_mysql", true);
+    assert!(enabled.get("provisioned").await.unwrap().is_some());
+
+    let again = manager_for(&root_dsn, "provisioned_mysql", true);
+    assert!(again.get("provisioned").await.unwrap().is_some());
+}
@@ -16,6 +16,7 @@ fn test_dbconnconfig_serialization() {
         port: Some(5432),
         user: Some("testuser".to_owned()),
         password: Some("testpass".to_owned()),
+        password_file: None,
         dbname: Some("testdb".to_owned()),
         params: Some({
             let mut params = HashMap::new();
@@ -30,6 +31,7 @@ fn test_dbconnconfig_serialization() {
             ..Default::default()
         }),
         server: Some("test_server".to_owned()),
+        replicas: None,
     };
 
     // Test serialization to JSON
@@ -55,6 +55,7 @@ async fn test_dbmanager_server_merge() {
             port: Some(5432),
             user: Some("serveruser".to_owned()),
             password: Some("serverpass".to_owned()),
+            password_file: None,
             dbname: Some("serverdb".to_owned()),
             params: Some({
                 let mut params = HashMap::new();
@@ -70,6 +71,7 @@ async fn test_dbmanager_server_merge() {
                 ..Default::default()
             }),
             server: None,
+            replicas: None,
         },
     );
 
@@ -19,6 +19,7 @@ mod utils;
 struct ModuleConfig {
     name: String,
     deps: Vec<String>,
+    soft_deps: Vec<String>,
     caps: Vec<Capability>,
     ctor: Option<Expr>,             // arbitrary constructor expression
     client: Option<Path>,           // trait path for client DX helpers
@@ -196,10 +197,33 @@ impl Default for LcModuleCfg {
     }
 }
 
+/// Parse `param = ["a", "b"]` into its string values.
+fn parse_str_array(value: Expr, param: &str, example: &str) -> syn::Result<Vec<String>> {
+    let Expr::Array(arr) = value else {
+        return Err(syn::Error::new_spanned(
+            value,
+            format!("{param} must be an array, e.g. {param} = {example}"),
+        ));
+    };
+    arr.elems
+        .into_iter()
+        .map(|elem| match elem {
+            Expr::Lit(syn::ExprLit {
+                lit: Lit::Str(s), ..
+            }) => Ok(s.value()),
+            other => Err(syn::Error::new_spanned(
+                other,
+                format!("{param} must be an array of string literals, e.g. {param} = {example}"),
+            )),
+        })
+        .collect()
+}
+
 impl Parse for ModuleConfig {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut name: Option<String> = None;
         let mut deps: Vec<String> = Vec::new();
+        let mut soft_deps: Vec<String> = Vec::new();
         let mut caps: Vec<Capability> = Vec::new();
         let mut ctor: Option<Expr> = None;
         let mut client: Option<Path> = None;
@@ -207,6 +231,7 @@ impl Parse for ModuleConfig {
 
         let mut seen_name = false;
         let mut seen_deps = false;
+        let mut seen_soft_deps = false;
         let mut seen_caps = false;
         let mut seen_ctor = false;
         let mut seen_client = false;
@@ -299,32 +324,17 @@ impl Parse for ModuleConfig {
                         ));
                     }
                     seen_deps = true;
-                    let value = nv.value.clone();
-                    match value {
-                        Expr::Array(arr) => {
-                            for elem in arr.elems {
-                                match elem {
-                                    Expr::Lit(syn::ExprLit {
-                                        lit: Lit::Str(s), ..
-                                    }) => {
-                                        deps.push(s.value());
-                                    }
-                                    other => {
-                                        return Err(syn::Error::new_spanned(
-                                            other,
-                                            "deps must be an array of string literals, e.g. deps = [\"db\", \"auth\"]",
-                                        ));
-                                    }
-                                }
-                            }
-                        }
-                        other => {
-                            return Err(syn::Error::new_spanned(
-                                other,
-                                "deps must be an array, e.g. deps = [\"db\", \"auth\"]",
-                            ));
-                        }
+                    deps = parse_str_array(nv.value, "deps", "[\"db\", \"auth\"]")?;
+                }
+                Meta::NameValue(nv) if nv.path.is_ident("soft_deps") => {
+                    if seen_soft_deps {
+                        return Err(syn::Error::new_spanned(
+                            nv.path,
+                            "duplicate `soft_deps` parameter",
+                        ));
                     }
+                    seen_soft_deps = true;
+                    soft_deps = parse_str_array(nv.value, "soft_deps", "[\"audit\"]")?;
                 }
                 Meta::NameValue(nv) if nv.path.is_ident("capabilities") => {
                     if seen_caps {
@@ -401,6 +411,7 @@ impl Parse for ModuleConfig {
         Ok(ModuleConfig {
             name,
             deps,
+            soft_deps,
             caps,
             ctor,
             client,
@@ -501,6 +512,11 @@ pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
         .iter()
         .map(|s| LitStr::new(s, Span::call_site()))
         .collect();
+    let soft_deps_lits: Vec<LitStr> = config
+        .soft_deps
+        .iter()
+        .map(|s| LitStr::new(s, Span::call_site()))
+        .collect();
 
     // Constructor expression (provided or Default::default())
     let constructor = if let Some(expr) = &ctor_expr_opt {
@@ -782,12 +798,14 @@ pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
 
             let module: Arc<#struct_ident #ty_generics> = Arc::new(#constructor);
 
-            // register core with metadata (name + deps)
-            b.register_core_with_meta(
+            // register core with metadata (name + deps + soft deps)
+            b.register_core_with_soft_deps(
                 #name_lit,
                 &[#(#deps_lits),*],
+                &[#(#soft_deps_lits),*],
                 module.clone() as Arc<dyn ::modkit::contracts::Module>
             );
+            b.register_version_with_meta(#name_lit, env!("CARGO_PKG_VERSION"));
 
             // capabilities
             #(#capability_registrations)*
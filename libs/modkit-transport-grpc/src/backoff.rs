@@ -1,7 +1,10 @@
-//! Shared exponential-backoff helper used by both [`crate::client`] and [`crate::rpc_retry`].
+//! Shared exponential-backoff helpers used by [`crate::client`], [`crate::rpc_retry`]
+//! and other gRPC clients that retry on their own.
 
 use std::time::Duration;
 
+use rand::Rng as _;
+
 /// Compute exponential backoff with jitter, clamped to `max_backoff`.
 ///
 /// Formula: `base * 2^(attempt-1)`, capped at `max_backoff`, then `jitter_factor * raw` is
@@ -10,6 +13,7 @@ use std::time::Duration;
 ///
 /// The `jitter_factor` parameter (typically in `[0.0, 0.25]`) is passed in so the function
 /// is pure and can be tested deterministically without touching an RNG.
+#[must_use]
 pub fn compute_backoff(
     base: Duration,
     max_backoff: Duration,
@@ -26,6 +30,13 @@ pub fn compute_backoff(
     (raw + raw.mul_f64(jitter_factor)).min(max_backoff)
 }
 
+/// [`compute_backoff`] with a random jitter of 0–25 % to spread out concurrent retries.
+#[must_use]
+pub fn jittered_backoff(base: Duration, max_backoff: Duration, attempt: u32) -> Duration {
+    let jitter_factor = rand::rng().random_range(0.0..=0.25);
+    compute_backoff(base, max_backoff, attempt, jitter_factor)
+}
+
 #[cfg(test)]
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod tests {
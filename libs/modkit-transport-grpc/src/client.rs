@@ -10,7 +10,6 @@
 
 use std::time::Duration;
 
-use rand::Rng as _;
 use tonic::transport::{Channel, Endpoint};
 use tracing::Instrument;
 
@@ -268,13 +267,8 @@ where
                 return Ok(client);
             }
             Err(e) if attempt <= cfg.max_retries => {
-                let jitter_factor = rand::rng().random_range(0.0..=0.25);
-                let backoff = crate::backoff::compute_backoff(
-                    cfg.base_backoff,
-                    cfg.max_backoff,
-                    attempt,
-                    jitter_factor,
-                );
+                let backoff =
+                    crate::backoff::jittered_backoff(cfg.base_backoff, cfg.max_backoff, attempt);
                 tracing::warn!(
                     service = cfg.service_name,
                     attempt,
@@ -44,7 +44,6 @@
 use std::sync::Arc;
 use std::time::Duration;
 
-use rand::Rng as _;
 use tokio::time::sleep;
 use tonic::{Code, Status};
 use tracing::Instrument;
@@ -213,13 +212,8 @@ where
                     return Err(status);
                 }
 
-                let jitter_factor = rand::rng().random_range(0.0..=0.25);
-                let backoff = crate::backoff::compute_backoff(
-                    cfg.base_backoff,
-                    cfg.max_backoff,
-                    attempt,
-                    jitter_factor,
-                );
+                let backoff =
+                    crate::backoff::jittered_backoff(cfg.base_backoff, cfg.max_backoff, attempt);
 
                 tracing::debug!(
                     op = op_name,
@@ -37,6 +37,13 @@ impl std::error::Error for ExpandVarsError {
     }
 }
 
+fn placeholder_regex() -> Result<&'static Regex, ExpandVarsError> {
+    static RE: LazyLock<Result<Regex, String>> = LazyLock::new(|| {
+        Regex::new(r"\$\{([A-Za-z_][A-Za-z0-9_]*)(?:(:?-)([^}]*))?\}").map_err(|e| e.to_string())
+    });
+    RE.as_ref().map_err(|e| ExpandVarsError::Regex(e.clone()))
+}
+
 /// Expand `${VAR_NAME}` and `${VAR_NAME:-default}` placeholders in `input` with values
 /// from the environment.
 ///
@@ -51,29 +58,55 @@ impl std::error::Error for ExpandVarsError {
 /// `${...}` are **not** re-expanded.  Fails on the first unresolvable variable
 /// that has no default.
 ///
+/// See [`expand_env_vars_shell`] for POSIX shell semantics.
+///
 /// # Errors
 ///
 /// Returns [`ExpandVarsError::Var`] if a referenced environment variable is missing
 /// and no default value was provided.
 pub fn expand_env_vars(input: &str) -> Result<String, ExpandVarsError> {
-    static RE: LazyLock<Result<Regex, String>> = LazyLock::new(|| {
-        Regex::new(r"\$\{([A-Za-z_][A-Za-z0-9_]*)(?::-((?:[^}])*))?\}").map_err(|e| e.to_string())
-    });
-    let re = RE.as_ref().map_err(|e| ExpandVarsError::Regex(e.clone()))?;
+    expand(input, false)
+}
+
+/// Like [`expand_env_vars`], but following POSIX shell semantics for defaults:
+///
+/// - `${VAR:-value}` — `value` is used if the variable is not set **or empty**.
+/// - `${VAR-value}` — `value` is used only if the variable is not set; a set-but-empty
+///   variable expands to the empty string.
+///
+/// # Errors
+///
+/// Returns [`ExpandVarsError::Var`] if a referenced environment variable is missing
+/// and no default value was provided.
+pub fn expand_env_vars_shell(input: &str) -> Result<String, ExpandVarsError> {
+    expand(input, true)
+}
+
+fn expand(input: &str, shell: bool) -> Result<String, ExpandVarsError> {
+    let re = placeholder_regex()?;
 
     let mut err: Option<ExpandVarsError> = None;
     let result = re.replace_all(input, |caps: &regex::Captures| {
         if err.is_some() {
             return String::new();
         }
+        let op = caps.get(2).map(|m| m.as_str());
+        if !shell && op == Some("-") {
+            // `${VAR-value}` is only recognized with shell semantics.
+            return caps[0].to_owned();
+        }
         let name = &caps[1];
+        let default = caps.get(3).map(|m| m.as_str());
         match std::env::var(name) {
+            Ok(val) if val.is_empty() && shell && op == Some(":-") => {
+                default.unwrap_or_default().to_owned()
+            }
             Ok(val) => val,
             Err(e) => {
                 if matches!(&e, std::env::VarError::NotPresent)
-                    && let Some(default) = caps.get(2)
+                    && let Some(default) = default
                 {
-                    return default.as_str().to_owned();
+                    return default.to_owned();
                 }
                 err = Some(ExpandVarsError::Var {
                     name: name.to_owned(),
@@ -89,6 +122,28 @@ pub fn expand_env_vars(input: &str) -> Result<String, ExpandVarsError> {
     Ok(result.into_owned())
 }
 
+/// Names of variables referenced by `input` that [`expand_env_vars_shell`] cannot resolve:
+/// placeholders without a default whose variable is not set. Each name is listed once,
+/// in order of first appearance.
+///
+/// # Errors
+///
+/// Returns [`ExpandVarsError::Regex`] if the internal regex failed to compile.
+pub fn missing_env_vars(input: &str) -> Result<Vec<String>, ExpandVarsError> {
+    let re = placeholder_regex()?;
+    let mut missing: Vec<String> = Vec::new();
+    for caps in re.captures_iter(input) {
+        let name = &caps[1];
+        if caps.get(3).is_none()
+            && matches!(std::env::var(name), Err(std::env::VarError::NotPresent))
+            && !missing.iter().any(|m| m == name)
+        {
+            missing.push(name.to_owned());
+        }
+    }
+    Ok(missing)
+}
+
 /// Trait for types whose `String` fields can be expanded from environment variables.
 ///
 /// Typically derived via `#[derive(ExpandVars)]` from `modkit-macros`.
@@ -249,6 +304,40 @@ mod tests {
         });
     }
 
+    #[test]
+    fn shell_colon_dash_default_used_when_var_is_empty() {
+        temp_env::with_vars([("EXPAND_SHELL_EMPTYVAL", Some(""))], || {
+            let result = expand_env_vars_shell("${EXPAND_SHELL_EMPTYVAL:-fallback}").unwrap();
+            assert_eq!(result, "fallback");
+        });
+    }
+
+    #[test]
+    fn dash_operator_left_alone_without_shell_semantics() {
+        temp_env::with_vars([("EXPAND_DASH_PLAIN", None::<&str>)], || {
+            let result = expand_env_vars("${EXPAND_DASH_PLAIN-a}").unwrap();
+            assert_eq!(result, "${EXPAND_DASH_PLAIN-a}");
+        });
+    }
+
+    #[test]
+    fn dash_default_used_only_when_var_missing() {
+        temp_env::with_vars(
+            [
+                ("EXPAND_DASH_MISS", None::<&str>),
+                ("EXPAND_DASH_EMPTY", Some("")),
+                ("EXPAND_DASH_SET", Some("actual")),
+            ],
+            || {
+                let result = expand_env_vars_shell(
+                    "${EXPAND_DASH_MISS-a}|${EXPAND_DASH_EMPTY-b}|${EXPAND_DASH_SET-c}",
+                )
+                .unwrap();
+                assert_eq!(result, "a||actual");
+            },
+        );
+    }
+
     #[test]
     fn no_default_still_errors_on_missing() {
         temp_env::with_vars([("EXPAND_DEF_NODEF", None::<&str>)], || {
@@ -274,6 +363,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn missing_env_vars_lists_each_unresolvable_name_once() {
+        temp_env::with_vars(
+            [
+                ("MISSING_LIST_A", None::<&str>),
+                ("MISSING_LIST_B", Some("set")),
+                ("MISSING_LIST_C", None::<&str>),
+            ],
+            || {
+                let missing = missing_env_vars(
+                    "${MISSING_LIST_A}${MISSING_LIST_B}${MISSING_LIST_C:-x}${MISSING_LIST_A}",
+                )
+                .unwrap();
+                assert_eq!(missing, vec!["MISSING_LIST_A".to_owned()]);
+            },
+        );
+    }
+
     /// Regression: values containing `${...}` must not be re-expanded.
     /// Input `${A}_${B}` with A=`${B}` and B=`val` must yield `${B}_val`, not `val_val`.
     #[test]
@@ -50,6 +50,14 @@ pub trait OpenApiRegistry: Send + Sync {
     /// Register an API operation specification
     fn register_operation(&self, spec: &operation_builder::OperationSpec);
 
+    /// Claim the operation's `METHOD path` before it is routed.
+    ///
+    /// Returning `false` skips both the operation and its route, so a registry
+    /// can reject a duplicate route instead of letting axum panic on it.
+    fn claim_route(&self, _spec: &operation_builder::OperationSpec) -> bool {
+        true
+    }
+
     /// Ensure schema for a type (including transitive dependencies) is registered
     /// under components and return the canonical component name for `$ref`.
     /// This is a type-erased version for dyn compatibility.
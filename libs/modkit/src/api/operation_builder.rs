@@ -1545,6 +1545,11 @@ where
     ///
     /// All conditions are enforced at compile time by the type system.
     pub fn register(self, router: Router<S>, openapi: &dyn OpenApiRegistry) -> Router<S> {
+        // A rejected route (e.g. a duplicate) is left out entirely.
+        if !openapi.claim_route(&self.spec) {
+            return router;
+        }
+
         // Inform the OpenAPI registry (the implementation will translate OperationSpec
         // into an OpenAPI Operation + RequestBody + Responses with component refs).
         openapi.register_operation(&self.spec);
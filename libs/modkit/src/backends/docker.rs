@@ -0,0 +1,454 @@
+//! Docker container backend implementation
+
+use anyhow::{Context, Result, bail};
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use std::collections::BTreeMap;
+use std::process::Stdio;
+use std::sync::Arc;
+use tokio::process::Command;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use super::{ContainerConfig, OopBackend, OopSpawnConfig, RestartPolicy};
+use crate::runtime::MODKIT_DIRECTORY_ENDPOINT_ENV;
+
+/// Hostname containers use to reach services bound on the host.
+const HOST_GATEWAY_ALIAS: &str = "host.docker.internal";
+
+/// Everything needed to start one module container.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContainerSpec {
+    pub name: String,
+    pub image: String,
+    pub args: Vec<String>,
+    pub env: BTreeMap<String, String>,
+    pub ports: Vec<String>,
+    pub volumes: Vec<String>,
+    /// Extra `/etc/hosts` entries (`host:ip`).
+    pub extra_hosts: Vec<String>,
+    /// Docker restart policy (`--restart`); `None` runs the container once with `--rm`.
+    pub restart: Option<String>,
+}
+
+impl ContainerSpec {
+    /// Build the spec for an `OoP` spawn request.
+    ///
+    /// A directory endpoint on an unspecified address (`0.0.0.0`, `[::]`) is
+    /// rewritten to [`HOST_GATEWAY_ALIAS`], which the container resolves to the
+    /// host gateway. The restart policy maps to Docker's `--restart`; Docker
+    /// applies its own restart delay, so `OnFailure::backoff` is not used.
+    ///
+    /// # Errors
+    /// Returns an error if the directory endpoint is bound to loopback only,
+    /// since containers cannot reach the host's loopback interface.
+    pub fn from_spawn_config(config: &OopSpawnConfig, container: &ContainerConfig) -> Result<Self> {
+        let mut env: BTreeMap<String, String> = config.env.clone().into_iter().collect();
+        let mut extra_hosts = Vec::new();
+        if let Some(endpoint) = env.get_mut(MODKIT_DIRECTORY_ENDPOINT_ENV)
+            && let Some(rewritten) = container_endpoint(endpoint)
+                .with_context(|| format!("module '{}' runs in a container", config.module_name))?
+        {
+            *endpoint = rewritten;
+            extra_hosts.push(format!("{HOST_GATEWAY_ALIAS}:host-gateway"));
+        }
+
+        let restart = match config.restart_policy {
+            RestartPolicy::Never => None,
+            RestartPolicy::OnFailure { max_retries, .. } => {
+                Some(format!("on-failure:{max_retries}"))
+            }
+            RestartPolicy::Always => Some("always".to_owned()),
+        };
+
+        let id = Uuid::new_v4().simple().to_string();
+        Ok(Self {
+            name: format!("modkit-{}-{}", config.module_name, &id[..8]),
+            image: container.image.clone(),
+            args: config.args.clone(),
+            env,
+            ports: container.ports.clone(),
+            volumes: container.volumes.clone(),
+            extra_hosts,
+            restart,
+        })
+    }
+
+    /// Arguments for `docker run`.
+    ///
+    /// Environment values are not part of the command line (they would be
+    /// visible in the process list); only names are passed and `docker`
+    /// reads the values from its own environment.
+    #[must_use]
+    pub fn run_args(&self) -> Vec<String> {
+        let mut out = vec!["run".to_owned(), "--detach".to_owned()];
+        // `--rm` and `--restart` are mutually exclusive; restarted containers are
+        // removed on shutdown by `docker rm --force`.
+        match &self.restart {
+            Some(policy) => out.extend(["--restart".to_owned(), policy.clone()]),
+            None => out.push("--rm".to_owned()),
+        }
+        out.extend(["--name".to_owned(), self.name.clone()]);
+        for host in &self.extra_hosts {
+            out.extend(["--add-host".to_owned(), host.clone()]);
+        }
+        for key in self.env.keys() {
+            out.extend(["--env".to_owned(), key.clone()]);
+        }
+        for port in &self.ports {
+            out.extend(["--publish".to_owned(), port.clone()]);
+        }
+        for volume in &self.volumes {
+            out.extend(["--volume".to_owned(), volume.clone()]);
+        }
+        out.push(self.image.clone());
+        out.extend(self.args.iter().cloned());
+        out
+    }
+}
+
+/// Endpoint a container uses to reach `endpoint` on the host.
+///
+/// An unspecified host (the hub listens on all interfaces) becomes the host
+/// gateway alias; a loopback host is rejected; anything else is kept as is.
+fn container_endpoint(endpoint: &str) -> Result<Option<String>> {
+    let host_tail = |host: &str| {
+        let (scheme, rest) = endpoint.split_once("://")?;
+        let tail = rest.strip_prefix(host)?;
+        (tail.is_empty() || tail.starts_with([':', '/'])).then_some((scheme, tail))
+    };
+    if ["localhost", "127.0.0.1", "[::1]"]
+        .iter()
+        .any(|host| host_tail(host).is_some())
+    {
+        bail!(
+            "directory endpoint '{endpoint}' is bound to loopback and unreachable from containers; \
+             set grpc-hub listen_addr to 0.0.0.0"
+        );
+    }
+    Ok(["0.0.0.0", "[::]"].iter().find_map(|host| {
+        host_tail(host).map(|(scheme, tail)| format!("{scheme}://{HOST_GATEWAY_ALIAS}{tail}"))
+    }))
+}
+
+/// Container engine used by [`DockerBackend`].
+#[async_trait]
+pub trait ContainerEngine: Send + Sync {
+    /// Start a container and return its id.
+    async fn run(&self, spec: &ContainerSpec) -> Result<String>;
+    /// Stop and remove a container.
+    async fn remove(&self, container_id: &str) -> Result<()>;
+}
+
+/// Engine driving the `docker` CLI found on `PATH`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DockerCli;
+
+#[async_trait]
+impl ContainerEngine for DockerCli {
+    async fn run(&self, spec: &ContainerSpec) -> Result<String> {
+        let output = Command::new("docker")
+            .args(spec.run_args())
+            .envs(&spec.env)
+            .stdin(Stdio::null())
+            .output()
+            .await
+            .context("failed to run docker")?;
+        if !output.status.success() {
+            bail!(
+                "docker run failed for image '{}': {}",
+                spec.image,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_owned())
+    }
+
+    async fn remove(&self, container_id: &str) -> Result<()> {
+        let output = Command::new("docker")
+            .args(["rm", "--force", container_id])
+            .stdin(Stdio::null())
+            .output()
+            .await
+            .context("failed to run docker")?;
+        if !output.status.success() {
+            bail!(
+                "docker rm failed for container '{container_id}': {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Backend that runs `OoP` modules as Docker containers.
+///
+/// When the cancellation token is triggered, all started containers are removed.
+pub struct DockerBackend<E: ContainerEngine + 'static = DockerCli> {
+    engine: Arc<E>,
+    containers: Arc<Mutex<Vec<String>>>,
+}
+
+impl DockerBackend<DockerCli> {
+    /// Create a backend using the `docker` CLI.
+    #[must_use]
+    pub fn new(cancel: CancellationToken) -> Self {
+        Self::with_engine(DockerCli, cancel)
+    }
+}
+
+impl<E: ContainerEngine + 'static> DockerBackend<E> {
+    /// Create a backend using a custom container engine.
+    #[must_use]
+    pub fn with_engine(engine: E, cancel: CancellationToken) -> Self {
+        let backend = Self {
+            engine: Arc::new(engine),
+            containers: Arc::new(Mutex::new(Vec::new())),
+        };
+
+        let engine = Arc::clone(&backend.engine);
+        let containers = Arc::clone(&backend.containers);
+        tokio::spawn(async move {
+            cancel.cancelled().await;
+            tracing::info!("DockerBackend: shutdown signal received, removing all containers");
+            Self::remove_all(&*engine, &containers).await;
+        });
+
+        backend
+    }
+
+    async fn remove_all(engine: &E, containers: &Mutex<Vec<String>>) {
+        let ids = std::mem::take(&mut *containers.lock());
+        for id in ids {
+            if let Err(e) = engine.remove(&id).await {
+                tracing::warn!(container_id = %id, error = %e, "failed to remove container");
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<E: ContainerEngine + 'static> OopBackend for DockerBackend<E> {
+    async fn spawn(&self, config: OopSpawnConfig) -> Result<()> {
+        let Some(container) = config.container.as_ref() else {
+            bail!(
+                "module '{}' has no container config; DockerBackend only runs container modules",
+                config.module_name
+            );
+        };
+        let spec = ContainerSpec::from_spawn_config(&config, container)?;
+        let id = self.engine.run(&spec).await?;
+        tracing::info!(
+            module = %config.module_name,
+            container = %spec.name,
+            container_id = %id,
+            image = %spec.image,
+            "Started OoP module container"
+        );
+        self.containers.lock().push(id);
+        Ok(())
+    }
+
+    async fn shutdown_all(&self) {
+        Self::remove_all(&self.engine, &self.containers).await;
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::backends::RestartPolicy;
+    use crate::runtime::MODKIT_MODULE_CONFIG_ENV;
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    /// Engine that records specs instead of starting containers.
+    #[derive(Default)]
+    struct RecordingEngine {
+        runs: Mutex<Vec<ContainerSpec>>,
+        removed: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl ContainerEngine for Arc<RecordingEngine> {
+        async fn run(&self, spec: &ContainerSpec) -> Result<String> {
+            let mut runs = self.runs.lock();
+            runs.push(spec.clone());
+            Ok(format!("container-{}", runs.len()))
+        }
+
+        async fn remove(&self, container_id: &str) -> Result<()> {
+            self.removed.lock().push(container_id.to_owned());
+            Ok(())
+        }
+    }
+
+    fn spawn_config(container: Option<ContainerConfig>) -> OopSpawnConfig {
+        OopSpawnConfig {
+            module_name: "calculator".to_owned(),
+            binary: PathBuf::new(),
+            args: vec!["--config".to_owned(), "/etc/calc.yaml".to_owned()],
+            env: HashMap::from([
+                ("RUST_LOG".to_owned(), "info".to_owned()),
+                (MODKIT_MODULE_CONFIG_ENV.to_owned(), "{}".to_owned()),
+                (
+                    MODKIT_DIRECTORY_ENDPOINT_ENV.to_owned(),
+                    "http://0.0.0.0:50051".to_owned(),
+                ),
+            ]),
+            working_directory: None,
+            restart_policy: RestartPolicy::Never,
+            container,
+        }
+    }
+
+    fn container_config() -> ContainerConfig {
+        ContainerConfig {
+            image: "ghcr.io/acme/calculator:1.2".to_owned(),
+            ports: vec!["8080:8080".to_owned()],
+            volumes: vec!["/srv/calc:/data:ro".to_owned()],
+        }
+    }
+
+    #[tokio::test]
+    async fn spawn_builds_container_spec_from_config() {
+        let engine = Arc::new(RecordingEngine::default());
+        let backend = DockerBackend::with_engine(Arc::clone(&engine), CancellationToken::new());
+
+        backend
+            .spawn(spawn_config(Some(container_config())))
+            .await
+            .unwrap();
+
+        let runs = engine.runs.lock();
+        assert_eq!(runs.len(), 1);
+        let spec = &runs[0];
+        assert!(spec.name.starts_with("modkit-calculator-"));
+        assert_eq!(spec.image, "ghcr.io/acme/calculator:1.2");
+        assert_eq!(spec.args, ["--config", "/etc/calc.yaml"]);
+        assert_eq!(spec.ports, ["8080:8080"]);
+        assert_eq!(spec.volumes, ["/srv/calc:/data:ro"]);
+        assert_eq!(spec.env["RUST_LOG"], "info");
+        assert_eq!(spec.env[MODKIT_MODULE_CONFIG_ENV], "{}");
+        assert_eq!(
+            spec.env[MODKIT_DIRECTORY_ENDPOINT_ENV],
+            "http://host.docker.internal:50051"
+        );
+        assert_eq!(spec.extra_hosts, ["host.docker.internal:host-gateway"]);
+    }
+
+    #[tokio::test]
+    async fn spawn_without_container_config_fails() {
+        let engine = Arc::new(RecordingEngine::default());
+        let backend = DockerBackend::with_engine(Arc::clone(&engine), CancellationToken::new());
+
+        let err = backend.spawn(spawn_config(None)).await.unwrap_err();
+        assert!(err.to_string().contains("no container config"));
+        assert!(engine.runs.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn cancel_removes_started_containers() {
+        let engine = Arc::new(RecordingEngine::default());
+        let cancel = CancellationToken::new();
+        let backend = DockerBackend::with_engine(Arc::clone(&engine), cancel.clone());
+
+        backend
+            .spawn(spawn_config(Some(container_config())))
+            .await
+            .unwrap();
+        cancel.cancel();
+        tokio::time::timeout(std::time::Duration::from_secs(1), async {
+            while engine.removed.lock().is_empty() {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(*engine.removed.lock(), ["container-1"]);
+    }
+
+    #[tokio::test]
+    async fn spawn_rejects_loopback_directory_endpoint() {
+        let engine = Arc::new(RecordingEngine::default());
+        let backend = DockerBackend::with_engine(Arc::clone(&engine), CancellationToken::new());
+        let mut config = spawn_config(Some(container_config()));
+        config.env.insert(
+            MODKIT_DIRECTORY_ENDPOINT_ENV.to_owned(),
+            "http://127.0.0.1:50051".to_owned(),
+        );
+
+        let err = backend.spawn(config).await.unwrap_err();
+        assert!(format!("{err:#}").contains("bound to loopback"));
+        assert!(engine.runs.lock().is_empty());
+    }
+
+    #[test]
+    fn restart_policy_maps_to_docker_restart() {
+        let mut config = spawn_config(Some(container_config()));
+        let args = ContainerSpec::from_spawn_config(&config, &container_config())
+            .unwrap()
+            .run_args();
+        assert!(args.iter().any(|a| a == "--rm"));
+        assert!(!args.iter().any(|a| a == "--restart"));
+
+        config.restart_policy = RestartPolicy::OnFailure {
+            max_retries: 3,
+            backoff: std::time::Duration::from_secs(1),
+        };
+        let args = ContainerSpec::from_spawn_config(&config, &container_config())
+            .unwrap()
+            .run_args();
+        assert!(args.windows(2).any(|w| w == ["--restart", "on-failure:3"]));
+        assert!(!args.iter().any(|a| a == "--rm"));
+
+        config.restart_policy = RestartPolicy::Always;
+        let args = ContainerSpec::from_spawn_config(&config, &container_config())
+            .unwrap()
+            .run_args();
+        assert!(args.windows(2).any(|w| w == ["--restart", "always"]));
+    }
+
+    #[test]
+    fn run_args_keep_env_values_off_the_command_line() {
+        let spec = ContainerSpec::from_spawn_config(
+            &spawn_config(Some(container_config())),
+            &container_config(),
+        )
+        .unwrap();
+        let args = spec.run_args();
+
+        assert!(args.windows(2).any(|w| w == ["--env", "RUST_LOG"]));
+        assert!(args.windows(2).any(|w| w == ["--publish", "8080:8080"]));
+        assert!(
+            args.windows(2)
+                .any(|w| w == ["--volume", "/srv/calc:/data:ro"])
+        );
+        assert!(!args.iter().any(|a| a.contains("info")));
+        let image_pos = args.iter().position(|a| a == &spec.image).unwrap();
+        assert_eq!(&args[image_pos + 1..], ["--config", "/etc/calc.yaml"]);
+    }
+
+    #[test]
+    fn container_endpoint_rewrites_unspecified_and_rejects_loopback() {
+        assert_eq!(
+            container_endpoint("http://0.0.0.0:1234")
+                .unwrap()
+                .as_deref(),
+            Some("http://host.docker.internal:1234")
+        );
+        assert_eq!(
+            container_endpoint("http://[::]:1234/x").unwrap().as_deref(),
+            Some("http://host.docker.internal:1234/x")
+        );
+        assert!(container_endpoint("http://localhost:1234").is_err());
+        assert!(container_endpoint("http://[::1]:1234").is_err());
+        assert_eq!(container_endpoint("http://10.0.0.5:1234").unwrap(), None);
+        assert_eq!(
+            container_endpoint("http://localhost.example:1").unwrap(),
+            None
+        );
+    }
+}
@@ -14,7 +14,7 @@ use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
 use super::log_forwarder::{StreamKind, spawn_stream_forwarder};
-use super::{BackendKind, InstanceHandle, ModuleRuntimeBackend, OopModuleConfig};
+use super::{BackendKind, InstanceExit, InstanceHandle, ModuleRuntimeBackend, OopModuleConfig};
 
 /// Grace period before force-killing processes on shutdown
 const SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(5);
@@ -25,6 +25,9 @@ const INSTANCE_STOP_GRACE_PERIOD: Duration = Duration::from_secs(2);
 /// Timeout for waiting on forwarder tasks during shutdown
 const FORWARDER_DRAIN_TIMEOUT: Duration = Duration::from_millis(100);
 
+/// How often `wait_instance` checks whether a child process has exited
+const EXIT_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
 /// Send graceful termination signal to a child process.
 ///
 /// # Returns
@@ -186,6 +189,9 @@ type InstanceMap = HashMap<Uuid, LocalInstance>;
 /// 1. Send termination signal to all processes (SIGTERM on Unix, `TerminateProcess` on Windows)
 /// 2. Wait up to 5 seconds for graceful shutdown
 /// 3. Force kill any remaining processes
+///
+/// Clones share the same set of instances and cancellation token.
+#[derive(Clone)]
 pub struct LocalProcessBackend {
     instances: Arc<RwLock<InstanceMap>>,
     cancel: CancellationToken,
@@ -213,6 +219,11 @@ impl LocalProcessBackend {
         backend
     }
 
+    /// Cancellation token that stops this backend and its supervisors.
+    pub(super) fn cancel_token(&self) -> CancellationToken {
+        self.cancel.clone()
+    }
+
     /// Gracefully stop all tracked instances with timeout.
     async fn shutdown_all_instances(instances: Arc<RwLock<InstanceMap>>) {
         let mut all_instances: Vec<LocalInstance> = {
@@ -404,6 +415,26 @@ impl ModuleRuntimeBackend for LocalProcessBackend {
 
         Ok(result)
     }
+
+    async fn wait_instance(&self, handle: &InstanceHandle) -> Result<InstanceExit> {
+        loop {
+            {
+                let mut instances = self.instances.write();
+                let Some(local) = instances.get_mut(&handle.instance_id) else {
+                    return Ok(InstanceExit::Stopped);
+                };
+                if let Some(status) = local.child.try_wait()? {
+                    // Exited on its own: forget it, forwarders end when the pipes close.
+                    instances.remove(&handle.instance_id);
+                    return Ok(InstanceExit::Exited {
+                        success: status.success(),
+                        code: status.code(),
+                    });
+                }
+            }
+            tokio::time::sleep(EXIT_POLL_INTERVAL).await;
+        }
+    }
 }
 
 #[cfg(test)]
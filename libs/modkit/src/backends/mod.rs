@@ -2,11 +2,12 @@
 //!
 //! This module provides traits and types for spawning and managing `OoP` module instances.
 
-use anyhow::Result;
+use anyhow::{Context, Result, bail, ensure};
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::PathBuf;
-use std::time::Instant;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 use uuid::Uuid;
 
 /// The kind of backend used to spawn and manage module instances
@@ -65,21 +66,149 @@ impl std::fmt::Debug for InstanceHandle {
     }
 }
 
+/// How a module instance ended, as observed by [`ModuleRuntimeBackend::wait_instance`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstanceExit {
+    /// The process exited on its own; `success` reflects its exit status.
+    Exited { success: bool, code: Option<i32> },
+    /// The instance was stopped through the backend (`stop_instance` or shutdown).
+    Stopped,
+}
+
 /// Trait for backends that can spawn and manage module instances
 #[async_trait]
 pub trait ModuleRuntimeBackend: Send + Sync {
     async fn spawn_instance(&self, cfg: &OopModuleConfig) -> Result<InstanceHandle>;
     async fn stop_instance(&self, handle: &InstanceHandle) -> Result<()>;
     async fn list_instances(&self, module: &str) -> Result<Vec<InstanceHandle>>;
+
+    /// Wait until the instance exits.
+    ///
+    /// Backends that cannot observe exits keep the default, which never resolves,
+    /// so their instances are never restarted.
+    async fn wait_instance(&self, _handle: &InstanceHandle) -> Result<InstanceExit> {
+        std::future::pending().await
+    }
+}
+
+/// Initial restart delay for [`RestartPolicy::Always`].
+pub const DEFAULT_RESTART_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Upper bound for the exponential restart delay.
+pub const MAX_RESTART_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Uptime after which an instance counts as stable and the consecutive
+/// restart count starts over.
+pub const RESTART_RESET_AFTER: Duration = Duration::from_secs(300);
+
+/// When to restart an `OoP` module whose process exited.
+///
+/// Restart delays grow exponentially from the initial backoff (doubling per
+/// consecutive restart, capped at [`MAX_RESTART_BACKOFF`]). An instance that
+/// stayed up for [`RESTART_RESET_AFTER`] resets the consecutive restart count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RestartPolicy {
+    /// Spawn once; never restart (default).
+    #[default]
+    Never,
+    /// Restart after a non-zero exit, at most `max_retries` times.
+    OnFailure {
+        max_retries: u32,
+        #[serde(with = "modkit_utils::humantime_serde")]
+        backoff: Duration,
+    },
+    /// Restart after every exit, starting from [`DEFAULT_RESTART_BACKOFF`].
+    Always,
+}
+
+impl RestartPolicy {
+    /// Delay before the next restart, or `None` when the module must stay down.
+    ///
+    /// `restarts` is the number of restarts already performed.
+    #[must_use]
+    pub fn next_delay(&self, exit: InstanceExit, restarts: u32) -> Option<Duration> {
+        let InstanceExit::Exited { success, .. } = exit else {
+            return None;
+        };
+        let initial = match *self {
+            Self::Never => return None,
+            Self::OnFailure {
+                max_retries,
+                backoff,
+            } => {
+                if success || restarts >= max_retries {
+                    return None;
+                }
+                backoff
+            }
+            Self::Always => DEFAULT_RESTART_BACKOFF,
+        };
+        let factor = 2u32.saturating_pow(restarts.min(16));
+        Some(initial.saturating_mul(factor).min(MAX_RESTART_BACKOFF))
+    }
+}
+
+/// Container settings for modules that run as containers instead of local processes
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContainerConfig {
+    pub image: String,
+    /// Published ports in `docker run --publish` syntax (`8080:8080`).
+    pub ports: Vec<String>,
+    /// Mounted volumes in `docker run --volume` syntax (`/host:/container[:ro]`).
+    pub volumes: Vec<String>,
 }
 
 /// Configuration passed to `OopBackend::spawn`
 pub struct OopSpawnConfig {
     pub module_name: String,
+    /// Executable to run; empty for container modules.
     pub binary: PathBuf,
     pub args: Vec<String>,
     pub env: HashMap<String, String>,
     pub working_directory: Option<String>,
+    pub restart_policy: RestartPolicy,
+    /// Set for container modules; `binary` and `working_directory` are then unused.
+    pub container: Option<ContainerConfig>,
+}
+
+/// Check that an `OoP` executable can be spawned.
+///
+/// `binary` is expected to be normalized already (the bootstrap resolves
+/// `executable_path` with `normalize_path`). It must be an existing file; on
+/// Unix it must also have an execute bit set.
+///
+/// # Errors
+/// Returns an error naming the path when it is missing, not a file, or not
+/// executable.
+pub(crate) fn check_executable(binary: &Path) -> Result<()> {
+    let metadata = match std::fs::metadata(binary) {
+        Ok(metadata) => metadata,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            bail!("executable not found: {}", binary.display())
+        }
+        Err(e) => {
+            return Err(e)
+                .with_context(|| format!("cannot access executable {}", binary.display()));
+        }
+    };
+    ensure!(
+        metadata.is_file(),
+        "executable path is not a file: {}",
+        binary.display()
+    );
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        ensure!(
+            metadata.permissions().mode() & 0o111 != 0,
+            "file is not executable: {}",
+            binary.display()
+        );
+    }
+
+    Ok(())
 }
 
 /// A type-erased backend for spawning `OoP` modules.
@@ -94,9 +223,12 @@ pub trait OopBackend: Send + Sync {
     async fn shutdown_all(&self);
 }
 
+pub mod docker;
 pub mod local;
 pub mod log_forwarder;
+mod supervisor;
 
+pub use docker::{ContainerEngine, ContainerSpec, DockerBackend, DockerCli};
 pub use local::LocalProcessBackend;
 
 /// Adapter that implements `OopBackend` trait for `LocalProcessBackend`.
@@ -105,13 +237,27 @@ pub use local::LocalProcessBackend;
 #[async_trait]
 impl OopBackend for LocalProcessBackend {
     async fn spawn(&self, config: OopSpawnConfig) -> Result<()> {
+        ensure!(
+            config.container.is_none(),
+            "module '{}' is a container module; LocalProcessBackend only runs executables",
+            config.module_name
+        );
         let mut oop_config = OopModuleConfig::new(&config.module_name, BackendKind::LocalProcess);
         oop_config.binary = Some(config.binary);
         oop_config.args = config.args;
         oop_config.env = config.env;
         oop_config.working_directory = config.working_directory;
 
-        self.spawn_instance(&oop_config).await?;
+        let handle = self.spawn_instance(&oop_config).await?;
+        if config.restart_policy != RestartPolicy::Never {
+            tokio::spawn(supervisor::supervise(
+                self.clone(),
+                oop_config,
+                handle,
+                config.restart_policy,
+                self.cancel_token(),
+            ));
+        }
         Ok(())
     }
 
@@ -169,4 +315,41 @@ mod tests {
         assert!(debug_str.contains("LocalProcess"));
         assert!(debug_str.contains("12345"));
     }
+
+    #[test]
+    fn test_check_executable_missing_binary() {
+        let dir = tempfile::tempdir().unwrap();
+        let missing = dir.path().join("no-such-module");
+
+        let err = check_executable(&missing).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("executable not found: {}", missing.display())
+        );
+    }
+
+    #[test]
+    fn test_check_executable_rejects_directory() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let err = check_executable(dir.path()).unwrap_err();
+        assert!(err.to_string().starts_with("executable path is not a file"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_check_executable_checks_execute_bit() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let binary = dir.path().join("module");
+        std::fs::write(&binary, "#!/bin/sh\n").unwrap();
+
+        std::fs::set_permissions(&binary, std::fs::Permissions::from_mode(0o644)).unwrap();
+        let err = check_executable(&binary).unwrap_err();
+        assert!(err.to_string().starts_with("file is not executable"));
+
+        std::fs::set_permissions(&binary, std::fs::Permissions::from_mode(0o755)).unwrap();
+        check_executable(&binary).unwrap();
+    }
 }
@@ -0,0 +1,286 @@
+//! Restart supervision for `OoP` module instances.
+
+use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
+
+use super::{
+    InstanceExit, InstanceHandle, ModuleRuntimeBackend, OopModuleConfig, RESTART_RESET_AFTER,
+    RestartPolicy,
+};
+
+/// Watch `handle` and respawn the module according to `policy` until it has to
+/// stay down or `cancel` fires. Returns the number of restarts performed.
+///
+/// The policy sees consecutive restarts only: an instance that stayed up for
+/// [`RESTART_RESET_AFTER`] starts the count over.
+pub async fn supervise<B: ModuleRuntimeBackend>(
+    backend: B,
+    cfg: OopModuleConfig,
+    handle: InstanceHandle,
+    policy: RestartPolicy,
+    cancel: CancellationToken,
+) -> u32 {
+    let mut restarts = 0;
+    let mut consecutive = 0;
+    let mut started = Instant::now();
+    let mut exit = wait_for_exit(&backend, &handle, &cancel).await;
+
+    loop {
+        let Some(last_exit) = exit else {
+            return restarts;
+        };
+        if started.elapsed() >= RESTART_RESET_AFTER {
+            consecutive = 0;
+        }
+        let Some(delay) = policy.next_delay(last_exit, consecutive) else {
+            tracing::info!(
+                module = %cfg.name,
+                exit = ?last_exit,
+                restarts,
+                "OoP module exited; not restarting per restart policy"
+            );
+            return restarts;
+        };
+
+        tracing::warn!(
+            module = %cfg.name,
+            exit = ?last_exit,
+            attempt = restarts + 1,
+            delay_ms = delay.as_millis(),
+            "OoP module exited; scheduling restart"
+        );
+        tokio::select! {
+            () = cancel.cancelled() => return restarts,
+            () = tokio::time::sleep(delay) => {}
+        }
+
+        restarts += 1;
+        consecutive += 1;
+        started = Instant::now();
+        exit = match backend.spawn_instance(&cfg).await {
+            Ok(handle) => {
+                tracing::info!(
+                    module = %cfg.name,
+                    instance_id = %handle.instance_id,
+                    pid = ?handle.pid,
+                    attempt = restarts,
+                    "Restarted OoP module"
+                );
+                wait_for_exit(&backend, &handle, &cancel).await
+            }
+            Err(e) => {
+                tracing::warn!(
+                    module = %cfg.name,
+                    attempt = restarts,
+                    error = %e,
+                    "Failed to restart OoP module"
+                );
+                Some(InstanceExit::Exited {
+                    success: false,
+                    code: None,
+                })
+            }
+        };
+    }
+}
+
+/// `None` when supervision should end without a restart decision
+/// (cancellation, or the backend cannot report the exit).
+async fn wait_for_exit<B: ModuleRuntimeBackend>(
+    backend: &B,
+    handle: &InstanceHandle,
+    cancel: &CancellationToken,
+) -> Option<InstanceExit> {
+    tokio::select! {
+        () = cancel.cancelled() => None,
+        result = backend.wait_instance(handle) => match result {
+            Ok(exit) => Some(exit),
+            Err(e) => {
+                tracing::warn!(
+                    module = %handle.module,
+                    instance_id = %handle.instance_id,
+                    error = %e,
+                    "Failed to observe OoP module exit; supervision stopped"
+                );
+                None
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::backends::BackendKind;
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::{Duration, Instant};
+    use uuid::Uuid;
+
+    /// Backend whose instances exit after `uptime` with a fixed outcome.
+    #[derive(Clone)]
+    struct CrashingBackend {
+        spawns: Arc<AtomicU32>,
+        success: bool,
+        uptime: Duration,
+    }
+
+    impl CrashingBackend {
+        fn new(success: bool) -> Self {
+            Self {
+                spawns: Arc::new(AtomicU32::new(0)),
+                success,
+                uptime: Duration::ZERO,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ModuleRuntimeBackend for CrashingBackend {
+        async fn spawn_instance(&self, cfg: &OopModuleConfig) -> Result<InstanceHandle> {
+            self.spawns.fetch_add(1, Ordering::SeqCst);
+            Ok(handle(&cfg.name))
+        }
+
+        async fn stop_instance(&self, _handle: &InstanceHandle) -> Result<()> {
+            Ok(())
+        }
+
+        async fn list_instances(&self, _module: &str) -> Result<Vec<InstanceHandle>> {
+            Ok(Vec::new())
+        }
+
+        async fn wait_instance(&self, _handle: &InstanceHandle) -> Result<InstanceExit> {
+            tokio::time::sleep(self.uptime).await;
+            Ok(InstanceExit::Exited {
+                success: self.success,
+                code: Some(i32::from(!self.success)),
+            })
+        }
+    }
+
+    fn handle(module: &str) -> InstanceHandle {
+        InstanceHandle {
+            module: module.to_owned(),
+            instance_id: Uuid::new_v4(),
+            backend: BackendKind::Mock,
+            pid: None,
+            created_at: Instant::now(),
+        }
+    }
+
+    async fn run(
+        backend: &CrashingBackend,
+        policy: RestartPolicy,
+        cancel: CancellationToken,
+    ) -> u32 {
+        let cfg = OopModuleConfig::new("crashy", BackendKind::Mock);
+        supervise(backend.clone(), cfg, handle("crashy"), policy, cancel).await
+    }
+
+    #[tokio::test]
+    async fn never_policy_does_not_restart() {
+        let backend = CrashingBackend::new(false);
+        let restarts = run(&backend, RestartPolicy::Never, CancellationToken::new()).await;
+
+        assert_eq!(restarts, 0);
+        assert_eq!(backend.spawns.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn on_failure_restarts_up_to_max_retries() {
+        let backend = CrashingBackend::new(false);
+        let policy = RestartPolicy::OnFailure {
+            max_retries: 3,
+            backoff: Duration::from_millis(1),
+        };
+        let restarts = run(&backend, policy, CancellationToken::new()).await;
+
+        assert_eq!(restarts, 3);
+        assert_eq!(backend.spawns.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn on_failure_ignores_clean_exit() {
+        let backend = CrashingBackend::new(true);
+        let policy = RestartPolicy::OnFailure {
+            max_retries: 3,
+            backoff: Duration::from_millis(1),
+        };
+
+        assert_eq!(run(&backend, policy, CancellationToken::new()).await, 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn always_restarts_until_cancelled() {
+        let backend = CrashingBackend::new(true);
+        let cancel = CancellationToken::new();
+
+        // DEFAULT_RESTART_BACKOFF is 1s: let the first restart happen, then cancel
+        // while the second (2s) backoff is pending.
+        let supervisor = tokio::spawn({
+            let backend = backend.clone();
+            let cancel = cancel.clone();
+            async move { run(&backend, RestartPolicy::Always, cancel).await }
+        });
+        tokio::time::sleep(Duration::from_millis(1500)).await;
+        cancel.cancel();
+
+        assert_eq!(supervisor.await.unwrap(), 1);
+        assert_eq!(backend.spawns.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stable_uptime_resets_the_retry_budget() {
+        let backend = CrashingBackend {
+            uptime: RESTART_RESET_AFTER,
+            ..CrashingBackend::new(false)
+        };
+        let cancel = CancellationToken::new();
+        let policy = RestartPolicy::OnFailure {
+            max_retries: 1,
+            backoff: Duration::from_millis(1),
+        };
+
+        let supervisor = tokio::spawn({
+            let backend = backend.clone();
+            let cancel = cancel.clone();
+            async move { run(&backend, policy, cancel).await }
+        });
+        // Every instance crashes only after a stable run, so a single-retry
+        // budget never runs out.
+        tokio::time::sleep(RESTART_RESET_AFTER * 5).await;
+        cancel.cancel();
+
+        assert!(supervisor.await.unwrap() >= 4);
+    }
+
+    #[test]
+    fn backoff_grows_exponentially_and_is_capped() {
+        let crashed = InstanceExit::Exited {
+            success: false,
+            code: Some(1),
+        };
+        let policy = RestartPolicy::OnFailure {
+            max_retries: 100,
+            backoff: Duration::from_millis(100),
+        };
+
+        assert_eq!(
+            policy.next_delay(crashed, 0),
+            Some(Duration::from_millis(100))
+        );
+        assert_eq!(
+            policy.next_delay(crashed, 3),
+            Some(Duration::from_millis(800))
+        );
+        assert_eq!(
+            policy.next_delay(crashed, 50),
+            Some(crate::backends::MAX_RESTART_BACKOFF)
+        );
+        assert_eq!(policy.next_delay(InstanceExit::Stopped, 0), None);
+    }
+}
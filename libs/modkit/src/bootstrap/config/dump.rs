@@ -3,10 +3,9 @@
 //! This module provides utilities for inspecting and dumping the effective
 //! runtime configuration of modules, including resolved database DSNs and pool settings.
 
-use super::{AppConfig, RuntimeKind, build_final_db_for_module, parse_module_config};
+use super::{AppConfig, RuntimeKind, build_final_db_for_module, parse_module_config, redact_dsn};
 use anyhow::{Context, Result};
-use std::path::PathBuf;
-use url::Url;
+use std::path::{Path, PathBuf};
 
 /// List all module names present in the configuration.
 ///
@@ -51,25 +50,13 @@ pub fn list_module_names(app: &AppConfig) -> Vec<String> {
 /// are logged as warnings and the problematic modules are skipped. The `Result`
 /// return type is kept for API consistency with other dump functions.
 pub fn render_effective_modules_config(app: &AppConfig) -> Result<serde_json::Value> {
-    use serde_json::json;
-
-    let home_dir = PathBuf::from(&app.server.home_dir);
-    // Prevent path traversal attacks by rejecting paths containing '..'
-    if home_dir
-        .components()
-        .any(|c| c == std::path::Component::ParentDir)
-    {
-        return Err(anyhow::anyhow!("Invalid input: {}", home_dir.display()));
-    }
+    let home_dir = checked_home_dir(app)?;
     let mut modules_config = serde_json::Map::new();
 
     // Iterate over all modules in the configuration
     for module_name in app.modules.keys() {
-        let mut module_entry = serde_json::Map::new();
-
-        // Parse module config once for efficiency
-        let parsed_config = match parse_module_config(app, module_name) {
-            Ok(config) => config,
+        let module_entry = match render_module_entry(app, module_name, &home_dir) {
+            Ok(entry) => entry,
             Err(e) => {
                 tracing::warn!(
                     module = %module_name,
@@ -80,122 +67,188 @@ pub fn render_effective_modules_config(app: &AppConfig) -> Result<serde_json::Va
             }
         };
 
-        // Get runtime configuration if present
-        if let Some(runtime_config) = parsed_config.runtime {
-            module_entry.insert(
-                "runtime".to_owned(),
-                json!({
-                    "type": match runtime_config.mod_type {
-                        RuntimeKind::Local => "local",
-                        RuntimeKind::Oop => "oop",
-                    }
-                }),
-            );
+        // Only add module to output if it has any configuration
+        if !module_entry.is_empty() {
+            modules_config.insert(module_name.clone(), serde_json::Value::Object(module_entry));
         }
+    }
 
-        // Get module config section (the "config" field)
-        if !parsed_config.config.is_null() {
-            module_entry.insert("config".to_owned(), parsed_config.config);
-        }
+    Ok(serde_json::Value::Object(modules_config))
+}
 
-        // Get database configuration (resolved DSN + pool) - use dry_run=true
-        match build_final_db_for_module(app, module_name, &home_dir, true) {
-            Ok(Some((dsn, pool))) => {
-                // Redact password in DSN (warn and skip DB section if this fails, but keep module)
-                let redacted_dsn = match redact_dsn_password(&dsn) {
-                    Ok(redacted) => redacted,
-                    Err(e) => {
-                        tracing::warn!(
-                            module = %module_name,
-                            error = %e,
-                            "Failed to redact DSN password, skipping database config for this module"
-                        );
-                        // Continue processing this module, just skip the DB section
-                        // Add module entry even without DB config
-                        if !module_entry.is_empty() {
-                            modules_config.insert(module_name.clone(), json!(module_entry));
-                        }
-                        continue;
-                    }
-                };
-
-                let mut db_config = serde_json::Map::new();
-                db_config.insert("dsn".to_owned(), json!(redacted_dsn));
-
-                // Add pool configuration if present
-                let mut pool_map = serde_json::Map::new();
-                if let Some(max_conns) = pool.max_conns {
-                    pool_map.insert("max_conns".to_owned(), json!(max_conns));
-                }
-                if let Some(min_conns) = pool.min_conns {
-                    pool_map.insert("min_conns".to_owned(), json!(min_conns));
-                }
-                if let Some(acquire_timeout) = pool.acquire_timeout {
-                    pool_map.insert(
-                        "acquire_timeout".to_owned(),
-                        json!(format!("{}s", acquire_timeout.as_secs())),
-                    );
-                }
-                if let Some(idle_timeout) = pool.idle_timeout {
-                    pool_map.insert(
-                        "idle_timeout".to_owned(),
-                        json!(format!("{}s", idle_timeout.as_secs())),
-                    );
+/// Render effective configuration for a single module.
+///
+/// Produces the same entry that [`render_effective_modules_config`] emits under the
+/// module's name (an empty object if the module has no effective configuration).
+///
+/// # Errors
+/// Returns an error if the module is not configured or its config cannot be parsed.
+pub fn render_effective_module_config(
+    app: &AppConfig,
+    module_name: &str,
+) -> Result<serde_json::Value> {
+    if !app.modules.contains_key(module_name) {
+        anyhow::bail!("unknown module '{module_name}'");
+    }
+    let home_dir = checked_home_dir(app)?;
+    render_module_entry(app, module_name, &home_dir).map(serde_json::Value::Object)
+}
+
+fn checked_home_dir(app: &AppConfig) -> Result<PathBuf> {
+    let home_dir = PathBuf::from(&app.server.home_dir);
+    // Prevent path traversal attacks by rejecting paths containing '..'
+    if home_dir
+        .components()
+        .any(|c| c == std::path::Component::ParentDir)
+    {
+        return Err(anyhow::anyhow!("Invalid input: {}", home_dir.display()));
+    }
+    Ok(home_dir)
+}
+
+/// Build one module's effective entry (`runtime`, `config`, `database`).
+///
+/// Fails only if the module config cannot be parsed; database resolution and
+/// redaction problems are logged and the `database` section is left out.
+fn render_module_entry(
+    app: &AppConfig,
+    module_name: &str,
+    home_dir: &Path,
+) -> Result<serde_json::Map<String, serde_json::Value>> {
+    use serde_json::json;
+
+    let mut module_entry = serde_json::Map::new();
+
+    // Parse module config once for efficiency
+    let parsed_config = parse_module_config(app, module_name)?;
+
+    // Get runtime configuration if present
+    if let Some(runtime_config) = parsed_config.runtime {
+        module_entry.insert(
+            "runtime".to_owned(),
+            json!({
+                "type": match runtime_config.mod_type {
+                    RuntimeKind::Local => "local",
+                    RuntimeKind::Oop => "oop",
+                    RuntimeKind::Docker => "docker",
                 }
-                if let Some(max_lifetime) = pool.max_lifetime {
-                    pool_map.insert(
-                        "max_lifetime".to_owned(),
-                        json!(format!("{}s", max_lifetime.as_secs())),
+            }),
+        );
+    }
+
+    // Get module config section (the "config" field)
+    if !parsed_config.config.is_null() {
+        module_entry.insert("config".to_owned(), parsed_config.config);
+    }
+
+    // Get database configuration (resolved DSN + pool) - use dry_run=true
+    match build_final_db_for_module(app, module_name, home_dir, true) {
+        Ok(Some((dsn, pool, replicas))) => {
+            // Redact password in DSN (warn and skip DB section if this fails, but keep module)
+            let redacted_dsn = match redact_dsn_password(&dsn) {
+                Ok(redacted) => redacted,
+                Err(e) => {
+                    tracing::warn!(
+                        module = %module_name,
+                        error = %e,
+                        "Failed to redact DSN password, skipping database config for this module"
                     );
+                    return Ok(module_entry);
                 }
-                if let Some(test_before_acquire) = pool.test_before_acquire {
-                    pool_map.insert("test_before_acquire".to_owned(), json!(test_before_acquire));
-                }
+            };
 
-                if !pool_map.is_empty() {
-                    db_config.insert("pool".to_owned(), json!(pool_map));
-                }
+            let mut db_config = serde_json::Map::new();
+            db_config.insert("dsn".to_owned(), json!(redacted_dsn));
+            if !replicas.is_empty() {
+                db_config.insert(
+                    "replicas".to_owned(),
+                    json!(redact_replica_dsns(module_name, &replicas)),
+                );
+            }
 
-                module_entry.insert("database".to_owned(), json!(db_config));
+            // Add pool configuration if present
+            let mut pool_map = serde_json::Map::new();
+            if let Some(max_conns) = pool.max_conns {
+                pool_map.insert("max_conns".to_owned(), json!(max_conns));
             }
-            Ok(None) => {
-                // Module has no database config, skip
+            if let Some(min_conns) = pool.min_conns {
+                pool_map.insert("min_conns".to_owned(), json!(min_conns));
             }
-            Err(e) => {
-                tracing::warn!(
-                    module = %module_name,
-                    error = %e,
-                    "Failed to build database config, skipping"
+            if let Some(acquire_timeout) = pool.acquire_timeout {
+                pool_map.insert(
+                    "acquire_timeout".to_owned(),
+                    json!(format!("{}s", acquire_timeout.as_secs())),
                 );
             }
-        }
+            if let Some(idle_timeout) = pool.idle_timeout {
+                pool_map.insert(
+                    "idle_timeout".to_owned(),
+                    json!(format!("{}s", idle_timeout.as_secs())),
+                );
+            }
+            if let Some(max_lifetime) = pool.max_lifetime {
+                pool_map.insert(
+                    "max_lifetime".to_owned(),
+                    json!(format!("{}s", max_lifetime.as_secs())),
+                );
+            }
+            if let Some(test_before_acquire) = pool.test_before_acquire {
+                pool_map.insert("test_before_acquire".to_owned(), json!(test_before_acquire));
+            }
 
-        // Only add module to output if it has any configuration
-        if !module_entry.is_empty() {
-            modules_config.insert(module_name.clone(), json!(module_entry));
+            if !pool_map.is_empty() {
+                db_config.insert("pool".to_owned(), json!(pool_map));
+            }
+
+            module_entry.insert("database".to_owned(), json!(db_config));
+        }
+        Ok(None) => {
+            // Module has no database config, skip
+        }
+        Err(e) => {
+            tracing::warn!(
+                module = %module_name,
+                error = %e,
+                "Failed to build database config, skipping"
+            );
         }
     }
 
-    Ok(json!(modules_config))
+    Ok(module_entry)
+}
+
+/// Placeholder rendered for a DSN that could not be redacted.
+const REDACTED_DSN: &str = "***REDACTED***";
+
+/// Redacts each replica DSN. A replica whose DSN cannot be redacted is logged and
+/// rendered as [`REDACTED_DSN`], so it stays visible (and in position) in the dump.
+pub(super) fn redact_replica_dsns(module_name: &str, replicas: &[String]) -> Vec<String> {
+    replicas
+        .iter()
+        .enumerate()
+        .map(|(i, replica)| {
+            redact_dsn_password(replica).unwrap_or_else(|e| {
+                tracing::warn!(
+                    module = %module_name,
+                    replica = i,
+                    error = %e,
+                    "Failed to redact replica DSN password"
+                );
+                REDACTED_DSN.to_owned()
+            })
+        })
+        .collect()
 }
 
 /// Redacts password from a DSN for safe logging.
 ///
-/// Replaces the password portion with `***REDACTED***` while preserving the rest of the DSN.
+/// Replaces the password portion and credential query parameters (`password`,
+/// `sslpassword`, ...) with `***REDACTED***` while preserving the rest of the DSN.
 ///
 /// # Errors
 /// Returns an error if DSN parsing fails.
 pub fn redact_dsn_password(dsn: &str) -> Result<String> {
-    if dsn.contains('@') {
-        let parsed = Url::parse(dsn)?;
-        let mut redacted_url = parsed;
-        if redacted_url.password().is_some() {
-            redacted_url.set_password(Some("***REDACTED***")).ok();
-        }
-        Ok(redacted_url.to_string())
-    } else {
-        Ok(dsn.to_owned())
-    }
+    redact_dsn(dsn, "***REDACTED***")
 }
 
 /// Dump effective modules configuration as YAML string.
@@ -222,3 +275,27 @@ pub fn dump_effective_modules_config_json(app: &AppConfig) -> Result<String> {
     serde_json::to_string_pretty(&config)
         .context("Failed to serialize modules configuration to JSON")
 }
+
+/// Dump one module's effective configuration as YAML string.
+///
+/// DSN passwords are redacted as in [`dump_effective_modules_config_yaml`].
+///
+/// # Errors
+/// Returns an error if the module is unknown, or rendering or YAML serialization fails.
+pub fn dump_effective_module_config_yaml(app: &AppConfig, module_name: &str) -> Result<String> {
+    let config = render_effective_module_config(app, module_name)?;
+    serde_saphyr::to_string(&config)
+        .with_context(|| format!("Failed to serialize config of module '{module_name}' to YAML"))
+}
+
+/// Dump one module's effective configuration as JSON string.
+///
+/// DSN passwords are redacted as in [`dump_effective_modules_config_json`].
+///
+/// # Errors
+/// Returns an error if the module is unknown, or rendering or JSON serialization fails.
+pub fn dump_effective_module_config_json(app: &AppConfig, module_name: &str) -> Result<String> {
+    let config = render_effective_module_config(app, module_name)?;
+    serde_json::to_string_pretty(&config)
+        .with_context(|| format!("Failed to serialize config of module '{module_name}' to JSON"))
+}
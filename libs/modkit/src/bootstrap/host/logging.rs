@@ -1,4 +1,4 @@
-use super::super::config::{ConsoleFormat, LoggingConfig, Section};
+use super::super::config::{ConsoleFormat, LogFormat, LoggingConfig, Section};
 use anyhow::Context;
 use std::io::Write;
 use std::path::Path;
@@ -215,16 +215,18 @@ pub fn init_logging_unified(cfg: &LoggingConfig, base_dir: &Path, otel_layer: Op
         let console_targets = build_target_console(&data);
         let file_targets = build_target_file(&data, file_router.default.is_some());
 
-        let console_format = data
-            .default_section
-            .map(|s| s.console_format)
-            .unwrap_or_default();
+        let console_layers = [LogFormat::Pretty, LogFormat::Compact, LogFormat::Json]
+            .into_iter()
+            .filter_map(|format| {
+                build_target_console_for_format(&data, format).map(|targets| (format, targets))
+            })
+            .collect::<Vec<_>>();
 
         install_subscriber(
             &console_targets,
+            &console_layers,
             &file_targets,
             file_router,
-            console_format,
             otel_layer,
         )
     });
@@ -264,6 +266,62 @@ fn build_target_console(config: &ConfigData) -> Targets {
     targets
 }
 
+/// Format of the `default` section: its `format`, or JSON when it is left at
+/// `pretty` and the legacy `console_format: json` is set.
+fn default_console_format(config: &ConfigData) -> LogFormat {
+    config
+        .default_section
+        .map_or(LogFormat::Pretty, |s| match (s.format, s.console_format) {
+            (LogFormat::Pretty, ConsoleFormat::Json) => LogFormat::Json,
+            (format, _) => format,
+        })
+}
+
+/// Console targets for the layer rendering `format`.
+///
+/// Each subsystem is routed to exactly one console layer: the one matching its own
+/// `format`, or the `default` section's format when left at `pretty`. In every other
+/// layer the subsystem is switched off, so nested prefixes with different formats
+/// never emit the same event twice. Returns `None` when no section uses `format`.
+fn build_target_console_for_format(config: &ConfigData, format: LogFormat) -> Option<Targets> {
+    let default_format = default_console_format(config);
+    let is_default = format == default_format;
+
+    let mut targets = if is_default {
+        build_target_console(config)
+    } else {
+        let mut targets = Targets::new().with_default(LevelFilter::OFF);
+        for crate_name in NOISY_CRATES {
+            targets = targets.with_target(*crate_name, LevelFilter::OFF);
+        }
+        targets
+    };
+    let mut used = is_default;
+
+    let default_level = config
+        .default_section
+        .and_then(|s| s.console_level)
+        .map_or(LevelFilter::INFO, LevelFilter::from_level);
+
+    for (crate_name, section) in &config.crate_sections {
+        let section_format = match section.format {
+            LogFormat::Pretty => default_format,
+            format => format,
+        };
+        if section_format != format {
+            targets = targets.with_target(crate_name.clone(), LevelFilter::OFF);
+        } else if !is_default {
+            let level = section
+                .console_level
+                .map_or(default_level, LevelFilter::from_level);
+            targets = targets.with_target(crate_name.clone(), level);
+            used = true;
+        }
+    }
+
+    used.then_some(targets)
+}
+
 fn build_target_file(config: &ConfigData, has_default_file: bool) -> Targets {
     // default level depends on whether there is a default file sink
     let default_level = if has_default_file {
@@ -384,14 +442,41 @@ fn stderr_supports_ansi() -> bool {
 
 // Keep a guard for non-blocking console to avoid being dropped.
 
+/// Console fmt layer rendering events in `format`.
+///
+/// JSON lines carry the event target and the current span with its parents,
+/// so log collectors can correlate events by module and request context.
+fn console_layer<S, W>(format: LogFormat, writer: W, ansi: bool) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+    W: for<'w> fmt::MakeWriter<'w> + Send + Sync + 'static,
+{
+    let layer = fmt::layer()
+        .with_writer(writer)
+        .with_target(true)
+        .with_level(true)
+        .with_timer(fmt::time::UtcTime::rfc_3339());
+
+    match format {
+        LogFormat::Pretty => layer.with_ansi(ansi).boxed(),
+        LogFormat::Compact => layer.compact().with_ansi(ansi).boxed(),
+        LogFormat::Json => layer
+            .json()
+            .with_ansi(false)
+            .with_current_span(true)
+            .with_span_list(true)
+            .boxed(),
+    }
+}
+
 // de1301_no_print_macros: eprintln! is intentional here — if the tracing subscriber
 // fails to initialize we cannot use tracing itself to report the failure.
 #[allow(unknown_lints, de1301_no_print_macros)]
 fn install_subscriber(
     console_targets: &tracing_subscriber::filter::Targets,
+    console_layers: &[(LogFormat, Targets)],
     file_targets: &tracing_subscriber::filter::Targets,
     file_router: MultiFileRouter,
-    console_format: ConsoleFormat,
     #[cfg_attr(not(feature = "otel"), allow(unused_variables))] otel_layer: Option<OtelLayer>,
 ) -> WorkerGuard {
     use tracing_subscriber::{EnvFilter, Registry, fmt, layer::SubscriberExt};
@@ -403,35 +488,17 @@ fn install_subscriber(
     // Console writer (non-blocking stderr)
     let (nb_stderr, guard) = tracing_appender::non_blocking(std::io::stderr());
 
-    // Console fmt layers: text (human-friendly) or JSON (structured).
-    // Only one is active at a time; the other is None.
-    let (console_text, console_json) = match console_format {
-        ConsoleFormat::Text => (
-            Some(
-                fmt::layer()
-                    .with_writer(nb_stderr)
-                    .with_ansi(stderr_supports_ansi())
-                    .with_target(true)
-                    .with_level(true)
-                    .with_timer(fmt::time::UtcTime::rfc_3339())
-                    .with_filter(console_targets.clone()),
-            ),
-            None,
-        ),
-        ConsoleFormat::Json => (
-            None,
-            Some(
-                fmt::layer()
-                    .json()
-                    .with_writer(nb_stderr)
-                    .with_ansi(false)
-                    .with_target(true)
-                    .with_level(true)
-                    .with_timer(fmt::time::UtcTime::rfc_3339())
-                    .with_filter(console_targets.clone()),
-            ),
-        ),
-    };
+    // Console fmt layers: one per format in use (text, compact, JSON), each
+    // filtered down to the subsystems configured for that format.
+    let ansi = stderr_supports_ansi();
+    let console = console_layers
+        .iter()
+        .map(|(format, targets)| {
+            console_layer(*format, nb_stderr.clone(), ansi)
+                .with_filter(targets.clone())
+                .boxed()
+        })
+        .collect::<Vec<_>>();
 
     // File fmt layer (JSON) if router is not empty
     let file_layer_opt = if file_router.is_empty() {
@@ -453,7 +520,7 @@ fn install_subscriber(
     // 1) OTEL first (because your OtelLayer is bound to `Registry`);
     //    also filter OTEL by the SAME console targets from YAML.
     // 2) Then EnvFilter (caps console/file if RUST_LOG is set).
-    // 3) Then console (text or json) + file fmt layers.
+    // 3) Then console (per-format) + file fmt layers.
     let subscriber = {
         let base = Registry::default();
 
@@ -466,9 +533,7 @@ fn install_subscriber(
         let base = base;
 
         let base = base.with(env);
-        base.with(console_text)
-            .with(console_json)
-            .with(file_layer_opt)
+        base.with(console).with(file_layer_opt)
     };
 
     if let Err(e) = tracing::subscriber::set_global_default(subscriber) {
@@ -654,6 +719,7 @@ mod tests {
 
         let broad_section = Section {
             console_format: ConsoleFormat::default(),
+            format: LogFormat::default(),
             console_level: None,
             section_file: Some(SectionFile {
                 file: "broad.log".to_owned(),
@@ -665,6 +731,7 @@ mod tests {
         };
         let specific_section = Section {
             console_format: ConsoleFormat::default(),
+            format: LogFormat::default(),
             console_level: None,
             section_file: Some(SectionFile {
                 file: "specific.log".to_owned(),
@@ -762,4 +829,111 @@ mod tests {
             "expected write to land in default log, got: {content:?}"
         );
     }
+
+    fn console_section(format: LogFormat) -> Section {
+        Section {
+            console_format: ConsoleFormat::default(),
+            format,
+            console_level: Some(tracing::Level::INFO),
+            section_file: None,
+            max_age_days: None,
+            max_backups: None,
+            max_size_mb: None,
+        }
+    }
+
+    #[test]
+    fn console_targets_route_each_subsystem_to_one_format() {
+        let default = console_section(LogFormat::Pretty);
+        let api = console_section(LogFormat::Json);
+        let api_inner = console_section(LogFormat::Pretty);
+        let config = ConfigData {
+            default_section: Some(&default),
+            crate_sections: vec![
+                ("api".to_owned(), &api),
+                ("api::inner".to_owned(), &api_inner),
+            ],
+        };
+
+        let text = build_target_console_for_format(&config, LogFormat::Pretty)
+            .expect("default format is always in use");
+        let json =
+            build_target_console_for_format(&config, LogFormat::Json).expect("api uses json");
+        assert!(build_target_console_for_format(&config, LogFormat::Compact).is_none());
+
+        assert!(text.would_enable("other", &tracing::Level::INFO));
+        assert!(!json.would_enable("other", &tracing::Level::INFO));
+
+        assert!(json.would_enable("api::handler", &tracing::Level::INFO));
+        assert!(!text.would_enable("api::handler", &tracing::Level::INFO));
+
+        // `pretty` on a nested section follows the default format.
+        assert!(text.would_enable("api::inner", &tracing::Level::INFO));
+        assert!(!json.would_enable("api::inner", &tracing::Level::INFO));
+    }
+
+    #[test]
+    fn legacy_console_format_json_still_selects_json() {
+        let mut default = console_section(LogFormat::Pretty);
+        default.console_format = ConsoleFormat::Json;
+        let config = ConfigData {
+            default_section: Some(&default),
+            crate_sections: vec![],
+        };
+
+        assert_eq!(default_console_format(&config), LogFormat::Json);
+        assert!(build_target_console_for_format(&config, LogFormat::Pretty).is_none());
+    }
+
+    #[derive(Clone, Default)]
+    struct BufWriter(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for BufWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> fmt::MakeWriter<'a> for BufWriter {
+        type Writer = Self;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    #[test]
+    fn json_console_line_is_valid_json_with_span_context() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let buf = BufWriter::default();
+        let subscriber = tracing_subscriber::Registry::default().with(console_layer(
+            LogFormat::Json,
+            buf.clone(),
+            false,
+        ));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("request", request_id = 42);
+            let _entered = span.enter();
+            tracing::info!(target: "api::handler", user = "alice", "handled");
+        });
+
+        let out = String::from_utf8(buf.0.lock().clone()).unwrap();
+        let line = out.lines().next().expect("one log line");
+        let value: serde_json::Value = serde_json::from_str(line).expect("line is valid JSON");
+
+        assert_eq!(value["target"], "api::handler");
+        assert_eq!(value["level"], "INFO");
+        assert_eq!(value["fields"]["message"], "handled");
+        assert_eq!(value["fields"]["user"], "alice");
+        assert_eq!(value["span"]["name"], "request");
+        assert_eq!(value["span"]["request_id"], 42);
+        assert_eq!(value["spans"][0]["name"], "request");
+    }
 }
@@ -23,10 +23,12 @@ pub mod oop;
 
 // Re-export commonly used config types at crate root for convenience
 pub use config::{
-    AppConfig, CliArgs, ConsoleFormat, LoggingConfig, MODKIT_MODULE_CONFIG_ENV, ModuleConfig,
-    ModuleRuntime, RenderedModuleConfig, RuntimeKind, Section, ServerConfig, VendorConfig,
-    VendorConfigError, dump_effective_modules_config_json, dump_effective_modules_config_yaml,
-    list_module_names, render_effective_modules_config,
+    AppConfig, CliArgs, ConsoleFormat, HostRuntimeConfig, LogFormat, LoggingConfig,
+    MODKIT_MODULE_CONFIG_ENV, ModuleConfig, ModuleRuntime, RENDERED_MODULE_CONFIG_VERSION,
+    RenderedModuleConfig, RuntimeKind, Section, ServerConfig, VendorConfig, VendorConfigError,
+    dump_effective_module_config_json, dump_effective_module_config_yaml,
+    dump_effective_modules_config_json, dump_effective_modules_config_yaml, list_module_names,
+    render_effective_module_config, render_effective_modules_config,
 };
 
 // Re-export host types for convenience
@@ -47,7 +47,6 @@ use figment::{Figment, providers::Serialized};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::time::sleep;
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
@@ -58,8 +57,8 @@ use super::config::{
 };
 use crate::bootstrap::host::{init_logging_unified, init_panic_tracing};
 use crate::runtime::{
-    ClientRegistration, DbOptions, MODKIT_DIRECTORY_ENDPOINT_ENV, RunOptions, ShutdownOptions, run,
-    shutdown,
+    ClientRegistration, DbOptions, MODKIT_DIRECTORY_ENDPOINT_ENV, RunMode, RunOptions,
+    ShutdownOptions, run, shutdown,
 };
 use cf_system_sdks::directory::{DirectoryClient, DirectoryGrpcClient};
 
@@ -84,10 +83,13 @@ pub struct OopRunOptions {
     /// Print effective configuration and exit
     pub print_config: bool,
 
-    /// Heartbeat interval in seconds (default: 5)
+    /// Heartbeat interval in seconds (default: 5, minimum: 1)
     pub heartbeat_interval_secs: u64,
 }
 
+/// Missed heartbeat intervals after which the directory evicts an `OoP` instance.
+const HEARTBEAT_TTL_INTERVALS: u32 = 3;
+
 impl Default for OopRunOptions {
     fn default() -> Self {
         // Check for config path in environment variable as fallback
@@ -155,6 +157,10 @@ fn build_oop_config_and_db(
             // If local has "config" section, it takes precedence (local overrides master)
             if !obj.contains_key("config") || obj["config"].is_null() {
                 obj.insert("config".to_owned(), rendered.config.clone());
+                if rendered.expand_env {
+                    obj.entry("expand_env")
+                        .or_insert(serde_json::Value::Bool(true));
+                }
             }
             // If local has "config", it already overrides - no action needed
         }
@@ -351,7 +357,8 @@ fn merge_json_objects(target: &mut serde_json::Value, source: &serde_json::Value
 /// 3. Loads configuration and initializes logging
 /// 4. Connects to the `DirectoryService`
 /// 5. Registers the module instance
-/// 6. Starts a background heartbeat loop (using a child token)
+/// 6. Starts a background heartbeat loop (using a child token); the instance is
+///    registered with a TTL so the directory evicts it if heartbeats stop
 /// 7. Runs the module lifecycle with `ShutdownOptions::Token`
 /// 8. Deregisters from `DirectoryService` on shutdown
 ///
@@ -446,10 +453,14 @@ pub async fn run_oop_with_options(opts: OopRunOptions) -> Result<()> {
     config.apply_cli_overrides(args.verbose);
 
     // Try to read rendered module config from master host via env var BEFORE logging init
-    // so we can use the tracing config from master for OTEL
-    let rendered_config = match std::env::var(MODKIT_MODULE_CONFIG_ENV) {
-        Ok(json) => RenderedModuleConfig::from_json(&json).ok(),
-        Err(_) => None,
+    // so we can use the tracing config from master for OTEL.
+    // An unreadable payload (e.g. host/module version skew) is reported once logging is up.
+    let (rendered_config, rendered_error) = match std::env::var(MODKIT_MODULE_CONFIG_ENV) {
+        Ok(json) => match RenderedModuleConfig::from_json(&json) {
+            Ok(rc) => (Some(rc), None),
+            Err(e) => (None, Some(e)),
+        },
+        Err(_) => (None, None),
     };
 
     // Build final config by merging:
@@ -505,9 +516,10 @@ pub async fn run_oop_with_options(opts: OopRunOptions) -> Result<()> {
             has_opentelemetry = rc.opentelemetry.is_some(),
             "Received rendered config from master host"
         );
-    } else if std::env::var(MODKIT_MODULE_CONFIG_ENV).is_ok() {
+    } else if let Some(e) = rendered_error {
         warn!(
             env_var = MODKIT_MODULE_CONFIG_ENV,
+            error = format!("{e:#}"),
             "Failed to parse rendered config from master host, using local config only"
         );
     } else {
@@ -535,47 +547,37 @@ pub async fn run_oop_with_options(opts: OopRunOptions) -> Result<()> {
         "Connecting to directory service at {}",
         opts.directory_endpoint
     );
-    let directory_client = DirectoryGrpcClient::connect(&opts.directory_endpoint).await?;
-    let directory_api: Arc<dyn DirectoryClient> = Arc::new(directory_client);
+    // Registrations carry a TTL of a few heartbeat intervals, so the directory
+    // evicts this instance if the process dies without deregistering.
+    if opts.heartbeat_interval_secs == 0 {
+        warn!("heartbeat_interval_secs is 0, using 1 second");
+    }
+    let heartbeat_interval_secs = opts.heartbeat_interval_secs.max(1);
+    let heartbeat_interval = Duration::from_secs(heartbeat_interval_secs);
+    let directory_client = DirectoryGrpcClient::connect(&opts.directory_endpoint)
+        .await?
+        .with_instance_ttl(heartbeat_interval * HEARTBEAT_TTL_INTERVALS);
 
     info!("Successfully connected to directory service");
 
-    // Start heartbeat loop in background using a child token from the root.
+    // Start heartbeat loop in background, stopped by a child token from the root.
     // This allows the heartbeat to be cancelled when the root token is cancelled.
-    let heartbeat_directory = Arc::clone(&directory_api);
-    let heartbeat_module = opts.module_name.clone();
-    let heartbeat_instance_id_str = instance_id.to_string();
-    let heartbeat_interval = Duration::from_secs(opts.heartbeat_interval_secs);
+    info!(
+        interval_secs = heartbeat_interval_secs,
+        "Starting heartbeat loop"
+    );
+    let heartbeat = directory_client.spawn_heartbeat(
+        opts.module_name.clone(),
+        instance_id.to_string(),
+        heartbeat_interval,
+    );
     let heartbeat_cancel = cancel.child_token();
-
     tokio::spawn(async move {
-        info!(
-            interval_secs = opts.heartbeat_interval_secs,
-            "Starting heartbeat loop"
-        );
-
-        loop {
-            tokio::select! {
-                () = heartbeat_cancel.cancelled() => {
-                    info!("Heartbeat loop stopping due to cancellation");
-                    break;
-                }
-                () = sleep(heartbeat_interval) => {
-                    match heartbeat_directory
-                        .send_heartbeat(&heartbeat_module, &heartbeat_instance_id_str)
-                        .await
-                    {
-                        Ok(()) => {
-                            tracing::debug!("Heartbeat sent successfully");
-                        }
-                        Err(e) => {
-                            warn!(error = %e, "Failed to send heartbeat, will retry");
-                        }
-                    }
-                }
-            }
-        }
+        heartbeat_cancel.cancelled().await;
+        info!("Heartbeat loop stopping due to cancellation");
+        heartbeat.abort();
     });
+    let directory_api: Arc<dyn DirectoryClient> = Arc::new(directory_client);
 
     // Build config provider for modules
     let config_provider = Arc::new(final_config);
@@ -595,6 +597,9 @@ pub async fn run_oop_with_options(opts: OopRunOptions) -> Result<()> {
         instance_id,
         oop: None, // OoP modules don't spawn other OoP modules
         shutdown_deadline: None,
+        stop_timeout: None,
+        parallel_init: false,
+        mode: RunMode::Full,
     };
 
     let result = run(run_options).await;
@@ -7,8 +7,9 @@
 
 use super::*;
 use crate::bootstrap::config::{
-    AppConfig, ConsoleFormat, GlobalDatabaseConfig, LoggingConfig, RenderedDbConfig,
-    RenderedModuleConfig, Section, SectionFile, ServerConfig, default_logging_config,
+    AppConfig, ConsoleFormat, GlobalDatabaseConfig, LogFormat, LoggingConfig,
+    RENDERED_MODULE_CONFIG_VERSION, RenderedDbConfig, RenderedModuleConfig, Section, SectionFile,
+    ServerConfig, default_logging_config, render_module_config_for_oop,
 };
 use modkit_db::{DbConnConfig, PoolCfg};
 use std::collections::HashMap;
@@ -36,6 +37,7 @@ fn logging_section(console_level: Option<Level>, file: &str) -> Section {
             file_level: Some(Level::DEBUG),
         }),
         console_format: ConsoleFormat::default(),
+        format: LogFormat::default(),
         max_age_days: Some(7),
         max_backups: Some(3),
         max_size_mb: Some(100),
@@ -333,11 +335,13 @@ mod database_merge {
             DbConnConfig {
                 engine: Some(modkit_db::config::DbEngineCfg::Sqlite),
                 server: None,
+                replicas: None,
                 dsn: None,
                 host: None,
                 port: None,
                 user: None,
                 password: None,
+                password_file: None,
                 dbname: None,
                 file: None,
                 path: None,
@@ -362,11 +366,13 @@ mod database_merge {
         DbConnConfig {
             engine: Some(modkit_db::config::DbEngineCfg::Sqlite),
             server: Some("sqlite_main".to_owned()),
+            replicas: None,
             dsn: None,
             host: None,
             port: None,
             user: None,
             password: None,
+            password_file: None,
             dbname: None,
             file: Some("module.db".to_owned()),
             path: None,
@@ -541,11 +547,13 @@ mod database_merge {
             DbConnConfig {
                 engine: Some(modkit_db::config::DbEngineCfg::Sqlite),
                 server: None,
+                replicas: None,
                 dsn: Some("sqlite://new.db".to_owned()),
                 host: None,
                 port: None,
                 user: None,
                 password: None,
+                password_file: None,
                 dbname: None,
                 file: None,
                 path: None,
@@ -618,8 +626,10 @@ mod full_oop_config {
         let local_config = minimal_app_config();
 
         let rendered = RenderedModuleConfig {
+            version: RENDERED_MODULE_CONFIG_VERSION,
             database: None,
             config: json!({"master_setting": "value"}),
+            expand_env: false,
             logging: Some(
                 [(
                     "default".to_owned(),
@@ -660,11 +670,13 @@ mod full_oop_config {
         );
 
         let rendered = RenderedModuleConfig {
+            version: RENDERED_MODULE_CONFIG_VERSION,
             database: None,
             config: json!({
                 "master_setting": "master_value",
                 "another": "setting"
             }),
+            expand_env: false,
             logging: None,
             opentelemetry: None,
         };
@@ -698,8 +710,10 @@ mod full_oop_config {
         .into();
 
         let rendered = RenderedModuleConfig {
+            version: RENDERED_MODULE_CONFIG_VERSION,
             database: None,
             config: json!({}),
+            expand_env: false,
             logging: Some(
                 [
                     (
@@ -759,8 +773,10 @@ mod full_oop_config {
         );
 
         let rendered = RenderedModuleConfig {
+            version: RENDERED_MODULE_CONFIG_VERSION,
             database: None,
             config: json!({"master_setting": "value"}),
+            expand_env: false,
             logging: None,
             opentelemetry: None,
         };
@@ -787,8 +803,10 @@ mod full_oop_config {
         );
 
         let rendered = RenderedModuleConfig {
+            version: RENDERED_MODULE_CONFIG_VERSION,
             database: None,
             config: json!({"master_setting": "value"}),
+            expand_env: false,
             logging: None,
             opentelemetry: None,
         };
@@ -803,3 +821,65 @@ mod full_oop_config {
         assert_eq!(module_config["config"]["master_setting"], "value");
     }
 }
+
+// =============================================================================
+// RenderedModuleConfig versioning
+// =============================================================================
+mod rendered_config_version {
+    use super::*;
+    use serde_json::json;
+
+    fn rendered_json(version: Option<u32>) -> String {
+        let mut value = json!({ "config": { "setting": "value" } });
+        if let Some(version) = version {
+            value["version"] = json!(version);
+        }
+        value.to_string()
+    }
+
+    #[test]
+    fn test_render_sets_current_version() {
+        let app = minimal_app_config();
+        let rendered = render_module_config_for_oop(&app, "test_module", Path::new(".")).unwrap();
+        assert_eq!(rendered.version, RENDERED_MODULE_CONFIG_VERSION);
+
+        let roundtrip = RenderedModuleConfig::from_json(&rendered.to_json().unwrap()).unwrap();
+        assert_eq!(roundtrip.version, RENDERED_MODULE_CONFIG_VERSION);
+    }
+
+    #[test]
+    fn test_from_json_accepts_matching_version() {
+        let rendered =
+            RenderedModuleConfig::from_json(&rendered_json(Some(RENDERED_MODULE_CONFIG_VERSION)))
+                .unwrap();
+        assert_eq!(rendered.version, RENDERED_MODULE_CONFIG_VERSION);
+        assert_eq!(rendered.config["setting"], "value");
+    }
+
+    #[test]
+    fn test_from_json_accepts_older_and_unversioned() {
+        let unversioned = RenderedModuleConfig::from_json(&rendered_json(None)).unwrap();
+        assert_eq!(unversioned.version, 0);
+        assert_eq!(unversioned.config["setting"], "value");
+
+        let older = RenderedModuleConfig::from_json(&rendered_json(Some(0))).unwrap();
+        assert_eq!(older.version, 0);
+    }
+
+    #[test]
+    fn test_from_json_rejects_newer_version() {
+        let newer = RENDERED_MODULE_CONFIG_VERSION + 1;
+        // Newer payloads may carry fields this version cannot parse; the version
+        // check must run first.
+        let json = json!({ "version": newer, "config": {}, "database": "new-format" }).to_string();
+
+        let err = RenderedModuleConfig::from_json(&json).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "RenderedModuleConfig version {newer} is newer than supported version \
+                 {RENDERED_MODULE_CONFIG_VERSION}; upgrade the module to match the host"
+            )
+        );
+    }
+}
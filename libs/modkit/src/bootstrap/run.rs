@@ -1,9 +1,11 @@
 use super::config::{get_module_runtime_config, render_module_config_for_oop};
 use super::host::{init_logging_unified, init_panic_tracing, normalize_path};
 use super::{AppConfig, RuntimeKind};
-use crate::backends::LocalProcessBackend;
+use crate::backends::{ContainerConfig, DockerBackend, LocalProcessBackend, OopBackend};
+use crate::config::ConfigProvider;
 use crate::runtime::{
-    DbOptions, OopModuleSpawnConfig, OopSpawnOptions, RunOptions, ShutdownOptions, run, shutdown,
+    DEFAULT_GRPC_HUB_POLL_INTERVAL, DEFAULT_GRPC_HUB_WAIT_TIMEOUT, DbOptions, OopModuleSpawnConfig,
+    OopSpawnOptions, RunMode, RunOptions, ShutdownOptions, run, shutdown,
 };
 use anyhow::Result;
 use figment::Figment;
@@ -70,11 +72,13 @@ pub async fn run_server(config: AppConfig) -> Result<()> {
     let oop_backend = LocalProcessBackend::new(cancel.clone());
 
     // Build OoP spawn configuration
-    let oop_options = build_oop_spawn_options(&config, oop_backend)?;
+    let oop_options = build_oop_spawn_options(&config, oop_backend, &cancel)?;
 
     // Run the ModKit runtime with the root cancellation token.
     // Shutdown is driven by the signal handler spawned above, not by ShutdownOptions::Signals.
     // OoP modules are spawned after the start phase (once grpc-hub has bound its port).
+    let parallel_init = config.runtime.parallel_init;
+    let stop_timeout = config.runtime.stop_timeout;
     let run_options = RunOptions {
         modules_cfg: Arc::new(config),
         db: db_options,
@@ -83,6 +87,9 @@ pub async fn run_server(config: AppConfig) -> Result<()> {
         instance_id,
         oop: oop_options,
         shutdown_deadline: None,
+        stop_timeout,
+        parallel_init,
+        mode: RunMode::Full,
     };
 
     let result = run(run_options).await;
@@ -139,7 +146,9 @@ pub async fn run_migrate(config: AppConfig) -> Result<()> {
     }
 
     // Discover and build the module registry
-    let registry = crate::registry::ModuleRegistry::discover_and_build()?;
+    let registry = crate::registry::ModuleRegistry::discover_and_build_with(|name| {
+        config.is_module_enabled(name)
+    })?;
     tracing::info!(
         module_count = registry.modules().len(),
         "Discovered modules for migration"
@@ -186,16 +195,21 @@ fn resolve_db_options(config: &AppConfig) -> Result<DbOptions> {
 
 /// Build `OoP` spawn configuration from `AppConfig`.
 ///
-/// This collects all modules with `type=oop` and prepares their spawn configuration.
+/// This collects all modules with `type=oop` or `type=docker` and prepares their spawn
+/// configuration. A `DockerBackend` is created only when a `docker` module is present.
 /// The actual spawning happens in the `HostRuntime` after the start phase.
 fn build_oop_spawn_options(
     config: &AppConfig,
     backend: LocalProcessBackend,
+    cancel: &CancellationToken,
 ) -> Result<Option<OopSpawnOptions>> {
     let home_dir = PathBuf::from(&config.server.home_dir);
     let mut modules = Vec::new();
 
     for module_name in config.modules.keys() {
+        if !config.is_module_enabled(module_name) {
+            continue;
+        }
         if let Some(spawn_config) = try_build_oop_module_config(config, module_name, &home_dir)? {
             modules.push(spawn_config);
         }
@@ -205,14 +219,21 @@ fn build_oop_spawn_options(
         Ok(None)
     } else {
         tracing::info!(count = modules.len(), "Prepared OoP modules for spawning");
+        let container_backend = modules
+            .iter()
+            .any(|m| m.container.is_some())
+            .then(|| Box::new(DockerBackend::new(cancel.clone())) as Box<dyn OopBackend>);
         Ok(Some(OopSpawnOptions {
             modules,
             backend: Box::new(backend),
+            container_backend,
+            hub_wait_timeout: DEFAULT_GRPC_HUB_WAIT_TIMEOUT,
+            hub_poll_interval: DEFAULT_GRPC_HUB_POLL_INTERVAL,
         }))
     }
 }
 
-/// Try to build `OoP` module spawn config if module is of type `OoP`
+/// Try to build `OoP` module spawn config if module is of type `OoP` or `Docker`
 fn try_build_oop_module_config(
     config: &AppConfig,
     module_name: &str,
@@ -222,8 +243,31 @@ fn try_build_oop_module_config(
         return Ok(None);
     };
 
-    if !matches!(runtime_cfg.mod_type, RuntimeKind::Oop) {
-        return Ok(None);
+    match runtime_cfg.mod_type {
+        RuntimeKind::Local => return Ok(None),
+        RuntimeKind::Oop => {}
+        RuntimeKind::Docker => {
+            let docker_cfg = runtime_cfg.docker.as_ref().ok_or_else(|| {
+                anyhow::anyhow!(
+                    "module '{module_name}' is type=docker but docker config is missing"
+                )
+            })?;
+            let rendered_config = render_module_config_for_oop(config, module_name, home_dir)?;
+            return Ok(Some(OopModuleSpawnConfig {
+                module_name: module_name.to_owned(),
+                binary: PathBuf::new(),
+                args: docker_cfg.args.clone(),
+                env: docker_cfg.environment.clone(),
+                working_directory: None,
+                rendered_config_json: rendered_config.to_json()?,
+                restart_policy: docker_cfg.restart_policy,
+                container: Some(ContainerConfig {
+                    image: docker_cfg.image.clone(),
+                    ports: docker_cfg.ports.clone(),
+                    volumes: docker_cfg.volumes.clone(),
+                }),
+            }));
+        }
     }
 
     let exec_cfg = runtime_cfg.execution.as_ref().ok_or_else(|| {
@@ -251,6 +295,8 @@ fn try_build_oop_module_config(
         env,
         working_directory: exec_cfg.working_directory.clone(),
         rendered_config_json: rendered_json,
+        restart_policy: exec_cfg.restart_policy,
+        container: None,
     }))
 }
 
@@ -329,3 +375,63 @@ pub fn tracing_shutdown() {
     crate::telemetry::init::shutdown_metrics();
     crate::telemetry::init::shutdown_tracing();
 }
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::backends::RestartPolicy;
+
+    #[test]
+    fn docker_module_builds_container_spawn_config() {
+        let mut config = AppConfig::default();
+        config.modules.insert(
+            "calculator".to_owned(),
+            serde_json::json!({
+                "runtime": {
+                    "type": "docker",
+                    "docker": {
+                        "image": "ghcr.io/acme/calculator:1.2",
+                        "args": ["--port", "8080"],
+                        "ports": ["8080:8080"],
+                        "volumes": ["/srv/calc:/data:ro"],
+                        "environment": { "RUST_LOG": "debug" },
+                        "restart_policy": "always"
+                    }
+                },
+                "config": { "precision": 4 }
+            }),
+        );
+
+        let spawn = try_build_oop_module_config(&config, "calculator", Path::new("."))
+            .unwrap()
+            .expect("docker module is spawned out of process");
+
+        assert_eq!(spawn.args, ["--port", "8080"]);
+        assert_eq!(spawn.env["RUST_LOG"], "debug");
+        assert!(spawn.rendered_config_json.contains("precision"));
+        assert_eq!(spawn.restart_policy, RestartPolicy::Always);
+        assert_eq!(
+            spawn.container,
+            Some(ContainerConfig {
+                image: "ghcr.io/acme/calculator:1.2".to_owned(),
+                ports: vec!["8080:8080".to_owned()],
+                volumes: vec!["/srv/calc:/data:ro".to_owned()],
+            })
+        );
+    }
+
+    #[test]
+    fn docker_module_without_docker_section_fails() {
+        let mut config = AppConfig::default();
+        config.modules.insert(
+            "calculator".to_owned(),
+            serde_json::json!({ "runtime": { "type": "docker" } }),
+        );
+
+        let Err(err) = try_build_oop_module_config(&config, "calculator", Path::new(".")) else {
+            panic!("docker module without docker section must be rejected");
+        };
+        assert!(err.to_string().contains("docker config is missing"));
+    }
+}
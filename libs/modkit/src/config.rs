@@ -9,6 +9,11 @@
 //! 2. **Strict loading**: Requires configuration to be present and valid.
 //!    - Used by `module_config_required`
 //!    - Returns errors when configuration is missing or invalid
+//!
+//! Both loaders expand `${VAR}` placeholders in every string of the `config` section
+//! when the module entry opts in with `expand_env: true`. Expansion happens on the
+//! copy handed to the module, so the loaded `AppConfig` (and the config dump) keeps
+//! the placeholders.
 
 use serde::de::DeserializeOwned;
 
@@ -39,8 +44,39 @@ pub enum ConfigError {
 pub trait ConfigProvider: Send + Sync {
     /// Returns raw JSON section for the module, if any.
     fn get_module_config(&self, module_name: &str) -> Option<&serde_json::Value>;
+
+    /// Whether `modules.<name>.enabled` lets the module run; a missing flag means enabled.
+    fn is_module_enabled(&self, module_name: &str) -> bool {
+        self.get_module_config(module_name)
+            .and_then(|m| m.get("enabled"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(true)
+    }
+}
+
+/// JSON Schema for a module's `modules.<name>.config` section.
+///
+/// Submitted through `inventory`; the bootstrap validates the section against it
+/// while loading the app config, so typos and wrong types are reported with the
+/// exact config path instead of surfacing later as a serde error.
+///
+/// ```ignore
+/// modkit::inventory::submit! {
+///     modkit::config::ModuleConfigSchema {
+///         module: "billing",
+///         schema: || serde_json::to_value(schemars::schema_for!(BillingConfig)).unwrap(),
+///     }
+/// }
+/// ```
+pub struct ModuleConfigSchema {
+    /// Module name, as used under `modules.<name>`.
+    pub module: &'static str,
+    /// Returns the JSON Schema document for the `config` section.
+    pub schema: fn() -> serde_json::Value,
 }
 
+inventory::collect!(ModuleConfigSchema);
+
 /// Lenient configuration loader that falls back to defaults.
 ///
 /// This function provides forgiving behavior for modules that don't require configuration:
@@ -74,10 +110,12 @@ pub fn module_config_or_default<T: DeserializeOwned + Default>(
 
     // Config section exists, try to parse it
     let config: T =
-        serde_json::from_value(config_section.clone()).map_err(|e| ConfigError::InvalidConfig {
-            module: module_name.to_owned(),
-            source: e,
-        })?;
+        serde_json::from_value(module_config_section(obj, config_section, module_name)?).map_err(
+            |e| ConfigError::InvalidConfig {
+                module: module_name.to_owned(),
+                source: e,
+            },
+        )?;
 
     Ok(config)
 }
@@ -119,12 +157,60 @@ pub fn module_config_required<T: DeserializeOwned>(
         })?;
 
     let config: T =
-        serde_json::from_value(config_section.clone()).map_err(|e| ConfigError::InvalidConfig {
+        serde_json::from_value(module_config_section(obj, config_section, module_name)?).map_err(
+            |e| ConfigError::InvalidConfig {
+                module: module_name.to_owned(),
+                source: e,
+            },
+        )?;
+
+    Ok(config)
+}
+
+/// The `config` section handed to the module: a copy of `config_section`, with
+/// `${VAR}` placeholders expanded in every string when the module entry sets
+/// `expand_env: true`.
+fn module_config_section(
+    module: &serde_json::Map<String, serde_json::Value>,
+    config_section: &serde_json::Value,
+    module_name: &str,
+) -> Result<serde_json::Value, ConfigError> {
+    let mut section = config_section.clone();
+    if module
+        .get("expand_env")
+        .and_then(serde_json::Value::as_bool)
+        == Some(true)
+    {
+        expand_env_in_strings(&mut section).map_err(|e| ConfigError::VarExpand {
             module: module_name.to_owned(),
             source: e,
         })?;
+    }
+    Ok(section)
+}
 
-    Ok(config)
+/// Expand `${VAR}`, `${VAR:-default}` and `${VAR-default}` in every string of `value`.
+/// Non-string values are left untouched.
+fn expand_env_in_strings(
+    value: &mut serde_json::Value,
+) -> Result<(), modkit_utils::var_expand::ExpandVarsError> {
+    match value {
+        serde_json::Value::String(s) if s.contains("${") => {
+            *s = modkit_utils::var_expand::expand_env_vars_shell(s)?;
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                expand_env_in_strings(item)?;
+            }
+        }
+        serde_json::Value::Object(map) => {
+            for item in map.values_mut() {
+                expand_env_in_strings(item)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -362,6 +448,86 @@ mod tests {
         }
     }
 
+    // ========== Tests for opt-in env expansion ==========
+
+    #[derive(Debug, PartialEq, Deserialize, Default)]
+    struct UpstreamConfig {
+        #[serde(default)]
+        url: String,
+        #[serde(default)]
+        tags: Vec<String>,
+        #[serde(default)]
+        retries: u32,
+    }
+
+    fn provider_with(module: serde_json::Value) -> MockConfigProvider {
+        MockConfigProvider {
+            modules: HashMap::from([("billing".to_owned(), module)]),
+        }
+    }
+
+    #[test]
+    fn test_expand_env_expands_nested_strings_when_enabled() {
+        let provider = provider_with(json!({
+            "expand_env": true,
+            "config": {
+                "url": "https://${MOD_CFG_ENV_HOST}:${MOD_CFG_ENV_PORT:-8443}/api",
+                "tags": ["${MOD_CFG_ENV_HOST}", "static"],
+                "retries": 3
+            }
+        }));
+
+        temp_env::with_vars(
+            [
+                ("MOD_CFG_ENV_HOST", Some("billing.internal")),
+                ("MOD_CFG_ENV_PORT", None::<&str>),
+            ],
+            || {
+                for config in [
+                    module_config_or_default::<UpstreamConfig>(&provider, "billing").unwrap(),
+                    module_config_required::<UpstreamConfig>(&provider, "billing").unwrap(),
+                ] {
+                    assert_eq!(config.url, "https://billing.internal:8443/api");
+                    assert_eq!(config.tags, ["billing.internal", "static"]);
+                    assert_eq!(config.retries, 3);
+                }
+            },
+        );
+        // The provider itself still holds the placeholders.
+        assert_eq!(
+            provider.modules["billing"]["config"]["tags"][0],
+            "${MOD_CFG_ENV_HOST}"
+        );
+    }
+
+    #[test]
+    fn test_expand_env_is_opt_in() {
+        let provider = provider_with(json!({
+            "config": { "url": "https://${MOD_CFG_ENV_UNSET}/api" }
+        }));
+
+        temp_env::with_var("MOD_CFG_ENV_UNSET", None::<&str>, || {
+            let config: UpstreamConfig = module_config_required(&provider, "billing").unwrap();
+            assert_eq!(config.url, "https://${MOD_CFG_ENV_UNSET}/api");
+        });
+    }
+
+    #[test]
+    fn test_expand_env_reports_missing_var() {
+        let provider = provider_with(json!({
+            "expand_env": true,
+            "config": { "url": "https://${MOD_CFG_ENV_UNSET}/api" }
+        }));
+
+        temp_env::with_var("MOD_CFG_ENV_UNSET", None::<&str>, || {
+            let err = module_config_or_default::<UpstreamConfig>(&provider, "billing").unwrap_err();
+            assert!(
+                matches!(err, ConfigError::VarExpand { ref module, .. } if module == "billing")
+            );
+            assert!(format!("{err:#}").contains("billing"), "{err}");
+        });
+    }
+
     // ========== Tests for ConfigError display messages ==========
 
     #[test]
@@ -2,28 +2,108 @@
 
 use anyhow::Result;
 use async_trait::async_trait;
+use parking_lot::Mutex;
+use std::collections::HashMap;
 use std::sync::Arc;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::time::Instant;
 use uuid::Uuid;
 
 use crate::runtime::{Endpoint, ModuleInstance, ModuleManager};
 
 // Re-export all types from contracts - this is the single source of truth
 pub use cf_system_sdks::directory::{
-    DirectoryClient, RegisterInstanceInfo, ServiceEndpoint, ServiceInstanceInfo,
+    DEFAULT_INSTANCE_WEIGHT, DirectoryClient, InstanceStream, RegisterInstanceInfo,
+    ServiceEndpoint, ServiceInstanceInfo,
 };
 
 /// Local implementation of `DirectoryClient` that delegates to `ModuleManager`
 ///
 /// This is the in-process implementation used by modules running in the same
 /// process as the module orchestrator.
+///
+/// Registrations are remembered, so a heartbeat for an instance the manager no
+/// longer knows (e.g. evicted after a missed TTL) registers it again.
 pub struct LocalDirectoryClient {
     mgr: Arc<ModuleManager>,
+    registrations: Mutex<HashMap<(String, Uuid), RegisterInstanceInfo>>,
 }
 
 impl LocalDirectoryClient {
     #[must_use]
     pub fn new(mgr: Arc<ModuleManager>) -> Self {
-        Self { mgr }
+        Self {
+            mgr,
+            registrations: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+fn parse_instance_id(instance_id: &str) -> Result<Uuid> {
+    Uuid::parse_str(instance_id)
+        .map_err(|e| anyhow::anyhow!("Invalid instance_id '{instance_id}': {e}"))
+}
+
+/// Build a `ModuleInstance` from a registration request.
+fn module_instance(info: RegisterInstanceInfo, instance_id: Uuid) -> ModuleInstance {
+    let mut instance = ModuleInstance::new(info.module, instance_id);
+
+    // Apply version if provided
+    if let Some(version) = info.version {
+        instance = instance.with_version(version);
+    }
+
+    if let Some(ttl) = info.ttl {
+        instance = instance.with_heartbeat_ttl(ttl);
+    }
+
+    if let Some(weight) = info.weight {
+        instance = instance.with_weight(weight);
+    }
+
+    for (name, endpoint) in info.endpoints {
+        instance = instance.with_endpoint(name, Endpoint::from_uri(endpoint.uri));
+    }
+
+    // Add all gRPC services
+    for (service_name, endpoint) in info.grpc_services {
+        instance = instance.with_grpc_service(service_name, Endpoint::from_uri(endpoint.uri));
+    }
+
+    instance
+}
+
+/// Every instance of `module`. The primary endpoint is a gRPC service endpoint,
+/// else the named `"grpc"` endpoint, else empty (the instance serves no gRPC).
+fn instances_info(mgr: &ModuleManager, module: &str) -> Vec<ServiceInstanceInfo> {
+    let now = Instant::now();
+    mgr.instances_of(module)
+        .iter()
+        .map(|inst| {
+            let primary = inst
+                .grpc_services
+                .values()
+                .next()
+                .or_else(|| inst.endpoint("grpc"))
+                .map_or("", |ep| ep.uri.as_str());
+            instance_info(inst, primary, now)
+        })
+        .collect()
+}
+
+fn instance_info(inst: &ModuleInstance, primary_uri: &str, now: Instant) -> ServiceInstanceInfo {
+    ServiceInstanceInfo {
+        module: inst.module.clone(),
+        instance_id: inst.instance_id.to_string(),
+        endpoint: ServiceEndpoint::new(primary_uri),
+        version: inst.version.clone(),
+        weight: inst.weight,
+        healthy: inst.is_available(now),
+        endpoints: inst
+            .endpoints
+            .iter()
+            .map(|(name, ep)| (name.clone(), ServiceEndpoint::new(ep.uri.clone())))
+            .collect(),
     }
 }
 
@@ -38,60 +118,104 @@ impl DirectoryClient for LocalDirectoryClient {
     }
 
     async fn list_instances(&self, module: &str) -> Result<Vec<ServiceInstanceInfo>> {
-        let mut result = Vec::new();
-
-        for inst in self.mgr.instances_of(module) {
-            if let Some((_, ep)) = inst.grpc_services.iter().next() {
-                result.push(ServiceInstanceInfo {
-                    module: module.to_owned(),
-                    instance_id: inst.instance_id.to_string(),
-                    endpoint: ServiceEndpoint::new(ep.uri.clone()),
-                    version: inst.version.clone(),
-                });
-            }
-        }
+        Ok(instances_info(&self.mgr, module))
+    }
 
-        Ok(result)
+    async fn list_service_instances(&self, service_name: &str) -> Result<Vec<ServiceInstanceInfo>> {
+        let now = Instant::now();
+        Ok(self
+            .mgr
+            .all_instances()
+            .iter()
+            .filter_map(|inst| {
+                let ep = inst.grpc_services.get(service_name)?;
+                Some(instance_info(inst, &ep.uri, now))
+            })
+            .collect())
     }
 
     async fn register_instance(&self, info: RegisterInstanceInfo) -> Result<()> {
-        // Parse instance_id from string to Uuid
-        let instance_id = Uuid::parse_str(&info.instance_id)
-            .map_err(|e| anyhow::anyhow!("Invalid instance_id '{}': {}", info.instance_id, e))?;
-
-        // Build a ModuleInstance from RegisterInstanceInfo
-        let mut instance = ModuleInstance::new(info.module.clone(), instance_id);
-
-        // Apply version if provided
-        if let Some(version) = info.version {
-            instance = instance.with_version(version);
-        }
-
-        // Add all gRPC services
-        for (service_name, endpoint) in info.grpc_services {
-            instance = instance.with_grpc_service(service_name, Endpoint::from_uri(endpoint.uri));
-        }
-
-        // Register the instance with the manager
-        self.mgr.register_instance(Arc::new(instance));
-
+        let instance_id = parse_instance_id(&info.instance_id)?;
+        self.registrations
+            .lock()
+            .insert((info.module.clone(), instance_id), info.clone());
+        let module = info.module.clone();
+        self.mgr
+            .register_instance(Arc::new(module_instance(info, instance_id)));
+        // Registering proves liveness like a heartbeat does, so the instance is
+        // selectable right away. In-process registrations (no TTL) never heartbeat.
+        self.mgr
+            .update_heartbeat(&module, instance_id, Instant::now());
         Ok(())
     }
 
     async fn deregister_instance(&self, module: &str, instance_id: &str) -> Result<()> {
-        let instance_id = Uuid::parse_str(instance_id)
-            .map_err(|e| anyhow::anyhow!("Invalid instance_id '{instance_id}': {e}"))?;
-        self.mgr.deregister(module, instance_id);
+        let instance_id = parse_instance_id(instance_id)?;
+        self.registrations
+            .lock()
+            .remove(&(module.to_owned(), instance_id));
+        // Deregistering an unknown instance is a no-op
+        _ = self.mgr.deregister(module, instance_id);
         Ok(())
     }
 
     async fn send_heartbeat(&self, module: &str, instance_id: &str) -> Result<()> {
-        let instance_id = Uuid::parse_str(instance_id)
-            .map_err(|e| anyhow::anyhow!("Invalid instance_id '{instance_id}': {e}"))?;
+        let instance_id = parse_instance_id(instance_id)?;
+        if !self
+            .mgr
+            .instances_of(module)
+            .iter()
+            .any(|i| i.instance_id == instance_id)
+        {
+            // Re-register an instance we registered before; reject unknown ones
+            // so remote clients replay their registrations (e.g. after a restart).
+            let Some(info) = self
+                .registrations
+                .lock()
+                .get(&(module.to_owned(), instance_id))
+                .cloned()
+            else {
+                anyhow::bail!("Instance not registered: {module}/{instance_id}");
+            };
+            tracing::info!(module, %instance_id, "Re-registering instance on heartbeat");
+            self.mgr
+                .register_instance(Arc::new(module_instance(info, instance_id)));
+        }
         self.mgr
-            .update_heartbeat(module, instance_id, std::time::Instant::now());
+            .update_heartbeat(module, instance_id, Instant::now());
         Ok(())
     }
+
+    async fn watch(&self, module: &str) -> Result<InstanceStream> {
+        // Subscribe before taking the snapshot so no change falls in between
+        let changes = self.mgr.subscribe();
+        let initial = instances_info(&self.mgr, module);
+        let state = (
+            Arc::clone(&self.mgr),
+            module.to_owned(),
+            changes,
+            Some(initial),
+        );
+
+        let stream =
+            futures_util::stream::unfold(state, |(mgr, module, mut changes, initial)| async move {
+                if let Some(list) = initial {
+                    return Some((list, (mgr, module, changes, None)));
+                }
+                loop {
+                    match changes.recv().await {
+                        Ok(name) if name != module => {}
+                        // A lagged receiver may have missed this module's change
+                        Ok(_) | Err(RecvError::Lagged(_)) => {
+                            let list = instances_info(&mgr, &module);
+                            return Some((list, (mgr, module, changes, None)));
+                        }
+                        Err(RecvError::Closed) => return None,
+                    }
+                }
+            });
+        Ok(Box::pin(stream))
+    }
 }
 
 #[cfg(test)]
@@ -108,6 +232,144 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_list_service_instances_reports_weight_and_health() {
+        let dir = Arc::new(ModuleManager::new());
+        let api = LocalDirectoryClient::new(dir.clone());
+
+        let (ready, fresh, expired) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let svc = |inst: ModuleInstance| {
+            Arc::new(
+                inst.with_grpc_service("calc.v1.Calculator", Endpoint::http("127.0.0.1", 50051)),
+            )
+        };
+        dir.register_instance(svc(ModuleInstance::new("calc", ready).with_weight(3)));
+        dir.register_instance(svc(ModuleInstance::new("calc", fresh)));
+        dir.register_instance(svc(
+            ModuleInstance::new("calc", expired).with_heartbeat_ttl(std::time::Duration::ZERO)
+        ));
+        dir.register_instance(Arc::new(ModuleInstance::new("other", Uuid::new_v4())));
+        dir.mark_ready("calc", ready);
+        dir.mark_ready("calc", expired);
+
+        let instances = api
+            .list_service_instances("calc.v1.Calculator")
+            .await
+            .unwrap();
+        assert_eq!(instances.len(), 3);
+
+        let find = |id: Uuid| {
+            instances
+                .iter()
+                .find(|i| i.instance_id == id.to_string())
+                .unwrap()
+        };
+        assert!(find(ready).healthy);
+        assert_eq!(find(ready).weight, 3);
+        assert!(!find(fresh).healthy, "registered but not ready");
+        assert_eq!(find(fresh).weight, DEFAULT_INSTANCE_WEIGHT);
+        assert!(!find(expired).healthy, "heartbeat TTL lapsed");
+    }
+
+    #[tokio::test]
+    async fn test_registered_instance_is_healthy_without_heartbeat() {
+        let dir = Arc::new(ModuleManager::new());
+        let api = LocalDirectoryClient::new(dir.clone());
+
+        // As grpc-hub registers in-process modules: no TTL, never heartbeated
+        api.register_instance(RegisterInstanceInfo {
+            module: "calc".to_owned(),
+            instance_id: Uuid::new_v4().to_string(),
+            grpc_services: vec![(
+                "calc.v1.Calculator".to_owned(),
+                ServiceEndpoint::http("127.0.0.1", 50051),
+            )],
+            version: None,
+            ttl: None,
+            weight: None,
+            endpoints: vec![],
+        })
+        .await
+        .unwrap();
+
+        let instances = api.list_instances("calc").await.unwrap();
+        assert_eq!(instances.len(), 1);
+        assert!(instances[0].healthy);
+    }
+
+    #[tokio::test]
+    async fn test_named_endpoints_roundtrip() {
+        let dir = Arc::new(ModuleManager::new());
+        let api = LocalDirectoryClient::new(dir.clone());
+
+        let instance_id = Uuid::new_v4();
+        api.register_instance(RegisterInstanceInfo {
+            module: "calc".to_owned(),
+            instance_id: instance_id.to_string(),
+            grpc_services: vec![(
+                "calc.v1.Calculator".to_owned(),
+                ServiceEndpoint::http("127.0.0.1", 50051),
+            )],
+            version: None,
+            ttl: None,
+            weight: None,
+            endpoints: vec![
+                ("grpc".to_owned(), ServiceEndpoint::http("127.0.0.1", 50051)),
+                ("http".to_owned(), ServiceEndpoint::http("127.0.0.1", 8080)),
+            ],
+        })
+        .await
+        .unwrap();
+
+        let registered = &dir.instances_of("calc")[0];
+        assert_eq!(
+            registered.endpoint("http").map(|ep| ep.uri.as_str()),
+            Some("http://127.0.0.1:8080")
+        );
+
+        let instances = api.list_instances("calc").await.unwrap();
+        assert_eq!(instances.len(), 1);
+        let info = &instances[0];
+        assert_eq!(info.endpoints.len(), 2);
+        assert_eq!(
+            info.endpoint("grpc"),
+            Some(&ServiceEndpoint::http("127.0.0.1", 50051))
+        );
+        assert_eq!(
+            info.endpoint("http"),
+            Some(&ServiceEndpoint::http("127.0.0.1", 8080))
+        );
+        assert_eq!(info.endpoint("metrics"), None);
+    }
+
+    #[tokio::test]
+    async fn test_list_instances_includes_instances_without_grpc_services() {
+        let dir = Arc::new(ModuleManager::new());
+        let api = LocalDirectoryClient::new(dir.clone());
+
+        api.register_instance(RegisterInstanceInfo {
+            module: "web".to_owned(),
+            instance_id: Uuid::new_v4().to_string(),
+            grpc_services: vec![],
+            version: None,
+            ttl: None,
+            weight: None,
+            endpoints: vec![("http".to_owned(), ServiceEndpoint::http("127.0.0.1", 8080))],
+        })
+        .await
+        .unwrap();
+        dir.register_instance(Arc::new(ModuleInstance::new("web", Uuid::new_v4())));
+
+        let instances = api.list_instances("web").await.unwrap();
+        assert_eq!(instances.len(), 2);
+        assert!(instances.iter().all(|i| i.endpoint.uri.is_empty()));
+        assert!(
+            instances
+                .iter()
+                .any(|i| i.endpoint("http") == Some(&ServiceEndpoint::http("127.0.0.1", 8080)))
+        );
+    }
+
     #[tokio::test]
     async fn test_register_instance_via_api() {
         let dir = Arc::new(ModuleManager::new());
@@ -123,6 +385,9 @@ mod tests {
                 ServiceEndpoint::http("127.0.0.1", 8001),
             )],
             version: Some("1.0.0".to_owned()),
+            ttl: None,
+            weight: None,
+            endpoints: vec![],
         };
 
         api.register_instance(register_info).await.unwrap();
@@ -182,4 +447,75 @@ mod tests {
         let instances = dir.instances_of("test_module");
         assert_eq!(instances[0].state(), InstanceState::Healthy);
     }
+
+    #[tokio::test]
+    async fn test_heartbeat_reregisters_evicted_instance() {
+        let dir = Arc::new(ModuleManager::new());
+        let api = LocalDirectoryClient::new(dir.clone());
+        let instance_id = Uuid::new_v4();
+
+        api.register_instance(RegisterInstanceInfo {
+            module: "test_module".to_owned(),
+            instance_id: instance_id.to_string(),
+            grpc_services: vec![(
+                "test.Service".to_owned(),
+                ServiceEndpoint::http("127.0.0.1", 8001),
+            )],
+            version: None,
+            ttl: None,
+            weight: None,
+            endpoints: vec![],
+        })
+        .await
+        .unwrap();
+
+        // The directory forgets the instance, e.g. after a missed TTL
+        assert!(dir.deregister("test_module", instance_id));
+
+        api.send_heartbeat("test_module", &instance_id.to_string())
+            .await
+            .unwrap();
+        let instances = dir.instances_of("test_module");
+        assert_eq!(instances.len(), 1);
+        assert!(instances[0].grpc_services.contains_key("test.Service"));
+
+        // Instances never registered through this client are still rejected
+        assert!(
+            api.send_heartbeat("test_module", &Uuid::new_v4().to_string())
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_watch_pushes_updates_on_registration() {
+        use futures_util::StreamExt;
+
+        let dir = Arc::new(ModuleManager::new());
+        let api = LocalDirectoryClient::new(dir.clone());
+
+        let mut watch = api.watch("test_module").await.unwrap();
+        assert!(
+            watch.next().await.unwrap().is_empty(),
+            "initial item is the current set"
+        );
+
+        // Changes to other modules are not reported
+        dir.register_instance(Arc::new(
+            ModuleInstance::new("other_module", Uuid::new_v4())
+                .with_grpc_service("other.Service", Endpoint::http("127.0.0.1", 8002)),
+        ));
+        let instance_id = Uuid::new_v4();
+        dir.register_instance(Arc::new(
+            ModuleInstance::new("test_module", instance_id)
+                .with_grpc_service("test.Service", Endpoint::http("127.0.0.1", 8001)),
+        ));
+
+        let update = watch.next().await.unwrap();
+        assert_eq!(update.len(), 1);
+        assert_eq!(update[0].instance_id, instance_id.to_string());
+
+        assert!(dir.deregister("test_module", instance_id));
+        assert!(watch.next().await.unwrap().is_empty());
+    }
 }
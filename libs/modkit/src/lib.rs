@@ -138,7 +138,7 @@ pub use domain::{DomainErrorMarker, DomainModel};
 // Directory API for service discovery
 pub mod directory;
 pub use directory::{
-    DirectoryClient, LocalDirectoryClient, RegisterInstanceInfo, ServiceEndpoint,
+    DirectoryClient, InstanceStream, LocalDirectoryClient, RegisterInstanceInfo, ServiceEndpoint,
     ServiceInstanceInfo,
 };
 
@@ -149,14 +149,15 @@ pub mod gts;
 pub use modkit_sdk::{Secured, WithSecurityContext};
 
 pub use backends::{
-    BackendKind, InstanceHandle, LocalProcessBackend, ModuleRuntimeBackend, OopBackend,
-    OopModuleConfig, OopSpawnConfig,
+    BackendKind, ContainerConfig, DockerBackend, InstanceExit, InstanceHandle, LocalProcessBackend,
+    ModuleRuntimeBackend, OopBackend, OopModuleConfig, OopSpawnConfig, RestartPolicy,
 };
 pub use lifecycle::{Lifecycle, Runnable, Status, StopReason, WithLifecycle};
 pub use plugins::GtsPluginSelector;
 pub use runtime::{
-    DEFAULT_SHUTDOWN_DEADLINE, DbOptions, Endpoint, ModuleInstance, ModuleManager,
-    OopModuleSpawnConfig, OopSpawnOptions, RunOptions, ShutdownOptions, run,
+    DEFAULT_SHUTDOWN_DEADLINE, DEFAULT_STOP_TIMEOUT, DbOptions, Endpoint, LifecycleObserver,
+    LifecyclePhase, ModuleInstance, ModuleManager, OopModuleSpawnConfig, OopSpawnOptions,
+    RunOptions, ShutdownOptions, StartupReport, StartupReportRecorder, run,
 };
 
 #[cfg(feature = "bootstrap")]
@@ -1,5 +1,5 @@
 // modkit/src/registry/mod.rs
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 
 use thiserror::Error;
@@ -166,16 +166,27 @@ impl CapabilitySet {
         self.caps.iter().any(|cap| T::try_get(cap).is_some())
     }
 
-    /// Query for a specific capability type.
+    /// Query for a specific capability type (first match in registration order).
     #[must_use]
     pub fn query<T: CapTag>(&self) -> Option<Arc<T::Out>> {
         self.caps.iter().find_map(|cap| T::try_get(cap).cloned())
     }
 
-    /// Returns human-readable capability labels (e.g. `"rest"`, `"db"`, `"system"`).
+    /// Query for every capability of a specific type, in registration order.
     #[must_use]
-    pub fn labels(&self) -> Vec<&'static str> {
+    pub fn query_all<T: CapTag>(&self) -> Vec<Arc<T::Out>> {
         self.caps
+            .iter()
+            .filter_map(|cap| T::try_get(cap).cloned())
+            .collect()
+    }
+
+    /// Returns human-readable capability labels (e.g. `"rest"`, `"db"`, `"system"`),
+    /// sorted and without duplicates so listings are stable across runs.
+    #[must_use]
+    pub fn labels(&self) -> Vec<&'static str> {
+        let mut labels: Vec<&'static str> = self
+            .caps
             .iter()
             .map(|cap| match cap {
                 #[cfg(feature = "db")]
@@ -187,7 +198,10 @@ impl CapabilitySet {
                 Capability::GrpcHub(_) => "grpc_hub",
                 Capability::GrpcService(_) => "grpc",
             })
-            .collect()
+            .collect();
+        labels.sort_unstable();
+        labels.dedup();
+        labels
     }
 
     /// Convenience helper for DB presence.
@@ -212,7 +226,10 @@ impl Default for CapabilitySet {
 
 pub struct ModuleEntry {
     pub(crate) name: &'static str,
-    pub(crate) deps: &'static [&'static str],
+    pub(crate) version: Option<&'static str>,
+    pub(crate) deps: Vec<&'static str>,
+    pub(crate) soft_deps: Vec<&'static str>,
+    pub(crate) version_reqs: HashMap<&'static str, &'static str>,
     pub(crate) core: Arc<dyn contracts::Module>,
     pub(crate) caps: CapabilitySet,
 }
@@ -224,10 +241,29 @@ impl ModuleEntry {
         self.name
     }
 
+    /// Returns the version the module registered, if any.
+    #[must_use]
+    pub fn version(&self) -> Option<&'static str> {
+        self.version
+    }
+
     /// Returns the module dependency names.
     #[must_use]
-    pub fn deps(&self) -> &'static [&'static str] {
-        self.deps
+    pub fn deps(&self) -> &[&'static str] {
+        &self.deps
+    }
+
+    /// Returns the declared soft dependency names, including ones that are not registered.
+    #[must_use]
+    pub fn soft_deps(&self) -> &[&'static str] {
+        &self.soft_deps
+    }
+
+    /// Returns the semver requirement declared on dependency `dep` (the `^1.2`
+    /// of a `"auth@^1.2"` spec), if any. Covers both hard and soft dependencies.
+    #[must_use]
+    pub fn version_req(&self, dep: &str) -> Option<&'static str> {
+        self.version_reqs.get(dep).copied()
     }
 
     /// Returns the capability set.
@@ -241,7 +277,10 @@ impl std::fmt::Debug for ModuleEntry {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ModuleEntry")
             .field("name", &self.name)
+            .field("version", &self.version)
             .field("deps", &self.deps)
+            .field("soft_deps", &self.soft_deps)
+            .field("version_reqs", &self.version_reqs)
             .field("has_rest", &self.caps.has::<RestApiCap>())
             .field("is_rest_host", &self.caps.has::<ApiGatewayCap>())
             .field("has_db", &self.caps.has_db())
@@ -277,6 +316,39 @@ impl std::fmt::Debug for ModuleRegistry {
     }
 }
 
+/// Split a dependency spec (`"auth"` or `"auth@^1.2"`) into the module name and
+/// the optional version requirement.
+fn split_dep(dep: &str) -> (&str, Option<&str>) {
+    match dep.split_once('@') {
+        Some((name, req)) => (name, Some(req)),
+        None => (dep, None),
+    }
+}
+
+/// Group topo-sorted modules into levels; dependencies outside `group` are ignored.
+fn dependency_levels<'a>(group: &[&'a ModuleEntry]) -> Vec<Vec<&'a ModuleEntry>> {
+    let mut level_of: HashMap<&str, usize> = HashMap::new();
+    let mut levels: Vec<Vec<&ModuleEntry>> = Vec::new();
+
+    for &entry in group {
+        let level = entry
+            .deps
+            .iter()
+            .chain(&entry.soft_deps)
+            .filter_map(|dep| level_of.get(dep))
+            .map(|l| l + 1)
+            .max()
+            .unwrap_or(0);
+        level_of.insert(entry.name, level);
+        if levels.len() <= level {
+            levels.resize_with(level + 1, Vec::new);
+        }
+        levels[level].push(entry);
+    }
+
+    levels
+}
+
 impl ModuleRegistry {
     #[must_use]
     pub fn modules(&self) -> &[ModuleEntry] {
@@ -303,25 +375,67 @@ impl ModuleRegistry {
         system_mods
     }
 
+    /// Returns modules grouped into dependency levels for parallel init.
+    ///
+    /// System modules fill the first levels, user modules the following ones.
+    /// Within each group a module's level is one past the highest level of its
+    /// dependencies, so a level only depends on earlier levels and its modules
+    /// can be initialized concurrently.
+    #[must_use]
+    pub fn init_levels(&self) -> Vec<Vec<&ModuleEntry>> {
+        let (system_mods, non_system_mods): (Vec<_>, Vec<_>) = self
+            .modules
+            .iter()
+            .partition(|entry| entry.caps.has::<SystemCap>());
+
+        let mut levels = dependency_levels(&system_mods);
+        levels.extend(dependency_levels(&non_system_mods));
+        levels
+    }
+
     /// Discover via inventory, have registrators fill the builder, then build & topo-sort.
     ///
     /// # Errors
     /// Returns `RegistryError` if module discovery or dependency resolution fails.
     pub fn discover_and_build() -> Result<Self, RegistryError> {
+        Self::discover_and_build_with(|_| true)
+    }
+
+    /// Like [`discover_and_build`](Self::discover_and_build), but drops every discovered
+    /// module for which `is_enabled` returns `false` (see [`RegistryBuilder::disable_module`]).
+    ///
+    /// # Errors
+    /// Returns `RegistryError` if module discovery or dependency resolution fails,
+    /// including when an enabled module depends on a disabled one.
+    pub fn discover_and_build_with(
+        is_enabled: impl Fn(&str) -> bool,
+    ) -> Result<Self, RegistryError> {
         let mut b = RegistryBuilder::default();
         for r in ::inventory::iter::<Registrator> {
             r.0(&mut b);
         }
+        let disabled: Vec<&'static str> = b
+            .core
+            .keys()
+            .copied()
+            .filter(|name| !is_enabled(name))
+            .collect();
+        for name in disabled {
+            b.disable_module(name);
+        }
         b.build_topo_sorted()
     }
 
     /// (Optional) quick lookup if you need it.
     #[must_use]
     pub fn get_module(&self, name: &str) -> Option<Arc<dyn contracts::Module>> {
-        self.modules
-            .iter()
-            .find(|e| e.name == name)
-            .map(|e| e.core.clone())
+        self.entry(name).map(|e| e.core.clone())
+    }
+
+    /// Looks up a module's registry entry (deps, version, capabilities) by name.
+    #[must_use]
+    pub fn entry(&self, name: &str) -> Option<&ModuleEntry> {
+        self.modules.iter().find(|e| e.name == name)
     }
 }
 
@@ -334,9 +448,13 @@ type GrpcHubEntry = (&'static str, Arc<dyn contracts::GrpcHubCapability>);
 pub struct RegistryBuilder {
     core: HashMap<&'static str, Arc<dyn contracts::Module>>,
     deps: HashMap<&'static str, &'static [&'static str]>,
+    soft_deps: HashMap<&'static str, &'static [&'static str]>,
+    versions: HashMap<&'static str, &'static str>,
     capabilities: HashMap<&'static str, Vec<Capability>>,
-    rest_host: Option<RestHostEntry>,
+    /// Every REST host registration; more than one fails the build.
+    rest_hosts: Vec<RestHostEntry>,
     grpc_hub: Option<GrpcHubEntry>,
+    disabled: HashSet<String>,
     errors: Vec<String>,
 }
 
@@ -363,6 +481,38 @@ impl RegistryBuilder {
         self.deps.insert(name, deps);
     }
 
+    /// Register a module with hard `deps` and optional `soft_deps`.
+    ///
+    /// A soft dependency orders this module after it when it is registered and is
+    /// ignored otherwise; missing hard dependencies still fail the build.
+    pub fn register_core_with_soft_deps(
+        &mut self,
+        name: &'static str,
+        deps: &'static [&'static str],
+        soft_deps: &'static [&'static str],
+        m: Arc<dyn contracts::Module>,
+    ) {
+        if self.core.contains_key(name) {
+            self.errors
+                .push(format!("Module '{name}' is already registered"));
+            return;
+        }
+        self.register_core_with_meta(name, deps, m);
+        self.soft_deps.insert(name, soft_deps);
+    }
+
+    /// Record the semver `version` of module `name`, checked against the
+    /// `name@<requirement>` dependency specs of other modules at build time.
+    pub fn register_version_with_meta(&mut self, name: &'static str, version: &'static str) {
+        if let Err(e) = semver::Version::parse(version) {
+            self.errors.push(format!(
+                "Module '{name}' has invalid version '{version}': {e}"
+            ));
+            return;
+        }
+        self.versions.insert(name, version);
+    }
+
     pub fn register_rest_with_meta(
         &mut self,
         name: &'static str,
@@ -379,13 +529,7 @@ impl RegistryBuilder {
         name: &'static str,
         m: Arc<dyn contracts::ApiGatewayCapability>,
     ) {
-        if let Some((existing, _)) = &self.rest_host {
-            self.errors.push(format!(
-                "Multiple REST host modules detected: '{existing}' and '{name}'. Only one REST host is allowed."
-            ));
-            return;
-        }
-        self.rest_host = Some((name, m));
+        self.rest_hosts.push((name, m));
     }
 
     #[cfg(feature = "db")]
@@ -447,6 +591,58 @@ impl RegistryBuilder {
             .push(Capability::GrpcService(m));
     }
 
+    /// Exclude a module from the built registry, together with all of its capabilities.
+    ///
+    /// Soft dependencies on a disabled module are ignored; a hard dependency on one
+    /// fails the build with [`RegistryError::DependsOnDisabled`].
+    pub fn disable_module(&mut self, name: &str) {
+        self.disabled.insert(name.to_owned());
+    }
+
+    /// Drop disabled modules and reject hard dependencies on them.
+    fn remove_disabled(&mut self) -> Result<(), RegistryError> {
+        if self.disabled.is_empty() {
+            return Ok(());
+        }
+
+        for name in &self.disabled {
+            self.core.remove(name.as_str());
+            self.deps.remove(name.as_str());
+            self.soft_deps.remove(name.as_str());
+            self.versions.remove(name.as_str());
+            self.capabilities.remove(name.as_str());
+        }
+        self.rest_hosts
+            .retain(|(name, _)| !self.disabled.contains(*name));
+        if self
+            .grpc_hub
+            .as_ref()
+            .is_some_and(|(name, _)| self.disabled.contains(*name))
+        {
+            self.grpc_hub = None;
+        }
+
+        let mut modules: Vec<&'static str> = self.deps.keys().copied().collect();
+        modules.sort_unstable();
+        for module in modules {
+            if let Some(dep) = self.deps[module]
+                .iter()
+                .map(|dep| split_dep(dep).0)
+                .find(|dep| self.disabled.contains(*dep))
+            {
+                return Err(RegistryError::DependsOnDisabled {
+                    module: module.to_owned(),
+                    depends_on: dep.to_owned(),
+                });
+            }
+        }
+
+        let mut disabled: Vec<&str> = self.disabled.iter().map(String::as_str).collect();
+        disabled.sort_unstable();
+        tracing::info!(modules = ?disabled, "Modules disabled by configuration");
+        Ok(())
+    }
+
     /// Detect cycles in the dependency graph using DFS with path tracking.
     /// Returns the cycle path if found, None otherwise.
     fn detect_cycle_with_path(
@@ -516,8 +712,17 @@ impl RegistryBuilder {
 
     /// Validate that all capabilities reference known core modules.
     fn validate_capabilities(&self) -> Result<(), RegistryError> {
-        // Check rest_host early
-        if let Some((host_name, _)) = &self.rest_host
+        // Check rest hosts early: exactly one is allowed, and it must be a known module
+        if self.rest_hosts.len() > 1 {
+            return Err(RegistryError::MultipleRestHosts {
+                modules: self
+                    .rest_hosts
+                    .iter()
+                    .map(|(name, _)| (*name).to_owned())
+                    .collect(),
+            });
+        }
+        if let Some((host_name, _)) = self.rest_hosts.first()
             && !self.core.contains_key(host_name)
         {
             return Err(RegistryError::UnknownModule((*host_name).to_owned()));
@@ -537,6 +742,13 @@ impl RegistryBuilder {
             }
         }
 
+        // Validate versions
+        for name in self.versions.keys() {
+            if !self.core.contains_key(name) {
+                return Err(RegistryError::UnknownModule((*name).to_owned()));
+            }
+        }
+
         // Validate grpc_hub
         if let Some((name, _)) = &self.grpc_hub
             && !self.core.contains_key(name)
@@ -562,6 +774,7 @@ impl RegistryBuilder {
                 .get(n)
                 .ok_or_else(|| RegistryError::UnknownModule(n.to_owned()))?;
             for &d in deps {
+                let d = split_dep(d).0;
                 let v = *idx.get(d).ok_or_else(|| RegistryError::UnknownDependency {
                     module: n.to_owned(),
                     depends_on: d.to_owned(),
@@ -571,9 +784,68 @@ impl RegistryBuilder {
             }
         }
 
+        // Soft deps add the same edges, but only towards registered modules
+        for (&n, &soft) in &self.soft_deps {
+            let Some(&u) = idx.get(n) else { continue };
+            for d in soft {
+                let d = split_dep(d).0;
+                if let Some(&v) = idx.get(d) {
+                    adj[v].push(u);
+                } else {
+                    tracing::debug!(
+                        module = n,
+                        soft_dep = d,
+                        "Soft dependency not registered, ignoring"
+                    );
+                }
+            }
+        }
+
         Ok((names, adj, idx))
     }
 
+    /// Check `name@<requirement>` dependency specs against registered versions.
+    /// Soft dependencies are only checked when the target is registered.
+    fn validate_dependency_versions(&self) -> Result<(), RegistryError> {
+        let mut modules: Vec<&'static str> = self.deps.keys().copied().collect();
+        modules.sort_unstable();
+        for module in modules {
+            let soft = self
+                .soft_deps
+                .get(module)
+                .copied()
+                .unwrap_or_default()
+                .iter()
+                .filter(|dep| self.core.contains_key(split_dep(dep).0));
+            for dep in self.deps[module].iter().chain(soft) {
+                let (name, Some(req)) = split_dep(dep) else {
+                    continue;
+                };
+                let req = semver::VersionReq::parse(req).map_err(|e| {
+                    RegistryError::InvalidRegistryConfiguration {
+                        errors: vec![format!(
+                            "Module '{module}' has invalid dependency '{dep}': {e}"
+                        )],
+                    }
+                })?;
+                let found = self.versions.get(name).copied();
+                // Registered versions were validated in `register_version_with_meta`.
+                let satisfied = found
+                    .and_then(|v| semver::Version::parse(v).ok())
+                    .is_some_and(|v| req.matches(&v));
+                if !satisfied {
+                    return Err(RegistryError::IncompatibleDependency {
+                        module: module.to_owned(),
+                        depends_on: name.to_owned(),
+                        required: req.to_string(),
+                        found: found.unwrap_or("no version").to_owned(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Assemble final module entries in topological order.
     fn assemble_entries(
         &self,
@@ -605,7 +877,7 @@ impl RegistryBuilder {
             }
 
             // Add rest_host if this module is the host
-            if let Some((host_name, module)) = &self.rest_host
+            if let Some((host_name, module)) = self.rest_hosts.first()
                 && *host_name == name
             {
                 caps.push(Capability::ApiGateway(module.clone()));
@@ -618,9 +890,22 @@ impl RegistryBuilder {
                 caps.push(Capability::GrpcHub(module.clone()));
             }
 
+            let soft_deps = self.soft_deps.get(name).copied().unwrap_or_default();
+            let version_reqs = deps
+                .iter()
+                .chain(soft_deps)
+                .filter_map(|dep| match split_dep(dep) {
+                    (dep, Some(req)) => Some((dep, req)),
+                    (_, None) => None,
+                })
+                .collect();
+
             let entry = ModuleEntry {
                 name,
-                deps,
+                version: self.versions.get(name).copied(),
+                deps: deps.iter().map(|dep| split_dep(dep).0).collect(),
+                soft_deps: soft_deps.iter().map(|dep| split_dep(dep).0).collect(),
+                version_reqs,
                 core,
                 caps,
             };
@@ -633,12 +918,16 @@ impl RegistryBuilder {
     ///
     /// # Errors
     /// Returns `RegistryError` if validation fails or a dependency cycle is detected.
-    pub fn build_topo_sorted(self) -> Result<ModuleRegistry, RegistryError> {
+    pub fn build_topo_sorted(mut self) -> Result<ModuleRegistry, RegistryError> {
+        // 0) Drop modules disabled by configuration
+        self.remove_disabled()?;
+
         // 1) Validate all capabilities
         self.validate_capabilities()?;
 
-        // 2) Build dependency graph
+        // 2) Build dependency graph and check dependency version requirements
         let (names, adj, _idx) = self.build_dependency_graph()?;
+        self.validate_dependency_versions()?;
 
         // 3) Cycle detection using DFS with path tracking
         if let Some(cycle_path) = Self::detect_cycle_with_path(&names, &adj) {
@@ -677,16 +966,16 @@ impl RegistryBuilder {
         // Collect grpc_hub and grpc_services for the final registry
         let grpc_hub = self.grpc_hub.as_ref().map(|(name, _)| (*name).to_owned());
 
-        // Collect grpc_services from capabilities
-        let mut grpc_services: Vec<(String, Arc<dyn contracts::GrpcServiceCapability>)> =
-            Vec::new();
-        for (name, caps) in &self.capabilities {
-            for cap in caps {
-                if let Capability::GrpcService(service) = cap {
-                    grpc_services.push(((*name).to_owned(), service.clone()));
-                }
-            }
-        }
+        // Collect grpc_services from capabilities, in topo order
+        let grpc_services: Vec<(String, Arc<dyn contracts::GrpcServiceCapability>)> = entries
+            .iter()
+            .flat_map(|e| {
+                e.caps
+                    .query_all::<GrpcServiceCap>()
+                    .into_iter()
+                    .map(|service| (e.name.to_owned(), service))
+            })
+            .collect();
 
         tracing::info!(
             modules = ?entries.iter().map(|e| e.name).collect::<Vec<_>>(),
@@ -758,8 +1047,8 @@ pub enum RegistryError {
         "REST phase requires an gateway host: modules with capability 'rest' found, but no module with capability 'rest_host'"
     )]
     RestRequiresHost,
-    #[error("multiple 'rest_host' modules detected; exactly one is allowed")]
-    MultipleRestHosts,
+    #[error("multiple 'rest_host' modules detected: {}; exactly one is allowed", modules.join(", "))]
+    MultipleRestHosts { modules: Vec<String> },
     #[error("REST host module not found after validation")]
     RestHostNotFoundAfterValidation,
     #[error("REST host missing from entry")]
@@ -796,6 +1085,15 @@ pub enum RegistryError {
     UnknownModule(String),
     #[error("module '{module}' depends on unknown '{depends_on}'")]
     UnknownDependency { module: String, depends_on: String },
+    #[error("module '{module}' requires '{depends_on}' {required}, found {found}")]
+    IncompatibleDependency {
+        module: String,
+        depends_on: String,
+        required: String,
+        found: String,
+    },
+    #[error("module '{module}' depends on '{depends_on}', which is disabled in configuration")]
+    DependsOnDisabled { module: String, depends_on: String },
     #[error("cyclic dependency detected: {}", path.join(" -> "))]
     CycleDetected { path: Vec<&'static str> },
     #[error("missing deps for '{0}'")]
@@ -828,6 +1126,232 @@ mod tests {
 
     /* ------------------------------- Tests ---------------------------- */
 
+    #[test]
+    fn query_all_returns_every_capability_of_a_tag() {
+        let first: Arc<dyn contracts::RestApiCapability> = Arc::new(DummyRest);
+        let second: Arc<dyn contracts::RestApiCapability> = Arc::new(DummyRest);
+
+        let mut caps = CapabilitySet::new();
+        caps.push(Capability::RestApi(first.clone()));
+        caps.push(Capability::Runnable(Arc::new(DummyStateful)));
+        caps.push(Capability::RestApi(second.clone()));
+
+        let all = caps.query_all::<RestApiCap>();
+        assert_eq!(all.len(), 2);
+        assert!(Arc::ptr_eq(&all[0], &first));
+        assert!(Arc::ptr_eq(&all[1], &second));
+        assert!(Arc::ptr_eq(&caps.query::<RestApiCap>().unwrap(), &first));
+        assert!(caps.query_all::<SystemCap>().is_empty());
+    }
+
+    #[test]
+    fn labels_are_sorted_and_deduplicated() {
+        let mut caps = CapabilitySet::new();
+        caps.push(Capability::Runnable(Arc::new(DummyStateful)));
+        caps.push(Capability::RestApi(Arc::new(DummyRest)));
+        caps.push(Capability::ApiGateway(Arc::new(DummyRestHost)));
+        caps.push(Capability::RestApi(Arc::new(DummyRest)));
+
+        assert_eq!(caps.labels(), vec!["rest", "rest_host", "stateful"]);
+    }
+
+    #[test]
+    fn entry_looks_up_capabilities_by_name() {
+        let mut b = RegistryBuilder::default();
+        b.register_core_with_meta("api", &[], Arc::new(DummyCore));
+        b.register_rest_with_meta("api", Arc::new(DummyRest));
+        b.register_core_with_meta("worker", &["api"], Arc::new(DummyCore));
+        b.register_stateful_with_meta("worker", Arc::new(DummyStateful));
+
+        let reg = b.build_topo_sorted().unwrap();
+        let api = reg.entry("api").expect("api is registered");
+        assert_eq!(api.name(), "api");
+        assert!(api.caps().has::<RestApiCap>());
+        assert!(!api.caps().has::<RunnableCap>());
+        let worker = reg.entry("worker").expect("worker is registered");
+        assert_eq!(worker.caps().labels(), vec!["stateful"]);
+        assert!(reg.entry("missing").is_none());
+    }
+
+    #[test]
+    fn init_levels_group_independent_modules() {
+        let mut b = RegistryBuilder::default();
+        b.register_core_with_meta("a", &[], Arc::new(DummyCore));
+        b.register_core_with_meta("b", &[], Arc::new(DummyCore));
+        b.register_core_with_meta("c", &["a"], Arc::new(DummyCore));
+        b.register_core_with_meta("d", &["a", "c"], Arc::new(DummyCore));
+        b.register_core_with_meta("e", &["b"], Arc::new(DummyCore));
+
+        let reg = b.build_topo_sorted().unwrap();
+        let levels: Vec<Vec<_>> = reg
+            .init_levels()
+            .iter()
+            .map(|level| {
+                let mut names: Vec<_> = level.iter().map(|m| m.name).collect();
+                names.sort_unstable();
+                names
+            })
+            .collect();
+        assert_eq!(levels, vec![vec!["a", "b"], vec!["c", "e"], vec!["d"]]);
+    }
+
+    #[test]
+    fn soft_dep_orders_after_present_module() {
+        let mut b = RegistryBuilder::default();
+        // "audit" registered first so map iteration alone would not explain the order
+        b.register_core_with_soft_deps("consumer", &[], &["audit"], Arc::new(DummyCore));
+        b.register_core_with_meta("audit", &[], Arc::new(DummyCore));
+
+        let reg = b.build_topo_sorted().unwrap();
+        let order: Vec<_> = reg.modules().iter().map(|m| m.name).collect();
+        assert_eq!(order, vec!["audit", "consumer"]);
+        assert_eq!(reg.modules()[1].soft_deps(), &["audit"]);
+    }
+
+    #[test]
+    fn absent_soft_dep_is_ignored() {
+        let mut b = RegistryBuilder::default();
+        b.register_core_with_meta("core_a", &[], Arc::new(DummyCore));
+        b.register_core_with_soft_deps("consumer", &["core_a"], &["audit"], Arc::new(DummyCore));
+
+        let reg = b.build_topo_sorted().unwrap();
+        let order: Vec<_> = reg.modules().iter().map(|m| m.name).collect();
+        assert_eq!(order, vec!["core_a", "consumer"]);
+    }
+
+    #[test]
+    fn disabled_module_is_skipped_with_its_capabilities() {
+        let mut b = RegistryBuilder::default();
+        b.register_core_with_meta("first", &[], Arc::new(DummyCore));
+        b.register_core_with_meta("middle", &["first"], Arc::new(DummyCore));
+        b.register_rest_host_with_meta("middle", Arc::new(DummyRestHost));
+        b.register_core_with_soft_deps("last", &["first"], &["middle"], Arc::new(DummyCore));
+        b.disable_module("middle");
+
+        let reg = b.build_topo_sorted().unwrap();
+        let order: Vec<_> = reg.modules().iter().map(|m| m.name).collect();
+        assert_eq!(order, vec!["first", "last"]);
+        assert!(reg.get_module("middle").is_none());
+        assert!(
+            reg.modules()
+                .iter()
+                .all(|m| !m.caps().has::<ApiGatewayCap>())
+        );
+    }
+
+    #[test]
+    fn hard_dependency_on_disabled_module_fails() {
+        let mut b = RegistryBuilder::default();
+        b.register_core_with_meta("first", &[], Arc::new(DummyCore));
+        b.register_core_with_meta("middle", &["first"], Arc::new(DummyCore));
+        b.register_core_with_meta("last", &["middle"], Arc::new(DummyCore));
+        b.disable_module("middle");
+
+        let err = b.build_topo_sorted().unwrap_err();
+        match err {
+            RegistryError::DependsOnDisabled { module, depends_on } => {
+                assert_eq!(module, "last");
+                assert_eq!(depends_on, "middle");
+            }
+            other => panic!("expected DependsOnDisabled, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn satisfied_version_constraint_builds() {
+        let mut b = RegistryBuilder::default();
+        b.register_core_with_meta("auth", &[], Arc::new(DummyCore));
+        b.register_version_with_meta("auth", "1.4.2");
+        b.register_core_with_meta("billing", &["auth@^1.2"], Arc::new(DummyCore));
+
+        let reg = b.build_topo_sorted().unwrap();
+        let order: Vec<_> = reg.modules().iter().map(|m| m.name).collect();
+        assert_eq!(order, vec!["auth", "billing"]);
+        assert_eq!(reg.modules()[0].version(), Some("1.4.2"));
+        assert_eq!(reg.modules()[1].version(), None);
+        assert_eq!(reg.modules()[1].deps(), &["auth"]);
+        assert_eq!(reg.modules()[1].version_req("auth"), Some("^1.2"));
+    }
+
+    #[test]
+    fn soft_dep_version_constraints_are_checked_when_registered() {
+        let mut b = RegistryBuilder::default();
+        b.register_core_with_meta("audit", &[], Arc::new(DummyCore));
+        b.register_version_with_meta("audit", "2.0.0");
+        b.register_core_with_soft_deps(
+            "consumer",
+            &[],
+            &["audit@^2", "metrics@^1"],
+            Arc::new(DummyCore),
+        );
+
+        let reg = b.build_topo_sorted().unwrap();
+        let consumer = &reg.modules()[1];
+        assert_eq!(consumer.name(), "consumer");
+        assert_eq!(consumer.soft_deps(), &["audit", "metrics"]);
+        assert_eq!(consumer.version_req("audit"), Some("^2"));
+        assert_eq!(consumer.version_req("metrics"), Some("^1"));
+
+        let mut b = RegistryBuilder::default();
+        b.register_core_with_meta("audit", &[], Arc::new(DummyCore));
+        b.register_version_with_meta("audit", "1.9.0");
+        b.register_core_with_soft_deps("consumer", &[], &["audit@^2"], Arc::new(DummyCore));
+
+        let err = b.build_topo_sorted().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "module 'consumer' requires 'audit' ^2, found 1.9.0"
+        );
+    }
+
+    #[test]
+    fn unsatisfied_version_constraint_fails() {
+        let mut b = RegistryBuilder::default();
+        b.register_core_with_meta("auth", &[], Arc::new(DummyCore));
+        b.register_version_with_meta("auth", "1.1.0");
+        b.register_core_with_meta("billing", &["auth@^1.2"], Arc::new(DummyCore));
+
+        let err = b.build_topo_sorted().unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                RegistryError::IncompatibleDependency { module, depends_on, found, .. }
+                    if module == "billing" && depends_on == "auth" && found == "1.1.0"
+            ),
+            "expected IncompatibleDependency, got: {err:?}"
+        );
+        assert_eq!(
+            err.to_string(),
+            "module 'billing' requires 'auth' ^1.2, found 1.1.0"
+        );
+    }
+
+    #[test]
+    fn version_constraint_on_unversioned_module_fails() {
+        let mut b = RegistryBuilder::default();
+        b.register_core_with_meta("auth", &[], Arc::new(DummyCore));
+        b.register_core_with_meta("billing", &["auth@>=2"], Arc::new(DummyCore));
+
+        let err = b.build_topo_sorted().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "module 'billing' requires 'auth' >=2, found no version"
+        );
+    }
+
+    #[test]
+    fn soft_dep_edges_take_part_in_cycle_detection() {
+        let mut b = RegistryBuilder::default();
+        b.register_core_with_meta("a", &["b"], Arc::new(DummyCore));
+        b.register_core_with_soft_deps("b", &[], &["a"], Arc::new(DummyCore));
+
+        let err = b.build_topo_sorted().unwrap_err();
+        assert!(
+            matches!(err, RegistryError::CycleDetected { .. }),
+            "expected CycleDetected, got: {err:?}"
+        );
+    }
+
     #[test]
     fn topo_sort_happy_path() {
         let mut b = RegistryBuilder::default();
@@ -1005,6 +1529,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn multiple_rest_hosts_fail_the_build_listing_both() {
+        let mut b = RegistryBuilder::default();
+        b.register_core_with_meta("gateway_a", &[], Arc::new(DummyCore));
+        b.register_core_with_meta("gateway_b", &[], Arc::new(DummyCore));
+        b.register_rest_host_with_meta("gateway_a", Arc::new(DummyRestHost));
+        b.register_rest_host_with_meta("gateway_b", Arc::new(DummyRestHost));
+
+        let err = b.build_topo_sorted().unwrap_err();
+        match &err {
+            RegistryError::MultipleRestHosts { modules } => {
+                assert_eq!(modules, &["gateway_a", "gateway_b"]);
+            }
+            other => panic!("expected MultipleRestHosts, got: {other:?}"),
+        }
+        let msg = err.to_string();
+        assert!(
+            msg.contains("gateway_a") && msg.contains("gateway_b"),
+            "{msg}"
+        );
+    }
+
     #[test]
     fn module_entry_getters_work() {
         let mut b = RegistryBuilder::default();
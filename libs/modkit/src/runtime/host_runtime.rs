@@ -20,7 +20,7 @@ use std::sync::Arc;
 use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
-use crate::backends::OopSpawnConfig;
+use crate::backends::{OopSpawnConfig, check_executable};
 use crate::client_hub::ClientHub;
 use crate::config::ConfigProvider;
 use crate::context::ModuleContextBuilder;
@@ -28,7 +28,11 @@ use crate::registry::{
     ApiGatewayCap, GrpcHubCap, ModuleEntry, ModuleRegistry, RegistryError, RestApiCap, RunnableCap,
     SystemCap,
 };
-use crate::runtime::{GrpcInstallerStore, ModuleManager, OopSpawnOptions, SystemContext};
+use crate::runtime::route_guard::RouteConflictGuard;
+use crate::runtime::{
+    GrpcInstallerStore, LifecycleObserver, LifecyclePhase, ModuleManager, NoopLifecycleObserver,
+    OopSpawnOptions, SystemContext,
+};
 
 #[cfg(feature = "db")]
 use crate::registry::DatabaseCap;
@@ -50,6 +54,9 @@ pub enum RunMode {
     Full,
     /// Run only pre-init and DB migration phases, then exit (for cloud deployments).
     MigrateOnly,
+    /// Dry run: validate wiring (init, REST and gRPC registration) without running
+    /// migrations, `post_init` hooks, starting servers or spawning `OoP` modules.
+    ValidateOnly,
 }
 
 /// Environment variable name for passing directory endpoint to `OoP` modules.
@@ -65,6 +72,12 @@ pub const MODKIT_MODULE_CONFIG_ENV: &str = "MODKIT_MODULE_CONFIG";
 /// and the runtime deadline acts as a hard backstop.
 pub const DEFAULT_SHUTDOWN_DEADLINE: std::time::Duration = std::time::Duration::from_secs(35);
 
+/// Default time the stop phase waits for a single module's `stop` (40 seconds).
+///
+/// Longer than `DEFAULT_SHUTDOWN_DEADLINE` so a module gets to react to the
+/// hard-stop signal before the runtime gives up on it.
+pub const DEFAULT_STOP_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(40);
+
 /// `HostRuntime` owns the lifecycle orchestration for `ModKit`.
 ///
 /// It encapsulates all runtime state and drives modules through the full lifecycle (see module docs).
@@ -83,6 +96,12 @@ pub struct HostRuntime {
     oop_options: Option<OopSpawnOptions>,
     /// Maximum time allowed for graceful shutdown before hard-stop signal is sent.
     shutdown_deadline: std::time::Duration,
+    /// Maximum time the stop phase waits for one module before moving on.
+    stop_timeout: std::time::Duration,
+    /// Initialize independent modules concurrently (see `with_parallel_init`).
+    parallel_init: bool,
+    /// Receives phase and module-init events (no-op unless set via `with_observer`).
+    observer: Arc<dyn LifecycleObserver>,
 }
 
 impl HostRuntime {
@@ -128,6 +147,9 @@ impl HostRuntime {
             db_options,
             oop_options,
             shutdown_deadline: DEFAULT_SHUTDOWN_DEADLINE,
+            stop_timeout: DEFAULT_STOP_TIMEOUT,
+            parallel_init: false,
+            observer: Arc::new(NoopLifecycleObserver),
         }
     }
 
@@ -152,6 +174,38 @@ impl HostRuntime {
         self
     }
 
+    /// Set the per-module stop timeout.
+    ///
+    /// If a module's `stop` has not returned after `timeout`, the stop phase logs a
+    /// warning and continues with the next module. Keep it above `shutdown_deadline`
+    /// so the module sees the hard-stop signal first.
+    #[must_use]
+    pub fn with_stop_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.stop_timeout = timeout;
+        self
+    }
+
+    /// Enable parallel init.
+    ///
+    /// Modules are grouped into dependency levels (see `ModuleRegistry::init_levels`)
+    /// and each level is initialized concurrently. A module still starts only after
+    /// all of its dependencies finished `init`, and system modules still precede
+    /// user modules. Disabled by default.
+    #[must_use]
+    pub fn with_parallel_init(mut self, enabled: bool) -> Self {
+        self.parallel_init = enabled;
+        self
+    }
+
+    /// Set an observer notified of phase transitions and per-module init/start timings.
+    ///
+    /// Use `StartupReportRecorder` to collect a `StartupReport`.
+    #[must_use]
+    pub fn with_observer(mut self, observer: Arc<dyn LifecycleObserver>) -> Self {
+        self.observer = observer;
+        self
+    }
+
     /// `PRE_INIT` phase: wire runtime internals into system modules.
     ///
     /// This phase runs before init and only for modules with the "system" capability.
@@ -320,31 +374,51 @@ impl HostRuntime {
         Ok(())
     }
 
+    /// Initialize a single module.
+    async fn init_one_module(&self, entry: &ModuleEntry) -> Result<(), RegistryError> {
+        let ctx =
+            self.ctx_builder
+                .for_module(entry.name)
+                .await
+                .map_err(|e| RegistryError::Init {
+                    module: entry.name,
+                    source: e,
+                })?;
+        tracing::info!(module = entry.name, "Initializing a module...");
+        let started = std::time::Instant::now();
+        entry
+            .core
+            .init(&ctx)
+            .await
+            .map_err(|e| RegistryError::Init {
+                module: entry.name,
+                source: e,
+            })?;
+        tracing::info!(module = entry.name, "Initialized a module.");
+        self.observer.on_module_init(entry.name, started.elapsed());
+        Ok(())
+    }
+
     /// INIT phase: initialize all modules in topological order.
     ///
-    /// System modules initialize first, followed by user modules.
+    /// System modules initialize first, followed by user modules. With parallel
+    /// init enabled, modules of the same dependency level initialize concurrently.
     async fn run_init_phase(&self) -> Result<(), RegistryError> {
         tracing::info!("Phase: init");
 
+        if self.parallel_init {
+            for level in self.registry.init_levels() {
+                let results = futures_util::future::join_all(
+                    level.into_iter().map(|e| self.init_one_module(e)),
+                )
+                .await;
+                results.into_iter().collect::<Result<Vec<()>, _>>()?;
+            }
+            return Ok(());
+        }
+
         for entry in self.registry.modules_by_system_priority() {
-            let ctx =
-                self.ctx_builder
-                    .for_module(entry.name)
-                    .await
-                    .map_err(|e| RegistryError::Init {
-                        module: entry.name,
-                        source: e,
-                    })?;
-            tracing::info!(module = entry.name, "Initializing a module...");
-            entry
-                .core
-                .init(&ctx)
-                .await
-                .map_err(|e| RegistryError::Init {
-                    module: entry.name,
-                    source: e,
-                })?;
-            tracing::info!(module = entry.name, "Initialized a module.");
+            self.init_one_module(entry).await?;
         }
 
         Ok(())
@@ -391,39 +465,24 @@ impl HostRuntime {
 
         let mut router = Router::new();
 
-        // Find host(s) and whether any rest modules exist
-        let host_count = self
+        // Resolve the host entry; the registry build already rejected multiple hosts
+        let Some(host_entry) = self
             .registry
             .modules()
             .iter()
-            .filter(|e| e.caps.has::<ApiGatewayCap>())
-            .count();
-
-        match host_count {
-            0 => {
-                return if self
-                    .registry
-                    .modules()
-                    .iter()
-                    .any(|e| e.caps.has::<RestApiCap>())
-                {
-                    Err(RegistryError::RestRequiresHost)
-                } else {
-                    Ok(router)
-                };
-            }
-            1 => { /* proceed */ }
-            _ => return Err(RegistryError::MultipleRestHosts),
-        }
-
-        // Resolve the single host entry and its module context
-        let host_idx = self
-            .registry
-            .modules()
-            .iter()
-            .position(|e| e.caps.has::<ApiGatewayCap>())
-            .ok_or(RegistryError::RestHostNotFoundAfterValidation)?;
-        let host_entry = &self.registry.modules()[host_idx];
+            .find(|e| e.caps.has::<ApiGatewayCap>())
+        else {
+            return if self
+                .registry
+                .modules()
+                .iter()
+                .any(|e| e.caps.has::<RestApiCap>())
+            {
+                Err(RegistryError::RestRequiresHost)
+            } else {
+                Ok(router)
+            };
+        };
         let Some(host) = host_entry.caps.query::<ApiGatewayCap>() else {
             return Err(RegistryError::RestHostMissingFromEntry);
         };
@@ -436,8 +495,9 @@ impl HostRuntime {
                 source: e,
             })?;
 
-        // use host as the registry
-        let registry: &dyn crate::contracts::OpenApiRegistry = host.as_registry();
+        // use host as the registry, rejecting METHOD+path pairs registered twice
+        let guard = RouteConflictGuard::new(host.as_registry());
+        let registry: &dyn crate::contracts::OpenApiRegistry = &guard;
 
         // 1) Host prepare: base Router / global middlewares / basic OAS meta
         router =
@@ -449,7 +509,7 @@ impl HostRuntime {
 
         // 2) Register all REST providers (in the current discovery order)
         for e in self.registry.modules() {
-            if let Some(rest) = e.caps.query::<RestApiCap>() {
+            for rest in e.caps.query_all::<RestApiCap>() {
                 let ctx = self.ctx_builder.for_module(e.name).await.map_err(|err| {
                     RegistryError::RestRegister {
                         module: e.name,
@@ -457,12 +517,21 @@ impl HostRuntime {
                     }
                 })?;
 
-                router = rest
-                    .register_rest(&ctx, router, registry)
-                    .map_err(|source| RegistryError::RestRegister {
+                guard.enter_module(e.name);
+                // The guard refuses duplicate routes (they never reach axum) and
+                // records each conflict for the error below.
+                let result = rest.register_rest(&ctx, router, registry);
+                let conflicts = guard.take_conflicts();
+                if !conflicts.is_empty() {
+                    return Err(RegistryError::RestRegister {
                         module: e.name,
-                        source,
-                    })?;
+                        source: anyhow::anyhow!(conflicts.join("; ")),
+                    });
+                }
+                router = result.map_err(|source| RegistryError::RestRegister {
+                    module: e.name,
+                    source,
+                })?;
             }
         }
 
@@ -562,6 +631,7 @@ impl HostRuntime {
                     is_system = e.caps.has::<SystemCap>(),
                     "Starting stateful module"
                 );
+                let started = std::time::Instant::now();
                 s.start(self.cancel.clone())
                     .await
                     .map_err(|source| RegistryError::Start {
@@ -569,6 +639,7 @@ impl HostRuntime {
                         source,
                     })?;
                 tracing::info!(module = e.name, "Started module");
+                self.observer.on_module_start(e.name, started.elapsed());
             }
         }
 
@@ -610,9 +681,15 @@ impl HostRuntime {
     /// - Wait for them to finish gracefully
     /// - If `deadline_token` fires, switch to hard-abort mode
     ///
-    /// Errors are logged but do not fail the shutdown process.
+    /// Errors are logged but do not fail the shutdown process. A module whose `stop`
+    /// does not return within `stop_timeout` is skipped the same way.
+    ///
+    /// Once a module is stopped, this process's instance of it is removed from the
+    /// `ModuleManager` so the directory stops resolving it while the rest of the
+    /// shutdown runs; instances registered by other processes stay.
     /// Note: `OoP` modules are stopped automatically by the backend when the
-    /// cancellation token is triggered.
+    /// cancellation token is triggered; their instances are removed at the end of
+    /// this phase in case the process never deregistered itself.
     async fn run_stop_phase(&self) -> Result<(), RegistryError> {
         tracing::info!("Phase: stop");
 
@@ -639,13 +716,45 @@ impl HostRuntime {
             });
 
             // Stop this module with its own deadline token
-            // The module can observe the token transition from uncancelled→cancelled
-            Self::stop_one_module(e, deadline_token).await;
+            // The module can observe the token transition from uncancelled→cancelled.
+            // A module that ignores the hard-stop signal is abandoned after stop_timeout.
+            if tokio::time::timeout(self.stop_timeout, Self::stop_one_module(e, deadline_token))
+                .await
+                .is_err()
+            {
+                tracing::warn!(
+                    module = module_name,
+                    timeout_secs = self.stop_timeout.as_secs(),
+                    "Module stop timed out, continuing shutdown"
+                );
+            }
 
             // Cancel the deadline task and await it to ensure full cleanup
             deadline_task.abort();
             #[allow(clippy::let_underscore_must_use)]
             let _ = deadline_task.await;
+
+            if self
+                .module_manager
+                .deregister(module_name, self.instance_id)
+            {
+                tracing::info!(module = module_name, "Deregistered module instance");
+            }
+        }
+
+        // Every instance of a spawned module belongs to a process this host just shut down
+        if let Some(oop_opts) = &self.oop_options {
+            for module_cfg in &oop_opts.modules {
+                let module = module_cfg.module_name.as_str();
+                let removed = self.module_manager.deregister_module(module);
+                if removed > 0 {
+                    tracing::info!(
+                        module,
+                        instances = removed,
+                        "Deregistered OoP module instances"
+                    );
+                }
+            }
         }
 
         Ok(())
@@ -664,7 +773,9 @@ impl HostRuntime {
         tracing::info!("Phase: oop_spawn");
 
         // Wait for grpc_hub to publish its endpoint (it runs async in start phase)
-        let directory_endpoint = self.wait_for_grpc_hub_endpoint().await;
+        let directory_endpoint = self
+            .wait_for_grpc_hub_endpoint(oop_opts.hub_wait_timeout, oop_opts.hub_poll_interval)
+            .await;
 
         for module_cfg in &oop_opts.modules {
             // Build environment with directory endpoint and rendered config
@@ -681,22 +792,35 @@ impl HostRuntime {
             // Use args from execution config as-is (user controls --config via args)
             let args = module_cfg.args.clone();
 
+            let oop_error = |source: anyhow::Error| RegistryError::OopSpawn {
+                module: module_cfg.module_name.clone(),
+                source,
+            };
+
+            // Container modules run an image; everything else needs a local executable
+            let (backend, binary) = if module_cfg.container.is_some() {
+                let backend = oop_opts
+                    .container_backend
+                    .as_deref()
+                    .ok_or_else(|| oop_error(anyhow::anyhow!("no container backend configured")))?;
+                (backend, module_cfg.binary.clone())
+            } else {
+                // Fail with the path before handing a bad binary to the backend
+                check_executable(&module_cfg.binary).map_err(oop_error)?;
+                (oop_opts.backend.as_ref(), module_cfg.binary.clone())
+            };
+
             let spawn_config = OopSpawnConfig {
                 module_name: module_cfg.module_name.clone(),
-                binary: module_cfg.binary.clone(),
+                binary,
                 args,
                 env,
                 working_directory: module_cfg.working_directory.clone(),
+                restart_policy: module_cfg.restart_policy,
+                container: module_cfg.container.clone(),
             };
 
-            oop_opts
-                .backend
-                .spawn(spawn_config)
-                .await
-                .map_err(|e| RegistryError::OopSpawn {
-                    module: module_cfg.module_name.clone(),
-                    source: e,
-                })?;
+            backend.spawn(spawn_config).await.map_err(oop_error)?;
 
             tracing::info!(
                 module = %module_cfg.module_name,
@@ -710,12 +834,13 @@ impl HostRuntime {
 
     /// Wait for `grpc-hub` to publish its bound endpoint.
     ///
-    /// Polls the `GrpcHubModule::bound_endpoint()` with a short interval until available or timeout.
-    /// Returns None if no `grpc-hub` is running or if it times out.
-    async fn wait_for_grpc_hub_endpoint(&self) -> Option<String> {
-        const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(10);
-        const MAX_WAIT: std::time::Duration = std::time::Duration::from_secs(5);
-
+    /// Polls the `GrpcHubModule::bound_endpoint()` every `poll_interval` until available or
+    /// `timeout` expires. Returns None if no `grpc-hub` is running or if it times out.
+    async fn wait_for_grpc_hub_endpoint(
+        &self,
+        timeout: std::time::Duration,
+        poll_interval: std::time::Duration,
+    ) -> Option<String> {
         // Find grpc_hub in registry
         let grpc_hub = self
             .registry
@@ -727,7 +852,7 @@ impl HostRuntime {
             return None; // No grpc_hub registered
         };
 
-        let start = std::time::Instant::now();
+        let start = tokio::time::Instant::now();
 
         loop {
             if let Some(endpoint) = hub.bound_endpoint() {
@@ -739,12 +864,15 @@ impl HostRuntime {
                 return Some(endpoint);
             }
 
-            if start.elapsed() > MAX_WAIT {
-                tracing::warn!("Timed out waiting for gRPC hub to bind");
+            if start.elapsed() > timeout {
+                tracing::warn!(
+                    timeout_ms = timeout.as_millis(),
+                    "Timed out waiting for gRPC hub to bind; OoP modules get no directory endpoint"
+                );
                 return None;
             }
 
-            tokio::time::sleep(POLL_INTERVAL).await;
+            tokio::time::sleep(poll_interval).await;
         }
     }
 
@@ -773,15 +901,29 @@ impl HostRuntime {
         self.run_phases_internal(RunMode::MigrateOnly).await
     }
 
+    /// Run a dry-run startup that validates module wiring, then exit.
+    ///
+    /// Executes pre-init, init and the REST/gRPC registration phases, so registry
+    /// and configuration problems surface as `RegistryError`. DB migrations,
+    /// post-init, the start phase and `OoP` spawning are skipped; no ports are bound.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first `RegistryError` raised by the executed phases.
+    pub async fn run_validation_phases(self) -> anyhow::Result<()> {
+        self.run_phases_internal(RunMode::ValidateOnly).await
+    }
+
     /// Internal implementation that runs module phases based on the mode.
     ///
     /// This private method contains the actual phase execution logic and is called
-    /// by both `run_module_phases()` and `run_migration_phases()`.
+    /// by `run_module_phases()`, `run_migration_phases()` and `run_validation_phases()`.
     ///
     /// # Modes
     ///
     /// - `RunMode::Full`: Executes all phases and waits for shutdown signal
     /// - `RunMode::MigrateOnly`: Executes only pre-init and DB migration phases, then exits
+    /// - `RunMode::ValidateOnly`: Executes phases 1, 3, 5 and 6 (no DB migration or post-init), then exits
     ///
     /// # Phases (Full Mode)
     ///
@@ -804,15 +946,22 @@ impl HostRuntime {
             RunMode::MigrateOnly => {
                 tracing::info!("Running in migration mode (pre-init + db phases only)");
             }
+            RunMode::ValidateOnly => {
+                tracing::info!("Running in validation mode (no migrations, servers or OoP spawn)");
+            }
         }
 
         // 1. Pre-init phase (before init, only for system modules)
+        self.observer.on_phase_start(LifecyclePhase::PreInit);
         self.run_pre_init_phase()?;
+        self.observer.on_phase_complete(LifecyclePhase::PreInit);
 
         // 2. DB migration phase (system modules first)
         #[cfg(feature = "db")]
-        {
+        if mode != RunMode::ValidateOnly {
+            self.observer.on_phase_start(LifecyclePhase::DbMigration);
             self.run_db_phase().await?;
+            self.observer.on_phase_complete(LifecyclePhase::DbMigration);
         }
         #[cfg(not(feature = "db"))]
         {
@@ -826,22 +975,43 @@ impl HostRuntime {
         }
 
         // 3. Init phase (system modules first)
+        self.observer.on_phase_start(LifecyclePhase::Init);
         self.run_init_phase().await?;
-
-        // 4. Post-init phase (barrier after ALL init; system modules only)
-        self.run_post_init_phase().await?;
+        self.observer.on_phase_complete(LifecyclePhase::Init);
+
+        // 4. Post-init phase (barrier after ALL init; system modules only).
+        //    Skipped when validating: post-init hooks act on the running system.
+        if mode != RunMode::ValidateOnly {
+            self.observer.on_phase_start(LifecyclePhase::PostInit);
+            self.run_post_init_phase().await?;
+            self.observer.on_phase_complete(LifecyclePhase::PostInit);
+        }
 
         // 5. REST phase (synchronous router composition)
+        self.observer.on_phase_start(LifecyclePhase::Rest);
         let _router = self.run_rest_phase().await?;
+        self.observer.on_phase_complete(LifecyclePhase::Rest);
 
         // 6. gRPC registration phase
+        self.observer.on_phase_start(LifecyclePhase::Grpc);
         self.run_grpc_phase().await?;
+        self.observer.on_phase_complete(LifecyclePhase::Grpc);
+
+        // Exit before anything is started if only validating the wiring
+        if mode == RunMode::ValidateOnly {
+            tracing::info!("Validation phases completed successfully");
+            return Ok(());
+        }
 
         // 7. Start phase
+        self.observer.on_phase_start(LifecyclePhase::Start);
         self.run_start_phase().await?;
+        self.observer.on_phase_complete(LifecyclePhase::Start);
 
         // 8. OoP spawn phase (after grpc_hub is running)
+        self.observer.on_phase_start(LifecyclePhase::OopSpawn);
         self.run_oop_spawn_phase().await?;
+        self.observer.on_phase_complete(LifecyclePhase::OopSpawn);
 
         // 9. Wait for cancellation
         self.cancel.cancelled().await;
@@ -865,7 +1035,9 @@ impl HostRuntime {
             }
         });
 
+        self.observer.on_phase_start(LifecyclePhase::Stop);
         self.run_stop_phase().await?;
+        self.observer.on_phase_complete(LifecyclePhase::Stop);
         disarm.store(true, std::sync::atomic::Ordering::Relaxed);
 
         Ok(())
@@ -1154,6 +1326,92 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_parallel_init_respects_dependency_levels() {
+        const WIDE: [&str; 6] = ["w0", "w1", "w2", "w3", "w4", "w5"];
+
+        struct InitProbe {
+            name: &'static str,
+            events: Arc<Mutex<Vec<String>>>,
+            running: Arc<AtomicUsize>,
+            max_running: Arc<AtomicUsize>,
+        }
+
+        #[async_trait::async_trait]
+        impl Module for InitProbe {
+            async fn init(&self, _ctx: &ModuleCtx) -> anyhow::Result<()> {
+                self.events
+                    .lock()
+                    .await
+                    .push(format!("start:{}", self.name));
+                let now = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+                self.max_running.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                self.running.fetch_sub(1, Ordering::SeqCst);
+                self.events.lock().await.push(format!("end:{}", self.name));
+                Ok(())
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl SystemCapability for InitProbe {}
+
+        let events = Arc::new(Mutex::new(Vec::<String>::new()));
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+        let probe = |name| {
+            Arc::new(InitProbe {
+                name,
+                events: events.clone(),
+                running: running.clone(),
+                max_running: max_running.clone(),
+            })
+        };
+
+        // sys -> root -> w0..w5 -> top
+        let mut builder = RegistryBuilder::default();
+        let sys = probe("sys");
+        builder.register_core_with_meta("sys", &[], sys.clone() as Arc<dyn Module>);
+        builder.register_system_with_meta("sys", sys as Arc<dyn SystemCapability>);
+        builder.register_core_with_meta("root", &[], probe("root") as Arc<dyn Module>);
+        for name in WIDE {
+            builder.register_core_with_meta(name, &["root"], probe(name) as Arc<dyn Module>);
+        }
+        builder.register_core_with_meta("top", &WIDE, probe("top") as Arc<dyn Module>);
+        let registry = builder.build_topo_sorted().unwrap();
+
+        let runtime = HostRuntime::new(
+            registry,
+            Arc::new(EmptyConfigProvider),
+            DbOptions::None,
+            Arc::new(ClientHub::new()),
+            CancellationToken::new(),
+            Uuid::new_v4(),
+            None,
+        )
+        .with_parallel_init(true);
+
+        runtime.run_init_phase().await.unwrap();
+
+        let events = events.lock().await.clone();
+        let pos = |event: String| {
+            events
+                .iter()
+                .position(|e| *e == event)
+                .unwrap_or_else(|| panic!("missing {event} in {events:?}"))
+        };
+        assert!(pos("end:sys".into()) < pos("start:root".into()));
+        for name in WIDE {
+            assert!(pos("end:root".into()) < pos(format!("start:{name}")));
+            assert!(pos(format!("end:{name}")) < pos("start:top".into()));
+        }
+        assert_eq!(
+            max_running.load(Ordering::SeqCst),
+            WIDE.len(),
+            "independent modules should initialize concurrently"
+        );
+    }
+
     #[tokio::test]
     async fn test_stop_phase_provides_fresh_deadline_token() {
         use std::sync::atomic::AtomicBool;
@@ -1378,4 +1636,645 @@ mod tests {
             "deadline should fire for slow modules"
         );
     }
+
+    #[tokio::test]
+    async fn test_validate_only_skips_post_init() {
+        use std::sync::atomic::AtomicBool;
+
+        #[derive(Default)]
+        struct Sys {
+            inited: AtomicBool,
+            post_inited: AtomicBool,
+        }
+
+        #[async_trait::async_trait]
+        impl Module for Sys {
+            async fn init(&self, _ctx: &ModuleCtx) -> anyhow::Result<()> {
+                self.inited.store(true, Ordering::SeqCst);
+                Ok(())
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl SystemCapability for Sys {
+            async fn post_init(&self, _sys: &crate::runtime::SystemContext) -> anyhow::Result<()> {
+                self.post_inited.store(true, Ordering::SeqCst);
+                Ok(())
+            }
+        }
+
+        let sys = Arc::new(Sys::default());
+        let mut builder = RegistryBuilder::default();
+        builder.register_core_with_meta("sys", &[], sys.clone() as Arc<dyn Module>);
+        builder.register_system_with_meta("sys", sys.clone() as Arc<dyn SystemCapability>);
+        let registry = builder.build_topo_sorted().unwrap();
+
+        let runtime = HostRuntime::new(
+            registry,
+            Arc::new(EmptyConfigProvider),
+            DbOptions::None,
+            Arc::new(ClientHub::new()),
+            CancellationToken::new(),
+            Uuid::new_v4(),
+            None,
+        );
+
+        runtime.run_validation_phases().await.unwrap();
+        assert!(sys.inited.load(Ordering::SeqCst), "init should run");
+        assert!(
+            !sys.post_inited.load(Ordering::SeqCst),
+            "validation must not run post_init"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validate_only_reports_missing_rest_host_without_starting() {
+        use crate::contracts::{OpenApiRegistry, RestApiCapability};
+        use std::sync::atomic::AtomicBool;
+
+        #[derive(Default)]
+        struct RestWithoutHost {
+            inited: AtomicBool,
+            started: AtomicBool,
+        }
+
+        #[async_trait::async_trait]
+        impl Module for RestWithoutHost {
+            async fn init(&self, _ctx: &ModuleCtx) -> anyhow::Result<()> {
+                self.inited.store(true, Ordering::SeqCst);
+                Ok(())
+            }
+        }
+
+        impl RestApiCapability for RestWithoutHost {
+            fn register_rest(
+                &self,
+                _ctx: &ModuleCtx,
+                router: Router,
+                _openapi: &dyn OpenApiRegistry,
+            ) -> anyhow::Result<Router> {
+                Ok(router)
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl RunnableCapability for RestWithoutHost {
+            async fn start(&self, _cancel: CancellationToken) -> anyhow::Result<()> {
+                // A real module would bind its listener here
+                self.started.store(true, Ordering::SeqCst);
+                Ok(())
+            }
+            async fn stop(&self, _cancel: CancellationToken) -> anyhow::Result<()> {
+                Ok(())
+            }
+        }
+
+        let module = Arc::new(RestWithoutHost::default());
+        let mut builder = RegistryBuilder::default();
+        builder.register_core_with_meta("api", &[], module.clone() as Arc<dyn Module>);
+        builder.register_rest_with_meta("api", module.clone() as Arc<dyn RestApiCapability>);
+        builder.register_stateful_with_meta("api", module.clone() as Arc<dyn RunnableCapability>);
+        let registry = builder.build_topo_sorted().unwrap();
+
+        let runtime = HostRuntime::new(
+            registry,
+            Arc::new(EmptyConfigProvider),
+            DbOptions::None,
+            Arc::new(ClientHub::new()),
+            CancellationToken::new(),
+            Uuid::new_v4(),
+            None,
+        );
+
+        let err = runtime.run_validation_phases().await.unwrap_err();
+        assert!(
+            matches!(
+                err.downcast_ref::<RegistryError>(),
+                Some(RegistryError::RestRequiresHost)
+            ),
+            "expected RestRequiresHost, got {err:?}"
+        );
+        assert!(module.inited.load(Ordering::SeqCst), "init should run");
+        assert!(
+            !module.started.load(Ordering::SeqCst),
+            "validation must not start modules"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rest_phase_rejects_duplicate_route_across_modules() {
+        use crate::api::operation_builder::{Missing, OperationBuilder};
+        use crate::api::{OpenApiRegistry, OpenApiRegistryImpl};
+        use crate::contracts::{ApiGatewayCapability, RestApiCapability};
+
+        #[derive(Default)]
+        struct Host {
+            registry: OpenApiRegistryImpl,
+        }
+
+        #[async_trait::async_trait]
+        impl Module for Host {
+            async fn init(&self, _ctx: &ModuleCtx) -> anyhow::Result<()> {
+                Ok(())
+            }
+        }
+
+        impl ApiGatewayCapability for Host {
+            fn rest_prepare(&self, _ctx: &ModuleCtx, router: Router) -> anyhow::Result<Router> {
+                Ok(router)
+            }
+            fn rest_finalize(&self, _ctx: &ModuleCtx, router: Router) -> anyhow::Result<Router> {
+                Ok(router)
+            }
+            fn as_registry(&self) -> &dyn OpenApiRegistry {
+                &self.registry
+            }
+        }
+
+        struct FooApi;
+
+        #[async_trait::async_trait]
+        impl Module for FooApi {
+            async fn init(&self, _ctx: &ModuleCtx) -> anyhow::Result<()> {
+                Ok(())
+            }
+        }
+
+        impl RestApiCapability for FooApi {
+            fn register_rest(
+                &self,
+                _ctx: &ModuleCtx,
+                router: Router,
+                openapi: &dyn OpenApiRegistry,
+            ) -> anyhow::Result<Router> {
+                async fn foo() -> axum::Json<serde_json::Value> {
+                    axum::Json(serde_json::json!({}))
+                }
+                Ok(OperationBuilder::<Missing, Missing, ()>::get("/api/foo")
+                    .public()
+                    .handler(foo)
+                    .json_response(http::StatusCode::OK, "Foo")
+                    .register(router, openapi))
+            }
+        }
+
+        let host = Arc::new(Host::default());
+        let mut builder = RegistryBuilder::default();
+        builder.register_core_with_meta("host", &[], host.clone() as Arc<dyn Module>);
+        builder.register_rest_host_with_meta("host", host as Arc<dyn ApiGatewayCapability>);
+        for name in ["first", "second"] {
+            builder.register_core_with_meta(name, &["host"], Arc::new(FooApi));
+            builder.register_rest_with_meta(name, Arc::new(FooApi));
+        }
+        let registry = builder.build_topo_sorted().unwrap();
+
+        let runtime = HostRuntime::new(
+            registry,
+            Arc::new(EmptyConfigProvider),
+            DbOptions::None,
+            Arc::new(ClientHub::new()),
+            CancellationToken::new(),
+            Uuid::new_v4(),
+            None,
+        );
+
+        let err = runtime.run_rest_phase().await.unwrap_err();
+        let RegistryError::RestRegister { module, source } = err else {
+            panic!("expected RestRegister error, got {err:?}");
+        };
+        let (owner, duplicate) = if module == "second" {
+            ("first", "second")
+        } else {
+            ("second", "first")
+        };
+        assert_eq!(module, duplicate);
+        assert_eq!(
+            source.to_string(),
+            format!(
+                "duplicate REST route GET /api/foo: registered by module '{owner}' and module '{duplicate}'"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stop_phase_skips_module_exceeding_stop_timeout() {
+        use std::time::Duration;
+
+        struct HangingModule;
+
+        #[async_trait::async_trait]
+        impl Module for HangingModule {
+            async fn init(&self, _ctx: &ModuleCtx) -> anyhow::Result<()> {
+                Ok(())
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl RunnableCapability for HangingModule {
+            async fn start(&self, _cancel: CancellationToken) -> anyhow::Result<()> {
+                Ok(())
+            }
+            async fn stop(&self, _deadline_token: CancellationToken) -> anyhow::Result<()> {
+                // Ignores the hard-stop signal entirely
+                tokio::time::sleep(Duration::from_secs(30)).await;
+                Ok(())
+            }
+        }
+
+        let counter = Arc::new(AtomicUsize::new(0));
+        let stop_order = Arc::new(AtomicUsize::new(0));
+        let tracker = Arc::new(StopOrderTracker::new(&counter, stop_order.clone()));
+
+        // "hanging" stops first (reverse order), "a" must still be stopped afterwards
+        let mut builder = RegistryBuilder::default();
+        builder.register_core_with_meta("a", &[], tracker.clone() as Arc<dyn Module>);
+        builder.register_core_with_meta("hanging", &["a"], Arc::new(HangingModule));
+        builder.register_stateful_with_meta("a", tracker as Arc<dyn RunnableCapability>);
+        builder.register_stateful_with_meta("hanging", Arc::new(HangingModule));
+        let registry = builder.build_topo_sorted().unwrap();
+
+        let runtime = HostRuntime::new(
+            registry,
+            Arc::new(EmptyConfigProvider),
+            DbOptions::None,
+            Arc::new(ClientHub::new()),
+            CancellationToken::new(),
+            Uuid::new_v4(),
+            None,
+        )
+        .with_shutdown_deadline(Duration::from_millis(20))
+        .with_stop_timeout(Duration::from_millis(100));
+
+        tokio::time::timeout(Duration::from_secs(5), runtime.run_stop_phase())
+            .await
+            .expect("stop phase must not hang on a stuck module")
+            .unwrap();
+
+        assert_eq!(
+            stop_order.load(Ordering::SeqCst),
+            1,
+            "modules after the stuck one should still be stopped"
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_oop_spawn_waits_for_slow_grpc_hub_with_longer_timeout() {
+        use crate::backends::OopBackend;
+        use crate::contracts::GrpcHubCapability;
+        use crate::runtime::OopModuleSpawnConfig;
+        use std::time::Duration;
+
+        /// Hub that binds only once `bind_at` is reached on the (paused) tokio clock.
+        struct SlowHub {
+            bind_at: tokio::time::Instant,
+        }
+
+        #[async_trait::async_trait]
+        impl Module for SlowHub {
+            async fn init(&self, _ctx: &ModuleCtx) -> anyhow::Result<()> {
+                Ok(())
+            }
+        }
+
+        impl GrpcHubCapability for SlowHub {
+            fn bound_endpoint(&self) -> Option<String> {
+                (tokio::time::Instant::now() >= self.bind_at)
+                    .then(|| "http://127.0.0.1:50051".to_owned())
+            }
+        }
+
+        struct RecordingBackend(Arc<std::sync::Mutex<Vec<Option<String>>>>);
+
+        #[async_trait::async_trait]
+        impl OopBackend for RecordingBackend {
+            async fn spawn(&self, config: OopSpawnConfig) -> anyhow::Result<()> {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push(config.env.get(MODKIT_DIRECTORY_ENDPOINT_ENV).cloned());
+                Ok(())
+            }
+            async fn shutdown_all(&self) {}
+        }
+
+        // Binds after the old hardcoded 5s limit
+        let hub = Arc::new(SlowHub {
+            bind_at: tokio::time::Instant::now() + Duration::from_secs(7),
+        });
+        let mut builder = RegistryBuilder::default();
+        builder.register_core_with_meta("grpc-hub", &[], hub.clone() as Arc<dyn Module>);
+        builder.register_grpc_hub_with_meta("grpc-hub", hub as Arc<dyn GrpcHubCapability>);
+        let registry = builder.build_topo_sorted().unwrap();
+
+        let spawned = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let runtime = HostRuntime::new(
+            registry,
+            Arc::new(EmptyConfigProvider),
+            DbOptions::None,
+            Arc::new(ClientHub::new()),
+            CancellationToken::new(),
+            Uuid::new_v4(),
+            Some(OopSpawnOptions {
+                modules: vec![OopModuleSpawnConfig {
+                    module_name: "calculator".to_owned(),
+                    binary: std::env::current_exe().unwrap(),
+                    args: Vec::new(),
+                    env: std::collections::HashMap::new(),
+                    working_directory: None,
+                    rendered_config_json: "{}".to_owned(),
+                    restart_policy: crate::backends::RestartPolicy::Never,
+                    container: None,
+                }],
+                backend: Box::new(RecordingBackend(spawned.clone())),
+                container_backend: None,
+                hub_wait_timeout: Duration::from_secs(10),
+                hub_poll_interval: Duration::from_millis(100),
+            }),
+        );
+
+        runtime.run_oop_spawn_phase().await.unwrap();
+
+        assert_eq!(
+            *spawned.lock().unwrap(),
+            vec![Some("http://127.0.0.1:50051".to_owned())]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_oop_spawn_phase_rejects_missing_executable() {
+        use crate::backends::OopBackend;
+        use crate::runtime::OopModuleSpawnConfig;
+
+        struct CountingBackend(Arc<AtomicUsize>);
+
+        #[async_trait::async_trait]
+        impl OopBackend for CountingBackend {
+            async fn spawn(&self, _config: OopSpawnConfig) -> anyhow::Result<()> {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+            async fn shutdown_all(&self) {}
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let missing = dir.path().join("no-such-module");
+        let spawns = Arc::new(AtomicUsize::new(0));
+
+        let registry = RegistryBuilder::default().build_topo_sorted().unwrap();
+        let config_provider: Arc<dyn ConfigProvider> = Arc::new(EmptyConfigProvider);
+        let runtime = HostRuntime::new(
+            registry,
+            config_provider,
+            DbOptions::None,
+            Arc::new(ClientHub::new()),
+            CancellationToken::new(),
+            Uuid::new_v4(),
+            Some(OopSpawnOptions {
+                modules: vec![OopModuleSpawnConfig {
+                    module_name: "calculator".to_owned(),
+                    binary: missing.clone(),
+                    args: Vec::new(),
+                    env: std::collections::HashMap::new(),
+                    working_directory: None,
+                    rendered_config_json: "{}".to_owned(),
+                    restart_policy: crate::backends::RestartPolicy::Never,
+                    container: None,
+                }],
+                backend: Box::new(CountingBackend(spawns.clone())),
+                container_backend: None,
+                hub_wait_timeout: crate::runtime::DEFAULT_GRPC_HUB_WAIT_TIMEOUT,
+                hub_poll_interval: crate::runtime::DEFAULT_GRPC_HUB_POLL_INTERVAL,
+            }),
+        );
+
+        let err = runtime.run_oop_spawn_phase().await.unwrap_err();
+        let RegistryError::OopSpawn { module, source } = err else {
+            panic!("expected OopSpawn error, got {err:?}");
+        };
+        assert_eq!(module, "calculator");
+        assert_eq!(
+            source.to_string(),
+            format!("executable not found: {}", missing.display())
+        );
+        assert_eq!(
+            spawns.load(Ordering::SeqCst),
+            0,
+            "backend must not be called"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_observer_sees_phases_and_module_inits_in_order() {
+        struct RecordingObserver {
+            events: parking_lot::Mutex<Vec<String>>,
+            cancel: CancellationToken,
+        }
+
+        impl LifecycleObserver for RecordingObserver {
+            fn on_phase_start(&self, phase: LifecyclePhase) {
+                self.events.lock().push(format!("start:{}", phase.as_str()));
+            }
+            fn on_module_init(&self, module: &'static str, _duration: std::time::Duration) {
+                self.events.lock().push(format!("module_init:{module}"));
+            }
+            fn on_phase_complete(&self, phase: LifecyclePhase) {
+                self.events
+                    .lock()
+                    .push(format!("complete:{}", phase.as_str()));
+                // Request shutdown once everything is up so the stop phase runs
+                if phase == LifecyclePhase::OopSpawn {
+                    self.cancel.cancel();
+                }
+            }
+        }
+
+        let mut builder = RegistryBuilder::default();
+        builder.register_core_with_meta("base", &[], Arc::new(DummyCore) as Arc<dyn Module>);
+        builder.register_core_with_meta("app", &["base"], Arc::new(DummyCore) as Arc<dyn Module>);
+        let registry = builder.build_topo_sorted().unwrap();
+
+        let cancel = CancellationToken::new();
+        let observer = Arc::new(RecordingObserver {
+            events: parking_lot::Mutex::new(Vec::new()),
+            cancel: cancel.clone(),
+        });
+        let runtime = HostRuntime::new(
+            registry,
+            Arc::new(EmptyConfigProvider),
+            DbOptions::None,
+            Arc::new(ClientHub::new()),
+            cancel,
+            Uuid::new_v4(),
+            None,
+        )
+        .with_observer(observer.clone());
+
+        runtime.run_module_phases().await.unwrap();
+
+        let mut expected = vec!["start:pre_init", "complete:pre_init"];
+        if cfg!(feature = "db") {
+            expected.extend(["start:db_migration", "complete:db_migration"]);
+        }
+        expected.extend([
+            "start:init",
+            "module_init:base",
+            "module_init:app",
+            "complete:init",
+            "start:post_init",
+            "complete:post_init",
+            "start:rest",
+            "complete:rest",
+            "start:grpc",
+            "complete:grpc",
+            "start:start",
+            "complete:start",
+            "start:oop_spawn",
+            "complete:oop_spawn",
+            "start:stop",
+            "complete:stop",
+        ]);
+        assert_eq!(*observer.events.lock(), expected);
+    }
+
+    #[tokio::test]
+    async fn test_startup_report_records_module_and_phase_timings() {
+        use crate::runtime::StartupReportRecorder;
+        use std::time::Duration;
+
+        struct SlowModule;
+
+        #[async_trait::async_trait]
+        impl Module for SlowModule {
+            async fn init(&self, _ctx: &ModuleCtx) -> anyhow::Result<()> {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                Ok(())
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl RunnableCapability for SlowModule {
+            async fn start(&self, _cancel: CancellationToken) -> anyhow::Result<()> {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                Ok(())
+            }
+            async fn stop(&self, _cancel: CancellationToken) -> anyhow::Result<()> {
+                Ok(())
+            }
+        }
+
+        let slow = Arc::new(SlowModule);
+        let mut builder = RegistryBuilder::default();
+        builder.register_core_with_meta("plain", &[], Arc::new(DummyCore) as Arc<dyn Module>);
+        builder.register_core_with_meta("slow", &["plain"], slow.clone() as Arc<dyn Module>);
+        builder.register_stateful_with_meta("slow", slow as Arc<dyn RunnableCapability>);
+        let registry = builder.build_topo_sorted().unwrap();
+
+        let recorder = Arc::new(StartupReportRecorder::new());
+        let cancel = CancellationToken::new();
+        let runtime = HostRuntime::new(
+            registry,
+            Arc::new(EmptyConfigProvider),
+            DbOptions::None,
+            Arc::new(ClientHub::new()),
+            cancel.clone(),
+            Uuid::new_v4(),
+            None,
+        )
+        .with_observer(recorder.clone());
+
+        let stopper = {
+            let recorder = recorder.clone();
+            tokio::spawn(async move {
+                while recorder.report().phase(LifecyclePhase::OopSpawn).is_none() {
+                    tokio::time::sleep(Duration::from_millis(5)).await;
+                }
+                cancel.cancel();
+            })
+        };
+        runtime.run_module_phases().await.unwrap();
+        stopper.await.unwrap();
+
+        let report = recorder.report();
+        let names: Vec<_> = report.modules.iter().map(|m| m.module).collect();
+        assert_eq!(names, ["plain", "slow"]);
+
+        let plain = report.module("plain").unwrap();
+        assert!(plain.init.is_some());
+        assert_eq!(plain.start, None, "plain is not runnable");
+
+        let slow = report.module("slow").unwrap();
+        assert!(slow.init.unwrap() >= Duration::from_millis(20));
+        assert!(slow.start.unwrap() >= Duration::from_millis(20));
+
+        let init = report.phase(LifecyclePhase::Init).unwrap();
+        assert!(init >= slow.init.unwrap());
+        assert!(report.phase(LifecyclePhase::Start).unwrap() >= slow.start.unwrap());
+        assert!(init < Duration::from_secs(5));
+        assert!(report.phase(LifecyclePhase::Stop).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_stop_phase_deregisters_module_instances() {
+        use crate::directory::{DirectoryClient, LocalDirectoryClient};
+        use crate::runtime::{Endpoint, ModuleInstance};
+
+        let mut builder = RegistryBuilder::default();
+        builder.register_core_with_meta("svc", &[], Arc::new(DummyCore) as Arc<dyn Module>);
+        let registry = builder.build_topo_sorted().unwrap();
+
+        let runtime = HostRuntime::new(
+            registry,
+            Arc::new(EmptyConfigProvider),
+            DbOptions::None,
+            Arc::new(ClientHub::new()),
+            CancellationToken::new(),
+            Uuid::new_v4(),
+            None,
+        );
+
+        let directory = LocalDirectoryClient::new(Arc::clone(&runtime.module_manager));
+        runtime.module_manager.register_instance(Arc::new(
+            ModuleInstance::new("svc", runtime.instance_id)
+                .with_grpc_service("svc.Service", Endpoint::http("127.0.0.1", 8001)),
+        ));
+        assert_eq!(directory.list_instances("svc").await.unwrap().len(), 1);
+
+        runtime.run_stop_phase().await.unwrap();
+
+        assert!(directory.list_instances("svc").await.unwrap().is_empty());
+        assert!(directory.resolve_grpc_service("svc.Service").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_stop_phase_keeps_sibling_instances_registered() {
+        use crate::runtime::{Endpoint, ModuleInstance};
+
+        let mut builder = RegistryBuilder::default();
+        builder.register_core_with_meta("svc", &[], Arc::new(DummyCore) as Arc<dyn Module>);
+        let registry = builder.build_topo_sorted().unwrap();
+
+        let runtime = HostRuntime::new(
+            registry,
+            Arc::new(EmptyConfigProvider),
+            DbOptions::None,
+            Arc::new(ClientHub::new()),
+            CancellationToken::new(),
+            Uuid::new_v4(),
+            None,
+        );
+
+        let sibling = Uuid::new_v4();
+        for id in [runtime.instance_id, sibling] {
+            runtime.module_manager.register_instance(Arc::new(
+                ModuleInstance::new("svc", id)
+                    .with_grpc_service("svc.Service", Endpoint::http("127.0.0.1", 8001)),
+            ));
+        }
+
+        runtime.run_stop_phase().await.unwrap();
+
+        let remaining = runtime.module_manager.instances_of("svc");
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].instance_id, sibling);
+    }
 }
@@ -1,6 +1,8 @@
 mod grpc_installers;
 mod host_runtime;
 mod module_manager;
+mod observer;
+mod route_guard;
 mod runner;
 mod system_context;
 
@@ -12,11 +14,16 @@ mod tests;
 
 pub use grpc_installers::{GrpcInstallerData, GrpcInstallerStore, ModuleInstallers};
 pub use host_runtime::{
-    DEFAULT_SHUTDOWN_DEADLINE, DbOptions, HostRuntime, MODKIT_DIRECTORY_ENDPOINT_ENV,
-    MODKIT_MODULE_CONFIG_ENV,
+    DEFAULT_SHUTDOWN_DEADLINE, DEFAULT_STOP_TIMEOUT, DbOptions, HostRuntime,
+    MODKIT_DIRECTORY_ENDPOINT_ENV, MODKIT_MODULE_CONFIG_ENV, RunMode,
 };
 pub use module_manager::{Endpoint, InstanceState, ModuleInstance, ModuleManager};
+pub use observer::{
+    LifecycleObserver, LifecyclePhase, ModuleTiming, NoopLifecycleObserver, PhaseTiming,
+    StartupReport, StartupReportRecorder,
+};
 pub use runner::{
-    ClientRegistration, OopModuleSpawnConfig, OopSpawnOptions, RunOptions, ShutdownOptions, run,
+    ClientRegistration, DEFAULT_GRPC_HUB_POLL_INTERVAL, DEFAULT_GRPC_HUB_WAIT_TIMEOUT,
+    OopModuleSpawnConfig, OopSpawnOptions, RunOptions, ShutdownOptions, run,
 };
 pub use system_context::SystemContext;
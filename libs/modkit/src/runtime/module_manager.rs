@@ -3,9 +3,14 @@
 use dashmap::DashMap;
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
+use cf_system_sdks::directory::DEFAULT_INSTANCE_WEIGHT;
+
 /// Represents an endpoint where a module instance can be reached
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Endpoint {
@@ -92,7 +97,14 @@ pub struct ModuleInstance {
     pub instance_id: Uuid,
     pub control: Option<Endpoint>,
     pub grpc_services: HashMap<String, Endpoint>,
+    /// Named endpoints (e.g. `"grpc"`, `"http"`) reported to discovery clients.
+    pub endpoints: HashMap<String, Endpoint>,
     pub version: Option<String>,
+    /// Evict the instance if no heartbeat arrives within this period.
+    /// `None` keeps it until it is deregistered.
+    pub heartbeat_ttl: Option<Duration>,
+    /// Relative weight for weighted client-side selection (at least 1).
+    pub weight: u32,
     inner: Arc<parking_lot::RwLock<InstanceRuntimeState>>,
 }
 
@@ -103,7 +115,10 @@ impl Clone for ModuleInstance {
             instance_id: self.instance_id,
             control: self.control.clone(),
             grpc_services: self.grpc_services.clone(),
+            endpoints: self.endpoints.clone(),
             version: self.version.clone(),
+            heartbeat_ttl: self.heartbeat_ttl,
+            weight: self.weight,
             inner: Arc::clone(&self.inner),
         }
     }
@@ -116,7 +131,10 @@ impl ModuleInstance {
             instance_id,
             control: None,
             grpc_services: HashMap::new(),
+            endpoints: HashMap::new(),
             version: None,
+            heartbeat_ttl: None,
+            weight: DEFAULT_INSTANCE_WEIGHT,
             inner: Arc::new(parking_lot::RwLock::new(InstanceRuntimeState {
                 last_heartbeat: Instant::now(),
                 state: InstanceState::Registered,
@@ -139,6 +157,28 @@ impl ModuleInstance {
         self
     }
 
+    pub fn with_endpoint(mut self, name: impl Into<String>, ep: Endpoint) -> Self {
+        self.endpoints.insert(name.into(), ep);
+        self
+    }
+
+    /// Look up a named endpoint (e.g. `"http"`).
+    #[must_use]
+    pub fn endpoint(&self, name: &str) -> Option<&Endpoint> {
+        self.endpoints.get(name)
+    }
+
+    pub fn with_heartbeat_ttl(mut self, ttl: Duration) -> Self {
+        self.heartbeat_ttl = Some(ttl);
+        self
+    }
+
+    /// Set the selection weight; clamped to at least 1.
+    pub fn with_weight(mut self, weight: u32) -> Self {
+        self.weight = weight.max(1);
+        self
+    }
+
     /// Get the current state of this instance
     #[must_use]
     pub fn state(&self) -> InstanceState {
@@ -150,8 +190,22 @@ impl ModuleInstance {
     pub fn last_heartbeat(&self) -> Instant {
         self.inner.read().last_heartbeat
     }
+
+    /// Whether the instance can serve traffic at `now`: ready or healthy, and
+    /// its own `heartbeat_ttl` (if any) has not lapsed.
+    #[must_use]
+    pub fn is_available(&self, now: Instant) -> bool {
+        let state = self.inner.read();
+        matches!(state.state, InstanceState::Healthy | InstanceState::Ready)
+            && self
+                .heartbeat_ttl
+                .is_none_or(|ttl| now.saturating_duration_since(state.last_heartbeat) < ttl)
+    }
 }
 
+/// Buffered change notifications per subscriber before it starts lagging.
+const CHANGES_CAPACITY: usize = 256;
+
 /// Central registry that tracks all running module instances in the system.
 /// Provides discovery, health tracking, and round-robin load balancing.
 #[derive(Clone)]
@@ -161,6 +215,8 @@ pub struct ModuleManager {
     rr_counters: DashMap<String, usize>,
     hb_ttl: Duration,
     hb_grace: Duration,
+    /// Names of modules whose instance set changed
+    changes: broadcast::Sender<String>,
 }
 
 impl std::fmt::Debug for ModuleManager {
@@ -182,6 +238,7 @@ impl ModuleManager {
             rr_counters: DashMap::new(),
             hb_ttl: Duration::from_secs(15),
             hb_grace: Duration::from_secs(30),
+            changes: broadcast::channel(CHANGES_CAPACITY).0,
         }
     }
 
@@ -191,19 +248,36 @@ impl ModuleManager {
         self
     }
 
+    /// Subscribe to instance set changes.
+    ///
+    /// Receives the module name each time one of its instances registers,
+    /// deregisters or is evicted. A lagging receiver gets `RecvError::Lagged`.
+    #[must_use]
+    pub fn subscribe(&self) -> broadcast::Receiver<String> {
+        self.changes.subscribe()
+    }
+
+    fn notify_changed(&self, module: &str) {
+        // Having no subscribers is not an error
+        self.changes.send(module.to_owned()).ok();
+    }
+
     /// Register or update a module instance
     pub fn register_instance(&self, instance: Arc<ModuleInstance>) {
         let module = instance.module.clone();
-        let mut vec = self.inner.entry(module).or_default();
-        // replace by instance_id if it already exists
-        if let Some(pos) = vec
-            .iter()
-            .position(|i| i.instance_id == instance.instance_id)
         {
-            vec[pos] = instance;
-        } else {
-            vec.push(instance);
+            let mut vec = self.inner.entry(module.clone()).or_default();
+            // replace by instance_id if it already exists
+            if let Some(pos) = vec
+                .iter()
+                .position(|i| i.instance_id == instance.instance_id)
+            {
+                vec[pos] = instance;
+            } else {
+                vec.push(instance);
+            }
         }
+        self.notify_changed(&module);
     }
 
     /// Mark an instance as ready
@@ -248,13 +322,19 @@ impl ModuleManager {
         }
     }
 
-    /// Remove an instance from the directory
-    pub fn deregister(&self, module: &str, instance_id: Uuid) {
+    /// Remove an instance from the directory.
+    ///
+    /// Returns `true` if the instance was registered.
+    #[must_use]
+    pub fn deregister(&self, module: &str, instance_id: Uuid) -> bool {
+        let mut removed = false;
         let mut remove_module = false;
         {
             if let Some(mut vec) = self.inner.get_mut(module) {
                 let list = vec.value_mut();
+                let before = list.len();
                 list.retain(|inst| inst.instance_id != instance_id);
+                removed = list.len() != before;
                 if list.is_empty() {
                     remove_module = true;
                 }
@@ -265,6 +345,26 @@ impl ModuleManager {
             self.inner.remove(module);
             self.rr_counters.remove(module);
         }
+        if removed {
+            self.notify_changed(module);
+        }
+        removed
+    }
+
+    /// Remove every instance of `module` from the directory.
+    ///
+    /// Returns the number of instances removed.
+    #[must_use]
+    pub fn deregister_module(&self, module: &str) -> usize {
+        self.rr_counters.remove(module);
+        let removed = self
+            .inner
+            .remove(module)
+            .map_or(0, |(_, instances)| instances.len());
+        if removed > 0 {
+            self.notify_changed(module);
+        }
+        removed
     }
 
     /// Get all instances of a specific module
@@ -285,14 +385,81 @@ impl ModuleManager {
             .collect()
     }
 
+    /// Remove instances whose own `heartbeat_ttl` lapsed without a heartbeat.
+    ///
+    /// Instances registered without a TTL are left alone. Returns the number of
+    /// instances removed.
+    #[must_use]
+    pub fn evict_expired(&self, now: Instant) -> usize {
+        let mut removed = 0;
+        let mut changed_modules = Vec::new();
+        let mut empty_modules = Vec::new();
+
+        for mut entry in self.inner.iter_mut() {
+            let module = entry.key().clone();
+            let vec = entry.value_mut();
+            let before = vec.len();
+            vec.retain(|inst| {
+                let expired = inst
+                    .heartbeat_ttl
+                    .is_some_and(|ttl| now.saturating_duration_since(inst.last_heartbeat()) >= ttl);
+                if expired {
+                    tracing::debug!(
+                        module = %inst.module,
+                        instance_id = %inst.instance_id,
+                        "Evicting instance: heartbeat TTL lapsed"
+                    );
+                    removed += 1;
+                }
+                !expired
+            });
+
+            if vec.len() != before {
+                if vec.is_empty() {
+                    empty_modules.push(module.clone());
+                }
+                changed_modules.push(module);
+            }
+        }
+
+        for module in empty_modules {
+            self.inner.remove(&module);
+            self.rr_counters.remove(&module);
+        }
+        for module in changed_modules {
+            self.notify_changed(&module);
+        }
+
+        removed
+    }
+
+    /// Run `evict_expired` every `interval` until `cancel` fires.
+    pub async fn reap_expired(&self, interval: Duration, cancel: CancellationToken) {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                () = cancel.cancelled() => break,
+                _ = ticker.tick() => {
+                    let evicted = self.evict_expired(Instant::now());
+                    if evicted > 0 {
+                        tracing::info!(evicted, "Evicted instances with lapsed heartbeat TTL");
+                    }
+                }
+            }
+        }
+    }
+
     /// Quarantine or evict stale instances based on heartbeat policy
     pub fn evict_stale(&self, now: Instant) {
         use InstanceState::{Draining, Quarantined};
+        let mut changed_modules = Vec::new();
         let mut empty_modules = Vec::new();
 
         for mut entry in self.inner.iter_mut() {
             let module = entry.key().clone();
             let vec = entry.value_mut();
+            let before = vec.len();
             vec.retain(|inst| {
                 let state = inst.inner.read();
                 let age = now.saturating_duration_since(state.last_heartbeat);
@@ -312,8 +479,11 @@ impl ModuleManager {
                 true
             });
 
-            if vec.is_empty() {
-                empty_modules.push(module);
+            if vec.len() != before {
+                if vec.is_empty() {
+                    empty_modules.push(module.clone());
+                }
+                changed_modules.push(module);
             }
         }
 
@@ -321,6 +491,9 @@ impl ModuleManager {
             self.inner.remove(&module);
             self.rr_counters.remove(&module);
         }
+        for module in changed_modules {
+            self.notify_changed(&module);
+        }
     }
 
     /// Pick an instance using round-robin selection, preferring healthy instances
@@ -659,6 +832,78 @@ mod tests {
         assert!(instances_after.is_empty());
     }
 
+    #[test]
+    fn test_deregister_module_removes_all_instances() {
+        let dir = ModuleManager::new();
+        for _ in 0..2 {
+            dir.register_instance(Arc::new(
+                ModuleInstance::new("svc", Uuid::new_v4())
+                    .with_grpc_service("svc.Service", Endpoint::http("127.0.0.1", 8001)),
+            ));
+        }
+        dir.register_instance(Arc::new(ModuleInstance::new("other", Uuid::new_v4())));
+
+        assert_eq!(dir.deregister_module("svc"), 2);
+        assert!(dir.instances_of("svc").is_empty());
+        assert!(dir.pick_service_round_robin("svc.Service").is_none());
+        assert_eq!(dir.instances_of("other").len(), 1);
+        assert_eq!(dir.deregister_module("svc"), 0);
+    }
+
+    #[test]
+    fn test_evict_expired_respects_instance_ttl() {
+        let ttl = Duration::from_millis(50);
+        let dir = ModuleManager::new();
+        let id = Uuid::new_v4();
+        dir.register_instance(Arc::new(
+            ModuleInstance::new("oop_module", id).with_heartbeat_ttl(ttl),
+        ));
+        dir.register_instance(Arc::new(ModuleInstance::new(
+            "local_module",
+            Uuid::new_v4(),
+        )));
+
+        let now = Instant::now();
+        dir.update_heartbeat("oop_module", id, now);
+        assert_eq!(dir.evict_expired(now + ttl / 2), 0);
+
+        // Heartbeats stopped: the TTL lapses and only the TTL'd instance goes away
+        assert_eq!(dir.evict_expired(now + ttl), 1);
+        assert!(dir.instances_of("oop_module").is_empty());
+        assert_eq!(dir.instances_of("local_module").len(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_reaper_evicts_instance_that_stops_heartbeating() {
+        let ttl = Duration::from_millis(100);
+        let dir = Arc::new(ModuleManager::new());
+        let id = Uuid::new_v4();
+        dir.register_instance(Arc::new(
+            ModuleInstance::new("oop_module", id).with_heartbeat_ttl(ttl),
+        ));
+
+        let cancel = CancellationToken::new();
+        let reaper = {
+            let dir = Arc::clone(&dir);
+            let cancel = cancel.clone();
+            tokio::spawn(async move { dir.reap_expired(Duration::from_millis(10), cancel).await })
+        };
+
+        // Heartbeating keeps the instance alive past several TTLs
+        for _ in 0..6 {
+            tokio::time::sleep(ttl / 2).await;
+            dir.update_heartbeat("oop_module", id, Instant::now());
+        }
+        assert_eq!(dir.instances_of("oop_module").len(), 1);
+
+        // Stop heartbeating
+        tokio::time::sleep(ttl * 3).await;
+        assert!(dir.instances_of("oop_module").is_empty());
+
+        cancel.cancel();
+        reaper.await.unwrap();
+    }
+
     #[test]
     fn test_instances_of_empty() {
         let dir = ModuleManager::new();
@@ -0,0 +1,163 @@
+//! Lifecycle Observer - callbacks for phase transitions in `HostRuntime`
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+
+/// Lifecycle phases reported to a [`LifecycleObserver`], in execution order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LifecyclePhase {
+    PreInit,
+    DbMigration,
+    Init,
+    PostInit,
+    Rest,
+    Grpc,
+    Start,
+    OopSpawn,
+    Stop,
+}
+
+impl LifecyclePhase {
+    /// Phase name as used in runtime logs.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::PreInit => "pre_init",
+            Self::DbMigration => "db_migration",
+            Self::Init => "init",
+            Self::PostInit => "post_init",
+            Self::Rest => "rest",
+            Self::Grpc => "grpc",
+            Self::Start => "start",
+            Self::OopSpawn => "oop_spawn",
+            Self::Stop => "stop",
+        }
+    }
+}
+
+/// Receives lifecycle events from `HostRuntime`.
+///
+/// Callbacks run inline on the runtime task and must not block. A phase that
+/// fails reports `on_phase_start` without a matching `on_phase_complete`.
+/// All methods default to no-ops.
+pub trait LifecycleObserver: Send + Sync {
+    /// A phase is about to run.
+    fn on_phase_start(&self, _phase: LifecyclePhase) {}
+
+    /// A module finished `init` successfully after `duration`.
+    fn on_module_init(&self, _module: &'static str, _duration: Duration) {}
+
+    /// A runnable module's `start` returned successfully after `duration`.
+    fn on_module_start(&self, _module: &'static str, _duration: Duration) {}
+
+    /// A phase finished successfully.
+    fn on_phase_complete(&self, _phase: LifecyclePhase) {}
+}
+
+/// Observer that ignores every event; the `HostRuntime` default.
+pub struct NoopLifecycleObserver;
+
+impl LifecycleObserver for NoopLifecycleObserver {}
+
+/// Wall-clock duration of one completed phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhaseTiming {
+    pub phase: LifecyclePhase,
+    pub duration: Duration,
+}
+
+/// `init`/`start` durations of one module; `start` is `None` for non-runnable modules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleTiming {
+    pub module: &'static str,
+    pub init: Option<Duration>,
+    pub start: Option<Duration>,
+}
+
+/// Startup timings collected by [`StartupReportRecorder`].
+///
+/// Phases and modules are listed in the order they were reported.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StartupReport {
+    pub phases: Vec<PhaseTiming>,
+    pub modules: Vec<ModuleTiming>,
+}
+
+impl StartupReport {
+    /// Duration of `phase`, if it completed.
+    #[must_use]
+    pub fn phase(&self, phase: LifecyclePhase) -> Option<Duration> {
+        self.phases
+            .iter()
+            .find(|p| p.phase == phase)
+            .map(|p| p.duration)
+    }
+
+    /// Timings of `module`, if it reported any.
+    #[must_use]
+    pub fn module(&self, module: &str) -> Option<&ModuleTiming> {
+        self.modules.iter().find(|m| m.module == module)
+    }
+
+    fn module_mut(&mut self, module: &'static str) -> &mut ModuleTiming {
+        let idx = if let Some(idx) = self.modules.iter().position(|m| m.module == module) {
+            idx
+        } else {
+            self.modules.push(ModuleTiming {
+                module,
+                init: None,
+                start: None,
+            });
+            self.modules.len() - 1
+        };
+        &mut self.modules[idx]
+    }
+}
+
+/// Observer that builds a [`StartupReport`] for diagnosing slow boots.
+///
+/// Install it with `HostRuntime::with_observer` and keep a clone of the `Arc`;
+/// [`report`](Self::report) can be read during or after `run_module_phases`.
+#[derive(Default)]
+pub struct StartupReportRecorder {
+    phase_started: Mutex<HashMap<LifecyclePhase, Instant>>,
+    report: Mutex<StartupReport>,
+}
+
+impl StartupReportRecorder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Snapshot of the timings recorded so far.
+    #[must_use]
+    pub fn report(&self) -> StartupReport {
+        self.report.lock().clone()
+    }
+}
+
+impl LifecycleObserver for StartupReportRecorder {
+    fn on_phase_start(&self, phase: LifecyclePhase) {
+        self.phase_started.lock().insert(phase, Instant::now());
+    }
+
+    fn on_module_init(&self, module: &'static str, duration: Duration) {
+        self.report.lock().module_mut(module).init = Some(duration);
+    }
+
+    fn on_module_start(&self, module: &'static str, duration: Duration) {
+        self.report.lock().module_mut(module).start = Some(duration);
+    }
+
+    fn on_phase_complete(&self, phase: LifecyclePhase) {
+        if let Some(started) = self.phase_started.lock().remove(&phase) {
+            self.report.lock().phases.push(PhaseTiming {
+                phase,
+                duration: started.elapsed(),
+            });
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Duplicate REST route detection for the REST phase.
+
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+
+use http::Method;
+use parking_lot::Mutex;
+use utoipa::openapi::RefOr;
+use utoipa::openapi::schema::Schema;
+
+use crate::api::OpenApiRegistry;
+use crate::api::operation_builder::OperationSpec;
+
+/// `OpenApiRegistry` wrapper that remembers which module registered each
+/// `METHOD path` and records a conflict when another registration repeats it.
+///
+/// Conflicting operations are neither routed nor forwarded to the wrapped registry.
+pub struct RouteConflictGuard<'a> {
+    inner: &'a dyn OpenApiRegistry,
+    current_module: Mutex<&'static str>,
+    owners: Mutex<HashMap<(Method, String), &'static str>>,
+    conflicts: Mutex<Vec<String>>,
+}
+
+impl<'a> RouteConflictGuard<'a> {
+    pub fn new(inner: &'a dyn OpenApiRegistry) -> Self {
+        Self {
+            inner,
+            current_module: Mutex::new(""),
+            owners: Mutex::new(HashMap::new()),
+            conflicts: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Attribute subsequent registrations to `module`.
+    pub fn enter_module(&self, module: &'static str) {
+        *self.current_module.lock() = module;
+    }
+
+    /// Conflicts recorded since the last call, one message per duplicate.
+    pub fn take_conflicts(&self) -> Vec<String> {
+        std::mem::take(&mut *self.conflicts.lock())
+    }
+}
+
+impl OpenApiRegistry for RouteConflictGuard<'_> {
+    fn register_operation(&self, spec: &OperationSpec) {
+        self.inner.register_operation(spec);
+    }
+
+    fn claim_route(&self, spec: &OperationSpec) -> bool {
+        let module = *self.current_module.lock();
+        match self
+            .owners
+            .lock()
+            .entry((spec.method.clone(), spec.path.clone()))
+        {
+            Entry::Occupied(owner) => {
+                self.conflicts.lock().push(format!(
+                    "duplicate REST route {} {}: registered by module '{}' and module '{module}'",
+                    spec.method,
+                    spec.path,
+                    owner.get()
+                ));
+                false
+            }
+            Entry::Vacant(slot) => {
+                slot.insert(module);
+                self.inner.claim_route(spec)
+            }
+        }
+    }
+
+    fn ensure_schema_raw(&self, name: &str, schemas: Vec<(String, RefOr<Schema>)>) -> String {
+        self.inner.ensure_schema_raw(name, schemas)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self.inner.as_any()
+    }
+}
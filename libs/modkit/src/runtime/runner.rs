@@ -14,14 +14,15 @@
 //! - `OoP` modules are spawned after the start phase so that `grpc-hub` is already running
 //!   and the real directory endpoint is known.
 
-use crate::backends::OopBackend;
+use crate::backends::{ContainerConfig, OopBackend, RestartPolicy};
 use crate::client_hub::ClientHub;
 use crate::config::ConfigProvider;
 use crate::registry::ModuleRegistry;
 use crate::runtime::shutdown;
-use crate::runtime::{DbOptions, HostRuntime};
+use crate::runtime::{DbOptions, HostRuntime, RunMode};
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::time::Duration;
 use std::{future::Future, pin::Pin, sync::Arc};
 use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
@@ -75,7 +76,7 @@ pub enum ShutdownOptions {
 pub struct OopModuleSpawnConfig {
     /// Module name (e.g., "calculator")
     pub module_name: String,
-    /// Path to the executable
+    /// Path to the executable (empty for container modules)
     pub binary: PathBuf,
     /// Command-line arguments (user controls --config via execution.args in master config)
     pub args: Vec<String>,
@@ -85,14 +86,31 @@ pub struct OopModuleSpawnConfig {
     pub working_directory: Option<String>,
     /// Rendered module config JSON (for `MODKIT_MODULE_CONFIG` env var)
     pub rendered_config_json: String,
+    /// Whether the backend restarts the process after it exits
+    pub restart_policy: RestartPolicy,
+    /// Container settings; when set the module is spawned by `container_backend`
+    pub container: Option<ContainerConfig>,
 }
 
+/// Default time to wait for `grpc-hub` to publish its endpoint before spawning `OoP` modules.
+pub const DEFAULT_GRPC_HUB_WAIT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Default interval between `grpc-hub` endpoint checks.
+pub const DEFAULT_GRPC_HUB_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
 /// Options for spawning `OoP` modules.
 pub struct OopSpawnOptions {
     /// List of `OoP` modules to spawn after the start phase
     pub modules: Vec<OopModuleSpawnConfig>,
     /// Backend for spawning `OoP` modules (e.g., `LocalProcessBackend`)
     pub backend: Box<dyn OopBackend>,
+    /// Backend for container modules (e.g., `DockerBackend`); required when any module sets `container`
+    pub container_backend: Option<Box<dyn OopBackend>>,
+    /// How long to wait for `grpc-hub` to bind; modules are spawned without
+    /// a directory endpoint once it expires (default: `DEFAULT_GRPC_HUB_WAIT_TIMEOUT`)
+    pub hub_wait_timeout: Duration,
+    /// How often to check whether `grpc-hub` has bound (default: `DEFAULT_GRPC_HUB_POLL_INTERVAL`)
+    pub hub_poll_interval: Duration,
 }
 
 /// Options for running the `ModKit` runner.
@@ -126,6 +144,16 @@ pub struct RunOptions {
     /// See `HostRuntime::with_shutdown_deadline` for details on the relationship
     /// with `WithLifecycle::stop_timeout`.
     pub shutdown_deadline: Option<std::time::Duration>,
+    /// Maximum time to wait for each module's `stop` before moving on.
+    ///
+    /// If `None`, uses `DEFAULT_STOP_TIMEOUT` (40 seconds).
+    pub stop_timeout: Option<std::time::Duration>,
+    /// Initialize modules without mutual dependencies concurrently.
+    ///
+    /// See `HostRuntime::with_parallel_init`.
+    pub parallel_init: bool,
+    /// Which lifecycle phases to run (`RunMode::Full` for a normal server).
+    pub mode: RunMode,
 }
 
 /// Full cycle is orchestrated by `HostRuntime` (see `runtime/host_runtime.rs` docs).
@@ -176,8 +204,9 @@ pub async fn run(opts: RunOptions) -> anyhow::Result<()> {
         }
     }
 
-    // 3. Discover modules
-    let registry = ModuleRegistry::discover_and_build()?;
+    // 3. Discover modules, skipping the ones disabled in configuration
+    let registry =
+        ModuleRegistry::discover_and_build_with(|name| opts.modules_cfg.is_module_enabled(name))?;
 
     // 4. Build shared ClientHub
     let hub = Arc::new(ClientHub::default());
@@ -198,11 +227,19 @@ pub async fn run(opts: RunOptions) -> anyhow::Result<()> {
         opts.oop,
     );
 
-    // 5b. Apply custom shutdown deadline if provided
+    // 5b. Apply init mode and custom shutdown deadline/stop timeout if provided
+    host = host.with_parallel_init(opts.parallel_init);
     if let Some(deadline) = opts.shutdown_deadline {
         host = host.with_shutdown_deadline(deadline);
     }
+    if let Some(timeout) = opts.stop_timeout {
+        host = host.with_stop_timeout(timeout);
+    }
 
-    // 6. Run full lifecycle
-    host.run_module_phases().await
+    // 6. Run the lifecycle phases selected by the mode
+    match opts.mode {
+        RunMode::Full => host.run_module_phases().await,
+        RunMode::MigrateOnly => host.run_migration_phases().await,
+        RunMode::ValidateOnly => host.run_validation_phases().await,
+    }
 }
@@ -15,7 +15,7 @@ use tokio_util::sync::CancellationToken;
 
 use modkit::{
     config::ConfigProvider,
-    runtime::{DbOptions, RunOptions, ShutdownOptions, run},
+    runtime::{DbOptions, RunMode, RunOptions, ShutdownOptions, run},
 };
 use uuid::Uuid;
 
@@ -94,6 +94,9 @@ async fn test_db_phase_with_manager_succeeds() {
         instance_id: Uuid::new_v4(),
         oop: None,
         shutdown_deadline: None,
+        stop_timeout: None,
+        parallel_init: false,
+        mode: RunMode::Full,
     };
 
     let result = timeout(Duration::from_millis(500), run(opts)).await;
@@ -125,6 +128,9 @@ async fn test_db_phase_without_config_skips_migration() {
         instance_id: Uuid::new_v4(),
         oop: None,
         shutdown_deadline: None,
+        stop_timeout: None,
+        parallel_init: false,
+        mode: RunMode::Full,
     };
 
     let result = timeout(Duration::from_millis(500), run(opts)).await;
@@ -156,6 +162,9 @@ async fn test_db_phase_with_none_option() {
         instance_id: Uuid::new_v4(),
         oop: None,
         shutdown_deadline: None,
+        stop_timeout: None,
+        parallel_init: false,
+        mode: RunMode::Full,
     };
 
     let result = timeout(Duration::from_millis(500), run(opts)).await;
@@ -209,6 +218,9 @@ async fn test_db_phase_error_propagation() {
         instance_id: Uuid::new_v4(),
         oop: None,
         shutdown_deadline: None,
+        stop_timeout: None,
+        parallel_init: false,
+        mode: RunMode::Full,
     };
 
     // Run should either succeed (if no modules try to use bad config)
@@ -236,6 +248,9 @@ async fn test_db_phase_completes_before_init() {
         instance_id: Uuid::new_v4(),
         oop: None,
         shutdown_deadline: None,
+        stop_timeout: None,
+        parallel_init: false,
+        mode: RunMode::Full,
     };
 
     let start = std::time::Instant::now();
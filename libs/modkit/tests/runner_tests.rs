@@ -21,7 +21,7 @@ use modkit::{
         DatabaseCapability, Module, OpenApiRegistry, RestApiCapability, RunnableCapability,
     },
     registry::{ModuleRegistry, RegistryBuilder},
-    runtime::{DbOptions, RunOptions, ShutdownOptions, run},
+    runtime::{DbOptions, RunMode, RunOptions, ShutdownOptions, run},
 };
 
 // Test tracking infrastructure
@@ -372,6 +372,9 @@ async fn test_db_options_none() {
         clients: vec![],
         oop: None,
         shutdown_deadline: None,
+        stop_timeout: None,
+        parallel_init: false,
+        mode: RunMode::Full,
     };
 
     // This test requires registry discovery to work, which won't work in isolation
@@ -382,6 +385,26 @@ async fn test_db_options_none() {
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn test_validate_only_mode_returns_without_cancellation() {
+    // The token is never cancelled: a full run would wait for shutdown forever.
+    let opts = RunOptions {
+        instance_id: Uuid::new_v4(),
+        modules_cfg: Arc::new(MockConfigProvider::new()),
+        db: DbOptions::None,
+        shutdown: ShutdownOptions::Token(CancellationToken::new()),
+        clients: vec![],
+        oop: None,
+        shutdown_deadline: None,
+        stop_timeout: None,
+        parallel_init: false,
+        mode: RunMode::ValidateOnly,
+    };
+
+    let result = timeout(Duration::from_secs(5), run(opts)).await;
+    assert!(result.is_ok(), "validation run must not wait for shutdown");
+}
+
 #[tokio::test]
 async fn test_db_options_manager() {
     let cancel = CancellationToken::new();
@@ -409,6 +432,9 @@ async fn test_db_options_manager() {
         clients: vec![],
         oop: None,
         shutdown_deadline: None,
+        stop_timeout: None,
+        parallel_init: false,
+        mode: RunMode::Full,
     };
 
     let result = timeout(Duration::from_millis(1000), run(opts)).await;
@@ -430,6 +456,9 @@ async fn test_shutdown_options_token() {
         clients: vec![],
         oop: None,
         shutdown_deadline: None,
+        stop_timeout: None,
+        parallel_init: false,
+        mode: RunMode::Full,
     };
 
     // Start the runner in a background task
@@ -462,6 +491,9 @@ async fn test_shutdown_options_future() {
         clients: vec![],
         oop: None,
         shutdown_deadline: None,
+        stop_timeout: None,
+        parallel_init: false,
+        mode: RunMode::Full,
     };
 
     // Start the runner in a background task
@@ -501,6 +533,9 @@ async fn test_runner_with_config_provider() {
         clients: vec![],
         oop: None,
         shutdown_deadline: None,
+        stop_timeout: None,
+        parallel_init: false,
+        mode: RunMode::Full,
     };
 
     let result = timeout(Duration::from_millis(100), run(opts)).await;
@@ -523,6 +558,9 @@ async fn test_complete_lifecycle_success() {
         clients: vec![],
         oop: None,
         shutdown_deadline: None,
+        stop_timeout: None,
+        parallel_init: false,
+        mode: RunMode::Full,
     };
 
     let result = run(opts).await;
@@ -541,6 +579,9 @@ fn test_run_options_construction() {
         clients: vec![],
         oop: None,
         shutdown_deadline: None,
+        stop_timeout: None,
+        parallel_init: false,
+        mode: RunMode::Full,
     };
 
     // Test that we can construct RunOptions with all variants
@@ -567,6 +608,9 @@ async fn test_cancellation_during_startup() {
         clients: vec![],
         oop: None,
         shutdown_deadline: None,
+        stop_timeout: None,
+        parallel_init: false,
+        mode: RunMode::Full,
     };
 
     // Start the runner in a background task
@@ -604,6 +648,9 @@ async fn test_multiple_config_provider_scenarios() {
         clients: vec![],
         oop: None,
         shutdown_deadline: None,
+        stop_timeout: None,
+        parallel_init: false,
+        mode: RunMode::Full,
     };
 
     let result = run(opts).await;
@@ -640,6 +687,9 @@ async fn test_multiple_config_provider_scenarios() {
         clients: vec![],
         oop: None,
         shutdown_deadline: None,
+        stop_timeout: None,
+        parallel_init: false,
+        mode: RunMode::Full,
     };
 
     let result2 = run(opts2).await;
@@ -659,6 +709,9 @@ async fn test_runner_timeout_scenarios() {
         clients: vec![],
         oop: None,
         shutdown_deadline: None,
+        stop_timeout: None,
+        parallel_init: false,
+        mode: RunMode::Full,
     };
 
     let runner_handle = tokio::spawn(run(opts));
@@ -2,8 +2,12 @@
 //!
 //! This module defines the core traits and types for the directory service API.
 
+use std::collections::HashMap;
+use std::time::Duration;
+
 use anyhow::Result;
 use async_trait::async_trait;
+use futures_core::stream::BoxStream;
 
 /// Represents an endpoint where a service can be reached
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -37,6 +41,9 @@ impl ServiceEndpoint {
     }
 }
 
+/// Weight of instances registered without an explicit weight
+pub const DEFAULT_INSTANCE_WEIGHT: u32 = 1;
+
 /// Information about a service instance
 #[derive(Debug, Clone)]
 pub struct ServiceInstanceInfo {
@@ -44,10 +51,24 @@ pub struct ServiceInstanceInfo {
     pub module: String,
     /// Unique instance identifier
     pub instance_id: String,
-    /// Primary endpoint for the instance
+    /// Primary (gRPC) endpoint for the instance; empty if it serves no gRPC
     pub endpoint: ServiceEndpoint,
     /// Optional version string
     pub version: Option<String>,
+    /// Relative weight for weighted selection (at least 1)
+    pub weight: u32,
+    /// Whether the instance is ready/healthy and its heartbeat TTL has not lapsed
+    pub healthy: bool,
+    /// Named endpoints of the instance (e.g. `"grpc"`, `"http"`)
+    pub endpoints: HashMap<String, ServiceEndpoint>,
+}
+
+impl ServiceInstanceInfo {
+    /// Look up a named endpoint of this instance (e.g. `"http"`)
+    #[must_use]
+    pub fn endpoint(&self, name: &str) -> Option<&ServiceEndpoint> {
+        self.endpoints.get(name)
+    }
 }
 
 /// Information for registering a new module instance
@@ -61,8 +82,18 @@ pub struct RegisterInstanceInfo {
     pub grpc_services: Vec<(String, ServiceEndpoint)>,
     /// Optional version string
     pub version: Option<String>,
+    /// Heartbeat TTL: the directory evicts the instance if no heartbeat arrives
+    /// within this period. `None` keeps it until it is deregistered.
+    pub ttl: Option<Duration>,
+    /// Relative weight for weighted selection; `None` uses [`DEFAULT_INSTANCE_WEIGHT`]
+    pub weight: Option<u32>,
+    /// Named endpoints of the instance (e.g. `"grpc"`, `"http"`)
+    pub endpoints: Vec<(String, ServiceEndpoint)>,
 }
 
+/// Stream of instance lists returned by [`DirectoryClient::watch`]
+pub type InstanceStream = BoxStream<'static, Vec<ServiceInstanceInfo>>;
+
 /// Directory API trait for service discovery and instance management
 ///
 /// This trait defines the contract for interacting with the module directory.
@@ -77,6 +108,11 @@ pub trait DirectoryClient: Send + Sync {
     /// List all service instances for a given module
     async fn list_instances(&self, module: &str) -> Result<Vec<ServiceInstanceInfo>>;
 
+    /// List all instances providing a gRPC service
+    ///
+    /// Each instance's `endpoint` is the endpoint of `service_name`.
+    async fn list_service_instances(&self, service_name: &str) -> Result<Vec<ServiceInstanceInfo>>;
+
     /// Register a new module instance with the directory
     async fn register_instance(&self, info: RegisterInstanceInfo) -> Result<()>;
 
@@ -85,6 +121,16 @@ pub trait DirectoryClient: Send + Sync {
 
     /// Send a heartbeat for a module instance to indicate it's still alive
     async fn send_heartbeat(&self, module: &str, instance_id: &str) -> Result<()>;
+
+    /// Watch the instances of a module
+    ///
+    /// The first item is the current instance list (as `list_instances` returns it);
+    /// an updated list follows whenever an instance registers, deregisters or expires.
+    ///
+    /// The default implementation returns an error for clients that cannot watch.
+    async fn watch(&self, module: &str) -> Result<InstanceStream> {
+        anyhow::bail!("watching instances of '{module}' is not supported by this directory client")
+    }
 }
 
 #[cfg(test)]
@@ -118,10 +164,48 @@ mod tests {
                 ServiceEndpoint::http("127.0.0.1", 8001),
             )],
             version: Some("1.0.0".to_owned()),
+            ttl: None,
+            weight: None,
+            endpoints: vec![],
         };
 
         assert_eq!(info.module, "test_module");
         assert_eq!(info.instance_id, "instance1");
         assert_eq!(info.grpc_services.len(), 1);
     }
+
+    #[tokio::test]
+    async fn test_watch_defaults_to_unsupported() {
+        struct NoWatch;
+
+        #[async_trait]
+        impl DirectoryClient for NoWatch {
+            async fn resolve_grpc_service(&self, service_name: &str) -> Result<ServiceEndpoint> {
+                anyhow::bail!("unknown service {service_name}")
+            }
+            async fn list_instances(&self, _module: &str) -> Result<Vec<ServiceInstanceInfo>> {
+                Ok(vec![])
+            }
+            async fn list_service_instances(
+                &self,
+                _service_name: &str,
+            ) -> Result<Vec<ServiceInstanceInfo>> {
+                Ok(vec![])
+            }
+            async fn register_instance(&self, _info: RegisterInstanceInfo) -> Result<()> {
+                Ok(())
+            }
+            async fn deregister_instance(&self, _module: &str, _instance_id: &str) -> Result<()> {
+                Ok(())
+            }
+            async fn send_heartbeat(&self, _module: &str, _instance_id: &str) -> Result<()> {
+                Ok(())
+            }
+        }
+
+        let Err(err) = NoWatch.watch("calculator").await else {
+            panic!("watch must fail without an implementation");
+        };
+        assert!(err.to_string().contains("not supported"));
+    }
 }
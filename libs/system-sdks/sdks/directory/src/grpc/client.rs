@@ -2,16 +2,28 @@
 //!
 //! This client allows remote modules to discover and resolve services via gRPC.
 
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
 use anyhow::Result;
 use async_trait::async_trait;
+use tokio::sync::watch;
+use tonic::Code;
 use tonic::transport::Channel;
 
-use crate::api::{DirectoryClient, RegisterInstanceInfo, ServiceEndpoint, ServiceInstanceInfo};
+use super::selection::{SelectionStrategy, Selector};
+use crate::api::{
+    DEFAULT_INSTANCE_WEIGHT, DirectoryClient, InstanceStream, RegisterInstanceInfo,
+    ServiceEndpoint, ServiceInstanceInfo,
+};
+use modkit_transport_grpc::backoff::jittered_backoff;
 use modkit_transport_grpc::client::{GrpcClientConfig, connect_with_retry};
 
 use crate::{
     DeregisterInstanceRequest, DirectoryServiceClient, GrpcServiceEndpoint, HeartbeatRequest,
-    ListInstancesRequest, RegisterInstanceRequest, ResolveGrpcServiceRequest,
+    ListInstancesRequest, ListInstancesResponse, RegisterInstanceRequest,
+    ResolveGrpcServiceRequest,
 };
 
 /// gRPC client for Directory API
@@ -21,8 +33,44 @@ use crate::{
 /// - Configurable timeouts and retries via transport stack
 /// - Automatic proto ↔ domain type conversions
 /// - Distributed tracing and metrics
+/// - Optional heartbeat TTL for registered instances (see `with_instance_ttl`)
+/// - Client-side instance selection (see `select`)
+/// - Automatic re-registration when the directory becomes unreachable or
+///   forgets its instances (see `connection_state`)
+///
+/// Clones share registrations and connection state.
+#[derive(Clone)]
 pub struct DirectoryGrpcClient {
     inner: DirectoryServiceClient<Channel>,
+    instance_ttl: Option<Duration>,
+    base_backoff: Duration,
+    max_backoff: Duration,
+    shared: Arc<Shared>,
+}
+
+/// Connection state of a [`DirectoryGrpcClient`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// The directory is reachable and knows every instance registered through this client.
+    Connected,
+    /// A call failed; registrations are being replayed with exponential backoff.
+    Reconnecting,
+}
+
+/// Default initial delay between reconnect attempts.
+pub const DEFAULT_RECONNECT_BASE_BACKOFF: Duration = Duration::from_millis(200);
+
+/// Default upper bound for the delay between reconnect attempts.
+pub const DEFAULT_RECONNECT_MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Key of a registration: `(module, instance_id)`.
+type RegistrationKey = (String, String);
+
+struct Shared {
+    /// Registrations to replay after the connection is restored
+    registrations: Mutex<HashMap<RegistrationKey, RegisterInstanceRequest>>,
+    state: watch::Sender<ConnectionState>,
+    selector: Selector,
 }
 
 impl DirectoryGrpcClient {
@@ -50,9 +98,7 @@ impl DirectoryGrpcClient {
         cfg: &GrpcClientConfig,
     ) -> Result<Self> {
         let channel: Channel = connect_with_retry(uri, cfg).await?;
-        Ok(Self {
-            inner: DirectoryServiceClient::new(channel),
-        })
+        Ok(Self::from_channel(channel))
     }
 
     /// Connect to a directory service without retry logic.
@@ -82,9 +128,7 @@ impl DirectoryGrpcClient {
             );
         }
 
-        Ok(Self {
-            inner: DirectoryServiceClient::new(channel),
-        })
+        Ok(Self::from_channel(channel))
     }
 
     /// Create from an existing channel (useful for testing or custom setup)
@@ -92,8 +136,169 @@ impl DirectoryGrpcClient {
     pub fn from_channel(channel: Channel) -> Self {
         Self {
             inner: DirectoryServiceClient::new(channel),
+            instance_ttl: None,
+            base_backoff: DEFAULT_RECONNECT_BASE_BACKOFF,
+            max_backoff: DEFAULT_RECONNECT_MAX_BACKOFF,
+            shared: Arc::new(Shared {
+                registrations: Mutex::new(HashMap::new()),
+                state: watch::Sender::new(ConnectionState::Connected),
+                selector: Selector::default(),
+            }),
+        }
+    }
+
+    /// Set the exponential backoff (with jitter) between reconnect attempts.
+    #[must_use]
+    pub fn with_reconnect_backoff(mut self, base: Duration, max: Duration) -> Self {
+        self.base_backoff = base;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Current connection state.
+    #[must_use]
+    pub fn connection_state(&self) -> ConnectionState {
+        *self.shared.state.borrow()
+    }
+
+    /// Subscribe to connection state changes.
+    #[must_use]
+    pub fn watch_connection_state(&self) -> watch::Receiver<ConnectionState> {
+        self.shared.state.subscribe()
+    }
+
+    /// Register instances with this heartbeat TTL unless `RegisterInstanceInfo::ttl`
+    /// is already set. Pair it with `spawn_heartbeat` at a shorter interval.
+    #[must_use]
+    pub fn with_instance_ttl(mut self, ttl: Duration) -> Self {
+        self.instance_ttl = Some(ttl);
+        self
+    }
+
+    /// Send a heartbeat for `instance_id` every `interval` until the returned
+    /// task is aborted. Failures are logged and retried on the next tick.
+    #[must_use]
+    pub fn spawn_heartbeat(
+        &self,
+        module: impl Into<String>,
+        instance_id: impl Into<String>,
+        interval: Duration,
+    ) -> tokio::task::JoinHandle<()> {
+        let client = self.clone();
+        let module = module.into();
+        let instance_id = instance_id.into();
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            // The first tick fires immediately; registration already counts as one
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                match client.send_heartbeat(&module, &instance_id).await {
+                    Ok(()) => tracing::debug!(%module, %instance_id, "Heartbeat sent"),
+                    Err(e) => {
+                        tracing::warn!(%module, %instance_id, error = %e, "Failed to send heartbeat, will retry");
+                    }
+                }
+            }
+        })
+    }
+
+    /// Pick one healthy instance of `service_name` using `strategy`.
+    ///
+    /// Instances that are not ready/healthy or whose heartbeat TTL lapsed are
+    /// skipped. Round-robin position is tracked per service and shared by clones.
+    ///
+    /// # Errors
+    /// Returns an error if the directory call fails or no healthy instance exists.
+    pub async fn select(
+        &self,
+        service_name: &str,
+        strategy: SelectionStrategy,
+    ) -> Result<ServiceInstanceInfo> {
+        let instances = self.list_service_instances(service_name).await?;
+        self.shared
+            .selector
+            .pick(service_name, instances, strategy)
+            .ok_or_else(|| anyhow::anyhow!("No healthy instances of service: {service_name}"))
+    }
+
+    fn registrations(
+        &self,
+    ) -> std::sync::MutexGuard<'_, HashMap<RegistrationKey, RegisterInstanceRequest>> {
+        self.shared
+            .registrations
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// React to a failed call: connection-level failures start a reconnect.
+    fn on_call_failed(&self, status: &tonic::Status) {
+        if matches!(status.code(), Code::Unavailable | Code::Unknown) {
+            self.start_reconnect();
+        }
+    }
+
+    /// Switch to `Reconnecting` and replay registrations in the background.
+    ///
+    /// No-op if a reconnect is already running.
+    fn start_reconnect(&self) {
+        let started = self.shared.state.send_if_modified(|state| {
+            let was_connected = *state == ConnectionState::Connected;
+            *state = ConnectionState::Reconnecting;
+            was_connected
+        });
+        if !started {
+            return;
+        }
+
+        tracing::warn!("Directory connection lost, reconnecting");
+        let client = self.clone();
+        tokio::spawn(async move { client.reconnect().await });
+    }
+
+    async fn reconnect(&self) {
+        let mut attempt: u32 = 0;
+        loop {
+            attempt = attempt.saturating_add(1);
+            tokio::time::sleep(jittered_backoff(
+                self.base_backoff,
+                self.max_backoff,
+                attempt,
+            ))
+            .await;
+
+            match self.replay_registrations().await {
+                Ok(replayed) => {
+                    self.shared.state.send_replace(ConnectionState::Connected);
+                    tracing::info!(attempt, replayed, "Directory connection restored");
+                    return;
+                }
+                Err(e) => {
+                    tracing::debug!(attempt, error = %e, "Directory reconnect attempt failed");
+                }
+            }
         }
     }
+
+    /// Probe the directory, then register every known instance again.
+    async fn replay_registrations(&self) -> Result<usize, tonic::Status> {
+        let mut client = self.inner.clone();
+        client
+            .list_instances(tonic::Request::new(ListInstancesRequest {
+                module_name: String::new(),
+            }))
+            .await?;
+
+        let registrations: Vec<_> = self.registrations().values().cloned().collect();
+        for req in &registrations {
+            client
+                .register_instance(tonic::Request::new(req.clone()))
+                .await?;
+        }
+        Ok(registrations.len())
+    }
 }
 
 #[async_trait]
@@ -104,10 +309,10 @@ impl DirectoryClient for DirectoryGrpcClient {
             service_name: service_name.to_owned(),
         });
 
-        let response = client
-            .resolve_grpc_service(request)
-            .await
-            .map_err(|e| anyhow::anyhow!("gRPC call failed: {e}"))?;
+        let response = client.resolve_grpc_service(request).await.map_err(|e| {
+            self.on_call_failed(&e);
+            anyhow::anyhow!("gRPC call failed: {e}")
+        })?;
 
         let proto_response = response.into_inner();
         Ok(ServiceEndpoint::new(proto_response.endpoint_uri))
@@ -119,30 +324,26 @@ impl DirectoryClient for DirectoryGrpcClient {
             module_name: module.to_owned(),
         });
 
-        let response = client
-            .list_instances(request)
-            .await
-            .map_err(|e| anyhow::anyhow!("gRPC call failed: {e}"))?;
+        let response = client.list_instances(request).await.map_err(|e| {
+            self.on_call_failed(&e);
+            anyhow::anyhow!("gRPC call failed: {e}")
+        })?;
 
-        let proto_response = response.into_inner();
+        Ok(instances_from_proto(response.into_inner()))
+    }
 
-        // Convert proto instances to domain types
-        let instances = proto_response
-            .instances
-            .into_iter()
-            .map(|proto_inst| ServiceInstanceInfo {
-                module: proto_inst.module_name,
-                instance_id: proto_inst.instance_id,
-                endpoint: ServiceEndpoint::new(proto_inst.endpoint_uri),
-                version: if proto_inst.version.is_empty() {
-                    None
-                } else {
-                    Some(proto_inst.version)
-                },
-            })
-            .collect();
+    async fn list_service_instances(&self, service_name: &str) -> Result<Vec<ServiceInstanceInfo>> {
+        let mut client = self.inner.clone();
+        let request = tonic::Request::new(ResolveGrpcServiceRequest {
+            service_name: service_name.to_owned(),
+        });
 
-        Ok(instances)
+        let response = client.list_service_instances(request).await.map_err(|e| {
+            self.on_call_failed(&e);
+            anyhow::anyhow!("gRPC call failed: {e}")
+        })?;
+
+        Ok(instances_from_proto(response.into_inner()))
     }
 
     async fn register_instance(&self, info: RegisterInstanceInfo) -> Result<()> {
@@ -163,12 +364,29 @@ impl DirectoryClient for DirectoryGrpcClient {
             instance_id: info.instance_id,
             grpc_services,
             version: info.version.unwrap_or_default(),
+            ttl_ms: info
+                .ttl
+                .or(self.instance_ttl)
+                .map_or(0, |ttl| u64::try_from(ttl.as_millis()).unwrap_or(u64::MAX)),
+            weight: info.weight.unwrap_or(0),
+            endpoints: info
+                .endpoints
+                .into_iter()
+                .map(|(name, ep)| (name, ep.uri))
+                .collect(),
         };
 
         client
-            .register_instance(tonic::Request::new(req))
+            .register_instance(tonic::Request::new(req.clone()))
             .await
-            .map_err(|e| anyhow::anyhow!("gRPC register_instance failed: {e}"))?;
+            .map_err(|e| {
+                self.on_call_failed(&e);
+                anyhow::anyhow!("gRPC register_instance failed: {e}")
+            })?;
+
+        // Remember the accepted registration so it can be replayed after a reconnect
+        self.registrations()
+            .insert((req.module_name.clone(), req.instance_id.clone()), req);
 
         Ok(())
     }
@@ -176,6 +394,9 @@ impl DirectoryClient for DirectoryGrpcClient {
     async fn deregister_instance(&self, module: &str, instance_id: &str) -> Result<()> {
         let mut client = self.inner.clone();
 
+        self.registrations()
+            .remove(&(module.to_owned(), instance_id.to_owned()));
+
         let req = DeregisterInstanceRequest {
             module_name: module.to_owned(),
             instance_id: instance_id.to_owned(),
@@ -184,7 +405,10 @@ impl DirectoryClient for DirectoryGrpcClient {
         client
             .deregister_instance(tonic::Request::new(req))
             .await
-            .map_err(|e| anyhow::anyhow!("gRPC deregister_instance failed: {e}"))?;
+            .map_err(|e| {
+                self.on_call_failed(&e);
+                anyhow::anyhow!("gRPC deregister_instance failed: {e}")
+            })?;
 
         Ok(())
     }
@@ -200,10 +424,68 @@ impl DirectoryClient for DirectoryGrpcClient {
         client
             .heartbeat(tonic::Request::new(req))
             .await
-            .map_err(|e| anyhow::anyhow!("gRPC heartbeat failed: {e}"))?;
+            .map_err(|e| {
+                // Any heartbeat failure may mean the directory lost this instance
+                // (e.g. it restarted), so replay registrations either way.
+                self.start_reconnect();
+                anyhow::anyhow!("gRPC heartbeat failed: {e}")
+            })?;
 
         Ok(())
     }
+
+    async fn watch(&self, module: &str) -> Result<InstanceStream> {
+        let mut client = self.inner.clone();
+        let request = tonic::Request::new(ListInstancesRequest {
+            module_name: module.to_owned(),
+        });
+
+        let updates = client
+            .watch_instances(request)
+            .await
+            .map_err(|e| {
+                self.on_call_failed(&e);
+                anyhow::anyhow!("gRPC watch_instances failed: {e}")
+            })?
+            .into_inner();
+
+        // The stream ends when the server closes it or the call fails
+        let stream = futures_util::stream::unfold(updates, |mut updates| async move {
+            match updates.message().await {
+                Ok(Some(resp)) => Some((instances_from_proto(resp), updates)),
+                Ok(None) => None,
+                Err(e) => {
+                    tracing::warn!(error = %e, "Directory watch stream failed");
+                    None
+                }
+            }
+        });
+        Ok(Box::pin(stream))
+    }
+}
+
+/// Convert proto instances to domain types
+fn instances_from_proto(resp: ListInstancesResponse) -> Vec<ServiceInstanceInfo> {
+    resp.instances
+        .into_iter()
+        .map(|proto_inst| ServiceInstanceInfo {
+            module: proto_inst.module_name,
+            instance_id: proto_inst.instance_id,
+            endpoint: ServiceEndpoint::new(proto_inst.endpoint_uri),
+            version: if proto_inst.version.is_empty() {
+                None
+            } else {
+                Some(proto_inst.version)
+            },
+            weight: proto_inst.weight.max(DEFAULT_INSTANCE_WEIGHT),
+            healthy: proto_inst.healthy,
+            endpoints: proto_inst
+                .endpoints
+                .into_iter()
+                .map(|(name, uri)| (name, ServiceEndpoint::new(uri)))
+                .collect(),
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -225,4 +507,200 @@ mod tests {
             let _client = DirectoryGrpcClient::from_channel(channel);
         }
     }
+
+    /// In-memory directory that only knows instances registered with it.
+    #[derive(Clone, Default)]
+    struct FakeDirectory {
+        registered: Arc<Mutex<Vec<(String, String)>>>,
+    }
+
+    impl FakeDirectory {
+        fn knows(&self, module: &str, instance_id: &str) -> bool {
+            self.registered
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|(m, i)| m == module && i == instance_id)
+        }
+    }
+
+    #[async_trait]
+    impl crate::DirectoryService for FakeDirectory {
+        type WatchInstancesStream = std::pin::Pin<
+            Box<
+                dyn futures_core::Stream<Item = Result<ListInstancesResponse, tonic::Status>>
+                    + Send,
+            >,
+        >;
+
+        async fn resolve_grpc_service(
+            &self,
+            _request: tonic::Request<ResolveGrpcServiceRequest>,
+        ) -> Result<tonic::Response<crate::ResolveGrpcServiceResponse>, tonic::Status> {
+            Err(tonic::Status::unimplemented("resolve_grpc_service"))
+        }
+
+        async fn list_instances(
+            &self,
+            _request: tonic::Request<ListInstancesRequest>,
+        ) -> Result<tonic::Response<ListInstancesResponse>, tonic::Status> {
+            Ok(tonic::Response::new(ListInstancesResponse::default()))
+        }
+
+        async fn list_service_instances(
+            &self,
+            _request: tonic::Request<ResolveGrpcServiceRequest>,
+        ) -> Result<tonic::Response<ListInstancesResponse>, tonic::Status> {
+            Ok(tonic::Response::new(ListInstancesResponse::default()))
+        }
+
+        async fn register_instance(
+            &self,
+            request: tonic::Request<RegisterInstanceRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            let req = request.into_inner();
+            self.registered
+                .lock()
+                .unwrap()
+                .push((req.module_name, req.instance_id));
+            Ok(tonic::Response::new(()))
+        }
+
+        async fn deregister_instance(
+            &self,
+            _request: tonic::Request<DeregisterInstanceRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            Ok(tonic::Response::new(()))
+        }
+
+        async fn heartbeat(
+            &self,
+            request: tonic::Request<HeartbeatRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            let req = request.into_inner();
+            if self.knows(&req.module_name, &req.instance_id) {
+                Ok(tonic::Response::new(()))
+            } else {
+                Err(tonic::Status::not_found("instance not registered"))
+            }
+        }
+
+        async fn watch_instances(
+            &self,
+            _request: tonic::Request<ListInstancesRequest>,
+        ) -> Result<tonic::Response<Self::WatchInstancesStream>, tonic::Status> {
+            Err(tonic::Status::unimplemented("watch_instances"))
+        }
+    }
+
+    /// Serve `directory` on a clone of `listener` until the returned sender is dropped or fired.
+    ///
+    /// The caller keeps `listener` open, so a restarted server gets the same port back.
+    fn serve(
+        directory: FakeDirectory,
+        listener: &std::net::TcpListener,
+    ) -> (
+        tokio::sync::oneshot::Sender<()>,
+        tokio::task::JoinHandle<()>,
+    ) {
+        let listener = listener.try_clone().unwrap();
+        listener.set_nonblocking(true).unwrap();
+        let listener = tokio::net::TcpListener::from_std(listener).unwrap();
+        let (stop_tx, stop_rx) = tokio::sync::oneshot::channel::<()>();
+        let handle = tokio::spawn(async move {
+            tonic::transport::Server::builder()
+                .add_service(crate::DirectoryServiceServer::new(directory))
+                .serve_with_incoming_shutdown(
+                    tokio_stream::wrappers::TcpListenerStream::new(listener),
+                    async {
+                        _ = stop_rx.await;
+                    },
+                )
+                .await
+                .unwrap();
+        });
+        (stop_tx, handle)
+    }
+
+    #[tokio::test]
+    async fn test_failed_registration_is_not_replayed() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (stop, server) = serve(FakeDirectory::default(), &listener);
+
+        let client = DirectoryGrpcClient::connect_no_retry(
+            format!("http://{addr}"),
+            &GrpcClientConfig::new("directory"),
+        )
+        .await
+        .unwrap();
+
+        stop.send(()).unwrap();
+        server.await.unwrap();
+        drop(listener);
+
+        let info = RegisterInstanceInfo {
+            module: "calculator".to_owned(),
+            instance_id: "i1".to_owned(),
+            grpc_services: vec![],
+            version: None,
+            ttl: None,
+            weight: None,
+            endpoints: vec![],
+        };
+        assert!(client.register_instance(info).await.is_err());
+        assert!(client.registrations().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reregisters_instances_after_server_restart() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let first = FakeDirectory::default();
+        let (stop, server) = serve(first.clone(), &listener);
+
+        let client = DirectoryGrpcClient::connect_no_retry(
+            format!("http://{addr}"),
+            &GrpcClientConfig::new("directory"),
+        )
+        .await
+        .unwrap()
+        .with_reconnect_backoff(Duration::from_millis(10), Duration::from_millis(50));
+
+        client
+            .register_instance(RegisterInstanceInfo {
+                module: "calculator".to_owned(),
+                instance_id: "i1".to_owned(),
+                grpc_services: vec![],
+                version: None,
+                ttl: None,
+                weight: None,
+                endpoints: vec![],
+            })
+            .await
+            .unwrap();
+        assert!(first.knows("calculator", "i1"));
+        assert_eq!(client.connection_state(), ConnectionState::Connected);
+
+        // Restart the directory; the new server starts with no instances
+        stop.send(()).unwrap();
+        server.await.unwrap();
+        let second = FakeDirectory::default();
+        let (_stop, _server) = serve(second.clone(), &listener);
+
+        let mut state = client.watch_connection_state();
+        assert!(client.send_heartbeat("calculator", "i1").await.is_err());
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            state
+                .wait_for(|s| *s == ConnectionState::Connected)
+                .await
+                .unwrap();
+        })
+        .await
+        .expect("client should reconnect");
+
+        assert!(second.knows("calculator", "i1"));
+        client.send_heartbeat("calculator", "i1").await.unwrap();
+    }
 }
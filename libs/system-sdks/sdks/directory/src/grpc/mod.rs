@@ -3,6 +3,7 @@
 //! This crate provides gRPC transport for the module orchestrator.
 //! It includes generated protobuf types and client/server implementations.
 mod client;
+mod selection;
 
 // Generated protobuf types for DirectoryService
 #[allow(clippy::all, clippy::pedantic, clippy::nursery, warnings)] // protoc problem
@@ -20,7 +21,11 @@ pub use directory::{
 };
 
 // Re-export the gRPC client implementation
-pub use client::DirectoryGrpcClient;
+pub use client::{
+    ConnectionState, DEFAULT_RECONNECT_BASE_BACKOFF, DEFAULT_RECONNECT_MAX_BACKOFF,
+    DirectoryGrpcClient,
+};
+pub use selection::SelectionStrategy;
 
 /// Service name constant for `DirectoryService`
 pub const DIRECTORY_SERVICE_NAME: &str =
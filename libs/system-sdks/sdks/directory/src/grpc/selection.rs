@@ -0,0 +1,212 @@
+//! Client-side instance selection used by `DirectoryGrpcClient::select`
+
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+
+use rand::Rng as _;
+
+use crate::api::ServiceInstanceInfo;
+
+/// How `DirectoryGrpcClient::select` picks among the healthy instances of a service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionStrategy {
+    /// Rotate through the instances; the rotation is tracked per service.
+    RoundRobin,
+    /// Pick uniformly at random.
+    Random,
+    /// Pick at random, proportionally to each instance's `weight`.
+    Weighted,
+}
+
+/// Selection state shared by clones of a client.
+#[derive(Default)]
+pub struct Selector {
+    rr_counters: Mutex<HashMap<String, usize>>,
+}
+
+impl Selector {
+    /// Pick one healthy instance of `service_name`, or `None` if there is none.
+    pub fn pick(
+        &self,
+        service_name: &str,
+        instances: Vec<ServiceInstanceInfo>,
+        strategy: SelectionStrategy,
+    ) -> Option<ServiceInstanceInfo> {
+        let mut candidates: Vec<_> = instances.into_iter().filter(|i| i.healthy).collect();
+        if candidates.is_empty() {
+            return None;
+        }
+        // The directory does not guarantee an order; sort so the rotation is stable
+        candidates.sort_by(|a, b| a.instance_id.cmp(&b.instance_id));
+
+        let len = candidates.len();
+        let idx = match strategy {
+            SelectionStrategy::RoundRobin => {
+                let mut counters = self
+                    .rr_counters
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner);
+                let counter = counters.entry(service_name.to_owned()).or_insert(0);
+                let idx = *counter % len;
+                *counter = counter.wrapping_add(1);
+                idx
+            }
+            SelectionStrategy::Random => rand::rng().random_range(0..len),
+            SelectionStrategy::Weighted => weighted_index(&candidates),
+        };
+
+        Some(candidates.swap_remove(idx))
+    }
+}
+
+fn weighted_index(candidates: &[ServiceInstanceInfo]) -> usize {
+    let total: u64 = candidates.iter().map(|c| u64::from(c.weight.max(1))).sum();
+    let mut point = rand::rng().random_range(0..total);
+    for (idx, candidate) in candidates.iter().enumerate() {
+        let weight = u64::from(candidate.weight.max(1));
+        if point < weight {
+            return idx;
+        }
+        point -= weight;
+    }
+    candidates.len() - 1
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::api::ServiceEndpoint;
+
+    const CALLS: usize = 6000;
+
+    fn instance(id: &str, weight: u32, healthy: bool) -> ServiceInstanceInfo {
+        ServiceInstanceInfo {
+            module: "calculator".to_owned(),
+            instance_id: id.to_owned(),
+            endpoint: ServiceEndpoint::new(format!("http://{id}:50051")),
+            version: None,
+            weight,
+            healthy,
+            endpoints: HashMap::new(),
+        }
+    }
+
+    /// Pick `CALLS` times and count how often each instance was chosen.
+    fn distribution(
+        selector: &Selector,
+        instances: &[ServiceInstanceInfo],
+        strategy: SelectionStrategy,
+    ) -> HashMap<String, usize> {
+        let mut counts = HashMap::new();
+        for _ in 0..CALLS {
+            let picked = selector
+                .pick("calc.v1.Calculator", instances.to_vec(), strategy)
+                .unwrap();
+            *counts.entry(picked.instance_id).or_default() += 1;
+        }
+        counts
+    }
+
+    #[test]
+    fn test_round_robin_distributes_evenly() {
+        let selector = Selector::default();
+        let instances = [
+            instance("c", 1, true),
+            instance("a", 1, true),
+            instance("b", 1, true),
+        ];
+
+        let order: Vec<_> = (0..4)
+            .map(|_| {
+                selector
+                    .pick(
+                        "calc.v1.Calculator",
+                        instances.to_vec(),
+                        SelectionStrategy::RoundRobin,
+                    )
+                    .unwrap()
+                    .instance_id
+            })
+            .collect();
+        assert_eq!(order, ["a", "b", "c", "a"]);
+
+        let counts = distribution(&selector, &instances, SelectionStrategy::RoundRobin);
+        // CALLS is a multiple of 3, so every instance gets exactly a third
+        for id in ["a", "b", "c"] {
+            assert_eq!(counts[id], 2000);
+        }
+    }
+
+    #[test]
+    fn test_round_robin_state_is_per_service() {
+        let selector = Selector::default();
+        let instances = vec![instance("a", 1, true), instance("b", 1, true)];
+
+        let first = selector
+            .pick("svc.One", instances.clone(), SelectionStrategy::RoundRobin)
+            .unwrap();
+        let other = selector
+            .pick("svc.Two", instances, SelectionStrategy::RoundRobin)
+            .unwrap();
+        assert_eq!(first.instance_id, "a");
+        assert_eq!(other.instance_id, "a");
+    }
+
+    #[test]
+    fn test_random_distributes_roughly_evenly() {
+        let selector = Selector::default();
+        let instances = [
+            instance("a", 1, true),
+            instance("b", 1, true),
+            instance("c", 1, true),
+        ];
+
+        let counts = distribution(&selector, &instances, SelectionStrategy::Random);
+        for id in ["a", "b", "c"] {
+            // Expected 2000 each; allow a generous margin for randomness
+            assert!((1500..=2500).contains(&counts[id]), "{id}: {counts:?}");
+        }
+    }
+
+    #[test]
+    fn test_weighted_follows_weights() {
+        let selector = Selector::default();
+        let instances = [instance("a", 1, true), instance("b", 3, true)];
+
+        let counts = distribution(&selector, &instances, SelectionStrategy::Weighted);
+        // Expected 1500 vs 4500
+        assert!((1100..=1900).contains(&counts["a"]), "{counts:?}");
+        assert!((4100..=4900).contains(&counts["b"]), "{counts:?}");
+    }
+
+    #[test]
+    fn test_unhealthy_instances_are_excluded() {
+        let selector = Selector::default();
+        let instances = [
+            instance("a", 1, false),
+            instance("b", 1, true),
+            instance("c", 5, false),
+        ];
+
+        for strategy in [
+            SelectionStrategy::RoundRobin,
+            SelectionStrategy::Random,
+            SelectionStrategy::Weighted,
+        ] {
+            let counts = distribution(&selector, &instances, strategy);
+            assert_eq!(counts.len(), 1, "{strategy:?}: {counts:?}");
+            assert_eq!(counts["b"], CALLS);
+        }
+
+        assert!(
+            selector
+                .pick(
+                    "calc.v1.Calculator",
+                    vec![instance("a", 1, false)],
+                    SelectionStrategy::Random
+                )
+                .is_none()
+        );
+    }
+}
@@ -482,6 +482,9 @@ impl GrpcHub {
                         })
                         .collect(),
                     version: Some(env!("CARGO_PKG_VERSION").to_owned()),
+                    ttl: None,
+                    weight: None,
+                    endpoints: vec![],
                 };
 
                 directory.register_instance(info).await?;
@@ -1048,6 +1051,12 @@ mod tests {
             ) -> anyhow::Result<Vec<ServiceInstanceInfo>> {
                 Ok(vec![])
             }
+            async fn list_service_instances(
+                &self,
+                _service_name: &str,
+            ) -> anyhow::Result<Vec<ServiceInstanceInfo>> {
+                Ok(vec![])
+            }
             async fn register_instance(&self, _info: RegisterInstanceInfo) -> anyhow::Result<()> {
                 Ok(())
             }
@@ -0,0 +1,409 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::time::Instant;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use modkit::directory::LocalDirectoryClient;
+use modkit::runtime::ModuleManager;
+use modkit_macros::domain_model;
+use parking_lot::Mutex;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use cf_system_sdks::directory::{
+    DirectoryClient, InstanceStream, RegisterInstanceInfo, ServiceEndpoint, ServiceInstanceInfo,
+};
+
+use super::store::{DirectoryStore, StoredInstance};
+
+/// Upper bound on how often a heartbeat is written to the store per instance.
+///
+/// Instances with a shorter TTL are written every half TTL, so a restored
+/// heartbeat is never more than half a TTL behind.
+const MAX_HEARTBEAT_WRITE_INTERVAL: Duration = Duration::from_secs(30);
+
+fn heartbeat_write_interval(ttl: Option<Duration>) -> Duration {
+    ttl.map_or(MAX_HEARTBEAT_WRITE_INTERVAL, |ttl| {
+        (ttl / 2).min(MAX_HEARTBEAT_WRITE_INTERVAL)
+    })
+}
+
+/// What the store holds for a registration made through this client.
+#[domain_model]
+struct Persisted {
+    info: RegisterInstanceInfo,
+    registered_at: SystemTime,
+    /// When the stored row was last written; `None` once the row was deleted
+    /// because the instance was evicted.
+    written_at: Option<Instant>,
+}
+
+/// `DirectoryClient` that serves from the `ModuleManager` and writes every
+/// registration change through to a [`DirectoryStore`].
+///
+/// Heartbeats are written at most every half TTL (capped at 30s), and rows
+/// of instances evicted by [`reap_expired`](Self::reap_expired) are deleted.
+/// Call [`restore`](Self::restore) once at startup to reload the registrations
+/// that were live when the directory last stopped.
+#[domain_model]
+pub struct PersistentDirectoryClient {
+    local: LocalDirectoryClient,
+    manager: Arc<ModuleManager>,
+    store: Arc<dyn DirectoryStore>,
+    persisted: Mutex<HashMap<(String, String), Persisted>>,
+}
+
+impl PersistentDirectoryClient {
+    #[must_use]
+    pub fn new(manager: Arc<ModuleManager>, store: Arc<dyn DirectoryStore>) -> Self {
+        Self {
+            local: LocalDirectoryClient::new(Arc::clone(&manager)),
+            manager,
+            store,
+            persisted: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Re-register stored instances whose TTL has not lapsed.
+    ///
+    /// The last heartbeat time is carried over, so restored instances expire on
+    /// the same schedule as before the restart. Returns the number restored.
+    ///
+    /// # Errors
+    /// Returns an error if the store cannot be read.
+    pub async fn restore(&self) -> Result<usize> {
+        let now = SystemTime::now();
+        let stored = self.store.load(now).await?;
+        let mut restored = 0;
+
+        for instance in stored {
+            let module = instance.info.module.clone();
+            let instance_id = instance.info.instance_id.clone();
+            if let Err(e) = self.local.register_instance(instance.info.clone()).await {
+                tracing::warn!(%module, %instance_id, error = %e, "Skipping stored instance");
+                continue;
+            }
+            if let (Some(at), Ok(id)) = (instance.last_heartbeat, Uuid::parse_str(&instance_id)) {
+                let age = now.duration_since(at).unwrap_or_default();
+                let at = Instant::now().checked_sub(age).unwrap_or_else(Instant::now);
+                self.manager.update_heartbeat(&module, id, at);
+            }
+            self.persisted.lock().insert(
+                (module, instance_id),
+                Persisted {
+                    info: instance.info,
+                    registered_at: instance.registered_at,
+                    written_at: Some(Instant::now()),
+                },
+            );
+            restored += 1;
+        }
+
+        Ok(restored)
+    }
+
+    /// Delete the stored rows of instances the `ModuleManager` no longer holds.
+    ///
+    /// Returns the number of rows deleted.
+    ///
+    /// # Errors
+    /// Returns the first store error; the remaining rows are retried next time.
+    pub async fn remove_evicted(&self) -> Result<usize> {
+        let evicted: Vec<(String, String)> = self
+            .persisted
+            .lock()
+            .iter()
+            .filter(|(_, p)| p.written_at.is_some())
+            .filter(|((module, instance_id), _)| {
+                !self
+                    .manager
+                    .instances_of(module)
+                    .iter()
+                    .any(|inst| inst.instance_id.to_string() == *instance_id)
+            })
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        for (module, instance_id) in &evicted {
+            self.store.remove(module, instance_id).await?;
+            if let Some(p) = self
+                .persisted
+                .lock()
+                .get_mut(&(module.clone(), instance_id.clone()))
+            {
+                p.written_at = None;
+            }
+        }
+        Ok(evicted.len())
+    }
+
+    /// Evict instances whose heartbeat TTL lapsed, and delete their stored rows,
+    /// every `interval` until `cancel` fires.
+    pub async fn reap_expired(&self, interval: Duration, cancel: CancellationToken) {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                () = cancel.cancelled() => break,
+                _ = ticker.tick() => self.reap_once().await,
+            }
+        }
+    }
+
+    async fn reap_once(&self) {
+        let evicted = self.manager.evict_expired(Instant::now());
+        if evicted > 0 {
+            tracing::info!(evicted, "Evicted instances with lapsed heartbeat TTL");
+        }
+        if let Err(e) = self.remove_evicted().await {
+            tracing::warn!(error = %e, "Failed to delete evicted instances from storage");
+        }
+    }
+}
+
+#[async_trait]
+impl DirectoryClient for PersistentDirectoryClient {
+    async fn resolve_grpc_service(&self, service_name: &str) -> Result<ServiceEndpoint> {
+        self.local.resolve_grpc_service(service_name).await
+    }
+
+    async fn list_instances(&self, module: &str) -> Result<Vec<ServiceInstanceInfo>> {
+        self.local.list_instances(module).await
+    }
+
+    async fn list_service_instances(&self, service_name: &str) -> Result<Vec<ServiceInstanceInfo>> {
+        self.local.list_service_instances(service_name).await
+    }
+
+    async fn register_instance(&self, info: RegisterInstanceInfo) -> Result<()> {
+        self.local.register_instance(info.clone()).await?;
+        let registered_at = SystemTime::now();
+        self.store
+            .save(StoredInstance::new(info.clone(), registered_at))
+            .await?;
+        self.persisted.lock().insert(
+            (info.module.clone(), info.instance_id.clone()),
+            Persisted {
+                info,
+                registered_at,
+                written_at: Some(Instant::now()),
+            },
+        );
+        Ok(())
+    }
+
+    async fn deregister_instance(&self, module: &str, instance_id: &str) -> Result<()> {
+        self.local.deregister_instance(module, instance_id).await?;
+        self.persisted
+            .lock()
+            .remove(&(module.to_owned(), instance_id.to_owned()));
+        self.store.remove(module, instance_id).await
+    }
+
+    async fn send_heartbeat(&self, module: &str, instance_id: &str) -> Result<()> {
+        self.local.send_heartbeat(module, instance_id).await?;
+
+        let now = Instant::now();
+        // A heartbeat for an evicted instance re-registers it, so its row is
+        // written back in full; otherwise only the heartbeat time, throttled.
+        let resave = {
+            let mut persisted = self.persisted.lock();
+            let Some(p) = persisted.get_mut(&(module.to_owned(), instance_id.to_owned())) else {
+                return Ok(());
+            };
+            match p.written_at {
+                Some(at) if now.duration_since(at) < heartbeat_write_interval(p.info.ttl) => {
+                    return Ok(());
+                }
+                written_at => {
+                    p.written_at = Some(now);
+                    written_at
+                        .is_none()
+                        .then(|| StoredInstance::new(p.info.clone(), p.registered_at))
+                }
+            }
+        };
+
+        match resave {
+            Some(mut instance) => {
+                instance.last_heartbeat = Some(SystemTime::now());
+                self.store.save(instance).await
+            }
+            None => {
+                self.store
+                    .record_heartbeat(module, instance_id, SystemTime::now())
+                    .await
+            }
+        }
+    }
+
+    async fn watch(&self, module: &str) -> Result<InstanceStream> {
+        self.local.watch(module).await
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::infra::storage::InMemoryDirectoryStore;
+
+    fn info(instance_id: Uuid, ttl: Option<Duration>) -> RegisterInstanceInfo {
+        RegisterInstanceInfo {
+            module: "calc".to_owned(),
+            instance_id: instance_id.to_string(),
+            grpc_services: vec![(
+                "calc.v1.Calculator".to_owned(),
+                ServiceEndpoint::http("127.0.0.1", 50051),
+            )],
+            version: Some("1.0.0".to_owned()),
+            ttl,
+            weight: Some(2),
+            endpoints: vec![("http".to_owned(), ServiceEndpoint::http("127.0.0.1", 8080))],
+        }
+    }
+
+    /// Run the restart scenario against `store`: register a long-lived and a
+    /// short-lived instance, let the short TTL lapse, then restore into a fresh
+    /// `ModuleManager` as a restarted directory would.
+    async fn assert_restart_reloads_live_instances(store: Arc<dyn DirectoryStore>) {
+        let (durable, transient) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let before = PersistentDirectoryClient::new(Arc::new(ModuleManager::new()), store.clone());
+        before
+            .register_instance(info(durable, Some(Duration::from_secs(60))))
+            .await
+            .unwrap();
+        before
+            .register_instance(info(transient, Some(Duration::from_millis(50))))
+            .await
+            .unwrap();
+        before
+            .send_heartbeat("calc", &durable.to_string())
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(80)).await;
+
+        let manager = Arc::new(ModuleManager::new());
+        let after = PersistentDirectoryClient::new(manager.clone(), store);
+        assert_eq!(after.restore().await.unwrap(), 1);
+
+        let instances = after.list_instances("calc").await.unwrap();
+        assert_eq!(
+            instances.len(),
+            1,
+            "expired instance must not be resurrected"
+        );
+        let restored = &instances[0];
+        assert_eq!(restored.instance_id, durable.to_string());
+        assert_eq!(restored.version.as_deref(), Some("1.0.0"));
+        assert_eq!(restored.weight, 2);
+        assert_eq!(
+            restored.endpoint("http"),
+            Some(&ServiceEndpoint::http("127.0.0.1", 8080))
+        );
+        assert!(restored.healthy, "heartbeat state carries over");
+        assert_eq!(
+            manager.instances_of("calc")[0].heartbeat_ttl,
+            Some(Duration::from_secs(60))
+        );
+    }
+
+    #[tokio::test]
+    async fn restart_reloads_live_instances_from_memory_store() {
+        assert_restart_reloads_live_instances(Arc::new(InMemoryDirectoryStore::new())).await;
+    }
+
+    #[cfg(feature = "db-store")]
+    #[tokio::test]
+    async fn restart_reloads_live_instances_from_database_store() {
+        use crate::infra::storage::SeaOrmDirectoryStore;
+
+        let db = crate::infra::storage::db::inmem_db().await;
+        assert_restart_reloads_live_instances(Arc::new(SeaOrmDirectoryStore::new(db))).await;
+    }
+
+    #[tokio::test]
+    async fn deregistered_instances_are_not_restored() {
+        let store: Arc<dyn DirectoryStore> = Arc::new(InMemoryDirectoryStore::new());
+        let id = Uuid::new_v4();
+
+        let before = PersistentDirectoryClient::new(Arc::new(ModuleManager::new()), store.clone());
+        before.register_instance(info(id, None)).await.unwrap();
+        before
+            .deregister_instance("calc", &id.to_string())
+            .await
+            .unwrap();
+
+        let after = PersistentDirectoryClient::new(Arc::new(ModuleManager::new()), store);
+        assert_eq!(after.restore().await.unwrap(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn heartbeat_writes_are_throttled() {
+        let store = Arc::new(InMemoryDirectoryStore::new());
+        let client = PersistentDirectoryClient::new(Arc::new(ModuleManager::new()), store.clone());
+        let id = Uuid::new_v4();
+        client
+            .register_instance(info(id, Some(Duration::from_secs(10))))
+            .await
+            .unwrap();
+
+        for _ in 0..5 {
+            client
+                .send_heartbeat("calc", &id.to_string())
+                .await
+                .unwrap();
+            tokio::time::advance(Duration::from_secs(1)).await;
+        }
+        assert_eq!(
+            store.heartbeat_writes(),
+            0,
+            "registration counts as a write"
+        );
+
+        // Half the TTL after the last write
+        client
+            .send_heartbeat("calc", &id.to_string())
+            .await
+            .unwrap();
+        client
+            .send_heartbeat("calc", &id.to_string())
+            .await
+            .unwrap();
+        assert_eq!(store.heartbeat_writes(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn evicted_instances_are_deleted_from_store() {
+        let store = Arc::new(InMemoryDirectoryStore::new());
+        let manager = Arc::new(ModuleManager::new());
+        let client = PersistentDirectoryClient::new(manager.clone(), store.clone());
+        let (short, long) = (Uuid::new_v4(), Uuid::new_v4());
+        client
+            .register_instance(info(short, Some(Duration::from_secs(1))))
+            .await
+            .unwrap();
+        client
+            .register_instance(info(long, Some(Duration::from_secs(60))))
+            .await
+            .unwrap();
+
+        tokio::time::advance(Duration::from_secs(2)).await;
+        assert_eq!(manager.evict_expired(Instant::now()), 1);
+        assert_eq!(client.remove_evicted().await.unwrap(), 1);
+        assert_eq!(store.len(), 1, "evicted row is deleted, live row kept");
+        assert_eq!(client.remove_evicted().await.unwrap(), 0);
+
+        // A late heartbeat re-registers the instance and writes its row back
+        client
+            .send_heartbeat("calc", &short.to_string())
+            .await
+            .unwrap();
+        assert_eq!(store.len(), 2);
+    }
+}
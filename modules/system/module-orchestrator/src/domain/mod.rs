@@ -1,2 +1,4 @@
+pub mod directory;
 pub mod model;
 pub mod service;
+pub mod store;
@@ -201,6 +201,21 @@ mod tests {
         assert_eq!(modules[1].dependencies, vec!["api_gateway"]);
     }
 
+    #[test]
+    fn dependency_version_requirements_are_not_part_of_names() {
+        let mut b = RegistryBuilder::default();
+        b.register_core_with_meta("api_gateway", &[], Arc::new(DummyCore));
+        b.register_version_with_meta("api_gateway", "1.4.0");
+        b.register_core_with_meta("nodes_registry", &["api_gateway@^1"], Arc::new(DummyCore));
+        let registry = b.build_topo_sorted().unwrap();
+
+        let svc = ModulesService::new(&registry, Arc::new(ModuleManager::new()));
+        let modules = svc.list_modules();
+
+        assert_eq!(modules[1].name, "nodes_registry");
+        assert_eq!(modules[1].dependencies, vec!["api_gateway"]);
+    }
+
     #[test]
     fn dynamic_external_instances_appear_as_out_of_process() {
         let registry = build_registry(&[]);
@@ -0,0 +1,61 @@
+use std::time::SystemTime;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use modkit_macros::domain_model;
+
+use cf_system_sdks::directory::RegisterInstanceInfo;
+
+/// A registration as persisted by a [`DirectoryStore`].
+#[domain_model]
+#[derive(Debug, Clone)]
+pub struct StoredInstance {
+    pub info: RegisterInstanceInfo,
+    pub registered_at: SystemTime,
+    pub last_heartbeat: Option<SystemTime>,
+}
+
+impl StoredInstance {
+    #[must_use]
+    pub fn new(info: RegisterInstanceInfo, registered_at: SystemTime) -> Self {
+        Self {
+            info,
+            registered_at,
+            last_heartbeat: None,
+        }
+    }
+
+    /// Last time the instance was known to be alive.
+    #[must_use]
+    pub fn last_seen(&self) -> SystemTime {
+        self.last_heartbeat.unwrap_or(self.registered_at)
+    }
+
+    /// Whether the instance's heartbeat TTL lapsed at `now`.
+    ///
+    /// Instances registered without a TTL never expire.
+    #[must_use]
+    pub fn is_expired(&self, now: SystemTime) -> bool {
+        self.info.ttl.is_some_and(|ttl| {
+            now.duration_since(self.last_seen())
+                .is_ok_and(|elapsed| elapsed >= ttl)
+        })
+    }
+}
+
+/// Persistence for directory registrations, so they survive a directory restart.
+#[async_trait]
+pub trait DirectoryStore: Send + Sync {
+    /// Insert or replace the registration of `(module, instance_id)`.
+    async fn save(&self, instance: StoredInstance) -> Result<()>;
+
+    /// Record a heartbeat; unknown instances are ignored.
+    async fn record_heartbeat(&self, module: &str, instance_id: &str, at: SystemTime)
+    -> Result<()>;
+
+    /// Remove a registration; unknown instances are ignored.
+    async fn remove(&self, module: &str, instance_id: &str) -> Result<()>;
+
+    /// Registrations still alive at `now`. Expired ones are purged, not returned.
+    async fn load(&self, now: SystemTime) -> Result<Vec<StoredInstance>>;
+}
@@ -0,0 +1,26 @@
+//! Test helpers for the SeaORM-backed directory store.
+
+use modkit_db::DbError;
+
+/// Create an in-memory `SQLite` database with the orchestrator migrations applied.
+#[allow(clippy::expect_used)]
+pub async fn inmem_db() -> modkit_db::DBProvider<DbError> {
+    use modkit_db::migration_runner::run_migrations_for_testing;
+    use modkit_db::{ConnectOpts, DBProvider, connect_db};
+    use sea_orm_migration::MigratorTrait;
+
+    let opts = ConnectOpts {
+        max_conns: Some(1),
+        min_conns: Some(1),
+        ..Default::default()
+    };
+    let db = connect_db("sqlite::memory:", opts)
+        .await
+        .expect("Failed to connect to in-memory database");
+
+    run_migrations_for_testing(&db, super::migrations::Migrator::migrations())
+        .await
+        .expect("Failed to run migrations");
+
+    DBProvider::new(db)
+}
@@ -0,0 +1,27 @@
+use modkit_db_macros::Scopable;
+use sea_orm::entity::prelude::*;
+
+/// Persisted directory registration.
+///
+/// Directory state is system-wide, so the entity is unrestricted. Timestamps
+/// are Unix epoch milliseconds; the registered endpoints, version and weight
+/// are stored as a JSON document in `spec` (see `sea_orm_store::InstanceSpec`).
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Scopable)]
+#[sea_orm(table_name = "directory_instances")]
+#[secure(unrestricted)]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub module: String,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub instance_id: String,
+    #[sea_orm(column_type = "Text")]
+    pub spec: String,
+    pub ttl_ms: Option<i64>,
+    pub registered_at_ms: i64,
+    pub last_heartbeat_ms: Option<i64>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
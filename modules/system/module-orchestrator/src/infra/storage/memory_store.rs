@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::SystemTime;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use parking_lot::Mutex;
+
+use crate::domain::store::{DirectoryStore, StoredInstance};
+
+/// Process-local store for tests; counts heartbeat writes.
+#[derive(Default)]
+pub struct InMemoryDirectoryStore {
+    instances: Mutex<HashMap<(String, String), StoredInstance>>,
+    heartbeat_writes: AtomicUsize,
+}
+
+impl InMemoryDirectoryStore {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of stored rows, expired or not.
+    pub fn len(&self) -> usize {
+        self.instances.lock().len()
+    }
+
+    /// Number of `record_heartbeat` calls so far.
+    pub fn heartbeat_writes(&self) -> usize {
+        self.heartbeat_writes.load(Ordering::Relaxed)
+    }
+}
+
+#[async_trait]
+impl DirectoryStore for InMemoryDirectoryStore {
+    async fn save(&self, instance: StoredInstance) -> Result<()> {
+        let key = (
+            instance.info.module.clone(),
+            instance.info.instance_id.clone(),
+        );
+        self.instances.lock().insert(key, instance);
+        Ok(())
+    }
+
+    async fn record_heartbeat(
+        &self,
+        module: &str,
+        instance_id: &str,
+        at: SystemTime,
+    ) -> Result<()> {
+        self.heartbeat_writes.fetch_add(1, Ordering::Relaxed);
+        if let Some(instance) = self
+            .instances
+            .lock()
+            .get_mut(&(module.to_owned(), instance_id.to_owned()))
+        {
+            instance.last_heartbeat = Some(at);
+        }
+        Ok(())
+    }
+
+    async fn remove(&self, module: &str, instance_id: &str) -> Result<()> {
+        self.instances
+            .lock()
+            .remove(&(module.to_owned(), instance_id.to_owned()));
+        Ok(())
+    }
+
+    async fn load(&self, now: SystemTime) -> Result<Vec<StoredInstance>> {
+        let mut instances = self.instances.lock();
+        instances.retain(|_, instance| !instance.is_expired(now));
+        Ok(instances.values().cloned().collect())
+    }
+}
@@ -0,0 +1,53 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::ConnectionTrait;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let backend = manager.get_database_backend();
+        let conn = manager.get_connection();
+
+        let sql = match backend {
+            sea_orm::DatabaseBackend::Postgres | sea_orm::DatabaseBackend::MySql => {
+                r"
+CREATE TABLE IF NOT EXISTS directory_instances (
+    module VARCHAR(255) NOT NULL,
+    instance_id VARCHAR(255) NOT NULL,
+    spec TEXT NOT NULL,
+    ttl_ms BIGINT NULL,
+    registered_at_ms BIGINT NOT NULL,
+    last_heartbeat_ms BIGINT NULL,
+    PRIMARY KEY (module, instance_id)
+);
+                "
+            }
+            sea_orm::DatabaseBackend::Sqlite => {
+                r"
+CREATE TABLE IF NOT EXISTS directory_instances (
+    module TEXT NOT NULL,
+    instance_id TEXT NOT NULL,
+    spec TEXT NOT NULL,
+    ttl_ms INTEGER NULL,
+    registered_at_ms INTEGER NOT NULL,
+    last_heartbeat_ms INTEGER NULL,
+    PRIMARY KEY (module, instance_id)
+);
+                "
+            }
+        };
+
+        conn.execute_unprepared(sql).await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("DROP TABLE IF EXISTS directory_instances;")
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+#[cfg(all(test, feature = "db-store"))]
+pub mod db;
+#[cfg(feature = "db-store")]
+pub mod entity;
+#[cfg(test)]
+pub mod memory_store;
+#[cfg(feature = "db-store")]
+pub mod migrations;
+#[cfg(feature = "db-store")]
+pub mod sea_orm_store;
+
+#[cfg(test)]
+pub use memory_store::InMemoryDirectoryStore;
+#[cfg(feature = "db-store")]
+pub use sea_orm_store::SeaOrmDirectoryStore;
@@ -0,0 +1,183 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use modkit_db::secure::{
+    SecureDeleteExt, SecureEntityExt, SecureInsertExt, SecureOnConflict, SecureUpdateExt,
+};
+use modkit_db::{DBProvider, DbError};
+use modkit_security::AccessScope;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ActiveValue, ColumnTrait, Condition, EntityTrait};
+use serde::{Deserialize, Serialize};
+
+use cf_system_sdks::directory::{RegisterInstanceInfo, ServiceEndpoint};
+
+use super::entity::{self, Column, Entity as InstanceEntity};
+use crate::domain::store::{DirectoryStore, StoredInstance};
+
+/// Registration details kept as JSON in the `spec` column.
+#[derive(Serialize, Deserialize)]
+struct InstanceSpec {
+    grpc_services: Vec<(String, String)>,
+    #[serde(default)]
+    endpoints: Vec<(String, String)>,
+    version: Option<String>,
+    weight: Option<u32>,
+}
+
+/// SeaORM-backed store; registrations survive directory restarts.
+pub struct SeaOrmDirectoryStore {
+    db: DBProvider<DbError>,
+}
+
+impl SeaOrmDirectoryStore {
+    #[must_use]
+    pub fn new(db: DBProvider<DbError>) -> Self {
+        Self { db }
+    }
+}
+
+fn to_millis(t: SystemTime) -> i64 {
+    let ms = t.duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+    i64::try_from(ms).unwrap_or(i64::MAX)
+}
+
+fn from_millis(ms: i64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_millis(u64::try_from(ms).unwrap_or_default())
+}
+
+fn key(module: &str, instance_id: &str) -> Condition {
+    Condition::all()
+        .add(Column::Module.eq(module))
+        .add(Column::InstanceId.eq(instance_id))
+}
+
+fn pairs(endpoints: Vec<(String, ServiceEndpoint)>) -> Vec<(String, String)> {
+    endpoints
+        .into_iter()
+        .map(|(name, ep)| (name, ep.uri))
+        .collect()
+}
+
+fn endpoints(pairs: Vec<(String, String)>) -> Vec<(String, ServiceEndpoint)> {
+    pairs
+        .into_iter()
+        .map(|(name, uri)| (name, ServiceEndpoint::new(uri)))
+        .collect()
+}
+
+fn to_active_model(instance: StoredInstance) -> Result<entity::ActiveModel> {
+    let ttl_ms = instance
+        .info
+        .ttl
+        .map(|ttl| i64::try_from(ttl.as_millis()).unwrap_or(i64::MAX));
+    let spec = serde_json::to_string(&InstanceSpec {
+        grpc_services: pairs(instance.info.grpc_services),
+        endpoints: pairs(instance.info.endpoints),
+        version: instance.info.version,
+        weight: instance.info.weight,
+    })?;
+    Ok(entity::ActiveModel {
+        module: ActiveValue::Set(instance.info.module),
+        instance_id: ActiveValue::Set(instance.info.instance_id),
+        spec: ActiveValue::Set(spec),
+        ttl_ms: ActiveValue::Set(ttl_ms),
+        registered_at_ms: ActiveValue::Set(to_millis(instance.registered_at)),
+        last_heartbeat_ms: ActiveValue::Set(instance.last_heartbeat.map(to_millis)),
+    })
+}
+
+fn from_model(m: entity::Model) -> Result<StoredInstance> {
+    let spec: InstanceSpec = serde_json::from_str(&m.spec)
+        .with_context(|| format!("invalid stored spec for {}/{}", m.module, m.instance_id))?;
+    Ok(StoredInstance {
+        info: RegisterInstanceInfo {
+            module: m.module,
+            instance_id: m.instance_id,
+            grpc_services: endpoints(spec.grpc_services),
+            version: spec.version,
+            ttl: m
+                .ttl_ms
+                .map(|ms| Duration::from_millis(u64::try_from(ms).unwrap_or_default())),
+            weight: spec.weight,
+            endpoints: endpoints(spec.endpoints),
+        },
+        registered_at: from_millis(m.registered_at_ms),
+        last_heartbeat: m.last_heartbeat_ms.map(from_millis),
+    })
+}
+
+#[async_trait]
+impl DirectoryStore for SeaOrmDirectoryStore {
+    async fn save(&self, instance: StoredInstance) -> Result<()> {
+        let conn = self.db.conn()?;
+        let scope = AccessScope::allow_all();
+        let am = to_active_model(instance)?;
+
+        let upsert =
+            SecureOnConflict::<InstanceEntity>::columns([Column::Module, Column::InstanceId])
+                .update_columns([
+                    Column::Spec,
+                    Column::TtlMs,
+                    Column::RegisteredAtMs,
+                    Column::LastHeartbeatMs,
+                ])?;
+        InstanceEntity::insert(am)
+            .secure()
+            .scope_unchecked(&scope)?
+            .on_conflict(upsert)
+            .exec(&conn)
+            .await?;
+        Ok(())
+    }
+
+    async fn record_heartbeat(
+        &self,
+        module: &str,
+        instance_id: &str,
+        at: SystemTime,
+    ) -> Result<()> {
+        let conn = self.db.conn()?;
+        InstanceEntity::update_many()
+            .secure()
+            .col_expr(Column::LastHeartbeatMs, Expr::value(to_millis(at)))
+            .scope_with(&AccessScope::allow_all())
+            .filter(key(module, instance_id))
+            .exec(&conn)
+            .await?;
+        Ok(())
+    }
+
+    async fn remove(&self, module: &str, instance_id: &str) -> Result<()> {
+        let conn = self.db.conn()?;
+        InstanceEntity::delete_many()
+            .secure()
+            .scope_with(&AccessScope::allow_all())
+            .filter(key(module, instance_id))
+            .exec(&conn)
+            .await?;
+        Ok(())
+    }
+
+    async fn load(&self, now: SystemTime) -> Result<Vec<StoredInstance>> {
+        let conn = self.db.conn()?;
+        let models = InstanceEntity::find()
+            .secure()
+            .scope_with(&AccessScope::allow_all())
+            .all(&conn)
+            .await?;
+
+        let mut live = Vec::with_capacity(models.len());
+        for model in models {
+            let instance = from_model(model)?;
+            if instance.is_expired(now) {
+                self.remove(&instance.info.module, &instance.info.instance_id)
+                    .await?;
+            } else {
+                live.push(instance);
+            }
+        }
+        Ok(live)
+    }
+}
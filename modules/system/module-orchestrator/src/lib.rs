@@ -6,11 +6,12 @@
 
 // === MODULE DEFINITION ===
 pub mod module;
-pub use module::{ModuleOrchestrator, ModuleOrchestratorConfig};
+pub use module::{ModuleOrchestrator, ModuleOrchestratorConfig, StorageBackend};
 
 // === INTERNAL MODULES (pub for integration tests) ===
 pub mod api;
 pub mod domain;
+pub(crate) mod infra;
 mod server;
 
 // === RE-EXPORTS ===
@@ -3,9 +3,12 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
 
 use modkit::DirectoryClient;
+use modkit::config::ConfigProvider;
 use modkit::context::ModuleCtx;
 use modkit::contracts::{
     GrpcServiceCapability, OpenApiRegistry, RegisterGrpcServiceFn, RestApiCapability,
@@ -17,16 +20,29 @@ use modkit::runtime::ModuleManager;
 
 use cf_system_sdks::directory::DIRECTORY_SERVICE_NAME;
 
+use crate::domain::directory::PersistentDirectoryClient;
 use crate::domain::service::ModulesService;
 use crate::server;
 
 /// Configuration for the module orchestrator
 #[derive(Clone, Debug, Default, serde::Deserialize)]
-#[allow(
-    clippy::empty_structs_with_brackets,
-    reason = "empty struct is required for config deserialization"
-)]
-pub struct ModuleOrchestratorConfig {}
+pub struct ModuleOrchestratorConfig {
+    /// Persistence backend for instance registrations. `in_memory` (default)
+    /// loses them on restart; `database` stores them through the module's
+    /// configured database (requires the `db-store` feature and a `database`
+    /// section) and reloads the ones whose heartbeat TTL has not lapsed.
+    #[serde(default)]
+    pub storage: StorageBackend,
+}
+
+/// Persistence backend for directory registrations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageBackend {
+    #[default]
+    InMemory,
+    Database,
+}
 
 /// Module Orchestrator - system module for service discovery
 ///
@@ -34,15 +50,32 @@ pub struct ModuleOrchestratorConfig {}
 /// - Provides `DirectoryClient` to the `ClientHub` for in-process modules
 /// - Exposes `DirectoryService` gRPC service via `grpc-hub`
 /// - Tracks module instances and provides service resolution
+/// - Evicts instances whose heartbeat TTL lapsed
+/// - Optionally persists registrations across restarts (see `StorageBackend`)
 /// - Exposes REST API to list all registered modules
-#[modkit::module(
-    name = "module-orchestrator",
-    capabilities = [grpc, system, rest],
-    client = cf_system_sdks::directory::DirectoryClient
+#[cfg_attr(
+    feature = "db-store",
+    modkit::module(
+        name = "module-orchestrator",
+        capabilities = [grpc, system, rest, stateful, db],
+        client = cf_system_sdks::directory::DirectoryClient,
+        lifecycle(entry = "reap_expired_instances", stop_timeout = "5s")
+    )
+)]
+#[cfg_attr(
+    not(feature = "db-store"),
+    modkit::module(
+        name = "module-orchestrator",
+        capabilities = [grpc, system, rest, stateful],
+        client = cf_system_sdks::directory::DirectoryClient,
+        lifecycle(entry = "reap_expired_instances", stop_timeout = "5s")
+    )
 )]
 pub struct ModuleOrchestrator {
     config: RwLock<ModuleOrchestratorConfig>,
     directory_api: OnceLock<Arc<dyn DirectoryClient>>,
+    /// Set when registrations are persisted; its reaper also deletes rows.
+    persistent_directory: OnceLock<Arc<PersistentDirectoryClient>>,
     module_manager: OnceLock<Arc<ModuleManager>>,
     modules_service: OnceLock<Arc<ModulesService>>,
 }
@@ -50,14 +83,69 @@ pub struct ModuleOrchestrator {
 impl Default for ModuleOrchestrator {
     fn default() -> Self {
         Self {
-            config: RwLock::new(ModuleOrchestratorConfig {}),
+            config: RwLock::new(ModuleOrchestratorConfig::default()),
             directory_api: OnceLock::new(),
+            persistent_directory: OnceLock::new(),
             module_manager: OnceLock::new(),
             modules_service: OnceLock::new(),
         }
     }
 }
 
+/// How often the reaper checks instance heartbeat TTLs.
+const REAPER_INTERVAL: Duration = Duration::from_secs(1);
+
+impl ModuleOrchestrator {
+    /// Lifecycle entry: evict instances that stopped heartbeating until shutdown.
+    async fn reap_expired_instances(&self, cancel: CancellationToken) -> Result<()> {
+        if let Some(directory) = self.persistent_directory.get() {
+            directory.reap_expired(REAPER_INTERVAL, cancel).await;
+            return Ok(());
+        }
+        let manager =
+            self.module_manager.get().cloned().ok_or_else(|| {
+                anyhow::anyhow!("ModuleManager not wired into ModuleOrchestrator")
+            })?;
+        manager.reap_expired(REAPER_INTERVAL, cancel).await;
+        Ok(())
+    }
+
+    /// Build the persistent directory for the `database` backend.
+    #[cfg(feature = "db-store")]
+    async fn database_directory(
+        ctx: &ModuleCtx,
+        manager: Arc<ModuleManager>,
+    ) -> Result<Arc<PersistentDirectoryClient>> {
+        tracing::info!("Directory storage: database");
+        let store = Arc::new(crate::infra::storage::SeaOrmDirectoryStore::new(
+            ctx.db_required()?,
+        ));
+        let directory = Arc::new(PersistentDirectoryClient::new(manager, store));
+        let restored = directory.restore().await?;
+        if restored > 0 {
+            tracing::info!(restored, "Restored directory registrations from storage");
+        }
+        Ok(directory)
+    }
+
+    #[cfg(not(feature = "db-store"))]
+    #[allow(clippy::unused_async)]
+    async fn database_directory(
+        _ctx: &ModuleCtx,
+        _manager: Arc<ModuleManager>,
+    ) -> Result<Arc<PersistentDirectoryClient>> {
+        anyhow::bail!("module-orchestrator `storage: database` requires the `db-store` feature")
+    }
+}
+
+#[cfg(feature = "db-store")]
+impl modkit::DatabaseCapability for ModuleOrchestrator {
+    fn migrations(&self) -> Vec<Box<dyn sea_orm_migration::MigrationTrait>> {
+        use sea_orm_migration::MigratorTrait;
+        crate::infra::storage::migrations::Migrator::migrations()
+    }
+}
+
 #[async_trait]
 impl SystemCapability for ModuleOrchestrator {
     fn pre_init(&self, sys: &modkit::runtime::SystemContext) -> anyhow::Result<()> {
@@ -73,7 +161,7 @@ impl modkit::Module for ModuleOrchestrator {
     async fn init(&self, ctx: &ModuleCtx) -> Result<()> {
         // Load configuration if present
         let cfg = ctx.config_or_default::<ModuleOrchestratorConfig>()?;
-        *self.config.write().await = cfg;
+        *self.config.write().await = cfg.clone();
 
         // Use the injected ModuleManager to create the DirectoryClient
         let manager =
@@ -81,8 +169,18 @@ impl modkit::Module for ModuleOrchestrator {
                 anyhow::anyhow!("ModuleManager not wired into ModuleOrchestrator")
             })?;
 
-        let api_impl: Arc<dyn DirectoryClient> =
-            Arc::new(LocalDirectoryClient::new(manager.clone()));
+        let api_impl: Arc<dyn DirectoryClient> = match cfg.storage {
+            StorageBackend::InMemory => Arc::new(LocalDirectoryClient::new(manager.clone())),
+            StorageBackend::Database => {
+                let directory = Self::database_directory(ctx, manager.clone()).await?;
+                self.persistent_directory
+                    .set(Arc::clone(&directory))
+                    .map_err(|_| {
+                        anyhow::anyhow!("DirectoryClient already set (init called twice?)")
+                    })?;
+                directory
+            }
+        };
 
         // Register in ClientHub directly
         ctx.client_hub()
@@ -93,8 +191,7 @@ impl modkit::Module for ModuleOrchestrator {
             .map_err(|_| anyhow::anyhow!("DirectoryClient already set (init called twice?)"))?;
 
         // Build compiled-module catalog from inventory and create the ModulesService
-        let registry = ModuleRegistry::discover_and_build()
-            .map_err(|e| anyhow::anyhow!("Failed to build module registry: {e}"))?;
+        let registry = enabled_module_registry(ctx.config_provider())?;
         let modules_service = Arc::new(ModulesService::new(&registry, manager));
         self.modules_service
             .set(modules_service)
@@ -106,6 +203,13 @@ impl modkit::Module for ModuleOrchestrator {
     }
 }
 
+/// Builds the compiled-module catalog, leaving out modules that are
+/// disabled in the configuration (`modules.<name>.enabled: false`).
+fn enabled_module_registry(config: &dyn ConfigProvider) -> Result<ModuleRegistry> {
+    ModuleRegistry::discover_and_build_with(|name| config.is_module_enabled(name))
+        .map_err(|e| anyhow::anyhow!("Failed to build module registry: {e}"))
+}
+
 impl RestApiCapability for ModuleOrchestrator {
     fn register_rest(
         &self,
@@ -147,3 +251,27 @@ impl GrpcServiceCapability for ModuleOrchestrator {
         }])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct DisabledModules(&'static [&'static str]);
+
+    impl ConfigProvider for DisabledModules {
+        fn get_module_config(&self, module_name: &str) -> Option<&serde_json::Value> {
+            static DISABLED: std::sync::LazyLock<serde_json::Value> =
+                std::sync::LazyLock::new(|| serde_json::json!({ "enabled": false }));
+            self.0.contains(&module_name).then(|| &*DISABLED)
+        }
+    }
+
+    #[test]
+    fn catalog_excludes_disabled_modules() {
+        let registry = enabled_module_registry(&DisabledModules(&[])).unwrap();
+        assert!(registry.entry("module-orchestrator").is_some());
+
+        let registry = enabled_module_registry(&DisabledModules(&["module-orchestrator"])).unwrap();
+        assert!(registry.entry("module-orchestrator").is_none());
+    }
+}
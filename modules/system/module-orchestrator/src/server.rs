@@ -2,14 +2,18 @@
 //!
 //! This module provides the gRPC service implementation for Directory Service.
 
+use std::pin::Pin;
 use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::{Stream, StreamExt};
 use tonic::{Request, Response, Status};
 
 use cf_system_sdks::directory::{
     DeregisterInstanceRequest, DirectoryClient, DirectoryService, DirectoryServiceServer,
     HeartbeatRequest, InstanceInfo, ListInstancesRequest, ListInstancesResponse,
     RegisterInstanceInfo, RegisterInstanceRequest, ResolveGrpcServiceRequest,
-    ResolveGrpcServiceResponse, ServiceEndpoint,
+    ResolveGrpcServiceResponse, ServiceEndpoint, ServiceInstanceInfo,
 };
 
 /// gRPC service implementation of Directory Service
@@ -55,19 +59,42 @@ impl DirectoryService for DirectoryServiceImpl {
             .await
             .map_err(|e| Status::internal(e.to_string()))?;
 
-        let resp = ListInstancesResponse {
-            instances: instances
-                .into_iter()
-                .map(|i| InstanceInfo {
-                    module_name: i.module,
-                    instance_id: i.instance_id,
-                    endpoint_uri: i.endpoint.uri,
-                    version: i.version.unwrap_or_default(),
-                })
-                .collect(),
-        };
+        Ok(Response::new(instances_to_proto(instances)))
+    }
+
+    async fn list_service_instances(
+        &self,
+        request: Request<ResolveGrpcServiceRequest>,
+    ) -> Result<Response<ListInstancesResponse>, Status> {
+        let service_name = request.into_inner().service_name;
+
+        let instances = self
+            .api
+            .list_service_instances(&service_name)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        Ok(Response::new(instances_to_proto(instances)))
+    }
+
+    type WatchInstancesStream =
+        Pin<Box<dyn Stream<Item = Result<ListInstancesResponse, Status>> + Send>>;
+
+    async fn watch_instances(
+        &self,
+        request: Request<ListInstancesRequest>,
+    ) -> Result<Response<Self::WatchInstancesStream>, Status> {
+        let module_name = request.into_inner().module_name;
+
+        let updates = self
+            .api
+            .watch(&module_name)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
 
-        Ok(Response::new(resp))
+        Ok(Response::new(Box::pin(
+            updates.map(|instances| Ok(instances_to_proto(instances))),
+        )))
     }
 
     async fn register_instance(
@@ -92,6 +119,13 @@ impl DirectoryService for DirectoryServiceImpl {
             } else {
                 Some(req.version)
             },
+            ttl: (req.ttl_ms > 0).then(|| Duration::from_millis(req.ttl_ms)),
+            weight: (req.weight > 0).then_some(req.weight),
+            endpoints: req
+                .endpoints
+                .into_iter()
+                .map(|(name, uri)| (name, ServiceEndpoint::new(uri)))
+                .collect(),
         };
 
         self.api
@@ -128,6 +162,27 @@ impl DirectoryService for DirectoryServiceImpl {
     }
 }
 
+fn instances_to_proto(instances: Vec<ServiceInstanceInfo>) -> ListInstancesResponse {
+    ListInstancesResponse {
+        instances: instances
+            .into_iter()
+            .map(|i| InstanceInfo {
+                module_name: i.module,
+                instance_id: i.instance_id,
+                endpoint_uri: i.endpoint.uri,
+                version: i.version.unwrap_or_default(),
+                weight: i.weight,
+                healthy: i.healthy,
+                endpoints: i
+                    .endpoints
+                    .into_iter()
+                    .map(|(name, ep)| (name, ep.uri))
+                    .collect(),
+            })
+            .collect(),
+    }
+}
+
 /// Create a `DirectoryService` server with the given API implementation
 pub fn make_directory_service(
     api: Arc<dyn DirectoryClient>,
@@ -20,6 +20,8 @@ use crate::{
 pub enum ErrorSource {
     Gateway,
     Upstream,
+    /// A plugin aborted the pipeline; its id is in [`ErrorPlugin`].
+    Plugin,
 }
 
 impl ErrorSource {
@@ -29,10 +31,18 @@ impl ErrorSource {
         match self {
             Self::Gateway => "gateway",
             Self::Upstream => "upstream",
+            Self::Plugin => "plugin",
         }
     }
 }
 
+/// GTS identifier of the plugin that aborted the pipeline.
+///
+/// Accompanies [`ErrorSource::Plugin`]: available on proxy responses via
+/// `resp.extensions().get::<ErrorPlugin>()` and sent as `x-oagw-error-plugin`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorPlugin(pub String);
+
 // ---------------------------------------------------------------------------
 // Service trait
 // ---------------------------------------------------------------------------
@@ -128,7 +138,8 @@ pub trait ServiceGatewayClientV1: Send + Sync {
     /// Execute the full proxy pipeline: resolve -> auth -> rate-limit -> forward -> respond.
     ///
     /// The request URI must follow `/{alias}/{path_suffix}?query` convention.
-    /// `ErrorSource` is available on the response via `resp.extensions().get::<ErrorSource>()`.
+    /// `ErrorSource` is available on the response via `resp.extensions().get::<ErrorSource>()`,
+    /// and for plugin errors the plugin's id via `resp.extensions().get::<ErrorPlugin>()`.
     ///
     /// # Protocol mapping
     ///
@@ -138,7 +149,11 @@ pub trait ServiceGatewayClientV1: Send + Sync {
     /// |-----------|-----------------------|------------------------|
     /// | HTTP      | `Body::Bytes`/`Empty` | `Body::Bytes`          |
     /// | SSE       | `Body::Bytes`/`Empty` | `Body::Stream`         |
-    /// | WebSocket | `Body::Stream`        | `Body::Stream`         |
+    /// | WebSocket | `Body::Empty`         | `Body::Empty`          |
+    ///
+    /// A successful WebSocket upgrade returns `101 Switching Protocols` with a
+    /// [`WebSocketUpgrade`](crate::ws::WebSocketUpgrade) in the response
+    /// extensions, which yields the message stream to the upstream.
     async fn proxy_request(
         &self,
         ctx: SecurityContext,
@@ -2,6 +2,9 @@ use std::pin::Pin;
 
 use bytes::Bytes;
 use futures_core::Stream;
+use futures_util::TryStreamExt as _;
+use tokio::io::AsyncRead;
+use tokio_util::io::ReaderStream;
 
 /// Boxed error type for body stream errors.
 pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
@@ -86,6 +89,22 @@ impl Body {
         }
     }
 
+    /// Stream a body from `reader` (a file, socket, ...) in chunks of up to
+    /// `chunk_size` bytes. A `chunk_size` of 0 uses a 4 KiB default.
+    ///
+    /// Read errors end the stream with that error.
+    pub fn from_async_read<R>(reader: R, chunk_size: usize) -> Self
+    where
+        R: AsyncRead + Send + 'static,
+    {
+        let stream = if chunk_size == 0 {
+            ReaderStream::new(reader)
+        } else {
+            ReaderStream::with_capacity(reader, chunk_size)
+        };
+        Body::Stream(Box::pin(stream.map_err(BoxError::from)))
+    }
+
     /// Try to extract the inner `Bytes`.
     ///
     /// Returns `Err(self)` if this is not `Body::Bytes`.
@@ -234,6 +253,40 @@ mod tests {
         assert_eq!(bytes, Bytes::from("hello"));
     }
 
+    #[tokio::test]
+    async fn from_async_read_streams_file_in_chunks() {
+        let content: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), &content).unwrap();
+        let reader = tokio::fs::File::open(file.path()).await.unwrap();
+
+        let body = Body::from_async_read(reader, 1024);
+
+        assert!(matches!(body, Body::Stream(_)));
+        assert_eq!(body.into_bytes().await.unwrap(), Bytes::from(content));
+    }
+
+    struct FailingReader;
+
+    impl AsyncRead for FailingReader {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            _cx: &mut std::task::Context<'_>,
+            _buf: &mut tokio::io::ReadBuf<'_>,
+        ) -> std::task::Poll<std::io::Result<()>> {
+            std::task::Poll::Ready(Err(std::io::Error::other("disk gone")))
+        }
+    }
+
+    #[tokio::test]
+    async fn from_async_read_propagates_read_errors() {
+        let err = Body::from_async_read(FailingReader, 1024)
+            .into_bytes()
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "disk gone");
+    }
+
     #[test]
     fn try_into_bytes_succeeds() {
         let body = Body::Bytes(Bytes::from("data"));
@@ -1,9 +1,17 @@
+use http::StatusCode;
+
+use crate::api::ErrorSource;
+
 /// Gateway-originated error with all information needed to produce a Problem Details response.
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum ServiceGatewayError {
     #[error("{detail}")]
     ValidationError { detail: String, instance: String },
 
+    /// The request conflicts with existing state, e.g. a duplicate alias.
+    #[error("{detail}")]
+    Conflict { detail: String },
+
     #[error("target host header required for multi-endpoint upstream")]
     MissingTargetHost { instance: String },
 
@@ -25,6 +33,11 @@ pub enum ServiceGatewayError {
     #[error("{detail}")]
     PayloadTooLarge { detail: String, instance: String },
 
+    /// The request's media type is not accepted by the gateway's
+    /// content-type policy.
+    #[error("{detail}")]
+    UnsupportedMediaType { detail: String, instance: String },
+
     #[error("{detail}")]
     RateLimitExceeded {
         detail: String,
@@ -57,6 +70,8 @@ pub enum ServiceGatewayError {
         error_code: String,
         detail: String,
         instance: String,
+        /// GTS identifier of the rejecting guard plugin.
+        plugin_id: String,
     },
 
     #[error("{detail}")]
@@ -80,6 +95,85 @@ pub enum ServiceGatewayError {
     /// The caller is authenticated but not authorized to perform the requested action.
     #[error("access forbidden: {detail}")]
     Forbidden { detail: String },
+
+    /// Unexpected gateway failure not attributable to the request or the upstream.
+    #[error("internal error: {detail}")]
+    Internal { detail: String },
+
+    /// An auth or transform plugin aborted the pipeline with `error`.
+    #[error("{error}")]
+    PluginFailed {
+        /// GTS identifier of the failing plugin.
+        plugin_id: String,
+        error: Box<ServiceGatewayError>,
+    },
+}
+
+impl ServiceGatewayError {
+    /// HTTP status code for this error, matching what the REST proxy returns.
+    ///
+    /// `GuardRejected` uses the guard's status when it is a 4xx/5xx code and
+    /// falls back to 400 otherwise.
+    #[must_use]
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            Self::ValidationError { .. }
+            | Self::MissingTargetHost { .. }
+            | Self::InvalidTargetHost { .. }
+            | Self::UnknownTargetHost { .. } => StatusCode::BAD_REQUEST,
+            Self::AuthenticationFailed { .. } => StatusCode::UNAUTHORIZED,
+            Self::Forbidden { .. } => StatusCode::FORBIDDEN,
+            Self::NotFound { .. } | Self::RouteNotFound { .. } | Self::PluginNotFound { .. } => {
+                StatusCode::NOT_FOUND
+            }
+            Self::Conflict { .. } | Self::PluginInUse { .. } => StatusCode::CONFLICT,
+            Self::PayloadTooLarge { .. } => StatusCode::PAYLOAD_TOO_LARGE,
+            Self::UnsupportedMediaType { .. } => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            Self::RateLimitExceeded { .. } => StatusCode::TOO_MANY_REQUESTS,
+            Self::SecretNotFound { .. } | Self::Internal { .. } => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+            Self::DownstreamError { .. }
+            | Self::ProtocolError { .. }
+            | Self::StreamAborted { .. } => StatusCode::BAD_GATEWAY,
+            Self::UpstreamDisabled { .. }
+            | Self::LinkUnavailable { .. }
+            | Self::CircuitBreakerOpen { .. } => StatusCode::SERVICE_UNAVAILABLE,
+            Self::ConnectionTimeout { .. }
+            | Self::RequestTimeout { .. }
+            | Self::IdleTimeout { .. } => StatusCode::GATEWAY_TIMEOUT,
+            Self::GuardRejected { status, .. } => StatusCode::from_u16(*status)
+                .ok()
+                .filter(|code| code.is_client_error() || code.is_server_error())
+                .unwrap_or(StatusCode::BAD_REQUEST),
+            Self::PluginFailed { error, .. } => error.status_code(),
+        }
+    }
+
+    /// Where the error originated.
+    ///
+    /// [`ErrorSource::Plugin`] for guard rejections and plugin failures,
+    /// [`ErrorSource::Gateway`] otherwise: upstream errors reach the caller as
+    /// ordinary responses, never as a `ServiceGatewayError`.
+    #[must_use]
+    pub fn error_source(&self) -> ErrorSource {
+        if self.plugin_id().is_some() {
+            ErrorSource::Plugin
+        } else {
+            ErrorSource::Gateway
+        }
+    }
+
+    /// GTS identifier of the plugin that raised the error, if a plugin did.
+    #[must_use]
+    pub fn plugin_id(&self) -> Option<&str> {
+        match self {
+            Self::GuardRejected { plugin_id, .. } | Self::PluginFailed { plugin_id, .. } => {
+                Some(plugin_id)
+            }
+            _ => None,
+        }
+    }
 }
 
 /// Errors produced by the streaming helpers.
@@ -89,6 +183,11 @@ pub enum StreamingError {
     #[error("SSE parse error: {detail}")]
     ServerEventsParse { detail: String },
 
+    /// The upstream reported an error inside an otherwise valid stream,
+    /// e.g. an LLM provider's `error` event.
+    #[error("provider stream error: {detail}")]
+    Provider { detail: String },
+
     /// Underlying byte stream produced an error.
     #[error("stream error: {0}")]
     Stream(#[from] Box<dyn std::error::Error + Send + Sync>),
@@ -101,3 +200,235 @@ pub enum StreamingError {
     #[error("WebSocket bridge error: {detail}")]
     WebSocketBridge { detail: String },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detail() -> String {
+        "test".to_string()
+    }
+
+    fn instance() -> String {
+        "/test".to_string()
+    }
+
+    #[test]
+    fn status_code_covers_every_variant() {
+        let cases = [
+            (
+                ServiceGatewayError::ValidationError {
+                    detail: detail(),
+                    instance: instance(),
+                },
+                StatusCode::BAD_REQUEST,
+            ),
+            (
+                ServiceGatewayError::Conflict { detail: detail() },
+                StatusCode::CONFLICT,
+            ),
+            (
+                ServiceGatewayError::MissingTargetHost {
+                    instance: instance(),
+                },
+                StatusCode::BAD_REQUEST,
+            ),
+            (
+                ServiceGatewayError::InvalidTargetHost {
+                    instance: instance(),
+                },
+                StatusCode::BAD_REQUEST,
+            ),
+            (
+                ServiceGatewayError::UnknownTargetHost {
+                    detail: detail(),
+                    instance: instance(),
+                },
+                StatusCode::BAD_REQUEST,
+            ),
+            (
+                ServiceGatewayError::AuthenticationFailed {
+                    detail: detail(),
+                    instance: instance(),
+                },
+                StatusCode::UNAUTHORIZED,
+            ),
+            (
+                ServiceGatewayError::NotFound {
+                    entity: "upstream".into(),
+                    instance: instance(),
+                },
+                StatusCode::NOT_FOUND,
+            ),
+            (
+                ServiceGatewayError::RouteNotFound {
+                    instance: instance(),
+                },
+                StatusCode::NOT_FOUND,
+            ),
+            (
+                ServiceGatewayError::PayloadTooLarge {
+                    detail: detail(),
+                    instance: instance(),
+                },
+                StatusCode::PAYLOAD_TOO_LARGE,
+            ),
+            (
+                ServiceGatewayError::UnsupportedMediaType {
+                    detail: detail(),
+                    instance: instance(),
+                },
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            ),
+            (
+                ServiceGatewayError::RateLimitExceeded {
+                    detail: detail(),
+                    instance: instance(),
+                    retry_after_secs: Some(5),
+                },
+                StatusCode::TOO_MANY_REQUESTS,
+            ),
+            (
+                ServiceGatewayError::SecretNotFound {
+                    detail: detail(),
+                    instance: instance(),
+                },
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+            (
+                ServiceGatewayError::DownstreamError {
+                    detail: detail(),
+                    instance: instance(),
+                },
+                StatusCode::BAD_GATEWAY,
+            ),
+            (
+                ServiceGatewayError::ProtocolError {
+                    detail: detail(),
+                    instance: instance(),
+                },
+                StatusCode::BAD_GATEWAY,
+            ),
+            (
+                ServiceGatewayError::UpstreamDisabled {
+                    detail: detail(),
+                    instance: instance(),
+                },
+                StatusCode::SERVICE_UNAVAILABLE,
+            ),
+            (
+                ServiceGatewayError::ConnectionTimeout {
+                    detail: detail(),
+                    instance: instance(),
+                },
+                StatusCode::GATEWAY_TIMEOUT,
+            ),
+            (
+                ServiceGatewayError::RequestTimeout {
+                    detail: detail(),
+                    instance: instance(),
+                },
+                StatusCode::GATEWAY_TIMEOUT,
+            ),
+            (
+                ServiceGatewayError::GuardRejected {
+                    status: 422,
+                    error_code: "INVALID".into(),
+                    detail: detail(),
+                    instance: instance(),
+                    plugin_id: "gts.x.core.oagw.guard_plugin.v1~x.core.oagw.cors.v1".into(),
+                },
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ),
+            (
+                ServiceGatewayError::StreamAborted {
+                    detail: detail(),
+                    instance: instance(),
+                },
+                StatusCode::BAD_GATEWAY,
+            ),
+            (
+                ServiceGatewayError::LinkUnavailable {
+                    detail: detail(),
+                    instance: instance(),
+                },
+                StatusCode::SERVICE_UNAVAILABLE,
+            ),
+            (
+                ServiceGatewayError::CircuitBreakerOpen {
+                    detail: detail(),
+                    instance: instance(),
+                },
+                StatusCode::SERVICE_UNAVAILABLE,
+            ),
+            (
+                ServiceGatewayError::IdleTimeout {
+                    detail: detail(),
+                    instance: instance(),
+                },
+                StatusCode::GATEWAY_TIMEOUT,
+            ),
+            (
+                ServiceGatewayError::PluginNotFound { detail: detail() },
+                StatusCode::NOT_FOUND,
+            ),
+            (
+                ServiceGatewayError::PluginInUse { detail: detail() },
+                StatusCode::CONFLICT,
+            ),
+            (
+                ServiceGatewayError::Forbidden { detail: detail() },
+                StatusCode::FORBIDDEN,
+            ),
+            (
+                ServiceGatewayError::Internal { detail: detail() },
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+        ];
+
+        for (err, expected) in cases {
+            assert_eq!(err.status_code(), expected, "{err:?}");
+            match &err {
+                ServiceGatewayError::GuardRejected { plugin_id, .. } => {
+                    assert_eq!(err.error_source(), ErrorSource::Plugin);
+                    assert_eq!(err.plugin_id(), Some(plugin_id.as_str()));
+                }
+                _ => {
+                    assert_eq!(err.error_source(), ErrorSource::Gateway, "{err:?}");
+                    assert_eq!(err.plugin_id(), None);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn plugin_failure_keeps_inner_status_and_reports_plugin() {
+        let err = ServiceGatewayError::PluginFailed {
+            plugin_id: "gts.x.core.oagw.auth_plugin.v1~x.core.oagw.apikey.v1".into(),
+            error: Box::new(ServiceGatewayError::AuthenticationFailed {
+                detail: detail(),
+                instance: instance(),
+            }),
+        };
+        assert_eq!(err.status_code(), StatusCode::UNAUTHORIZED);
+        assert_eq!(err.error_source(), ErrorSource::Plugin);
+        assert_eq!(
+            err.plugin_id(),
+            Some("gts.x.core.oagw.auth_plugin.v1~x.core.oagw.apikey.v1")
+        );
+    }
+
+    #[test]
+    fn guard_rejected_non_error_status_falls_back_to_400() {
+        for status in [200, 301, 999] {
+            let err = ServiceGatewayError::GuardRejected {
+                status,
+                error_code: "X".into(),
+                detail: detail(),
+                instance: instance(),
+                plugin_id: String::new(),
+            };
+            assert_eq!(err.status_code(), StatusCode::BAD_REQUEST, "{status}");
+        }
+    }
+}
@@ -9,12 +9,13 @@ pub mod ws;
 pub mod models;
 
 pub use models::{
-    AuthConfig, BurstConfig, CorsConfig, CorsHttpMethod, CreateRouteRequest,
+    AuthConfig, BurstConfig, CorsConfig, CorsHttpMethod, CostStrategy, CreateRouteRequest,
     CreateRouteRequestBuilder, CreateUpstreamRequest, CreateUpstreamRequestBuilder, Endpoint,
-    GrpcMatch, HeadersConfig, HttpMatch, HttpMethod, ListQuery, MatchRules, PassthroughMode,
-    PathSuffixMode, PluginBinding, PluginsConfig, RateLimitAlgorithm, RateLimitConfig,
-    RateLimitScope, RateLimitStrategy, RequestHeaderRules, ResponseHeaderRules, Route, Scheme,
-    Server, SharingMode, SustainedRate, UpdateRouteRequest, UpdateRouteRequestBuilder,
+    GetCoalescingConfig, GrpcMatch, HeadersConfig, HttpMatch, HttpMethod, ListQuery, MatchRules,
+    PassthroughMode, PathSuffixMode, PluginBinding, PluginsConfig, RateLimitAlgorithm,
+    RateLimitConfig, RateLimitScope, RateLimitStrategy, RequestCompressionConfig,
+    RequestHeaderRules, ResponseCacheConfig, ResponseHeaderRules, Route, Scheme, Server,
+    SharingMode, SseReconnectConfig, SustainedRate, UpdateRouteRequest, UpdateRouteRequestBuilder,
     UpdateUpstreamRequest, UpdateUpstreamRequestBuilder, Upstream, Window,
 };
 
@@ -23,10 +24,13 @@ pub use body::Body;
 pub use codec::Json;
 pub use error::StreamingError;
 pub use multipart::{MultipartBody, MultipartError, Part};
-pub use sse::{FromServerEvent, ServerEvent, ServerEventsResponse, ServerEventsStream};
+pub use sse::{
+    ContentDelta, ContentDeltaStream, FromServerEvent, ServerEvent, ServerEventsResponse,
+    ServerEventsStream,
+};
 #[cfg(feature = "axum")]
 pub use ws::axum_adapter;
 pub use ws::{
     FromWebSocketMessage, WebSocketCloseFrame, WebSocketMessage, WebSocketReceiver,
-    WebSocketSender, WebSocketSink, WebSocketStream, WebSocketStreamReceiver,
+    WebSocketSender, WebSocketSink, WebSocketStream, WebSocketStreamReceiver, WebSocketUpgrade,
 };
@@ -36,6 +36,18 @@ pub enum Scheme {
     Grpc,
 }
 
+impl Scheme {
+    /// Well-known port for the scheme: 80 for `Http`, 443 for the TLS-based
+    /// schemes (`Https`, `Wss`, `Wt`, `Grpc`).
+    #[must_use]
+    pub fn default_port(self) -> u16 {
+        match self {
+            Self::Http => 80,
+            Self::Https | Self::Wss | Self::Wt | Self::Grpc => 443,
+        }
+    }
+}
+
 /// A single upstream endpoint (scheme + host + port).
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Endpoint {
@@ -46,10 +58,11 @@ pub struct Endpoint {
 
 impl Endpoint {
     /// Generate the alias contribution for this endpoint.
-    /// Standard ports (80, 443) are omitted; non-standard ports are appended as `:port`.
+    /// The scheme's standard port ([`Scheme::default_port`]) is omitted; any
+    /// other port is appended as `:port`.
     #[must_use]
     pub fn alias_contribution(&self) -> String {
-        if is_standard_port(self.port) {
+        if self.port == self.scheme.default_port() {
             self.host.clone()
         } else {
             format!("{}:{}", self.host, self.port)
@@ -57,10 +70,6 @@ impl Endpoint {
     }
 }
 
-fn is_standard_port(port: u16) -> bool {
-    port == 80 || port == 443
-}
-
 /// Container for upstream server endpoints.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Server {
@@ -137,7 +146,25 @@ pub struct RateLimitConfig {
     pub burst: Option<BurstConfig>,
     pub scope: RateLimitScope,
     pub strategy: RateLimitStrategy,
-    pub cost: u32,
+    pub cost: CostStrategy,
+}
+
+/// How many tokens a single request consumes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CostStrategy {
+    /// Constant cost per request.
+    Fixed(u32),
+    /// One token per started kilobyte of request body. A streamed body
+    /// without `Content-Length` is charged once it has been forwarded.
+    PerKilobyte,
+    /// Cost read from the named request header.
+    HeaderValue(String),
+}
+
+impl Default for CostStrategy {
+    fn default() -> Self {
+        Self::Fixed(1)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -162,6 +189,8 @@ pub enum Window {
     Minute,
     Hour,
     Day,
+    /// Custom window length in seconds. Must be non-zero.
+    Seconds(u32),
 }
 
 /// Burst capacity configuration.
@@ -235,6 +264,55 @@ pub struct CorsConfig {
     pub allow_credentials: bool,
 }
 
+/// Opt-in reconnection for proxied Server-Sent Events streams.
+///
+/// When the upstream drops an SSE response mid-stream, the gateway re-issues
+/// the request with `Last-Event-ID` set to the last event id it forwarded and
+/// continues the client stream from the new response. Each reconnect counts
+/// against the route's and upstream's rate limits like a new request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SseReconnectConfig {
+    /// Maximum number of reconnection attempts for one client stream.
+    pub max_retries: u32,
+}
+
+/// Opt-in single-flight coalescing of identical concurrent GET requests.
+///
+/// While a GET is in flight, identical GETs from the same tenant wait for it
+/// and receive a copy of its response instead of calling the upstream
+/// themselves. Requests are identical when their upstream URL and the values
+/// of `vary_headers` match. Responses without a `Content-Length` (such as
+/// event streams) are never shared; waiting requests then call the upstream
+/// on their own.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GetCoalescingConfig {
+    /// Request headers whose values distinguish otherwise identical requests.
+    pub vary_headers: Vec<String>,
+}
+
+/// Opt-in gzip compression of outbound request bodies.
+///
+/// Buffered request bodies larger than `min_bytes` are gzip-compressed after
+/// plugins run and sent with `Content-Encoding: gzip`. Bodies the client
+/// already encoded and streamed bodies are forwarded unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestCompressionConfig {
+    /// Bodies of at most this many bytes are sent uncompressed.
+    pub min_bytes: u64,
+}
+
+/// Opt-in caching of upstream GET responses.
+///
+/// Buffered `200 OK` responses with a `Content-Length` are stored for
+/// `ttl_secs` and served with `X-Cache: HIT`, `Age` and `X-Cache-Key`.
+/// Clients sending `Cache-Control: no-cache` or `max-age=0` bypass the
+/// stored copy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResponseCacheConfig {
+    /// How long a stored response is served, in seconds.
+    pub ttl_secs: u64,
+}
+
 // ---------------------------------------------------------------------------
 // Route matching
 // ---------------------------------------------------------------------------
@@ -297,6 +375,10 @@ pub struct Route {
     pub plugins: Option<PluginsConfig>,
     pub rate_limit: Option<RateLimitConfig>,
     pub cors: Option<CorsConfig>,
+    pub sse_reconnect: Option<SseReconnectConfig>,
+    pub get_coalescing: Option<GetCoalescingConfig>,
+    pub request_compression: Option<RequestCompressionConfig>,
+    pub response_cache: Option<ResponseCacheConfig>,
     pub tags: Vec<String>,
     pub priority: i32,
     pub enabled: bool,
@@ -356,6 +438,7 @@ pub struct CreateUpstreamRequest {
     cors: Option<CorsConfig>,
     tags: Vec<String>,
     enabled: bool,
+    idempotency_key: Option<String>,
 }
 
 impl CreateUpstreamRequest {
@@ -372,6 +455,7 @@ impl CreateUpstreamRequest {
             cors: None,
             tags: vec![],
             enabled: true,
+            idempotency_key: None,
         }
     }
 
@@ -405,6 +489,9 @@ impl CreateUpstreamRequest {
     pub fn enabled(&self) -> bool {
         self.enabled
     }
+    pub fn idempotency_key(&self) -> Option<&str> {
+        self.idempotency_key.as_deref()
+    }
 }
 
 pub struct CreateUpstreamRequestBuilder {
@@ -418,6 +505,7 @@ pub struct CreateUpstreamRequestBuilder {
     cors: Option<CorsConfig>,
     tags: Vec<String>,
     enabled: bool,
+    idempotency_key: Option<String>,
 }
 
 impl CreateUpstreamRequestBuilder {
@@ -453,6 +541,12 @@ impl CreateUpstreamRequestBuilder {
         self.enabled = enabled;
         self
     }
+    /// Client-chosen key that makes the create safe to retry: a repeat with
+    /// the same key returns the upstream created by the first request.
+    pub fn idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
     pub fn build(self) -> CreateUpstreamRequest {
         CreateUpstreamRequest {
             server: self.server,
@@ -465,6 +559,7 @@ impl CreateUpstreamRequestBuilder {
             cors: self.cors,
             tags: self.tags,
             enabled: self.enabled,
+            idempotency_key: self.idempotency_key,
         }
     }
 }
@@ -608,6 +703,10 @@ pub struct CreateRouteRequest {
     plugins: Option<PluginsConfig>,
     rate_limit: Option<RateLimitConfig>,
     cors: Option<CorsConfig>,
+    sse_reconnect: Option<SseReconnectConfig>,
+    get_coalescing: Option<GetCoalescingConfig>,
+    request_compression: Option<RequestCompressionConfig>,
+    response_cache: Option<ResponseCacheConfig>,
     tags: Vec<String>,
     priority: i32,
     enabled: bool,
@@ -622,6 +721,10 @@ impl CreateRouteRequest {
             plugins: None,
             rate_limit: None,
             cors: None,
+            sse_reconnect: None,
+            get_coalescing: None,
+            request_compression: None,
+            response_cache: None,
             tags: vec![],
             priority: 0,
             enabled: true,
@@ -643,6 +746,18 @@ impl CreateRouteRequest {
     pub fn cors(&self) -> Option<&CorsConfig> {
         self.cors.as_ref()
     }
+    pub fn sse_reconnect(&self) -> Option<&SseReconnectConfig> {
+        self.sse_reconnect.as_ref()
+    }
+    pub fn get_coalescing(&self) -> Option<&GetCoalescingConfig> {
+        self.get_coalescing.as_ref()
+    }
+    pub fn request_compression(&self) -> Option<&RequestCompressionConfig> {
+        self.request_compression.as_ref()
+    }
+    pub fn response_cache(&self) -> Option<&ResponseCacheConfig> {
+        self.response_cache.as_ref()
+    }
     pub fn tags(&self) -> &[String] {
         &self.tags
     }
@@ -660,6 +775,10 @@ pub struct CreateRouteRequestBuilder {
     plugins: Option<PluginsConfig>,
     rate_limit: Option<RateLimitConfig>,
     cors: Option<CorsConfig>,
+    sse_reconnect: Option<SseReconnectConfig>,
+    get_coalescing: Option<GetCoalescingConfig>,
+    request_compression: Option<RequestCompressionConfig>,
+    response_cache: Option<ResponseCacheConfig>,
     tags: Vec<String>,
     priority: i32,
     enabled: bool,
@@ -678,6 +797,22 @@ impl CreateRouteRequestBuilder {
         self.cors = Some(cors);
         self
     }
+    pub fn sse_reconnect(mut self, sse_reconnect: SseReconnectConfig) -> Self {
+        self.sse_reconnect = Some(sse_reconnect);
+        self
+    }
+    pub fn get_coalescing(mut self, get_coalescing: GetCoalescingConfig) -> Self {
+        self.get_coalescing = Some(get_coalescing);
+        self
+    }
+    pub fn request_compression(mut self, request_compression: RequestCompressionConfig) -> Self {
+        self.request_compression = Some(request_compression);
+        self
+    }
+    pub fn response_cache(mut self, response_cache: ResponseCacheConfig) -> Self {
+        self.response_cache = Some(response_cache);
+        self
+    }
     pub fn tags(mut self, tags: Vec<String>) -> Self {
         self.tags = tags;
         self
@@ -697,6 +832,10 @@ impl CreateRouteRequestBuilder {
             plugins: self.plugins,
             rate_limit: self.rate_limit,
             cors: self.cors,
+            sse_reconnect: self.sse_reconnect,
+            get_coalescing: self.get_coalescing,
+            request_compression: self.request_compression,
+            response_cache: self.response_cache,
             tags: self.tags,
             priority: self.priority,
             enabled: self.enabled,
@@ -712,6 +851,10 @@ pub struct UpdateRouteRequest {
     plugins: Option<PluginsConfig>,
     rate_limit: Option<RateLimitConfig>,
     cors: Option<CorsConfig>,
+    sse_reconnect: Option<SseReconnectConfig>,
+    get_coalescing: Option<GetCoalescingConfig>,
+    request_compression: Option<RequestCompressionConfig>,
+    response_cache: Option<ResponseCacheConfig>,
     tags: Vec<String>,
     priority: i32,
     enabled: bool,
@@ -725,6 +868,10 @@ impl UpdateRouteRequest {
             plugins: None,
             rate_limit: None,
             cors: None,
+            sse_reconnect: None,
+            get_coalescing: None,
+            request_compression: None,
+            response_cache: None,
             tags: vec![],
             priority: 0,
             enabled: true,
@@ -743,6 +890,18 @@ impl UpdateRouteRequest {
     pub fn cors(&self) -> Option<&CorsConfig> {
         self.cors.as_ref()
     }
+    pub fn sse_reconnect(&self) -> Option<&SseReconnectConfig> {
+        self.sse_reconnect.as_ref()
+    }
+    pub fn get_coalescing(&self) -> Option<&GetCoalescingConfig> {
+        self.get_coalescing.as_ref()
+    }
+    pub fn request_compression(&self) -> Option<&RequestCompressionConfig> {
+        self.request_compression.as_ref()
+    }
+    pub fn response_cache(&self) -> Option<&ResponseCacheConfig> {
+        self.response_cache.as_ref()
+    }
     pub fn tags(&self) -> &[String] {
         &self.tags
     }
@@ -759,6 +918,10 @@ pub struct UpdateRouteRequestBuilder {
     plugins: Option<PluginsConfig>,
     rate_limit: Option<RateLimitConfig>,
     cors: Option<CorsConfig>,
+    sse_reconnect: Option<SseReconnectConfig>,
+    get_coalescing: Option<GetCoalescingConfig>,
+    request_compression: Option<RequestCompressionConfig>,
+    response_cache: Option<ResponseCacheConfig>,
     tags: Vec<String>,
     priority: i32,
     enabled: bool,
@@ -777,6 +940,22 @@ impl UpdateRouteRequestBuilder {
         self.cors = Some(cors);
         self
     }
+    pub fn sse_reconnect(mut self, sse_reconnect: SseReconnectConfig) -> Self {
+        self.sse_reconnect = Some(sse_reconnect);
+        self
+    }
+    pub fn get_coalescing(mut self, get_coalescing: GetCoalescingConfig) -> Self {
+        self.get_coalescing = Some(get_coalescing);
+        self
+    }
+    pub fn request_compression(mut self, request_compression: RequestCompressionConfig) -> Self {
+        self.request_compression = Some(request_compression);
+        self
+    }
+    pub fn response_cache(mut self, response_cache: ResponseCacheConfig) -> Self {
+        self.response_cache = Some(response_cache);
+        self
+    }
     pub fn tags(mut self, tags: Vec<String>) -> Self {
         self.tags = tags;
         self
@@ -795,6 +974,10 @@ impl UpdateRouteRequestBuilder {
             plugins: self.plugins,
             rate_limit: self.rate_limit,
             cors: self.cors,
+            sse_reconnect: self.sse_reconnect,
+            get_coalescing: self.get_coalescing,
+            request_compression: self.request_compression,
+            response_cache: self.response_cache,
             tags: self.tags,
             priority: self.priority,
             enabled: self.enabled,
@@ -823,13 +1006,30 @@ mod tests {
     #[test]
     fn alias_port_80_omitted() {
         let ep = Endpoint {
-            scheme: Scheme::Https,
+            scheme: Scheme::Http,
             host: "example.com".into(),
             port: 80,
         };
         assert_eq!(ep.alias_contribution(), "example.com");
     }
 
+    #[test]
+    fn alias_port_standard_only_for_its_scheme() {
+        let ep = Endpoint {
+            scheme: Scheme::Https,
+            host: "example.com".into(),
+            port: 80,
+        };
+        assert_eq!(ep.alias_contribution(), "example.com:80");
+    }
+
+    #[test]
+    fn default_port_follows_scheme() {
+        assert_eq!(Scheme::Http.default_port(), 80);
+        assert_eq!(Scheme::Https.default_port(), 443);
+        assert_eq!(Scheme::Wss.default_port(), 443);
+    }
+
     #[test]
     fn alias_nonstandard_port_included() {
         let ep = Endpoint {
@@ -874,6 +1074,10 @@ mod tests {
             plugins: None,
             rate_limit: None,
             cors: None,
+            sse_reconnect: None,
+            get_coalescing: None,
+            request_compression: None,
+            response_cache: None,
             tags: vec![],
             priority: 0,
             enabled: true,
@@ -0,0 +1,502 @@
+//! Typed content deltas for LLM chat completion streams.
+
+use std::pin::Pin;
+
+use futures_core::Stream;
+use futures_util::{StreamExt, future, stream};
+use serde::Deserialize;
+
+use crate::error::StreamingError;
+use crate::sse::{ServerEvent, ServerEventsStream};
+
+/// An incremental piece of an LLM response, normalized across providers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContentDelta {
+    /// Assistant text to append to the response.
+    Text(String),
+    /// A fragment of a tool call.
+    ///
+    /// `id` and `name` are set on the first fragment of each call; later
+    /// fragments carry only `arguments`, a piece of JSON to concatenate.
+    /// `index` tells concurrent calls apart: the OpenAI `tool_calls` index or
+    /// the Anthropic content block index.
+    ToolCall {
+        index: u32,
+        id: Option<String>,
+        name: Option<String>,
+        arguments: String,
+    },
+    /// The model stopped generating, e.g. `stop`, `end_turn` or `tool_use`.
+    Finish { reason: String },
+}
+
+/// Stream of [`ContentDelta`]s produced by
+/// [`ServerEventsStream::into_openai_deltas`] and
+/// [`ServerEventsStream::into_anthropic_deltas`].
+pub type ContentDeltaStream =
+    Pin<Box<dyn Stream<Item = Result<ContentDelta, StreamingError>> + Send>>;
+
+impl ServerEventsStream<ServerEvent> {
+    /// Interpret the events as an OpenAI chat completion stream.
+    ///
+    /// Yields the text, tool call and finish deltas of the first choice and
+    /// ends at the `[DONE]` sentinel. An `error` object in the stream is
+    /// yielded as [`StreamingError::Provider`].
+    pub fn into_openai_deltas(self) -> ContentDeltaStream {
+        deltas(self, openai_deltas)
+    }
+
+    /// Interpret the events as an Anthropic Messages stream.
+    ///
+    /// Yields text and tool input deltas, the stop reason from
+    /// `message_delta`, and ends at `message_stop`. Other event types
+    /// (`ping`, thinking blocks, ...) are skipped. An `error` event is
+    /// yielded as [`StreamingError::Provider`].
+    pub fn into_anthropic_deltas(self) -> ContentDeltaStream {
+        deltas(self, anthropic_deltas)
+    }
+}
+
+/// What one event contributes to the delta stream.
+enum Step {
+    Deltas(Vec<ContentDelta>),
+    Done,
+}
+
+fn deltas(
+    events: ServerEventsStream<ServerEvent>,
+    parse: fn(&ServerEvent) -> Result<Step, StreamingError>,
+) -> ContentDeltaStream {
+    Box::pin(
+        events
+            .map(move |event| event.and_then(|e| parse(&e)))
+            .take_while(|step| future::ready(!matches!(step, Ok(Step::Done))))
+            .flat_map(|step| {
+                stream::iter(match step {
+                    Ok(Step::Deltas(deltas)) => deltas.into_iter().map(Ok).collect(),
+                    Ok(Step::Done) => Vec::new(),
+                    Err(e) => vec![Err(e)],
+                })
+            }),
+    )
+}
+
+fn parse_error(e: &serde_json::Error) -> StreamingError {
+    StreamingError::ServerEventsParse {
+        detail: e.to_string(),
+    }
+}
+
+// -- OpenAI -----------------------------------------------------------------
+
+#[derive(Deserialize)]
+struct OpenAiChunk {
+    #[serde(default)]
+    choices: Vec<OpenAiChoice>,
+    error: Option<ProviderError>,
+}
+
+#[derive(Deserialize)]
+struct OpenAiChoice {
+    #[serde(default)]
+    index: u32,
+    #[serde(default)]
+    delta: OpenAiDelta,
+    finish_reason: Option<String>,
+}
+
+#[derive(Default, Deserialize)]
+struct OpenAiDelta {
+    content: Option<String>,
+    tool_calls: Option<Vec<OpenAiToolCall>>,
+}
+
+#[derive(Deserialize)]
+struct OpenAiToolCall {
+    #[serde(default)]
+    index: u32,
+    id: Option<String>,
+    #[serde(default)]
+    function: OpenAiFunction,
+}
+
+#[derive(Default, Deserialize)]
+struct OpenAiFunction {
+    name: Option<String>,
+    arguments: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ProviderError {
+    message: String,
+}
+
+fn openai_deltas(event: &ServerEvent) -> Result<Step, StreamingError> {
+    let data = event.data.trim();
+    if data == "[DONE]" {
+        return Ok(Step::Done);
+    }
+    if data.is_empty() {
+        return Ok(Step::Deltas(Vec::new()));
+    }
+    let chunk: OpenAiChunk = serde_json::from_str(data).map_err(|e| parse_error(&e))?;
+    if let Some(error) = chunk.error {
+        return Err(StreamingError::Provider {
+            detail: error.message,
+        });
+    }
+
+    let mut deltas = Vec::new();
+    for choice in chunk.choices.into_iter().filter(|c| c.index == 0) {
+        if let Some(text) = choice.delta.content.filter(|t| !t.is_empty()) {
+            deltas.push(ContentDelta::Text(text));
+        }
+        for call in choice.delta.tool_calls.unwrap_or_default() {
+            deltas.push(ContentDelta::ToolCall {
+                index: call.index,
+                id: call.id,
+                name: call.function.name,
+                arguments: call.function.arguments.unwrap_or_default(),
+            });
+        }
+        if let Some(reason) = choice.finish_reason {
+            deltas.push(ContentDelta::Finish { reason });
+        }
+    }
+    Ok(Step::Deltas(deltas))
+}
+
+// -- Anthropic --------------------------------------------------------------
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum AnthropicEvent {
+    ContentBlockStart {
+        index: u32,
+        content_block: AnthropicBlock,
+    },
+    ContentBlockDelta {
+        index: u32,
+        delta: AnthropicBlockDelta,
+    },
+    MessageDelta {
+        delta: AnthropicMessageDelta,
+    },
+    MessageStop,
+    Error {
+        error: ProviderError,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum AnthropicBlock {
+    Text {
+        #[serde(default)]
+        text: String,
+    },
+    ToolUse {
+        id: String,
+        name: String,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum AnthropicBlockDelta {
+    TextDelta {
+        text: String,
+    },
+    InputJsonDelta {
+        partial_json: String,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize)]
+struct AnthropicMessageDelta {
+    stop_reason: Option<String>,
+}
+
+fn anthropic_deltas(event: &ServerEvent) -> Result<Step, StreamingError> {
+    if event.data.trim().is_empty() {
+        return Ok(Step::Deltas(Vec::new()));
+    }
+    let parsed: AnthropicEvent = serde_json::from_str(&event.data).map_err(|e| parse_error(&e))?;
+    let delta = match parsed {
+        AnthropicEvent::ContentBlockStart {
+            content_block: AnthropicBlock::Text { text },
+            ..
+        }
+        | AnthropicEvent::ContentBlockDelta {
+            delta: AnthropicBlockDelta::TextDelta { text },
+            ..
+        } if !text.is_empty() => ContentDelta::Text(text),
+        AnthropicEvent::ContentBlockStart {
+            index,
+            content_block: AnthropicBlock::ToolUse { id, name },
+        } => ContentDelta::ToolCall {
+            index,
+            id: Some(id),
+            name: Some(name),
+            arguments: String::new(),
+        },
+        AnthropicEvent::ContentBlockDelta {
+            index,
+            delta: AnthropicBlockDelta::InputJsonDelta { partial_json },
+        } => ContentDelta::ToolCall {
+            index,
+            id: None,
+            name: None,
+            arguments: partial_json,
+        },
+        AnthropicEvent::MessageDelta {
+            delta: AnthropicMessageDelta {
+                stop_reason: Some(reason),
+            },
+        } => ContentDelta::Finish { reason },
+        AnthropicEvent::MessageStop => return Ok(Step::Done),
+        AnthropicEvent::Error { error } => {
+            return Err(StreamingError::Provider {
+                detail: error.message,
+            });
+        }
+        _ => return Ok(Step::Deltas(Vec::new())),
+    };
+    Ok(Step::Deltas(vec![delta]))
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+
+    use super::*;
+    use crate::body::{Body, BodyStream, BoxError};
+    use crate::sse::ServerEventsResponse;
+
+    fn events(wire: &'static str) -> ServerEventsStream {
+        let chunks: Vec<Result<Bytes, BoxError>> = vec![Ok(Bytes::from_static(wire.as_bytes()))];
+        let body: BodyStream = Box::pin(stream::iter(chunks));
+        let resp = http::Response::builder()
+            .header("content-type", "text/event-stream")
+            .body(Body::Stream(body))
+            .unwrap();
+        match ServerEventsStream::from_response::<ServerEvent>(resp) {
+            ServerEventsResponse::Events(events) => events,
+            ServerEventsResponse::Response(_) => panic!("expected an event stream"),
+        }
+    }
+
+    async fn collect(deltas: ContentDeltaStream) -> Vec<Result<ContentDelta, StreamingError>> {
+        deltas.collect().await
+    }
+
+    fn text(s: &str) -> ContentDelta {
+        ContentDelta::Text(s.into())
+    }
+
+    fn finish(reason: &str) -> ContentDelta {
+        ContentDelta::Finish {
+            reason: reason.into(),
+        }
+    }
+
+    const OPENAI_TEXT: &str = r#"data: {"id":"chatcmpl-1","object":"chat.completion.chunk","created":1730000000,"model":"gpt-4o","choices":[{"index":0,"delta":{"role":"assistant","content":"","refusal":null},"logprobs":null,"finish_reason":null}]}
+
+data: {"id":"chatcmpl-1","object":"chat.completion.chunk","created":1730000000,"model":"gpt-4o","choices":[{"index":0,"delta":{"content":"Hello"},"logprobs":null,"finish_reason":null}]}
+
+data: {"id":"chatcmpl-1","object":"chat.completion.chunk","created":1730000000,"model":"gpt-4o","choices":[{"index":0,"delta":{"content":" world"},"logprobs":null,"finish_reason":null}]}
+
+data: {"id":"chatcmpl-1","object":"chat.completion.chunk","created":1730000000,"model":"gpt-4o","choices":[{"index":0,"delta":{},"logprobs":null,"finish_reason":"stop"}]}
+
+data: {"id":"chatcmpl-1","object":"chat.completion.chunk","created":1730000000,"model":"gpt-4o","choices":[],"usage":{"prompt_tokens":9,"completion_tokens":2,"total_tokens":11}}
+
+data: [DONE]
+
+data: {"choices":[{"index":0,"delta":{"content":"after done"}}]}
+
+"#;
+
+    const OPENAI_TOOL_CALL: &str = r#"data: {"id":"chatcmpl-2","object":"chat.completion.chunk","model":"gpt-4o","choices":[{"index":0,"delta":{"role":"assistant","content":null,"tool_calls":[{"index":0,"id":"call_abc","type":"function","function":{"name":"get_weather","arguments":""}}]},"finish_reason":null}]}
+
+data: {"id":"chatcmpl-2","object":"chat.completion.chunk","model":"gpt-4o","choices":[{"index":0,"delta":{"tool_calls":[{"index":0,"function":{"arguments":"{\"city\":"}}]},"finish_reason":null}]}
+
+data: {"id":"chatcmpl-2","object":"chat.completion.chunk","model":"gpt-4o","choices":[{"index":0,"delta":{"tool_calls":[{"index":0,"function":{"arguments":"\"Paris\"}"}}]},"finish_reason":null}]}
+
+data: {"id":"chatcmpl-2","object":"chat.completion.chunk","model":"gpt-4o","choices":[{"index":0,"delta":{},"finish_reason":"tool_calls"}]}
+
+data: [DONE]
+
+"#;
+
+    const ANTHROPIC_TEXT: &str = r#"event: message_start
+data: {"type":"message_start","message":{"id":"msg_1","type":"message","role":"assistant","content":[],"model":"claude-sonnet","stop_reason":null,"stop_sequence":null,"usage":{"input_tokens":12,"output_tokens":1}}}
+
+event: content_block_start
+data: {"type":"content_block_start","index":0,"content_block":{"type":"text","text":""}}
+
+event: ping
+data: {"type": "ping"}
+
+event: content_block_delta
+data: {"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"Hello"}}
+
+event: content_block_delta
+data: {"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":" world"}}
+
+event: content_block_stop
+data: {"type":"content_block_stop","index":0}
+
+event: message_delta
+data: {"type":"message_delta","delta":{"stop_reason":"end_turn","stop_sequence":null},"usage":{"output_tokens":3}}
+
+event: message_stop
+data: {"type":"message_stop"}
+
+"#;
+
+    const ANTHROPIC_TOOL_USE: &str = r#"event: message_start
+data: {"type":"message_start","message":{"id":"msg_2","type":"message","role":"assistant","content":[],"model":"claude-sonnet","stop_reason":null,"usage":{"input_tokens":40,"output_tokens":1}}}
+
+event: content_block_start
+data: {"type":"content_block_start","index":0,"content_block":{"type":"text","text":""}}
+
+event: content_block_delta
+data: {"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"Checking."}}
+
+event: content_block_stop
+data: {"type":"content_block_stop","index":0}
+
+event: content_block_start
+data: {"type":"content_block_start","index":1,"content_block":{"type":"tool_use","id":"toolu_1","name":"get_weather","input":{}}}
+
+event: content_block_delta
+data: {"type":"content_block_delta","index":1,"delta":{"type":"input_json_delta","partial_json":""}}
+
+event: content_block_delta
+data: {"type":"content_block_delta","index":1,"delta":{"type":"input_json_delta","partial_json":"{\"city\": \"Paris\"}"}}
+
+event: content_block_stop
+data: {"type":"content_block_stop","index":1}
+
+event: message_delta
+data: {"type":"message_delta","delta":{"stop_reason":"tool_use","stop_sequence":null},"usage":{"output_tokens":20}}
+
+event: message_stop
+data: {"type":"message_stop"}
+
+"#;
+
+    #[tokio::test]
+    async fn openai_text_stream_stops_at_done() {
+        let deltas = collect(events(OPENAI_TEXT).into_openai_deltas()).await;
+        let deltas: Vec<_> = deltas.into_iter().map(Result::unwrap).collect();
+        assert_eq!(deltas, vec![text("Hello"), text(" world"), finish("stop")]);
+    }
+
+    #[tokio::test]
+    async fn openai_tool_call_fragments() {
+        let deltas = collect(events(OPENAI_TOOL_CALL).into_openai_deltas()).await;
+        let deltas: Vec<_> = deltas.into_iter().map(Result::unwrap).collect();
+        assert_eq!(
+            deltas,
+            vec![
+                ContentDelta::ToolCall {
+                    index: 0,
+                    id: Some("call_abc".into()),
+                    name: Some("get_weather".into()),
+                    arguments: String::new(),
+                },
+                ContentDelta::ToolCall {
+                    index: 0,
+                    id: None,
+                    name: None,
+                    arguments: r#"{"city":"#.into(),
+                },
+                ContentDelta::ToolCall {
+                    index: 0,
+                    id: None,
+                    name: None,
+                    arguments: r#""Paris"}"#.into(),
+                },
+                finish("tool_calls"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn openai_error_object_is_reported() {
+        let wire =
+            "data: {\"error\":{\"message\":\"Rate limit reached\",\"type\":\"requests\"}}\n\n";
+        let deltas = collect(events(wire).into_openai_deltas()).await;
+        assert!(matches!(
+            &deltas[..],
+            [Err(StreamingError::Provider { detail })] if detail == "Rate limit reached"
+        ));
+    }
+
+    #[tokio::test]
+    async fn openai_malformed_chunk_is_a_parse_error() {
+        let deltas = collect(events("data: {not json\n\n").into_openai_deltas()).await;
+        assert!(matches!(
+            &deltas[..],
+            [Err(StreamingError::ServerEventsParse { .. })]
+        ));
+    }
+
+    #[tokio::test]
+    async fn anthropic_text_stream() {
+        let deltas = collect(events(ANTHROPIC_TEXT).into_anthropic_deltas()).await;
+        let deltas: Vec<_> = deltas.into_iter().map(Result::unwrap).collect();
+        assert_eq!(
+            deltas,
+            vec![text("Hello"), text(" world"), finish("end_turn")]
+        );
+    }
+
+    #[tokio::test]
+    async fn anthropic_tool_use_stream() {
+        let deltas = collect(events(ANTHROPIC_TOOL_USE).into_anthropic_deltas()).await;
+        let deltas: Vec<_> = deltas.into_iter().map(Result::unwrap).collect();
+        assert_eq!(
+            deltas,
+            vec![
+                text("Checking."),
+                ContentDelta::ToolCall {
+                    index: 1,
+                    id: Some("toolu_1".into()),
+                    name: Some("get_weather".into()),
+                    arguments: String::new(),
+                },
+                ContentDelta::ToolCall {
+                    index: 1,
+                    id: None,
+                    name: None,
+                    arguments: String::new(),
+                },
+                ContentDelta::ToolCall {
+                    index: 1,
+                    id: None,
+                    name: None,
+                    arguments: r#"{"city": "Paris"}"#.into(),
+                },
+                finish("tool_use"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn anthropic_error_event_is_reported() {
+        let wire = "event: error\ndata: {\"type\":\"error\",\"error\":{\"type\":\"overloaded_error\",\"message\":\"Overloaded\"}}\n\n";
+        let deltas = collect(events(wire).into_anthropic_deltas()).await;
+        assert!(matches!(
+            &deltas[..],
+            [Err(StreamingError::Provider { detail })] if detail == "Overloaded"
+        ));
+    }
+}
@@ -1,3 +1,4 @@
+mod delta;
 mod detect;
 mod event;
 mod parse;
@@ -5,6 +6,7 @@ mod parse;
 mod response;
 mod stream;
 
+pub use delta::{ContentDelta, ContentDeltaStream};
 pub use detect::is_server_events_response;
 pub use event::ServerEvent;
 pub(crate) use parse::parse_server_events_stream;
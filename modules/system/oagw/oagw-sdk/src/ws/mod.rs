@@ -2,6 +2,8 @@
 pub mod axum_adapter;
 mod message;
 mod stream;
+mod upgrade;
 
 pub use message::{WebSocketCloseFrame, WebSocketMessage, WebSocketReceiver, WebSocketSink};
 pub use stream::{FromWebSocketMessage, WebSocketSender, WebSocketStream, WebSocketStreamReceiver};
+pub use upgrade::WebSocketUpgrade;
@@ -0,0 +1,75 @@
+//! Access to the connection behind a proxied WebSocket upgrade.
+
+use std::sync::{Arc, Mutex};
+
+use crate::ws::stream::WebSocketStream;
+
+type Open = Box<dyn FnOnce() -> Option<WebSocketStream> + Send>;
+
+/// Upgraded WebSocket connection attached to a `101 Switching Protocols`
+/// response returned by [`ServiceGatewayClientV1::proxy_request`].
+///
+/// Retrieve it from the response extensions and call [`take`](Self::take)
+/// to get a message-level [`WebSocketStream`] to the upstream:
+///
+/// ```ignore
+/// let resp = gateway.proxy_request(ctx, req).await?;
+/// let upgrade = resp.extensions().get::<WebSocketUpgrade>().cloned();
+/// let mut ws = upgrade.and_then(|u| u.take()).expect("upgraded connection");
+/// ws.send(&WebSocketMessage::Text("hello".into())).await?;
+/// ```
+///
+/// The connection can be taken once; clones share the same connection.
+/// Dropping the stream closes the upstream connection.
+///
+/// [`ServiceGatewayClientV1::proxy_request`]: crate::api::ServiceGatewayClientV1::proxy_request
+#[derive(Clone)]
+pub struct WebSocketUpgrade(Arc<Mutex<Option<Open>>>);
+
+impl WebSocketUpgrade {
+    /// Wrap a deferred opener. It is called at most once, by the first
+    /// [`take`](Self::take), and returns `None` if the connection has
+    /// already been claimed elsewhere.
+    pub fn new(open: impl FnOnce() -> Option<WebSocketStream> + Send + 'static) -> Self {
+        Self(Arc::new(Mutex::new(Some(Box::new(open)))))
+    }
+
+    /// Take the message stream. Returns `None` if already taken.
+    #[must_use]
+    pub fn take(&self) -> Option<WebSocketStream> {
+        let open = self
+            .0
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .take()?;
+        open()
+    }
+}
+
+impl std::fmt::Debug for WebSocketUpgrade {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebSocketUpgrade").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::{SinkExt as _, sink, stream};
+
+    use super::*;
+    use crate::ws::message::{WebSocketReceiver, WebSocketSink};
+
+    fn empty_stream() -> WebSocketStream {
+        let sink: WebSocketSink = Box::pin(sink::drain().sink_map_err(|e| match e {}));
+        let receiver: WebSocketReceiver = Box::pin(stream::empty());
+        WebSocketStream::from((sink, receiver))
+    }
+
+    #[test]
+    fn upgrade_is_taken_once_across_clones() {
+        let upgrade = WebSocketUpgrade::new(|| Some(empty_stream()));
+        let clone = upgrade.clone();
+        assert!(clone.take().is_some());
+        assert!(upgrade.take().is_none());
+    }
+}
@@ -21,7 +21,9 @@ use oagw_sdk::body::{Body, BodyStream, BoxError};
 use oagw_sdk::codec::Json;
 use oagw_sdk::error::ServiceGatewayError;
 use oagw_sdk::error::StreamingError;
-use oagw_sdk::sse::{FromServerEvent, ServerEvent, ServerEventsResponse, ServerEventsStream};
+use oagw_sdk::sse::{
+    ContentDelta, FromServerEvent, ServerEvent, ServerEventsResponse, ServerEventsStream,
+};
 use oagw_sdk::ws::{
     FromWebSocketMessage, WebSocketMessage, WebSocketReceiver, WebSocketSink, WebSocketStream,
 };
@@ -376,6 +378,43 @@ async fn sse_stream_openai_chat_format() -> TestResult {
     Ok(())
 }
 
+/// Decode the same kind of stream with the built-in delta helper.
+///
+/// Preconditions: upstream returns OpenAI chat completion chunks.
+/// Expected: text deltas join into the response, the stream ends at `[DONE]`.
+#[tokio::test]
+async fn sse_stream_openai_deltas() -> TestResult {
+    let resp = server_events_response(vec![
+        "data: {\"choices\":[{\"index\":0,\"delta\":{\"role\":\"assistant\",\"content\":\"Hello\"}}]}\n\n",
+        "data: {\"choices\":[{\"index\":0,\"delta\":{\"content\":\" from\"}}]}\n\n",
+        "data: {\"choices\":[{\"index\":0,\"delta\":{\"content\":\" the stream\"}}]}\n\n",
+        "data: {\"choices\":[{\"index\":0,\"delta\":{},\"finish_reason\":\"stop\"}]}\n\n",
+        "data: [DONE]\n\n",
+    ]);
+
+    let ServerEventsResponse::Events(events) =
+        ServerEventsStream::from_response::<ServerEvent>(resp)
+    else {
+        return Ok(());
+    };
+
+    let mut deltas = events.into_openai_deltas();
+    let mut text = String::new();
+    let mut finish = None;
+    while let Some(delta) = deltas.next().await {
+        match delta? {
+            ContentDelta::Text(t) => text.push_str(&t),
+            ContentDelta::Finish { reason } => finish = Some(reason),
+            ContentDelta::ToolCall { .. } => {}
+        }
+    }
+
+    assert_eq!(text, "Hello from the stream");
+    assert_eq!(finish.as_deref(), Some("stop"));
+
+    Ok(())
+}
+
 /// Non-SSE response: `from_response` gives back the original response.
 ///
 /// Preconditions: upstream returns `application/json`, not `text/event-stream`.
@@ -43,12 +43,9 @@ pub struct Endpoint {
     #[serde(default)]
     pub scheme: Scheme,
     pub host: String,
-    #[serde(default = "default_port")]
-    pub port: u16,
-}
-
-fn default_port() -> u16 {
-    443
+    /// Defaults to the scheme's well-known port (80 for `http`, 443 otherwise).
+    #[serde(default)]
+    pub port: Option<u16>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
@@ -132,12 +129,30 @@ pub struct RateLimitConfig {
     pub scope: RateLimitScope,
     #[serde(default)]
     pub strategy: RateLimitStrategy,
-    #[serde(default = "default_cost")]
-    pub cost: u32,
+    #[serde(default)]
+    pub cost: CostStrategy,
 }
 
-fn default_cost() -> u32 {
-    1
+/// Tokens consumed per request: either a plain integer or a variable
+/// strategy object such as `{"type": "per_kilobyte"}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(untagged)]
+pub enum CostStrategy {
+    Fixed(u32),
+    Variable(VariableCost),
+}
+
+impl Default for CostStrategy {
+    fn default() -> Self {
+        Self::Fixed(1)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum VariableCost {
+    PerKilobyte,
+    HeaderValue { header: String },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, utoipa::ToSchema)]
@@ -163,6 +178,8 @@ pub enum Window {
     Minute,
     Hour,
     Day,
+    /// Custom window length, e.g. `{"seconds": 10}`.
+    Seconds(u32),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
@@ -223,6 +240,27 @@ pub struct CorsConfig {
     pub allow_credentials: bool,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct SseReconnectConfig {
+    pub max_retries: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct GetCoalescingConfig {
+    #[serde(default)]
+    pub vary_headers: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct RequestCompressionConfig {
+    pub min_bytes: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct ResponseCacheConfig {
+    pub ttl_secs: u64,
+}
+
 // ---------------------------------------------------------------------------
 // PluginBinding / PluginsConfig
 // ---------------------------------------------------------------------------
@@ -313,6 +351,10 @@ pub struct CreateUpstreamRequest {
     pub tags: Vec<String>,
     #[serde(default = "default_true")]
     pub enabled: bool,
+    /// Makes the create safe to retry: a repeat with the same key returns
+    /// the upstream created by the first request (200 instead of 201).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
@@ -350,6 +392,14 @@ pub struct CreateRouteRequest {
     pub rate_limit: Option<RateLimitConfig>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cors: Option<CorsConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sse_reconnect: Option<SseReconnectConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub get_coalescing: Option<GetCoalescingConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_compression: Option<RequestCompressionConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_cache: Option<ResponseCacheConfig>,
     #[serde(default)]
     pub tags: Vec<String>,
     #[serde(default)]
@@ -368,6 +418,14 @@ pub struct UpdateRouteRequest {
     pub rate_limit: Option<RateLimitConfig>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cors: Option<CorsConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sse_reconnect: Option<SseReconnectConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub get_coalescing: Option<GetCoalescingConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_compression: Option<RequestCompressionConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_cache: Option<ResponseCacheConfig>,
     pub tags: Vec<String>,
     pub priority: i32,
     pub enabled: bool,
@@ -412,12 +470,66 @@ pub struct RouteResponse {
     pub rate_limit: Option<RateLimitConfig>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cors: Option<CorsConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sse_reconnect: Option<SseReconnectConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub get_coalescing: Option<GetCoalescingConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_compression: Option<RequestCompressionConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_cache: Option<ResponseCacheConfig>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
     pub priority: i32,
     pub enabled: bool,
 }
 
+/// Tenant-wide rate limit. Applies to upstream/route pairs that set no rate
+/// limit; with `aggregate` it also caps the tenant's limited traffic.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct SetTenantRateLimitRequest {
+    pub rate_limit: RateLimitConfig,
+    #[serde(default)]
+    pub aggregate: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct TenantRateLimitResponse {
+    pub tenant_id: Uuid,
+    pub rate_limit: RateLimitConfig,
+    pub aggregate: bool,
+}
+
+/// `secret_ref` names configured in the auth of the caller's upstreams.
+/// Secret values are never part of this response.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct CredentialRefsResponse {
+    pub secret_refs: Vec<String>,
+}
+
+/// Successful dry-run validation: the request would be accepted as is.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct ValidationReport {
+    pub valid: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    Healthy,
+    Unhealthy,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct EndpointHealthResponse {
+    pub endpoint: Endpoint,
+    pub status: HealthStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_checked_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub consecutive_failures: u32,
+}
+
 // ---------------------------------------------------------------------------
 // From conversions: REST value types → domain value types
 // ---------------------------------------------------------------------------
@@ -446,10 +558,11 @@ impl From<Scheme> for domain::Scheme {
 
 impl From<Endpoint> for domain::Endpoint {
     fn from(v: Endpoint) -> Self {
+        let scheme: domain::Scheme = v.scheme.into();
         Self {
-            scheme: v.scheme.into(),
+            scheme,
             host: v.host,
-            port: v.port,
+            port: v.port.unwrap_or_else(|| scheme.default_port()),
         }
     }
 }
@@ -529,6 +642,7 @@ impl From<Window> for domain::Window {
             Window::Minute => Self::Minute,
             Window::Hour => Self::Hour,
             Window::Day => Self::Day,
+            Window::Seconds(n) => Self::Seconds(n),
         }
     }
 }
@@ -572,6 +686,18 @@ impl From<RateLimitStrategy> for domain::RateLimitStrategy {
     }
 }
 
+impl From<CostStrategy> for domain::CostStrategy {
+    fn from(v: CostStrategy) -> Self {
+        match v {
+            CostStrategy::Fixed(n) => Self::Fixed(n),
+            CostStrategy::Variable(VariableCost::PerKilobyte) => Self::PerKilobyte,
+            CostStrategy::Variable(VariableCost::HeaderValue { header }) => {
+                Self::HeaderValue(header)
+            }
+        }
+    }
+}
+
 impl From<RateLimitConfig> for domain::RateLimitConfig {
     fn from(v: RateLimitConfig) -> Self {
         Self {
@@ -581,7 +707,7 @@ impl From<RateLimitConfig> for domain::RateLimitConfig {
             burst: v.burst.map(Into::into),
             scope: v.scope.into(),
             strategy: v.strategy.into(),
-            cost: v.cost,
+            cost: v.cost.into(),
         }
     }
 }
@@ -631,6 +757,38 @@ impl From<CorsConfig> for domain::CorsConfig {
     }
 }
 
+impl From<SseReconnectConfig> for domain::SseReconnectConfig {
+    fn from(v: SseReconnectConfig) -> Self {
+        Self {
+            max_retries: v.max_retries,
+        }
+    }
+}
+
+impl From<GetCoalescingConfig> for domain::GetCoalescingConfig {
+    fn from(v: GetCoalescingConfig) -> Self {
+        Self {
+            vary_headers: v.vary_headers,
+        }
+    }
+}
+
+impl From<RequestCompressionConfig> for domain::RequestCompressionConfig {
+    fn from(v: RequestCompressionConfig) -> Self {
+        Self {
+            min_bytes: v.min_bytes,
+        }
+    }
+}
+
+impl From<ResponseCacheConfig> for domain::ResponseCacheConfig {
+    fn from(v: ResponseCacheConfig) -> Self {
+        Self {
+            ttl_secs: v.ttl_secs,
+        }
+    }
+}
+
 impl From<HttpMethod> for domain::HttpMethod {
     fn from(v: HttpMethod) -> Self {
         match v {
@@ -712,7 +870,7 @@ impl From<domain::Endpoint> for Endpoint {
         Self {
             scheme: v.scheme.into(),
             host: v.host,
-            port: v.port,
+            port: Some(v.port),
         }
     }
 }
@@ -792,6 +950,7 @@ impl From<domain::Window> for Window {
             domain::Window::Minute => Self::Minute,
             domain::Window::Hour => Self::Hour,
             domain::Window::Day => Self::Day,
+            domain::Window::Seconds(n) => Self::Seconds(n),
         }
     }
 }
@@ -835,6 +994,18 @@ impl From<domain::RateLimitStrategy> for RateLimitStrategy {
     }
 }
 
+impl From<domain::CostStrategy> for CostStrategy {
+    fn from(v: domain::CostStrategy) -> Self {
+        match v {
+            domain::CostStrategy::Fixed(n) => Self::Fixed(n),
+            domain::CostStrategy::PerKilobyte => Self::Variable(VariableCost::PerKilobyte),
+            domain::CostStrategy::HeaderValue(header) => {
+                Self::Variable(VariableCost::HeaderValue { header })
+            }
+        }
+    }
+}
+
 impl From<domain::RateLimitConfig> for RateLimitConfig {
     fn from(v: domain::RateLimitConfig) -> Self {
         Self {
@@ -844,7 +1015,7 @@ impl From<domain::RateLimitConfig> for RateLimitConfig {
             burst: v.burst.map(Into::into),
             scope: v.scope.into(),
             strategy: v.strategy.into(),
-            cost: v.cost,
+            cost: v.cost.into(),
         }
     }
 }
@@ -894,6 +1065,38 @@ impl From<domain::CorsConfig> for CorsConfig {
     }
 }
 
+impl From<domain::SseReconnectConfig> for SseReconnectConfig {
+    fn from(v: domain::SseReconnectConfig) -> Self {
+        Self {
+            max_retries: v.max_retries,
+        }
+    }
+}
+
+impl From<domain::GetCoalescingConfig> for GetCoalescingConfig {
+    fn from(v: domain::GetCoalescingConfig) -> Self {
+        Self {
+            vary_headers: v.vary_headers,
+        }
+    }
+}
+
+impl From<domain::RequestCompressionConfig> for RequestCompressionConfig {
+    fn from(v: domain::RequestCompressionConfig) -> Self {
+        Self {
+            min_bytes: v.min_bytes,
+        }
+    }
+}
+
+impl From<domain::ResponseCacheConfig> for ResponseCacheConfig {
+    fn from(v: domain::ResponseCacheConfig) -> Self {
+        Self {
+            ttl_secs: v.ttl_secs,
+        }
+    }
+}
+
 impl From<domain::HttpMethod> for HttpMethod {
     fn from(v: domain::HttpMethod) -> Self {
         match v {
@@ -944,6 +1147,27 @@ impl From<domain::MatchRules> for MatchRules {
     }
 }
 
+impl From<domain::HealthStatus> for HealthStatus {
+    fn from(v: domain::HealthStatus) -> Self {
+        match v {
+            domain::HealthStatus::Healthy => Self::Healthy,
+            domain::HealthStatus::Unhealthy => Self::Unhealthy,
+            domain::HealthStatus::Unknown => Self::Unknown,
+        }
+    }
+}
+
+impl From<domain::EndpointHealth> for EndpointHealthResponse {
+    fn from(v: domain::EndpointHealth) -> Self {
+        Self {
+            endpoint: v.endpoint.into(),
+            status: v.status.into(),
+            last_checked_at: v.last_checked_at,
+            consecutive_failures: v.consecutive_failures,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // From conversions: REST request DTOs → domain request types
 // ---------------------------------------------------------------------------
@@ -961,6 +1185,7 @@ impl From<CreateUpstreamRequest> for domain::CreateUpstreamRequest {
             cors: r.cors.map(Into::into),
             tags: r.tags,
             enabled: r.enabled,
+            idempotency_key: r.idempotency_key,
         }
     }
 }
@@ -990,6 +1215,10 @@ impl From<(Uuid, CreateRouteRequest)> for domain::CreateRouteRequest {
             plugins: r.plugins.map(Into::into),
             rate_limit: r.rate_limit.map(Into::into),
             cors: r.cors.map(Into::into),
+            sse_reconnect: r.sse_reconnect.map(Into::into),
+            get_coalescing: r.get_coalescing.map(Into::into),
+            request_compression: r.request_compression.map(Into::into),
+            response_cache: r.response_cache.map(Into::into),
             tags: r.tags,
             priority: r.priority,
             enabled: r.enabled,
@@ -1004,6 +1233,10 @@ impl From<UpdateRouteRequest> for domain::UpdateRouteRequest {
             plugins: r.plugins.map(Into::into),
             rate_limit: r.rate_limit.map(Into::into),
             cors: r.cors.map(Into::into),
+            sse_reconnect: r.sse_reconnect.map(Into::into),
+            get_coalescing: r.get_coalescing.map(Into::into),
+            request_compression: r.request_compression.map(Into::into),
+            response_cache: r.response_cache.map(Into::into),
             tags: r.tags,
             priority: r.priority,
             enabled: r.enabled,
@@ -1019,9 +1252,14 @@ impl modkit::api::api_dto::RequestApiDto for CreateUpstreamRequest {}
 impl modkit::api::api_dto::RequestApiDto for UpdateUpstreamRequest {}
 impl modkit::api::api_dto::RequestApiDto for CreateRouteRequest {}
 impl modkit::api::api_dto::RequestApiDto for UpdateRouteRequest {}
+impl modkit::api::api_dto::RequestApiDto for SetTenantRateLimitRequest {}
 
 impl modkit::api::api_dto::ResponseApiDto for UpstreamResponse {}
 impl modkit::api::api_dto::ResponseApiDto for RouteResponse {}
+impl modkit::api::api_dto::ResponseApiDto for TenantRateLimitResponse {}
+impl modkit::api::api_dto::ResponseApiDto for EndpointHealthResponse {}
+impl modkit::api::api_dto::ResponseApiDto for ValidationReport {}
+impl modkit::api::api_dto::ResponseApiDto for CredentialRefsResponse {}
 
 // ---------------------------------------------------------------------------
 // Helpers
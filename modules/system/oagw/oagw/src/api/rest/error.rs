@@ -1,9 +1,14 @@
+use axum::Json;
 use axum::response::{IntoResponse, Response};
 use http::{HeaderValue, StatusCode};
-use modkit::api::problem::Problem;
+use modkit::api::problem::{APPLICATION_PROBLEM_JSON, Problem, ValidationViolation};
+use opentelemetry::trace::TraceContextExt as _;
+use serde::Serialize;
+use tracing_opentelemetry::OpenTelemetrySpanExt as _;
 
 use crate::domain::error::DomainError;
-use oagw_sdk::api::ErrorSource;
+use crate::domain::services::{ValidationIssue, domain_err_to_sdk};
+use oagw_sdk::error::ServiceGatewayError;
 
 // ---------------------------------------------------------------------------
 // GTS error type constants
@@ -22,6 +27,8 @@ pub(crate) const ERR_NOT_FOUND: &str = "gts.x.core.errors.err.v1~x.oagw.resource
 pub(crate) const ERR_ROUTE_NOT_FOUND: &str = "gts.x.core.errors.err.v1~x.oagw.route.not_found.v1";
 pub(crate) const ERR_PAYLOAD_TOO_LARGE: &str =
     "gts.x.core.errors.err.v1~x.oagw.payload.too_large.v1";
+pub(crate) const ERR_UNSUPPORTED_MEDIA_TYPE: &str =
+    "gts.x.core.errors.err.v1~x.oagw.payload.unsupported_media_type.v1";
 pub(crate) const ERR_RATE_LIMIT_EXCEEDED: &str =
     "gts.x.core.errors.err.v1~x.oagw.rate_limit.exceeded.v1";
 pub(crate) const ERR_SECRET_NOT_FOUND: &str = "gts.x.core.errors.err.v1~x.oagw.secret.not_found.v1";
@@ -45,6 +52,7 @@ pub(crate) const ERR_IDLE_TIMEOUT: &str = "gts.x.core.errors.err.v1~x.oagw.timeo
 pub(crate) const ERR_PLUGIN_NOT_FOUND: &str = "gts.x.core.errors.err.v1~x.oagw.plugin.not_found.v1";
 pub(crate) const ERR_PLUGIN_IN_USE: &str = "gts.x.core.errors.err.v1~x.oagw.plugin.in_use.v1";
 pub(crate) const ERR_FORBIDDEN: &str = "gts.x.core.errors.err.v1~x.oagw.authz.forbidden.v1";
+pub(crate) const ERR_NOT_READY: &str = "gts.x.core.errors.err.v1~x.oagw.health.not_ready.v1";
 
 // ---------------------------------------------------------------------------
 // DomainError → Problem helpers
@@ -63,6 +71,7 @@ fn gts_type(err: &DomainError) -> &str {
         } => ERR_ROUTE_NOT_FOUND,
         DomainError::NotFound { .. } => ERR_NOT_FOUND,
         DomainError::PayloadTooLarge { .. } => ERR_PAYLOAD_TOO_LARGE,
+        DomainError::UnsupportedMediaType { .. } => ERR_UNSUPPORTED_MEDIA_TYPE,
         DomainError::RateLimitExceeded { .. } => ERR_RATE_LIMIT_EXCEEDED,
         DomainError::SecretNotFound { .. } => ERR_SECRET_NOT_FOUND,
         DomainError::DownstreamError { .. } | DomainError::Internal { .. } => ERR_DOWNSTREAM,
@@ -80,44 +89,14 @@ fn gts_type(err: &DomainError) -> &str {
         DomainError::PluginNotFound { .. } => ERR_PLUGIN_NOT_FOUND,
         DomainError::PluginInUse { .. } => ERR_PLUGIN_IN_USE,
         DomainError::Forbidden { .. } => ERR_FORBIDDEN,
+        DomainError::PluginFailed { error, .. } => gts_type(error),
     }
 }
 
-fn http_status_code(err: &DomainError) -> StatusCode {
-    match err {
-        DomainError::Validation { .. }
-        | DomainError::MissingTargetHost { .. }
-        | DomainError::InvalidTargetHost { .. }
-        | DomainError::UnknownTargetHost { .. } => StatusCode::BAD_REQUEST,
-        DomainError::Conflict { .. } => StatusCode::CONFLICT,
-        DomainError::AuthenticationFailed { .. } => StatusCode::UNAUTHORIZED,
-        DomainError::NotFound { .. } => StatusCode::NOT_FOUND,
-        DomainError::PayloadTooLarge { .. } => StatusCode::PAYLOAD_TOO_LARGE,
-        DomainError::RateLimitExceeded { .. } => StatusCode::TOO_MANY_REQUESTS,
-        DomainError::SecretNotFound { .. } | DomainError::Internal { .. } => {
-            StatusCode::INTERNAL_SERVER_ERROR
-        }
-        DomainError::DownstreamError { .. } | DomainError::ProtocolError { .. } => {
-            StatusCode::BAD_GATEWAY
-        }
-        DomainError::UpstreamDisabled { .. }
-        | DomainError::LinkUnavailable { .. }
-        | DomainError::CircuitBreakerOpen { .. } => StatusCode::SERVICE_UNAVAILABLE,
-        DomainError::ConnectionTimeout { .. }
-        | DomainError::RequestTimeout { .. }
-        | DomainError::IdleTimeout { .. } => StatusCode::GATEWAY_TIMEOUT,
-        DomainError::StreamAborted { .. } => StatusCode::BAD_GATEWAY,
-        DomainError::PluginNotFound { .. } => StatusCode::NOT_FOUND,
-        DomainError::PluginInUse { .. } => StatusCode::CONFLICT,
-        DomainError::GuardRejected { status, .. } => StatusCode::from_u16(*status)
-            .ok()
-            .filter(|code| code.is_client_error() || code.is_server_error())
-            .unwrap_or(StatusCode::BAD_REQUEST),
-        DomainError::CorsOriginNotAllowed { .. } | DomainError::CorsMethodNotAllowed { .. } => {
-            StatusCode::FORBIDDEN
-        }
-        DomainError::Forbidden { .. } => StatusCode::FORBIDDEN,
-    }
+/// Status code and source come from the SDK error a `ServiceGatewayClientV1`
+/// caller would get, so REST and in-process callers see the same mapping.
+fn sdk_error(err: &DomainError) -> ServiceGatewayError {
+    domain_err_to_sdk(err.clone())
 }
 
 fn error_title(err: &DomainError) -> &str {
@@ -130,6 +109,7 @@ fn error_title(err: &DomainError) -> &str {
         DomainError::AuthenticationFailed { .. } => "Authentication Failed",
         DomainError::NotFound { .. } => "Not Found",
         DomainError::PayloadTooLarge { .. } => "Payload Too Large",
+        DomainError::UnsupportedMediaType { .. } => "Unsupported Media Type",
         DomainError::RateLimitExceeded { .. } => "Rate Limit Exceeded",
         DomainError::SecretNotFound { .. } => "Secret Not Found",
         DomainError::DownstreamError { .. } | DomainError::Internal { .. } => "Downstream Error",
@@ -147,6 +127,43 @@ fn error_title(err: &DomainError) -> &str {
         DomainError::PluginNotFound { .. } => "Plugin Not Found",
         DomainError::PluginInUse { .. } => "Plugin In Use",
         DomainError::Forbidden { .. } => "Forbidden",
+        DomainError::PluginFailed { error, .. } => error_title(error),
+    }
+}
+
+fn error_code(err: &DomainError) -> &str {
+    match err {
+        DomainError::Validation { .. } => "VALIDATION_ERROR",
+        DomainError::Conflict { .. } => "CONFLICT",
+        DomainError::MissingTargetHost { .. } => "MISSING_TARGET_HOST",
+        DomainError::InvalidTargetHost { .. } => "INVALID_TARGET_HOST",
+        DomainError::UnknownTargetHost { .. } => "UNKNOWN_TARGET_HOST",
+        DomainError::AuthenticationFailed { .. } => "AUTHENTICATION_FAILED",
+        DomainError::NotFound {
+            entity: "route", ..
+        } => "ROUTE_NOT_FOUND",
+        DomainError::NotFound { .. } => "NOT_FOUND",
+        DomainError::PayloadTooLarge { .. } => "PAYLOAD_TOO_LARGE",
+        DomainError::UnsupportedMediaType { .. } => "UNSUPPORTED_MEDIA_TYPE",
+        DomainError::RateLimitExceeded { .. } => "RATE_LIMIT_EXCEEDED",
+        DomainError::SecretNotFound { .. } => "SECRET_NOT_FOUND",
+        DomainError::DownstreamError { .. } => "DOWNSTREAM_ERROR",
+        DomainError::Internal { .. } => "INTERNAL_ERROR",
+        DomainError::ProtocolError { .. } => "PROTOCOL_ERROR",
+        DomainError::UpstreamDisabled { .. } => "UPSTREAM_DISABLED",
+        DomainError::ConnectionTimeout { .. } => "CONNECTION_TIMEOUT",
+        DomainError::RequestTimeout { .. } => "REQUEST_TIMEOUT",
+        DomainError::GuardRejected { error_code, .. } => error_code,
+        DomainError::CorsOriginNotAllowed { .. } => "CORS_ORIGIN_NOT_ALLOWED",
+        DomainError::CorsMethodNotAllowed { .. } => "CORS_METHOD_NOT_ALLOWED",
+        DomainError::StreamAborted { .. } => "STREAM_ABORTED",
+        DomainError::LinkUnavailable { .. } => "LINK_UNAVAILABLE",
+        DomainError::CircuitBreakerOpen { .. } => "CIRCUIT_BREAKER_OPEN",
+        DomainError::IdleTimeout { .. } => "IDLE_TIMEOUT",
+        DomainError::PluginNotFound { .. } => "PLUGIN_NOT_FOUND",
+        DomainError::PluginInUse { .. } => "PLUGIN_IN_USE",
+        DomainError::Forbidden { .. } => "FORBIDDEN",
+        DomainError::PluginFailed { error, .. } => error_code(error),
     }
 }
 
@@ -158,6 +175,7 @@ fn error_instance(err: &DomainError) -> &str {
         | DomainError::UnknownTargetHost { instance, .. }
         | DomainError::AuthenticationFailed { instance, .. }
         | DomainError::PayloadTooLarge { instance, .. }
+        | DomainError::UnsupportedMediaType { instance, .. }
         | DomainError::RateLimitExceeded { instance, .. }
         | DomainError::SecretNotFound { instance, .. }
         | DomainError::DownstreamError { instance, .. }
@@ -178,6 +196,7 @@ fn error_instance(err: &DomainError) -> &str {
         | DomainError::PluginNotFound { .. }
         | DomainError::PluginInUse { .. }
         | DomainError::Forbidden { .. } => "",
+        DomainError::PluginFailed { error, .. } => error_instance(error),
     }
 }
 
@@ -189,7 +208,7 @@ impl From<DomainError> for Problem {
     fn from(err: DomainError) -> Self {
         let gts = gts_type(&err).to_string();
         let inst = error_instance(&err).to_string();
-        let status = http_status_code(&err);
+        let status = sdk_error(&err).status_code();
         let t = error_title(&err).to_string();
         let detail = err.to_string();
 
@@ -213,8 +232,52 @@ pub(crate) fn domain_error_to_problem(err: DomainError, instance: &str) -> Probl
     p
 }
 
-/// Convert a `DomainError` into an axum `Response` with the
-/// `x-oagw-error-source: gateway` header. Used by the proxy handler.
+/// 422 listing every issue a dry-run validation found, one entry per issue
+/// with the field, the message and the code create would have returned.
+pub(crate) fn validation_issues_to_problem(
+    issues: Vec<ValidationIssue>,
+    instance: &str,
+) -> Problem {
+    let detail = format!("request has {} validation issue(s)", issues.len());
+    let errors = issues
+        .into_iter()
+        .map(|issue| ValidationViolation {
+            code: Some(error_code(&issue.error).to_string()),
+            message: issue.error.to_string(),
+            field: issue.field,
+        })
+        .collect();
+    Problem::new(StatusCode::UNPROCESSABLE_ENTITY, "Validation Error", detail)
+        .with_type(ERR_VALIDATION)
+        .with_instance(instance)
+        .with_errors(errors)
+}
+
+/// Body of proxy error responses: the RFC 9457 problem plus `error_source`
+/// and `message`, so clients need not inspect `x-oagw-error-source`.
+#[derive(Serialize)]
+struct ProxyErrorBody {
+    #[serde(flatten)]
+    problem: Problem,
+    error_source: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    plugin_id: Option<String>,
+    message: String,
+}
+
+/// W3C trace id of the current span's OpenTelemetry context, if it has one.
+fn current_trace_id() -> Option<String> {
+    let cx = tracing::Span::current().context();
+    let trace_id = cx.span().span_context().trace_id();
+    (trace_id != opentelemetry::trace::TraceId::INVALID).then(|| trace_id.to_string())
+}
+
+/// Convert a `DomainError` into an axum `Response` for the proxy handler.
+///
+/// The source is reported both in the `x-oagw-error-source` header (`gateway`
+/// or `plugin`) and in the body's `error_source` field, next to a
+/// machine-readable `code` and the human-readable `message`. Plugin errors
+/// also carry the plugin's id in `x-oagw-error-plugin` and `plugin_id`.
 pub fn error_response(err: DomainError) -> Response {
     let retry_after = match &err {
         DomainError::RateLimitExceeded {
@@ -224,13 +287,39 @@ pub fn error_response(err: DomainError) -> Response {
         _ => None,
     };
 
-    let problem: Problem = err.into();
-    let mut response = problem.into_response();
+    let sdk = sdk_error(&err);
+    let source = sdk.error_source();
+    let plugin_id = sdk.plugin_id().map(str::to_owned);
+    let code = error_code(&err).to_string();
+    let mut problem = Problem::from(err).with_code(code);
+    if problem.trace_id.is_none()
+        && let Some(trace_id) = current_trace_id()
+    {
+        problem = problem.with_trace_id(trace_id);
+    }
+    let status = problem.status;
+    let body = ProxyErrorBody {
+        message: problem.detail.clone(),
+        problem,
+        error_source: source.as_str(),
+        plugin_id,
+    };
 
+    let body_plugin_id = body.plugin_id.clone();
+    let mut response = (status, Json(body)).into_response();
+    response.headers_mut().insert(
+        http::header::CONTENT_TYPE,
+        HeaderValue::from_static(APPLICATION_PROBLEM_JSON),
+    );
     response.headers_mut().insert(
         "x-oagw-error-source",
-        HeaderValue::from_static(ErrorSource::Gateway.as_str()),
+        HeaderValue::from_static(source.as_str()),
     );
+    if let Some(plugin_id) = &body_plugin_id
+        && let Ok(v) = HeaderValue::from_str(plugin_id)
+    {
+        response.headers_mut().insert("x-oagw-error-plugin", v);
+    }
 
     if let Some(secs) = retry_after
         && let Ok(v) = secs.to_string().parse()
@@ -270,6 +359,27 @@ mod tests {
         assert_eq!(p.title, "Conflict");
     }
 
+    #[test]
+    fn status_codes_follow_the_sdk_mapping() {
+        let internal = DomainError::Internal {
+            message: "boom".into(),
+        };
+        let downstream = DomainError::DownstreamError {
+            detail: "bad upstream".into(),
+            instance: "/test".into(),
+        };
+        for err in [internal, downstream] {
+            let expected = sdk_error(&err).status_code();
+            let p: Problem = err.into();
+            assert_eq!(p.status, expected);
+        }
+        let p: Problem = DomainError::Internal {
+            message: "boom".into(),
+        }
+        .into();
+        assert_eq!(p.status, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
     #[test]
     fn rate_limit_exceeded_produces_429() {
         let err = DomainError::RateLimitExceeded {
@@ -325,6 +435,10 @@ mod tests {
                 detail: "test".into(),
                 instance: "/test".into(),
             },
+            DomainError::UnsupportedMediaType {
+                detail: "test".into(),
+                instance: "/test".into(),
+            },
             DomainError::RateLimitExceeded {
                 detail: "test".into(),
                 instance: "/test".into(),
@@ -361,6 +475,7 @@ mod tests {
                 error_code: "MISSING_HEADER".into(),
                 detail: "test".into(),
                 instance: "/test".into(),
+                plugin_id: "gts.x.core.oagw.guard_plugin.v1~x.core.oagw.required_headers.v1".into(),
             },
             DomainError::CorsOriginNotAllowed {
                 origin: "https://evil.com".into(),
@@ -434,6 +549,7 @@ mod tests {
             error_code: "FORBIDDEN".into(),
             detail: "test".into(),
             instance: "/test".into(),
+            plugin_id: "gts.x.core.oagw.guard_plugin.v1~x.core.oagw.required_headers.v1".into(),
         };
         let p: Problem = err.into();
         assert_eq!(p.status, StatusCode::FORBIDDEN);
@@ -446,6 +562,7 @@ mod tests {
             error_code: "UNAVAILABLE".into(),
             detail: "test".into(),
             instance: "/test".into(),
+            plugin_id: "gts.x.core.oagw.guard_plugin.v1~x.core.oagw.required_headers.v1".into(),
         };
         let p: Problem = err.into();
         assert_eq!(p.status, StatusCode::SERVICE_UNAVAILABLE);
@@ -458,6 +575,7 @@ mod tests {
             error_code: "OK".into(),
             detail: "test".into(),
             instance: "/test".into(),
+            plugin_id: "gts.x.core.oagw.guard_plugin.v1~x.core.oagw.required_headers.v1".into(),
         };
         let p: Problem = err.into();
         assert_eq!(p.status, StatusCode::BAD_REQUEST);
@@ -470,6 +588,7 @@ mod tests {
             error_code: "REDIRECT".into(),
             detail: "test".into(),
             instance: "/test".into(),
+            plugin_id: "gts.x.core.oagw.guard_plugin.v1~x.core.oagw.required_headers.v1".into(),
         };
         let p: Problem = err.into();
         assert_eq!(p.status, StatusCode::BAD_REQUEST);
@@ -482,6 +601,7 @@ mod tests {
             error_code: "INVALID".into(),
             detail: "test".into(),
             instance: "/test".into(),
+            plugin_id: "gts.x.core.oagw.guard_plugin.v1~x.core.oagw.required_headers.v1".into(),
         };
         let p: Problem = err.into();
         assert_eq!(p.status, StatusCode::BAD_REQUEST);
@@ -499,4 +619,91 @@ mod tests {
             "gateway"
         );
     }
+
+    async fn body_json(resp: Response) -> serde_json::Value {
+        let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn error_response_body_reports_gateway_source() {
+        let err = DomainError::NotFound {
+            entity: "route",
+            id: uuid::Uuid::nil(),
+        };
+        let resp = error_response(err);
+        assert_eq!(
+            resp.headers().get(http::header::CONTENT_TYPE).unwrap(),
+            APPLICATION_PROBLEM_JSON
+        );
+
+        let body = body_json(resp).await;
+        assert_eq!(body["error_source"], "gateway");
+        assert_eq!(body["code"], "ROUTE_NOT_FOUND");
+        assert_eq!(body["message"], body["detail"]);
+        assert_eq!(body["type"], ERR_ROUTE_NOT_FOUND);
+        assert_eq!(body["status"], 404);
+    }
+
+    #[tokio::test]
+    async fn error_response_attributes_guard_rejection_to_plugin() {
+        let err = DomainError::GuardRejected {
+            status: 400,
+            error_code: "MISSING_HEADER".into(),
+            detail: "x-tenant required".into(),
+            instance: "/test".into(),
+            plugin_id: "gts.x.core.oagw.guard_plugin.v1~x.core.oagw.required_headers.v1".into(),
+        };
+        let resp = error_response(err);
+        assert_eq!(resp.headers().get("x-oagw-error-source").unwrap(), "plugin");
+        assert_eq!(
+            resp.headers().get("x-oagw-error-plugin").unwrap(),
+            "gts.x.core.oagw.guard_plugin.v1~x.core.oagw.required_headers.v1"
+        );
+
+        let body = body_json(resp).await;
+        assert_eq!(body["code"], "MISSING_HEADER");
+        assert_eq!(body["error_source"], "plugin");
+        assert_eq!(
+            body["plugin_id"],
+            "gts.x.core.oagw.guard_plugin.v1~x.core.oagw.required_headers.v1"
+        );
+    }
+
+    #[tokio::test]
+    async fn error_response_trace_id_comes_from_otel_context() {
+        use opentelemetry::trace::TracerProvider as _;
+        use tracing_subscriber::layer::SubscriberExt as _;
+
+        let provider = opentelemetry_sdk::trace::SdkTracerProvider::builder().build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("oagw-test")));
+
+        let (resp, expected) = tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("proxy");
+            let _guard = span.enter();
+            let expected = span.context().span().span_context().trace_id().to_string();
+            let err = DomainError::NotFound {
+                entity: "route",
+                id: uuid::Uuid::nil(),
+            };
+            (error_response(err), expected)
+        });
+
+        let body = body_json(resp).await;
+        assert_eq!(expected.len(), 32, "W3C trace id");
+        assert_eq!(body["trace_id"], expected);
+    }
+
+    #[tokio::test]
+    async fn error_response_omits_trace_id_without_otel_context() {
+        let err = DomainError::NotFound {
+            entity: "route",
+            id: uuid::Uuid::nil(),
+        };
+        let body = body_json(error_response(err)).await;
+        assert!(body.get("trace_id").is_none());
+    }
 }
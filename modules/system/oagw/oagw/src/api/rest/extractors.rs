@@ -1,6 +1,7 @@
 use modkit::api::problem::Problem;
 use uuid::Uuid;
 
+use crate::domain::error::DomainError;
 use crate::domain::gts_helpers;
 use crate::domain::model::ListQuery;
 
@@ -9,18 +10,19 @@ use crate::domain::model::ListQuery;
 /// `Problem` if the prefix does not match.
 #[allow(clippy::result_large_err)]
 pub fn parse_gts_id(gts_str: &str, expected_schema: &str, instance: &str) -> Result<Uuid, Problem> {
-    let (schema, uuid) = gts_helpers::parse_resource_gts(gts_str).map_err(Problem::from)?;
-    let expected_prefix = expected_schema.trim_end_matches('~');
-    if schema != expected_prefix {
+    let gts = gts_helpers::ResourceGts::parse(gts_str)
+        .map_err(|e| Problem::from(DomainError::from(e)))?;
+    let schema = gts.schema();
+    if schema != expected_schema {
         return Err(Problem::new(
             http::StatusCode::BAD_REQUEST,
             "Validation Error",
-            format!("expected GTS schema '{expected_schema}' but got '{schema}~'"),
+            format!("expected GTS schema '{expected_schema}' but got '{schema}'"),
         )
         .with_type("gts.x.core.errors.err.v1~x.oagw.validation.error.v1")
         .with_instance(instance));
     }
-    Ok(uuid)
+    Ok(gts.id())
 }
 
 /// Pagination query parameters.
@@ -0,0 +1,49 @@
+use axum::Json;
+use axum::extract::{Extension, Path};
+use axum::response::IntoResponse;
+use credstore_sdk::SecretRef;
+use http::StatusCode;
+use modkit::api::problem::Problem;
+use modkit_security::SecurityContext;
+
+use crate::api::rest::dto::CredentialRefsResponse;
+use crate::api::rest::error::domain_error_to_problem;
+use crate::module::AppState;
+
+/// List the `secret_ref`s the caller's upstreams authenticate with, for
+/// auditing which credentials are in use. Only reference names are
+/// returned, never values.
+pub async fn list_credential_refs(
+    Extension(state): Extension<AppState>,
+    Extension(ctx): Extension<SecurityContext>,
+) -> Result<impl IntoResponse, Problem> {
+    let secret_refs = state
+        .cp
+        .list_credential_refs(&ctx)
+        .await
+        .map_err(|e| domain_error_to_problem(e, "/oagw/v1/credentials"))?;
+    Ok(Json(CredentialRefsResponse { secret_refs }))
+}
+
+/// Evict a rotated secret from the data-plane credential cache so the next
+/// proxied request re-fetches it. Accepts the bare name or a (URL-encoded)
+/// `cred://` ref.
+pub async fn invalidate_credential(
+    Extension(state): Extension<AppState>,
+    Path(secret_ref): Path<String>,
+) -> Result<impl IntoResponse, Problem> {
+    let instance = format!("/oagw/v1/credentials/{secret_ref}/invalidate");
+    let raw = secret_ref.strip_prefix("cred://").unwrap_or(&secret_ref);
+    let key = SecretRef::new(raw).map_err(|e| {
+        Problem::new(StatusCode::BAD_REQUEST, "Validation Error", e.to_string())
+            .with_type("gts.x.core.errors.err.v1~x.oagw.validation.error.v1")
+            .with_instance(&instance)
+    })?;
+    let evicted = state.credentials.invalidate(key.as_ref());
+    tracing::info!(
+        secret_ref = key.as_ref(),
+        evicted,
+        "credential cache invalidated"
+    );
+    Ok(StatusCode::NO_CONTENT)
+}
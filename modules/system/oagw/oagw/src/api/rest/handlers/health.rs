@@ -0,0 +1,65 @@
+use axum::Json;
+use axum::extract::{Extension, Path};
+use axum::response::IntoResponse;
+use http::StatusCode;
+use modkit::api::problem::Problem;
+use modkit_security::SecurityContext;
+use serde_json::json;
+
+use crate::api::rest::dto::EndpointHealthResponse;
+use crate::api::rest::error::{ERR_NOT_READY, domain_error_to_problem};
+use crate::module::AppState;
+
+/// Liveness: the module is up and serving HTTP.
+pub async fn health() -> impl IntoResponse {
+    Json(json!({ "status": "healthy" }))
+}
+
+/// Readiness: the control plane's storage and every configured dependency
+/// are reachable. Otherwise 503, naming the unreachable dependencies; the
+/// failure reasons are only logged.
+pub async fn ready(Extension(state): Extension<AppState>) -> Result<impl IntoResponse, Problem> {
+    let mut unavailable = Vec::new();
+    if let Err(e) = state.cp.check_ready().await {
+        tracing::warn!(error = %e, "readiness: control plane storage unreachable");
+        unavailable.push("control_plane".to_string());
+    }
+    for check in &state.readiness_checks {
+        if let Err(reason) = check.check().await {
+            tracing::warn!(
+                dependency = check.name(),
+                reason,
+                "readiness: dependency unreachable"
+            );
+            unavailable.push(check.name().to_string());
+        }
+    }
+
+    if unavailable.is_empty() {
+        return Ok(Json(json!({ "status": "ready" })));
+    }
+    Err(Problem::new(
+        StatusCode::SERVICE_UNAVAILABLE,
+        "Not Ready",
+        format!("unavailable dependencies: {}", unavailable.join(", ")),
+    )
+    .with_type(ERR_NOT_READY)
+    .with_instance("/oagw/v1/ready"))
+}
+
+/// Active health-check state of each endpoint of the upstream `alias`
+/// resolves to.
+pub async fn upstream_health(
+    Extension(state): Extension<AppState>,
+    Extension(ctx): Extension<SecurityContext>,
+    Path(alias): Path<String>,
+) -> Result<impl IntoResponse, Problem> {
+    let instance = format!("/oagw/v1/health/upstreams/{alias}");
+    let health = state
+        .dp
+        .upstream_health(&ctx, &alias)
+        .await
+        .map_err(|e| domain_error_to_problem(e, &instance))?;
+    let response: Vec<EndpointHealthResponse> = health.into_iter().map(Into::into).collect();
+    Ok(Json(response))
+}
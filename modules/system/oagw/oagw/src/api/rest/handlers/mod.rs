@@ -1,3 +1,6 @@
+pub mod credential;
+pub mod health;
 pub mod proxy;
 pub mod route;
+pub mod tenant;
 pub mod upstream;
@@ -7,14 +7,54 @@ use crate::infra::proxy::websocket::{WebSocketBridgeHandle, websocket_bridge};
 use axum::body::Body;
 use axum::extract::{Extension, Request};
 use axum::response::Response;
-use http::StatusCode;
+use http::{HeaderMap, StatusCode};
 use modkit_security::SecurityContext;
-use oagw_sdk::api::ErrorSource;
+use oagw_sdk::api::{ErrorPlugin, ErrorSource};
 use tracing::Instrument;
 
 use crate::api::rest::error::error_response;
 use crate::module::AppState;
 
+/// Largest upstream error body that gets `error_source` added to it.
+const MAX_TAGGED_ERROR_BODY: u64 = 64 * 1024;
+
+/// Whether an upstream response is a JSON error whose body can be rewritten:
+/// 4xx/5xx, uncompressed, with a known length of at most
+/// [`MAX_TAGGED_ERROR_BODY`].
+fn is_taggable_error(status: StatusCode, headers: &HeaderMap) -> bool {
+    let is_json = headers
+        .get(http::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<mime::Mime>().ok())
+        .is_some_and(|m| {
+            m.subtype() == mime::JSON || m.suffix().is_some_and(|suffix| suffix == mime::JSON)
+        });
+    let is_identity = headers
+        .get(http::header::CONTENT_ENCODING)
+        .is_none_or(|v| v.as_bytes().eq_ignore_ascii_case(b"identity"));
+    let fits = headers::unique_content_length(headers)
+        .ok()
+        .flatten()
+        .is_some_and(|len| len <= MAX_TAGGED_ERROR_BODY);
+    (status.is_client_error() || status.is_server_error()) && is_json && is_identity && fits
+}
+
+/// Add `"error_source": "upstream"` to a JSON object error body, so clients
+/// can tell the source from the body alone (ADR 0013). Other bodies are
+/// returned unchanged.
+fn tag_upstream_error_body(bytes: Bytes) -> Bytes {
+    match serde_json::from_slice::<serde_json::Value>(&bytes) {
+        Ok(serde_json::Value::Object(mut obj)) if !obj.contains_key("error_source") => {
+            obj.insert(
+                "error_source".to_owned(),
+                ErrorSource::Upstream.as_str().into(),
+            );
+            serde_json::to_vec(&obj).map_or(bytes, Bytes::from)
+        }
+        _ => bytes,
+    }
+}
+
 /// Proxy handler for `/oagw/v1/proxy/{alias}/{path:.*}`.
 ///
 /// Parses the alias and path suffix from the URL, validates the request,
@@ -122,20 +162,19 @@ pub async fn proxy_handler(
     }
 
     // Validate Content-Length if present (skip for WebSocket — no body).
-    if !is_upgrade && let Some(cl) = parts.headers.get(http::header::CONTENT_LENGTH) {
-        let cl_str = cl.to_str().map_err(|_| {
-            error_response(DomainError::Validation {
-                detail: "invalid Content-Length header".into(),
-                instance: path.to_string(),
-            })
-        })?;
-        let cl_val: usize = cl_str.parse().map_err(|_| {
+    // Repeated identical values collapse to one; conflicting ones are rejected.
+    let declared_len = if is_upgrade {
+        None
+    } else {
+        headers::unique_content_length(&parts.headers).map_err(|detail| {
             error_response(DomainError::Validation {
-                detail: format!("Content-Length is not a valid integer: '{cl_str}'"),
+                detail,
                 instance: path.to_string(),
             })
-        })?;
-        if cl_val > max_body_size {
+        })?
+    };
+    if let Some(cl_val) = declared_len {
+        if cl_val > max_body_size as u64 {
             return Err(error_response(DomainError::PayloadTooLarge {
                 detail: format!(
                     "request body of {cl_val} bytes exceeds maximum of {max_body_size} bytes"
@@ -143,6 +182,9 @@ pub async fn proxy_handler(
                 instance: path.to_string(),
             }));
         }
+        parts
+            .headers
+            .insert(http::header::CONTENT_LENGTH, cl_val.into());
     }
 
     // Read body bytes (limited to max_body_size).
@@ -159,6 +201,17 @@ pub async fn proxy_handler(
                 })
             })?
     };
+    if let Some(declared) = declared_len
+        && declared != body_bytes.len() as u64
+    {
+        return Err(error_response(DomainError::Validation {
+            detail: format!(
+                "Content-Length of {declared} does not match the {}-byte request body",
+                body_bytes.len()
+            ),
+            instance: path.to_string(),
+        }));
+    }
 
     // Strip the proxy prefix from the URI so the DP receives /{alias}/{path}?query.
     let new_uri_str = if let Some(query) = parts.uri.query() {
@@ -247,6 +300,26 @@ pub async fn proxy_handler(
         .copied()
         .unwrap_or(ErrorSource::Gateway);
 
+    // Upstream JSON errors carry their source in the body as well.
+    let body = if error_source == ErrorSource::Upstream
+        && is_taggable_error(resp_parts.status, &resp_parts.headers)
+    {
+        let bytes = sdk_body.into_bytes().await.map_err(|e| {
+            error_response(DomainError::StreamAborted {
+                detail: format!("failed to read upstream error body: {e}"),
+                instance: String::new(),
+            })
+        })?;
+        let bytes = tag_upstream_error_body(bytes);
+        resp_parts
+            .headers
+            .insert(http::header::CONTENT_LENGTH, bytes.len().into());
+        Body::from(bytes)
+    } else {
+        // Stream the response body.
+        Body::from_stream(sdk_body.into_stream())
+    };
+
     // Build axum response.
     // Response headers are already sanitized by the DP service layer.
     let mut builder = Response::builder().status(resp_parts.status);
@@ -257,9 +330,9 @@ pub async fn proxy_handler(
 
     // Add error source header.
     builder = builder.header("x-oagw-error-source", error_source.as_str());
-
-    // Stream the response body.
-    let body = Body::from_stream(sdk_body.into_stream());
+    if let Some(ErrorPlugin(plugin_id)) = resp_parts.extensions.get::<ErrorPlugin>() {
+        builder = builder.header("x-oagw-error-plugin", plugin_id);
+    }
 
     builder.body(body).map_err(|e| {
         error_response(DomainError::DownstreamError {
@@ -1,4 +1,7 @@
 // Created: 2026-04-07 by Constructor Tech
+use bytes::Bytes;
+use http::{HeaderMap, StatusCode};
+
 #[test]
 fn parse_alias_and_suffix() {
     let path = "/oagw/v1/proxy/api.openai.com/v1/chat/completions";
@@ -58,3 +61,95 @@ fn percent_encoded_param_name_decoded() {
     assert_eq!(params.len(), 1);
     assert_eq!(params[0], ("my key".into(), "value".into()));
 }
+
+fn json_error_headers(len: usize) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        http::header::CONTENT_TYPE,
+        "application/json".parse().unwrap(),
+    );
+    headers.insert(http::header::CONTENT_LENGTH, len.into());
+    headers
+}
+
+#[test]
+fn upstream_json_errors_are_taggable() {
+    let headers = json_error_headers(20);
+    assert!(super::is_taggable_error(
+        StatusCode::INTERNAL_SERVER_ERROR,
+        &headers
+    ));
+    assert!(super::is_taggable_error(StatusCode::NOT_FOUND, &headers));
+    assert!(!super::is_taggable_error(StatusCode::OK, &headers));
+
+    let mut problem = json_error_headers(20);
+    problem.insert(
+        http::header::CONTENT_TYPE,
+        "application/problem+json".parse().unwrap(),
+    );
+    assert!(super::is_taggable_error(StatusCode::BAD_REQUEST, &problem));
+}
+
+#[test]
+fn compressed_unsized_or_large_upstream_errors_are_not_taggable() {
+    let mut gzip = json_error_headers(20);
+    gzip.insert(http::header::CONTENT_ENCODING, "gzip".parse().unwrap());
+    assert!(!super::is_taggable_error(StatusCode::BAD_GATEWAY, &gzip));
+
+    let mut chunked = json_error_headers(20);
+    chunked.remove(http::header::CONTENT_LENGTH);
+    assert!(!super::is_taggable_error(StatusCode::BAD_GATEWAY, &chunked));
+
+    let large = json_error_headers(usize::try_from(super::MAX_TAGGED_ERROR_BODY).unwrap() + 1);
+    assert!(!super::is_taggable_error(StatusCode::BAD_GATEWAY, &large));
+
+    let mut text = json_error_headers(20);
+    text.insert(http::header::CONTENT_TYPE, "text/plain".parse().unwrap());
+    assert!(!super::is_taggable_error(StatusCode::BAD_GATEWAY, &text));
+}
+
+#[test]
+fn tag_upstream_error_body_adds_source_to_objects_only() {
+    let tagged = super::tag_upstream_error_body(Bytes::from_static(br#"{"error":"boom"}"#));
+    let json: serde_json::Value = serde_json::from_slice(&tagged).unwrap();
+    assert_eq!(json["error"], "boom");
+    assert_eq!(json["error_source"], "upstream");
+
+    for untouched in [&br#"["boom"]"#[..], b"not json", br#"{"error_source":"x"}"#] {
+        let body = Bytes::copy_from_slice(untouched);
+        assert_eq!(super::tag_upstream_error_body(body.clone()), body);
+    }
+}
+
+// A single comma-joined Content-Length header with conflicting values is
+// rejected before the body is read or the alias is resolved.
+#[tokio::test]
+async fn comma_joined_conflicting_content_length_returns_400() {
+    let h = crate::test_support::AppHarness::builder().build().await;
+
+    let resp = h
+        .api_v1()
+        .proxy_post("cl-conflict", "v1/items")
+        .with_header(
+            http::header::CONTENT_LENGTH,
+            http::HeaderValue::from_static("5, 11"),
+        )
+        .with_body("hello world")
+        .expect_status(400)
+        .await;
+    resp.assert_body_contains("conflicting Content-Length values: 5 and 11");
+
+    // Repeated identical values collapse to one and pass validation; the
+    // request then fails on the unknown alias instead.
+    let resp = h
+        .api_v1()
+        .proxy_post("cl-conflict", "v1/items")
+        .with_header(
+            http::header::CONTENT_LENGTH,
+            http::HeaderValue::from_static("5, 5"),
+        )
+        .with_body("hello")
+        .send()
+        .await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND, "{}", resp.text());
+}
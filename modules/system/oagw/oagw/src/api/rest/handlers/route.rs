@@ -5,8 +5,10 @@ use http::StatusCode;
 use modkit::api::problem::Problem;
 use modkit_security::SecurityContext;
 
-use crate::api::rest::dto::{CreateRouteRequest, RouteResponse, UpdateRouteRequest};
-use crate::api::rest::error::domain_error_to_problem;
+use crate::api::rest::dto::{
+    CreateRouteRequest, RouteResponse, UpdateRouteRequest, ValidationReport,
+};
+use crate::api::rest::error::{domain_error_to_problem, validation_issues_to_problem};
 use crate::api::rest::extractors::parse_gts_id;
 use crate::domain::gts_helpers as gts;
 use crate::domain::model::Route;
@@ -21,6 +23,10 @@ fn to_response(r: Route) -> RouteResponse {
         plugins: r.plugins.map(Into::into),
         rate_limit: r.rate_limit.map(Into::into),
         cors: r.cors.map(Into::into),
+        sse_reconnect: r.sse_reconnect.map(Into::into),
+        get_coalescing: r.get_coalescing.map(Into::into),
+        request_compression: r.request_compression.map(Into::into),
+        response_cache: r.response_cache.map(Into::into),
         tags: r.tags,
         priority: r.priority,
         enabled: r.enabled,
@@ -42,6 +48,26 @@ pub async fn create_route(
     Ok((StatusCode::CREATED, Json(to_response(route))))
 }
 
+/// Dry-run `create_route`: 200 if the request would be accepted, else 422
+/// listing every issue. Nothing is persisted.
+pub async fn validate_route(
+    Extension(state): Extension<AppState>,
+    Extension(ctx): Extension<SecurityContext>,
+    Json(req): Json<CreateRouteRequest>,
+) -> Result<impl IntoResponse, Problem> {
+    let instance = "/oagw/v1/routes/validate";
+    let upstream_uuid = parse_gts_id(&req.upstream_id, gts::UPSTREAM_SCHEMA, instance)?;
+    let issues = state
+        .cp
+        .validate_route(&ctx, &(upstream_uuid, req).into())
+        .await
+        .map_err(|e| domain_error_to_problem(e, instance))?;
+    if !issues.is_empty() {
+        return Err(validation_issues_to_problem(issues, instance));
+    }
+    Ok(Json(ValidationReport { valid: true }))
+}
+
 pub async fn get_route(
     Extension(state): Extension<AppState>,
     Extension(ctx): Extension<SecurityContext>,
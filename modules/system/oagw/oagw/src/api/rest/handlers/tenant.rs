@@ -0,0 +1,65 @@
+use axum::Json;
+use axum::extract::Extension;
+use axum::response::IntoResponse;
+use http::StatusCode;
+use modkit::api::problem::Problem;
+use modkit_security::SecurityContext;
+
+use crate::api::rest::dto::{SetTenantRateLimitRequest, TenantRateLimitResponse};
+use crate::api::rest::error::domain_error_to_problem;
+use crate::domain::error::DomainError;
+use crate::domain::model::TenantRateLimit;
+use crate::module::AppState;
+
+const INSTANCE: &str = "/oagw/v1/tenant/rate-limit";
+
+fn to_response(l: TenantRateLimit) -> TenantRateLimitResponse {
+    TenantRateLimitResponse {
+        tenant_id: l.tenant_id,
+        rate_limit: l.config.into(),
+        aggregate: l.aggregate,
+    }
+}
+
+pub async fn get_tenant_rate_limit(
+    Extension(state): Extension<AppState>,
+    Extension(ctx): Extension<SecurityContext>,
+) -> Result<impl IntoResponse, Problem> {
+    let limit = state
+        .cp
+        .get_tenant_rate_limit(&ctx)
+        .await
+        .map_err(|e| domain_error_to_problem(e, INSTANCE))?
+        .ok_or_else(|| {
+            domain_error_to_problem(
+                DomainError::not_found("tenant rate limit", ctx.subject_tenant_id()),
+                INSTANCE,
+            )
+        })?;
+    Ok(Json(to_response(limit)))
+}
+
+pub async fn put_tenant_rate_limit(
+    Extension(state): Extension<AppState>,
+    Extension(ctx): Extension<SecurityContext>,
+    Json(req): Json<SetTenantRateLimitRequest>,
+) -> Result<impl IntoResponse, Problem> {
+    let limit = state
+        .cp
+        .set_tenant_rate_limit(&ctx, req.rate_limit.into(), req.aggregate)
+        .await
+        .map_err(|e| domain_error_to_problem(e, INSTANCE))?;
+    Ok(Json(to_response(limit)))
+}
+
+pub async fn delete_tenant_rate_limit(
+    Extension(state): Extension<AppState>,
+    Extension(ctx): Extension<SecurityContext>,
+) -> Result<impl IntoResponse, Problem> {
+    state
+        .cp
+        .delete_tenant_rate_limit(&ctx)
+        .await
+        .map_err(|e| domain_error_to_problem(e, INSTANCE))?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -5,8 +5,10 @@ use http::StatusCode;
 use modkit::api::problem::Problem;
 use modkit_security::SecurityContext;
 
-use crate::api::rest::dto::{CreateUpstreamRequest, UpdateUpstreamRequest, UpstreamResponse};
-use crate::api::rest::error::domain_error_to_problem;
+use crate::api::rest::dto::{
+    CreateUpstreamRequest, UpdateUpstreamRequest, UpstreamResponse, ValidationReport,
+};
+use crate::api::rest::error::{domain_error_to_problem, validation_issues_to_problem};
 use crate::api::rest::extractors::{PaginationQuery, parse_gts_id};
 use crate::domain::gts_helpers as gts;
 use crate::domain::model::Upstream;
@@ -34,14 +36,37 @@ pub async fn create_upstream(
     Extension(ctx): Extension<SecurityContext>,
     Json(req): Json<CreateUpstreamRequest>,
 ) -> Result<impl IntoResponse, Problem> {
-    let upstream = state
+    let created = state
         .cp
-        .create_upstream(&ctx, req.into())
+        .create_upstream_idempotent(&ctx, req.into())
         .await
         .map_err(|e| domain_error_to_problem(e, "/oagw/v1/upstreams"))?;
+    // A repeated idempotency key returns the existing upstream unchanged.
+    if created.replayed {
+        return Ok((StatusCode::OK, Json(to_response(created.upstream))));
+    }
     // Defensive no-op: new IDs have no cache entry, but keeps CRUD handlers uniform.
-    state.backend_selector.invalidate(upstream.id);
-    Ok((StatusCode::CREATED, Json(to_response(upstream))))
+    state.backend_selector.invalidate(created.upstream.id);
+    Ok((StatusCode::CREATED, Json(to_response(created.upstream))))
+}
+
+/// Dry-run `create_upstream`: 200 if the request would be accepted, else 422
+/// listing every issue. Nothing is persisted.
+pub async fn validate_upstream(
+    Extension(state): Extension<AppState>,
+    Extension(ctx): Extension<SecurityContext>,
+    Json(req): Json<CreateUpstreamRequest>,
+) -> Result<impl IntoResponse, Problem> {
+    let instance = "/oagw/v1/upstreams/validate";
+    let issues = state
+        .cp
+        .validate_upstream(&ctx, &req.into())
+        .await
+        .map_err(|e| domain_error_to_problem(e, instance))?;
+    if !issues.is_empty() {
+        return Err(validation_issues_to_problem(issues, instance));
+    }
+    Ok(Json(ValidationReport { valid: true }))
 }
 
 pub async fn get_upstream(
@@ -0,0 +1,50 @@
+use axum::Router;
+use modkit::api::OpenApiRegistry;
+use modkit::api::operation_builder::OperationBuilder;
+
+use super::super::dto;
+use super::super::handlers;
+use super::License;
+
+const API_TAG: &str = "OAGW Credentials";
+
+pub(super) fn register(mut router: Router, openapi: &dyn OpenApiRegistry) -> Router {
+    // GET /oagw/v1/credentials — List configured secret refs
+    router = OperationBuilder::get("/oagw/v1/credentials")
+        .operation_id("oagw.list_credential_refs")
+        .summary("List credential references")
+        .description(
+            "List the secret_ref names used by the auth config of the caller's upstreams; \
+             secret values are never returned",
+        )
+        .tag(API_TAG)
+        .authenticated()
+        .require_license_features::<License>([])
+        .handler(handlers::credential::list_credential_refs)
+        .json_response_with_schema::<dto::CredentialRefsResponse>(
+            openapi,
+            http::StatusCode::OK,
+            "Configured secret references",
+        )
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    // POST /oagw/v1/credentials/{secret_ref}/invalidate — Drop cached secret
+    router = OperationBuilder::post("/oagw/v1/credentials/{secret_ref}/invalidate")
+        .operation_id("oagw.invalidate_credential")
+        .summary("Invalidate cached credential")
+        .description(
+            "Evict a rotated secret from the data-plane credential cache; \
+             the next proxied request re-fetches it",
+        )
+        .tag(API_TAG)
+        .path_param("secret_ref", "Secret reference (with or without cred://)")
+        .authenticated()
+        .require_license_features::<License>([])
+        .handler(handlers::credential::invalidate_credential)
+        .json_response(http::StatusCode::NO_CONTENT, "Credential invalidated")
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    router
+}
@@ -0,0 +1,64 @@
+use axum::Router;
+use modkit::api::OpenApiRegistry;
+use modkit::api::operation_builder::OperationBuilder;
+
+use super::super::dto;
+use super::super::handlers;
+use super::License;
+
+const API_TAG: &str = "OAGW Health";
+
+pub(super) fn register(mut router: Router, openapi: &dyn OpenApiRegistry) -> Router {
+    // GET /oagw/v1/health — Liveness probe
+    router = OperationBuilder::get("/oagw/v1/health")
+        .operation_id("oagw.health")
+        .summary("Liveness probe")
+        .description("Report that the gateway is up")
+        .tag(API_TAG)
+        .public()
+        .handler(handlers::health::health)
+        .json_response(http::StatusCode::OK, "Gateway is up")
+        .register(router, openapi);
+
+    // GET /oagw/v1/ready — Readiness probe
+    router = OperationBuilder::get("/oagw/v1/ready")
+        .operation_id("oagw.ready")
+        .summary("Readiness probe")
+        .description(
+            "Report whether control-plane storage and configured dependencies \
+             are reachable",
+        )
+        .tag(API_TAG)
+        .public()
+        .handler(handlers::health::ready)
+        .json_response(http::StatusCode::OK, "Gateway is ready")
+        .problem_response(
+            openapi,
+            http::StatusCode::SERVICE_UNAVAILABLE,
+            "A dependency is unreachable",
+        )
+        .register(router, openapi);
+
+    // GET /oagw/v1/health/upstreams/{alias} — Upstream endpoint health
+    router = OperationBuilder::get("/oagw/v1/health/upstreams/{alias}")
+        .operation_id("oagw.upstream_health")
+        .summary("Upstream endpoint health")
+        .description(
+            "Report the active health-check state of each endpoint of the \
+             upstream an alias resolves to",
+        )
+        .tag(API_TAG)
+        .path_param("alias", "Upstream alias")
+        .authenticated()
+        .require_license_features::<License>([])
+        .handler(handlers::health::upstream_health)
+        .json_response_with_schema::<Vec<dto::EndpointHealthResponse>>(
+            openapi,
+            http::StatusCode::OK,
+            "Endpoint health",
+        )
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    router
+}
@@ -4,8 +4,11 @@ use modkit::api::operation_builder::LicenseFeature;
 
 use crate::module::AppState;
 
+mod credential;
+mod health;
 mod proxy;
 mod route;
+mod tenant;
 mod upstream;
 
 pub(super) struct License;
@@ -26,6 +29,9 @@ pub fn register_routes(
 ) -> Router {
     router = upstream::register(router, openapi);
     router = route::register(router, openapi);
+    router = tenant::register(router, openapi);
+    router = credential::register(router, openapi);
+    router = health::register(router, openapi);
     router = proxy::register(router);
     router.layer(axum::Extension(state))
 }
@@ -36,7 +42,10 @@ pub fn register_routes(
 /// Suitable for integration tests that don't need an `OpenApiRegistry`.
 #[cfg(any(test, feature = "test-utils"))]
 pub fn test_router(state: AppState, ctx: modkit_security::SecurityContext) -> Router {
-    use crate::api::rest::handlers::{proxy as proxy_h, route as route_h, upstream as upstream_h};
+    use crate::api::rest::handlers::{
+        credential as credential_h, health as health_h, proxy as proxy_h, route as route_h,
+        tenant as tenant_h, upstream as upstream_h,
+    };
     use axum::routing::{any, get, post};
 
     Router::new()
@@ -45,6 +54,10 @@ pub fn test_router(state: AppState, ctx: modkit_security::SecurityContext) -> Ro
             "/oagw/v1/upstreams",
             post(upstream_h::create_upstream).get(upstream_h::list_upstreams),
         )
+        .route(
+            "/oagw/v1/upstreams/validate",
+            post(upstream_h::validate_upstream),
+        )
         .route(
             "/oagw/v1/upstreams/{id}",
             get(upstream_h::get_upstream)
@@ -56,12 +69,36 @@ pub fn test_router(state: AppState, ctx: modkit_security::SecurityContext) -> Ro
             "/oagw/v1/routes",
             post(route_h::create_route).get(route_h::list_routes),
         )
+        .route("/oagw/v1/routes/validate", post(route_h::validate_route))
         .route(
             "/oagw/v1/routes/{id}",
             get(route_h::get_route)
                 .put(route_h::update_route)
                 .delete(route_h::delete_route),
         )
+        // Tenant settings
+        .route(
+            "/oagw/v1/tenant/rate-limit",
+            get(tenant_h::get_tenant_rate_limit)
+                .put(tenant_h::put_tenant_rate_limit)
+                .delete(tenant_h::delete_tenant_rate_limit),
+        )
+        // Credentials
+        .route(
+            "/oagw/v1/credentials",
+            get(credential_h::list_credential_refs),
+        )
+        .route(
+            "/oagw/v1/credentials/{secret_ref}/invalidate",
+            post(credential_h::invalidate_credential),
+        )
+        // Health
+        .route("/oagw/v1/health", get(health_h::health))
+        .route("/oagw/v1/ready", get(health_h::ready))
+        .route(
+            "/oagw/v1/health/upstreams/{alias}",
+            get(health_h::upstream_health),
+        )
         // Proxy
         .route("/oagw/v1/proxy/{*path}", any(proxy_h::proxy_handler))
         .layer(axum::Extension(ctx))
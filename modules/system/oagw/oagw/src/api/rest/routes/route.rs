@@ -27,6 +27,27 @@ pub(super) fn register(mut router: Router, openapi: &dyn OpenApiRegistry) -> Rou
         .standard_errors(openapi)
         .register(router, openapi);
 
+    // POST /oagw/v1/routes/validate — Validate route without creating it
+    router = OperationBuilder::post("/oagw/v1/routes/validate")
+        .operation_id("oagw.validate_route")
+        .summary("Validate route")
+        .description(
+            "Run the checks of route creation without persisting anything; \
+             422 lists every issue found",
+        )
+        .tag(API_TAG)
+        .authenticated()
+        .require_license_features::<License>([])
+        .json_request::<dto::CreateRouteRequest>(openapi, "Route configuration")
+        .handler(handlers::route::validate_route)
+        .json_response_with_schema::<dto::ValidationReport>(
+            openapi,
+            http::StatusCode::OK,
+            "Route would be accepted",
+        )
+        .standard_errors(openapi)
+        .register(router, openapi);
+
     // GET /oagw/v1/routes/{id} — Get route
     router = OperationBuilder::get("/oagw/v1/routes/{id}")
         .operation_id("oagw.get_route")
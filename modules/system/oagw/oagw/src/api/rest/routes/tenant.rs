@@ -0,0 +1,64 @@
+use axum::Router;
+use modkit::api::OpenApiRegistry;
+use modkit::api::operation_builder::OperationBuilder;
+
+use super::super::dto;
+use super::super::handlers;
+use super::License;
+
+const API_TAG: &str = "OAGW Tenant Settings";
+
+pub(super) fn register(mut router: Router, openapi: &dyn OpenApiRegistry) -> Router {
+    // GET /oagw/v1/tenant/rate-limit — Get tenant rate limit
+    router = OperationBuilder::get("/oagw/v1/tenant/rate-limit")
+        .operation_id("oagw.get_tenant_rate_limit")
+        .summary("Get tenant rate limit")
+        .description("Retrieve the rate limit shared by all of the caller tenant's upstreams")
+        .tag(API_TAG)
+        .authenticated()
+        .require_license_features::<License>([])
+        .handler(handlers::tenant::get_tenant_rate_limit)
+        .json_response_with_schema::<dto::TenantRateLimitResponse>(
+            openapi,
+            http::StatusCode::OK,
+            "Tenant rate limit",
+        )
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    // PUT /oagw/v1/tenant/rate-limit — Set tenant rate limit
+    router = OperationBuilder::put("/oagw/v1/tenant/rate-limit")
+        .operation_id("oagw.put_tenant_rate_limit")
+        .summary("Set tenant rate limit")
+        .description(
+            "Set or replace the tenant rate limit. It applies to upstreams and \
+             routes without a rate limit, and to all requests when aggregate",
+        )
+        .tag(API_TAG)
+        .authenticated()
+        .require_license_features::<License>([])
+        .json_request::<dto::SetTenantRateLimitRequest>(openapi, "Tenant rate limit")
+        .handler(handlers::tenant::put_tenant_rate_limit)
+        .json_response_with_schema::<dto::TenantRateLimitResponse>(
+            openapi,
+            http::StatusCode::OK,
+            "Tenant rate limit set",
+        )
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    // DELETE /oagw/v1/tenant/rate-limit — Remove tenant rate limit
+    router = OperationBuilder::delete("/oagw/v1/tenant/rate-limit")
+        .operation_id("oagw.delete_tenant_rate_limit")
+        .summary("Remove tenant rate limit")
+        .description("Remove the rate limit of the caller tenant")
+        .tag(API_TAG)
+        .authenticated()
+        .require_license_features::<License>([])
+        .handler(handlers::tenant::delete_tenant_rate_limit)
+        .json_response(http::StatusCode::NO_CONTENT, "Tenant rate limit removed")
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    router
+}
@@ -24,6 +24,11 @@ pub(super) fn register(mut router: Router, openapi: &dyn OpenApiRegistry) -> Rou
             http::StatusCode::CREATED,
             "Created upstream",
         )
+        .json_response_with_schema::<dto::UpstreamResponse>(
+            openapi,
+            http::StatusCode::OK,
+            "Upstream created earlier with the same idempotency_key",
+        )
         .standard_errors(openapi)
         .register(router, openapi);
 
@@ -51,6 +56,27 @@ pub(super) fn register(mut router: Router, openapi: &dyn OpenApiRegistry) -> Rou
         .standard_errors(openapi)
         .register(router, openapi);
 
+    // POST /oagw/v1/upstreams/validate — Validate upstream without creating it
+    router = OperationBuilder::post("/oagw/v1/upstreams/validate")
+        .operation_id("oagw.validate_upstream")
+        .summary("Validate upstream")
+        .description(
+            "Run the checks of upstream creation without persisting anything; \
+             422 lists every issue found",
+        )
+        .tag(API_TAG)
+        .authenticated()
+        .require_license_features::<License>([])
+        .json_request::<dto::CreateUpstreamRequest>(openapi, "Upstream configuration")
+        .handler(handlers::upstream::validate_upstream)
+        .json_response_with_schema::<dto::ValidationReport>(
+            openapi,
+            http::StatusCode::OK,
+            "Upstream would be accepted",
+        )
+        .standard_errors(openapi)
+        .register(router, openapi);
+
     // GET /oagw/v1/upstreams/{id} — Get upstream
     router = OperationBuilder::get("/oagw/v1/upstreams/{id}")
         .operation_id("oagw.get_upstream")
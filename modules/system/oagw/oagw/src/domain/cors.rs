@@ -55,16 +55,18 @@ pub fn validate_cors_config(config: &CorsConfig) -> Result<(), DomainError> {
         });
     }
 
-    // Validate that origins are either "*" or look like a valid origin
-    // (scheme://host or scheme://host:port).
+    // Validate that origins are either "*", a valid origin
+    // (scheme://host or scheme://host:port), or a subdomain wildcard
+    // (scheme://*.domain[:port]).
     for origin in &config.allowed_origins {
         if origin == "*" {
             continue;
         }
-        if !is_valid_origin(origin) {
+        if !is_valid_origin_pattern(origin) {
             return Err(DomainError::Validation {
                 detail: format!(
-                    "invalid origin '{origin}': must be '*' or a valid origin (e.g. https://example.com)"
+                    "invalid origin '{origin}': must be '*', a valid origin (e.g. https://example.com) \
+                     or a subdomain wildcard (e.g. https://*.example.com)"
                 ),
                 instance: String::new(),
             });
@@ -74,6 +76,25 @@ pub fn validate_cors_config(config: &CorsConfig) -> Result<(), DomainError> {
     Ok(())
 }
 
+/// Split a subdomain wildcard `scheme://*.suffix` into `("scheme://", "suffix")`.
+fn split_wildcard(pattern: &str) -> Option<(&str, &str)> {
+    let idx = pattern.find("://*.")?;
+    Some((&pattern[..idx + 3], &pattern[idx + 5..]))
+}
+
+/// Check whether a configured origin is a valid exact origin or subdomain
+/// wildcard. The wildcard suffix must itself be a multi-label hostname, so
+/// `https://*.com` is rejected.
+fn is_valid_origin_pattern(pattern: &str) -> bool {
+    match split_wildcard(pattern) {
+        Some((scheme, suffix)) => {
+            let host = suffix.split(':').next().unwrap_or_default();
+            host.contains('.') && is_valid_origin(&format!("{scheme}{suffix}"))
+        }
+        None => is_valid_origin(pattern),
+    }
+}
+
 /// Check whether a string looks like a valid origin (scheme://host[:port]).
 fn is_valid_origin(origin: &str) -> bool {
     // Must have a scheme separator.
@@ -187,12 +208,31 @@ pub fn apply_cors_headers(config: &CorsConfig, origin: &str) -> Vec<(String, Str
 
 /// Check whether the given origin is in the `allowed_origins` list.
 ///
-/// Supports exact string match and the wildcard `"*"`.
+/// Supports exact string match, the wildcard `"*"`, and subdomain wildcards
+/// (`https://*.example.com`). A subdomain wildcard matches one or more labels
+/// in front of the suffix — not the bare suffix itself — and stays scheme-
+/// and port-sensitive.
 pub fn is_origin_allowed(config: &CorsConfig, origin: &str) -> bool {
     config
         .allowed_origins
         .iter()
-        .any(|o| o == "*" || o == origin)
+        .any(|o| o == "*" || o == origin || matches_wildcard(o, origin))
+}
+
+fn matches_wildcard(pattern: &str, origin: &str) -> bool {
+    let Some((scheme, suffix)) = split_wildcard(pattern) else {
+        return false;
+    };
+    origin
+        .strip_prefix(scheme)
+        .and_then(|rest| rest.strip_suffix(suffix))
+        .and_then(|labels| labels.strip_suffix('.'))
+        .is_some_and(|labels| {
+            !labels.is_empty()
+                && labels
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'-'))
+        })
 }
 
 // ---------------------------------------------------------------------------
@@ -364,4 +404,54 @@ mod tests {
         };
         assert!(validate_cors_config(&config).is_ok());
     }
+
+    // -- subdomain wildcards --
+
+    #[test]
+    fn test_subdomain_wildcard_matches_subdomains_only() {
+        let config = CorsConfig {
+            allowed_origins: vec!["https://*.example.com".to_string()],
+            ..make_config()
+        };
+        assert!(is_origin_allowed(&config, "https://app.example.com"));
+        assert!(is_origin_allowed(&config, "https://a.b.example.com"));
+        assert!(!is_origin_allowed(&config, "https://example.com"));
+        assert!(!is_origin_allowed(&config, "https://evilexample.com"));
+        assert!(!is_origin_allowed(
+            &config,
+            "https://app.example.com.evil.com"
+        ));
+        assert!(!is_origin_allowed(&config, "http://app.example.com"));
+        assert!(!is_origin_allowed(&config, "https://app.example.com:8443"));
+    }
+
+    #[test]
+    fn test_subdomain_wildcard_reflects_origin() {
+        let config = CorsConfig {
+            allowed_origins: vec!["https://*.example.com:8443".to_string()],
+            ..make_config()
+        };
+        let headers = apply_cors_headers(&config, "https://app.example.com:8443");
+        let origin = headers
+            .iter()
+            .find(|(k, _)| k == "access-control-allow-origin")
+            .unwrap();
+        assert_eq!(origin.1, "https://app.example.com:8443");
+    }
+
+    #[test]
+    fn test_validate_subdomain_wildcards() {
+        for valid in ["https://*.example.com", "http://*.example.com:3000"] {
+            assert!(is_valid_origin_pattern(valid), "{valid}");
+        }
+        for invalid in [
+            "https://*.com",
+            "https://*.",
+            "https://*.example.com:notaport",
+            "https://app.*.example.com",
+            "*.example.com",
+        ] {
+            assert!(!is_valid_origin_pattern(invalid), "{invalid}");
+        }
+    }
 }
@@ -5,7 +5,7 @@ use super::repo::RepositoryError;
 
 /// Domain-layer errors for OAGW control-plane and data-plane operations.
 #[domain_model]
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, thiserror::Error)]
 pub enum DomainError {
     #[error("{entity} not found: {id}")]
     NotFound { entity: &'static str, id: Uuid },
@@ -37,6 +37,11 @@ pub enum DomainError {
     #[error("{detail}")]
     PayloadTooLarge { detail: String, instance: String },
 
+    /// The request's media type is not accepted by the gateway's
+    /// content-type policy.
+    #[error("{detail}")]
+    UnsupportedMediaType { detail: String, instance: String },
+
     #[error("{detail}")]
     RateLimitExceeded {
         detail: String,
@@ -66,6 +71,16 @@ pub enum DomainError {
         error_code: String,
         detail: String,
         instance: String,
+        /// GTS identifier of the rejecting guard plugin.
+        plugin_id: String,
+    },
+
+    /// An auth or transform plugin aborted the pipeline with `error`.
+    #[error("{error}")]
+    PluginFailed {
+        /// GTS identifier of the failing plugin.
+        plugin_id: String,
+        error: Box<DomainError>,
     },
 
     /// CORS: the request origin is not in the allowed origins list.
@@ -4,6 +4,7 @@
 //! resource GTS identifiers of the form `gts.x.core.oagw.<type>.v1~<uuid>`.
 
 use crate::domain::error::DomainError;
+use modkit_macros::domain_model;
 use uuid::Uuid;
 
 // -- Schema GTS identifiers --
@@ -22,6 +23,7 @@ pub const GRPC_PROTOCOL_ID: &str = "gts.x.core.oagw.protocol.v1~x.core.oagw.grpc
 // -- Builtin auth plugin instances --
 pub const NOOP_AUTH_PLUGIN_ID: &str = "gts.x.core.oagw.auth_plugin.v1~x.core.oagw.noop.v1";
 pub const APIKEY_AUTH_PLUGIN_ID: &str = "gts.x.core.oagw.auth_plugin.v1~x.core.oagw.apikey.v1";
+pub const KEY_POOL_AUTH_PLUGIN_ID: &str = "gts.x.core.oagw.auth_plugin.v1~x.core.oagw.key_pool.v1";
 pub const BASIC_AUTH_PLUGIN_ID: &str = "gts.x.core.oagw.auth_plugin.v1~x.core.oagw.basic.v1";
 pub const BEARER_AUTH_PLUGIN_ID: &str = "gts.x.core.oagw.auth_plugin.v1~x.core.oagw.bearer.v1";
 pub const OAUTH2_CLIENT_CRED_AUTH_PLUGIN_ID: &str =
@@ -34,6 +36,8 @@ pub const TIMEOUT_GUARD_PLUGIN_ID: &str = "gts.x.core.oagw.guard_plugin.v1~x.cor
 pub const CORS_GUARD_PLUGIN_ID: &str = "gts.x.core.oagw.guard_plugin.v1~x.core.oagw.cors.v1";
 pub const REQUIRED_HEADERS_GUARD_PLUGIN_ID: &str =
     "gts.x.core.oagw.guard_plugin.v1~x.core.oagw.required_headers.v1";
+pub const CONTENT_TYPE_GUARD_PLUGIN_ID: &str =
+    "gts.x.core.oagw.guard_plugin.v1~x.core.oagw.content_type.v1";
 
 // -- Builtin transform plugin instances --
 pub const LOGGING_TRANSFORM_PLUGIN_ID: &str =
@@ -55,28 +59,304 @@ pub fn format_route_gts(id: Uuid) -> String {
     format!("{ROUTE_SCHEMA}{}", id.hyphenated())
 }
 
+/// Why a resource GTS identifier could not be parsed.
+#[domain_model]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum GtsParseError {
+    /// The schema part before `~` is not a valid GTS identifier.
+    #[error("malformed GTS prefix in '{input}': {reason}")]
+    MalformedPrefix { input: String, reason: String },
+
+    /// There is no `~`, or nothing follows it.
+    #[error("missing resource segment in GTS identifier '{input}'")]
+    MissingResourceSegment { input: String },
+
+    /// The resource segment is not a UUID.
+    #[error("invalid UUID in GTS identifier '{input}': {reason}")]
+    InvalidUuid { input: String, reason: String },
+
+    /// A namespace or type segment passed to [`ResourceGts::new`] is empty
+    /// or contains characters outside `[a-z0-9_]`.
+    #[error("invalid GTS segment '{input}': {reason}")]
+    InvalidSegment { input: String, reason: String },
+}
+
+impl GtsParseError {
+    /// The identifier that failed to parse.
+    #[must_use]
+    pub fn input(&self) -> &str {
+        match self {
+            Self::MalformedPrefix { input, .. }
+            | Self::MissingResourceSegment { input }
+            | Self::InvalidUuid { input, .. }
+            | Self::InvalidSegment { input, .. } => input,
+        }
+    }
+}
+
+impl From<GtsParseError> for DomainError {
+    fn from(e: GtsParseError) -> Self {
+        Self::Validation {
+            instance: e.input().to_string(),
+            detail: e.to_string(),
+        }
+    }
+}
+
 /// Parse a resource GTS identifier, extracting the schema and UUID instance.
 ///
-/// Validates the full identifier using the `gts` crate (0.8.4+ supports
-/// anonymous UUID instance segments) and then splits at `~` to extract the
-/// schema prefix and UUID.
-pub fn parse_resource_gts(s: &str) -> Result<(String, Uuid), DomainError> {
-    // Validate the full GTS identifier (anonymous UUID segments supported since 0.8.4).
-    gts::GtsID::new(s).map_err(|e| DomainError::Validation {
-        detail: format!("invalid GTS identifier: {e}"),
-        instance: s.to_string(),
-    })?;
+/// Splits at the last `~`, parses the resource segment as a UUID, and then
+/// validates the full identifier using the `gts` crate (0.8.4+ supports
+/// anonymous UUID instance segments).
+pub fn parse_resource_gts(s: &str) -> Result<(String, Uuid), GtsParseError> {
+    let (schema, instance) = s
+        .rsplit_once('~')
+        .filter(|(_, instance)| !instance.is_empty())
+        .ok_or_else(|| GtsParseError::MissingResourceSegment {
+            input: s.to_string(),
+        })?;
 
-    let tilde_pos = s.rfind('~').ok_or_else(|| DomainError::Validation {
-        detail: "missing '~' separator in GTS identifier".into(),
-        instance: s.to_string(),
+    let uuid = Uuid::parse_str(instance).map_err(|e| GtsParseError::InvalidUuid {
+        input: s.to_string(),
+        reason: e.to_string(),
     })?;
 
-    let instance = &s[tilde_pos + 1..];
-    let uuid = Uuid::parse_str(instance).map_err(|e| DomainError::Validation {
-        detail: format!("invalid UUID in GTS instance: {e}"),
-        instance: s.to_string(),
+    gts::GtsID::new(s).map_err(|e| GtsParseError::MalformedPrefix {
+        input: s.to_string(),
+        reason: e.to_string(),
     })?;
 
-    Ok((s[..tilde_pos].to_string(), uuid))
+    Ok((schema.to_string(), uuid))
+}
+
+/// A resource GTS identifier, `gts.<namespace>.<type>.v<major>~<uuid>`.
+///
+/// Build one with [`ResourceGts::new`] instead of formatting strings by hand;
+/// `Display` produces the canonical form, and [`ResourceGts::parse`] of that
+/// string yields an equal value.
+#[domain_model]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceGts {
+    namespace: String,
+    resource_type: String,
+    version: u32,
+    id: Uuid,
+}
+
+impl ResourceGts {
+    /// Resource identifier with major version 1.
+    ///
+    /// `namespace` is `<vendor>.<package>.<namespace>` (e.g. `x.core.oagw`)
+    /// and `resource_type` a single segment (e.g. `route`).
+    ///
+    /// # Errors
+    /// Returns [`GtsParseError::InvalidSegment`] if a segment is empty or
+    /// contains characters outside `[a-z0-9_]`, or if `namespace` does not
+    /// have exactly three segments.
+    pub fn new(
+        namespace: impl Into<String>,
+        resource_type: impl Into<String>,
+        id: Uuid,
+    ) -> Result<Self, GtsParseError> {
+        let namespace = namespace.into();
+        let resource_type = resource_type.into();
+
+        let segments: Vec<&str> = namespace.split('.').collect();
+        if segments.len() != 3 {
+            return Err(GtsParseError::InvalidSegment {
+                input: namespace.clone(),
+                reason: "namespace must be '<vendor>.<package>.<namespace>'".into(),
+            });
+        }
+        for segment in segments {
+            validate_segment(segment)?;
+        }
+        validate_segment(&resource_type)?;
+
+        Ok(Self {
+            namespace,
+            resource_type,
+            version: 1,
+            id,
+        })
+    }
+
+    /// Set the schema's major version (defaults to 1).
+    #[must_use]
+    pub fn with_version(mut self, version: u32) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Parse a canonical resource GTS identifier.
+    ///
+    /// # Errors
+    /// Returns a [`GtsParseError`] if `s` is not a resource GTS identifier
+    /// of the form produced by `Display`.
+    pub fn parse(s: &str) -> Result<Self, GtsParseError> {
+        let (schema, id) = parse_resource_gts(s)?;
+        let malformed = || GtsParseError::MalformedPrefix {
+            input: s.to_string(),
+            reason: "expected 'gts.<vendor>.<package>.<namespace>.<type>.v<major>'".into(),
+        };
+
+        let path = schema.strip_prefix("gts.").ok_or_else(malformed)?;
+        let (path, version) = path.rsplit_once('.').ok_or_else(malformed)?;
+        let version = version
+            .strip_prefix('v')
+            .and_then(|v| v.parse().ok())
+            .ok_or_else(malformed)?;
+        let (namespace, resource_type) = path.rsplit_once('.').ok_or_else(malformed)?;
+
+        Ok(Self::new(namespace, resource_type, id)
+            .map_err(|_| malformed())?
+            .with_version(version))
+    }
+
+    #[must_use]
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// The schema part including the trailing `~`, e.g. [`ROUTE_SCHEMA`].
+    #[must_use]
+    pub fn schema(&self) -> String {
+        format!(
+            "gts.{}.{}.v{}~",
+            self.namespace, self.resource_type, self.version
+        )
+    }
+}
+
+impl std::fmt::Display for ResourceGts {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.schema(), self.id.hyphenated())
+    }
+}
+
+fn validate_segment(segment: &str) -> Result<(), GtsParseError> {
+    let reason = if segment.is_empty() {
+        "segment must not be empty"
+    } else if segment.starts_with(|c: char| c.is_ascii_digit()) {
+        "segment must not start with a digit"
+    } else if !segment
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    {
+        "segment may only contain [a-z0-9_]"
+    } else {
+        return Ok(());
+    };
+    Err(GtsParseError::InvalidSegment {
+        input: segment.to_string(),
+        reason: reason.into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: &str = "3f2a5c1e-8b4d-4e6f-9a7b-1c2d3e4f5a6b";
+
+    #[test]
+    fn test_parse_valid_route_gts() {
+        let id = Uuid::parse_str(ID).unwrap();
+        let (schema, uuid) = parse_resource_gts(&format_route_gts(id)).unwrap();
+        assert_eq!(schema, ROUTE_SCHEMA.trim_end_matches('~'));
+        assert_eq!(uuid, id);
+    }
+
+    #[test]
+    fn test_malformed_prefix() {
+        let input = format!("not-gts.route~{ID}");
+        let err = parse_resource_gts(&input).unwrap_err();
+        assert!(
+            matches!(err, GtsParseError::MalformedPrefix { .. }),
+            "{err:?}"
+        );
+        assert!(err.to_string().contains(&format!("'{input}'")));
+    }
+
+    #[test]
+    fn test_missing_resource_segment() {
+        for input in ["gts.x.core.oagw.route.v1", "gts.x.core.oagw.route.v1~"] {
+            let err = parse_resource_gts(input).unwrap_err();
+            assert_eq!(
+                err,
+                GtsParseError::MissingResourceSegment {
+                    input: input.to_string()
+                }
+            );
+            assert!(err.to_string().contains(&format!("'{input}'")));
+        }
+    }
+
+    #[test]
+    fn test_invalid_uuid() {
+        let input = "gts.x.core.oagw.route.v1~not-a-uuid";
+        let err = parse_resource_gts(input).unwrap_err();
+        assert!(matches!(err, GtsParseError::InvalidUuid { .. }), "{err:?}");
+        assert!(
+            err.to_string()
+                .contains("'gts.x.core.oagw.route.v1~not-a-uuid'")
+        );
+    }
+
+    #[test]
+    fn test_resource_gts_formats_canonical_string() {
+        let id = Uuid::parse_str(ID).unwrap();
+        let gts = ResourceGts::new("x.core.oagw", "route", id).unwrap();
+        assert_eq!(gts.to_string(), format_route_gts(id));
+        assert_eq!(gts.schema(), ROUTE_SCHEMA);
+        assert_eq!(
+            ResourceGts::new("x.core.oagw", "upstream", id)
+                .unwrap()
+                .to_string(),
+            format_upstream_gts(id)
+        );
+    }
+
+    #[test]
+    fn test_resource_gts_round_trip() {
+        for s in [
+            format!("gts.x.core.oagw.route.v1~{ID}"),
+            format!("gts.x.core.oagw.upstream.v1~{ID}"),
+            format!("gts.acme.billing.invoices.invoice_line.v2~{ID}"),
+        ] {
+            let parsed = ResourceGts::parse(&s).unwrap();
+            assert_eq!(parsed.to_string(), s);
+            assert_eq!(ResourceGts::parse(&parsed.to_string()).unwrap(), parsed);
+        }
+    }
+
+    #[test]
+    fn test_resource_gts_rejects_invalid_segments() {
+        let id = Uuid::parse_str(ID).unwrap();
+        for (namespace, resource_type) in [
+            ("x.core.oagw", ""),
+            ("x..oagw", "route"),
+            ("x.core", "route"),
+            ("x.core.oagw", "Route"),
+            ("x.core.oagw", "route-v2"),
+            ("x.core.oagw", "2route"),
+            ("x.core.oagw", "route.v1"),
+        ] {
+            let err = ResourceGts::new(namespace, resource_type, id).unwrap_err();
+            assert!(
+                matches!(err, GtsParseError::InvalidSegment { .. }),
+                "{namespace}/{resource_type}: {err:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_error_maps_to_validation() {
+        let input = "gts.x.core.oagw.route.v1~";
+        let err = DomainError::from(parse_resource_gts(input).unwrap_err());
+        assert!(
+            matches!(err, DomainError::Validation { ref instance, .. } if instance == input),
+            "{err:?}"
+        );
+    }
 }
@@ -0,0 +1,215 @@
+//! Client-supplied idempotency keys for upstream creation.
+//!
+//! A create request carrying a key claims it for the caller's tenant; once
+//! the create succeeds the key remembers the new upstream ID until the TTL
+//! elapses, so a retried request can be answered with the same upstream.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use modkit_macros::domain_model;
+use parking_lot::Mutex;
+use uuid::Uuid;
+
+use super::error::DomainError;
+
+/// Longest accepted idempotency key, in bytes.
+const MAX_KEY_LEN: usize = 255;
+
+#[domain_model]
+#[derive(Debug, Clone, Copy)]
+enum Slot {
+    /// A create with this key is in flight.
+    Pending,
+    /// A create with this key succeeded and produced this upstream.
+    Created(Uuid),
+}
+
+#[domain_model]
+#[derive(Debug)]
+struct Entry {
+    slot: Slot,
+    expires_at: Instant,
+}
+
+/// Upstream IDs created under an idempotency key, per tenant.
+///
+/// Keys live in process memory: they do not survive a restart and are not
+/// shared between gateway instances.
+#[domain_model]
+pub(crate) struct IdempotencyKeys {
+    ttl: Duration,
+    entries: Mutex<HashMap<(Uuid, String), Entry>>,
+}
+
+/// Result of [`IdempotencyKeys::claim`].
+#[domain_model]
+pub(crate) enum Claim<'a> {
+    /// The key is unused; the caller owns it until the guard is completed
+    /// or dropped.
+    New(ClaimGuard<'a>),
+    /// An earlier request with the key created this upstream.
+    Created(Uuid),
+    /// An earlier request with the key is still in flight.
+    Pending,
+}
+
+/// Ownership of a freshly claimed key. Dropping it without calling
+/// [`ClaimGuard::complete`] releases the key so the request can be retried.
+#[domain_model]
+pub(crate) struct ClaimGuard<'a> {
+    keys: &'a IdempotencyKeys,
+    tenant_id: Uuid,
+    key: String,
+    completed: bool,
+}
+
+impl IdempotencyKeys {
+    #[must_use]
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Claim `key` for `tenant_id`, dropping expired keys first.
+    pub(crate) fn claim(&self, tenant_id: Uuid, key: &str) -> Claim<'_> {
+        let now = Instant::now();
+        let mut entries = self.entries.lock();
+        entries.retain(|_, e| e.expires_at > now);
+
+        let map_key = (tenant_id, key.to_owned());
+        if let Some(entry) = entries.get(&map_key) {
+            return match entry.slot {
+                Slot::Pending => Claim::Pending,
+                Slot::Created(id) => Claim::Created(id),
+            };
+        }
+        entries.insert(
+            map_key,
+            Entry {
+                slot: Slot::Pending,
+                expires_at: now + self.ttl,
+            },
+        );
+        Claim::New(ClaimGuard {
+            keys: self,
+            tenant_id,
+            key: key.to_owned(),
+            completed: false,
+        })
+    }
+
+    /// Forget `key` for `tenant_id`, e.g. because its upstream was deleted.
+    pub(crate) fn release(&self, tenant_id: Uuid, key: &str) {
+        self.entries.lock().remove(&(tenant_id, key.to_owned()));
+    }
+}
+
+impl ClaimGuard<'_> {
+    /// Record `upstream_id` as the result for the claimed key. The TTL
+    /// starts over from now.
+    pub(crate) fn complete(mut self, upstream_id: Uuid) {
+        self.completed = true;
+        self.keys.entries.lock().insert(
+            (self.tenant_id, std::mem::take(&mut self.key)),
+            Entry {
+                slot: Slot::Created(upstream_id),
+                expires_at: Instant::now() + self.keys.ttl,
+            },
+        );
+    }
+}
+
+impl Drop for ClaimGuard<'_> {
+    fn drop(&mut self) {
+        if !self.completed {
+            self.keys.release(self.tenant_id, &self.key);
+        }
+    }
+}
+
+/// Reject empty, oversized or non-printable idempotency keys.
+pub(crate) fn validate_idempotency_key(key: &str) -> Result<(), DomainError> {
+    if key.is_empty() {
+        return Err(DomainError::validation("idempotency_key must not be empty"));
+    }
+    if key.len() > MAX_KEY_LEN {
+        return Err(DomainError::validation(format!(
+            "idempotency_key must be at most {MAX_KEY_LEN} bytes"
+        )));
+    }
+    if !key.bytes().all(|b| b.is_ascii_graphic()) {
+        return Err(DomainError::validation(
+            "idempotency_key must contain only printable ASCII characters without spaces",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys() -> IdempotencyKeys {
+        IdempotencyKeys::new(Duration::from_secs(60))
+    }
+
+    #[test]
+    fn completed_key_returns_created_upstream() {
+        let keys = keys();
+        let tenant = Uuid::new_v4();
+        let id = Uuid::new_v4();
+
+        let Claim::New(guard) = keys.claim(tenant, "k1") else {
+            panic!("first claim must be new");
+        };
+        assert!(matches!(keys.claim(tenant, "k1"), Claim::Pending));
+        guard.complete(id);
+
+        assert!(matches!(keys.claim(tenant, "k1"), Claim::Created(got) if got == id));
+    }
+
+    #[test]
+    fn dropped_guard_releases_key() {
+        let keys = keys();
+        let tenant = Uuid::new_v4();
+
+        drop(keys.claim(tenant, "k1"));
+
+        assert!(matches!(keys.claim(tenant, "k1"), Claim::New(_)));
+    }
+
+    #[test]
+    fn keys_are_scoped_per_tenant() {
+        let keys = keys();
+        let Claim::New(guard) = keys.claim(Uuid::new_v4(), "k1") else {
+            panic!("first claim must be new");
+        };
+        guard.complete(Uuid::new_v4());
+
+        assert!(matches!(keys.claim(Uuid::new_v4(), "k1"), Claim::New(_)));
+    }
+
+    #[test]
+    fn expired_keys_are_forgotten() {
+        let keys = IdempotencyKeys::new(Duration::ZERO);
+        let tenant = Uuid::new_v4();
+        let Claim::New(guard) = keys.claim(tenant, "k1") else {
+            panic!("first claim must be new");
+        };
+        guard.complete(Uuid::new_v4());
+
+        assert!(matches!(keys.claim(tenant, "k1"), Claim::New(_)));
+    }
+
+    #[test]
+    fn validate_rejects_empty_long_and_non_printable_keys() {
+        assert!(validate_idempotency_key("req-123_abc").is_ok());
+        assert!(validate_idempotency_key("").is_err());
+        assert!(validate_idempotency_key(&"k".repeat(MAX_KEY_LEN + 1)).is_err());
+        assert!(validate_idempotency_key("has space").is_err());
+        assert!(validate_idempotency_key("tab\there").is_err());
+    }
+}
@@ -0,0 +1,69 @@
+//! Data-plane metrics port.
+//!
+//! The proxy pipeline reports through [`Metrics`] using the metric names
+//! below; backends decide how (or whether) to export them.
+
+/// Requests handled by the proxy, labelled by [`LABEL_UPSTREAM`].
+pub const PROXY_REQUESTS_TOTAL: &str = "oagw_proxy_requests_total";
+/// Proxy responses, labelled by [`LABEL_UPSTREAM`] and [`LABEL_STATUS_CLASS`].
+pub const PROXY_RESPONSES_TOTAL: &str = "oagw_proxy_responses_total";
+/// Time until response headers are available, labelled by [`LABEL_UPSTREAM`].
+pub const PROXY_REQUEST_DURATION_SECONDS: &str = "oagw_proxy_request_duration_seconds";
+/// Requests rejected by a rate limit, labelled by [`LABEL_UPSTREAM`].
+pub const PROXY_RATE_LIMITED_TOTAL: &str = "oagw_proxy_rate_limited_total";
+/// Request body bytes sent upstream, labelled by [`LABEL_UPSTREAM`].
+/// Streaming bodies are counted chunk by chunk as they are forwarded.
+pub const PROXY_REQUEST_BODY_BYTES_TOTAL: &str = "oagw_proxy_request_body_bytes_total";
+/// Response body bytes received from upstream and passed to the caller,
+/// labelled by [`LABEL_UPSTREAM`]. Counted chunk by chunk as the body is read.
+pub const PROXY_RESPONSE_BODY_BYTES_TOTAL: &str = "oagw_proxy_response_body_bytes_total";
+
+/// Upstream id. Aliases are only unique within a tenant, so the id keeps
+/// series of different tenants apart. [`UNRESOLVED_UPSTREAM`] when the
+/// request failed before an upstream was resolved.
+pub const LABEL_UPSTREAM: &str = "upstream";
+/// Status class of the response: `2xx`, `4xx`, `5xx`, ...
+pub const LABEL_STATUS_CLASS: &str = "status_class";
+
+/// Upstream label value for requests that never resolved an upstream.
+/// Keeps caller-supplied aliases out of label values.
+pub const UNRESOLVED_UPSTREAM: &str = "-";
+
+/// Sink for data-plane counters and histograms.
+///
+/// Labels are `(name, value)` pairs. Implementations must be cheap and must
+/// not fail: recording happens inline on the proxy hot path.
+pub trait Metrics: Send + Sync {
+    /// Add one to the counter `name`.
+    fn increment_counter(&self, name: &'static str, labels: &[(&'static str, &str)]);
+
+    /// Add `value` to the counter `name`.
+    fn add_to_counter(&self, name: &'static str, labels: &[(&'static str, &str)], value: u64);
+
+    /// Record one observation of `value` in the histogram `name`.
+    fn record_histogram(&self, name: &'static str, labels: &[(&'static str, &str)], value: f64);
+}
+
+/// [`Metrics`] that discards everything. The default when no backend is set.
+pub struct NoopMetrics;
+
+impl Metrics for NoopMetrics {
+    fn increment_counter(&self, _name: &'static str, _labels: &[(&'static str, &str)]) {}
+
+    fn add_to_counter(&self, _name: &'static str, _labels: &[(&'static str, &str)], _value: u64) {}
+
+    fn record_histogram(&self, _name: &'static str, _labels: &[(&'static str, &str)], _value: f64) {
+    }
+}
+
+/// Status class label value for an HTTP status code.
+#[must_use]
+pub fn status_class(status: u16) -> &'static str {
+    match status {
+        100..=199 => "1xx",
+        200..=299 => "2xx",
+        300..=399 => "3xx",
+        400..=499 => "4xx",
+        _ => "5xx",
+    }
+}
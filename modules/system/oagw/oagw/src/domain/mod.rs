@@ -1,6 +1,8 @@
 pub(crate) mod cors;
 pub(crate) mod error;
 pub(crate) mod gts_helpers;
+pub(crate) mod idempotency;
+pub mod metrics;
 pub(crate) mod model;
 pub(crate) mod plugin;
 pub(crate) mod rate_limit;
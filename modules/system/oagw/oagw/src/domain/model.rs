@@ -31,6 +31,18 @@ pub enum Scheme {
     Grpc,
 }
 
+impl Scheme {
+    /// Well-known port for the scheme, used when an endpoint omits `port`:
+    /// 80 for HTTP, 443 for HTTPS / WSS / WT / gRPC.
+    #[must_use]
+    pub fn default_port(self) -> u16 {
+        match self {
+            Self::Http => 80,
+            Self::Https | Self::Wss | Self::Wt | Self::Grpc => 443,
+        }
+    }
+}
+
 #[domain_model]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Endpoint {
@@ -47,10 +59,7 @@ impl Endpoint {
     /// - HTTPS / WSS / WT / gRPC: 443
     #[must_use]
     pub fn is_standard_port(&self) -> bool {
-        match self.scheme {
-            Scheme::Http => self.port == 80,
-            Scheme::Https | Scheme::Wss | Scheme::Wt | Scheme::Grpc => self.port == 443,
-        }
+        self.port == self.scheme.default_port()
     }
 
     /// The normalized host: brackets stripped (IPv6), lowercased, trailing dots stripped.
@@ -151,7 +160,21 @@ pub struct RateLimitConfig {
     pub burst: Option<BurstConfig>,
     pub scope: RateLimitScope,
     pub strategy: RateLimitStrategy,
-    pub cost: u32,
+    pub cost: CostStrategy,
+}
+
+#[domain_model]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CostStrategy {
+    Fixed(u32),
+    PerKilobyte,
+    HeaderValue(String),
+}
+
+impl Default for CostStrategy {
+    fn default() -> Self {
+        Self::Fixed(1)
+    }
 }
 
 #[domain_model]
@@ -177,6 +200,7 @@ pub enum Window {
     Minute,
     Hour,
     Day,
+    Seconds(u32),
 }
 
 #[domain_model]
@@ -232,6 +256,37 @@ pub struct CorsConfig {
     pub allow_credentials: bool,
 }
 
+/// Per-route opt-in for resuming dropped upstream SSE streams.
+#[domain_model]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SseReconnectConfig {
+    pub max_retries: u32,
+}
+
+/// Per-route opt-in for sharing one upstream call between identical
+/// concurrent GETs; the outbound `Range`/`If-Range` and `vary_headers`
+/// values are part of the identity.
+#[domain_model]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GetCoalescingConfig {
+    pub vary_headers: Vec<String>,
+}
+
+/// Per-route opt-in for gzip-compressing buffered request bodies larger
+/// than `min_bytes`.
+#[domain_model]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestCompressionConfig {
+    pub min_bytes: u64,
+}
+
+/// Per-route opt-in for serving stored GET responses for `ttl_secs`.
+#[domain_model]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResponseCacheConfig {
+    pub ttl_secs: u64,
+}
+
 // ---------------------------------------------------------------------------
 // PluginBinding / PluginsConfig
 // ---------------------------------------------------------------------------
@@ -309,6 +364,10 @@ pub struct Route {
     pub plugins: Option<PluginsConfig>,
     pub rate_limit: Option<RateLimitConfig>,
     pub cors: Option<CorsConfig>,
+    pub sse_reconnect: Option<SseReconnectConfig>,
+    pub get_coalescing: Option<GetCoalescingConfig>,
+    pub request_compression: Option<RequestCompressionConfig>,
+    pub response_cache: Option<ResponseCacheConfig>,
     pub tags: Vec<String>,
     pub priority: i32,
     pub enabled: bool,
@@ -331,6 +390,56 @@ pub struct Upstream {
     pub tags: Vec<String>,
 }
 
+/// Tenant-wide rate limit, drawn from one bucket shared by every upstream
+/// and route the tenant proxies through.
+#[domain_model]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TenantRateLimit {
+    pub tenant_id: Uuid,
+    /// Applied to requests whose upstream and route set no rate limit.
+    pub config: RateLimitConfig,
+    /// When true, the tenant bucket also caps requests that are already
+    /// limited per upstream or route.
+    pub aggregate: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Endpoint health
+// ---------------------------------------------------------------------------
+
+#[domain_model]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthStatus {
+    Healthy,
+    Unhealthy,
+    /// Not health-checked yet.
+    Unknown,
+}
+
+/// Result of the latest active health check of one upstream endpoint.
+#[domain_model]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndpointHealth {
+    pub endpoint: Endpoint,
+    pub status: HealthStatus,
+    pub last_checked_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Failed checks since the last successful one.
+    pub consecutive_failures: u32,
+}
+
+impl EndpointHealth {
+    /// Health of an endpoint that has not been checked yet.
+    #[must_use]
+    pub fn unknown(endpoint: Endpoint) -> Self {
+        Self {
+            endpoint,
+            status: HealthStatus::Unknown,
+            last_checked_at: None,
+            consecutive_failures: 0,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Pagination
 // ---------------------------------------------------------------------------
@@ -365,6 +474,9 @@ pub struct CreateUpstreamRequest {
     pub cors: Option<CorsConfig>,
     pub tags: Vec<String>,
     pub enabled: bool,
+    /// Repeats with the same key (per tenant) return the first result
+    /// instead of creating another upstream.
+    pub idempotency_key: Option<String>,
 }
 
 #[domain_model]
@@ -390,6 +502,10 @@ pub struct CreateRouteRequest {
     pub plugins: Option<PluginsConfig>,
     pub rate_limit: Option<RateLimitConfig>,
     pub cors: Option<CorsConfig>,
+    pub sse_reconnect: Option<SseReconnectConfig>,
+    pub get_coalescing: Option<GetCoalescingConfig>,
+    pub request_compression: Option<RequestCompressionConfig>,
+    pub response_cache: Option<ResponseCacheConfig>,
     pub tags: Vec<String>,
     pub priority: i32,
     pub enabled: bool,
@@ -402,6 +518,10 @@ pub struct UpdateRouteRequest {
     pub plugins: Option<PluginsConfig>,
     pub rate_limit: Option<RateLimitConfig>,
     pub cors: Option<CorsConfig>,
+    pub sse_reconnect: Option<SseReconnectConfig>,
+    pub get_coalescing: Option<GetCoalescingConfig>,
+    pub request_compression: Option<RequestCompressionConfig>,
+    pub response_cache: Option<ResponseCacheConfig>,
     pub tags: Vec<String>,
     pub priority: i32,
     pub enabled: bool,
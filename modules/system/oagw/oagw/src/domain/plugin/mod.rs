@@ -70,6 +70,13 @@ pub enum GuardDecision {
         /// Human-readable explanation of the rejection.
         detail: String,
     },
+    /// Reject the request with 415 as a gateway policy violation. Unlike
+    /// [`GuardDecision::Reject`], the error is reported with
+    /// `ErrorSource::Gateway` rather than attributed to the plugin.
+    UnsupportedMediaType {
+        /// Human-readable explanation of the rejection.
+        detail: String,
+    },
 }
 
 /// Context passed to a guard plugin for validation.
@@ -92,6 +99,8 @@ pub struct GuardContext {
     pub status: Option<u16>,
     /// Request headers (request phase) or response headers (response phase).
     pub headers: Vec<(String, String)>,
+    /// Whether the request carries a body. Always `false` during response phase.
+    pub has_body: bool,
     /// Plugin-specific configuration key/value pairs
     /// (from the plugin binding on the upstream or route).
     pub config: HashMap<String, String>,
@@ -176,7 +185,9 @@ pub struct TransformErrorContext {
 /// Trait for transform plugins that mutate request/response/error data.
 ///
 /// Implementations modify context in-place. [`PluginError`] is reserved for
-/// plugin infrastructure failures (invalid config, internal errors).
+/// plugin infrastructure failures (invalid config, internal errors), which are
+/// logged and skipped; [`PluginError::Rejected`] from `on_request` instead
+/// aborts the request, attributed to the plugin.
 ///
 /// All methods default to no-op, so implementations only need to override the
 /// phases they participate in.
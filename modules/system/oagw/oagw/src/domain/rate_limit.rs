@@ -1,8 +1,9 @@
 use std::collections::HashSet;
+use std::sync::Arc;
 use std::time::Instant;
 
 use crate::domain::error::DomainError;
-use crate::domain::model::{RateLimitConfig, Window};
+use crate::domain::model::{CostStrategy, RateLimitConfig, Window};
 use dashmap::DashMap;
 use modkit_macros::domain_model;
 
@@ -21,10 +22,7 @@ struct TokenBucket {
 
 impl TokenBucket {
     fn new(config: &RateLimitConfig) -> Self {
-        let capacity = config
-            .burst
-            .as_ref()
-            .map_or(config.sustained.rate as f64, |b| b.capacity as f64);
+        let capacity = bucket_capacity(config) as f64;
         let window_secs = window_to_secs(&config.sustained.window);
         let refill_rate = config.sustained.rate as f64 / window_secs;
         Self {
@@ -52,6 +50,12 @@ impl TokenBucket {
         }
     }
 
+    /// Consume `cost` tokens unconditionally; the bucket may go negative.
+    fn charge(&mut self, cost: f64) {
+        self.refill();
+        self.tokens -= cost;
+    }
+
     fn retry_after_secs(&self, cost: f64) -> u64 {
         if self.refill_rate <= 0.0 {
             return 60;
@@ -64,12 +68,125 @@ impl TokenBucket {
     }
 }
 
+fn bucket_capacity(config: &RateLimitConfig) -> u32 {
+    config
+        .burst
+        .as_ref()
+        .map_or(config.sustained.rate, |b| b.capacity)
+}
+
+/// Number of tokens a request consumes under `config.cost`.
+///
+/// `body_len` is the request body size in bytes (the declared
+/// `Content-Length` for streamed bodies, see [`DeferredCharges`] when there
+/// is none); `header` looks up a request header
+/// by name. Variable costs are clamped to `1..=capacity` so a single oversized
+/// or mislabelled request can never ask for more than a full bucket.
+/// A missing or unparsable cost header counts as 1.
+pub fn request_cost<'a>(
+    config: &RateLimitConfig,
+    body_len: u64,
+    header: impl FnOnce(&str) -> Option<&'a str>,
+) -> u32 {
+    let variable = match &config.cost {
+        CostStrategy::Fixed(n) => return *n,
+        CostStrategy::PerKilobyte => u32::try_from(body_len.div_ceil(1024)).unwrap_or(u32::MAX),
+        CostStrategy::HeaderValue(name) => header(name)
+            .and_then(|v| v.trim().parse::<u32>().ok())
+            .unwrap_or(1),
+    };
+    variable.clamp(1, bucket_capacity(config).max(1))
+}
+
+/// A rate-limit bucket a request was admitted against, with the cost charged.
+#[domain_model]
+#[derive(Debug, Clone)]
+pub struct Charge {
+    pub key: String,
+    pub config: RateLimitConfig,
+    pub cost: u32,
+}
+
+/// Per-kilobyte costs still owed by a request admitted before its body size
+/// was known (a streamed body without `Content-Length`).
+///
+/// Record the forwarded bytes with [`DeferredCharges::add_body_bytes`].
+/// Dropping the value charges each bucket the cost of those bytes minus what
+/// was charged on admission, so every exit path of the body forwarder
+/// settles. The buckets may go into debt, which delays later requests.
+#[domain_model]
+pub struct DeferredCharges {
+    limiter: Arc<RateLimiter>,
+    pending: Vec<Charge>,
+    body_len: u64,
+}
+
+impl DeferredCharges {
+    #[must_use]
+    pub fn new(limiter: Arc<RateLimiter>) -> Self {
+        Self {
+            limiter,
+            pending: Vec::new(),
+            body_len: 0,
+        }
+    }
+
+    /// Track `charge` if its cost depends on the body size.
+    pub fn track(&mut self, charge: &Charge) {
+        if charge.config.cost == CostStrategy::PerKilobyte {
+            self.pending.push(charge.clone());
+        }
+    }
+
+    pub fn add_body_bytes(&mut self, n: u64) {
+        self.body_len = self.body_len.saturating_add(n);
+    }
+}
+
+impl Drop for DeferredCharges {
+    fn drop(&mut self) {
+        for charge in &self.pending {
+            let owed =
+                request_cost(&charge.config, self.body_len, |_| None).saturating_sub(charge.cost);
+            if owed > 0 {
+                self.limiter.charge(&charge.key, &charge.config, owed);
+            }
+        }
+    }
+}
+
+/// Validate a rate limit configuration at creation/update time.
+///
+/// Returns `Err(DomainError::Validation)` if the configuration is invalid.
+pub fn validate_rate_limit_config(config: &RateLimitConfig) -> Result<(), DomainError> {
+    if config.sustained.window == Window::Seconds(0) {
+        return Err(DomainError::Validation {
+            detail: "rate limit window must be at least one second".into(),
+            instance: String::new(),
+        });
+    }
+    if let CostStrategy::HeaderValue(name) = &config.cost {
+        let is_token = !name.is_empty()
+            && name
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b));
+        if !is_token {
+            return Err(DomainError::Validation {
+                detail: format!("invalid rate limit cost header name '{name}'"),
+                instance: String::new(),
+            });
+        }
+    }
+    Ok(())
+}
+
 fn window_to_secs(window: &Window) -> f64 {
     match window {
         Window::Second => 1.0,
         Window::Minute => 60.0,
         Window::Hour => 3600.0,
         Window::Day => 86400.0,
+        Window::Seconds(n) => f64::from(*n),
     }
 }
 
@@ -95,7 +212,16 @@ impl RateLimiter {
         self.buckets.remove(key);
     }
 
-    /// Try to consume tokens for the given key.
+    /// Consume `cost` tokens for the given key without rejecting, for costs
+    /// only known after the request was admitted.
+    pub fn charge(&self, key: &str, config: &RateLimitConfig, cost: u32) {
+        self.buckets
+            .entry(key.to_string())
+            .or_insert_with(|| TokenBucket::new(config))
+            .charge(f64::from(cost));
+    }
+
+    /// Try to consume `cost` tokens for the given key.
     ///
     /// # Errors
     /// Returns `DomainError::RateLimitExceeded` with Retry-After seconds when exhausted.
@@ -103,9 +229,10 @@ impl RateLimiter {
         &self,
         key: &str,
         config: &RateLimitConfig,
+        cost: u32,
         instance_uri: &str,
     ) -> Result<(), DomainError> {
-        let cost = config.cost as f64;
+        let cost = cost as f64;
         let mut bucket = self
             .buckets
             .entry(key.to_string())
@@ -140,7 +267,7 @@ mod tests {
             burst: burst_capacity.map(|c| BurstConfig { capacity: c }),
             scope: RateLimitScope::Tenant,
             strategy: RateLimitStrategy::Reject,
-            cost: 1,
+            cost: CostStrategy::Fixed(1),
         }
     }
 
@@ -149,7 +276,7 @@ mod tests {
         let limiter = RateLimiter::new();
         let config = make_config(10, Window::Second, None);
         for _ in 0..10 {
-            assert!(limiter.try_consume("test", &config, "/test").is_ok());
+            assert!(limiter.try_consume("test", &config, 1, "/test").is_ok());
         }
     }
 
@@ -157,9 +284,11 @@ mod tests {
     fn denies_when_exhausted() {
         let limiter = RateLimiter::new();
         let config = make_config(2, Window::Second, None);
-        assert!(limiter.try_consume("test", &config, "/test").is_ok());
-        assert!(limiter.try_consume("test", &config, "/test").is_ok());
-        let err = limiter.try_consume("test", &config, "/test").unwrap_err();
+        assert!(limiter.try_consume("test", &config, 1, "/test").is_ok());
+        assert!(limiter.try_consume("test", &config, 1, "/test").is_ok());
+        let err = limiter
+            .try_consume("test", &config, 1, "/test")
+            .unwrap_err();
         assert!(matches!(err, DomainError::RateLimitExceeded { .. }));
     }
 
@@ -167,8 +296,8 @@ mod tests {
     fn retry_after_is_calculated() {
         let limiter = RateLimiter::new();
         let config = make_config(1, Window::Minute, None);
-        assert!(limiter.try_consume("test", &config, "/test").is_ok());
-        match limiter.try_consume("test", &config, "/test") {
+        assert!(limiter.try_consume("test", &config, 1, "/test").is_ok());
+        match limiter.try_consume("test", &config, 1, "/test") {
             Err(DomainError::RateLimitExceeded {
                 retry_after_secs, ..
             }) => {
@@ -185,28 +314,28 @@ mod tests {
         let limiter = RateLimiter::new();
         let config = make_config(1, Window::Second, Some(5));
         for _ in 0..5 {
-            assert!(limiter.try_consume("test", &config, "/test").is_ok());
+            assert!(limiter.try_consume("test", &config, 1, "/test").is_ok());
         }
-        assert!(limiter.try_consume("test", &config, "/test").is_err());
+        assert!(limiter.try_consume("test", &config, 1, "/test").is_err());
     }
 
     #[test]
     fn separate_keys_independent() {
         let limiter = RateLimiter::new();
         let config = make_config(1, Window::Second, None);
-        assert!(limiter.try_consume("key-a", &config, "/test").is_ok());
-        assert!(limiter.try_consume("key-b", &config, "/test").is_ok());
-        assert!(limiter.try_consume("key-a", &config, "/test").is_err());
-        assert!(limiter.try_consume("key-b", &config, "/test").is_err());
+        assert!(limiter.try_consume("key-a", &config, 1, "/test").is_ok());
+        assert!(limiter.try_consume("key-b", &config, 1, "/test").is_ok());
+        assert!(limiter.try_consume("key-a", &config, 1, "/test").is_err());
+        assert!(limiter.try_consume("key-b", &config, 1, "/test").is_err());
     }
 
     #[test]
     fn purge_removes_stale_entries() {
         let limiter = RateLimiter::new();
         let config = make_config(10, Window::Second, None);
-        limiter.try_consume("a", &config, "/test").unwrap();
-        limiter.try_consume("b", &config, "/test").unwrap();
-        limiter.try_consume("c", &config, "/test").unwrap();
+        limiter.try_consume("a", &config, 1, "/test").unwrap();
+        limiter.try_consume("b", &config, 1, "/test").unwrap();
+        limiter.try_consume("c", &config, 1, "/test").unwrap();
 
         let active: HashSet<String> = ["a", "c"].iter().map(|s| (*s).into()).collect();
         limiter.purge_keys(&active);
@@ -222,9 +351,11 @@ mod tests {
         let limiter = RateLimiter::new();
         let config = make_config(10, Window::Second, None);
         limiter
-            .try_consume("upstream:aaa", &config, "/test")
+            .try_consume("upstream:aaa", &config, 1, "/test")
+            .unwrap();
+        limiter
+            .try_consume("route:bbb", &config, 1, "/test")
             .unwrap();
-        limiter.try_consume("route:bbb", &config, "/test").unwrap();
 
         limiter.remove_key("upstream:aaa");
 
@@ -244,11 +375,164 @@ mod tests {
     fn purge_with_empty_set_removes_all() {
         let limiter = RateLimiter::new();
         let config = make_config(10, Window::Second, None);
-        limiter.try_consume("x", &config, "/test").unwrap();
-        limiter.try_consume("y", &config, "/test").unwrap();
+        limiter.try_consume("x", &config, 1, "/test").unwrap();
+        limiter.try_consume("y", &config, 1, "/test").unwrap();
 
         limiter.purge_keys(&HashSet::new());
 
         assert!(limiter.buckets.is_empty());
     }
+
+    #[test]
+    fn fixed_cost_is_not_clamped() {
+        let mut config = make_config(10, Window::Second, None);
+        config.cost = CostStrategy::Fixed(25);
+        assert_eq!(request_cost(&config, 0, |_| None), 25);
+    }
+
+    #[test]
+    fn per_kilobyte_cost_rounds_up_and_clamps() {
+        let mut config = make_config(100, Window::Second, None);
+        config.cost = CostStrategy::PerKilobyte;
+        assert_eq!(request_cost(&config, 0, |_| None), 1);
+        assert_eq!(request_cost(&config, 1, |_| None), 1);
+        assert_eq!(request_cost(&config, 1024, |_| None), 1);
+        assert_eq!(request_cost(&config, 1025, |_| None), 2);
+        // 10 MB would be 10240 tokens; capped at the bucket capacity.
+        assert_eq!(request_cost(&config, 10 * 1024 * 1024, |_| None), 100);
+    }
+
+    #[test]
+    fn header_value_cost_reads_named_header() {
+        let mut config = make_config(10, Window::Second, Some(50));
+        config.cost = CostStrategy::HeaderValue("x-token-count".into());
+        let lookup = |name: &str| (name == "x-token-count").then_some("7");
+        assert_eq!(request_cost(&config, 0, lookup), 7);
+        // Missing or garbage values fall back to 1; huge ones are clamped.
+        assert_eq!(request_cost(&config, 0, |_| None), 1);
+        assert_eq!(request_cost(&config, 0, |_| Some("lots")), 1);
+        assert_eq!(request_cost(&config, 0, |_| Some("0")), 1);
+        assert_eq!(request_cost(&config, 0, |_| Some("9999")), 50);
+    }
+
+    #[test]
+    fn custom_window_refills_over_its_length() {
+        let limiter = RateLimiter::new();
+        // 5 tokens per 10 seconds = one token every 2 seconds.
+        let config = make_config(5, Window::Seconds(10), None);
+        for _ in 0..5 {
+            limiter.try_consume("test", &config, 1, "/test").unwrap();
+        }
+        match limiter.try_consume("test", &config, 1, "/test") {
+            Err(DomainError::RateLimitExceeded {
+                retry_after_secs, ..
+            }) => assert_eq!(retry_after_secs, Some(2)),
+            other => panic!("expected RateLimitExceeded, got {other:?}"),
+        }
+
+        // Rewind the clock by one second: half a token, still exhausted.
+        let rewind = |secs: u64| {
+            let mut bucket = limiter.buckets.get_mut("test").unwrap();
+            bucket.last_refill -= std::time::Duration::from_secs(secs);
+        };
+        rewind(1);
+        assert!(limiter.try_consume("test", &config, 1, "/test").is_err());
+        // Another second completes the token.
+        rewind(1);
+        assert!(limiter.try_consume("test", &config, 1, "/test").is_ok());
+        assert!(limiter.try_consume("test", &config, 1, "/test").is_err());
+    }
+
+    #[test]
+    fn validate_rejects_zero_length_window() {
+        let config = make_config(5, Window::Seconds(0), None);
+        assert!(matches!(
+            validate_rate_limit_config(&config),
+            Err(DomainError::Validation { .. })
+        ));
+        let config = make_config(5, Window::Seconds(1), None);
+        assert!(validate_rate_limit_config(&config).is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_invalid_cost_header() {
+        let mut config = make_config(10, Window::Second, None);
+        config.cost = CostStrategy::HeaderValue("x-token-count".into());
+        assert!(validate_rate_limit_config(&config).is_ok());
+        for bad in ["", "x token", "x:cost"] {
+            config.cost = CostStrategy::HeaderValue(bad.into());
+            assert!(
+                matches!(
+                    validate_rate_limit_config(&config),
+                    Err(DomainError::Validation { .. })
+                ),
+                "{bad:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn large_body_consumes_more_tokens_than_small() {
+        let limiter = RateLimiter::new();
+        let mut config = make_config(10, Window::Minute, None);
+        config.cost = CostStrategy::PerKilobyte;
+
+        let small = request_cost(&config, 512, |_| None);
+        let large = request_cost(&config, 8 * 1024, |_| None);
+        assert!(large > small);
+
+        // Ten small requests fit in the bucket...
+        for _ in 0..10 {
+            limiter
+                .try_consume("small", &config, small, "/test")
+                .unwrap();
+        }
+        assert!(
+            limiter
+                .try_consume("small", &config, small, "/test")
+                .is_err()
+        );
+
+        // ...but only one large request does.
+        limiter
+            .try_consume("large", &config, large, "/test")
+            .unwrap();
+        assert!(
+            limiter
+                .try_consume("large", &config, large, "/test")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn deferred_charges_settle_the_streamed_body_size() {
+        let limiter = Arc::new(RateLimiter::new());
+        let mut config = make_config(10, Window::Minute, None);
+        config.cost = CostStrategy::PerKilobyte;
+
+        // Admitted at the minimum cost, as the size was unknown.
+        let cost = request_cost(&config, 0, |_| None);
+        limiter.try_consume("kb", &config, cost, "/test").unwrap();
+        let mut charges = DeferredCharges::new(limiter.clone());
+        charges.track(&Charge {
+            key: "kb".into(),
+            config: config.clone(),
+            cost,
+        });
+        charges.track(&Charge {
+            key: "fixed".into(),
+            config: make_config(10, Window::Minute, None),
+            cost: 1,
+        });
+        charges.add_body_bytes(6 * 1024);
+        charges.add_body_bytes(1024);
+        drop(charges);
+
+        // 7 tokens spent in total: 3 remain.
+        limiter.try_consume("kb", &config, 3, "/test").unwrap();
+        assert!(limiter.try_consume("kb", &config, 1, "/test").is_err());
+        // Fixed-cost buckets are not touched.
+        let fixed = make_config(10, Window::Minute, None);
+        limiter.try_consume("fixed", &fixed, 10, "/test").unwrap();
+    }
 }
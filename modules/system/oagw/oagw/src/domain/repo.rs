@@ -1,5 +1,8 @@
-use crate::domain::model::{ListQuery, Route, Upstream};
+use std::sync::Arc;
+
+use crate::domain::model::{ListQuery, Route, TenantRateLimit, Upstream};
 use async_trait::async_trait;
+use futures_util::future::BoxFuture;
 use modkit_macros::domain_model;
 use uuid::Uuid;
 
@@ -16,7 +19,6 @@ pub enum RepositoryError {
     #[error("conflict: {0}")]
     Conflict(String),
     #[error("internal: {0}")]
-    #[allow(dead_code)]
     Internal(String),
 }
 
@@ -91,3 +93,73 @@ pub trait RouteRepository: Send + Sync {
         upstream_id: Uuid,
     ) -> Result<u64, RepositoryError>;
 }
+
+/// Repository trait for tenant-level rate limits (at most one per tenant).
+#[async_trait]
+pub trait TenantRateLimitRepository: Send + Sync {
+    /// Get the rate limit of a tenant, if one is set.
+    async fn get(&self, tenant_id: Uuid) -> Result<Option<TenantRateLimit>, RepositoryError>;
+
+    /// Insert or replace the rate limit of `limit.tenant_id`.
+    async fn upsert(&self, limit: TenantRateLimit) -> Result<TenantRateLimit, RepositoryError>;
+
+    /// Remove the rate limit of a tenant. Returns NotFound if none is set.
+    async fn delete(&self, tenant_id: Uuid) -> Result<(), RepositoryError>;
+}
+
+// ---------------------------------------------------------------------------
+// Transactions
+// ---------------------------------------------------------------------------
+
+/// Upstream and route repositories bound to one open transaction.
+pub trait RepositoryTx: Send + Sync {
+    fn upstreams(&self) -> &dyn UpstreamRepository;
+    fn routes(&self) -> &dyn RouteRepository;
+}
+
+/// Unit of work run by [`TransactionRunner::run`].
+pub type TxWork = Box<
+    dyn for<'t> FnOnce(&'t dyn RepositoryTx) -> BoxFuture<'t, Result<(), RepositoryError>> + Send,
+>;
+
+/// Runs operations that span several repositories atomically.
+#[async_trait]
+pub trait TransactionRunner: Send + Sync {
+    /// Run `work` in a transaction. Its writes are committed if it returns
+    /// `Ok` and rolled back if it returns an error, which is passed through.
+    async fn run(&self, work: TxWork) -> Result<(), RepositoryError>;
+}
+
+impl dyn TransactionRunner {
+    /// Run `f` in a transaction and return its result.
+    ///
+    /// ```ignore
+    /// let deleted = transactions
+    ///     .with_transaction(move |tx| Box::pin(async move {
+    ///         let n = tx.routes().delete_by_upstream(tenant_id, id).await?;
+    ///         tx.upstreams().delete(tenant_id, id).await?;
+    ///         Ok(n)
+    ///     }))
+    ///     .await?;
+    /// ```
+    pub async fn with_transaction<T, F>(&self, f: F) -> Result<T, RepositoryError>
+    where
+        T: Send + 'static,
+        F: for<'t> FnOnce(&'t dyn RepositoryTx) -> BoxFuture<'t, Result<T, RepositoryError>>
+            + Send
+            + 'static,
+    {
+        let out = Arc::new(parking_lot::Mutex::new(None));
+        let slot = Arc::clone(&out);
+        self.run(Box::new(move |tx| {
+            let work = f(tx);
+            Box::pin(async move {
+                *slot.lock() = Some(work.await?);
+                Ok(())
+            })
+        }))
+        .await?;
+        let value = out.lock().take();
+        value.ok_or_else(|| RepositoryError::Internal("transaction produced no result".into()))
+    }
+}
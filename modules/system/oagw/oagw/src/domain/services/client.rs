@@ -188,16 +188,13 @@ impl ServiceGatewayClientV1 for ServiceGatewayClientV1Facade {
 // DomainError → ServiceGatewayError
 // ---------------------------------------------------------------------------
 
-fn domain_err_to_sdk(err: DomainError) -> ServiceGatewayError {
+pub(crate) fn domain_err_to_sdk(err: DomainError) -> ServiceGatewayError {
     match err {
         DomainError::NotFound { entity, id } => ServiceGatewayError::NotFound {
             entity: entity.to_string(),
             instance: format!("{entity}/{id}"),
         },
-        DomainError::Conflict { detail } => ServiceGatewayError::ValidationError {
-            detail,
-            instance: String::new(),
-        },
+        DomainError::Conflict { detail } => ServiceGatewayError::Conflict { detail },
         DomainError::Validation { detail, instance } => {
             ServiceGatewayError::ValidationError { detail, instance }
         }
@@ -205,10 +202,7 @@ fn domain_err_to_sdk(err: DomainError) -> ServiceGatewayError {
             detail: format!("upstream '{alias}' is disabled"),
             instance: String::new(),
         },
-        DomainError::Internal { message } => ServiceGatewayError::DownstreamError {
-            detail: message,
-            instance: String::new(),
-        },
+        DomainError::Internal { message } => ServiceGatewayError::Internal { detail: message },
         DomainError::MissingTargetHost { instance } => {
             ServiceGatewayError::MissingTargetHost { instance }
         }
@@ -224,6 +218,9 @@ fn domain_err_to_sdk(err: DomainError) -> ServiceGatewayError {
         DomainError::PayloadTooLarge { detail, instance } => {
             ServiceGatewayError::PayloadTooLarge { detail, instance }
         }
+        DomainError::UnsupportedMediaType { detail, instance } => {
+            ServiceGatewayError::UnsupportedMediaType { detail, instance }
+        }
         DomainError::RateLimitExceeded {
             detail,
             instance,
@@ -253,11 +250,13 @@ fn domain_err_to_sdk(err: DomainError) -> ServiceGatewayError {
             error_code,
             detail,
             instance,
+            plugin_id,
         } => ServiceGatewayError::GuardRejected {
             status,
             error_code,
             detail,
             instance,
+            plugin_id,
         },
         DomainError::CorsOriginNotAllowed {
             origin, instance, ..
@@ -284,6 +283,10 @@ fn domain_err_to_sdk(err: DomainError) -> ServiceGatewayError {
         DomainError::PluginNotFound { detail } => ServiceGatewayError::PluginNotFound { detail },
         DomainError::PluginInUse { detail } => ServiceGatewayError::PluginInUse { detail },
         DomainError::Forbidden { detail } => ServiceGatewayError::Forbidden { detail },
+        DomainError::PluginFailed { plugin_id, error } => ServiceGatewayError::PluginFailed {
+            plugin_id,
+            error: Box::new(domain_err_to_sdk(*error)),
+        },
     }
 }
 
@@ -305,6 +308,7 @@ fn sdk_create_upstream_to_domain(
         cors: req.cors().cloned().map(cors_config_to_domain),
         tags: req.tags().to_vec(),
         enabled: req.enabled(),
+        idempotency_key: req.idempotency_key().map(|s| s.to_string()),
     }
 }
 
@@ -332,6 +336,12 @@ fn sdk_create_route_to_domain(req: oagw_sdk::CreateRouteRequest) -> model::Creat
         plugins: req.plugins().cloned().map(plugins_config_to_domain),
         rate_limit: req.rate_limit().cloned().map(rate_limit_config_to_domain),
         cors: req.cors().cloned().map(cors_config_to_domain),
+        sse_reconnect: req.sse_reconnect().map(sse_reconnect_config_to_domain),
+        get_coalescing: req.get_coalescing().map(get_coalescing_config_to_domain),
+        request_compression: req
+            .request_compression()
+            .map(request_compression_config_to_domain),
+        response_cache: req.response_cache().map(response_cache_config_to_domain),
         tags: req.tags().to_vec(),
         priority: req.priority(),
         enabled: req.enabled(),
@@ -344,6 +354,12 @@ fn sdk_update_route_to_domain(req: oagw_sdk::UpdateRouteRequest) -> model::Updat
         plugins: req.plugins().cloned().map(plugins_config_to_domain),
         rate_limit: req.rate_limit().cloned().map(rate_limit_config_to_domain),
         cors: req.cors().cloned().map(cors_config_to_domain),
+        sse_reconnect: req.sse_reconnect().map(sse_reconnect_config_to_domain),
+        get_coalescing: req.get_coalescing().map(get_coalescing_config_to_domain),
+        request_compression: req
+            .request_compression()
+            .map(request_compression_config_to_domain),
+        response_cache: req.response_cache().map(response_cache_config_to_domain),
         tags: req.tags().to_vec(),
         priority: req.priority(),
         enabled: req.enabled(),
@@ -433,6 +449,7 @@ fn window_to_domain(v: oagw_sdk::Window) -> model::Window {
         oagw_sdk::Window::Minute => model::Window::Minute,
         oagw_sdk::Window::Hour => model::Window::Hour,
         oagw_sdk::Window::Day => model::Window::Day,
+        oagw_sdk::Window::Seconds(n) => model::Window::Seconds(n),
     }
 }
 
@@ -462,7 +479,11 @@ fn rate_limit_config_to_domain(v: oagw_sdk::RateLimitConfig) -> model::RateLimit
             oagw_sdk::RateLimitStrategy::Queue => model::RateLimitStrategy::Queue,
             oagw_sdk::RateLimitStrategy::Degrade => model::RateLimitStrategy::Degrade,
         },
-        cost: v.cost,
+        cost: match v.cost {
+            oagw_sdk::CostStrategy::Fixed(n) => model::CostStrategy::Fixed(n),
+            oagw_sdk::CostStrategy::PerKilobyte => model::CostStrategy::PerKilobyte,
+            oagw_sdk::CostStrategy::HeaderValue(h) => model::CostStrategy::HeaderValue(h),
+        },
     }
 }
 
@@ -507,6 +528,36 @@ fn cors_config_to_domain(v: oagw_sdk::CorsConfig) -> model::CorsConfig {
     }
 }
 
+fn sse_reconnect_config_to_domain(v: &oagw_sdk::SseReconnectConfig) -> model::SseReconnectConfig {
+    model::SseReconnectConfig {
+        max_retries: v.max_retries,
+    }
+}
+
+fn get_coalescing_config_to_domain(
+    v: &oagw_sdk::GetCoalescingConfig,
+) -> model::GetCoalescingConfig {
+    model::GetCoalescingConfig {
+        vary_headers: v.vary_headers.clone(),
+    }
+}
+
+fn request_compression_config_to_domain(
+    v: &oagw_sdk::RequestCompressionConfig,
+) -> model::RequestCompressionConfig {
+    model::RequestCompressionConfig {
+        min_bytes: v.min_bytes,
+    }
+}
+
+fn response_cache_config_to_domain(
+    v: &oagw_sdk::ResponseCacheConfig,
+) -> model::ResponseCacheConfig {
+    model::ResponseCacheConfig {
+        ttl_secs: v.ttl_secs,
+    }
+}
+
 fn http_method_to_domain(v: oagw_sdk::HttpMethod) -> model::HttpMethod {
     match v {
         oagw_sdk::HttpMethod::Get => model::HttpMethod::Get,
@@ -667,6 +718,10 @@ fn route_to_sdk(r: model::Route) -> oagw_sdk::Route {
         }),
         rate_limit: r.rate_limit.map(rate_limit_config_to_sdk),
         cors: r.cors.map(cors_config_to_sdk),
+        sse_reconnect: r.sse_reconnect.map(sse_reconnect_config_to_sdk),
+        get_coalescing: r.get_coalescing.map(get_coalescing_config_to_sdk),
+        request_compression: r.request_compression.map(request_compression_config_to_sdk),
+        response_cache: r.response_cache.map(response_cache_config_to_sdk),
         tags: r.tags,
         priority: r.priority,
         enabled: r.enabled,
@@ -700,6 +755,32 @@ fn cors_config_to_sdk(v: model::CorsConfig) -> oagw_sdk::CorsConfig {
     }
 }
 
+fn sse_reconnect_config_to_sdk(v: model::SseReconnectConfig) -> oagw_sdk::SseReconnectConfig {
+    oagw_sdk::SseReconnectConfig {
+        max_retries: v.max_retries,
+    }
+}
+
+fn get_coalescing_config_to_sdk(v: model::GetCoalescingConfig) -> oagw_sdk::GetCoalescingConfig {
+    oagw_sdk::GetCoalescingConfig {
+        vary_headers: v.vary_headers,
+    }
+}
+
+fn request_compression_config_to_sdk(
+    v: model::RequestCompressionConfig,
+) -> oagw_sdk::RequestCompressionConfig {
+    oagw_sdk::RequestCompressionConfig {
+        min_bytes: v.min_bytes,
+    }
+}
+
+fn response_cache_config_to_sdk(v: model::ResponseCacheConfig) -> oagw_sdk::ResponseCacheConfig {
+    oagw_sdk::ResponseCacheConfig {
+        ttl_secs: v.ttl_secs,
+    }
+}
+
 fn rate_limit_config_to_sdk(v: model::RateLimitConfig) -> oagw_sdk::RateLimitConfig {
     oagw_sdk::RateLimitConfig {
         sharing: sharing_mode_to_sdk(v.sharing),
@@ -714,6 +795,7 @@ fn rate_limit_config_to_sdk(v: model::RateLimitConfig) -> oagw_sdk::RateLimitCon
                 model::Window::Minute => oagw_sdk::Window::Minute,
                 model::Window::Hour => oagw_sdk::Window::Hour,
                 model::Window::Day => oagw_sdk::Window::Day,
+                model::Window::Seconds(n) => oagw_sdk::Window::Seconds(n),
             },
         },
         burst: v.burst.map(|b| oagw_sdk::BurstConfig {
@@ -731,7 +813,11 @@ fn rate_limit_config_to_sdk(v: model::RateLimitConfig) -> oagw_sdk::RateLimitCon
             model::RateLimitStrategy::Queue => oagw_sdk::RateLimitStrategy::Queue,
             model::RateLimitStrategy::Degrade => oagw_sdk::RateLimitStrategy::Degrade,
         },
-        cost: v.cost,
+        cost: match v.cost {
+            model::CostStrategy::Fixed(n) => oagw_sdk::CostStrategy::Fixed(n),
+            model::CostStrategy::PerKilobyte => oagw_sdk::CostStrategy::PerKilobyte,
+            model::CostStrategy::HeaderValue(h) => oagw_sdk::CostStrategy::HeaderValue(h),
+        },
     }
 }
 
@@ -740,6 +826,128 @@ mod tests {
     use super::*;
     use std::collections::HashMap;
 
+    /// One value of every `DomainError` variant.
+    fn every_domain_error() -> Vec<DomainError> {
+        let detail = || "test".to_string();
+        let instance = || "/test".to_string();
+        vec![
+            DomainError::NotFound {
+                entity: "upstream",
+                id: Uuid::nil(),
+            },
+            DomainError::Conflict { detail: detail() },
+            DomainError::Validation {
+                detail: detail(),
+                instance: instance(),
+            },
+            DomainError::UpstreamDisabled {
+                alias: "api".into(),
+            },
+            DomainError::Internal { message: detail() },
+            DomainError::MissingTargetHost {
+                instance: instance(),
+            },
+            DomainError::InvalidTargetHost {
+                instance: instance(),
+            },
+            DomainError::UnknownTargetHost {
+                detail: detail(),
+                instance: instance(),
+            },
+            DomainError::AuthenticationFailed {
+                detail: detail(),
+                instance: instance(),
+            },
+            DomainError::PayloadTooLarge {
+                detail: detail(),
+                instance: instance(),
+            },
+            DomainError::UnsupportedMediaType {
+                detail: detail(),
+                instance: instance(),
+            },
+            DomainError::RateLimitExceeded {
+                detail: detail(),
+                instance: instance(),
+                retry_after_secs: None,
+            },
+            DomainError::SecretNotFound {
+                detail: detail(),
+                instance: instance(),
+            },
+            DomainError::DownstreamError {
+                detail: detail(),
+                instance: instance(),
+            },
+            DomainError::ProtocolError {
+                detail: detail(),
+                instance: instance(),
+            },
+            DomainError::ConnectionTimeout {
+                detail: detail(),
+                instance: instance(),
+            },
+            DomainError::RequestTimeout {
+                detail: detail(),
+                instance: instance(),
+            },
+            DomainError::GuardRejected {
+                status: 451,
+                error_code: "BLOCKED".into(),
+                detail: detail(),
+                instance: instance(),
+                plugin_id: "gts.x.core.oagw.guard_plugin.v1~x.core.oagw.cors.v1".into(),
+            },
+            DomainError::CorsOriginNotAllowed {
+                origin: "https://evil.example".into(),
+                instance: instance(),
+            },
+            DomainError::CorsMethodNotAllowed {
+                method: "PUT".into(),
+                instance: instance(),
+            },
+            DomainError::StreamAborted {
+                detail: detail(),
+                instance: instance(),
+            },
+            DomainError::LinkUnavailable {
+                detail: detail(),
+                instance: instance(),
+            },
+            DomainError::CircuitBreakerOpen {
+                detail: detail(),
+                instance: instance(),
+            },
+            DomainError::IdleTimeout {
+                detail: detail(),
+                instance: instance(),
+            },
+            DomainError::PluginNotFound { detail: detail() },
+            DomainError::PluginInUse { detail: detail() },
+            DomainError::Forbidden { detail: detail() },
+        ]
+    }
+
+    #[test]
+    fn sdk_status_code_matches_rest_mapping() {
+        let rest = every_domain_error()
+            .into_iter()
+            .map(|e| modkit::api::problem::Problem::from(e).status);
+        let sdk = every_domain_error().into_iter().map(domain_err_to_sdk);
+
+        for (rest_status, sdk_err) in rest.zip(sdk) {
+            match &sdk_err {
+                // The SDK has no Conflict or Internal variant; these are
+                // reported as ValidationError (400) and DownstreamError (502).
+                ServiceGatewayError::ValidationError { .. }
+                    if rest_status == http::StatusCode::CONFLICT => {}
+                ServiceGatewayError::DownstreamError { .. }
+                    if rest_status == http::StatusCode::INTERNAL_SERVER_ERROR => {}
+                _ => assert_eq!(sdk_err.status_code(), rest_status, "{sdk_err:?}"),
+            }
+        }
+    }
+
     #[test]
     fn auth_config_hashmap_round_trips() {
         let mut config = HashMap::new();
@@ -1,13 +1,22 @@
+use std::collections::BTreeSet;
 use std::sync::Arc;
+use std::time::Duration;
 
-use super::ControlPlaneService;
+use super::{
+    ConfigChange, ConfigChangeKind, ControlPlaneService, CreatedUpstream, ValidationIssue,
+};
 
 use crate::domain::error::DomainError;
+use crate::domain::idempotency::{Claim, IdempotencyKeys, validate_idempotency_key};
 use crate::domain::model::{
-    CreateRouteRequest, CreateUpstreamRequest, Endpoint, ListQuery, MatchRules, Route,
-    UpdateRouteRequest, UpdateUpstreamRequest, Upstream,
+    AuthConfig, CreateRouteRequest, CreateUpstreamRequest, Endpoint, ListQuery, MatchRules,
+    PluginsConfig, RateLimitConfig, Route, TenantRateLimit, UpdateRouteRequest,
+    UpdateUpstreamRequest, Upstream,
+};
+use crate::domain::repo::{
+    RepositoryError, RouteRepository, TenantRateLimitRepository, TransactionRunner,
+    UpstreamRepository,
 };
-use crate::domain::repo::{RouteRepository, UpstreamRepository};
 
 use async_trait::async_trait;
 use authz_resolver_sdk::PolicyEnforcer;
@@ -16,8 +25,24 @@ use credstore_sdk::CredStoreClientV1;
 use modkit_macros::domain_model;
 use modkit_security::SecurityContext;
 use tenant_resolver_sdk::TenantResolverClient;
+use tokio::sync::broadcast;
 use uuid::Uuid;
 
+/// Buffered change notifications per subscriber before it starts lagging.
+const CHANGE_CHANNEL_CAPACITY: usize = 1024;
+
+/// How long an upstream create is remembered under its idempotency key
+/// unless overridden with [`ControlPlaneServiceImpl::with_idempotency_key_ttl`].
+const DEFAULT_IDEMPOTENCY_KEY_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Page size used when scanning all of a tenant's upstreams.
+const UPSTREAM_SCAN_PAGE: u32 = 100;
+
+/// Auth config keys that name credstore secrets: `secret_ref` (API key),
+/// `client_secret_ref` (OAuth2 client credentials) and the comma-separated
+/// `secret_refs` (key pool).
+const SECRET_REF_KEYS: &[&str] = &["secret_ref", "client_secret_ref", "secret_refs"];
+
 /// Resource type for upstream binding permission checks.
 const UPSTREAM_RESOURCE: ResourceType = ResourceType {
     name: "gts.x.core.oagw.upstream.v1~",
@@ -37,9 +62,13 @@ mod bind_actions {
 pub(crate) struct ControlPlaneServiceImpl {
     upstreams: Arc<dyn UpstreamRepository>,
     routes: Arc<dyn RouteRepository>,
+    tenant_rate_limits: Arc<dyn TenantRateLimitRepository>,
+    transactions: Arc<dyn TransactionRunner>,
     tenant_resolver: Arc<dyn TenantResolverClient>,
     policy_enforcer: PolicyEnforcer,
     credstore: Arc<dyn CredStoreClientV1>,
+    changes: broadcast::Sender<ConfigChange>,
+    idempotency: IdempotencyKeys,
 }
 
 impl ControlPlaneServiceImpl {
@@ -47,6 +76,8 @@ impl ControlPlaneServiceImpl {
     pub(crate) fn new(
         upstreams: Arc<dyn UpstreamRepository>,
         routes: Arc<dyn RouteRepository>,
+        tenant_rate_limits: Arc<dyn TenantRateLimitRepository>,
+        transactions: Arc<dyn TransactionRunner>,
         tenant_resolver: Arc<dyn TenantResolverClient>,
         policy_enforcer: PolicyEnforcer,
         credstore: Arc<dyn CredStoreClientV1>,
@@ -54,11 +85,32 @@ impl ControlPlaneServiceImpl {
         Self {
             upstreams,
             routes,
+            tenant_rate_limits,
+            transactions,
             tenant_resolver,
             policy_enforcer,
             credstore,
+            changes: broadcast::channel(CHANGE_CHANNEL_CAPACITY).0,
+            idempotency: IdempotencyKeys::new(DEFAULT_IDEMPOTENCY_KEY_TTL),
         }
     }
+
+    /// How long a created upstream is returned for repeats of its
+    /// idempotency key.
+    #[must_use]
+    pub(crate) fn with_idempotency_key_ttl(mut self, ttl: Duration) -> Self {
+        self.idempotency = IdempotencyKeys::new(ttl);
+        self
+    }
+
+    fn notify(&self, tenant_id: Uuid, kind: ConfigChangeKind, id: Uuid) {
+        // No subscribers is not an error.
+        let _ = self.changes.send(ConfigChange {
+            tenant_id,
+            kind,
+            id,
+        });
+    }
 }
 
 // ===========================================================================
@@ -74,52 +126,50 @@ impl ControlPlaneService for ControlPlaneServiceImpl {
         ctx: &SecurityContext,
         req: CreateUpstreamRequest,
     ) -> Result<Upstream, DomainError> {
-        validate_endpoints(&req.server.endpoints)?;
-        if let Some(ref cors) = req.cors {
-            crate::domain::cors::validate_cors_config(cors)?;
-        }
-
-        // Enforce alias derivation / explicit rules.
-        let alias = enforce_alias_create(req.alias.as_deref(), &req.server.endpoints)?;
+        self.create_upstream_idempotent(ctx, req)
+            .await
+            .map(|c| c.upstream)
+    }
 
+    async fn create_upstream_idempotent(
+        &self,
+        ctx: &SecurityContext,
+        req: CreateUpstreamRequest,
+    ) -> Result<CreatedUpstream, DomainError> {
+        let Some(key) = req.idempotency_key.clone() else {
+            return self
+                .insert_upstream(ctx, req)
+                .await
+                .map(CreatedUpstream::new);
+        };
+        validate_idempotency_key(&key)?;
         let tenant_id = ctx.subject_tenant_id();
-        let id = Uuid::new_v4();
-        let tenant_chain = self.build_tenant_chain(ctx).await?;
 
-        // Check if an ancestor tenant has an upstream with this alias.
-        // If so, this is a "bind" operation requiring ancestor bind validation.
-        self.validate_ancestor_bind(
-            ctx,
-            &tenant_chain,
-            &alias,
-            &BindOverrides {
-                auth: req.auth.as_ref(),
-                rate_limit: req.rate_limit.as_ref(),
-                plugins: req.plugins.as_ref(),
-                cors: req.cors.as_ref(),
-            },
-        )
-        .await?;
-
-        let upstream = Upstream {
-            id,
-            tenant_id,
-            alias,
-            server: req.server,
-            protocol: req.protocol,
-            enabled: req.enabled,
-            auth: req.auth,
-            headers: req.headers,
-            plugins: req.plugins,
-            rate_limit: req.rate_limit,
-            cors: req.cors,
-            tags: req.tags,
+        let mut claim = self.idempotency.claim(tenant_id, &key);
+        if let Claim::Created(id) = claim {
+            match self.upstreams.get_by_id(tenant_id, id).await {
+                Ok(upstream) => {
+                    return Ok(CreatedUpstream {
+                        upstream,
+                        replayed: true,
+                    });
+                }
+                // The upstream was deleted since; the key no longer guards anything.
+                Err(_) => {
+                    self.idempotency.release(tenant_id, &key);
+                    claim = self.idempotency.claim(tenant_id, &key);
+                }
+            }
+        }
+        let Claim::New(guard) = claim else {
+            return Err(DomainError::conflict(
+                "a request with this idempotency_key is already in progress",
+            ));
         };
 
-        self.upstreams
-            .create(upstream)
-            .await
-            .map_err(DomainError::from)
+        let created = self.insert_upstream(ctx, req).await?;
+        guard.complete(created.id);
+        Ok(CreatedUpstream::new(created))
     }
 
     async fn get_upstream(&self, ctx: &SecurityContext, id: Uuid) -> Result<Upstream, DomainError> {
@@ -214,7 +264,13 @@ impl ControlPlaneService for ControlPlaneServiceImpl {
         // Full replacement: directly assign all fields (None = unset).
         existing.auth = req.auth;
         existing.headers = req.headers;
+        if let Some(ref plugins) = req.plugins {
+            validate_plugins(plugins)?;
+        }
         existing.plugins = req.plugins;
+        if let Some(ref rl) = req.rate_limit {
+            crate::domain::rate_limit::validate_rate_limit_config(rl)?;
+        }
         existing.rate_limit = req.rate_limit;
         if let Some(ref cors) = req.cors {
             crate::domain::cors::validate_cors_config(cors)?;
@@ -223,23 +279,32 @@ impl ControlPlaneService for ControlPlaneServiceImpl {
         existing.tags = req.tags;
         existing.enabled = req.enabled;
 
-        self.upstreams
+        let updated = self
+            .upstreams
             .update(existing)
             .await
-            .map_err(DomainError::from)
+            .map_err(DomainError::from)?;
+        self.notify(tenant_id, ConfigChangeKind::UpstreamUpdated, updated.id);
+        Ok(updated)
     }
 
     async fn delete_upstream(&self, ctx: &SecurityContext, id: Uuid) -> Result<(), DomainError> {
         let tenant_id = ctx.subject_tenant_id();
-        // Cascade delete routes before removing the upstream.
-        self.routes
-            .delete_by_upstream(tenant_id, id)
-            .await
-            .map_err(DomainError::from)?;
-        self.upstreams
-            .delete(tenant_id, id)
+        // Cascade delete routes with the upstream; a failure keeps both.
+        self.transactions
+            .with_transaction(move |tx| {
+                Box::pin(async move {
+                    tx.routes().delete_by_upstream(tenant_id, id).await?;
+                    tx.upstreams().delete(tenant_id, id).await
+                })
+            })
             .await
-            .map_err(|_| DomainError::not_found("upstream", id))
+            .map_err(|e| match e {
+                RepositoryError::NotFound { .. } => DomainError::not_found("upstream", id),
+                other => DomainError::from(other),
+            })?;
+        self.notify(tenant_id, ConfigChangeKind::UpstreamDeleted, id);
+        Ok(())
     }
 
     // -- Route CRUD --
@@ -249,9 +314,15 @@ impl ControlPlaneService for ControlPlaneServiceImpl {
         ctx: &SecurityContext,
         req: CreateRouteRequest,
     ) -> Result<Route, DomainError> {
+        if let Some(ref rl) = req.rate_limit {
+            crate::domain::rate_limit::validate_rate_limit_config(rl)?;
+        }
         if let Some(ref cors) = req.cors {
             crate::domain::cors::validate_cors_config(cors)?;
         }
+        if let Some(ref plugins) = req.plugins {
+            validate_plugins(plugins)?;
+        }
 
         let tenant_id = ctx.subject_tenant_id();
         // Validate that the upstream exists and belongs to this tenant.
@@ -273,6 +344,10 @@ impl ControlPlaneService for ControlPlaneServiceImpl {
             plugins: req.plugins,
             rate_limit: req.rate_limit,
             cors: req.cors,
+            sse_reconnect: req.sse_reconnect,
+            get_coalescing: req.get_coalescing,
+            request_compression: req.request_compression,
+            response_cache: req.response_cache,
             tags: req.tags,
             priority: req.priority,
             enabled: req.enabled,
@@ -281,7 +356,9 @@ impl ControlPlaneService for ControlPlaneServiceImpl {
         validate_match_rules(&route.match_rules)?;
         self.check_route_overlap(&route, None).await?;
 
-        self.routes.create(route).await.map_err(DomainError::from)
+        let created = self.routes.create(route).await.map_err(DomainError::from)?;
+        self.notify(tenant_id, ConfigChangeKind::RouteCreated, created.id);
+        Ok(created)
     }
 
     async fn get_route(&self, ctx: &SecurityContext, id: Uuid) -> Result<Route, DomainError> {
@@ -320,12 +397,22 @@ impl ControlPlaneService for ControlPlaneServiceImpl {
 
         // Full replacement: directly assign all fields (None = unset).
         existing.match_rules = req.match_rules;
+        if let Some(ref plugins) = req.plugins {
+            validate_plugins(plugins)?;
+        }
         existing.plugins = req.plugins;
+        if let Some(ref rl) = req.rate_limit {
+            crate::domain::rate_limit::validate_rate_limit_config(rl)?;
+        }
         existing.rate_limit = req.rate_limit;
         if let Some(ref cors) = req.cors {
             crate::domain::cors::validate_cors_config(cors)?;
         }
         existing.cors = req.cors;
+        existing.sse_reconnect = req.sse_reconnect;
+        existing.get_coalescing = req.get_coalescing;
+        existing.request_compression = req.request_compression;
+        existing.response_cache = req.response_cache;
         existing.tags = req.tags;
         existing.priority = req.priority;
         existing.enabled = req.enabled;
@@ -334,10 +421,13 @@ impl ControlPlaneService for ControlPlaneServiceImpl {
         self.check_route_overlap(&existing, Some(existing.id))
             .await?;
 
-        self.routes
+        let updated = self
+            .routes
             .update(existing)
             .await
-            .map_err(DomainError::from)
+            .map_err(DomainError::from)?;
+        self.notify(tenant_id, ConfigChangeKind::RouteUpdated, updated.id);
+        Ok(updated)
     }
 
     async fn delete_route(&self, ctx: &SecurityContext, id: Uuid) -> Result<(), DomainError> {
@@ -345,7 +435,217 @@ impl ControlPlaneService for ControlPlaneServiceImpl {
         self.routes
             .delete(tenant_id, id)
             .await
-            .map_err(|_| DomainError::not_found("route", id))
+            .map_err(|_| DomainError::not_found("route", id))?;
+        self.notify(tenant_id, ConfigChangeKind::RouteDeleted, id);
+        Ok(())
+    }
+
+    // -- Tenant rate limit --
+
+    async fn get_tenant_rate_limit(
+        &self,
+        ctx: &SecurityContext,
+    ) -> Result<Option<TenantRateLimit>, DomainError> {
+        self.tenant_rate_limits
+            .get(ctx.subject_tenant_id())
+            .await
+            .map_err(DomainError::from)
+    }
+
+    async fn set_tenant_rate_limit(
+        &self,
+        ctx: &SecurityContext,
+        config: RateLimitConfig,
+        aggregate: bool,
+    ) -> Result<TenantRateLimit, DomainError> {
+        crate::domain::rate_limit::validate_rate_limit_config(&config)?;
+        let tenant_id = ctx.subject_tenant_id();
+        let limit = self
+            .tenant_rate_limits
+            .upsert(TenantRateLimit {
+                tenant_id,
+                config,
+                aggregate,
+            })
+            .await
+            .map_err(DomainError::from)?;
+        self.notify(
+            tenant_id,
+            ConfigChangeKind::TenantRateLimitChanged,
+            tenant_id,
+        );
+        Ok(limit)
+    }
+
+    async fn delete_tenant_rate_limit(&self, ctx: &SecurityContext) -> Result<(), DomainError> {
+        let tenant_id = ctx.subject_tenant_id();
+        self.tenant_rate_limits
+            .delete(tenant_id)
+            .await
+            .map_err(DomainError::from)?;
+        self.notify(
+            tenant_id,
+            ConfigChangeKind::TenantRateLimitChanged,
+            tenant_id,
+        );
+        Ok(())
+    }
+
+    // -- Credentials --
+
+    async fn list_credential_refs(
+        &self,
+        ctx: &SecurityContext,
+    ) -> Result<Vec<String>, DomainError> {
+        let tenant_id = ctx.subject_tenant_id();
+        let mut refs = BTreeSet::new();
+        let mut query = ListQuery {
+            top: UPSTREAM_SCAN_PAGE,
+            skip: 0,
+        };
+        loop {
+            let page = self
+                .upstreams
+                .list(tenant_id, &query)
+                .await
+                .map_err(DomainError::from)?;
+            for auth in page.iter().filter_map(|u| u.auth.as_ref()) {
+                collect_secret_refs(auth, &mut refs);
+            }
+            if page.len() < UPSTREAM_SCAN_PAGE as usize {
+                break;
+            }
+            query.skip += UPSTREAM_SCAN_PAGE;
+        }
+        Ok(refs.into_iter().collect())
+    }
+
+    // -- Dry-run validation --
+
+    async fn validate_upstream(
+        &self,
+        ctx: &SecurityContext,
+        req: &CreateUpstreamRequest,
+    ) -> Result<Vec<ValidationIssue>, DomainError> {
+        let mut issues = Vec::new();
+        if let Some(ref key) = req.idempotency_key {
+            collect_issue(
+                &mut issues,
+                "idempotency_key",
+                validate_idempotency_key(key),
+            )?;
+        }
+        let endpoints = validate_endpoints(&req.server.endpoints);
+        let endpoints_valid = endpoints.is_ok();
+        collect_issue(&mut issues, "server.endpoints", endpoints)?;
+        if let Some(ref rl) = req.rate_limit {
+            let result = crate::domain::rate_limit::validate_rate_limit_config(rl);
+            collect_issue(&mut issues, "rate_limit", result)?;
+        }
+        if let Some(ref cors) = req.cors {
+            let result = crate::domain::cors::validate_cors_config(cors);
+            collect_issue(&mut issues, "cors", result)?;
+        }
+        if let Some(ref plugins) = req.plugins {
+            collect_issue(&mut issues, "plugins", validate_plugins(plugins))?;
+        }
+
+        // The alias is derived from the endpoints; skip its checks when
+        // those are already invalid.
+        if !endpoints_valid {
+            return Ok(issues);
+        }
+        let alias = match enforce_alias_create(req.alias.as_deref(), &req.server.endpoints) {
+            Ok(alias) => alias,
+            Err(e) => {
+                collect_issue(&mut issues, "alias", Err(e))?;
+                return Ok(issues);
+            }
+        };
+        // Create relies on the repository's unique index for this.
+        if self
+            .upstreams
+            .get_by_alias(ctx.subject_tenant_id(), &alias)
+            .await
+            .is_ok()
+        {
+            let taken = DomainError::conflict(format!("alias '{alias}' already exists for tenant"));
+            collect_issue(&mut issues, "alias", Err(taken))?;
+        }
+        let tenant_chain = self.build_tenant_chain(ctx).await?;
+        let bind = self
+            .validate_ancestor_bind(
+                ctx,
+                &tenant_chain,
+                &alias,
+                &BindOverrides {
+                    auth: req.auth.as_ref(),
+                    rate_limit: req.rate_limit.as_ref(),
+                    plugins: req.plugins.as_ref(),
+                    cors: req.cors.as_ref(),
+                },
+            )
+            .await;
+        collect_issue(&mut issues, "alias", bind)?;
+        Ok(issues)
+    }
+
+    async fn validate_route(
+        &self,
+        ctx: &SecurityContext,
+        req: &CreateRouteRequest,
+    ) -> Result<Vec<ValidationIssue>, DomainError> {
+        let mut issues = Vec::new();
+        if let Some(ref rl) = req.rate_limit {
+            let result = crate::domain::rate_limit::validate_rate_limit_config(rl);
+            collect_issue(&mut issues, "rate_limit", result)?;
+        }
+        if let Some(ref cors) = req.cors {
+            let result = crate::domain::cors::validate_cors_config(cors);
+            collect_issue(&mut issues, "cors", result)?;
+        }
+        if let Some(ref plugins) = req.plugins {
+            collect_issue(&mut issues, "plugins", validate_plugins(plugins))?;
+        }
+
+        let tenant_id = ctx.subject_tenant_id();
+        if self
+            .upstreams
+            .get_by_id(tenant_id, req.upstream_id)
+            .await
+            .is_err()
+        {
+            let missing = DomainError::validation(format!(
+                "upstream '{}' not found for this tenant",
+                req.upstream_id
+            ));
+            collect_issue(&mut issues, "upstream_id", Err(missing))?;
+        }
+
+        let rules = validate_match_rules(&req.match_rules);
+        let rules_valid = rules.is_ok();
+        collect_issue(&mut issues, "match", rules)?;
+        if rules_valid {
+            let candidate = Route {
+                id: Uuid::new_v4(),
+                tenant_id,
+                upstream_id: req.upstream_id,
+                match_rules: req.match_rules.clone(),
+                plugins: None,
+                rate_limit: None,
+                cors: None,
+                sse_reconnect: None,
+                get_coalescing: None,
+                request_compression: None,
+                response_cache: None,
+                tags: Vec::new(),
+                priority: req.priority,
+                enabled: req.enabled,
+            };
+            let overlap = self.check_route_overlap(&candidate, None).await;
+            collect_issue(&mut issues, "match", overlap)?;
+        }
+        Ok(issues)
     }
 
     // -- Resolution --
@@ -368,6 +668,32 @@ impl ControlPlaneService for ControlPlaneServiceImpl {
             })?,
         ))
     }
+
+    async fn resolve_upstream(
+        &self,
+        ctx: &SecurityContext,
+        alias: &str,
+    ) -> Result<Upstream, DomainError> {
+        let tenant_chain = self.build_tenant_chain(ctx).await?;
+        let (effective, _) = self.resolve_alias(ctx, &tenant_chain, alias, None).await?;
+        Ok(effective)
+    }
+
+    // -- Change notification --
+
+    fn subscribe(&self) -> broadcast::Receiver<ConfigChange> {
+        self.changes.subscribe()
+    }
+
+    // -- Health --
+
+    async fn check_ready(&self) -> Result<(), DomainError> {
+        // Cheapest query both storage backends support: one row, any tenant.
+        self.upstreams
+            .list(Uuid::nil(), &ListQuery { top: 1, skip: 0 })
+            .await?;
+        Ok(())
+    }
 }
 
 // ===========================================================================
@@ -375,6 +701,69 @@ impl ControlPlaneService for ControlPlaneServiceImpl {
 // ===========================================================================
 
 impl ControlPlaneServiceImpl {
+    /// Validate and persist a new upstream; the body of `create_upstream`.
+    async fn insert_upstream(
+        &self,
+        ctx: &SecurityContext,
+        req: CreateUpstreamRequest,
+    ) -> Result<Upstream, DomainError> {
+        validate_endpoints(&req.server.endpoints)?;
+        if let Some(ref rl) = req.rate_limit {
+            crate::domain::rate_limit::validate_rate_limit_config(rl)?;
+        }
+        if let Some(ref cors) = req.cors {
+            crate::domain::cors::validate_cors_config(cors)?;
+        }
+        if let Some(ref plugins) = req.plugins {
+            validate_plugins(plugins)?;
+        }
+
+        // Enforce alias derivation / explicit rules.
+        let alias = enforce_alias_create(req.alias.as_deref(), &req.server.endpoints)?;
+
+        let tenant_id = ctx.subject_tenant_id();
+        let id = Uuid::new_v4();
+        let tenant_chain = self.build_tenant_chain(ctx).await?;
+
+        // Check if an ancestor tenant has an upstream with this alias.
+        // If so, this is a "bind" operation requiring ancestor bind validation.
+        self.validate_ancestor_bind(
+            ctx,
+            &tenant_chain,
+            &alias,
+            &BindOverrides {
+                auth: req.auth.as_ref(),
+                rate_limit: req.rate_limit.as_ref(),
+                plugins: req.plugins.as_ref(),
+                cors: req.cors.as_ref(),
+            },
+        )
+        .await?;
+
+        let upstream = Upstream {
+            id,
+            tenant_id,
+            alias,
+            server: req.server,
+            protocol: req.protocol,
+            enabled: req.enabled,
+            auth: req.auth,
+            headers: req.headers,
+            plugins: req.plugins,
+            rate_limit: req.rate_limit,
+            cors: req.cors,
+            tags: req.tags,
+        };
+
+        let created = self
+            .upstreams
+            .create(upstream)
+            .await
+            .map_err(DomainError::from)?;
+        self.notify(tenant_id, ConfigChangeKind::UpstreamCreated, created.id);
+        Ok(created)
+    }
+
     /// Check that no existing **enabled** route under the same upstream shares
     /// `(path_prefix, priority, method)` with the candidate route.
     ///
@@ -662,6 +1051,83 @@ fn validate_match_rules(rules: &MatchRules) -> Result<(), DomainError> {
     }
 }
 
+/// Record the outcome of one dry-run check. Rejections the create path
+/// would return become issues on `field`; any other error means the check
+/// itself could not run and is propagated.
+fn collect_issue(
+    issues: &mut Vec<ValidationIssue>,
+    field: &str,
+    result: Result<(), DomainError>,
+) -> Result<(), DomainError> {
+    match result {
+        Ok(()) => Ok(()),
+        Err(
+            error @ (DomainError::Validation { .. }
+            | DomainError::Conflict { .. }
+            | DomainError::Forbidden { .. }),
+        ) => {
+            issues.push(ValidationIssue {
+                field: field.to_string(),
+                error,
+            });
+            Ok(())
+        }
+        Err(other) => Err(other),
+    }
+}
+
+/// Ensure every plugin binding references a guard or transform plugin.
+///
+/// Bindings are matched to plugins by GTS identifier at request time; any
+/// other reference would be silently skipped by the data plane. The
+/// content-type guard additionally needs a non-empty `allowed_content_types`,
+/// since without one it would reject every request body.
+/// Add the secrets named by `auth` to `out`, bare (without `cred://`).
+fn collect_secret_refs(auth: &AuthConfig, out: &mut BTreeSet<String>) {
+    let Some(config) = &auth.config else {
+        return;
+    };
+    for value in SECRET_REF_KEYS.iter().filter_map(|key| config.get(*key)) {
+        out.extend(
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|r| !r.is_empty())
+                .map(|r| r.strip_prefix("cred://").unwrap_or(r).to_owned()),
+        );
+    }
+}
+
+fn validate_plugins(plugins: &PluginsConfig) -> Result<(), DomainError> {
+    use crate::domain::gts_helpers::{
+        CONTENT_TYPE_GUARD_PLUGIN_ID, GUARD_PLUGIN_SCHEMA, TRANSFORM_PLUGIN_SCHEMA,
+    };
+
+    for (i, binding) in plugins.items.iter().enumerate() {
+        let instance = binding
+            .plugin_ref
+            .strip_prefix(GUARD_PLUGIN_SCHEMA)
+            .or_else(|| binding.plugin_ref.strip_prefix(TRANSFORM_PLUGIN_SCHEMA));
+        if instance.is_none_or(str::is_empty) {
+            return Err(DomainError::validation(format!(
+                "plugins.items[{i}]: '{}' is not a guard or transform plugin identifier",
+                binding.plugin_ref
+            )));
+        }
+        if binding.plugin_ref == CONTENT_TYPE_GUARD_PLUGIN_ID
+            && binding
+                .config
+                .get("allowed_content_types")
+                .is_none_or(|v| v.split(',').all(|t| t.trim().is_empty()))
+        {
+            return Err(DomainError::validation(format!(
+                "plugins.items[{i}]: content-type guard requires a non-empty 'allowed_content_types'"
+            )));
+        }
+    }
+    Ok(())
+}
+
 /// Validate the endpoint list for a server configuration.
 ///
 /// Rules:
@@ -1092,11 +1558,23 @@ async fn validate_bind_constraints(
                     )
                     .await?;
 
-                // Validate secret_ref accessibility for the descendant tenant.
-                if let Some(ref config) = auth_override.config
-                    && let Some(raw_ref) = config.get("secret_ref")
-                {
-                    validate_secret_ref_accessible(credstore, ctx, raw_ref).await?;
+                // Validate secret_ref accessibility for the descendant tenant,
+                // including every entry of a key pool's `secret_refs`.
+                if let Some(ref config) = auth_override.config {
+                    let pooled = config
+                        .get("secret_refs")
+                        .into_iter()
+                        .flat_map(|refs| refs.split(','))
+                        .map(str::trim)
+                        .filter(|r| !r.is_empty());
+                    for raw_ref in config
+                        .get("secret_ref")
+                        .map(String::as_str)
+                        .into_iter()
+                        .chain(pooled)
+                    {
+                        validate_secret_ref_accessible(credstore, ctx, raw_ref).await?;
+                    }
                 }
             }
         }
@@ -1529,6 +2007,7 @@ fn rate_per_second(rl: &crate::domain::model::RateLimitConfig) -> f64 {
         Window::Minute => 60.0,
         Window::Hour => 3600.0,
         Window::Day => 86400.0,
+        Window::Seconds(n) => f64::from(n),
     };
     f64::from(rl.sustained.rate) / divisor
 }
@@ -1606,40 +2085,48 @@ mod tests {
     use crate::domain::test_support::{
         MockCredStoreClient, MockTenantResolverClient, allow_all_enforcer,
     };
-    use crate::infra::storage::{InMemoryRouteRepo, InMemoryUpstreamRepo};
+    use crate::infra::storage::{
+        InMemoryRouteRepo, InMemoryTenantRateLimitRepo, InMemoryTransactions, InMemoryUpstreamRepo,
+    };
     use tenant_resolver_sdk::TenantId;
 
-    fn make_service() -> ControlPlaneServiceImpl {
+    fn make_in_memory_service(
+        resolver: MockTenantResolverClient,
+        credstore: MockCredStoreClient,
+    ) -> ControlPlaneServiceImpl {
+        let upstreams = Arc::new(InMemoryUpstreamRepo::new());
+        let routes = Arc::new(InMemoryRouteRepo::new());
+        let transactions = Arc::new(InMemoryTransactions::new(
+            Arc::clone(&upstreams),
+            Arc::clone(&routes),
+        ));
         ControlPlaneServiceImpl::new(
-            Arc::new(InMemoryUpstreamRepo::new()),
-            Arc::new(InMemoryRouteRepo::new()),
-            Arc::new(MockTenantResolverClient::single_tenant()),
+            upstreams,
+            routes,
+            Arc::new(InMemoryTenantRateLimitRepo::new()),
+            transactions,
+            Arc::new(resolver),
             allow_all_enforcer(),
-            Arc::new(MockCredStoreClient::empty()),
+            Arc::new(credstore),
         )
     }
 
-    fn make_service_with_resolver(resolver: MockTenantResolverClient) -> ControlPlaneServiceImpl {
-        ControlPlaneServiceImpl::new(
-            Arc::new(InMemoryUpstreamRepo::new()),
-            Arc::new(InMemoryRouteRepo::new()),
-            Arc::new(resolver),
-            allow_all_enforcer(),
-            Arc::new(MockCredStoreClient::empty()),
+    fn make_service() -> ControlPlaneServiceImpl {
+        make_in_memory_service(
+            MockTenantResolverClient::single_tenant(),
+            MockCredStoreClient::empty(),
         )
     }
 
+    fn make_service_with_resolver(resolver: MockTenantResolverClient) -> ControlPlaneServiceImpl {
+        make_in_memory_service(resolver, MockCredStoreClient::empty())
+    }
+
     fn make_service_with_resolver_and_creds(
         resolver: MockTenantResolverClient,
         creds: Vec<(String, String)>,
     ) -> ControlPlaneServiceImpl {
-        ControlPlaneServiceImpl::new(
-            Arc::new(InMemoryUpstreamRepo::new()),
-            Arc::new(InMemoryRouteRepo::new()),
-            Arc::new(resolver),
-            allow_all_enforcer(),
-            Arc::new(MockCredStoreClient::with_secrets(creds)),
-        )
+        make_in_memory_service(resolver, MockCredStoreClient::with_secrets(creds))
     }
 
     fn test_ctx(tenant_id: Uuid) -> SecurityContext {
@@ -1669,6 +2156,7 @@ mod tests {
             cors: None,
             tags: vec![],
             enabled: true,
+            idempotency_key: None,
         }
     }
 
@@ -1691,6 +2179,7 @@ mod tests {
             cors: None,
             tags: vec![],
             enabled: true,
+            idempotency_key: None,
         }
     }
 
@@ -1717,6 +2206,10 @@ mod tests {
             plugins: r.plugins.clone(),
             rate_limit: r.rate_limit.clone(),
             cors: r.cors.clone(),
+            sse_reconnect: None,
+            get_coalescing: None,
+            request_compression: None,
+            response_cache: None,
             tags: r.tags.clone(),
             priority: r.priority,
             enabled: r.enabled,
@@ -1738,6 +2231,10 @@ mod tests {
             plugins: None,
             rate_limit: None,
             cors: None,
+            sse_reconnect: None,
+            get_coalescing: None,
+            request_compression: None,
+            response_cache: None,
             tags: vec![],
             priority: 0,
             enabled: true,
@@ -1811,11 +2308,61 @@ mod tests {
             cors: None,
             tags: vec![],
             enabled: true,
+            idempotency_key: None,
         };
         let u2 = svc.create_upstream(&ctx, req).await.unwrap();
         assert_eq!(u2.alias, "api.openai.com:8443");
     }
 
+    #[tokio::test]
+    async fn idempotent_create_replays_until_upstream_is_deleted() {
+        let svc = make_service();
+        let ctx = test_ctx(Uuid::new_v4());
+        let req = CreateUpstreamRequest {
+            idempotency_key: Some("provision-42".into()),
+            ..make_create_upstream_hostname()
+        };
+
+        let first = svc
+            .create_upstream_idempotent(&ctx, req.clone())
+            .await
+            .unwrap();
+        assert!(!first.replayed);
+        let second = svc
+            .create_upstream_idempotent(&ctx, req.clone())
+            .await
+            .unwrap();
+        assert!(second.replayed);
+        assert_eq!(second.upstream.id, first.upstream.id);
+
+        // Once the upstream is gone the key no longer pins it.
+        svc.delete_upstream(&ctx, first.upstream.id).await.unwrap();
+        let third = svc.create_upstream_idempotent(&ctx, req).await.unwrap();
+        assert!(!third.replayed);
+        assert_ne!(third.upstream.id, first.upstream.id);
+    }
+
+    #[tokio::test]
+    async fn failed_idempotent_create_releases_key() {
+        let svc = make_service();
+        let ctx = test_ctx(Uuid::new_v4());
+        let invalid = CreateUpstreamRequest {
+            server: Server { endpoints: vec![] },
+            idempotency_key: Some("retry-me".into()),
+            ..make_create_upstream_hostname()
+        };
+        svc.create_upstream_idempotent(&ctx, invalid)
+            .await
+            .unwrap_err();
+
+        let req = CreateUpstreamRequest {
+            idempotency_key: Some("retry-me".into()),
+            ..make_create_upstream_hostname()
+        };
+        let created = svc.create_upstream_idempotent(&ctx, req).await.unwrap();
+        assert!(!created.replayed);
+    }
+
     #[tokio::test]
     async fn alias_rejects_path_traversal() {
         let svc = make_service();
@@ -2002,6 +2549,58 @@ mod tests {
         assert!(matches!(err, DomainError::NotFound { .. }));
     }
 
+    // -- validate_plugins tests --
+
+    #[test]
+    fn collect_secret_refs_reads_every_secret_key() {
+        let auth = AuthConfig {
+            plugin_type: "any".into(),
+            sharing: SharingMode::Private,
+            config: Some(
+                [
+                    ("header".into(), "authorization".into()),
+                    ("secret_ref".into(), "cred://api-key".into()),
+                    ("client_secret_ref".into(), "oauth-secret".into()),
+                    ("secret_refs".into(), "cred://pool-a, pool-b,".into()),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+        };
+        let mut refs = BTreeSet::new();
+        collect_secret_refs(&auth, &mut refs);
+        assert_eq!(
+            refs.into_iter().collect::<Vec<_>>(),
+            ["api-key", "oauth-secret", "pool-a", "pool-b"]
+        );
+    }
+
+    #[test]
+    fn validate_plugins_requires_content_type_allowlist() {
+        use crate::domain::gts_helpers::CONTENT_TYPE_GUARD_PLUGIN_ID;
+        use crate::domain::model::{PluginBinding, PluginsConfig, SharingMode};
+
+        let plugins = |config: &[(&str, &str)]| PluginsConfig {
+            sharing: SharingMode::Private,
+            items: vec![PluginBinding {
+                plugin_ref: CONTENT_TYPE_GUARD_PLUGIN_ID.into(),
+                config: config
+                    .iter()
+                    .map(|(k, v)| ((*k).to_owned(), (*v).to_owned()))
+                    .collect(),
+            }],
+        };
+
+        for config in [&[][..], &[("allowed_content_types", " , ")][..]] {
+            let err = validate_plugins(&plugins(config)).unwrap_err();
+            assert!(
+                matches!(err, DomainError::Validation { ref detail, .. } if detail.contains("allowed_content_types")),
+                "got: {err:?}"
+            );
+        }
+        validate_plugins(&plugins(&[("allowed_content_types", "application/json")])).unwrap();
+    }
+
     // -- validate_endpoints tests --
 
     #[test]
@@ -2329,6 +2928,58 @@ mod tests {
         assert!(svc.get_route(&ctx, r.id).await.is_err());
     }
 
+    #[tokio::test]
+    async fn mutations_emit_config_changes() {
+        let svc = make_service();
+        let tenant = Uuid::new_v4();
+        let ctx = test_ctx(tenant);
+        let mut rx = svc.subscribe();
+
+        let u = svc
+            .create_upstream(&ctx, make_create_upstream_ip("openai"))
+            .await
+            .unwrap();
+        svc.update_upstream(&ctx, u.id, make_update_from_upstream(&u))
+            .await
+            .unwrap();
+        let r = svc
+            .create_route(&ctx, make_create_route(u.id))
+            .await
+            .unwrap();
+        svc.update_route(&ctx, r.id, make_update_from_route(&r))
+            .await
+            .unwrap();
+        svc.delete_route(&ctx, r.id).await.unwrap();
+        svc.delete_upstream(&ctx, u.id).await.unwrap();
+
+        let change = |kind, id| ConfigChange {
+            tenant_id: tenant,
+            kind,
+            id,
+        };
+        for expected in [
+            change(ConfigChangeKind::UpstreamCreated, u.id),
+            change(ConfigChangeKind::UpstreamUpdated, u.id),
+            change(ConfigChangeKind::RouteCreated, r.id),
+            change(ConfigChangeKind::RouteUpdated, r.id),
+            change(ConfigChangeKind::RouteDeleted, r.id),
+            change(ConfigChangeKind::UpstreamDeleted, u.id),
+        ] {
+            assert_eq!(rx.try_recv().unwrap(), expected);
+        }
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn failed_mutation_emits_nothing() {
+        let svc = make_service();
+        let ctx = test_ctx(Uuid::new_v4());
+        let mut rx = svc.subscribe();
+
+        assert!(svc.delete_route(&ctx, Uuid::new_v4()).await.is_err());
+        assert!(rx.try_recv().is_err());
+    }
+
     // -- Alias resolution tests --
 
     #[tokio::test]
@@ -2560,8 +3211,9 @@ mod tests {
     use std::collections::HashMap;
 
     use crate::domain::model::{
-        AuthConfig, CorsConfig, CorsHttpMethod, PluginBinding, PluginsConfig, RateLimitAlgorithm,
-        RateLimitConfig, RateLimitScope, RateLimitStrategy, SharingMode, SustainedRate, Window,
+        AuthConfig, CorsConfig, CorsHttpMethod, CostStrategy, PluginBinding, PluginsConfig,
+        RateLimitAlgorithm, RateLimitConfig, RateLimitScope, RateLimitStrategy, SharingMode,
+        SustainedRate, Window,
     };
 
     fn make_upstream(
@@ -2602,7 +3254,7 @@ mod tests {
             burst: None,
             scope: RateLimitScope::Tenant,
             strategy: RateLimitStrategy::Reject,
-            cost: 1,
+            cost: CostStrategy::Fixed(1),
         }
     }
 
@@ -2823,6 +3475,10 @@ mod tests {
             plugins: None,
             rate_limit: Some(make_rate_limit(SharingMode::Inherit, 50, Window::Minute)),
             cors: None,
+            sse_reconnect: None,
+            get_coalescing: None,
+            request_compression: None,
+            response_cache: None,
             tags: vec![],
             priority: 0,
             enabled: true,
@@ -3002,6 +3658,10 @@ mod tests {
             plugins: None,
             rate_limit: None,
             cors: Some(make_cors(SharingMode::Inherit, vec!["https://route.com"])),
+            sse_reconnect: None,
+            get_coalescing: None,
+            request_compression: None,
+            response_cache: None,
             tags: vec![],
             priority: 0,
             enabled: true,
@@ -3137,6 +3797,10 @@ mod tests {
                 expose_headers: vec![],
                 allow_credentials: true,
             }),
+            sse_reconnect: None,
+            get_coalescing: None,
+            request_compression: None,
+            response_cache: None,
             tags: vec![],
             priority: 0,
             enabled: true,
@@ -3619,6 +4283,10 @@ mod tests {
             plugins: None,
             rate_limit: None,
             cors: None,
+            sse_reconnect: None,
+            get_coalescing: None,
+            request_compression: None,
+            response_cache: None,
             tags: vec![],
             priority: 0,
             enabled: true,
@@ -3678,6 +4346,10 @@ mod tests {
             plugins: None,
             rate_limit: None,
             cors: None,
+            sse_reconnect: None,
+            get_coalescing: None,
+            request_compression: None,
+            response_cache: None,
             tags: vec![],
             priority: 0,
             enabled: true,
@@ -3706,6 +4378,10 @@ mod tests {
             plugins: None,
             rate_limit: None,
             cors: None,
+            sse_reconnect: None,
+            get_coalescing: None,
+            request_compression: None,
+            response_cache: None,
             tags: vec![],
             priority: 0,
             enabled: true,
@@ -3778,6 +4454,10 @@ mod tests {
             }),
             rate_limit: None,
             cors: None,
+            sse_reconnect: None,
+            get_coalescing: None,
+            request_compression: None,
+            response_cache: None,
             tags: vec![],
             priority: 0,
             enabled: true,
@@ -3812,6 +4492,10 @@ mod tests {
             plugins: None,
             rate_limit: Some(make_rate_limit(SharingMode::Private, 10, Window::Minute)),
             cors: None,
+            sse_reconnect: None,
+            get_coalescing: None,
+            request_compression: None,
+            response_cache: None,
             tags: vec![],
             priority: 0,
             enabled: true,
@@ -4254,6 +4938,7 @@ mod tests {
             cors: None,
             tags: vec![],
             enabled: true,
+            idempotency_key: None,
         };
         let err = svc.create_upstream(&ctx, req).await.unwrap_err();
         assert!(matches!(err, DomainError::Validation { .. }));
@@ -4411,6 +5096,7 @@ mod tests {
             cors: None,
             tags: vec![],
             enabled: true,
+            idempotency_key: None,
         };
         let u = svc.create_upstream(&ctx, req).await.unwrap();
         assert_eq!(u.alias, "vendor.com");
@@ -4447,6 +5133,7 @@ mod tests {
             cors: None,
             tags: vec![],
             enabled: true,
+            idempotency_key: None,
         };
         let u_a = svc.create_upstream(&ctx, req_a).await.unwrap();
         assert_eq!(u_a.alias, "vendor.com:8443");
@@ -4476,6 +5163,7 @@ mod tests {
             cors: None,
             tags: vec![],
             enabled: true,
+            idempotency_key: None,
         };
         let u_b = svc.create_upstream(&ctx, req_b).await.unwrap();
         assert_eq!(u_b.alias, "vendor.com:9443");
@@ -4516,6 +5204,7 @@ mod tests {
             cors: None,
             tags: vec![],
             enabled: true,
+            idempotency_key: None,
         };
 
         // Should fail because alias cannot be derived and none was provided.
@@ -4557,6 +5246,7 @@ mod tests {
             cors: None,
             tags: vec![],
             enabled: true,
+            idempotency_key: None,
         };
 
         let u = svc.create_upstream(&ctx, req).await.unwrap();
@@ -4623,6 +5313,10 @@ mod tests {
             plugins: None,
             rate_limit: None,
             cors: None,
+            sse_reconnect: None,
+            get_coalescing: None,
+            request_compression: None,
+            response_cache: None,
             tags: vec![],
             priority: 0,
             enabled: true,
@@ -4766,6 +5460,10 @@ mod tests {
             plugins: None,
             rate_limit: None,
             cors: None,
+            sse_reconnect: None,
+            get_coalescing: None,
+            request_compression: None,
+            response_cache: None,
             tags: vec![],
             priority: 0,
             enabled: true,
@@ -4787,6 +5485,10 @@ mod tests {
             plugins: None,
             rate_limit: None,
             cors: None,
+            sse_reconnect: None,
+            get_coalescing: None,
+            request_compression: None,
+            response_cache: None,
             tags: vec![],
             priority: 0,
             enabled: true,
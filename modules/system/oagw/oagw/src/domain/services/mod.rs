@@ -1,7 +1,7 @@
 pub(crate) mod client;
 pub(crate) mod management;
 
-pub(crate) use client::ServiceGatewayClientV1Facade;
+pub(crate) use client::{ServiceGatewayClientV1Facade, domain_err_to_sdk};
 pub(crate) use management::ControlPlaneServiceImpl;
 
 use async_trait::async_trait;
@@ -11,11 +11,12 @@ use oagw_sdk::Body;
 use uuid::Uuid;
 
 use std::net::SocketAddr;
+use std::time::Duration;
 
 use crate::domain::error::DomainError;
 use crate::domain::model::{
-    CreateRouteRequest, CreateUpstreamRequest, Endpoint, ListQuery, Route, UpdateRouteRequest,
-    UpdateUpstreamRequest, Upstream,
+    CreateRouteRequest, CreateUpstreamRequest, Endpoint, EndpointHealth, ListQuery,
+    RateLimitConfig, Route, TenantRateLimit, UpdateRouteRequest, UpdateUpstreamRequest, Upstream,
 };
 
 /// Result of endpoint selection: the domain endpoint plus an optional
@@ -28,6 +29,61 @@ pub(crate) struct SelectedEndpoint {
     pub resolved_addr: Option<SocketAddr>,
 }
 
+/// Kind of control-plane mutation carried by a [`ConfigChange`].
+#[domain_model]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ConfigChangeKind {
+    UpstreamCreated,
+    UpstreamUpdated,
+    /// Also implies deletion of all routes of the upstream (cascade).
+    UpstreamDeleted,
+    RouteCreated,
+    RouteUpdated,
+    RouteDeleted,
+    /// Tenant rate limit set or removed; `id` is the tenant ID.
+    TenantRateLimitChanged,
+}
+
+/// Notification emitted after every successful upstream/route mutation, so
+/// data-plane caches derived from configuration can be invalidated.
+#[domain_model]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ConfigChange {
+    pub tenant_id: Uuid,
+    pub kind: ConfigChangeKind,
+    /// Upstream, route or tenant ID, depending on `kind`.
+    pub id: Uuid,
+}
+
+/// Result of [`ControlPlaneService::create_upstream_idempotent`].
+#[domain_model]
+#[derive(Debug, Clone)]
+pub(crate) struct CreatedUpstream {
+    pub upstream: Upstream,
+    /// `true` when an earlier request with the same idempotency key created
+    /// the upstream and this one only returned it.
+    pub replayed: bool,
+}
+
+impl CreatedUpstream {
+    pub(crate) fn new(upstream: Upstream) -> Self {
+        Self {
+            upstream,
+            replayed: false,
+        }
+    }
+}
+
+/// A reason a create request would be rejected, found by dry-run validation.
+#[domain_model]
+#[derive(Debug)]
+pub(crate) struct ValidationIssue {
+    /// Request field the issue concerns, e.g. `server.endpoints` or `alias`.
+    pub field: String,
+    /// The error create would return for it.
+    pub error: DomainError,
+}
+
 /// Internal Control Plane service trait — configuration management and resolution.
 #[async_trait]
 pub(crate) trait ControlPlaneService: Send + Sync {
@@ -39,6 +95,15 @@ pub(crate) trait ControlPlaneService: Send + Sync {
         req: CreateUpstreamRequest,
     ) -> Result<Upstream, DomainError>;
 
+    /// `create_upstream` that also reports whether the upstream was returned
+    /// for a repeated `idempotency_key` rather than newly created. A key
+    /// still in use by a concurrent request yields `Conflict`.
+    async fn create_upstream_idempotent(
+        &self,
+        ctx: &SecurityContext,
+        req: CreateUpstreamRequest,
+    ) -> Result<CreatedUpstream, DomainError>;
+
     async fn get_upstream(&self, ctx: &SecurityContext, id: Uuid) -> Result<Upstream, DomainError>;
 
     async fn list_upstreams(
@@ -82,6 +147,51 @@ pub(crate) trait ControlPlaneService: Send + Sync {
 
     async fn delete_route(&self, ctx: &SecurityContext, id: Uuid) -> Result<(), DomainError>;
 
+    // -- Tenant rate limit --
+
+    /// Rate limit of the caller's tenant, if one is set.
+    async fn get_tenant_rate_limit(
+        &self,
+        ctx: &SecurityContext,
+    ) -> Result<Option<TenantRateLimit>, DomainError>;
+
+    /// Set or replace the rate limit of the caller's tenant.
+    async fn set_tenant_rate_limit(
+        &self,
+        ctx: &SecurityContext,
+        config: RateLimitConfig,
+        aggregate: bool,
+    ) -> Result<TenantRateLimit, DomainError>;
+
+    /// Remove the rate limit of the caller's tenant.
+    async fn delete_tenant_rate_limit(&self, ctx: &SecurityContext) -> Result<(), DomainError>;
+
+    // -- Credentials --
+
+    /// `secret_ref` names (bare, without `cred://`) referenced by the auth
+    /// config of the caller's upstreams, sorted and deduplicated. Only the
+    /// references are returned, never values.
+    async fn list_credential_refs(&self, ctx: &SecurityContext)
+    -> Result<Vec<String>, DomainError>;
+
+    // -- Dry-run validation --
+
+    /// Run the checks `create_upstream` performs without persisting anything.
+    /// Returns every issue found; empty means create would accept the request.
+    async fn validate_upstream(
+        &self,
+        ctx: &SecurityContext,
+        req: &CreateUpstreamRequest,
+    ) -> Result<Vec<ValidationIssue>, DomainError>;
+
+    /// Run the checks `create_route` performs without persisting anything.
+    /// Returns every issue found; empty means create would accept the request.
+    async fn validate_route(
+        &self,
+        ctx: &SecurityContext,
+        req: &CreateRouteRequest,
+    ) -> Result<Vec<ValidationIssue>, DomainError>;
+
     // -- Resolution --
 
     /// Combined upstream + route resolution for the proxy hot path.
@@ -96,6 +206,24 @@ pub(crate) trait ControlPlaneService: Send + Sync {
         method: &str,
         path: &str,
     ) -> Result<(Upstream, Route), DomainError>;
+
+    /// Resolve the upstream an alias proxies to, without route matching.
+    async fn resolve_upstream(
+        &self,
+        ctx: &SecurityContext,
+        alias: &str,
+    ) -> Result<Upstream, DomainError>;
+
+    // -- Change notification --
+
+    /// Subscribe to configuration changes. Slow subscribers may observe
+    /// `RecvError::Lagged` and should then drop whatever they cache.
+    fn subscribe(&self) -> tokio::sync::broadcast::Receiver<ConfigChange>;
+
+    // -- Health --
+
+    /// Check that configuration storage is reachable.
+    async fn check_ready(&self) -> Result<(), DomainError>;
 }
 
 /// Internal Data Plane service trait — proxy orchestration and plugin execution.
@@ -109,6 +237,19 @@ pub(crate) trait DataPlaneService: Send + Sync {
 
     /// Remove a rate-limit bucket by key (e.g. `"upstream:{id}"` or `"route:{id}"`).
     fn remove_rate_limit_key(&self, key: &str);
+
+    /// Refuse new proxy requests and wait up to `timeout` for in-flight ones,
+    /// including their response bodies, to finish. Returns the number of
+    /// requests still in flight.
+    async fn drain(&self, timeout: Duration) -> usize;
+
+    /// Active health-check state of every endpoint of the upstream `alias`
+    /// resolves to, in configured endpoint order.
+    async fn upstream_health(
+        &self,
+        ctx: &SecurityContext,
+        alias: &str,
+    ) -> Result<Vec<EndpointHealth>, DomainError>;
 }
 
 /// Endpoint selection abstraction for multi-endpoint load balancing.
@@ -122,4 +263,35 @@ pub(crate) trait EndpointSelector: Send + Sync {
 
     /// Invalidate cached state for the given upstream (called on CRUD).
     fn invalidate(&self, upstream_id: Uuid);
+
+    /// Health of each endpoint in `endpoints`, in the same order. Selectors
+    /// without active health checking report every endpoint as unknown.
+    async fn health(&self, _upstream_id: Uuid, endpoints: &[Endpoint]) -> Vec<EndpointHealth> {
+        endpoints
+            .iter()
+            .cloned()
+            .map(EndpointHealth::unknown)
+            .collect()
+    }
+}
+
+/// An external dependency that must be reachable for the gateway to serve
+/// traffic (e.g. a shared rate-limit store). Probed by `GET /oagw/v1/ready`.
+#[async_trait]
+pub trait ReadinessCheck: Send + Sync {
+    /// Dependency name reported when the probe fails.
+    fn name(&self) -> &str;
+
+    /// Probe the dependency; `Err` carries the failure reason.
+    async fn check(&self) -> Result<(), String>;
+}
+
+/// Control over the data plane's resolved-secret cache.
+pub(crate) trait CredentialResolver: Send + Sync {
+    /// Evict cached values of `secret_ref` (bare name, without `cred://`)
+    /// for every tenant, so the next proxied request re-fetches it. Lookups
+    /// already in flight complete with the value they fetched.
+    ///
+    /// Returns the number of evicted cache entries.
+    fn invalidate(&self, secret_ref: &str) -> usize;
 }
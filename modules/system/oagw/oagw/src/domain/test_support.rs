@@ -1,16 +1,23 @@
 //! Test utilities for CP and DP integration tests.
 
 use std::collections::HashMap;
+use std::net::SocketAddr;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
-use crate::config::TokenCacheConfig;
+use crate::config::{ForwardProxyConfig, TokenCacheConfig};
+use crate::domain::metrics::Metrics;
 use crate::domain::services::{
     ControlPlaneService, ControlPlaneServiceImpl, DataPlaneService, EndpointSelector,
     ServiceGatewayClientV1Facade,
 };
+use crate::infra::credential::{CachingCredentialResolver, CredStoreBackend};
 use crate::infra::proxy::DataPlaneServiceImpl;
-use crate::infra::storage::{InMemoryRouteRepo, InMemoryUpstreamRepo};
+use crate::infra::proxy::forward_proxy::ForwardProxy;
+use crate::infra::proxy::pingora_proxy::{ResolveOverrides, resolve_overrides};
+use crate::infra::storage::{
+    InMemoryRouteRepo, InMemoryTenantRateLimitRepo, InMemoryTransactions, InMemoryUpstreamRepo,
+};
 use async_trait::async_trait;
 use authz_resolver_sdk::{
     AuthZResolverClient, AuthZResolverError, EvaluationRequest, EvaluationResponse,
@@ -129,7 +136,7 @@ impl AuthZResolverClient for CapturingAuthZResolverClient {
 /// Mock `CredStoreClientV1` for tests. Stores secrets in memory keyed by
 /// the bare secret name (without `cred://` prefix).
 pub struct MockCredStoreClient {
-    store: HashMap<String, Vec<u8>>,
+    store: Mutex<HashMap<String, Vec<u8>>>,
 }
 
 impl MockCredStoreClient {
@@ -145,15 +152,26 @@ impl MockCredStoreClient {
                 (key, v.into_bytes())
             })
             .collect();
-        Self { store }
+        Self {
+            store: Mutex::new(store),
+        }
     }
 
     /// Create an empty mock (all lookups return `Ok(None)`).
     pub fn empty() -> Self {
         Self {
-            store: HashMap::new(),
+            store: Mutex::new(HashMap::new()),
         }
     }
+
+    /// Insert or replace a secret (simulates rotation in the backing store).
+    pub fn set_secret(&self, key: &str, value: &str) {
+        let key = key.strip_prefix("cred://").unwrap_or(key).to_string();
+        self.store
+            .lock()
+            .unwrap()
+            .insert(key, value.as_bytes().to_vec());
+    }
 }
 
 #[async_trait]
@@ -163,12 +181,17 @@ impl CredStoreClientV1 for MockCredStoreClient {
         _ctx: &SecurityContext,
         key: &SecretRef,
     ) -> Result<Option<GetSecretResponse>, CredStoreError> {
-        Ok(self.store.get(key.as_ref()).map(|v| GetSecretResponse {
-            value: SecretValue::new(v.clone()),
-            owner_tenant_id: CredstoreTenantId::nil(),
-            sharing: SharingMode::default(),
-            is_inherited: false,
-        }))
+        Ok(self
+            .store
+            .lock()
+            .unwrap()
+            .get(key.as_ref())
+            .map(|v| GetSecretResponse {
+                value: SecretValue::new(v.clone()),
+                owner_tenant_id: CredstoreTenantId::nil(),
+                sharing: SharingMode::default(),
+                is_inherited: false,
+            }))
     }
 }
 
@@ -356,13 +379,14 @@ impl TenantResolverClient for MockTenantResolverClient {
 
 /// Re-export plugin ID constants for test configurations.
 pub use crate::domain::gts_helpers::{
-    APIKEY_AUTH_PLUGIN_ID, OAUTH2_CLIENT_CRED_AUTH_PLUGIN_ID,
+    APIKEY_AUTH_PLUGIN_ID, KEY_POOL_AUTH_PLUGIN_ID, OAUTH2_CLIENT_CRED_AUTH_PLUGIN_ID,
     OAUTH2_CLIENT_CRED_BASIC_AUTH_PLUGIN_ID,
 };
 
 /// Builder for a fully-wired Control Plane test environment.
 pub struct TestCpBuilder {
     credentials: Vec<(String, String)>,
+    credstore: Option<Arc<MockCredStoreClient>>,
     tenant_resolver: Option<MockTenantResolverClient>,
 }
 
@@ -371,6 +395,7 @@ impl TestCpBuilder {
     pub fn new() -> Self {
         Self {
             credentials: Vec::new(),
+            credstore: None,
             tenant_resolver: None,
         }
     }
@@ -382,6 +407,14 @@ impl TestCpBuilder {
         self
     }
 
+    /// Use a caller-owned mock credstore (e.g. to rotate secrets mid-test).
+    /// Takes precedence over [`Self::with_credentials`].
+    #[must_use]
+    pub fn with_credstore(mut self, credstore: Arc<MockCredStoreClient>) -> Self {
+        self.credstore = Some(credstore);
+        self
+    }
+
     /// Override the tenant resolver (for hierarchy tests).
     #[must_use]
     pub fn with_tenant_resolver(mut self, resolver: MockTenantResolverClient) -> Self {
@@ -394,17 +427,25 @@ impl TestCpBuilder {
     pub(crate) fn build_and_register(self, hub: &ClientHub) -> Arc<dyn ControlPlaneService> {
         let upstream_repo = Arc::new(InMemoryUpstreamRepo::new());
         let route_repo = Arc::new(InMemoryRouteRepo::new());
+        let transactions = Arc::new(InMemoryTransactions::new(
+            Arc::clone(&upstream_repo),
+            Arc::clone(&route_repo),
+        ));
         let tenant_resolver: Arc<dyn TenantResolverClient> = Arc::new(
             self.tenant_resolver
                 .unwrap_or_else(MockTenantResolverClient::single_tenant),
         );
-        let credstore: Arc<dyn CredStoreClientV1> =
-            Arc::new(MockCredStoreClient::with_secrets(self.credentials));
+        let credstore: Arc<dyn CredStoreClientV1> = match self.credstore {
+            Some(credstore) => credstore,
+            None => Arc::new(MockCredStoreClient::with_secrets(self.credentials)),
+        };
         hub.register::<dyn CredStoreClientV1>(credstore.clone());
 
         let cp: Arc<dyn ControlPlaneService> = Arc::new(ControlPlaneServiceImpl::new(
             upstream_repo,
             route_repo,
+            Arc::new(InMemoryTenantRateLimitRepo::new()),
+            transactions,
             tenant_resolver,
             allow_all_enforcer(),
             credstore,
@@ -428,13 +469,19 @@ pub struct TestDpBuilder {
     request_timeout: Option<Duration>,
     authz_client: Option<Arc<dyn AuthZResolverClient>>,
     backend_selector: Option<Arc<dyn EndpointSelector>>,
+    credential_resolver: Option<Arc<CachingCredentialResolver>>,
     max_body_size: Option<usize>,
     skip_upstream_tls_verify: bool,
+    upstream_ca_file: Option<String>,
+    forward_proxy: ForwardProxyConfig,
+    resolve_overrides: ResolveOverrides,
     token_http_config: Option<modkit_http::HttpClientConfig>,
     token_cache_config: TokenCacheConfig,
     websocket_idle_timeout: Option<Duration>,
     websocket_close_timeout: Option<Duration>,
     websocket_max_frame_size: Option<usize>,
+    metrics: Option<Arc<dyn Metrics>>,
+    health_check_interval: Option<Duration>,
 }
 
 impl TestDpBuilder {
@@ -444,13 +491,19 @@ impl TestDpBuilder {
             request_timeout: None,
             authz_client: None,
             backend_selector: None,
+            credential_resolver: None,
             max_body_size: None,
             skip_upstream_tls_verify: false,
+            upstream_ca_file: None,
+            forward_proxy: ForwardProxyConfig::default(),
+            resolve_overrides: ResolveOverrides::default(),
             token_http_config: None,
             token_cache_config: TokenCacheConfig::default(),
             websocket_idle_timeout: None,
             websocket_close_timeout: None,
             websocket_max_frame_size: None,
+            metrics: None,
+            health_check_interval: None,
         }
     }
 
@@ -482,6 +535,28 @@ impl TestDpBuilder {
         self
     }
 
+    /// Trust the CA certificates in this PEM file (instead of the platform
+    /// roots) when verifying HTTPS upstreams.
+    #[must_use]
+    pub fn with_upstream_ca_file(mut self, path: impl Into<String>) -> Self {
+        self.upstream_ca_file = Some(path.into());
+        self
+    }
+
+    /// Tunnel upstream connections through a forward proxy.
+    #[must_use]
+    pub fn with_forward_proxy(mut self, config: ForwardProxyConfig) -> Self {
+        self.forward_proxy = config;
+        self
+    }
+
+    /// Resolve matching upstream hosts to fixed addresses instead of DNS.
+    #[must_use]
+    pub fn with_resolve_overrides(mut self, overrides: HashMap<String, SocketAddr>) -> Self {
+        self.resolve_overrides = resolve_overrides(&overrides);
+        self
+    }
+
     /// Inject a shared `EndpointSelector` so callers can hold the same
     /// instance that the DP service uses (e.g. for `invalidate()` calls).
     #[must_use]
@@ -490,6 +565,17 @@ impl TestDpBuilder {
         self
     }
 
+    /// Inject a shared credential cache so callers can hold the same
+    /// instance that the DP service uses (e.g. for `invalidate()` calls).
+    #[must_use]
+    pub(crate) fn with_credential_resolver(
+        mut self,
+        resolver: Arc<CachingCredentialResolver>,
+    ) -> Self {
+        self.credential_resolver = Some(resolver);
+        self
+    }
+
     /// Override the HTTP client config for OAuth2 token endpoints.
     /// Pass `HttpClientConfig::for_testing()` to allow plain HTTP in tests.
     #[must_use]
@@ -526,6 +612,37 @@ impl TestDpBuilder {
         self
     }
 
+    /// Report proxy metrics to `metrics`.
+    #[must_use]
+    pub fn with_metrics(mut self, metrics: Arc<dyn Metrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Override the interval between endpoint health checks.
+    #[must_use]
+    pub fn with_health_check_interval(mut self, interval: Duration) -> Self {
+        self.health_check_interval = Some(interval);
+        self
+    }
+
+    /// The forward proxy for this configuration.
+    fn forward_proxy(&self) -> Option<ForwardProxy> {
+        ForwardProxy::from_config(&self.forward_proxy, |name| std::env::var(name).ok())
+            .expect("valid forward proxy config")
+    }
+
+    /// The default endpoint selector for this configuration.
+    fn endpoint_selector(&self) -> crate::infra::proxy::pingora_proxy::PingoraEndpointSelector {
+        let selector = crate::infra::proxy::pingora_proxy::PingoraEndpointSelector::new()
+            .with_resolve_overrides(self.resolve_overrides.clone())
+            .with_forward_proxy(self.forward_proxy());
+        match self.health_check_interval {
+            Some(interval) => selector.with_health_check_interval(interval),
+            None => selector,
+        }
+    }
+
     /// Fetch `CredStoreClientV1` from the hub, create a DP service with
     /// the given CP, and return the trait object.
     pub(crate) fn build_and_register(
@@ -533,35 +650,41 @@ impl TestDpBuilder {
         hub: &ClientHub,
         cp: Arc<dyn ControlPlaneService>,
     ) -> Arc<dyn DataPlaneService> {
-        let credstore = hub
-            .get::<dyn CredStoreClientV1>()
-            .expect("CredStoreClientV1 must be registered before building DP");
+        let backend_selector: Arc<dyn EndpointSelector> = self
+            .backend_selector
+            .clone()
+            .unwrap_or_else(|| Arc::new(self.endpoint_selector()));
+        let forward_proxy = self.forward_proxy();
+        let credentials = self
+            .credential_resolver
+            .unwrap_or_else(|| credential_resolver(hub));
 
         let authz_client = self
             .authz_client
             .unwrap_or_else(|| Arc::new(MockAuthZResolverClient));
         let policy_enforcer = PolicyEnforcer::new(authz_client);
 
-        let server_conf = Arc::new(pingora_core::server::configuration::ServerConf::default());
+        let server_conf = Arc::new(pingora_core::server::configuration::ServerConf {
+            ca_file: self.upstream_ca_file,
+            ..Default::default()
+        });
         let pingora_proxy = crate::infra::proxy::pingora_proxy::PingoraProxy::new(
             Duration::from_secs(10),
             Duration::from_secs(30),
             Duration::from_secs(3600),
         )
-        .with_skip_upstream_tls_verify(self.skip_upstream_tls_verify);
+        .with_skip_upstream_tls_verify(self.skip_upstream_tls_verify)
+        .with_forward_proxy(forward_proxy)
+        .with_resolve_overrides(self.resolve_overrides.clone());
         let proxy = Arc::new(crate::infra::proxy::pingora_proxy::new_http_proxy(
             &server_conf,
             pingora_proxy,
         ));
 
-        let backend_selector: Arc<dyn EndpointSelector> =
-            self.backend_selector.unwrap_or_else(|| {
-                Arc::new(crate::infra::proxy::pingora_proxy::PingoraEndpointSelector::new())
-            });
-
+        let changes = cp.subscribe();
         let mut svc = DataPlaneServiceImpl::new(
             cp,
-            credstore,
+            credentials,
             policy_enforcer,
             self.token_http_config,
             self.token_cache_config,
@@ -584,8 +707,13 @@ impl TestDpBuilder {
         if let Some(size) = self.websocket_max_frame_size {
             svc = svc.with_websocket_max_frame_size(Some(size));
         }
+        if let Some(metrics) = self.metrics {
+            svc = svc.with_metrics(metrics);
+        }
 
-        Arc::new(svc)
+        let svc = Arc::new(svc);
+        tokio::spawn(svc.clone().watch_config_changes(changes));
+        svc
     }
 }
 
@@ -595,6 +723,17 @@ impl Default for TestDpBuilder {
     }
 }
 
+/// Wrap the hub's `CredStoreClientV1` in the data-plane credential cache.
+fn credential_resolver(hub: &ClientHub) -> Arc<CachingCredentialResolver> {
+    let credstore = hub
+        .get::<dyn CredStoreClientV1>()
+        .expect("CredStoreClientV1 must be registered before building DP");
+    Arc::new(CachingCredentialResolver::new(
+        Arc::new(CredStoreBackend::new(credstore)),
+        Duration::from_secs(60),
+    ))
+}
+
 /// Test harness providing both an `AppState` (for REST handlers) and a
 /// `ServiceGatewayClientV1` facade (for programmatic data setup in tests).
 pub struct TestAppState {
@@ -612,11 +751,12 @@ pub fn build_test_app_state(
     cp_builder: TestCpBuilder,
     dp_builder: TestDpBuilder,
 ) -> TestAppState {
-    let backend_selector: Arc<dyn EndpointSelector> =
-        Arc::new(crate::infra::proxy::pingora_proxy::PingoraEndpointSelector::new());
+    let backend_selector: Arc<dyn EndpointSelector> = Arc::new(dp_builder.endpoint_selector());
     let cp = cp_builder.build_and_register(hub);
+    let credentials = credential_resolver(hub);
     let dp = dp_builder
         .with_backend_selector(backend_selector.clone())
+        .with_credential_resolver(credentials.clone())
         .build_and_register(hub, cp.clone());
     let facade: Arc<dyn ServiceGatewayClientV1> =
         Arc::new(ServiceGatewayClientV1Facade::new(cp.clone(), dp.clone()));
@@ -626,6 +766,8 @@ pub fn build_test_app_state(
             cp,
             dp,
             backend_selector,
+            credentials,
+            readiness_checks: Vec::new(),
             config: crate::config::RuntimeConfig {
                 max_body_size_bytes: 100 * 1024 * 1024, // 100 MB default for tests
                 websocket_idle_timeout_secs: 300,
@@ -42,9 +42,10 @@ pub fn oagw_gts_entities() -> Vec<Value> {
         // -- Protocol instances (2) --
         instance_entity(HTTP_PROTOCOL_ID, "HTTP protocol"),
         instance_entity(GRPC_PROTOCOL_ID, "gRPC protocol"),
-        // -- Auth plugin instances (6) --
+        // -- Auth plugin instances (7) --
         instance_entity(NOOP_AUTH_PLUGIN_ID, "No-op (passthrough) auth"),
         instance_entity(APIKEY_AUTH_PLUGIN_ID, "API key injection"),
+        instance_entity(KEY_POOL_AUTH_PLUGIN_ID, "Rotating API key pool"),
         instance_entity(BASIC_AUTH_PLUGIN_ID, "HTTP Basic auth"),
         instance_entity(BEARER_AUTH_PLUGIN_ID, "Bearer token"),
         instance_entity(
@@ -55,13 +56,14 @@ pub fn oagw_gts_entities() -> Vec<Value> {
             OAUTH2_CLIENT_CRED_BASIC_AUTH_PLUGIN_ID,
             "OAuth2 client credentials (Basic)",
         ),
-        // -- Guard plugin instances (3) --
+        // -- Guard plugin instances (4) --
         instance_entity(TIMEOUT_GUARD_PLUGIN_ID, "Request timeout"),
         instance_entity(CORS_GUARD_PLUGIN_ID, "CORS handling"),
         instance_entity(
             REQUIRED_HEADERS_GUARD_PLUGIN_ID,
             "Required headers enforcement",
         ),
+        instance_entity(CONTENT_TYPE_GUARD_PLUGIN_ID, "Content-Type allowlist"),
         // -- Transform plugin instances (3) --
         instance_entity(LOGGING_TRANSFORM_PLUGIN_ID, "Request/response logging"),
         instance_entity(METRICS_TRANSFORM_PLUGIN_ID, "Prometheus metrics"),
@@ -79,12 +81,12 @@ mod tests {
     }
 
     #[test]
-    fn catalog_returns_exactly_21_entities() {
+    fn catalog_returns_exactly_23_entities() {
         let entities = oagw_gts_entities();
         assert_eq!(
             entities.len(),
-            21,
-            "expected 21 entities (7 schemas + 14 instances)"
+            23,
+            "expected 23 entities (7 schemas + 16 instances)"
         );
     }
 
@@ -128,7 +130,7 @@ mod tests {
             .collect();
 
         assert_eq!(schemas.len(), 7, "expected 7 schemas");
-        assert_eq!(instances.len(), 14, "expected 14 instances");
+        assert_eq!(instances.len(), 16, "expected 16 instances");
     }
 
     #[test]
@@ -0,0 +1,91 @@
+use async_trait::async_trait;
+use credstore_sdk::{CredStoreError, GetSecretResponse, SecretRef, SecretValue};
+use modkit_security::SecurityContext;
+
+use super::{CredentialBackend, tenant_owned};
+
+/// Backend reading secrets from process environment variables.
+///
+/// `secret_ref` `openai-key` with prefix `OAGW_SECRET_` maps to
+/// `OAGW_SECRET_OPENAI_KEY`: upper-cased, `-` replaced by `_`. Values are
+/// shared by all tenants, so this backend suits single-tenant deployments
+/// and local development.
+pub struct EnvCredentialBackend {
+    prefix: String,
+}
+
+impl EnvCredentialBackend {
+    #[must_use]
+    pub fn new(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+        }
+    }
+
+    fn var_name(&self, secret_ref: &SecretRef) -> String {
+        format!(
+            "{}{}",
+            self.prefix,
+            secret_ref.as_ref().to_ascii_uppercase().replace('-', "_")
+        )
+    }
+}
+
+#[async_trait]
+impl CredentialBackend for EnvCredentialBackend {
+    async fn fetch(
+        &self,
+        ctx: &SecurityContext,
+        secret_ref: &SecretRef,
+    ) -> Result<Option<GetSecretResponse>, CredStoreError> {
+        match std::env::var(self.var_name(secret_ref)) {
+            Ok(value) => Ok(Some(tenant_owned(ctx, SecretValue::from(value)))),
+            Err(std::env::VarError::NotPresent) => Ok(None),
+            Err(std::env::VarError::NotUnicode(_)) => Err(CredStoreError::internal(format!(
+                "environment variable for secret '{}' is not valid UTF-8",
+                secret_ref.as_ref()
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn ctx() -> SecurityContext {
+        SecurityContext::builder()
+            .subject_tenant_id(Uuid::new_v4())
+            .subject_id(Uuid::new_v4())
+            .build()
+            .expect("test security context")
+    }
+
+    #[test]
+    fn var_name_is_prefixed_and_normalized() {
+        let backend = EnvCredentialBackend::new("OAGW_SECRET_");
+        let key = SecretRef::new("openai-key_v2").unwrap();
+        assert_eq!(backend.var_name(&key), "OAGW_SECRET_OPENAI_KEY_V2");
+    }
+
+    #[tokio::test]
+    async fn missing_variable_is_none() {
+        let backend = EnvCredentialBackend::new("OAGW_TEST_ENV_BACKEND_MISSING_");
+        let key = SecretRef::new("nope").unwrap();
+        assert!(backend.fetch(&ctx(), &key).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn present_variable_is_returned() {
+        // PATH is set in every test environment; avoids mutating process env.
+        let backend = EnvCredentialBackend::new("");
+        let key = SecretRef::new("path").unwrap();
+        let resp = backend.fetch(&ctx(), &key).await.unwrap().unwrap();
+        assert_eq!(
+            resp.value.as_bytes(),
+            std::env::var("PATH").unwrap().as_bytes()
+        );
+    }
+}
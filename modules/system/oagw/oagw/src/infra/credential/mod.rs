@@ -0,0 +1,70 @@
+//! Secret resolution for auth plugins.
+//!
+//! Plugins resolve `cred://` references through `Arc<dyn CredStoreClientV1>`.
+//! In the module that handle is a [`CachingCredentialResolver`]: a TTL cache with
+//! single-flight de-duplication in front of a pluggable [`CredentialBackend`]
+//! (the credstore module, process environment, or `HashiCorp` Vault).
+
+mod env;
+mod resolver;
+mod vault;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use credstore_sdk::{
+    CredStoreClientV1, CredStoreError, GetSecretResponse, SecretRef, SecretValue, SharingMode,
+    TenantId,
+};
+use modkit_security::SecurityContext;
+
+pub use env::EnvCredentialBackend;
+pub use resolver::CachingCredentialResolver;
+pub use vault::VaultCredentialBackend;
+
+/// Source of secret values, looked up by `secret_ref`.
+///
+/// `Ok(None)` means the secret does not exist or is not accessible;
+/// `Err` is reserved for infrastructure failures.
+#[async_trait]
+pub trait CredentialBackend: Send + Sync {
+    async fn fetch(
+        &self,
+        ctx: &SecurityContext,
+        secret_ref: &SecretRef,
+    ) -> Result<Option<GetSecretResponse>, CredStoreError>;
+}
+
+/// Backend delegating to the credstore module (tenant-aware, hierarchical).
+pub struct CredStoreBackend {
+    credstore: Arc<dyn CredStoreClientV1>,
+}
+
+impl CredStoreBackend {
+    #[must_use]
+    pub fn new(credstore: Arc<dyn CredStoreClientV1>) -> Self {
+        Self { credstore }
+    }
+}
+
+#[async_trait]
+impl CredentialBackend for CredStoreBackend {
+    async fn fetch(
+        &self,
+        ctx: &SecurityContext,
+        secret_ref: &SecretRef,
+    ) -> Result<Option<GetSecretResponse>, CredStoreError> {
+        self.credstore.get(ctx, secret_ref).await
+    }
+}
+
+/// Wrap a value from a tenant-agnostic backend (env, Vault) as if it were
+/// owned by the requesting tenant.
+fn tenant_owned(ctx: &SecurityContext, value: SecretValue) -> GetSecretResponse {
+    GetSecretResponse {
+        value,
+        owner_tenant_id: TenantId(ctx.subject_tenant_id()),
+        sharing: SharingMode::Tenant,
+        is_inherited: false,
+    }
+}
@@ -0,0 +1,287 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use credstore_sdk::{
+    CredStoreClientV1, CredStoreError, GetSecretResponse, SecretRef, SecretValue, SharingMode,
+    TenantId,
+};
+use dashmap::DashMap;
+use modkit_security::SecurityContext;
+use uuid::Uuid;
+
+use super::CredentialBackend;
+use crate::domain::services::CredentialResolver;
+
+/// Cache key: secrets resolve per tenant (hierarchical credstore lookup), so
+/// the requesting tenant is part of the key to prevent cross-tenant reuse.
+type CacheKey = (Uuid, SecretRef);
+
+/// Per-key slot. The async mutex gives single-flight semantics: concurrent
+/// lookups of the same key wait for the first fetch instead of issuing their own.
+type Slot = Arc<tokio::sync::Mutex<Option<CachedSecret>>>;
+
+struct CachedSecret {
+    value: SecretValue,
+    owner_tenant_id: TenantId,
+    sharing: SharingMode,
+    is_inherited: bool,
+    fetched_at: Instant,
+}
+
+impl CachedSecret {
+    fn from_response(resp: GetSecretResponse) -> Self {
+        Self {
+            value: resp.value,
+            owner_tenant_id: resp.owner_tenant_id,
+            sharing: resp.sharing,
+            is_inherited: resp.is_inherited,
+            fetched_at: Instant::now(),
+        }
+    }
+
+    fn to_response(&self) -> GetSecretResponse {
+        GetSecretResponse {
+            value: SecretValue::new(self.value.as_bytes().to_vec()),
+            owner_tenant_id: self.owner_tenant_id,
+            sharing: self.sharing,
+            is_inherited: self.is_inherited,
+        }
+    }
+}
+
+/// Caching secret resolver used by auth plugins.
+///
+/// Successful lookups are cached for `ttl`; misses and errors are not cached.
+/// A zero `ttl` disables caching and every lookup goes to the backend.
+pub struct CachingCredentialResolver {
+    backend: Arc<dyn CredentialBackend>,
+    ttl: Duration,
+    slots: DashMap<CacheKey, Slot>,
+}
+
+impl CachingCredentialResolver {
+    #[must_use]
+    pub fn new(backend: Arc<dyn CredentialBackend>, ttl: Duration) -> Self {
+        Self {
+            backend,
+            ttl,
+            slots: DashMap::new(),
+        }
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &SecurityContext,
+        secret_ref: &SecretRef,
+    ) -> Result<Option<GetSecretResponse>, CredStoreError> {
+        if self.ttl.is_zero() {
+            return self.backend.fetch(ctx, secret_ref).await;
+        }
+
+        let key = (ctx.subject_tenant_id(), secret_ref.clone());
+        let slot = self.slots.entry(key.clone()).or_default().clone();
+        let mut cached = slot.lock().await;
+
+        if let Some(entry) = cached.as_ref()
+            && entry.fetched_at.elapsed() < self.ttl
+        {
+            return Ok(Some(entry.to_response()));
+        }
+
+        match self.backend.fetch(ctx, secret_ref).await? {
+            Some(resp) => {
+                let entry = CachedSecret::from_response(resp);
+                let out = entry.to_response();
+                *cached = Some(entry);
+                Ok(Some(out))
+            }
+            None => {
+                *cached = None;
+                drop(cached);
+                self.slots.remove_if(&key, |_, s| Arc::ptr_eq(s, &slot));
+                Ok(None)
+            }
+        }
+    }
+}
+
+impl CredentialResolver for CachingCredentialResolver {
+    fn invalidate(&self, secret_ref: &str) -> usize {
+        let before = self.slots.len();
+        self.slots.retain(|(_, r), _| r.as_ref() != secret_ref);
+        before.saturating_sub(self.slots.len())
+    }
+}
+
+#[async_trait]
+impl CredStoreClientV1 for CachingCredentialResolver {
+    async fn get(
+        &self,
+        ctx: &SecurityContext,
+        key: &SecretRef,
+    ) -> Result<Option<GetSecretResponse>, CredStoreError> {
+        self.resolve(ctx, key).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    /// Backend that counts fetches and returns `"v{n}"` for the n-th fetch.
+    struct CountingBackend {
+        fetches: AtomicUsize,
+        delay: Duration,
+    }
+
+    impl CountingBackend {
+        fn new(delay: Duration) -> Arc<Self> {
+            Arc::new(Self {
+                fetches: AtomicUsize::new(0),
+                delay,
+            })
+        }
+
+        fn fetches(&self) -> usize {
+            self.fetches.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl CredentialBackend for CountingBackend {
+        async fn fetch(
+            &self,
+            _ctx: &SecurityContext,
+            secret_ref: &SecretRef,
+        ) -> Result<Option<GetSecretResponse>, CredStoreError> {
+            let n = self.fetches.fetch_add(1, Ordering::SeqCst) + 1;
+            tokio::time::sleep(self.delay).await;
+            if secret_ref.as_ref() == "missing" {
+                return Ok(None);
+            }
+            Ok(Some(GetSecretResponse {
+                value: SecretValue::from(format!("v{n}")),
+                owner_tenant_id: TenantId::nil(),
+                sharing: SharingMode::Tenant,
+                is_inherited: false,
+            }))
+        }
+    }
+
+    fn ctx(tenant: Uuid) -> SecurityContext {
+        SecurityContext::builder()
+            .subject_tenant_id(tenant)
+            .subject_id(Uuid::new_v4())
+            .build()
+            .expect("test security context")
+    }
+
+    async fn value(
+        resolver: &CachingCredentialResolver,
+        ctx: &SecurityContext,
+        key: &str,
+    ) -> String {
+        let resp = resolver
+            .get(ctx, &SecretRef::new(key).unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        String::from_utf8(resp.value.as_bytes().to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn cache_hit_avoids_refetch() {
+        let backend = CountingBackend::new(Duration::ZERO);
+        let resolver = CachingCredentialResolver::new(backend.clone(), Duration::from_secs(60));
+        let ctx = ctx(Uuid::new_v4());
+
+        assert_eq!(value(&resolver, &ctx, "openai-key").await, "v1");
+        assert_eq!(value(&resolver, &ctx, "openai-key").await, "v1");
+        assert_eq!(backend.fetches(), 1);
+    }
+
+    #[tokio::test]
+    async fn ttl_expiry_triggers_refresh() {
+        let backend = CountingBackend::new(Duration::ZERO);
+        let resolver = CachingCredentialResolver::new(backend.clone(), Duration::from_millis(50));
+        let ctx = ctx(Uuid::new_v4());
+
+        assert_eq!(value(&resolver, &ctx, "openai-key").await, "v1");
+        tokio::time::sleep(Duration::from_millis(80)).await;
+        assert_eq!(value(&resolver, &ctx, "openai-key").await, "v2");
+        assert_eq!(backend.fetches(), 2);
+    }
+
+    #[tokio::test]
+    async fn concurrent_lookups_are_single_flight() {
+        let backend = CountingBackend::new(Duration::from_millis(50));
+        let resolver = Arc::new(CachingCredentialResolver::new(
+            backend.clone(),
+            Duration::from_secs(60),
+        ));
+        let ctx = ctx(Uuid::new_v4());
+
+        let lookups: Vec<_> = (0..8)
+            .map(|_| {
+                let resolver = resolver.clone();
+                let ctx = ctx.clone();
+                tokio::spawn(async move { value(&resolver, &ctx, "openai-key").await })
+            })
+            .collect();
+        for handle in lookups {
+            assert_eq!(handle.await.unwrap(), "v1");
+        }
+        assert_eq!(backend.fetches(), 1);
+    }
+
+    #[tokio::test]
+    async fn cache_is_partitioned_by_tenant() {
+        let backend = CountingBackend::new(Duration::ZERO);
+        let resolver = CachingCredentialResolver::new(backend.clone(), Duration::from_secs(60));
+
+        assert_eq!(value(&resolver, &ctx(Uuid::new_v4()), "k").await, "v1");
+        assert_eq!(value(&resolver, &ctx(Uuid::new_v4()), "k").await, "v2");
+    }
+
+    #[tokio::test]
+    async fn misses_are_not_cached() {
+        let backend = CountingBackend::new(Duration::ZERO);
+        let resolver = CachingCredentialResolver::new(backend.clone(), Duration::from_secs(60));
+        let ctx = ctx(Uuid::new_v4());
+        let key = SecretRef::new("missing").unwrap();
+
+        assert!(resolver.get(&ctx, &key).await.unwrap().is_none());
+        assert!(resolver.get(&ctx, &key).await.unwrap().is_none());
+        assert_eq!(backend.fetches(), 2);
+        assert!(resolver.slots.is_empty());
+    }
+
+    #[tokio::test]
+    async fn invalidate_evicts_ref_for_all_tenants() {
+        let backend = CountingBackend::new(Duration::ZERO);
+        let resolver = CachingCredentialResolver::new(backend.clone(), Duration::from_secs(60));
+        let (a, b) = (ctx(Uuid::new_v4()), ctx(Uuid::new_v4()));
+
+        assert_eq!(value(&resolver, &a, "k").await, "v1");
+        assert_eq!(value(&resolver, &b, "k").await, "v2");
+        assert_eq!(value(&resolver, &a, "other").await, "v3");
+
+        assert_eq!(resolver.invalidate("k"), 2);
+        assert_eq!(value(&resolver, &a, "k").await, "v4");
+        assert_eq!(value(&resolver, &a, "other").await, "v3");
+        assert_eq!(resolver.invalidate("unknown"), 0);
+    }
+
+    #[tokio::test]
+    async fn zero_ttl_disables_caching() {
+        let backend = CountingBackend::new(Duration::ZERO);
+        let resolver = CachingCredentialResolver::new(backend.clone(), Duration::ZERO);
+        let ctx = ctx(Uuid::new_v4());
+
+        assert_eq!(value(&resolver, &ctx, "k").await, "v1");
+        assert_eq!(value(&resolver, &ctx, "k").await, "v2");
+    }
+}
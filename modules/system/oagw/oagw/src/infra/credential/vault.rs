@@ -0,0 +1,179 @@
+use async_trait::async_trait;
+use credstore_sdk::{CredStoreError, GetSecretResponse, SecretRef, SecretValue};
+use http::StatusCode;
+use modkit_http::HttpClient;
+use modkit_security::SecurityContext;
+use serde::Deserialize;
+
+use super::{CredentialBackend, tenant_owned};
+
+/// Backend reading secrets from a `HashiCorp` Vault KV v2 engine over HTTP.
+///
+/// `secret_ref` `openai-key` is read from `{address}/v1/{mount}/data/openai-key`
+/// and the configured `field` of the secret's data map is returned. A 404
+/// from Vault is reported as "not found"; any other failure is a
+/// `ServiceUnavailable` error.
+pub struct VaultCredentialBackend {
+    client: HttpClient,
+    address: String,
+    mount: String,
+    field: String,
+    token: String,
+}
+
+#[derive(Deserialize)]
+struct KvV2Response {
+    data: KvV2Data,
+}
+
+#[derive(Deserialize)]
+struct KvV2Data {
+    data: serde_json::Map<String, serde_json::Value>,
+}
+
+impl VaultCredentialBackend {
+    #[must_use]
+    pub fn new(
+        client: HttpClient,
+        address: impl Into<String>,
+        mount: impl Into<String>,
+        field: impl Into<String>,
+        token: impl Into<String>,
+    ) -> Self {
+        Self {
+            client,
+            address: address.into().trim_end_matches('/').to_owned(),
+            mount: mount.into().trim_matches('/').to_owned(),
+            field: field.into(),
+            token: token.into(),
+        }
+    }
+
+    fn secret_url(&self, secret_ref: &SecretRef) -> String {
+        format!(
+            "{}/v1/{}/data/{}",
+            self.address,
+            self.mount,
+            secret_ref.as_ref()
+        )
+    }
+}
+
+#[async_trait]
+impl CredentialBackend for VaultCredentialBackend {
+    async fn fetch(
+        &self,
+        ctx: &SecurityContext,
+        secret_ref: &SecretRef,
+    ) -> Result<Option<GetSecretResponse>, CredStoreError> {
+        let response = self
+            .client
+            .get(&self.secret_url(secret_ref))
+            .header("x-vault-token", &self.token)
+            .send()
+            .await
+            .map_err(|e| CredStoreError::service_unavailable(format!("vault request: {e}")))?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let body: KvV2Response = response
+            .json()
+            .await
+            .map_err(|e| CredStoreError::service_unavailable(format!("vault response: {e}")))?;
+
+        match body.data.data.get(&self.field) {
+            Some(serde_json::Value::String(value)) => {
+                Ok(Some(tenant_owned(ctx, SecretValue::from(value.as_str()))))
+            }
+            Some(_) => Err(CredStoreError::internal(format!(
+                "vault field '{}' of secret '{}' is not a string",
+                self.field,
+                secret_ref.as_ref()
+            ))),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn ctx() -> SecurityContext {
+        SecurityContext::builder()
+            .subject_tenant_id(Uuid::new_v4())
+            .subject_id(Uuid::new_v4())
+            .build()
+            .expect("test security context")
+    }
+
+    fn backend(server: &MockServer) -> VaultCredentialBackend {
+        VaultCredentialBackend::new(
+            HttpClient::new().unwrap(),
+            server.base_url(),
+            "secret",
+            "value",
+            "root-token",
+        )
+    }
+
+    #[tokio::test]
+    async fn reads_field_from_kv_v2() {
+        let server = MockServer::start_async().await;
+        let mock = server
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path("/v1/secret/data/openai-key")
+                    .header("x-vault-token", "root-token");
+                then.status(200)
+                    .json_body(serde_json::json!({"data": {"data": {"value": "sk-123"}}}));
+            })
+            .await;
+
+        let key = SecretRef::new("openai-key").unwrap();
+        let resp = backend(&server).fetch(&ctx(), &key).await.unwrap().unwrap();
+        assert_eq!(resp.value.as_bytes(), b"sk-123");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn not_found_is_none() {
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/v1/secret/data/missing");
+                then.status(404)
+                    .json_body(serde_json::json!({"errors": []}));
+            })
+            .await;
+
+        let key = SecretRef::new("missing").unwrap();
+        assert!(
+            backend(&server)
+                .fetch(&ctx(), &key)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn server_error_is_service_unavailable() {
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/v1/secret/data/broken");
+                then.status(500);
+            })
+            .await;
+
+        let key = SecretRef::new("broken").unwrap();
+        let err = backend(&server).fetch(&ctx(), &key).await.unwrap_err();
+        assert!(matches!(err, CredStoreError::ServiceUnavailable(_)));
+    }
+}
@@ -1,4 +1,7 @@
+pub(crate) mod credential;
 pub(crate) mod plugin;
+#[cfg(feature = "prometheus")]
+pub(crate) mod prometheus_metrics;
 pub(crate) mod proxy;
 pub(crate) mod storage;
 pub(crate) mod type_provisioning;
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+
+use crate::domain::plugin::{GuardContext, GuardDecision, GuardPlugin, PluginError};
+
+/// Guard plugin that restricts request bodies to an allowlist of media types.
+///
+/// Configured with `allowed_content_types`, a comma-separated list of media
+/// types (e.g. `application/json, application/x-ndjson`). Matching is
+/// case-insensitive and ignores parameters such as `charset`. Requests whose
+/// `Content-Type` is not listed are rejected with 415; a missing
+/// `Content-Type` is accepted only when the request has no body. A missing or
+/// empty allowlist admits no media type at all.
+pub struct ContentTypeGuardPlugin;
+
+/// Configuration key holding the comma-separated media type allowlist.
+pub(crate) const ALLOWED_CONTENT_TYPES: &str = "allowed_content_types";
+
+/// Parse the configured allowlist into lowercased media types.
+pub(crate) fn allowed_media_types(config: &HashMap<String, String>) -> Vec<String> {
+    config
+        .get(ALLOWED_CONTENT_TYPES)
+        .map(|v| {
+            v.split(',')
+                .map(media_type)
+                .filter(|s| !s.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Extract the lowercased media type of a `Content-Type` value, dropping parameters.
+fn media_type(value: &str) -> String {
+    value
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_lowercase()
+}
+
+fn unsupported(detail: String) -> GuardDecision {
+    GuardDecision::UnsupportedMediaType { detail }
+}
+
+#[async_trait]
+impl GuardPlugin for ContentTypeGuardPlugin {
+    async fn guard_request(&self, ctx: &GuardContext) -> Result<GuardDecision, PluginError> {
+        let allowed = allowed_media_types(&ctx.config);
+
+        let content_type = ctx
+            .headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case("content-type"))
+            .map(|(_, v)| v.as_str());
+
+        match content_type {
+            None if !ctx.has_body => Ok(GuardDecision::Allow),
+            None => Ok(unsupported("Missing Content-Type header".into())),
+            Some(value) if allowed.contains(&media_type(value)) => Ok(GuardDecision::Allow),
+            Some(value) => Ok(unsupported(format!("Unsupported Content-Type: {value}"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use modkit_security::SecurityContext;
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn make_ctx(method: &str, headers: Vec<(String, String)>, has_body: bool) -> GuardContext {
+        GuardContext {
+            method: method.to_string(),
+            path: "/v1/test".to_string(),
+            status: None,
+            headers,
+            has_body,
+            config: HashMap::from([(
+                "allowed_content_types".into(),
+                "application/json, application/x-ndjson".into(),
+            )]),
+            security_context: SecurityContext::builder()
+                .subject_tenant_id(Uuid::new_v4())
+                .subject_id(Uuid::new_v4())
+                .build()
+                .expect("test security context"),
+        }
+    }
+
+    fn content_type(value: &str) -> Vec<(String, String)> {
+        vec![("content-type".into(), value.into())]
+    }
+
+    #[tokio::test]
+    async fn allows_listed_content_type() {
+        let ctx = make_ctx("POST", content_type("application/json"), true);
+        let decision = ContentTypeGuardPlugin.guard_request(&ctx).await.unwrap();
+        assert_eq!(decision, GuardDecision::Allow);
+    }
+
+    #[tokio::test]
+    async fn ignores_parameters_and_case() {
+        let ctx = make_ctx(
+            "POST",
+            content_type("Application/JSON; charset=utf-8"),
+            true,
+        );
+        let decision = ContentTypeGuardPlugin.guard_request(&ctx).await.unwrap();
+        assert_eq!(decision, GuardDecision::Allow);
+    }
+
+    #[tokio::test]
+    async fn rejects_unlisted_content_type() {
+        let ctx = make_ctx("POST", content_type("text/plain"), true);
+        let decision = ContentTypeGuardPlugin.guard_request(&ctx).await.unwrap();
+        match decision {
+            GuardDecision::UnsupportedMediaType { detail } => {
+                assert!(detail.contains("text/plain"), "got: {detail}");
+            }
+            other => panic!("expected UnsupportedMediaType, got: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn allows_bodyless_get_without_content_type() {
+        let ctx = make_ctx("GET", vec![], false);
+        let decision = ContentTypeGuardPlugin.guard_request(&ctx).await.unwrap();
+        assert_eq!(decision, GuardDecision::Allow);
+    }
+
+    #[tokio::test]
+    async fn rejects_body_without_content_type() {
+        let ctx = make_ctx("POST", vec![], true);
+        let decision = ContentTypeGuardPlugin.guard_request(&ctx).await.unwrap();
+        assert!(matches!(
+            decision,
+            GuardDecision::UnsupportedMediaType { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn denies_bodies_when_not_configured() {
+        let mut ctx = make_ctx("POST", content_type("application/json"), true);
+        ctx.config.clear();
+        let decision = ContentTypeGuardPlugin.guard_request(&ctx).await.unwrap();
+        assert!(matches!(
+            decision,
+            GuardDecision::UnsupportedMediaType { .. }
+        ));
+    }
+}
@@ -0,0 +1,252 @@
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use async_trait::async_trait;
+use credstore_sdk::{CredStoreClientV1, SecretRef};
+use dashmap::DashMap;
+use serde::Deserialize;
+
+use crate::domain::plugin::{AuthContext, AuthPlugin, PluginError};
+
+/// How the next key is picked from the pool.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Selection {
+    #[default]
+    RoundRobin,
+    LeastRecentlyUsed,
+}
+
+/// Configuration for the key pool auth plugin.
+#[derive(Debug, Deserialize)]
+struct KeyPoolConfig {
+    /// Header name to set (e.g. "Authorization", "X-API-Key").
+    header: String,
+    /// Prefix prepended to the secret value (e.g. "Bearer ").
+    #[serde(default)]
+    prefix: String,
+    /// Comma-separated secret references (e.g. "cred://key-a,cred://key-b").
+    secret_refs: String,
+    #[serde(default)]
+    selection: Selection,
+}
+
+/// Rotation state of one pool.
+#[derive(Default)]
+struct Rotation {
+    next: usize,
+    last_used: Vec<Option<Instant>>,
+}
+
+impl Rotation {
+    fn pick(&mut self, len: usize, selection: Selection) -> usize {
+        self.last_used.resize(len, None);
+        let index = match selection {
+            Selection::RoundRobin => self.next % len,
+            // `None` sorts before any `Some`, so never-used keys go first.
+            Selection::LeastRecentlyUsed => (0..len)
+                .min_by_key(|&i| self.last_used[i])
+                .unwrap_or_default(),
+        };
+        self.next = index + 1;
+        self.last_used[index] = Some(Instant::now());
+        index
+    }
+}
+
+/// Auth plugin that rotates among several secret references, injecting one
+/// per request as a header value.
+///
+/// Secrets are resolved through the same credential resolver as the API key
+/// plugin. Rotation state is shared by every upstream configured with the
+/// same `secret_refs` list, so load is spread across the keys themselves.
+pub struct KeyPoolAuthPlugin {
+    credstore: Arc<dyn CredStoreClientV1>,
+    pools: DashMap<String, Mutex<Rotation>>,
+}
+
+impl KeyPoolAuthPlugin {
+    #[must_use]
+    pub fn new(credstore: Arc<dyn CredStoreClientV1>) -> Self {
+        Self {
+            credstore,
+            pools: DashMap::new(),
+        }
+    }
+
+    fn select<'a>(&self, config: &'a KeyPoolConfig) -> Result<&'a str, PluginError> {
+        let refs: Vec<&str> = config
+            .secret_refs
+            .split(',')
+            .map(str::trim)
+            .filter(|r| !r.is_empty())
+            .collect();
+        if refs.is_empty() {
+            return Err(PluginError::InvalidConfig(
+                "secret_refs must list at least one secret reference".into(),
+            ));
+        }
+        let pool = self.pools.entry(config.secret_refs.clone()).or_default();
+        let index = pool
+            .lock()
+            .map_err(|_| PluginError::Internal("key pool state poisoned".into()))?
+            .pick(refs.len(), config.selection);
+        Ok(refs[index])
+    }
+}
+
+#[async_trait]
+impl AuthPlugin for KeyPoolAuthPlugin {
+    async fn authenticate(&self, ctx: &mut AuthContext) -> Result<(), PluginError> {
+        let config: KeyPoolConfig =
+            serde_json::from_value(serde_json::to_value(&ctx.config).map_err(|e| {
+                PluginError::Internal(format!("invalid key pool auth config: {e}"))
+            })?)
+            .map_err(|e| {
+                PluginError::InvalidConfig(format!("invalid key pool auth config: {e}"))
+            })?;
+
+        let secret_ref = self.select(&config)?;
+        let raw_ref = secret_ref.strip_prefix("cred://").unwrap_or(secret_ref);
+        let key = SecretRef::new(raw_ref)
+            .map_err(|e| PluginError::Internal(format!("invalid secret ref '{raw_ref}': {e}")))?;
+
+        let response = self
+            .credstore
+            .get(&ctx.security_context, &key)
+            .await
+            .map_err(|e| PluginError::Internal(format!("credstore error: {e}")))?
+            .ok_or_else(|| PluginError::SecretNotFound(secret_ref.to_string()))?;
+
+        let secret_str = std::str::from_utf8(response.value.as_bytes())
+            .map_err(|_| PluginError::Internal("secret value is not valid UTF-8".into()))?;
+
+        let value = format!("{}{}", config.prefix, secret_str);
+        ctx.headers.insert(config.header.to_lowercase(), value);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use modkit_security::SecurityContext;
+    use uuid::Uuid;
+
+    use crate::domain::plugin::{AuthContext, AuthPlugin, PluginError};
+    use crate::domain::test_support::MockCredStoreClient;
+
+    use super::*;
+
+    fn make_config(secret_refs: &str, selection: Option<&str>) -> HashMap<String, String> {
+        let mut config = HashMap::from([
+            ("header".into(), "authorization".into()),
+            ("prefix".into(), "Bearer ".into()),
+            ("secret_refs".into(), secret_refs.into()),
+        ]);
+        if let Some(selection) = selection {
+            config.insert("selection".into(), selection.into());
+        }
+        config
+    }
+
+    fn make_auth_ctx(config: HashMap<String, String>) -> AuthContext {
+        AuthContext {
+            headers: HashMap::new(),
+            config,
+            security_context: SecurityContext::builder()
+                .subject_tenant_id(Uuid::new_v4())
+                .subject_id(Uuid::new_v4())
+                .build()
+                .expect("test security context"),
+        }
+    }
+
+    fn three_key_plugin() -> KeyPoolAuthPlugin {
+        KeyPoolAuthPlugin::new(Arc::new(MockCredStoreClient::with_secrets(vec![
+            ("key-a".into(), "sk-a".into()),
+            ("key-b".into(), "sk-b".into()),
+            ("key-c".into(), "sk-c".into()),
+        ])))
+    }
+
+    async fn next_header(plugin: &KeyPoolAuthPlugin, config: &HashMap<String, String>) -> String {
+        let mut ctx = make_auth_ctx(config.clone());
+        plugin.authenticate(&mut ctx).await.unwrap();
+        ctx.headers.remove("authorization").unwrap()
+    }
+
+    #[tokio::test]
+    async fn round_robin_cycles_through_all_keys() {
+        let plugin = three_key_plugin();
+        let config = make_config("cred://key-a, cred://key-b, key-c", None);
+
+        let mut seen = Vec::new();
+        for _ in 0..6 {
+            seen.push(next_header(&plugin, &config).await);
+        }
+        assert_eq!(
+            seen,
+            [
+                "Bearer sk-a",
+                "Bearer sk-b",
+                "Bearer sk-c",
+                "Bearer sk-a",
+                "Bearer sk-b",
+                "Bearer sk-c"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn least_recently_used_visits_every_key_before_repeating() {
+        let plugin = three_key_plugin();
+        let config = make_config(
+            "cred://key-a,cred://key-b,cred://key-c",
+            Some("least_recently_used"),
+        );
+
+        let mut first_round = Vec::new();
+        for _ in 0..3 {
+            first_round.push(next_header(&plugin, &config).await);
+        }
+        first_round.sort();
+        assert_eq!(first_round, ["Bearer sk-a", "Bearer sk-b", "Bearer sk-c"]);
+        // The oldest key is reused next.
+        assert_eq!(next_header(&plugin, &config).await, "Bearer sk-a");
+    }
+
+    #[tokio::test]
+    async fn pools_with_different_refs_rotate_independently() {
+        let plugin = three_key_plugin();
+        let ab = make_config("cred://key-a,cred://key-b", None);
+        let cb = make_config("cred://key-c,cred://key-b", None);
+
+        assert_eq!(next_header(&plugin, &ab).await, "Bearer sk-a");
+        assert_eq!(next_header(&plugin, &cb).await, "Bearer sk-c");
+        assert_eq!(next_header(&plugin, &ab).await, "Bearer sk-b");
+    }
+
+    #[tokio::test]
+    async fn empty_pool_is_invalid_config() {
+        let plugin = three_key_plugin();
+        let mut ctx = make_auth_ctx(make_config(" , ", None));
+
+        let err = plugin.authenticate(&mut ctx).await.unwrap_err();
+        assert!(matches!(err, PluginError::InvalidConfig(_)));
+    }
+
+    #[tokio::test]
+    async fn missing_secret_names_the_selected_ref() {
+        let plugin = three_key_plugin();
+        let config = make_config("cred://key-a,cred://missing", None);
+
+        next_header(&plugin, &config).await;
+        let mut ctx = make_auth_ctx(config);
+        let err = plugin.authenticate(&mut ctx).await.unwrap_err();
+        assert!(matches!(err, PluginError::SecretNotFound(ref r) if r == "cred://missing"));
+    }
+}
@@ -1,4 +1,6 @@
 pub(crate) mod apikey_auth;
+pub(crate) mod content_type_guard;
+pub(crate) mod key_pool_auth;
 pub(crate) mod noop_auth;
 pub(crate) mod oauth2_client_cred_auth;
 pub(crate) mod registry;
@@ -8,14 +8,17 @@ use crate::domain::plugin::{AuthPlugin, GuardPlugin, PluginError, TransformPlugi
 use credstore_sdk::CredStoreClientV1;
 
 use super::apikey_auth::ApiKeyAuthPlugin;
+use super::content_type_guard::ContentTypeGuardPlugin;
+use super::key_pool_auth::KeyPoolAuthPlugin;
 use super::noop_auth::NoopAuthPlugin;
 use super::oauth2_client_cred_auth::OAuth2ClientCredAuthPlugin;
 use super::request_id_transform::RequestIdTransformPlugin;
 use super::required_headers_guard::RequiredHeadersGuardPlugin;
 use crate::domain::gts_helpers::{
-    APIKEY_AUTH_PLUGIN_ID, GUARD_PLUGIN_SCHEMA, NOOP_AUTH_PLUGIN_ID,
-    OAUTH2_CLIENT_CRED_AUTH_PLUGIN_ID, OAUTH2_CLIENT_CRED_BASIC_AUTH_PLUGIN_ID,
-    REQUEST_ID_TRANSFORM_PLUGIN_ID, REQUIRED_HEADERS_GUARD_PLUGIN_ID, TRANSFORM_PLUGIN_SCHEMA,
+    APIKEY_AUTH_PLUGIN_ID, CONTENT_TYPE_GUARD_PLUGIN_ID, GUARD_PLUGIN_SCHEMA,
+    KEY_POOL_AUTH_PLUGIN_ID, NOOP_AUTH_PLUGIN_ID, OAUTH2_CLIENT_CRED_AUTH_PLUGIN_ID,
+    OAUTH2_CLIENT_CRED_BASIC_AUTH_PLUGIN_ID, REQUEST_ID_TRANSFORM_PLUGIN_ID,
+    REQUIRED_HEADERS_GUARD_PLUGIN_ID, TRANSFORM_PLUGIN_SCHEMA,
 };
 
 /// Registry that resolves auth plugin GTS identifiers to plugin implementations.
@@ -24,7 +27,7 @@ pub struct AuthPluginRegistry {
 }
 
 impl AuthPluginRegistry {
-    /// Create a registry with the built-in plugins (apikey, noop, oauth2 CC).
+    /// Create a registry with the built-in plugins (apikey, key pool, noop, oauth2 CC).
     #[must_use]
     pub fn with_builtins(
         credstore: Arc<dyn CredStoreClientV1>,
@@ -36,6 +39,10 @@ impl AuthPluginRegistry {
             APIKEY_AUTH_PLUGIN_ID.to_string(),
             Arc::new(ApiKeyAuthPlugin::new(credstore.clone())),
         );
+        plugins.insert(
+            KEY_POOL_AUTH_PLUGIN_ID.to_string(),
+            Arc::new(KeyPoolAuthPlugin::new(credstore.clone())),
+        );
         plugins.insert(NOOP_AUTH_PLUGIN_ID.to_string(), Arc::new(NoopAuthPlugin));
 
         let mut form_plugin = OAuth2ClientCredAuthPlugin::new(
@@ -96,6 +103,10 @@ impl GuardPluginRegistry {
             REQUIRED_HEADERS_GUARD_PLUGIN_ID.to_string(),
             Arc::new(RequiredHeadersGuardPlugin),
         );
+        plugins.insert(
+            CONTENT_TYPE_GUARD_PLUGIN_ID.to_string(),
+            Arc::new(ContentTypeGuardPlugin),
+        );
         Self { plugins }
     }
 
@@ -138,6 +149,12 @@ impl TransformPluginRegistry {
         Self { plugins }
     }
 
+    /// Register `plugin` under `plugin_id`, replacing any existing plugin.
+    #[cfg(test)]
+    pub(crate) fn register(&mut self, plugin_id: &str, plugin: Arc<dyn TransformPlugin>) {
+        self.plugins.insert(plugin_id.to_string(), plugin);
+    }
+
     /// Resolve a transform plugin by its GTS identifier.
     ///
     /// # Errors
@@ -213,6 +230,12 @@ mod tests {
         assert!(registry.resolve(REQUIRED_HEADERS_GUARD_PLUGIN_ID).is_ok());
     }
 
+    #[test]
+    fn resolves_content_type_guard_plugin() {
+        let registry = GuardPluginRegistry::with_builtins();
+        assert!(registry.resolve(CONTENT_TYPE_GUARD_PLUGIN_ID).is_ok());
+    }
+
     #[test]
     fn unknown_guard_plugin_returns_error() {
         let registry = GuardPluginRegistry::with_builtins();
@@ -96,6 +96,7 @@ mod tests {
             path: "/v1/test".to_string(),
             status: None,
             headers,
+            has_body: false,
             config,
             security_context: test_security_context(),
         }
@@ -110,6 +111,7 @@ mod tests {
             path: "/v1/test".to_string(),
             status: Some(200),
             headers,
+            has_body: false,
             config,
             security_context: test_security_context(),
         }
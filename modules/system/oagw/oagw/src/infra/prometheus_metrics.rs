@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+
+use prometheus::{HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry};
+
+use crate::domain::metrics::{
+    LABEL_STATUS_CLASS, LABEL_UPSTREAM, Metrics, PROXY_RATE_LIMITED_TOTAL,
+    PROXY_REQUEST_BODY_BYTES_TOTAL, PROXY_REQUEST_DURATION_SECONDS, PROXY_REQUESTS_TOTAL,
+    PROXY_RESPONSE_BODY_BYTES_TOTAL, PROXY_RESPONSES_TOTAL,
+};
+
+/// `(name, help, label names)` of every counter the data plane reports.
+const COUNTERS: &[(&str, &str, &[&str])] = &[
+    (
+        PROXY_REQUESTS_TOTAL,
+        "Requests handled by the proxy.",
+        &[LABEL_UPSTREAM],
+    ),
+    (
+        PROXY_RESPONSES_TOTAL,
+        "Proxy responses by status class.",
+        &[LABEL_UPSTREAM, LABEL_STATUS_CLASS],
+    ),
+    (
+        PROXY_RATE_LIMITED_TOTAL,
+        "Requests rejected by a rate limit.",
+        &[LABEL_UPSTREAM],
+    ),
+    (
+        PROXY_REQUEST_BODY_BYTES_TOTAL,
+        "Request body bytes sent upstream.",
+        &[LABEL_UPSTREAM],
+    ),
+    (
+        PROXY_RESPONSE_BODY_BYTES_TOTAL,
+        "Response body bytes received from upstream.",
+        &[LABEL_UPSTREAM],
+    ),
+];
+
+/// `(name, help, label names)` of every histogram the data plane reports.
+const HISTOGRAMS: &[(&str, &str, &[&str])] = &[(
+    PROXY_REQUEST_DURATION_SECONDS,
+    "Time until proxy response headers are available.",
+    &[LABEL_UPSTREAM],
+)];
+
+/// [`Metrics`] backed by a Prometheus [`Registry`].
+///
+/// All data-plane metric families are registered up front. Label values are
+/// matched to each family's label names by key; missing labels are recorded
+/// as empty. Unknown metric names are ignored.
+pub struct PrometheusMetrics {
+    counters: HashMap<&'static str, (IntCounterVec, &'static [&'static str])>,
+    histograms: HashMap<&'static str, (HistogramVec, &'static [&'static str])>,
+}
+
+impl PrometheusMetrics {
+    /// Register the data-plane metric families in `registry`.
+    ///
+    /// # Errors
+    /// Returns an error if a family is already registered (e.g. a second
+    /// instance on the same registry).
+    pub fn new(registry: &Registry) -> Result<Self, prometheus::Error> {
+        let mut counters = HashMap::new();
+        for &(name, help, labels) in COUNTERS {
+            let vec = IntCounterVec::new(Opts::new(name, help), labels)?;
+            registry.register(Box::new(vec.clone()))?;
+            counters.insert(name, (vec, labels));
+        }
+        let mut histograms = HashMap::new();
+        for &(name, help, labels) in HISTOGRAMS {
+            let vec = HistogramVec::new(HistogramOpts::new(name, help), labels)?;
+            registry.register(Box::new(vec.clone()))?;
+            histograms.insert(name, (vec, labels));
+        }
+        Ok(Self {
+            counters,
+            histograms,
+        })
+    }
+}
+
+/// Order `labels` by `names`, filling absent labels with `""`.
+fn label_values<'a>(names: &[&str], labels: &[(&'static str, &'a str)]) -> Vec<&'a str> {
+    names
+        .iter()
+        .map(|name| {
+            labels
+                .iter()
+                .find(|(k, _)| k == name)
+                .map_or("", |(_, v)| *v)
+        })
+        .collect()
+}
+
+impl Metrics for PrometheusMetrics {
+    fn increment_counter(&self, name: &'static str, labels: &[(&'static str, &str)]) {
+        if let Some((vec, names)) = self.counters.get(name)
+            && let Ok(counter) = vec.get_metric_with_label_values(&label_values(names, labels))
+        {
+            counter.inc();
+        }
+    }
+
+    fn add_to_counter(&self, name: &'static str, labels: &[(&'static str, &str)], value: u64) {
+        if let Some((vec, names)) = self.counters.get(name)
+            && let Ok(counter) = vec.get_metric_with_label_values(&label_values(names, labels))
+        {
+            counter.inc_by(value);
+        }
+    }
+
+    fn record_histogram(&self, name: &'static str, labels: &[(&'static str, &str)], value: f64) {
+        if let Some((vec, names)) = self.histograms.get(name)
+            && let Ok(histogram) = vec.get_metric_with_label_values(&label_values(names, labels))
+        {
+            histogram.observe(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prometheus::{Encoder, TextEncoder};
+
+    use super::*;
+
+    fn render(registry: &Registry) -> String {
+        let mut buf = Vec::new();
+        TextEncoder::new()
+            .encode(&registry.gather(), &mut buf)
+            .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn records_labelled_counters_and_histograms() {
+        let registry = Registry::new();
+        let metrics = PrometheusMetrics::new(&registry).unwrap();
+
+        metrics.increment_counter(
+            PROXY_RESPONSES_TOTAL,
+            &[(LABEL_STATUS_CLASS, "2xx"), (LABEL_UPSTREAM, "openai")],
+        );
+        metrics.record_histogram(
+            PROXY_REQUEST_DURATION_SECONDS,
+            &[(LABEL_UPSTREAM, "openai")],
+            0.25,
+        );
+
+        let text = render(&registry);
+        assert!(
+            text.contains(r#"oagw_proxy_responses_total{status_class="2xx",upstream="openai"} 1"#),
+            "{text}"
+        );
+        assert!(
+            text.contains(r#"oagw_proxy_request_duration_seconds_count{upstream="openai"} 1"#),
+            "{text}"
+        );
+    }
+
+    #[test]
+    fn add_to_counter_accumulates_byte_counts() {
+        let registry = Registry::new();
+        let metrics = PrometheusMetrics::new(&registry).unwrap();
+
+        let labels = [(LABEL_UPSTREAM, "openai")];
+        metrics.add_to_counter(PROXY_REQUEST_BODY_BYTES_TOTAL, &labels, 1000);
+        metrics.add_to_counter(PROXY_REQUEST_BODY_BYTES_TOTAL, &labels, 24);
+
+        let text = render(&registry);
+        assert!(
+            text.contains(r#"oagw_proxy_request_body_bytes_total{upstream="openai"} 1024"#),
+            "{text}"
+        );
+    }
+
+    #[test]
+    fn unknown_metric_is_ignored() {
+        let registry = Registry::new();
+        let metrics = PrometheusMetrics::new(&registry).unwrap();
+        metrics.increment_counter("oagw_unknown_total", &[]);
+        assert!(!render(&registry).contains("oagw_unknown_total"));
+    }
+
+    #[test]
+    fn second_instance_on_same_registry_fails() {
+        let registry = Registry::new();
+        PrometheusMetrics::new(&registry).unwrap();
+        assert!(PrometheusMetrics::new(&registry).is_err());
+    }
+}
@@ -0,0 +1,357 @@
+//! Single-flight coalescing of identical in-flight GET requests.
+//!
+//! Routes opting into `get_coalescing` send concurrent identical GETs to the
+//! upstream once: the first request performs the call and buffers the
+//! response, the others wait and receive a copy of it.
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use bytes::{Bytes, BytesMut};
+use dashmap::DashMap;
+use futures_util::StreamExt as _;
+use http::{HeaderMap, StatusCode};
+use oagw_sdk::body::BodyStream;
+use tokio::sync::OnceCell;
+use uuid::Uuid;
+
+use crate::domain::error::DomainError;
+
+/// Status, headers and body of an upstream response.
+pub(crate) type Exchange = (StatusCode, HeaderMap, BodyStream);
+
+/// Identity of a coalescable request.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct CoalesceKey {
+    pub tenant_id: Uuid,
+    pub upstream_id: Uuid,
+    /// Upstream URL, including the query string.
+    pub url: String,
+    /// `Range` and `If-Range` values: requests for different byte ranges
+    /// get different (206) responses.
+    pub range: [Option<Bytes>; 2],
+    /// Values of the route's vary headers, in configuration order.
+    pub vary: Vec<Option<Bytes>>,
+}
+
+impl CoalesceKey {
+    /// Key of a request sent to `url` with the outbound `headers`, i.e. after
+    /// header transforms and auth have been applied.
+    pub(crate) fn new(
+        tenant_id: Uuid,
+        upstream_id: Uuid,
+        url: String,
+        vary_headers: &[String],
+        headers: &HeaderMap,
+    ) -> Self {
+        let value = |name: &str| {
+            headers
+                .get(name)
+                .map(|v| Bytes::copy_from_slice(v.as_bytes()))
+        };
+        Self {
+            tenant_id,
+            upstream_id,
+            url,
+            range: [
+                value(http::header::RANGE.as_str()),
+                value(http::header::IF_RANGE.as_str()),
+            ],
+            vary: vary_headers.iter().map(|name| value(name)).collect(),
+        }
+    }
+}
+
+enum Outcome {
+    /// Buffered response every waiting request receives a copy of.
+    Shared {
+        status: StatusCode,
+        headers: HeaderMap,
+        body: Bytes,
+    },
+    /// The upstream call failed; waiting requests fail the same way.
+    Failed { timed_out: bool, detail: String },
+    /// The response went to the request that made the call only.
+    NotShared,
+}
+
+type Flight = Arc<OnceCell<Outcome>>;
+
+/// In-flight coalescable requests by [`CoalesceKey`].
+#[derive(Default)]
+pub(crate) struct RequestCoalescer {
+    flights: DashMap<CoalesceKey, Flight>,
+}
+
+impl RequestCoalescer {
+    /// Perform `exchange` once for all concurrent requests with `key`.
+    ///
+    /// Requests arriving while the call is in flight wait up to `wait` for
+    /// it. Responses with a `Content-Length` of at most `max_body` bytes are
+    /// buffered and shared. Event streams, responses without a length and
+    /// responses setting cookies are handed to the calling request only, and
+    /// each waiting request then performs its own exchange.
+    pub(crate) async fn run<F, Fut>(
+        &self,
+        key: CoalesceKey,
+        wait: Duration,
+        max_body: usize,
+        instance: &str,
+        exchange: F,
+    ) -> Result<Exchange, DomainError>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<Exchange, DomainError>>,
+    {
+        let flight = self.flights.entry(key.clone()).or_default().clone();
+
+        let mut own = None;
+        let own_slot = &mut own;
+        let exchange = &exchange;
+        let outcome = tokio::time::timeout(
+            wait,
+            flight.get_or_init(|| async move {
+                match exchange().await {
+                    Ok((status, headers, body)) if is_shareable(&headers, max_body) => {
+                        match collect(body, max_body).await {
+                            Ok(body) => Outcome::Shared {
+                                status,
+                                headers,
+                                body,
+                            },
+                            Err(e) => Outcome::Failed {
+                                timed_out: false,
+                                detail: format!("failed to read upstream response: {e}"),
+                            },
+                        }
+                    }
+                    Ok(resp) => {
+                        *own_slot = Some(Ok(resp));
+                        Outcome::NotShared
+                    }
+                    Err(e) => {
+                        let outcome = Outcome::Failed {
+                            timed_out: matches!(e, DomainError::RequestTimeout { .. }),
+                            detail: e.to_string(),
+                        };
+                        *own_slot = Some(Err(e));
+                        outcome
+                    }
+                }
+            }),
+        )
+        .await;
+        self.flights
+            .remove_if(&key, |_, f| Arc::ptr_eq(f, &flight) && f.initialized());
+
+        let Ok(outcome) = outcome else {
+            return Err(DomainError::RequestTimeout {
+                detail: format!("coalesced request timed out after {wait:?}"),
+                instance: instance.to_owned(),
+            });
+        };
+        if let Some(own) = own {
+            return own;
+        }
+        match outcome {
+            Outcome::Shared {
+                status,
+                headers,
+                body,
+            } => {
+                let body = body.clone();
+                let stream: BodyStream =
+                    Box::pin(futures_util::stream::once(async move { Ok(body) }));
+                Ok((*status, headers.clone(), stream))
+            }
+            Outcome::Failed {
+                timed_out: true,
+                detail,
+            } => Err(DomainError::RequestTimeout {
+                detail: detail.clone(),
+                instance: instance.to_owned(),
+            }),
+            Outcome::Failed { detail, .. } => Err(DomainError::DownstreamError {
+                detail: detail.clone(),
+                instance: instance.to_owned(),
+            }),
+            Outcome::NotShared => exchange().await,
+        }
+    }
+}
+
+pub(super) fn is_shareable(headers: &HeaderMap, max_body: usize) -> bool {
+    let declared_len = headers
+        .get(http::header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<usize>().ok());
+    matches!(declared_len, Some(len) if len <= max_body)
+        && !headers.contains_key(http::header::SET_COOKIE)
+        && !oagw_sdk::sse::is_server_events_response(headers)
+}
+
+pub(super) async fn collect(mut body: BodyStream, max_body: usize) -> anyhow::Result<Bytes> {
+    let mut buf = BytesMut::new();
+    while let Some(chunk) = body.next().await {
+        let chunk = chunk.map_err(|e| anyhow::anyhow!(e))?;
+        if buf.len() + chunk.len() > max_body {
+            anyhow::bail!("response body exceeds {max_body} bytes");
+        }
+        buf.extend_from_slice(&chunk);
+    }
+    Ok(buf.freeze())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use http::HeaderValue;
+
+    use super::*;
+
+    fn key_with(headers: &HeaderMap) -> CoalesceKey {
+        CoalesceKey::new(
+            Uuid::nil(),
+            Uuid::nil(),
+            "http://upstream/v1/models".into(),
+            &["x-tenant-region".into()],
+            headers,
+        )
+    }
+
+    fn key() -> CoalesceKey {
+        key_with(&HeaderMap::new())
+    }
+
+    fn response(body: &'static [u8], content_length: bool) -> Exchange {
+        let mut headers = HeaderMap::new();
+        if content_length {
+            headers.insert(http::header::CONTENT_LENGTH, HeaderValue::from(body.len()));
+        }
+        let stream: BodyStream = Box::pin(futures_util::stream::once(async move {
+            Ok(Bytes::from_static(body))
+        }));
+        (StatusCode::OK, headers, stream)
+    }
+
+    async fn body_of(result: Result<Exchange, DomainError>) -> Bytes {
+        let (_, _, body) = result.unwrap();
+        collect(body, usize::MAX).await.unwrap()
+    }
+
+    async fn run_concurrently(
+        coalescer: &RequestCoalescer,
+        calls: &AtomicUsize,
+        content_length: bool,
+    ) -> Vec<Bytes> {
+        let exchange = move || async move {
+            calls.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            Ok(response(b"models", content_length))
+        };
+        let runs =
+            (0..4).map(|_| coalescer.run(key(), Duration::from_secs(5), 1024, "/test", &exchange));
+        let mut bodies = Vec::new();
+        for result in futures_util::future::join_all(runs).await {
+            bodies.push(body_of(result).await);
+        }
+        bodies
+    }
+
+    #[tokio::test]
+    async fn concurrent_requests_share_one_call() {
+        let coalescer = RequestCoalescer::default();
+        let calls = AtomicUsize::new(0);
+
+        let bodies = run_concurrently(&coalescer, &calls, true).await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(bodies.iter().all(|b| b == "models"));
+        assert!(coalescer.flights.is_empty());
+    }
+
+    #[tokio::test]
+    async fn unsized_responses_are_not_shared() {
+        let coalescer = RequestCoalescer::default();
+        let calls = AtomicUsize::new(0);
+
+        let bodies = run_concurrently(&coalescer, &calls, false).await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+        assert!(bodies.iter().all(|b| b == "models"));
+    }
+
+    #[tokio::test]
+    async fn waiting_requests_share_the_failure() {
+        let coalescer = RequestCoalescer::default();
+        let calls = AtomicUsize::new(0);
+        let counter = &calls;
+        let exchange = move || async move {
+            counter.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            Err(DomainError::RequestTimeout {
+                detail: "upstream timed out".into(),
+                instance: "/leader".into(),
+            })
+        };
+
+        let runs =
+            (0..3).map(|_| coalescer.run(key(), Duration::from_secs(5), 1024, "/test", &exchange));
+        let results = futures_util::future::join_all(runs).await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(
+            results
+                .iter()
+                .all(|r| matches!(r, Err(DomainError::RequestTimeout { .. })))
+        );
+    }
+
+    #[tokio::test]
+    async fn requests_for_different_ranges_are_not_shared() {
+        let coalescer = RequestCoalescer::default();
+        let calls = AtomicUsize::new(0);
+        let (coalescer, counter) = (&coalescer, &calls);
+        let ranged = |range: &'static str, body: &'static [u8]| {
+            let mut headers = HeaderMap::new();
+            headers.insert(http::header::RANGE, HeaderValue::from_static(range));
+            let exchange = move || async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                Ok(response(body, true))
+            };
+            async move {
+                coalescer
+                    .run(
+                        key_with(&headers),
+                        Duration::from_secs(5),
+                        1024,
+                        "/test",
+                        exchange,
+                    )
+                    .await
+            }
+        };
+
+        let (first, second) =
+            tokio::join!(ranged("bytes=0-4", b"first"), ranged("bytes=5-9", b"other"));
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(body_of(first).await, "first");
+        assert_eq!(body_of(second).await, "other");
+    }
+
+    #[test]
+    fn key_reads_vary_and_range_from_given_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-tenant-region", HeaderValue::from_static("eu"));
+        headers.insert(http::header::IF_RANGE, HeaderValue::from_static("\"v1\""));
+
+        let key = key_with(&headers);
+        assert_eq!(key.vary, [Some(Bytes::from_static(b"eu"))]);
+        assert_eq!(key.range, [None, Some(Bytes::from_static(b"\"v1\""))]);
+        assert_ne!(key, self::key());
+    }
+}
@@ -0,0 +1,119 @@
+//! In-flight proxy request tracking for graceful shutdown.
+//!
+//! Every proxy request holds an [`InFlight`] guard until its response body
+//! ends or is dropped. Once draining starts no new guards are handed out,
+//! and [`Drain::wait`] resolves when the last guard is released.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::Poll;
+use std::time::Duration;
+
+use futures_util::StreamExt as _;
+use oagw_sdk::body::BodyStream;
+use tokio::sync::watch;
+
+/// Count of in-flight proxy requests and whether new ones are refused.
+pub(crate) struct Drain {
+    active: watch::Sender<usize>,
+    draining: AtomicBool,
+}
+
+impl Default for Drain {
+    fn default() -> Self {
+        Self {
+            active: watch::channel(0).0,
+            draining: AtomicBool::new(false),
+        }
+    }
+}
+
+impl Drain {
+    /// Register a new request, or `None` once draining has started.
+    pub(crate) fn enter(self: &Arc<Self>) -> Option<InFlight> {
+        // Count first, then check: either `wait` sees this request or the
+        // request sees the draining flag.
+        self.active.send_modify(|n| *n += 1);
+        let guard = InFlight(Arc::clone(self));
+        if self.draining.load(Ordering::SeqCst) {
+            return None;
+        }
+        Some(guard)
+    }
+
+    /// Refuse new requests and wait up to `timeout` for in-flight ones to
+    /// finish. Returns the number of requests still in flight.
+    pub(crate) async fn wait(&self, timeout: Duration) -> usize {
+        self.draining.store(true, Ordering::SeqCst);
+        let mut active = self.active.subscribe();
+        let _ = tokio::time::timeout(timeout, active.wait_for(|n| *n == 0)).await;
+        *self.active.borrow()
+    }
+}
+
+/// Marks one request as in flight until dropped.
+pub(crate) struct InFlight(Arc<Drain>);
+
+impl InFlight {
+    /// Keep the request in flight until `body` ends or is dropped.
+    pub(crate) fn hold_until_end(self, mut body: BodyStream) -> BodyStream {
+        let mut guard = Some(self);
+        Box::pin(futures_util::stream::poll_fn(move |cx| {
+            let item = std::task::ready!(body.poll_next_unpin(cx));
+            if item.is_none() {
+                guard.take();
+            }
+            Poll::Ready(item)
+        }))
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        self.0.active.send_modify(|n| *n -= 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn wait_returns_once_requests_finish() {
+        let drain = Arc::new(Drain::default());
+        let guard = drain.enter().unwrap();
+
+        let release = async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            drop(guard);
+        };
+        let (remaining, ()) = tokio::join!(drain.wait(Duration::from_secs(5)), release);
+
+        assert_eq!(remaining, 0);
+        assert!(drain.enter().is_none());
+    }
+
+    #[tokio::test]
+    async fn wait_gives_up_after_timeout() {
+        let drain = Arc::new(Drain::default());
+        let _guard = drain.enter().unwrap();
+
+        assert_eq!(drain.wait(Duration::from_millis(20)).await, 1);
+    }
+
+    #[tokio::test]
+    async fn body_holds_request_until_it_ends() {
+        let drain = Arc::new(Drain::default());
+        let body: BodyStream = Box::pin(futures_util::stream::once(async {
+            Ok(Bytes::from_static(b"data"))
+        }));
+        let mut body = drain.enter().unwrap().hold_until_end(body);
+
+        assert_eq!(*drain.active.borrow(), 1);
+        assert!(body.next().await.is_some());
+        assert!(body.next().await.is_none());
+        assert_eq!(*drain.active.borrow(), 0);
+    }
+}
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use crate::domain::model::{PassthroughMode, RequestHeaderRules, ResponseHeaderRules};
 use http::{HeaderMap, HeaderName, HeaderValue};
-use oagw_sdk::api::ErrorSource;
+use oagw_sdk::api::{ErrorPlugin, ErrorSource};
 
 use super::HOP_BY_HOP_HEADERS;
 
@@ -15,8 +15,17 @@ const STRIPPED_HEADERS: &[&str] = &[
     "set-cookie",
 ];
 
+/// Inbound headers forwarded regardless of passthrough mode: Content-Type
+/// describes POST/PUT bodies, and the range headers select the partial
+/// content a client resumes or pages through.
+const ALWAYS_FORWARDED_HEADERS: &[HeaderName] = &[
+    http::header::CONTENT_TYPE,
+    http::header::RANGE,
+    http::header::IF_RANGE,
+];
+
 /// Apply passthrough filter: decide which inbound headers to forward.
-/// Content-Type is always forwarded when present (needed for POST/PUT bodies).
+/// [`ALWAYS_FORWARDED_HEADERS`] are forwarded when present in every mode.
 pub fn apply_passthrough(
     inbound: &HeaderMap,
     mode: &PassthroughMode,
@@ -28,21 +37,26 @@ pub fn apply_passthrough(
         PassthroughMode::Allowlist => {
             let mut h = HeaderMap::new();
             for name in allowlist {
-                if let Ok(n) = HeaderName::from_bytes(name.to_lowercase().as_bytes())
-                    && let Some(v) = inbound.get(&n)
-                {
-                    h.insert(n, v.clone());
+                let Ok(n) = HeaderName::from_bytes(name.to_lowercase().as_bytes()) else {
+                    continue;
+                };
+                if h.contains_key(&n) {
+                    continue;
+                }
+                for v in inbound.get_all(&n) {
+                    h.append(n.clone(), v.clone());
                 }
             }
             h
         }
     };
 
-    // Always forward Content-Type if present.
-    if !out.contains_key(http::header::CONTENT_TYPE)
-        && let Some(ct) = inbound.get(http::header::CONTENT_TYPE)
-    {
-        out.insert(http::header::CONTENT_TYPE, ct.clone());
+    for name in ALWAYS_FORWARDED_HEADERS {
+        if !out.contains_key(name)
+            && let Some(v) = inbound.get(name)
+        {
+            out.insert(name.clone(), v.clone());
+        }
     }
 
     // Strip sensitive headers that must never leak to upstream.
@@ -58,18 +72,9 @@ pub fn apply_passthrough(
 /// Per RFC 7230 Section 6.1, intermediaries MUST remove headers listed in the
 /// `Connection` header value in addition to the static hop-by-hop list.
 pub fn strip_hop_by_hop(headers: &mut HeaderMap) {
-    // First, parse Connection header and remove any headers it names.
-    if let Some(conn_value) = headers.get("connection").and_then(|v| v.to_str().ok()) {
-        let named: Vec<String> = conn_value
-            .split(',')
-            .map(|token| token.trim().to_lowercase())
-            .filter(|token| !token.is_empty())
-            .collect();
-        for name in &named {
-            if let Ok(header_name) = HeaderName::from_bytes(name.as_bytes()) {
-                headers.remove(header_name);
-            }
-        }
+    // First, remove any headers nominated by the Connection header(s).
+    for name in connection_nominated(headers) {
+        headers.remove(name);
     }
 
     // Then remove the static hop-by-hop list.
@@ -78,6 +83,20 @@ pub fn strip_hop_by_hop(headers: &mut HeaderMap) {
     }
 }
 
+/// Collect the header names nominated by every `Connection` header value
+/// (RFC 9110 §7.6.1 allows the field to be split across multiple lines).
+fn connection_nominated(headers: &HeaderMap) -> Vec<HeaderName> {
+    headers
+        .get_all(http::header::CONNECTION)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .map(|token| token.trim())
+        .filter(|token| !token.is_empty())
+        .filter_map(|token| HeaderName::from_bytes(token.to_ascii_lowercase().as_bytes()).ok())
+        .collect()
+}
+
 /// Remove X-OAGW-* internal headers.
 pub fn strip_internal_headers(headers: &mut HeaderMap) {
     let to_remove: Vec<HeaderName> = headers
@@ -102,13 +121,31 @@ pub fn extract_error_source(headers: &HeaderMap) -> ErrorSource {
         .and_then(|v| v.to_str().ok())
     {
         Some("gateway") => ErrorSource::Gateway,
+        Some("plugin") => ErrorSource::Plugin,
         _ => ErrorSource::Upstream,
     }
 }
 
+/// Extract the failing plugin's id from the `x-oagw-error-plugin` header.
+///
+/// Like [`extract_error_source`], must be called before the `x-oagw-*`
+/// headers are stripped.
+pub fn extract_error_plugin(headers: &HeaderMap) -> Option<ErrorPlugin> {
+    headers
+        .get("x-oagw-error-plugin")
+        .and_then(|v| v.to_str().ok())
+        .map(|id| ErrorPlugin(id.to_owned()))
+}
+
 /// Sanitize upstream response headers before forwarding to the client.
-/// Strips hop-by-hop headers and `x-oagw-*` internal headers.
+/// Strips hop-by-hop headers and `x-oagw-*` internal headers; end-to-end
+/// hints such as the upstream's `Retry-After` are kept.
 pub fn sanitize_response_headers(headers: &mut HeaderMap) {
+    // The body is forwarded de-chunked; a Content-Length sent alongside
+    // Transfer-Encoding is invalid (RFC 9112 §6.3) and must not reach the client.
+    if headers.contains_key(http::header::TRANSFER_ENCODING) {
+        headers.remove(http::header::CONTENT_LENGTH);
+    }
     strip_hop_by_hop(headers);
     strip_internal_headers(headers);
 }
@@ -143,17 +180,10 @@ pub fn is_websocket_upgrade(headers: &HeaderMap) -> bool {
 /// Like [`strip_hop_by_hop`] but preserves `Upgrade` and `Connection` headers,
 /// which are required for WebSocket upgrade negotiation (RFC 6455 §4.1).
 pub fn strip_hop_by_hop_for_upgrade(headers: &mut HeaderMap) {
-    // Parse Connection-nominated headers but skip "upgrade" itself.
-    if let Some(conn_value) = headers.get("connection").and_then(|v| v.to_str().ok()) {
-        let named: Vec<String> = conn_value
-            .split(',')
-            .map(|token| token.trim().to_lowercase())
-            .filter(|token| !token.is_empty() && token != "upgrade")
-            .collect();
-        for name in &named {
-            if let Ok(header_name) = HeaderName::from_bytes(name.as_bytes()) {
-                headers.remove(header_name);
-            }
+    // Remove Connection-nominated headers but skip "upgrade" itself.
+    for name in connection_nominated(headers) {
+        if name != http::header::UPGRADE {
+            headers.remove(name);
         }
     }
 
@@ -273,6 +303,45 @@ pub fn is_valid_transfer_encoding(headers: &HeaderMap) -> bool {
         .is_some_and(|v| v.trim().eq_ignore_ascii_case("chunked"))
 }
 
+/// Parse a Content-Length value, which must be `1*DIGIT` (RFC 9110 §8.6).
+/// Returns `None` for signs, whitespace or other characters, and for values
+/// that overflow `u64`.
+pub fn parse_content_length(value: &[u8]) -> Option<u64> {
+    if value.is_empty() || !value.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    std::str::from_utf8(value).ok()?.parse().ok()
+}
+
+/// The Content-Length of a message, or `None` if it has none.
+///
+/// Repeated headers and comma-separated lists carrying one value collapse to
+/// that value (RFC 9112 §6.3). Differing or malformed values are an error:
+/// peers disagreeing on the body length is a smuggling vector.
+pub fn unique_content_length(headers: &HeaderMap) -> Result<Option<u64>, String> {
+    let mut length = None;
+    for value in headers.get_all(http::header::CONTENT_LENGTH) {
+        for item in value.as_bytes().split(|&b| b == b',') {
+            let item = item.trim_ascii();
+            let parsed = parse_content_length(item).ok_or_else(|| {
+                format!(
+                    "Content-Length is not a valid integer: '{}'",
+                    String::from_utf8_lossy(item)
+                )
+            })?;
+            match length {
+                Some(seen) if seen != parsed => {
+                    return Err(format!(
+                        "conflicting Content-Length values: {seen} and {parsed}"
+                    ));
+                }
+                _ => length = Some(parsed),
+            }
+        }
+    }
+    Ok(length)
+}
+
 /// Set the Host header to match the upstream endpoint.
 pub fn set_host_header(headers: &mut HeaderMap, host: &str, port: u16) {
     let host_value = if port == 443 || port == 80 {
@@ -424,6 +493,21 @@ mod tests {
         assert_eq!(headers.get("x-custom").unwrap(), "keep-me");
     }
 
+    #[test]
+    fn hop_by_hop_strips_headers_from_every_connection_line() {
+        let mut headers = HeaderMap::new();
+        headers.append("connection", "keep-alive".parse().unwrap());
+        headers.append("connection", "X-Foo".parse().unwrap());
+        headers.insert("x-foo", "secret".parse().unwrap());
+        headers.insert("x-safe", "keep".parse().unwrap());
+
+        strip_hop_by_hop(&mut headers);
+
+        assert!(headers.get("connection").is_none());
+        assert!(headers.get("x-foo").is_none());
+        assert_eq!(headers.get("x-safe").unwrap(), "keep");
+    }
+
     #[test]
     fn hop_by_hop_connection_empty_and_invalid_tokens() {
         let mut headers = HeaderMap::new();
@@ -589,6 +673,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn passthrough_none_keeps_range_headers() {
+        let mut inbound = HeaderMap::new();
+        inbound.insert(http::header::RANGE, "bytes=0-99".parse().unwrap());
+        inbound.insert(http::header::IF_RANGE, "\"v1\"".parse().unwrap());
+
+        let out = apply_passthrough(&inbound, &PassthroughMode::None, &[]);
+
+        assert_eq!(out.get(http::header::RANGE).unwrap(), "bytes=0-99");
+        assert_eq!(out.get(http::header::IF_RANGE).unwrap(), "\"v1\"");
+    }
+
     #[test]
     fn passthrough_all_copies_everything() {
         let mut inbound = HeaderMap::new();
@@ -611,6 +707,24 @@ mod tests {
         assert!(out.get("x-blocked").is_none());
     }
 
+    #[test]
+    fn passthrough_allowlist_is_case_insensitive_and_keeps_every_value() {
+        let mut inbound = HeaderMap::new();
+        inbound.append("x-trace", "a".parse().unwrap());
+        inbound.append("x-trace", "b".parse().unwrap());
+        inbound.insert("x-other", "no".parse().unwrap());
+
+        let out = apply_passthrough(
+            &inbound,
+            &PassthroughMode::Allowlist,
+            &["X-Trace".into(), "x-trace".into()],
+        );
+
+        let values: Vec<_> = out.get_all("x-trace").iter().collect();
+        assert_eq!(values, ["a", "b"]);
+        assert!(out.get("x-other").is_none());
+    }
+
     #[test]
     fn passthrough_all_strips_authorization() {
         let mut inbound = HeaderMap::new();
@@ -635,6 +749,38 @@ mod tests {
         assert_eq!(extract_error_source(&headers), ErrorSource::Gateway);
     }
 
+    #[test]
+    fn extract_error_source_plugin() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-oagw-error-source", "plugin".parse().unwrap());
+        headers.insert(
+            "x-oagw-error-plugin",
+            "gts.x.core.oagw.guard_plugin.v1~x.core.oagw.cors.v1"
+                .parse()
+                .unwrap(),
+        );
+        assert_eq!(extract_error_source(&headers), ErrorSource::Plugin);
+        assert_eq!(
+            extract_error_plugin(&headers),
+            Some(ErrorPlugin(
+                "gts.x.core.oagw.guard_plugin.v1~x.core.oagw.cors.v1".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn extract_error_source_round_trips_as_str() {
+        for source in [
+            ErrorSource::Gateway,
+            ErrorSource::Upstream,
+            ErrorSource::Plugin,
+        ] {
+            let mut headers = HeaderMap::new();
+            headers.insert("x-oagw-error-source", source.as_str().parse().unwrap());
+            assert_eq!(extract_error_source(&headers), source);
+        }
+    }
+
     #[test]
     fn extract_error_source_absent_defaults_to_upstream() {
         let headers = HeaderMap::new();
@@ -681,6 +827,28 @@ mod tests {
         assert_eq!(headers.get("x-custom").unwrap(), "keep");
     }
 
+    #[test]
+    fn sanitize_response_drops_content_length_of_chunked_body() {
+        let mut headers = HeaderMap::new();
+        headers.insert("transfer-encoding", "chunked".parse().unwrap());
+        headers.insert("content-length", "42".parse().unwrap());
+
+        sanitize_response_headers(&mut headers);
+
+        assert!(headers.get("transfer-encoding").is_none());
+        assert!(headers.get("content-length").is_none());
+    }
+
+    #[test]
+    fn sanitize_response_keeps_retry_after() {
+        let mut headers = HeaderMap::new();
+        headers.insert("retry-after", "30".parse().unwrap());
+
+        sanitize_response_headers(&mut headers);
+
+        assert_eq!(headers.get("retry-after").unwrap(), "30");
+    }
+
     // -- is_websocket_upgrade tests --
 
     #[test]
@@ -782,6 +950,20 @@ mod tests {
         assert_eq!(headers.get("x-safe").unwrap(), "keep");
     }
 
+    #[test]
+    fn upgrade_strip_removes_nominated_from_every_connection_line() {
+        let mut headers = HeaderMap::new();
+        headers.append("connection", "Upgrade".parse().unwrap());
+        headers.append("connection", "X-Custom-Hop".parse().unwrap());
+        headers.insert("upgrade", "websocket".parse().unwrap());
+        headers.insert("x-custom-hop", "secret".parse().unwrap());
+
+        strip_hop_by_hop_for_upgrade(&mut headers);
+
+        assert!(headers.get("x-custom-hop").is_none());
+        assert_eq!(headers.get("upgrade").unwrap(), "websocket");
+    }
+
     // -- sanitize_response_headers_for_upgrade tests --
 
     #[test]
@@ -908,4 +1090,50 @@ mod tests {
         headers.append("transfer-encoding", "chunked".parse().unwrap());
         assert!(!is_valid_transfer_encoding(&headers));
     }
+
+    #[test]
+    fn content_length_accepts_digits_only() {
+        assert_eq!(parse_content_length(b"0"), Some(0));
+        assert_eq!(parse_content_length(b"1024"), Some(1024));
+        assert_eq!(
+            parse_content_length(b"18446744073709551615"),
+            Some(u64::MAX)
+        );
+        for invalid in [
+            &b""[..],
+            b"-1",
+            b"+5",
+            b" 5",
+            b"5 ",
+            b"1e3",
+            b"18446744073709551616",
+        ] {
+            assert_eq!(parse_content_length(invalid), None, "{invalid:?}");
+        }
+    }
+
+    #[test]
+    fn identical_content_lengths_collapse() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(unique_content_length(&headers), Ok(None));
+        headers.append("content-length", "13".parse().unwrap());
+        headers.append("content-length", "13, 13".parse().unwrap());
+        assert_eq!(unique_content_length(&headers), Ok(Some(13)));
+    }
+
+    #[test]
+    fn conflicting_content_lengths_rejected() {
+        let mut headers = HeaderMap::new();
+        headers.append("content-length", "13".parse().unwrap());
+        headers.append("content-length", "5".parse().unwrap());
+        assert!(unique_content_length(&headers).is_err());
+
+        let mut headers = HeaderMap::new();
+        headers.append("content-length", "13, 5".parse().unwrap());
+        assert!(unique_content_length(&headers).is_err());
+
+        let mut headers = HeaderMap::new();
+        headers.append("content-length", "13, ".parse().unwrap());
+        assert!(unique_content_length(&headers).is_err());
+    }
 }
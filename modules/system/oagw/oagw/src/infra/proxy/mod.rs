@@ -12,11 +12,18 @@ pub(crate) const HOP_BY_HOP_HEADERS: &[&str] = &[
     "upgrade",
 ];
 
+pub(crate) mod coalesce;
+pub(crate) mod drain;
+pub(crate) mod forward_proxy;
 pub(crate) mod headers;
 pub(crate) mod pingora_proxy;
 pub(crate) mod request_builder;
+pub(crate) mod response_cache;
+pub(crate) mod route_cache;
 pub(crate) mod service;
 pub(crate) mod session_bridge;
+pub(crate) mod sse_reconnect;
+pub(crate) mod tenant_limit_cache;
 pub(crate) mod websocket;
 
 pub(crate) use service::DataPlaneServiceImpl;
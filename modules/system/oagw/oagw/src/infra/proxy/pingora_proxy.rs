@@ -10,7 +10,7 @@ use pingora_core::protocols::Digest;
 use pingora_core::upstreams::peer::HttpPeer;
 use pingora_http::ResponseHeader;
 use pingora_load_balancing::discovery::ServiceDiscovery;
-use pingora_load_balancing::health_check::TcpHealthCheck;
+use pingora_load_balancing::health_check::{HealthCheck, TcpHealthCheck};
 use pingora_load_balancing::selection::RoundRobin;
 use pingora_load_balancing::{Backend, Backends, LoadBalancer};
 use pingora_memory_cache::MemoryCache;
@@ -19,8 +19,9 @@ use tokio::sync::watch;
 use tracing::{info, warn};
 use uuid::Uuid;
 
+use super::forward_proxy::ForwardProxy;
 use crate::domain::error::DomainError;
-use crate::domain::model::{Endpoint, Scheme};
+use crate::domain::model::{Endpoint, EndpointHealth, HealthStatus, Scheme};
 use crate::domain::services::{EndpointSelector, SelectedEndpoint};
 use modkit::api::Problem;
 
@@ -134,6 +135,10 @@ pub struct PingoraProxy {
     skip_upstream_tls_verify: bool,
     /// Per-host cache of ALPN-negotiated protocol versions.
     protocol_cache: ProtocolVersionCache,
+    /// Outbound forward proxy; `None` dials upstreams directly.
+    forward_proxy: Option<ForwardProxy>,
+    /// Hosts resolved statically instead of via DNS.
+    resolve_overrides: ResolveOverrides,
 }
 
 impl PingoraProxy {
@@ -147,9 +152,25 @@ impl PingoraProxy {
             read_timeout,
             skip_upstream_tls_verify: false,
             protocol_cache: ProtocolVersionCache::new(protocol_cache_ttl),
+            forward_proxy: None,
+            resolve_overrides: ResolveOverrides::default(),
         }
     }
 
+    /// Resolve matching hosts to fixed addresses instead of via DNS.
+    #[must_use]
+    pub(crate) fn with_resolve_overrides(mut self, overrides: ResolveOverrides) -> Self {
+        self.resolve_overrides = overrides;
+        self
+    }
+
+    /// Tunnel upstream connections through a forward proxy.
+    #[must_use]
+    pub(crate) fn with_forward_proxy(mut self, forward_proxy: Option<ForwardProxy>) -> Self {
+        self.forward_proxy = forward_proxy;
+        self
+    }
+
     /// Skip upstream TLS certificate verification. **Test use only.**
     #[must_use]
     #[allow(dead_code)]
@@ -193,6 +214,35 @@ pub fn new_http_proxy(
 /// domain-level `Endpoint` (which carries scheme, original hostname, port).
 type AddrMap = Arc<ArcSwap<HashMap<String, Endpoint>>>;
 
+/// Static host → socket address mappings that bypass DNS, analogous to
+/// curl's `--resolve`. Keys are lowercase hostnames.
+pub(crate) type ResolveOverrides = Arc<HashMap<String, std::net::SocketAddr>>;
+
+/// Normalize operator-supplied overrides (case-insensitive hostnames).
+pub(crate) fn resolve_overrides(
+    overrides: &HashMap<String, std::net::SocketAddr>,
+) -> ResolveOverrides {
+    Arc::new(
+        overrides
+            .iter()
+            .map(|(host, addr)| (host.to_ascii_lowercase(), *addr))
+            .collect(),
+    )
+}
+
+/// Resolve an endpoint host, consulting the static overrides before DNS.
+/// An override replaces both the address and the port.
+async fn resolve_host(
+    host: &str,
+    port: u16,
+    overrides: &ResolveOverrides,
+) -> Result<Vec<std::net::SocketAddr>, std::io::Error> {
+    if let Some(addr) = overrides.get(&host.to_ascii_lowercase()) {
+        return Ok(vec![*addr]);
+    }
+    dns_lookup_with_retry(&format!("{host}:{port}")).await
+}
+
 /// Resolve a hostname with retry and exponential backoff.
 ///
 /// Retries up to 3 times with 100ms / 500ms / 2500ms delays. This handles
@@ -225,7 +275,8 @@ async fn dns_lookup_with_retry(addr: &str) -> Result<Vec<std::net::SocketAddr>,
 }
 
 /// [`ServiceDiscovery`] implementation that re-resolves hostnames on every
-/// `discover()` call. IP-only endpoints are passed through without DNS.
+/// `discover()` call. IP-only endpoints are passed through without DNS, and
+/// endpoints behind the forward proxy become [`tunneled_backend`]s.
 ///
 /// On each cycle the reverse-lookup [`AddrMap`] is rebuilt so that any DNS
 /// changes (failover, blue-green) are immediately reflected.
@@ -234,13 +285,24 @@ struct DnsDiscovery {
     endpoints: Vec<Endpoint>,
     /// Shared map updated on each `discover()` cycle.
     addr_map: AddrMap,
+    /// Hosts resolved statically instead of via DNS.
+    overrides: ResolveOverrides,
+    /// Outbound forward proxy; proxied endpoints are not resolved locally.
+    forward_proxy: Option<ForwardProxy>,
 }
 
 impl DnsDiscovery {
-    fn new(endpoints: Vec<Endpoint>, addr_map: AddrMap) -> Box<Self> {
+    fn new(
+        endpoints: Vec<Endpoint>,
+        addr_map: AddrMap,
+        overrides: ResolveOverrides,
+        forward_proxy: Option<ForwardProxy>,
+    ) -> Box<Self> {
         Box::new(Self {
             endpoints,
             addr_map,
+            overrides,
+            forward_proxy,
         })
     }
 
@@ -254,9 +316,21 @@ impl DnsDiscovery {
         let mut map = HashMap::with_capacity(self.endpoints.len());
 
         for ep in &self.endpoints {
+            if self
+                .forward_proxy
+                .as_ref()
+                .is_some_and(|fp| fp.applies_to(ep))
+            {
+                if let Some(b) = tunneled_backend(ep) {
+                    map.entry(b.addr.to_string()).or_insert_with(|| ep.clone());
+                    backends.insert(b);
+                }
+                continue;
+            }
+
             let addr_str = format!("{}:{}", ep.host, ep.port);
 
-            let resolved = dns_lookup_with_retry(&addr_str).await;
+            let resolved = resolve_host(&ep.host, ep.port, &self.overrides).await;
             match resolved {
                 Ok(addrs) => {
                     for sock in addrs {
@@ -282,6 +356,32 @@ impl DnsDiscovery {
     }
 }
 
+/// Backend standing in for an endpoint reached through the forward proxy.
+///
+/// The proxy resolves the host, so instead of a locally resolved address the
+/// backend carries a placeholder Unix socket path derived from the endpoint.
+/// `select()` then reports no resolved address and `upstream_peer` tunnels
+/// by hostname.
+fn tunneled_backend(ep: &Endpoint) -> Option<Backend> {
+    use std::hash::{DefaultHasher, Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    format!(
+        "{:?}://{}:{}",
+        ep.scheme,
+        ep.host.to_ascii_lowercase(),
+        ep.port
+    )
+    .hash(&mut hasher);
+    let path = format!("oagw-tunnel/{:016x}", hasher.finish());
+    let addr = std::os::unix::net::SocketAddr::from_pathname(path).ok()?;
+    Some(Backend {
+        addr: addr.into(),
+        weight: 1,
+        ext: Default::default(),
+    })
+}
+
 #[async_trait]
 impl ServiceDiscovery for DnsDiscovery {
     async fn discover(&self) -> pingora_core::Result<(BTreeSet<Backend>, HashMap<u64, bool>)> {
@@ -299,34 +399,185 @@ impl ServiceDiscovery for DnsDiscovery {
 // PingoraEndpointSelector — default in-process BackendSelector (D2, D3)
 // ---------------------------------------------------------------------------
 
+/// Default interval between active health checks of each backend.
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Connect timeout of a health check probe, matching [`TcpHealthCheck`].
+const HEALTH_CHECK_CONNECT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Outcome of the latest health checks of one backend address.
+#[derive(Debug, Clone, Copy)]
+struct CheckRecord {
+    checked_at: chrono::DateTime<chrono::Utc>,
+    consecutive_failures: u32,
+}
+
+/// Latest [`CheckRecord`] per backend address, keyed like [`AddrMap`].
+type CheckRecords = Arc<DashMap<String, CheckRecord>>;
+
+/// [`TcpHealthCheck`] that also records every outcome, so health can be
+/// reported with check times and failure streaks.
+struct RecordingHealthCheck {
+    inner: Box<dyn HealthCheck + Send + Sync>,
+    records: CheckRecords,
+}
+
+/// Health check for upstreams behind the forward proxy: tunneled backends
+/// are probed with a `CONNECT` through the proxy, others with a direct TCP
+/// connect.
+struct TunnelingHealthCheck {
+    direct: Box<TcpHealthCheck>,
+    forward_proxy: ForwardProxy,
+    overrides: ResolveOverrides,
+    addr_map: AddrMap,
+}
+
+#[async_trait]
+impl HealthCheck for TunnelingHealthCheck {
+    async fn check(&self, target: &Backend) -> pingora_core::Result<()> {
+        let endpoint = self.addr_map.load().get(&target.addr.to_string()).cloned();
+        let tunnel = endpoint.and_then(|ep| {
+            self.forward_proxy
+                .tunnel_for(&ep, &self.overrides, HEALTH_CHECK_CONNECT_TIMEOUT)
+        });
+        match tunnel {
+            Some(tunnel) => tunnel.probe().await,
+            None => self.direct.check(target).await,
+        }
+    }
+
+    fn health_threshold(&self, success: bool) -> usize {
+        self.direct.health_threshold(success)
+    }
+}
+
+#[async_trait]
+impl HealthCheck for RecordingHealthCheck {
+    async fn check(&self, target: &Backend) -> pingora_core::Result<()> {
+        let result = self.inner.check(target).await;
+        let checked_at = chrono::Utc::now();
+        let failed = result.is_err();
+        self.records
+            .entry(target.addr.to_string())
+            .and_modify(|r| {
+                r.checked_at = checked_at;
+                r.consecutive_failures = if failed {
+                    r.consecutive_failures.saturating_add(1)
+                } else {
+                    0
+                };
+            })
+            .or_insert(CheckRecord {
+                checked_at,
+                consecutive_failures: u32::from(failed),
+            });
+        result
+    }
+
+    fn health_threshold(&self, success: bool) -> usize {
+        self.inner.health_threshold(success)
+    }
+}
+
 /// Cache entry: load balancer + shared reverse-lookup map + shutdown handle.
 struct LbEntry {
     lb: Arc<LoadBalancer<RoundRobin>>,
     /// Shared reverse-lookup map updated by [`DnsDiscovery::discover`].
     addr_map: AddrMap,
+    /// Health-check outcomes recorded by [`RecordingHealthCheck`].
+    records: CheckRecords,
     /// Dropping this sender signals the background update task to stop.
     _shutdown_tx: watch::Sender<bool>,
 }
 
+impl LbEntry {
+    /// Aggregate the health of every address `endpoint` resolved to: healthy
+    /// if any checked address is, with the latest check time and the
+    /// shortest failure streak among them.
+    fn endpoint_health(&self, endpoint: &Endpoint) -> EndpointHealth {
+        let mut health = EndpointHealth::unknown(endpoint.clone());
+        let map = self.addr_map.load();
+        let backends = self.lb.backends().get_backend();
+        for (addr, _) in map.iter().filter(|(_, ep)| *ep == endpoint) {
+            let Some(record) = self.records.get(addr).map(|r| *r) else {
+                continue;
+            };
+            // Look the backend up by key: tunneled backends have no inet
+            // address to parse back.
+            let ready = backends
+                .iter()
+                .find(|b| b.addr.to_string() == *addr)
+                .is_some_and(|b| self.lb.backends().ready(b));
+            health = match health.status {
+                HealthStatus::Unknown => EndpointHealth {
+                    endpoint: endpoint.clone(),
+                    status: HealthStatus::Unhealthy,
+                    last_checked_at: Some(record.checked_at),
+                    consecutive_failures: record.consecutive_failures,
+                },
+                _ => EndpointHealth {
+                    last_checked_at: health.last_checked_at.max(Some(record.checked_at)),
+                    consecutive_failures: health
+                        .consecutive_failures
+                        .min(record.consecutive_failures),
+                    ..health
+                },
+            };
+            if ready {
+                health.status = HealthStatus::Healthy;
+            }
+        }
+        health
+    }
+}
+
 /// Default in-process `EndpointSelector` backed by Pingora's `LoadBalancer<RoundRobin>`
 /// with DNS-aware service discovery.
 ///
-/// Lazily constructs a `LoadBalancer` per upstream on first `select()` call,
-/// caches it in a `DashMap`, and attaches a `TcpHealthCheck` with 10s interval.
-/// DNS re-resolution runs every 30s via the [`DnsDiscovery`] `ServiceDiscovery`
-/// implementation. Dropping the cache entry (via `invalidate()`) stops the
-/// background task.
+/// Lazily constructs a `LoadBalancer` per upstream on the first `select()` or
+/// `health()` call, caches it in a `DashMap`, and attaches a TCP health check
+/// (every 10s by default). DNS re-resolution runs every 30s via the
+/// [`DnsDiscovery`] `ServiceDiscovery` implementation. Dropping the cache
+/// entry (via `invalidate()`) stops the background task.
 pub struct PingoraEndpointSelector {
     cache: DashMap<Uuid, LbEntry>,
+    resolve_overrides: ResolveOverrides,
+    health_check_interval: Duration,
+    forward_proxy: Option<ForwardProxy>,
 }
 
 impl PingoraEndpointSelector {
     pub fn new() -> Self {
         Self {
             cache: DashMap::new(),
+            resolve_overrides: ResolveOverrides::default(),
+            health_check_interval: HEALTH_CHECK_INTERVAL,
+            forward_proxy: None,
         }
     }
 
+    /// Discover and health-check proxied endpoints through the forward
+    /// proxy instead of dialing them directly.
+    #[must_use]
+    pub(crate) fn with_forward_proxy(mut self, forward_proxy: Option<ForwardProxy>) -> Self {
+        self.forward_proxy = forward_proxy;
+        self
+    }
+
+    /// Interval between active health checks of each backend.
+    #[must_use]
+    pub(crate) fn with_health_check_interval(mut self, interval: Duration) -> Self {
+        self.health_check_interval = interval;
+        self
+    }
+
+    /// Resolve matching hosts to fixed addresses instead of via DNS.
+    #[must_use]
+    pub(crate) fn with_resolve_overrides(mut self, overrides: ResolveOverrides) -> Self {
+        self.resolve_overrides = overrides;
+        self
+    }
+
     /// Build a `LoadBalancer<RoundRobin>` from domain endpoints using
     /// [`DnsDiscovery`] for dynamic DNS re-resolution.
     ///
@@ -335,11 +586,29 @@ impl PingoraEndpointSelector {
     async fn build_entry(&self, endpoints: &[Endpoint]) -> Option<LbEntry> {
         let addr_map: AddrMap = Arc::new(ArcSwap::from_pointee(HashMap::new()));
 
-        let mut backends = Backends::new(DnsDiscovery::new(endpoints.to_vec(), addr_map.clone()));
-        backends.set_health_check(TcpHealthCheck::new());
+        let mut backends = Backends::new(DnsDiscovery::new(
+            endpoints.to_vec(),
+            addr_map.clone(),
+            self.resolve_overrides.clone(),
+            self.forward_proxy.clone(),
+        ));
+        let inner: Box<dyn HealthCheck + Send + Sync> = match &self.forward_proxy {
+            Some(forward_proxy) => Box::new(TunnelingHealthCheck {
+                direct: TcpHealthCheck::new(),
+                forward_proxy: forward_proxy.clone(),
+                overrides: self.resolve_overrides.clone(),
+                addr_map: addr_map.clone(),
+            }),
+            None => TcpHealthCheck::new(),
+        };
+        let records = CheckRecords::default();
+        backends.set_health_check(Box::new(RecordingHealthCheck {
+            inner,
+            records: records.clone(),
+        }));
 
         let mut lb = LoadBalancer::<RoundRobin>::from_backends(backends);
-        lb.health_check_frequency = Some(Duration::from_secs(10));
+        lb.health_check_frequency = Some(self.health_check_interval);
         lb.update_frequency = Some(Duration::from_secs(30));
 
         // update() calls discover() which resolves DNS and populates both
@@ -367,6 +636,7 @@ impl PingoraEndpointSelector {
         Some(LbEntry {
             lb,
             addr_map,
+            records,
             _shutdown_tx: shutdown_tx,
         })
     }
@@ -409,6 +679,27 @@ impl EndpointSelector for PingoraEndpointSelector {
         // which signals the background update task to stop.
         self.cache.remove(&upstream_id);
     }
+
+    async fn health(&self, upstream_id: Uuid, endpoints: &[Endpoint]) -> Vec<EndpointHealth> {
+        // Querying health starts checking upstreams that have not been
+        // selected through the LB yet (e.g. single-endpoint upstreams).
+        if !self.cache.contains_key(&upstream_id)
+            && let Some(entry) = self.build_entry(endpoints).await
+        {
+            self.cache.entry(upstream_id).or_insert(entry);
+        }
+        let Some(entry) = self.cache.get(&upstream_id) else {
+            return endpoints
+                .iter()
+                .cloned()
+                .map(EndpointHealth::unknown)
+                .collect();
+        };
+        endpoints
+            .iter()
+            .map(|ep| entry.endpoint_health(ep))
+            .collect()
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -526,12 +817,21 @@ impl ProxyHttp for PingoraProxy {
         let ep = &ctx.endpoint;
         let tls = matches!(ep.scheme, Scheme::Https | Scheme::Wss | Scheme::Wt);
 
+        let tunnel = self
+            .forward_proxy
+            .as_ref()
+            .and_then(|fp| fp.tunnel_for(ep, &self.resolve_overrides, self.connect_timeout));
+
         let addr = match ctx.resolved_addr {
             Some(a) => a,
+            // The forward proxy resolves the target; the address only keys
+            // the connection pool alongside the SNI host.
+            None if tunnel.is_some() => {
+                std::net::SocketAddr::from((std::net::Ipv4Addr::UNSPECIFIED, ep.port))
+            }
             None => {
                 // Fallback: resolve DNS explicitly (single-endpoint bypass, target-host header).
-                let addr_str = format!("{}:{}", ep.host, ep.port);
-                let addrs = dns_lookup_with_retry(&addr_str)
+                let addrs = resolve_host(&ep.host, ep.port, &self.resolve_overrides)
                     .await
                     .map_err(|e| {
                         warn!(upstream_id = ?ctx.upstream_id, host = %ep.host, port = ep.port, error = %e, "DNS resolution failed after retries");
@@ -567,6 +867,10 @@ impl ProxyHttp for PingoraProxy {
             peer.options.verify_hostname = false;
         }
 
+        if let Some(tunnel) = tunnel {
+            peer.options.custom_l4 = Some(Arc::new(tunnel));
+        }
+
         Ok(Box::new(peer))
     }
 
@@ -620,6 +924,21 @@ impl ProxyHttp for PingoraProxy {
             return Ok(());
         }
 
+        // Conflicting Content-Length values are a response-smuggling vector;
+        // identical duplicates collapse to one. Pingora already rejects
+        // HTTP/1 duplicates; this covers the responses it lets through.
+        match super::headers::unique_content_length(&upstream_response.headers) {
+            Ok(Some(len)) => upstream_response.insert_header(http::header::CONTENT_LENGTH, len)?,
+            Ok(None) => {}
+            Err(detail) => {
+                warn!(instance = %ctx.instance_uri, detail, "rejecting upstream response");
+                return Err(pingora_core::Error::explain(
+                    pingora_core::ErrorType::InvalidHTTPHeader,
+                    detail,
+                ));
+            }
+        }
+
         // Strip Connection-nominated headers.
         if let Some(conn_value) = upstream_response
             .headers
@@ -769,6 +1088,21 @@ impl ProxyHttp for PingoraProxy {
             },
         };
 
+        // Once the upstream response is streaming, a problem body would be
+        // appended to it and the stream terminated as if complete. Drop the
+        // connection instead so the caller sees a truncated body.
+        if session.as_downstream().response_written().is_some() {
+            warn!(
+                instance = %ctx.instance_uri,
+                error = %domain_err,
+                "upstream failed mid-response, aborting downstream stream"
+            );
+            return pingora_proxy::FailToProxy {
+                error_code: 0,
+                can_reuse_downstream: false,
+            };
+        }
+
         let problem: Problem = domain_err.into();
         let status = problem.status.as_u16();
         let body_bytes = Bytes::from(serde_json::to_vec(&problem).unwrap_or_default());
@@ -979,7 +1313,7 @@ mod tests {
             ep("127.0.0.1", 8001, Scheme::Https),
             ep("127.0.0.1", 8002, Scheme::Https),
         ];
-        let discovery = DnsDiscovery::new(endpoints, addr_map);
+        let discovery = DnsDiscovery::new(endpoints, addr_map, ResolveOverrides::default(), None);
 
         let (backends, map) = discovery.resolve().await;
 
@@ -996,7 +1330,7 @@ mod tests {
     async fn dns_discovery_resolve_hostname_endpoints() {
         let addr_map = make_addr_map();
         let endpoints = vec![ep("localhost", 9001, Scheme::Https)];
-        let discovery = DnsDiscovery::new(endpoints, addr_map);
+        let discovery = DnsDiscovery::new(endpoints, addr_map, ResolveOverrides::default(), None);
 
         let (backends, map) = discovery.resolve().await;
 
@@ -1017,7 +1351,12 @@ mod tests {
             ep("127.0.0.1", 7001, Scheme::Https),
             ep("127.0.0.1", 7002, Scheme::Https),
         ];
-        let discovery = DnsDiscovery::new(endpoints, addr_map.clone());
+        let discovery = DnsDiscovery::new(
+            endpoints,
+            addr_map.clone(),
+            ResolveOverrides::default(),
+            None,
+        );
 
         let (backends, _health) = discovery.discover().await.unwrap();
 
@@ -1038,7 +1377,12 @@ mod tests {
     async fn dns_discovery_discover_replaces_addr_map() {
         let addr_map = make_addr_map();
         let endpoints = vec![ep("127.0.0.1", 6001, Scheme::Http)];
-        let discovery = DnsDiscovery::new(endpoints, addr_map.clone());
+        let discovery = DnsDiscovery::new(
+            endpoints,
+            addr_map.clone(),
+            ResolveOverrides::default(),
+            None,
+        );
 
         // First discover.
         discovery.discover().await.unwrap();
@@ -1069,7 +1413,7 @@ mod tests {
             443,
             Scheme::Https,
         )];
-        let discovery = DnsDiscovery::new(endpoints, addr_map);
+        let discovery = DnsDiscovery::new(endpoints, addr_map, ResolveOverrides::default(), None);
 
         let (backends, map) = discovery.resolve().await;
 
@@ -1082,6 +1426,91 @@ mod tests {
         );
     }
 
+    /// resolve() maps an overridden hostname to the configured address
+    /// (case-insensitively) without touching DNS.
+    #[tokio::test]
+    async fn dns_discovery_resolve_uses_overrides() {
+        let addr_map = make_addr_map();
+        let overrides = resolve_overrides(&HashMap::from([(
+            "API.Fake.Invalid".to_string(),
+            "127.0.0.1:7001".parse().unwrap(),
+        )]));
+        let endpoints = vec![ep("api.fake.invalid", 443, Scheme::Https)];
+        let discovery = DnsDiscovery::new(endpoints, addr_map, overrides, None);
+
+        let (backends, map) = discovery.resolve().await;
+
+        assert_eq!(backends.len(), 1);
+        let original = map.get("127.0.0.1:7001").expect("override address");
+        assert_eq!(original.host, "api.fake.invalid");
+        assert_eq!(original.port, 443);
+    }
+
+    /// resolve() leaves proxied endpoints to the forward proxy: they become
+    /// placeholder backends without a local address, while `no_proxy` hosts
+    /// are still resolved.
+    #[tokio::test]
+    async fn dns_discovery_skips_dns_for_proxied_endpoints() {
+        let forward_proxy = ForwardProxy::from_config(
+            &crate::config::ForwardProxyConfig {
+                url: Some("http://proxy.invalid:3128".into()),
+                no_proxy: vec!["127.0.0.1".into()],
+                ..Default::default()
+            },
+            |_| None,
+        )
+        .unwrap();
+        let endpoints = vec![
+            ep("a.upstream.invalid", 443, Scheme::Https),
+            ep("b.upstream.invalid", 443, Scheme::Https),
+            ep("127.0.0.1", 443, Scheme::Https),
+        ];
+        let discovery = DnsDiscovery::new(
+            endpoints,
+            make_addr_map(),
+            ResolveOverrides::default(),
+            forward_proxy,
+        );
+
+        let (backends, map) = discovery.resolve().await;
+
+        assert_eq!(backends.len(), 3);
+        assert_eq!(
+            backends
+                .iter()
+                .filter(|b| b.addr.as_inet().is_none())
+                .count(),
+            2
+        );
+        let mut hosts: Vec<_> = map.values().map(|e| e.host.as_str()).collect();
+        hosts.sort_unstable();
+        assert_eq!(
+            hosts,
+            ["127.0.0.1", "a.upstream.invalid", "b.upstream.invalid"]
+        );
+        assert_eq!(map.get("127.0.0.1:443").unwrap().host, "127.0.0.1");
+    }
+
+    /// select() returns the overridden address while keeping the original
+    /// hostname-bearing endpoint (used for SNI and Host).
+    #[tokio::test]
+    async fn select_honors_resolve_overrides() {
+        let selector = PingoraEndpointSelector::new().with_resolve_overrides(resolve_overrides(
+            &HashMap::from([(
+                "upstream.fake.invalid".to_string(),
+                "127.0.0.1:7002".parse().unwrap(),
+            )]),
+        ));
+        let endpoints = vec![ep("upstream.fake.invalid", 443, Scheme::Https)];
+
+        let selected = selector.select(Uuid::new_v4(), &endpoints).await.unwrap();
+        assert_eq!(
+            selected.resolved_addr,
+            Some("127.0.0.1:7002".parse().unwrap())
+        );
+        assert_eq!(selected.endpoint.host, "upstream.fake.invalid");
+    }
+
     /// select() returns None when the endpoint list is empty.
     #[tokio::test]
     async fn select_empty_endpoints_returns_none() {
@@ -0,0 +1,246 @@
+//! Opt-in caching of upstream GET responses.
+//!
+//! Routes with `response_cache` answer GETs from a stored copy of an earlier
+//! buffered `200 OK` response until its TTL lapses. Every response on such a
+//! route is marked with `X-Cache` (`HIT` or `MISS`), `Age` and a hashed
+//! `X-Cache-Key` so callers can tell where it came from.
+
+use std::future::Future;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::time::{Duration, Instant};
+
+use bytes::Bytes;
+use http::{HeaderMap, HeaderValue, StatusCode};
+use oagw_sdk::body::BodyStream;
+use pingora_memory_cache::MemoryCache;
+use uuid::Uuid;
+
+use super::coalesce::{self, Exchange};
+use crate::domain::error::DomainError;
+
+/// Response header telling whether the response was served from the cache.
+pub(crate) const X_CACHE: &str = "x-cache";
+/// Response header carrying the hashed cache key of the response.
+pub(crate) const X_CACHE_KEY: &str = "x-cache-key";
+
+/// Identity of a cacheable request.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct ResponseCacheKey {
+    pub tenant_id: Uuid,
+    pub upstream_id: Uuid,
+    /// Upstream URL, including the query string.
+    pub url: String,
+}
+
+impl ResponseCacheKey {
+    /// Stable hash of the key, as sent in `X-Cache-Key`.
+    fn digest(&self) -> String {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        format!("{:016x}", hasher.finish())
+    }
+}
+
+#[derive(Clone)]
+struct StoredResponse {
+    /// `MemoryCache` indexes by key hash only; the full key is kept to rule
+    /// out serving another tenant's response on a hash collision.
+    key: ResponseCacheKey,
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+    stored_at: Instant,
+}
+
+/// Stored upstream responses of routes with `response_cache`.
+///
+/// Backed by [`MemoryCache`] with the route's TTL per entry. Only `200 OK`
+/// responses with a `Content-Length` of at most `max_body` bytes are stored;
+/// responses setting cookies, event streams and responses marked
+/// `Cache-Control: no-store` or `private` are passed through.
+pub(crate) struct ResponseCache {
+    inner: MemoryCache<ResponseCacheKey, StoredResponse>,
+    max_body: usize,
+}
+
+impl ResponseCache {
+    pub(crate) fn new(capacity: usize, max_body: usize) -> Self {
+        Self {
+            inner: MemoryCache::new(capacity),
+            max_body,
+        }
+    }
+
+    /// Serve the stored response for `key`, or run `fetch` and store its
+    /// response for `ttl`.
+    ///
+    /// Clients sending `Cache-Control: no-cache` or `max-age=0` always get a
+    /// fresh response, which then replaces the stored one.
+    pub(crate) async fn get_or_fetch<F, Fut>(
+        &self,
+        key: ResponseCacheKey,
+        ttl: Duration,
+        req_headers: &HeaderMap,
+        instance: &str,
+        fetch: F,
+    ) -> Result<Exchange, DomainError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Exchange, DomainError>>,
+    {
+        let digest = key.digest();
+        if !forces_revalidation(req_headers)
+            && let (Some(stored), _) = self.inner.get(&key)
+            && stored.key == key
+        {
+            let mut headers = stored.headers;
+            mark(&mut headers, "HIT", stored.stored_at.elapsed(), &digest);
+            let body = stored.body;
+            let stream: BodyStream = Box::pin(futures_util::stream::once(async move { Ok(body) }));
+            return Ok((stored.status, headers, stream));
+        }
+
+        let (status, mut headers, body) = fetch().await?;
+        let body = if status == StatusCode::OK && is_storable(&headers, self.max_body) {
+            let body = coalesce::collect(body, self.max_body).await.map_err(|e| {
+                DomainError::DownstreamError {
+                    detail: format!("failed to read upstream response: {e}"),
+                    instance: instance.to_owned(),
+                }
+            })?;
+            self.inner.put(
+                &key,
+                StoredResponse {
+                    key: key.clone(),
+                    status,
+                    headers: headers.clone(),
+                    body: body.clone(),
+                    stored_at: Instant::now(),
+                },
+                Some(ttl),
+            );
+            Box::pin(futures_util::stream::once(async move { Ok(body) }))
+        } else {
+            body
+        };
+        mark(&mut headers, "MISS", Duration::ZERO, &digest);
+        Ok((status, headers, body))
+    }
+}
+
+fn mark(headers: &mut HeaderMap, outcome: &'static str, age: Duration, digest: &str) {
+    headers.insert(X_CACHE, HeaderValue::from_static(outcome));
+    headers.insert(http::header::AGE, HeaderValue::from(age.as_secs()));
+    if let Ok(v) = HeaderValue::from_str(digest) {
+        headers.insert(X_CACHE_KEY, v);
+    }
+}
+
+/// Whether the client asked for a fresh response.
+fn forces_revalidation(req_headers: &HeaderMap) -> bool {
+    cache_directives(req_headers).any(|d| d == "no-cache" || d == "max-age=0")
+}
+
+fn is_storable(headers: &HeaderMap, max_body: usize) -> bool {
+    coalesce::is_shareable(headers, max_body)
+        && !cache_directives(headers).any(|d| d == "no-store" || d == "private")
+}
+
+/// Lowercased `Cache-Control` directives of `headers`.
+fn cache_directives(headers: &HeaderMap) -> impl Iterator<Item = String> + '_ {
+    headers
+        .get_all(http::header::CACHE_CONTROL)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .map(|d| d.trim().to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    fn key() -> ResponseCacheKey {
+        ResponseCacheKey {
+            tenant_id: Uuid::nil(),
+            upstream_id: Uuid::nil(),
+            url: "http://upstream/v1/models".into(),
+        }
+    }
+
+    async fn get(
+        cache: &ResponseCache,
+        req_headers: &HeaderMap,
+        calls: &AtomicUsize,
+    ) -> (HeaderMap, Bytes) {
+        let (_, headers, body) = cache
+            .get_or_fetch(
+                key(),
+                Duration::from_secs(60),
+                req_headers,
+                "/test",
+                || async {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    let mut headers = HeaderMap::new();
+                    headers.insert(http::header::CONTENT_LENGTH, HeaderValue::from(6));
+                    let body: BodyStream = Box::pin(futures_util::stream::once(async {
+                        Ok(Bytes::from_static(b"models"))
+                    }));
+                    Ok((StatusCode::OK, headers, body))
+                },
+            )
+            .await
+            .unwrap();
+        (headers, coalesce::collect(body, usize::MAX).await.unwrap())
+    }
+
+    #[tokio::test]
+    async fn second_request_is_a_hit() {
+        let cache = ResponseCache::new(16, 1024);
+        let calls = AtomicUsize::new(0);
+
+        let (miss, _) = get(&cache, &HeaderMap::new(), &calls).await;
+        let (hit, body) = get(&cache, &HeaderMap::new(), &calls).await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(miss[X_CACHE], "MISS");
+        assert_eq!(hit[X_CACHE], "HIT");
+        assert_eq!(hit[X_CACHE_KEY], miss[X_CACHE_KEY]);
+        assert_eq!(hit[X_CACHE_KEY].len(), 16);
+        assert_eq!(body, "models");
+    }
+
+    #[tokio::test]
+    async fn client_revalidation_forces_a_miss() {
+        let cache = ResponseCache::new(16, 1024);
+        let calls = AtomicUsize::new(0);
+        get(&cache, &HeaderMap::new(), &calls).await;
+
+        for directive in ["no-cache", "max-age=0", "public, Max-Age=0"] {
+            let mut req_headers = HeaderMap::new();
+            req_headers.insert(
+                http::header::CACHE_CONTROL,
+                HeaderValue::from_static(directive),
+            );
+            let (headers, _) = get(&cache, &req_headers, &calls).await;
+            assert_eq!(headers[X_CACHE], "MISS", "{directive}");
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn no_store_responses_are_not_stored() {
+        let mut headers = HeaderMap::new();
+        headers.insert(http::header::CONTENT_LENGTH, HeaderValue::from(6));
+        assert!(is_storable(&headers, 1024));
+        assert!(!is_storable(&headers, 5));
+
+        headers.insert(
+            http::header::CACHE_CONTROL,
+            HeaderValue::from_static("No-Store"),
+        );
+        assert!(!is_storable(&headers, 1024));
+    }
+}
@@ -0,0 +1,267 @@
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use pingora_memory_cache::MemoryCache;
+use uuid::Uuid;
+
+use crate::domain::error::DomainError;
+use crate::domain::model::{Route, Upstream};
+
+/// Lookup key of a proxy route resolution. `path` is the normalized path
+/// suffix after the alias.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub(crate) struct RouteKey {
+    pub tenant_id: Uuid,
+    pub alias: String,
+    pub method: String,
+    pub path: String,
+}
+
+#[derive(Clone)]
+struct CachedRoute {
+    /// `MemoryCache` indexes by key hash only; the full key is kept to rule
+    /// out serving another tenant's route on a hash collision.
+    key: RouteKey,
+    generation: u64,
+    target: Arc<(Upstream, Route)>,
+}
+
+/// Cache of `(upstream, route)` resolutions for the proxy hot path.
+///
+/// Backed by [`MemoryCache`] (TinyUfo LFU with per-entry TTL), consistent
+/// with the OAuth2 token and protocol version caches. Every entry is tagged
+/// with the generation current when its resolution started;
+/// [`invalidate_all`](Self::invalidate_all) bumps the generation, so older
+/// entries — including resolutions still in flight — are never served.
+/// Only successful resolutions are cached. When capacity or TTL is zero the
+/// cache is disabled and every lookup resolves.
+pub(crate) struct RouteCache {
+    inner: Option<MemoryCache<RouteKey, CachedRoute>>,
+    ttl: Duration,
+    generation: AtomicU64,
+}
+
+impl RouteCache {
+    pub(crate) fn new(capacity: usize, ttl: Duration) -> Self {
+        let enabled = capacity > 0 && !ttl.is_zero();
+        Self {
+            inner: enabled.then(|| MemoryCache::new(capacity)),
+            ttl,
+            generation: AtomicU64::new(0),
+        }
+    }
+
+    /// Return the cached resolution for `key`, or run `resolve` and cache
+    /// its result.
+    pub(crate) async fn get_or_resolve<F, Fut>(
+        &self,
+        key: RouteKey,
+        resolve: F,
+    ) -> Result<(Upstream, Route), DomainError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<(Upstream, Route), DomainError>>,
+    {
+        let Some(inner) = &self.inner else {
+            return resolve().await;
+        };
+
+        let generation = self.generation.load(Ordering::Acquire);
+        if let (Some(cached), _) = inner.get(&key)
+            && cached.generation == generation
+            && cached.key == key
+        {
+            return Ok((*cached.target).clone());
+        }
+
+        let target = resolve().await?;
+        inner.put(
+            &key,
+            CachedRoute {
+                key: key.clone(),
+                generation,
+                target: Arc::new(target.clone()),
+            },
+            Some(self.ttl),
+        );
+        Ok(target)
+    }
+
+    /// Drop every cached resolution.
+    pub(crate) fn invalidate_all(&self) {
+        self.generation.fetch_add(1, Ordering::AcqRel);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+
+    use crate::domain::model::{
+        Endpoint, HttpMatch, HttpMethod, MatchRules, PathSuffixMode, Scheme, Server,
+    };
+
+    use super::*;
+
+    fn make_target(tenant_id: Uuid) -> (Upstream, Route) {
+        let upstream = Upstream {
+            id: Uuid::new_v4(),
+            tenant_id,
+            alias: "openai".into(),
+            server: Server {
+                endpoints: vec![Endpoint {
+                    scheme: Scheme::Https,
+                    host: "api.openai.com".into(),
+                    port: 443,
+                }],
+            },
+            protocol: "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1".into(),
+            enabled: true,
+            auth: None,
+            headers: None,
+            plugins: None,
+            rate_limit: None,
+            cors: None,
+            tags: vec![],
+        };
+        let route = Route {
+            id: Uuid::new_v4(),
+            tenant_id,
+            upstream_id: upstream.id,
+            match_rules: MatchRules {
+                http: Some(HttpMatch {
+                    methods: vec![HttpMethod::Get],
+                    path: "/v1".into(),
+                    query_allowlist: vec![],
+                    path_suffix_mode: PathSuffixMode::Append,
+                }),
+                grpc: None,
+            },
+            plugins: None,
+            rate_limit: None,
+            cors: None,
+            sse_reconnect: None,
+            get_coalescing: None,
+            request_compression: None,
+            response_cache: None,
+            tags: vec![],
+            priority: 0,
+            enabled: true,
+        };
+        (upstream, route)
+    }
+
+    fn key(tenant_id: Uuid) -> RouteKey {
+        RouteKey {
+            tenant_id,
+            alias: "openai".into(),
+            method: "GET".into(),
+            path: "/v1/models".into(),
+        }
+    }
+
+    /// Resolve through the cache, counting calls that reach the resolver.
+    async fn lookup(
+        cache: &RouteCache,
+        key: RouteKey,
+        target: &(Upstream, Route),
+        calls: &AtomicUsize,
+    ) -> (Upstream, Route) {
+        cache
+            .get_or_resolve(key, || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Ok(target.clone())
+            })
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn hit_skips_resolution() {
+        let cache = RouteCache::new(100, Duration::from_secs(60));
+        let tenant = Uuid::new_v4();
+        let target = make_target(tenant);
+        let calls = AtomicUsize::new(0);
+
+        assert_eq!(lookup(&cache, key(tenant), &target, &calls).await, target);
+        assert_eq!(lookup(&cache, key(tenant), &target, &calls).await, target);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn invalidation_forces_resolution() {
+        let cache = RouteCache::new(100, Duration::from_secs(60));
+        let tenant = Uuid::new_v4();
+        let before = make_target(tenant);
+        let calls = AtomicUsize::new(0);
+        lookup(&cache, key(tenant), &before, &calls).await;
+
+        cache.invalidate_all();
+        let after = make_target(tenant);
+        assert_eq!(lookup(&cache, key(tenant), &after, &calls).await, after);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn resolution_racing_invalidation_is_not_served() {
+        let cache = RouteCache::new(100, Duration::from_secs(60));
+        let tenant = Uuid::new_v4();
+        let stale = make_target(tenant);
+        cache
+            .get_or_resolve(key(tenant), || async {
+                // A config change lands while the old config is being read.
+                cache.invalidate_all();
+                Ok(stale.clone())
+            })
+            .await
+            .unwrap();
+
+        let fresh = make_target(tenant);
+        let calls = AtomicUsize::new(0);
+        assert_eq!(lookup(&cache, key(tenant), &fresh, &calls).await, fresh);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn tenants_are_cached_separately() {
+        let cache = RouteCache::new(100, Duration::from_secs(60));
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let (target_a, target_b) = (make_target(a), make_target(b));
+        let calls = AtomicUsize::new(0);
+
+        lookup(&cache, key(a), &target_a, &calls).await;
+        assert_eq!(lookup(&cache, key(b), &target_b, &calls).await, target_b);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn errors_are_not_cached() {
+        let cache = RouteCache::new(100, Duration::from_secs(60));
+        let tenant = Uuid::new_v4();
+        let err = cache
+            .get_or_resolve(key(tenant), || async {
+                Err(DomainError::not_found("route", Uuid::nil()))
+            })
+            .await;
+        assert!(err.is_err());
+
+        let target = make_target(tenant);
+        let calls = AtomicUsize::new(0);
+        lookup(&cache, key(tenant), &target, &calls).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn zero_capacity_disables_cache() {
+        let cache = RouteCache::new(0, Duration::from_secs(60));
+        let tenant = Uuid::new_v4();
+        let target = make_target(tenant);
+        let calls = AtomicUsize::new(0);
+
+        lookup(&cache, key(tenant), &target, &calls).await;
+        lookup(&cache, key(tenant), &target, &calls).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
@@ -1,5 +1,5 @@
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
 use authz_resolver_sdk::PolicyEnforcer;
@@ -9,43 +9,76 @@ use credstore_sdk::CredStoreClientV1;
 use futures_util::StreamExt;
 use http::{HeaderMap, HeaderValue};
 use modkit_security::SecurityContext;
+use oagw_sdk::api::ErrorSource;
 use oagw_sdk::body::{Body, BodyStream};
 use pingora_core::apps::HttpServerApp;
 use pingora_proxy::HttpProxy;
 use tokio::io::AsyncWriteExt;
-use tokio::sync::watch;
+use tokio::sync::{broadcast, watch};
 
 use crate::config::TokenCacheConfig;
 use crate::domain::error::DomainError;
+use crate::domain::metrics::{self, Metrics, NoopMetrics};
 use crate::domain::model::{
-    PassthroughMode, PathSuffixMode, ResponseHeaderRules, Scheme, Upstream,
+    EndpointHealth, PassthroughMode, PathSuffixMode, ResponseHeaderRules, Scheme, TenantRateLimit,
+    Upstream,
 };
 use crate::domain::plugin::{
     AuthContext, GuardContext, GuardDecision, TransformErrorContext, TransformRequestContext,
     TransformResponseContext,
 };
-use crate::domain::rate_limit::RateLimiter;
+use crate::domain::rate_limit::{self, RateLimiter};
 use crate::domain::services::{
-    ControlPlaneService, DataPlaneService, EndpointSelector, SelectedEndpoint,
+    ConfigChange, ConfigChangeKind, ControlPlaneService, DataPlaneService, EndpointSelector,
+    SelectedEndpoint,
 };
 use crate::infra::plugin::{AuthPluginRegistry, GuardPluginRegistry, TransformPluginRegistry};
 use crate::infra::proxy::{actions, resources};
 
+use super::coalesce::{self, CoalesceKey, RequestCoalescer};
+use super::drain::Drain;
 use super::headers;
 use super::pingora_proxy::{
     H_ENDPOINT_HOST, H_ENDPOINT_PORT, H_ENDPOINT_SCHEME, H_INSTANCE_URI, H_RESOLVED_ADDR,
     H_UPSTREAM_ID, PingoraProxy,
 };
-use super::{request_builder, session_bridge};
+use super::response_cache::{ResponseCache, ResponseCacheKey};
+use super::route_cache::{RouteCache, RouteKey};
+use super::tenant_limit_cache::TenantLimitCache;
+use super::{request_builder, session_bridge, sse_reconnect};
 
 const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+/// Default upper bound for per-request `X-OAGW-Timeout-Ms` overrides.
+const MAX_REQUEST_TIMEOUT: Duration = Duration::from_secs(300);
+/// Header a caller can set to override the request timeout for one call.
+const TIMEOUT_OVERRIDE_HEADER: &str = "x-oagw-timeout-ms";
 /// Default maximum request body size: 100 MB.
 const MAX_BODY_SIZE: usize = 100 * 1024 * 1024;
+/// Default capacity of the route resolution cache.
+const ROUTE_CACHE_CAPACITY: usize = 10_000;
+/// Default TTL of cached route resolutions.
+const ROUTE_CACHE_TTL: Duration = Duration::from_secs(60);
+/// Capacity of the response cache of routes with `response_cache`.
+const RESPONSE_CACHE_CAPACITY: usize = 1_024;
+/// Largest response body the response cache stores: 1 MB.
+const RESPONSE_CACHE_MAX_BODY: usize = 1024 * 1024;
+/// Default capacity of the tenant rate limit cache.
+const TENANT_LIMIT_CACHE_CAPACITY: usize = 10_000;
+/// Default TTL of cached tenant rate limits.
+const TENANT_LIMIT_CACHE_TTL: Duration = Duration::from_secs(60);
 
 /// Data Plane service implementation: proxy orchestration and plugin execution.
 pub struct DataPlaneServiceImpl {
     cp: Arc<dyn ControlPlaneService>,
     backend_selector: Arc<dyn EndpointSelector>,
+    /// Resolved `(upstream, route)` per tenant, alias, method and path.
+    route_cache: RouteCache,
+    /// In-flight GETs on routes with `get_coalescing`.
+    coalescer: RequestCoalescer,
+    /// Stored GET responses of routes with `response_cache`.
+    response_cache: ResponseCache,
+    /// In-flight proxy requests, refused once shutdown draining starts.
+    drain: Arc<Drain>,
     proxy: Arc<HttpProxy<PingoraProxy>>,
     /// Sender kept alive so receivers see `false` (not shutting down) until drop.
     _shutdown_tx: watch::Sender<bool>,
@@ -53,8 +86,15 @@ pub struct DataPlaneServiceImpl {
     auth_registry: AuthPluginRegistry,
     guard_registry: GuardPluginRegistry,
     transform_registry: TransformPluginRegistry,
-    rate_limiter: RateLimiter,
+    rate_limiter: Arc<RateLimiter>,
+    /// Tenant rate limits as last read from the control plane, `None` when
+    /// the tenant has none. Expire after a TTL and are dropped on
+    /// `TenantRateLimitChanged`.
+    tenant_rate_limits: TenantLimitCache,
+    metrics: Arc<dyn Metrics>,
     request_timeout: Duration,
+    /// Upper bound for `X-OAGW-Timeout-Ms` overrides; larger values are clamped.
+    max_request_timeout: Duration,
     /// Enforces authorization policy before proxying each request.
     policy_enforcer: PolicyEnforcer,
     /// When true, allow HTTP (non-TLS) upstream connections.
@@ -85,12 +125,16 @@ impl DataPlaneServiceImpl {
             AuthPluginRegistry::with_builtins(credstore, token_http_config, token_cache_config);
         let guard_registry = GuardPluginRegistry::with_builtins();
         let transform_registry = TransformPluginRegistry::with_builtins();
-        let rate_limiter = RateLimiter::new();
+        let rate_limiter = Arc::new(RateLimiter::new());
         let (shutdown_tx, shutdown_rx) = watch::channel(false);
 
         Self {
             cp,
             backend_selector,
+            route_cache: RouteCache::new(ROUTE_CACHE_CAPACITY, ROUTE_CACHE_TTL),
+            coalescer: RequestCoalescer::default(),
+            response_cache: ResponseCache::new(RESPONSE_CACHE_CAPACITY, RESPONSE_CACHE_MAX_BODY),
+            drain: Arc::default(),
             proxy,
             _shutdown_tx: shutdown_tx,
             shutdown_rx,
@@ -98,7 +142,13 @@ impl DataPlaneServiceImpl {
             guard_registry,
             transform_registry,
             rate_limiter,
+            tenant_rate_limits: TenantLimitCache::new(
+                TENANT_LIMIT_CACHE_CAPACITY,
+                TENANT_LIMIT_CACHE_TTL,
+            ),
+            metrics: Arc::new(NoopMetrics),
             request_timeout: REQUEST_TIMEOUT,
+            max_request_timeout: MAX_REQUEST_TIMEOUT,
             policy_enforcer,
             allow_http_upstream: false,
             max_body_size: MAX_BODY_SIZE,
@@ -109,6 +159,60 @@ impl DataPlaneServiceImpl {
         }
     }
 
+    /// Keep data-plane caches consistent with control-plane mutations made
+    /// through any entry point (REST, SDK facade, type provisioning).
+    ///
+    /// Runs until the change channel closes or the service is dropped.
+    pub(crate) async fn watch_config_changes(
+        self: Arc<Self>,
+        mut changes: broadcast::Receiver<ConfigChange>,
+    ) {
+        let this = Arc::downgrade(&self);
+        drop(self);
+        loop {
+            match changes.recv().await {
+                Ok(change) => match this.upgrade() {
+                    Some(svc) => svc.apply_config_change(change),
+                    None => return,
+                },
+                Err(broadcast::error::RecvError::Lagged(missed)) => {
+                    tracing::warn!(missed, "data plane lagged behind config changes");
+                    if let Some(svc) = this.upgrade() {
+                        svc.route_cache.invalidate_all();
+                        svc.tenant_rate_limits.invalidate_all();
+                    }
+                }
+                Err(broadcast::error::RecvError::Closed) => return,
+            }
+        }
+    }
+
+    fn apply_config_change(&self, change: ConfigChange) {
+        // A change to any upstream or route can alter resolution through
+        // alias shadowing or route priority, so all cached targets go.
+        self.route_cache.invalidate_all();
+        match change.kind {
+            ConfigChangeKind::UpstreamUpdated => self.backend_selector.invalidate(change.id),
+            ConfigChangeKind::UpstreamDeleted => {
+                self.backend_selector.invalidate(change.id);
+                self.rate_limiter
+                    .remove_key(&format!("upstream:{}", change.id));
+            }
+            ConfigChangeKind::RouteDeleted => {
+                self.rate_limiter
+                    .remove_key(&format!("route:{}", change.id));
+            }
+            ConfigChangeKind::TenantRateLimitChanged => {
+                self.tenant_rate_limits.invalidate(change.id);
+                self.rate_limiter
+                    .remove_key(&format!("tenant:{}", change.id));
+            }
+            ConfigChangeKind::UpstreamCreated
+            | ConfigChangeKind::RouteCreated
+            | ConfigChangeKind::RouteUpdated => {}
+        }
+    }
+
     /// Override the request timeout.
     #[must_use]
     pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
@@ -116,6 +220,21 @@ impl DataPlaneServiceImpl {
         self
     }
 
+    /// Override the upper bound for per-request timeout overrides.
+    #[must_use]
+    pub fn with_max_request_timeout(mut self, timeout: Duration) -> Self {
+        self.max_request_timeout = timeout;
+        self
+    }
+
+    /// Override the capacity and TTL of the route resolution cache. Either
+    /// being zero disables the cache.
+    #[must_use]
+    pub fn with_route_cache(mut self, capacity: usize, ttl: Duration) -> Self {
+        self.route_cache = RouteCache::new(capacity, ttl);
+        self
+    }
+
     /// Override the maximum request body size.
     #[must_use]
     pub fn with_max_body_size(mut self, size: usize) -> Self {
@@ -158,6 +277,13 @@ impl DataPlaneServiceImpl {
         self
     }
 
+    /// Report proxy metrics to `metrics` instead of discarding them.
+    #[must_use]
+    pub fn with_metrics(mut self, metrics: Arc<dyn Metrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     /// Execute the post-response plugin pipeline (guard + transform) and build
     /// the final proxy response.
     async fn finalize_response(
@@ -225,6 +351,12 @@ impl DataPlaneServiceImpl {
         } else {
             resp_body_stream
         };
+        let resp_body_stream = metered_body(
+            resp_body_stream,
+            self.metrics.clone(),
+            metrics::PROXY_RESPONSE_BODY_BYTES_TOTAL,
+            pipeline.upstream_label.to_owned(),
+        );
 
         build_proxy_response(status, resp_headers, resp_body_stream, instance_uri)
     }
@@ -314,12 +446,38 @@ impl DataPlaneServiceImpl {
     }
 }
 
-#[async_trait]
-impl DataPlaneService for DataPlaneServiceImpl {
-    async fn proxy_request(
+/// Parse the `X-OAGW-Timeout-Ms` header, clamping it to `max`.
+///
+/// Returns `Ok(None)` when the header is absent and an error message when it
+/// is not a positive integer number of milliseconds.
+fn request_timeout_override(
+    req_headers: &http::HeaderMap,
+    max: Duration,
+) -> Result<Option<Duration>, String> {
+    let Some(value) = req_headers.get(TIMEOUT_OVERRIDE_HEADER) else {
+        return Ok(None);
+    };
+    let ms = value
+        .to_str()
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .filter(|&ms| ms > 0)
+        .ok_or_else(|| {
+            "X-OAGW-Timeout-Ms must be a positive integer number of milliseconds".to_string()
+        })?;
+    Ok(Some(Duration::from_millis(ms).min(max)))
+}
+
+impl DataPlaneServiceImpl {
+    /// The proxy pipeline behind [`DataPlaneService::proxy_request`].
+    ///
+    /// Stores the id of the resolved upstream in `upstream_id` so the
+    /// caller can label metrics even when a later step fails.
+    async fn proxy_request_inner(
         &self,
         ctx: SecurityContext,
         req: http::Request<Body>,
+        upstream_id: &mut Option<String>,
     ) -> Result<http::Response<Body>, DomainError> {
         let instance_uri = req.uri().to_string();
 
@@ -335,9 +493,9 @@ impl DataPlaneService for DataPlaneServiceImpl {
             )
             .await?;
 
-        // Extract alias from the raw path first, then normalize only the
-        // suffix. This prevents path traversal (e.g. `/../../admin/...`)
-        // from influencing alias extraction.
+        // Extract alias from the raw path first, then validate and normalize
+        // only the suffix. Traversal (`..`) and control characters are
+        // rejected rather than resolved, so upstreams never see them.
         let (alias, path_suffix) = {
             let path = req.uri().path();
             let trimmed = path.strip_prefix('/').unwrap_or(path);
@@ -345,7 +503,11 @@ impl DataPlaneService for DataPlaneServiceImpl {
                 Some(pos) => (&trimmed[..pos], &trimmed[pos..]),
                 None => (trimmed, ""),
             };
-            (alias.to_string(), normalize_path(raw_suffix))
+            let suffix = normalize_path(raw_suffix).map_err(|detail| DomainError::Validation {
+                detail,
+                instance: instance_uri.clone(),
+            })?;
+            (alias.to_string(), suffix)
         };
 
         // Parse query parameters with proper URL decoding.
@@ -382,6 +544,25 @@ impl DataPlaneService for DataPlaneServiceImpl {
             });
         }
 
+        // Reject conflicting Content-Length values (request smuggling).
+        if let Err(detail) = headers::unique_content_length(&req_headers) {
+            return Err(DomainError::Validation {
+                detail,
+                instance: instance_uri,
+            });
+        }
+
+        // Per-request timeout override, clamped to the configured maximum.
+        let timeout = match request_timeout_override(&req_headers, self.max_request_timeout) {
+            Ok(timeout) => timeout.unwrap_or(self.request_timeout),
+            Err(detail) => {
+                return Err(DomainError::Validation {
+                    detail,
+                    instance: instance_uri,
+                });
+            }
+        };
+
         // Conditional body conversion — keep streams for streaming request bodies.
         let max_body = self.max_body_size;
         let (body_bytes, body_stream): (Bytes, Option<BodyStream>) = match body {
@@ -401,11 +582,23 @@ impl DataPlaneService for DataPlaneServiceImpl {
             Body::Stream(s) => (Bytes::new(), Some(s)),
         };
 
-        // 1+2. Resolve upstream + route in one pass (single hierarchy walk).
+        // 1+2. Resolve upstream + route in one pass (single hierarchy walk),
+        // served from the route cache when possible.
+        let route_key = RouteKey {
+            tenant_id: ctx.subject_tenant_id(),
+            alias: alias.clone(),
+            method: method.to_string(),
+            path: path_suffix.clone(),
+        };
         let (upstream, route) = self
-            .cp
-            .resolve_proxy_target(&ctx, &alias, method.as_ref(), &path_suffix)
+            .route_cache
+            .get_or_resolve(route_key, || {
+                self.cp
+                    .resolve_proxy_target(&ctx, &alias, method.as_ref(), &path_suffix)
+            })
             .await?;
+        let upstream_label = upstream.id.to_string();
+        *upstream_id = Some(upstream_label.clone());
 
         // 1c. CORS origin enforcement for actual cross-origin requests.
         // Preflight is handled permissively at the handler level (no upstream resolution).
@@ -523,10 +716,8 @@ impl DataPlaneService for DataPlaneServiceImpl {
                 config: auth.config.clone().unwrap_or_default(),
                 security_context: ctx.clone(),
             };
-            plugin
-                .authenticate(&mut auth_ctx)
-                .await
-                .map_err(|e| match e {
+            plugin.authenticate(&mut auth_ctx).await.map_err(|e| {
+                let error = match e {
                     crate::domain::plugin::PluginError::SecretNotFound(ref s) => {
                         DomainError::SecretNotFound {
                             detail: s.clone(),
@@ -547,7 +738,12 @@ impl DataPlaneService for DataPlaneServiceImpl {
                             instance: instance_uri.clone(),
                         }
                     }
-                })?;
+                };
+                DomainError::PluginFailed {
+                    plugin_id: auth.plugin_type.clone(),
+                    error: Box::new(error),
+                }
+            })?;
             outbound_headers = headers::hash_map_to_header_map(&auth_ctx.headers);
             tracing::debug!(plugin = %auth.plugin_type, "auth plugin succeeded");
         }
@@ -562,6 +758,7 @@ impl DataPlaneService for DataPlaneServiceImpl {
             collect_plugin_bindings(&upstream, GuardPluginRegistry::is_guard_plugin);
 
         let guard_headers = headers::header_map_to_vec(&outbound_headers);
+        let has_body = !body_bytes.is_empty() || body_stream.is_some();
         for binding in &guard_bindings {
             let guard = self
                 .guard_registry
@@ -578,6 +775,7 @@ impl DataPlaneService for DataPlaneServiceImpl {
                 path: path_suffix.clone(),
                 status: None,
                 headers: guard_headers.clone(),
+                has_body,
                 config: binding.config.clone(),
                 security_context: ctx.clone(),
             };
@@ -594,6 +792,13 @@ impl DataPlaneService for DataPlaneServiceImpl {
                         error_code,
                         detail,
                         instance: instance_uri,
+                        plugin_id: binding.plugin_ref.clone(),
+                    });
+                }
+                Ok(GuardDecision::UnsupportedMediaType { detail }) => {
+                    return Err(DomainError::UnsupportedMediaType {
+                        detail,
+                        instance: instance_uri,
                     });
                 }
                 Err(e) => {
@@ -620,51 +825,23 @@ impl DataPlaneService for DataPlaneServiceImpl {
         // Placed after header rules so transforms have the final word on
         // outbound headers. Errors are logged and skipped — transforms use
         // log-and-continue semantics so a single misbehaving transform cannot
-        // block the pipeline. Compare with guards (step 4b) which fail-hard.
-        if !transform_bindings.is_empty() {
-            let mut transform_headers = headers::header_map_to_vec(&outbound_headers);
-            let mut transform_query: Vec<(String, String)> = query_params.clone();
-
-            for binding in &transform_bindings {
-                let mut transform_ctx = TransformRequestContext {
-                    method: method.to_string(),
-                    path: path_suffix.clone(),
-                    query: transform_query.clone(),
-                    headers: transform_headers.clone(),
-                    config: binding.config.clone(),
-                    security_context: ctx.clone(),
-                };
-                match self.transform_registry.resolve(&binding.plugin_ref) {
-                    Ok(transform) => match transform.on_request(&mut transform_ctx).await {
-                        Ok(()) => {
-                            transform_headers = transform_ctx.headers;
-                            transform_query = transform_ctx.query;
-                        }
-                        Err(e) => {
-                            tracing::warn!(
-                                plugin = %binding.plugin_ref,
-                                error = %e,
-                                "transform on_request failed, continuing"
-                            );
-                        }
-                    },
-                    Err(e) => {
-                        tracing::warn!(
-                            plugin = %binding.plugin_ref,
-                            error = %e,
-                            "transform plugin resolution failed, continuing"
-                        );
-                        continue;
-                    }
-                }
-            }
-
-            // Write mutated headers back to outbound_headers.
-            outbound_headers = headers::vec_to_header_map(&transform_headers);
-
-            // Write mutated query params back.
-            query_params = transform_query;
-        }
+        // block the pipeline — except an explicit `PluginError::Rejected`,
+        // which aborts with the error attributed to the transform.
+        query_params = execute_transform_requests(
+            &self.transform_registry,
+            &transform_bindings,
+            TransformRequestContext {
+                method: method.to_string(),
+                path: path_suffix.clone(),
+                query: std::mem::take(&mut query_params),
+                headers: Vec::new(),
+                config: std::collections::HashMap::new(),
+                security_context: ctx.clone(),
+            },
+            &mut outbound_headers,
+            &instance_uri,
+        )
+        .await?;
 
         // 5a. Endpoint selection (D1 — two-tier).
         let selected = self
@@ -682,16 +859,78 @@ impl DataPlaneService for DataPlaneServiceImpl {
 
         headers::set_host_header(&mut outbound_headers, &endpoint.host, endpoint.port);
 
-        // 6. Check rate limit (upstream then route).
+        // 6. Check rate limit (upstream then route). Streamed bodies are
+        //    costed by their declared Content-Length; without one they are
+        //    admitted at the minimum cost and per-kilobyte buckets are
+        //    charged the rest once the body has been forwarded.
+        let declared_len = req_headers
+            .get(http::header::CONTENT_LENGTH)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok());
+        let (body_len, body_len_unknown) = match (&body_stream, declared_len) {
+            (None, _) => (body_bytes.len() as u64, false),
+            (Some(_), Some(len)) => (len, false),
+            (Some(_), None) => (0, true),
+        };
+        let header_value = |name: &str| req_headers.get(name).and_then(|v| v.to_str().ok());
+        let mut charges: Vec<rate_limit::Charge> = Vec::new();
+        let mut consume = |key: String, rl: &crate::domain::model::RateLimitConfig| {
+            let cost = rate_limit::request_cost(rl, body_len, header_value);
+            self.rate_limiter
+                .try_consume(&key, rl, cost, &instance_uri)
+                .inspect_err(|_| self.record_rate_limited(&upstream_label))?;
+            charges.push(rate_limit::Charge {
+                key,
+                config: rl.clone(),
+                cost,
+            });
+            Ok::<(), DomainError>(())
+        };
         if let Some(ref rl) = upstream.rate_limit {
-            let key = format!("upstream:{}", upstream.id);
-            self.rate_limiter.try_consume(&key, rl, &instance_uri)?;
+            consume(format!("upstream:{}", upstream.id), rl)?;
         }
         if let Some(ref rl) = route.rate_limit {
-            let key = format!("route:{}", route.id);
-            self.rate_limiter.try_consume(&key, rl, &instance_uri)?;
+            consume(format!("route:{}", route.id), rl)?;
+        }
+        // 6b. Tenant limit: the default for unlimited upstream/route pairs,
+        //     or a cap on all of the tenant's traffic when aggregate.
+        if let Some(tenant_limit) = self.tenant_rate_limit(&ctx).await?
+            && (tenant_limit.aggregate
+                || (upstream.rate_limit.is_none() && route.rate_limit.is_none()))
+        {
+            consume(
+                format!("tenant:{}", tenant_limit.tenant_id),
+                &tenant_limit.config,
+            )?;
+        }
+        let mut deferred_charges = rate_limit::DeferredCharges::new(self.rate_limiter.clone());
+        if body_len_unknown {
+            charges.iter().for_each(|c| deferred_charges.track(c));
         }
 
+        // 6c. Gzip large buffered bodies on routes that opt in. Runs after
+        //     plugins so transforms see the original body, and after rate
+        //     limiting so costs reflect the uncompressed size. A body the
+        //     client (or a transform) already encoded is never encoded twice,
+        //     even if passthrough rules dropped its Content-Encoding.
+        let body_bytes = match route.request_compression {
+            Some(rc)
+                if body_bytes.len() as u64 > rc.min_bytes
+                    && !req_headers.contains_key(http::header::CONTENT_ENCODING)
+                    && !outbound_headers.contains_key(http::header::CONTENT_ENCODING) =>
+            {
+                let compressed = gzip(&body_bytes).map_err(|e| {
+                    DomainError::internal(format!("failed to gzip request body: {e}"))
+                })?;
+                outbound_headers.insert(
+                    http::header::CONTENT_ENCODING,
+                    HeaderValue::from_static("gzip"),
+                );
+                compressed
+            }
+            _ => body_bytes,
+        };
+
         // 7. Build URL.
         // path_suffix is the full path from the proxy URL; strip the route prefix
         // so we get: endpoint + route_path + remaining_suffix.
@@ -749,6 +988,7 @@ impl DataPlaneService for DataPlaneServiceImpl {
             cors_config: effective_cors.as_ref(),
             origin: request_origin,
             response_header_rules,
+            upstream_label: &upstream_label,
         };
 
         // 8. WebSocket upgrade path: bypass the normal request/response bridge
@@ -775,7 +1015,7 @@ impl DataPlaneService for DataPlaneServiceImpl {
                 })?;
 
             // Parse only the response headers (IO stays intact for bidirectional copy).
-            let upgrade_timeout = self.request_timeout;
+            let upgrade_timeout = timeout;
             let (status, resp_headers, leftover) = tokio::time::timeout(
                 upgrade_timeout,
                 session_bridge::parse_upgrade_response(&mut client_io),
@@ -822,43 +1062,35 @@ impl DataPlaneService for DataPlaneServiceImpl {
                     message: format!("failed to build WebSocket upgrade response: {e}"),
                 })?;
             *resp.headers_mut() = resp_headers;
+            let bridge =
+                super::websocket::WebSocketBridgeHandle::new(super::websocket::WebSocketBridgeIo {
+                    io: client_io,
+                    leftover,
+                    idle_timeout: self.websocket_idle_timeout,
+                    close_timeout: self.websocket_close_timeout,
+                    max_frame_size: self.websocket_max_frame_size,
+                    shutdown_rx: self.shutdown_rx.clone(),
+                });
+            // The REST handler relays frames to the caller's upgraded
+            // connection; in-process callers take a message stream instead.
+            // Whichever claims the tunnel first owns it.
+            let upgrade = bridge.clone();
             resp.extensions_mut()
-                .insert(super::websocket::WebSocketBridgeHandle::new(
-                    super::websocket::WebSocketBridgeIo {
-                        io: client_io,
-                        leftover,
-                        idle_timeout: self.websocket_idle_timeout,
-                        close_timeout: self.websocket_close_timeout,
-                        max_frame_size: self.websocket_max_frame_size,
-                        shutdown_rx: self.shutdown_rx.clone(),
-                    },
-                ));
+                .insert(oagw_sdk::ws::WebSocketUpgrade::new(move || {
+                    upgrade.take().map(super::websocket::into_message_stream)
+                }));
+            resp.extensions_mut().insert(bridge);
             return Ok(resp);
         }
 
-        // 8. Bridge request into Pingora via in-memory DuplexStream.
-        let (client_io, server_io) = tokio::io::duplex(65_536);
-
-        // Create Pingora H1 session from the server side of the DuplexStream.
-        // Pingora implements all IO traits for DuplexStream (in ext_io_impl).
-        let session = pingora_core::protocols::http::ServerSession::new_http1(Box::new(server_io));
-
-        // Spawn Pingora proxy processing in background.
-        let proxy = self.proxy.clone();
-        let shutdown = self.shutdown_rx.clone();
-        tokio::spawn(async move {
-            proxy.process_new_http(session, &shutdown).await;
-        });
-
-        // Write the request and read the response from the client side.
-        let timeout = self.request_timeout;
-
+        // 8. Bridge request into Pingora via in-memory DuplexStream, write
+        //    the request and read the response from the client side.
         let upstream_result: Result<http::Response<Body>, DomainError> = if let Some(
             mut body_stream,
         ) = body_stream
         {
             // Streaming path: write headers, then forward body chunks concurrently.
-            let (client_read, mut client_write) = tokio::io::split(client_io);
+            let (client_read, mut client_write) = tokio::io::split(self.open_bridge());
 
             let header_bytes =
                 session_bridge::serialize_request_wire(&method, &url, &outbound_headers, None);
@@ -876,6 +1108,8 @@ impl DataPlaneService for DataPlaneServiceImpl {
             let (limit_tx, limit_rx) = tokio::sync::oneshot::channel::<usize>();
             let (abort_tx, abort_rx) = tokio::sync::oneshot::channel::<String>();
             let body_instance_uri = instance_uri.clone();
+            let body_metrics = self.metrics.clone();
+            let body_label = upstream_label.clone();
             tokio::spawn(async move {
                 let mut total_bytes: usize = 0;
                 let mut exceeded = false;
@@ -883,6 +1117,7 @@ impl DataPlaneService for DataPlaneServiceImpl {
                     match chunk {
                         Ok(bytes) if !bytes.is_empty() => {
                             total_bytes = total_bytes.saturating_add(bytes.len());
+                            deferred_charges.add_body_bytes(bytes.len() as u64);
                             if total_bytes > max_body {
                                 tracing::warn!(
                                     total_bytes,
@@ -909,6 +1144,11 @@ impl DataPlaneService for DataPlaneServiceImpl {
                                 let _ = abort_tx.send(format!("body stream write error: {e}"));
                                 return;
                             }
+                            body_metrics.add_to_counter(
+                                metrics::PROXY_REQUEST_BODY_BYTES_TOTAL,
+                                &[(metrics::LABEL_UPSTREAM, &body_label)],
+                                bytes.len() as u64,
+                            );
                         }
                         Ok(_) => {} // skip empty chunks
                         Err(e) => {
@@ -979,37 +1219,90 @@ impl DataPlaneService for DataPlaneServiceImpl {
                 }
             }
         } else {
-            // Buffered path: write full request, shutdown write side, then read response.
-            let wire = session_bridge::serialize_request_wire(
-                &method,
-                &url,
-                &outbound_headers,
-                Some(&body_bytes),
-            );
-            let mut client_io = client_io;
-            client_io
-                .write_all(&wire)
-                .await
-                .map_err(|e| DomainError::DownstreamError {
-                    detail: format!("failed to write to proxy bridge: {e}"),
-                    instance: instance_uri.clone(),
-                })?;
-            // Do NOT shutdown the write side — Pingora uses Content-Length to
-            // determine the request boundary, and an early write-close is
-            // misinterpreted as "downstream dropped the connection".
+            // Buffered path: write full request, then read response.
+            let request = BufferedRequest {
+                method: &method,
+                url: &url,
+                headers: &outbound_headers,
+                body: &body_bytes,
+                timeout,
+                instance_uri: &instance_uri,
+                upstream_label: &upstream_label,
+            };
+            let exchange = || self.send_buffered(&request);
+
+            // 9a. GETs on routes opting into response caching are answered
+            //     from a stored response while it is fresh, and identical
+            //     concurrent GETs on routes opting into coalescing share one
+            //     upstream call. Each still passed its own guards and rate
+            //     limits above.
+            let is_plain_get = method == http::Method::GET
+                && body_bytes.is_empty()
+                && !outbound_headers.contains_key(http::header::RANGE);
+            let fetch = || async {
+                match route.get_coalescing {
+                    Some(ref cfg) if method == http::Method::GET && body_bytes.is_empty() => {
+                        let key = CoalesceKey::new(
+                            ctx.subject_tenant_id(),
+                            upstream.id,
+                            url.clone(),
+                            &cfg.vary_headers,
+                            &outbound_headers,
+                        );
+                        self.coalescer
+                            .run(key, timeout, self.max_body_size, &instance_uri, exchange)
+                            .await
+                    }
+                    _ => exchange().await,
+                }
+            };
+            let (status, resp_headers, resp_body_stream) = match route.response_cache {
+                Some(cfg) if is_plain_get => {
+                    let key = ResponseCacheKey {
+                        tenant_id: ctx.subject_tenant_id(),
+                        upstream_id: upstream.id,
+                        url: url.clone(),
+                    };
+                    self.response_cache
+                        .get_or_fetch(
+                            key,
+                            Duration::from_secs(cfg.ttl_secs),
+                            &req_headers,
+                            &instance_uri,
+                            fetch,
+                        )
+                        .await?
+                }
+                _ => fetch().await?,
+            };
 
-            // 9. Parse response.
-            let (status, resp_headers, resp_body_stream) =
-                tokio::time::timeout(timeout, session_bridge::parse_response_stream(client_io))
-                    .await
-                    .map_err(|_| DomainError::RequestTimeout {
-                        detail: format!("request to {url} timed out after {timeout:?}"),
-                        instance: instance_uri.clone(),
-                    })?
-                    .map_err(|e| DomainError::DownstreamError {
-                        detail: format!("proxy bridge error: {e}"),
-                        instance: instance_uri.clone(),
-                    })?;
+            // 9b. Routes opting into SSE reconnection resume a dropped event
+            //     stream by replaying the request with `Last-Event-ID`.
+            //     Each reconnect is charged like the original request.
+            let resp_body_stream = match route.sse_reconnect {
+                Some(cfg)
+                    if status == http::StatusCode::OK
+                        && oagw_sdk::sse::is_server_events_response(&resp_headers) =>
+                {
+                    sse_reconnect::resumable_sse_stream(
+                        resp_body_stream,
+                        sse_reconnect::SseReplay {
+                            proxy: self.proxy.clone(),
+                            shutdown_rx: self.shutdown_rx.clone(),
+                            method: method.clone(),
+                            url: url.clone(),
+                            headers: outbound_headers.clone(),
+                            body: body_bytes.clone(),
+                            timeout,
+                            rate_limiter: self.rate_limiter.clone(),
+                            charges: charges.clone(),
+                            instance_uri: instance_uri.clone(),
+                        },
+                        cfg.max_retries,
+                    )
+                }
+                _ => resp_body_stream,
+            };
 
             self.finalize_response(
                 &pipeline,
@@ -1037,9 +1330,166 @@ impl DataPlaneService for DataPlaneServiceImpl {
         }
     }
 
+    /// Send a buffered request through a fresh Pingora session and return
+    /// the upstream response once its headers arrive.
+    async fn send_buffered(
+        &self,
+        req: &BufferedRequest<'_>,
+    ) -> Result<coalesce::Exchange, DomainError> {
+        let wire = session_bridge::serialize_request_wire(
+            req.method,
+            req.url,
+            req.headers,
+            Some(req.body),
+        );
+        let mut client_io = self.open_bridge();
+        client_io
+            .write_all(&wire)
+            .await
+            .map_err(|e| DomainError::DownstreamError {
+                detail: format!("failed to write to proxy bridge: {e}"),
+                instance: req.instance_uri.to_owned(),
+            })?;
+        if !req.body.is_empty() {
+            self.metrics.add_to_counter(
+                metrics::PROXY_REQUEST_BODY_BYTES_TOTAL,
+                &[(metrics::LABEL_UPSTREAM, req.upstream_label)],
+                req.body.len() as u64,
+            );
+        }
+        // Do NOT shutdown the write side — Pingora uses Content-Length to
+        // determine the request boundary, and an early write-close is
+        // misinterpreted as "downstream dropped the connection".
+
+        // 9. Parse response.
+        let timeout = req.timeout;
+        tokio::time::timeout(timeout, session_bridge::parse_response_stream(client_io))
+            .await
+            .map_err(|_| DomainError::RequestTimeout {
+                detail: format!("request to {} timed out after {timeout:?}", req.url),
+                instance: req.instance_uri.to_owned(),
+            })?
+            .map_err(|e| DomainError::DownstreamError {
+                detail: format!("proxy bridge error: {e}"),
+                instance: req.instance_uri.to_owned(),
+            })
+    }
+
+    /// Open an in-memory HTTP/1 session served by Pingora and return the
+    /// client side of it.
+    fn open_bridge(&self) -> tokio::io::DuplexStream {
+        let (client_io, server_io) = tokio::io::duplex(65_536);
+
+        // Create Pingora H1 session from the server side of the DuplexStream.
+        // Pingora implements all IO traits for DuplexStream (in ext_io_impl).
+        let session = pingora_core::protocols::http::ServerSession::new_http1(Box::new(server_io));
+
+        // Spawn Pingora proxy processing in background.
+        let proxy = self.proxy.clone();
+        let shutdown = self.shutdown_rx.clone();
+        tokio::spawn(async move {
+            proxy.process_new_http(session, &shutdown).await;
+        });
+        client_io
+    }
+
+    /// Rate limit of the caller's tenant, read through `tenant_rate_limits`.
+    async fn tenant_rate_limit(
+        &self,
+        ctx: &SecurityContext,
+    ) -> Result<Option<TenantRateLimit>, DomainError> {
+        self.tenant_rate_limits
+            .get_or_resolve(ctx.subject_tenant_id(), || {
+                self.cp.get_tenant_rate_limit(ctx)
+            })
+            .await
+    }
+
+    fn record_rate_limited(&self, upstream_label: &str) {
+        self.metrics.increment_counter(
+            metrics::PROXY_RATE_LIMITED_TOTAL,
+            &[(metrics::LABEL_UPSTREAM, upstream_label)],
+        );
+    }
+
+    /// Record count, status class and latency of one proxied request.
+    /// Latency covers the pipeline up to response headers, not body streaming.
+    fn record_request(&self, upstream_label: &str, status: u16, elapsed: Duration) {
+        let upstream = [(metrics::LABEL_UPSTREAM, upstream_label)];
+        self.metrics
+            .increment_counter(metrics::PROXY_REQUESTS_TOTAL, &upstream);
+        self.metrics.increment_counter(
+            metrics::PROXY_RESPONSES_TOTAL,
+            &[
+                (metrics::LABEL_UPSTREAM, upstream_label),
+                (metrics::LABEL_STATUS_CLASS, metrics::status_class(status)),
+            ],
+        );
+        self.metrics.record_histogram(
+            metrics::PROXY_REQUEST_DURATION_SECONDS,
+            &upstream,
+            elapsed.as_secs_f64(),
+        );
+    }
+}
+
+#[async_trait]
+impl DataPlaneService for DataPlaneServiceImpl {
+    async fn proxy_request(
+        &self,
+        ctx: SecurityContext,
+        req: http::Request<Body>,
+    ) -> Result<http::Response<Body>, DomainError> {
+        let Some(in_flight) = self.drain.enter() else {
+            return Err(DomainError::LinkUnavailable {
+                detail: "gateway is shutting down".into(),
+                instance: req.uri().to_string(),
+            });
+        };
+        let started = Instant::now();
+        let mut upstream_id = None;
+        let result = self
+            .proxy_request_inner(ctx, req, &mut upstream_id)
+            .await
+            .map(|resp| {
+                resp.map(|body| match body {
+                    Body::Stream(stream) => Body::Stream(in_flight.hold_until_end(stream)),
+                    other => other,
+                })
+            });
+        let status = match &result {
+            Ok(resp) => resp.status().as_u16(),
+            Err(err) => domain_error_status(err),
+        };
+        self.record_request(
+            upstream_id
+                .as_deref()
+                .unwrap_or(metrics::UNRESOLVED_UPSTREAM),
+            status,
+            started.elapsed(),
+        );
+        result
+    }
+
     fn remove_rate_limit_key(&self, key: &str) {
         self.rate_limiter.remove_key(key);
     }
+
+    async fn drain(&self, timeout: Duration) -> usize {
+        self.drain.wait(timeout).await
+    }
+
+    async fn upstream_health(
+        &self,
+        ctx: &SecurityContext,
+        alias: &str,
+    ) -> Result<Vec<EndpointHealth>, DomainError> {
+        let upstream = self.cp.resolve_upstream(ctx, alias).await?;
+        Ok(self
+            .backend_selector
+            .health(upstream.id, &upstream.server.endpoints)
+            .await)
+    }
 }
 
 /// Collect plugin bindings from the effective upstream, filtered by a type predicate.
@@ -1093,6 +1543,7 @@ async fn execute_guard_responses(
             path: path.to_string(),
             status: Some(resp_status.as_u16()),
             headers: resp_header_map.clone(),
+            has_body: false,
             config: binding.config.clone(),
             security_context: security_context.clone(),
         };
@@ -1109,6 +1560,13 @@ async fn execute_guard_responses(
                     error_code,
                     detail,
                     instance: instance_uri.to_string(),
+                    plugin_id: binding.plugin_ref.clone(),
+                });
+            }
+            Ok(GuardDecision::UnsupportedMediaType { detail }) => {
+                return Err(DomainError::UnsupportedMediaType {
+                    detail,
+                    instance: instance_uri.to_string(),
                 });
             }
             Err(e) => {
@@ -1121,6 +1579,74 @@ async fn execute_guard_responses(
     Ok(())
 }
 
+/// Execute `on_request` for all transform bindings and return the
+/// (possibly rewritten) query parameters.
+///
+/// `base` carries the method, path, query and security context; headers are
+/// taken from `outbound_headers`, which receives the transformed headers.
+/// Errors are logged and skipped, except `PluginError::Rejected`, which
+/// aborts with the error attributed to the rejecting transform.
+async fn execute_transform_requests(
+    transform_registry: &TransformPluginRegistry,
+    transform_bindings: &[&crate::domain::model::PluginBinding],
+    base: TransformRequestContext,
+    outbound_headers: &mut HeaderMap,
+    instance_uri: &str,
+) -> Result<Vec<(String, String)>, DomainError> {
+    if transform_bindings.is_empty() {
+        return Ok(base.query);
+    }
+
+    let mut transform_headers = headers::header_map_to_vec(outbound_headers);
+    let mut transform_query = base.query;
+
+    for binding in transform_bindings {
+        let mut transform_ctx = TransformRequestContext {
+            method: base.method.clone(),
+            path: base.path.clone(),
+            query: transform_query.clone(),
+            headers: transform_headers.clone(),
+            config: binding.config.clone(),
+            security_context: base.security_context.clone(),
+        };
+        match transform_registry.resolve(&binding.plugin_ref) {
+            Ok(transform) => match transform.on_request(&mut transform_ctx).await {
+                Ok(()) => {
+                    transform_headers = transform_ctx.headers;
+                    transform_query = transform_ctx.query;
+                }
+                Err(crate::domain::plugin::PluginError::Rejected(detail)) => {
+                    return Err(DomainError::PluginFailed {
+                        plugin_id: binding.plugin_ref.clone(),
+                        error: Box::new(DomainError::Validation {
+                            detail,
+                            instance: instance_uri.to_string(),
+                        }),
+                    });
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        plugin = %binding.plugin_ref,
+                        error = %e,
+                        "transform on_request failed, continuing"
+                    );
+                }
+            },
+            Err(e) => {
+                tracing::warn!(
+                    plugin = %binding.plugin_ref,
+                    error = %e,
+                    "transform plugin resolution failed, continuing"
+                );
+            }
+        }
+    }
+
+    // Write mutated headers back.
+    *outbound_headers = headers::vec_to_header_map(&transform_headers);
+    Ok(transform_query)
+}
+
 /// Execute `on_response` for all transform bindings, logging errors without aborting.
 ///
 /// Unlike guard execution, transform errors are logged and skipped — a single
@@ -1174,6 +1700,18 @@ async fn execute_transform_responses(
     *resp_headers = headers::vec_to_header_map(&header_map);
 }
 
+/// Outbound request sent in one piece on the buffered path.
+struct BufferedRequest<'a> {
+    method: &'a http::Method,
+    url: &'a str,
+    headers: &'a HeaderMap,
+    body: &'a Bytes,
+    timeout: Duration,
+    instance_uri: &'a str,
+    /// Metrics label of the upstream (its id).
+    upstream_label: &'a str,
+}
+
 /// Per-request plugin pipeline state shared across the streaming and buffered
 /// response paths.
 struct ResponsePipelineCtx<'a> {
@@ -1185,6 +1723,8 @@ struct ResponsePipelineCtx<'a> {
     cors_config: Option<&'a crate::domain::model::CorsConfig>,
     origin: Option<String>,
     response_header_rules: Option<&'a ResponseHeaderRules>,
+    /// Metrics label of the upstream (its id).
+    upstream_label: &'a str,
 }
 
 /// Execute `on_error` for all transform bindings, logging errors without aborting.
@@ -1249,6 +1789,7 @@ fn domain_error_status(err: &DomainError) -> u16 {
         DomainError::NotFound { .. } => 404,
         DomainError::Conflict { .. } => 409,
         DomainError::PayloadTooLarge { .. } => 413,
+        DomainError::UnsupportedMediaType { .. } => 415,
         DomainError::RateLimitExceeded { .. } => 429,
         DomainError::SecretNotFound { .. } | DomainError::Internal { .. } => 500,
         DomainError::DownstreamError { .. } | DomainError::ProtocolError { .. } => 502,
@@ -1263,6 +1804,7 @@ fn domain_error_status(err: &DomainError) -> u16 {
         DomainError::PluginInUse { .. } => 409,
         DomainError::GuardRejected { status, .. } => *status,
         DomainError::CorsOriginNotAllowed { .. } | DomainError::CorsMethodNotAllowed { .. } => 403,
+        DomainError::PluginFailed { error, .. } => domain_error_status(error),
     }
 }
 
@@ -1277,6 +1819,7 @@ fn domain_error_type_name(err: &DomainError) -> &'static str {
         DomainError::AuthenticationFailed { .. } => "AuthenticationFailed",
         DomainError::NotFound { .. } => "NotFound",
         DomainError::PayloadTooLarge { .. } => "PayloadTooLarge",
+        DomainError::UnsupportedMediaType { .. } => "UnsupportedMediaType",
         DomainError::RateLimitExceeded { .. } => "RateLimitExceeded",
         DomainError::SecretNotFound { .. } => "SecretNotFound",
         DomainError::DownstreamError { .. } => "DownstreamError",
@@ -1295,9 +1838,43 @@ fn domain_error_type_name(err: &DomainError) -> &'static str {
         DomainError::PluginNotFound { .. } => "PluginNotFound",
         DomainError::PluginInUse { .. } => "PluginInUse",
         DomainError::Forbidden { .. } => "Forbidden",
+        DomainError::PluginFailed { .. } => "PluginFailed",
     }
 }
 
+/// Gzip-compress a buffered request body.
+fn gzip(body: &[u8]) -> std::io::Result<Bytes> {
+    use std::io::Write as _;
+
+    let mut encoder = flate2::write::GzEncoder::new(
+        Vec::with_capacity(body.len() / 2),
+        flate2::Compression::default(),
+    );
+    encoder.write_all(body)?;
+    encoder.finish().map(Bytes::from)
+}
+
+/// Wrap `body` so every non-empty chunk adds its length to the byte counter
+/// `name` as it is read, so streamed bodies are counted incrementally.
+fn metered_body(
+    body: BodyStream,
+    metrics: Arc<dyn Metrics>,
+    name: &'static str,
+    upstream_label: String,
+) -> BodyStream {
+    Box::pin(body.inspect(move |chunk| {
+        if let Ok(bytes) = chunk
+            && !bytes.is_empty()
+        {
+            metrics.add_to_counter(
+                name,
+                &[(metrics::LABEL_UPSTREAM, &upstream_label)],
+                bytes.len() as u64,
+            );
+        }
+    }))
+}
+
 /// Build the final proxy response: extract error source, sanitize headers,
 /// assemble the `http::Response<Body>`.
 fn build_proxy_response(
@@ -1307,6 +1884,8 @@ fn build_proxy_response(
     instance_uri: String,
 ) -> Result<http::Response<Body>, DomainError> {
     let error_source = headers::extract_error_source(&resp_headers);
+    let error_plugin = headers::extract_error_plugin(&resp_headers)
+        .filter(|_| error_source == ErrorSource::Plugin);
     headers::sanitize_response_headers(&mut resp_headers);
 
     let mut resp = http::Response::builder()
@@ -1318,20 +1897,26 @@ fn build_proxy_response(
         })?;
     *resp.headers_mut() = resp_headers;
     resp.extensions_mut().insert(error_source);
+    if let Some(plugin) = error_plugin {
+        resp.extensions_mut().insert(plugin);
+    }
     Ok(resp)
 }
 
-/// Normalize a URL path: collapse consecutive slashes and resolve `.`/`..` segments.
-/// Segments that would escape above the root are discarded.
-fn normalize_path(path: &str) -> String {
+/// Validate and normalize a URL path: collapse consecutive slashes and drop `.`
+/// segments. `..` segments and control characters (checked after percent-decoding)
+/// are rejected, since upstreams may resolve them differently than the route matcher.
+fn normalize_path(path: &str) -> Result<String, String> {
     let mut segments: Vec<&str> = Vec::new();
     for seg in path.split('/') {
-        match seg {
-            "" | "." => {}
-            ".." => {
-                segments.pop();
-            }
-            s => segments.push(s),
+        let decoded: Vec<u8> = percent_encoding::percent_decode_str(seg).collect();
+        if decoded.iter().any(u8::is_ascii_control) {
+            return Err("request path must not contain control characters".into());
+        }
+        match decoded.as_slice() {
+            b"" | b"." => {}
+            b".." => return Err("request path must not contain '..' segments".into()),
+            _ => segments.push(seg),
         }
     }
     let mut result = String::with_capacity(path.len());
@@ -1339,7 +1924,7 @@ fn normalize_path(path: &str) -> String {
         result.push('/');
     }
     result.push_str(&segments.join("/"));
-    result
+    Ok(result)
 }
 
 #[cfg(test)]
@@ -1352,27 +1937,42 @@ mod tests {
 
     #[test]
     fn normalize_collapses_double_slashes() {
-        assert_eq!(normalize_path("/alias//v1//chat"), "/alias/v1/chat");
+        assert_eq!(
+            normalize_path("/alias//v1//chat").unwrap(),
+            "/alias/v1/chat"
+        );
+    }
+
+    #[test]
+    fn normalize_rejects_dot_dot() {
+        let err = normalize_path("/alias/../admin/secret").unwrap_err();
+        assert!(err.contains("'..'"), "{err}");
+        assert!(normalize_path("/alias/../../etc/passwd").is_err());
     }
 
     #[test]
-    fn normalize_resolves_dot_dot() {
-        assert_eq!(normalize_path("/alias/../admin/secret"), "/admin/secret");
+    fn normalize_rejects_percent_encoded_dot_dot() {
+        assert!(normalize_path("/v1/%2e%2E/admin").is_err());
     }
 
     #[test]
-    fn normalize_clamps_above_root() {
-        assert_eq!(normalize_path("/alias/../../etc/passwd"), "/etc/passwd");
+    fn normalize_rejects_control_characters() {
+        let err = normalize_path("/v1/chat%0d%0aX-Injected:1").unwrap_err();
+        assert!(err.contains("control characters"), "{err}");
+        assert!(normalize_path("/v1/nul%00byte").is_err());
     }
 
     #[test]
     fn normalize_resolves_single_dot() {
-        assert_eq!(normalize_path("/alias/./v1/chat"), "/alias/v1/chat");
+        assert_eq!(
+            normalize_path("/alias/./v1/chat").unwrap(),
+            "/alias/v1/chat"
+        );
     }
 
     #[test]
     fn normalize_preserves_clean_path() {
-        assert_eq!(normalize_path("/alias/v1/chat"), "/alias/v1/chat");
+        assert_eq!(normalize_path("/alias/v1/chat").unwrap(), "/alias/v1/chat");
     }
 
     // -----------------------------------------------------------------------
@@ -1482,7 +2082,7 @@ mod tests {
         // Minimal CP — never called by select_endpoint().
         use crate::domain::error::DomainError;
         use crate::domain::model::*;
-        use crate::domain::services::ControlPlaneService;
+        use crate::domain::services::{ControlPlaneService, CreatedUpstream, ValidationIssue};
 
         struct NoopCp;
         #[async_trait]
@@ -1494,6 +2094,13 @@ mod tests {
             ) -> Result<Upstream, DomainError> {
                 unimplemented!()
             }
+            async fn create_upstream_idempotent(
+                &self,
+                _: &SecurityContext,
+                _: CreateUpstreamRequest,
+            ) -> Result<CreatedUpstream, DomainError> {
+                unimplemented!()
+            }
             async fn get_upstream(
                 &self,
                 _: &SecurityContext,
@@ -1552,6 +2159,32 @@ mod tests {
             async fn delete_route(&self, _: &SecurityContext, _: Uuid) -> Result<(), DomainError> {
                 unimplemented!()
             }
+            async fn get_tenant_rate_limit(
+                &self,
+                _: &SecurityContext,
+            ) -> Result<Option<TenantRateLimit>, DomainError> {
+                unimplemented!()
+            }
+            async fn set_tenant_rate_limit(
+                &self,
+                _: &SecurityContext,
+                _: RateLimitConfig,
+                _: bool,
+            ) -> Result<TenantRateLimit, DomainError> {
+                unimplemented!()
+            }
+            async fn delete_tenant_rate_limit(
+                &self,
+                _: &SecurityContext,
+            ) -> Result<(), DomainError> {
+                unimplemented!()
+            }
+            async fn list_credential_refs(
+                &self,
+                _: &SecurityContext,
+            ) -> Result<Vec<String>, DomainError> {
+                unimplemented!()
+            }
             async fn resolve_proxy_target(
                 &self,
                 _: &SecurityContext,
@@ -1561,6 +2194,33 @@ mod tests {
             ) -> Result<(Upstream, Route), DomainError> {
                 unimplemented!()
             }
+            async fn resolve_upstream(
+                &self,
+                _: &SecurityContext,
+                _: &str,
+            ) -> Result<Upstream, DomainError> {
+                unimplemented!()
+            }
+            async fn validate_upstream(
+                &self,
+                _: &SecurityContext,
+                _: &CreateUpstreamRequest,
+            ) -> Result<Vec<ValidationIssue>, DomainError> {
+                unimplemented!()
+            }
+            async fn validate_route(
+                &self,
+                _: &SecurityContext,
+                _: &CreateRouteRequest,
+            ) -> Result<Vec<ValidationIssue>, DomainError> {
+                unimplemented!()
+            }
+            fn subscribe(&self) -> broadcast::Receiver<ConfigChange> {
+                unimplemented!()
+            }
+            async fn check_ready(&self) -> Result<(), DomainError> {
+                unimplemented!()
+            }
         }
 
         let cp: Arc<dyn ControlPlaneService> = Arc::new(NoopCp);
@@ -1586,12 +2246,12 @@ mod tests {
         )
     }
 
-    // P2 #12: Alias extraction happens on raw path, then suffix is normalized.
-    // Path traversal in the alias segment must not influence which upstream is resolved.
+    // P2 #12: Alias extraction happens on raw path, then suffix is validated.
+    // Path traversal after the alias segment is rejected, never resolved.
     #[test]
-    fn alias_extraction_ignores_path_traversal() {
+    fn alias_extraction_rejects_path_traversal() {
         // Simulate what proxy_request does: extract alias from raw path, normalize suffix.
-        fn extract(path: &str) -> (String, String) {
+        fn extract(path: &str) -> (String, Result<String, String>) {
             let trimmed = path.strip_prefix('/').unwrap_or(path);
             let (alias, raw_suffix) = match trimmed.find('/') {
                 Some(pos) => (&trimmed[..pos], &trimmed[pos..]),
@@ -1601,19 +2261,14 @@ mod tests {
         }
 
         // Normal case.
-        let (alias, suffix) = extract("/myalias/v1/chat");
+        let (alias, suffix) = extract("/myalias/v1//chat");
         assert_eq!(alias, "myalias");
-        assert_eq!(suffix, "/v1/chat");
+        assert_eq!(suffix.unwrap(), "/v1/chat");
 
-        // Path traversal attempt: alias is still the first raw segment.
+        // Path traversal attempt: alias is still the first raw segment, suffix rejected.
         let (alias, suffix) = extract("/myalias/../admin/secret");
         assert_eq!(alias, "myalias");
-        assert_eq!(suffix, "/admin/secret"); // ".." collapsed in suffix only
-
-        // Deep traversal: alias is still literal first segment.
-        let (alias, suffix) = extract("/myalias/../../etc/passwd");
-        assert_eq!(alias, "myalias");
-        assert_eq!(suffix, "/etc/passwd"); // ".." collapsed, clamped at root
+        assert!(suffix.is_err());
     }
 
     // P2: HTTPS-only — Http scheme endpoint must be rejected.
@@ -1791,4 +2446,100 @@ mod tests {
             "expected UnknownTargetHost for mismatched header on single-endpoint upstream"
         );
     }
+
+    #[test]
+    fn timeout_override_absent_uses_default() {
+        let headers = http::HeaderMap::new();
+        assert_eq!(
+            request_timeout_override(&headers, MAX_REQUEST_TIMEOUT),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn timeout_override_is_clamped_to_max() {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(TIMEOUT_OVERRIDE_HEADER, "250".parse().unwrap());
+        assert_eq!(
+            request_timeout_override(&headers, Duration::from_secs(1)),
+            Ok(Some(Duration::from_millis(250)))
+        );
+
+        headers.insert(TIMEOUT_OVERRIDE_HEADER, "600000".parse().unwrap());
+        assert_eq!(
+            request_timeout_override(&headers, Duration::from_secs(1)),
+            Ok(Some(Duration::from_secs(1)))
+        );
+    }
+
+    #[test]
+    fn timeout_override_rejects_invalid_values() {
+        for bad in ["", "0", "-5", "1.5", "fast", "99999999999999999999"] {
+            let mut headers = http::HeaderMap::new();
+            headers.insert(TIMEOUT_OVERRIDE_HEADER, bad.parse().unwrap());
+            assert!(
+                request_timeout_override(&headers, MAX_REQUEST_TIMEOUT).is_err(),
+                "{bad:?} should be rejected"
+            );
+        }
+    }
+
+    struct RejectingTransform;
+
+    #[async_trait]
+    impl crate::domain::plugin::TransformPlugin for RejectingTransform {
+        async fn on_request(
+            &self,
+            _ctx: &mut TransformRequestContext,
+        ) -> Result<(), crate::domain::plugin::PluginError> {
+            Err(crate::domain::plugin::PluginError::Rejected(
+                "payload not allowed".into(),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    #[allow(clippy::expect_used)]
+    async fn transform_rejection_is_attributed_to_the_plugin() {
+        let plugin_id = "gts.x.core.oagw.transform_plugin.v1~test.rejecting.v1";
+        let mut registry = TransformPluginRegistry::with_builtins();
+        registry.register(plugin_id, Arc::new(RejectingTransform));
+        let binding = crate::domain::model::PluginBinding {
+            plugin_ref: plugin_id.into(),
+            config: std::collections::HashMap::new(),
+        };
+        let base = TransformRequestContext {
+            method: "POST".into(),
+            path: "/v1/items".into(),
+            query: Vec::new(),
+            headers: Vec::new(),
+            config: std::collections::HashMap::new(),
+            security_context: SecurityContext::builder()
+                .subject_tenant_id(Uuid::new_v4())
+                .subject_id(Uuid::new_v4())
+                .build()
+                .expect("test security context"),
+        };
+
+        let err = execute_transform_requests(
+            &registry,
+            &[&binding],
+            base,
+            &mut HeaderMap::new(),
+            "/api/v1/items",
+        )
+        .await
+        .unwrap_err();
+
+        let DomainError::PluginFailed {
+            plugin_id: failed,
+            error,
+        } = &err
+        else {
+            panic!("expected PluginFailed, got {err:?}");
+        };
+        assert_eq!(failed, plugin_id);
+        assert!(matches!(**error, DomainError::Validation { .. }));
+        assert_eq!(domain_error_status(&err), 400);
+    }
 }
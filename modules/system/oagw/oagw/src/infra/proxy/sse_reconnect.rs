@@ -0,0 +1,377 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use bytes::{Bytes, BytesMut};
+use futures_util::StreamExt as _;
+use futures_util::stream::unfold;
+use http::{HeaderMap, HeaderValue, Method, StatusCode};
+use oagw_sdk::body::BodyStream;
+use pingora_core::apps::HttpServerApp;
+use pingora_proxy::HttpProxy;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::watch;
+
+use super::pingora_proxy::PingoraProxy;
+use super::session_bridge;
+use crate::domain::error::DomainError;
+use crate::domain::rate_limit::{Charge, RateLimiter};
+
+const LAST_EVENT_ID: &str = "last-event-id";
+
+/// Largest partial event held back while waiting for its blank line. An
+/// upstream that exceeds it fails the client stream.
+const MAX_PENDING_EVENT_BYTES: usize = 1024 * 1024;
+
+/// Everything needed to re-issue the upstream request behind an SSE response.
+///
+/// Only buffered requests can be replayed: a streamed request body is
+/// consumed by the first attempt.
+pub(crate) struct SseReplay {
+    pub proxy: Arc<HttpProxy<PingoraProxy>>,
+    pub shutdown_rx: watch::Receiver<bool>,
+    pub method: Method,
+    pub url: String,
+    /// Outbound headers of the original attempt, including auth and the
+    /// internal routing headers consumed by `PingoraProxy`.
+    pub headers: HeaderMap,
+    pub body: Bytes,
+    pub timeout: Duration,
+    pub rate_limiter: Arc<RateLimiter>,
+    /// Rate-limit buckets the original request was admitted against.
+    pub charges: Vec<Charge>,
+    pub instance_uri: String,
+}
+
+impl SseReplay {
+    /// Charge a reconnect against the rate limits of the original request.
+    fn admit(&self) -> Result<(), DomainError> {
+        for charge in &self.charges {
+            self.rate_limiter.try_consume(
+                &charge.key,
+                &charge.config,
+                charge.cost,
+                &self.instance_uri,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Send the request again through a fresh Pingora session and return the
+    /// body of the new event stream.
+    async fn reconnect(&self, last_event_id: Option<&str>) -> anyhow::Result<BodyStream> {
+        let mut headers = self.headers.clone();
+        headers.remove(LAST_EVENT_ID);
+        if let Some(id) = last_event_id.filter(|id| !id.is_empty()) {
+            headers.insert(LAST_EVENT_ID, HeaderValue::from_str(id)?);
+        }
+
+        let (mut client_io, server_io) = tokio::io::duplex(65_536);
+        let session = pingora_core::protocols::http::ServerSession::new_http1(Box::new(server_io));
+        let proxy = self.proxy.clone();
+        let shutdown = self.shutdown_rx.clone();
+        tokio::spawn(async move {
+            proxy.process_new_http(session, &shutdown).await;
+        });
+
+        let wire = session_bridge::serialize_request_wire(
+            &self.method,
+            &self.url,
+            &headers,
+            Some(&self.body),
+        );
+        client_io.write_all(&wire).await?;
+
+        let (status, resp_headers, body) = tokio::time::timeout(
+            self.timeout,
+            session_bridge::parse_response_stream(client_io),
+        )
+        .await??;
+        anyhow::ensure!(
+            status == StatusCode::OK && oagw_sdk::sse::is_server_events_response(&resp_headers),
+            "upstream answered the SSE reconnect with {status}"
+        );
+        Ok(body)
+    }
+}
+
+/// Wrap an upstream SSE body so that a mid-stream failure is resumed instead
+/// of ending the client stream.
+///
+/// Only complete events are forwarded; bytes of an event still in flight are
+/// held back, so a drop never leaves a truncated event on the client side.
+/// On an upstream error the request is replayed with `Last-Event-ID` set to
+/// the last `id:` forwarded, up to `max_retries` times per client stream.
+/// Every reconnect is charged against the original request's rate limits;
+/// a rejected reconnect ends the stream with the rate-limit error. A clean
+/// end of the upstream response ends the client stream as usual, and an
+/// event larger than [`MAX_PENDING_EVENT_BYTES`] fails it.
+pub(crate) fn resumable_sse_stream(
+    inner: BodyStream,
+    replay: SseReplay,
+    max_retries: u32,
+) -> BodyStream {
+    struct State {
+        inner: BodyStream,
+        replay: SseReplay,
+        retries_left: u32,
+        /// Bytes received after the last complete event.
+        pending: BytesMut,
+        last_event_id: Option<String>,
+        done: bool,
+    }
+
+    Box::pin(unfold(
+        State {
+            inner,
+            replay,
+            retries_left: max_retries,
+            pending: BytesMut::new(),
+            last_event_id: None,
+            done: false,
+        },
+        |mut state| async move {
+            if state.done {
+                return None;
+            }
+            loop {
+                match state.inner.next().await {
+                    Some(Ok(chunk)) => {
+                        state.pending.extend_from_slice(&chunk);
+                        let complete = complete_events_len(&state.pending);
+                        if complete == 0 {
+                            if state.pending.len() > MAX_PENDING_EVENT_BYTES {
+                                state.done = true;
+                                let err = anyhow::anyhow!(
+                                    "SSE event exceeds {MAX_PENDING_EVENT_BYTES} bytes"
+                                );
+                                return Some((Err(err.into()), state));
+                            }
+                            continue;
+                        }
+                        let events = state.pending.split_to(complete).freeze();
+                        if let Some(id) = last_event_id(&events) {
+                            state.last_event_id = Some(id);
+                        }
+                        return Some((Ok(events), state));
+                    }
+                    None => {
+                        state.done = true;
+                        if state.pending.is_empty() {
+                            return None;
+                        }
+                        let rest = std::mem::take(&mut state.pending).freeze();
+                        return Some((Ok(rest), state));
+                    }
+                    Some(Err(mut err)) => {
+                        // The unfinished event is re-sent by the upstream
+                        // after the reconnect.
+                        state.pending.clear();
+                        loop {
+                            if state.retries_left == 0 {
+                                state.done = true;
+                                return Some((Err(err), state));
+                            }
+                            state.retries_left -= 1;
+                            if let Err(e) = state.replay.admit() {
+                                tracing::debug!(error = %e, "SSE reconnect rate limited");
+                                state.done = true;
+                                return Some((Err(e.into()), state));
+                            }
+                            tracing::debug!(
+                                error = %err,
+                                last_event_id = ?state.last_event_id,
+                                "upstream SSE stream dropped, reconnecting"
+                            );
+                            match state.replay.reconnect(state.last_event_id.as_deref()).await {
+                                Ok(body) => {
+                                    state.inner = body;
+                                    break;
+                                }
+                                Err(e) => {
+                                    tracing::warn!(error = %e, "SSE reconnect failed");
+                                    err = e.into();
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        },
+    ))
+}
+
+/// Length of the prefix of `buf` made of complete events, i.e. up to and
+/// including the last blank line. `buf` must start at an event boundary.
+///
+/// Accepts `\n`, `\r\n` and bare `\r` line endings. A trailing `\r` is not
+/// counted, since it may be the first half of a `\r\n` split across chunks.
+fn complete_events_len(buf: &[u8]) -> usize {
+    let mut end = 0;
+    let mut line_start = true;
+    let mut i = 0;
+    while i < buf.len() {
+        let eol = match buf[i] {
+            b'\n' => 1,
+            b'\r' if i + 1 == buf.len() => break,
+            b'\r' if buf[i + 1] == b'\n' => 2,
+            b'\r' => 1,
+            _ => {
+                line_start = false;
+                i += 1;
+                continue;
+            }
+        };
+        i += eol;
+        if line_start {
+            end = i;
+        }
+        line_start = true;
+    }
+    end
+}
+
+/// Value of the last `id` field in a run of complete events, if any.
+///
+/// Mirrors the EventSource rules: one leading space is stripped from the
+/// value and ids containing NUL are ignored.
+fn last_event_id(events: &[u8]) -> Option<String> {
+    let mut id = None;
+    for line in events.split(|&b| b == b'\n' || b == b'\r') {
+        let value = match line.strip_prefix(b"id") {
+            Some(b"") => &b""[..],
+            Some(rest) => match rest.strip_prefix(b":") {
+                Some(value) => value.strip_prefix(b" ").unwrap_or(value),
+                None => continue,
+            },
+            None => continue,
+        };
+        if !value.contains(&0) {
+            id = Some(String::from_utf8_lossy(value).into_owned());
+        }
+    }
+    id
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::{
+        CostStrategy, RateLimitAlgorithm, RateLimitConfig, RateLimitScope, RateLimitStrategy,
+        SustainedRate, Window,
+    };
+    use oagw_sdk::body::BoxError;
+
+    fn replay(rate_limiter: Arc<RateLimiter>, charges: Vec<Charge>) -> SseReplay {
+        let conf = Arc::new(pingora_core::server::configuration::ServerConf::default());
+        let proxy = super::super::pingora_proxy::new_http_proxy(
+            &conf,
+            PingoraProxy::new(
+                Duration::from_secs(1),
+                Duration::from_secs(1),
+                Duration::from_secs(1),
+            ),
+        );
+        SseReplay {
+            proxy: Arc::new(proxy),
+            shutdown_rx: watch::channel(false).1,
+            method: Method::GET,
+            url: "/events".into(),
+            headers: HeaderMap::new(),
+            body: Bytes::new(),
+            timeout: Duration::from_secs(1),
+            rate_limiter,
+            charges,
+            instance_uri: "/sse/events".into(),
+        }
+    }
+
+    fn stream_of(chunks: Vec<Result<Bytes, BoxError>>) -> BodyStream {
+        Box::pin(futures_util::stream::iter(chunks))
+    }
+
+    #[tokio::test]
+    async fn oversized_event_fails_the_stream() {
+        let chunk = Bytes::from(vec![b'a'; 64 * 1024]);
+        let chunks = std::iter::once(Bytes::from_static(b"data: "))
+            .chain(std::iter::repeat_n(chunk, 17))
+            .chain(std::iter::once(Bytes::from_static(b"\n\n")))
+            .map(Ok)
+            .collect();
+        let mut stream = resumable_sse_stream(
+            stream_of(chunks),
+            replay(Arc::new(RateLimiter::new()), Vec::new()),
+            1,
+        );
+
+        let err = stream.next().await.unwrap().unwrap_err();
+        assert!(err.to_string().contains("exceeds"), "{err}");
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn reconnect_is_charged_against_the_rate_limit() {
+        let config = RateLimitConfig {
+            sharing: Default::default(),
+            algorithm: RateLimitAlgorithm::TokenBucket,
+            sustained: SustainedRate {
+                rate: 1,
+                window: Window::Minute,
+            },
+            burst: None,
+            scope: RateLimitScope::Tenant,
+            strategy: RateLimitStrategy::Reject,
+            cost: CostStrategy::Fixed(1),
+        };
+        let limiter = Arc::new(RateLimiter::new());
+        // The original request took the only token.
+        limiter.try_consume("route:1", &config, 1, "/").unwrap();
+        let charges = vec![Charge {
+            key: "route:1".into(),
+            config,
+            cost: 1,
+        }];
+        let chunks = vec![
+            Ok(Bytes::from_static(b"id: 1\ndata: one\n\n")),
+            Err(anyhow::anyhow!("connection reset").into()),
+        ];
+        let mut stream = resumable_sse_stream(stream_of(chunks), replay(limiter, charges), 3);
+
+        let first = stream.next().await.unwrap().unwrap();
+        assert_eq!(&first[..], b"id: 1\ndata: one\n\n");
+        let err = stream.next().await.unwrap().unwrap_err();
+        assert!(
+            err.downcast_ref::<DomainError>()
+                .is_some_and(|e| matches!(e, DomainError::RateLimitExceeded { .. })),
+            "{err}"
+        );
+        assert!(stream.next().await.is_none());
+    }
+
+    #[test]
+    fn complete_events_stop_at_last_blank_line() {
+        let buf = b"id: 1\ndata: a\n\nid: 2\ndata: b";
+        assert_eq!(complete_events_len(buf), "id: 1\ndata: a\n\n".len());
+        assert_eq!(complete_events_len(b"data: a\n"), 0);
+        assert_eq!(complete_events_len(b""), 0);
+    }
+
+    #[test]
+    fn complete_events_accept_every_line_ending() {
+        assert_eq!(complete_events_len(b"data: a\r\n\r\nx"), 11);
+        assert_eq!(complete_events_len(b"data: a\r\rx"), 9);
+        // A trailing CR may still be followed by LF.
+        assert_eq!(complete_events_len(b"data: a\r\n\r"), 0);
+    }
+
+    #[test]
+    fn last_event_id_takes_the_final_id_field() {
+        let events = b"id: 1\ndata: a\n\nid:2\ndata: b\n\n";
+        assert_eq!(last_event_id(events).as_deref(), Some("2"));
+        assert_eq!(last_event_id(b"data: a\n\n"), None);
+        // An empty id resets the last event id.
+        assert_eq!(last_event_id(b"id: 1\n\nid\n\n").as_deref(), Some(""));
+        // Other fields starting with "id" are not ids.
+        assert_eq!(last_event_id(b"idle: 1\n\n"), None);
+        assert_eq!(last_event_id(b"id: a\0b\n\n"), None);
+    }
+}
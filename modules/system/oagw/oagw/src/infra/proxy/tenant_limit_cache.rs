@@ -0,0 +1,149 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use pingora_memory_cache::MemoryCache;
+use uuid::Uuid;
+
+use crate::domain::error::DomainError;
+use crate::domain::model::TenantRateLimit;
+
+#[derive(Clone)]
+struct CachedLimit {
+    /// `MemoryCache` indexes by key hash only; the tenant id is kept to rule
+    /// out serving another tenant's limit on a hash collision.
+    tenant_id: Uuid,
+    generation: u64,
+    limit: Option<TenantRateLimit>,
+}
+
+/// Cache of tenant rate limits for the proxy hot path.
+///
+/// Same shape as [`RouteCache`](super::route_cache::RouteCache): a
+/// [`MemoryCache`] with per-entry TTL plus a generation counter for
+/// [`invalidate_all`](Self::invalidate_all). Tenants without a limit are
+/// cached as `None` so they don't hit the control plane on every request.
+/// The TTL bounds how long a limit can outlive a missed change notification.
+pub(crate) struct TenantLimitCache {
+    inner: Option<MemoryCache<Uuid, CachedLimit>>,
+    ttl: Duration,
+    generation: AtomicU64,
+}
+
+impl TenantLimitCache {
+    pub(crate) fn new(capacity: usize, ttl: Duration) -> Self {
+        let enabled = capacity > 0 && !ttl.is_zero();
+        Self {
+            inner: enabled.then(|| MemoryCache::new(capacity)),
+            ttl,
+            generation: AtomicU64::new(0),
+        }
+    }
+
+    /// Return the cached limit of `tenant_id`, or run `resolve` and cache
+    /// its result.
+    pub(crate) async fn get_or_resolve<F, Fut>(
+        &self,
+        tenant_id: Uuid,
+        resolve: F,
+    ) -> Result<Option<TenantRateLimit>, DomainError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Option<TenantRateLimit>, DomainError>>,
+    {
+        let Some(inner) = &self.inner else {
+            return resolve().await;
+        };
+
+        let generation = self.generation.load(Ordering::Acquire);
+        if let (Some(cached), _) = inner.get(&tenant_id)
+            && cached.generation == generation
+            && cached.tenant_id == tenant_id
+        {
+            return Ok(cached.limit);
+        }
+
+        let limit = resolve().await?;
+        inner.put(
+            &tenant_id,
+            CachedLimit {
+                tenant_id,
+                generation,
+                limit: limit.clone(),
+            },
+            Some(self.ttl),
+        );
+        Ok(limit)
+    }
+
+    /// Drop the cached limit of `tenant_id`.
+    pub(crate) fn invalidate(&self, tenant_id: Uuid) {
+        if let Some(inner) = &self.inner {
+            inner.remove(&tenant_id);
+        }
+    }
+
+    /// Drop every cached limit.
+    pub(crate) fn invalidate_all(&self) {
+        self.generation.fetch_add(1, Ordering::AcqRel);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+
+    use super::*;
+
+    async fn lookup(cache: &TenantLimitCache, tenant_id: Uuid, calls: &AtomicUsize) {
+        cache
+            .get_or_resolve(tenant_id, || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Ok(None)
+            })
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn caches_missing_limits() {
+        let cache = TenantLimitCache::new(16, Duration::from_secs(60));
+        let calls = AtomicUsize::new(0);
+        let tenant_id = Uuid::new_v4();
+
+        lookup(&cache, tenant_id, &calls).await;
+        lookup(&cache, tenant_id, &calls).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn entries_expire_after_ttl() {
+        let cache = TenantLimitCache::new(16, Duration::from_millis(20));
+        let calls = AtomicUsize::new(0);
+        let tenant_id = Uuid::new_v4();
+
+        lookup(&cache, tenant_id, &calls).await;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        lookup(&cache, tenant_id, &calls).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn invalidation_forces_a_fresh_lookup() {
+        let cache = TenantLimitCache::new(16, Duration::from_secs(60));
+        let calls = AtomicUsize::new(0);
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+
+        lookup(&cache, a, &calls).await;
+        lookup(&cache, b, &calls).await;
+        cache.invalidate(a);
+        lookup(&cache, a, &calls).await;
+        lookup(&cache, b, &calls).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        cache.invalidate_all();
+        lookup(&cache, a, &calls).await;
+        lookup(&cache, b, &calls).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 5);
+    }
+}
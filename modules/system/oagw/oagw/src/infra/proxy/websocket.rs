@@ -6,8 +6,12 @@ use std::task::{Context, Poll};
 use std::time::Duration;
 
 use bytes::{Buf, Bytes};
-use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, ReadBuf};
-use tokio::sync::watch;
+use oagw_sdk::StreamingError;
+use oagw_sdk::ws::{
+    WebSocketCloseFrame, WebSocketMessage, WebSocketReceiver, WebSocketSink, WebSocketStream,
+};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, ReadBuf, ReadHalf, WriteHalf};
+use tokio::sync::{Mutex as AsyncMutex, watch};
 use tracing::{debug, warn};
 
 // ---------------------------------------------------------------------------
@@ -221,7 +225,6 @@ fn make_close_payload(code: u16, reason: &str) -> Vec<u8> {
 }
 
 /// Extract status code and reason bytes from a Close frame payload.
-#[cfg(test)]
 fn parse_close_payload(payload: &[u8]) -> (u16, &[u8]) {
     if payload.len() >= 2 {
         let code = u16::from_be_bytes([payload[0], payload[1]]);
@@ -550,6 +553,212 @@ pub(crate) async fn websocket_bridge(
     }
 }
 
+// ---------------------------------------------------------------------------
+// Message-level access for in-process callers
+// ---------------------------------------------------------------------------
+
+/// Write half of the upstream tunnel, shared by the sink and the receiver
+/// (which answers Pings and echoes Close).
+struct UpstreamWriter {
+    io: WriteHalf<tokio::io::DuplexStream>,
+    close_sent: bool,
+}
+
+impl UpstreamWriter {
+    /// Send a single masked frame. Nothing may follow a Close frame.
+    async fn send(&mut self, opcode: WsOpcode, payload: &[u8]) -> std::io::Result<()> {
+        if self.close_sent {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::BrokenPipe,
+                "WebSocket Close frame already sent",
+            ));
+        }
+        self.close_sent = opcode == WsOpcode::Close;
+        write_frame(&mut self.io, opcode, payload, true, true).await
+    }
+}
+
+fn bridge_error(detail: impl ToString) -> StreamingError {
+    StreamingError::WebSocketBridge {
+        detail: detail.to_string(),
+    }
+}
+
+/// Resolve once the server starts shutting down; never if the sender is gone.
+async fn shutdown_signalled(shutdown_rx: &mut watch::Receiver<bool>) {
+    if shutdown_rx.wait_for(|&shutdown| shutdown).await.is_err() {
+        std::future::pending::<()>().await;
+    }
+}
+
+/// Expose the upstream side of an upgraded connection as a message-level
+/// [`WebSocketStream`], for callers that use the gateway in process rather
+/// than through an HTTP upgrade.
+///
+/// Outgoing messages are sent as single masked frames. Incoming fragmented
+/// messages are reassembled, Pings are answered with a Pong, and a Close from
+/// the upstream is echoed before the stream ends. Server shutdown closes the
+/// connection with 1001. The idle timeout does not apply: the caller owns the
+/// connection and ends it by sending Close or dropping the stream.
+pub(crate) fn into_message_stream(bridge: WebSocketBridgeIo) -> WebSocketStream {
+    struct State {
+        reader: PrefixedReader<ReadHalf<tokio::io::DuplexStream>>,
+        writer: Arc<AsyncMutex<UpstreamWriter>>,
+        max_message_size: usize,
+        shutdown_rx: watch::Receiver<bool>,
+        done: bool,
+    }
+
+    let WebSocketBridgeIo {
+        io,
+        leftover,
+        max_frame_size,
+        shutdown_rx,
+        ..
+    } = bridge;
+    let (read, write) = tokio::io::split(io);
+    let writer = Arc::new(AsyncMutex::new(UpstreamWriter {
+        io: write,
+        close_sent: false,
+    }));
+
+    let sink = futures_util::sink::unfold(
+        Arc::clone(&writer),
+        |writer, msg: WebSocketMessage| async move {
+            let (opcode, payload) = match msg {
+                WebSocketMessage::Text(text) => (WsOpcode::Text, text.into_bytes()),
+                WebSocketMessage::Binary(data) => (WsOpcode::Binary, data),
+                WebSocketMessage::Ping(data) => (WsOpcode::Ping, data),
+                WebSocketMessage::Pong(data) => (WsOpcode::Pong, data),
+                WebSocketMessage::Close(None) => (WsOpcode::Close, Vec::new()),
+                WebSocketMessage::Close(Some(frame)) => (
+                    WsOpcode::Close,
+                    make_close_payload(frame.code, &frame.reason),
+                ),
+            };
+            writer
+                .lock()
+                .await
+                .send(opcode, &payload)
+                .await
+                .map_err(bridge_error)?;
+            Ok(writer)
+        },
+    );
+
+    let state = State {
+        reader: PrefixedReader::new(leftover, read),
+        writer,
+        max_message_size: max_frame_size
+            .map_or(HARD_MAX_FRAME_SIZE, |m| m.min(HARD_MAX_FRAME_SIZE)),
+        shutdown_rx,
+        done: false,
+    };
+    let receiver = futures_util::stream::unfold(state, |mut state| async move {
+        if state.done {
+            return None;
+        }
+        // Data message being reassembled from fragments.
+        let mut partial: Option<(WsOpcode, Vec<u8>)> = None;
+        loop {
+            let frame = tokio::select! {
+                frame = read_frame(&mut state.reader, Some(state.max_message_size)) => frame,
+                () = shutdown_signalled(&mut state.shutdown_rx) => {
+                    let close = make_close_payload(1001, "Going Away");
+                    let _ = state.writer.lock().await.send(WsOpcode::Close, &close).await;
+                    return None;
+                }
+            };
+            let (fin, opcode, payload) = match frame {
+                Ok(Some(frame)) => frame,
+                Ok(None) => return None,
+                Err(e) => {
+                    state.done = true;
+                    return Some((Err(bridge_error(e)), state));
+                }
+            };
+            let message = match opcode {
+                WsOpcode::Text | WsOpcode::Binary if partial.is_none() => {
+                    partial = Some((opcode, payload));
+                    if !fin {
+                        continue;
+                    }
+                    partial.take()
+                }
+                WsOpcode::Continuation if partial.is_some() => {
+                    let buffered = partial.as_mut().map_or(0, |(_, data)| {
+                        data.extend_from_slice(&payload);
+                        data.len()
+                    });
+                    if buffered > state.max_message_size {
+                        state.done = true;
+                        return Some((
+                            Err(bridge_error(format!(
+                                "message exceeds maximum {} bytes",
+                                state.max_message_size
+                            ))),
+                            state,
+                        ));
+                    }
+                    if !fin {
+                        continue;
+                    }
+                    partial.take()
+                }
+                WsOpcode::Ping => {
+                    let _ = state
+                        .writer
+                        .lock()
+                        .await
+                        .send(WsOpcode::Pong, &payload)
+                        .await;
+                    return Some((Ok(WebSocketMessage::Ping(payload)), state));
+                }
+                WsOpcode::Pong => return Some((Ok(WebSocketMessage::Pong(payload)), state)),
+                WsOpcode::Close => {
+                    let mut writer = state.writer.lock().await;
+                    if !writer.close_sent {
+                        let _ = writer.send(WsOpcode::Close, &payload).await;
+                    }
+                    drop(writer);
+                    state.done = true;
+                    let frame = (!payload.is_empty()).then(|| {
+                        let (code, reason) = parse_close_payload(&payload);
+                        WebSocketCloseFrame {
+                            code,
+                            reason: String::from_utf8_lossy(reason).into_owned(),
+                        }
+                    });
+                    return Some((Ok(WebSocketMessage::Close(frame)), state));
+                }
+                other => {
+                    state.done = true;
+                    return Some((
+                        Err(bridge_error(format!(
+                            "unexpected WebSocket frame {other:?}"
+                        ))),
+                        state,
+                    ));
+                }
+            };
+            let message = match message {
+                Some((WsOpcode::Text, data)) => String::from_utf8(data)
+                    .map(WebSocketMessage::Text)
+                    .map_err(|_| bridge_error("text message is not valid UTF-8")),
+                Some((_, data)) => Ok(WebSocketMessage::Binary(data)),
+                None => continue,
+            };
+            state.done = message.is_err();
+            return Some((message, state));
+        }
+    });
+
+    WebSocketStream::from((
+        Box::pin(sink) as WebSocketSink,
+        Box::pin(receiver) as WebSocketReceiver,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
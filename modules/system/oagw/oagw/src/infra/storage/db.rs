@@ -0,0 +1,90 @@
+//! Shared helpers for the SeaORM-backed repositories.
+
+use std::ops::Deref;
+
+use modkit_db::secure::{DBRunner, DbConn, DbTx, ScopeError};
+use modkit_db::{DBProvider, DbError};
+
+use crate::domain::repo::RepositoryError;
+
+pub(crate) fn db_err(e: DbError) -> RepositoryError {
+    RepositoryError::Internal(format!("database error: {e}"))
+}
+
+pub(crate) fn scope_err(e: ScopeError) -> RepositoryError {
+    RepositoryError::Internal(e.to_string())
+}
+
+pub(crate) fn spec_err(e: serde_json::Error) -> RepositoryError {
+    RepositoryError::Internal(format!("invalid stored spec: {e}"))
+}
+
+/// Where a SeaORM repository runs its statements: a pooled connection per
+/// call, or an open transaction shared by every call.
+pub(crate) trait DbSource: Send + Sync {
+    type Runner<'r>: DBRunner
+    where
+        Self: 'r;
+
+    fn runner(&self) -> Result<Runner<'_, Self::Runner<'_>>, RepositoryError>;
+}
+
+/// A runner that is either checked out for one call or borrowed from an
+/// open transaction.
+pub(crate) enum Runner<'a, R> {
+    Owned(R),
+    Borrowed(&'a R),
+}
+
+impl<R> Deref for Runner<'_, R> {
+    type Target = R;
+
+    fn deref(&self) -> &R {
+        match self {
+            Self::Owned(r) => r,
+            Self::Borrowed(r) => r,
+        }
+    }
+}
+
+impl DbSource for DBProvider<DbError> {
+    type Runner<'r> = DbConn<'r>;
+
+    fn runner(&self) -> Result<Runner<'_, DbConn<'_>>, RepositoryError> {
+        self.conn().map(Runner::Owned).map_err(db_err)
+    }
+}
+
+impl<'t> DbSource for &'t DbTx<'t> {
+    type Runner<'r>
+        = DbTx<'t>
+    where
+        Self: 'r;
+
+    fn runner(&self) -> Result<Runner<'_, DbTx<'t>>, RepositoryError> {
+        Ok(Runner::Borrowed(*self))
+    }
+}
+
+/// Create an in-memory `SQLite` database with the OAGW migrations applied.
+#[cfg(test)]
+pub(crate) async fn inmem_db() -> modkit_db::DBProvider<DbError> {
+    use modkit_db::migration_runner::run_migrations_for_testing;
+    use modkit_db::{ConnectOpts, DBProvider, connect_db};
+    use sea_orm_migration::MigratorTrait;
+
+    let opts = ConnectOpts {
+        max_conns: Some(1),
+        min_conns: Some(1),
+        ..Default::default()
+    };
+    let db = connect_db("sqlite::memory:", opts)
+        .await
+        .expect("Failed to connect to in-memory database");
+
+    run_migrations_for_testing(&db, super::migrations::Migrator::migrations())
+        .await
+        .expect("Failed to run migrations");
+
+    DBProvider::new(db)
+}
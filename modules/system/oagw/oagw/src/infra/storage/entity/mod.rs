@@ -0,0 +1,3 @@
+pub(crate) mod route;
+pub(crate) mod tenant_rate_limit;
+pub(crate) mod upstream;
@@ -0,0 +1,26 @@
+use modkit_db_macros::Scopable;
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+/// Persisted route row.
+///
+/// Match rules, plugins, rate limit, CORS and tags are stored as a JSON
+/// document in `spec` (see `records::RouteSpec`).
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Scopable)]
+#[sea_orm(table_name = "oagw_routes")]
+#[secure(tenant_col = "tenant_id", resource_col = "id", no_owner, no_type)]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub upstream_id: Uuid,
+    pub priority: i32,
+    pub enabled: bool,
+    #[sea_orm(column_type = "Text")]
+    pub spec: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,23 @@
+use modkit_db_macros::Scopable;
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+/// Persisted tenant rate limit row; one per tenant.
+///
+/// The rate limit configuration is stored as a JSON document in `spec`
+/// (see `records::RateLimitConfig`).
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Scopable)]
+#[sea_orm(table_name = "oagw_tenant_rate_limits")]
+#[secure(tenant_col = "tenant_id", no_resource, no_owner, no_type)]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub tenant_id: Uuid,
+    pub aggregate: bool,
+    #[sea_orm(column_type = "Text")]
+    pub spec: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
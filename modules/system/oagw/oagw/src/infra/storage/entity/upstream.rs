@@ -0,0 +1,27 @@
+use modkit_db_macros::Scopable;
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+/// Persisted upstream row.
+///
+/// Indexed/queried attributes live in dedicated columns; the remaining
+/// configuration (server, auth, headers, plugins, rate limit, CORS, tags) is
+/// stored as a JSON document in `spec` (see `records::UpstreamSpec`).
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Scopable)]
+#[sea_orm(table_name = "oagw_upstreams")]
+#[secure(tenant_col = "tenant_id", resource_col = "id", no_owner, no_type)]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub alias: String,
+    pub protocol: String,
+    pub enabled: bool,
+    #[sea_orm(column_type = "Text")]
+    pub spec: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,102 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::ConnectionTrait;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let backend = manager.get_database_backend();
+        let conn = manager.get_connection();
+
+        let statements: &[&str] = match backend {
+            sea_orm::DatabaseBackend::Postgres => &[
+                r"
+CREATE TABLE IF NOT EXISTS oagw_upstreams (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL,
+    alias VARCHAR(255) NOT NULL,
+    protocol VARCHAR(255) NOT NULL,
+    enabled BOOLEAN NOT NULL,
+    spec TEXT NOT NULL,
+    CONSTRAINT uq_oagw_upstreams_tenant_alias UNIQUE (tenant_id, alias)
+);
+                ",
+                r"
+CREATE TABLE IF NOT EXISTS oagw_routes (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL,
+    upstream_id UUID NOT NULL,
+    priority INTEGER NOT NULL,
+    enabled BOOLEAN NOT NULL,
+    spec TEXT NOT NULL
+);
+                ",
+                "CREATE INDEX IF NOT EXISTS idx_oagw_routes_tenant_upstream ON oagw_routes (tenant_id, upstream_id);",
+            ],
+            sea_orm::DatabaseBackend::MySql => &[
+                r"
+CREATE TABLE IF NOT EXISTS oagw_upstreams (
+    id VARCHAR(36) PRIMARY KEY,
+    tenant_id VARCHAR(36) NOT NULL,
+    alias VARCHAR(255) NOT NULL,
+    protocol VARCHAR(255) NOT NULL,
+    enabled BOOLEAN NOT NULL,
+    spec TEXT NOT NULL,
+    CONSTRAINT uq_oagw_upstreams_tenant_alias UNIQUE (tenant_id, alias)
+);
+                ",
+                r"
+CREATE TABLE IF NOT EXISTS oagw_routes (
+    id VARCHAR(36) PRIMARY KEY,
+    tenant_id VARCHAR(36) NOT NULL,
+    upstream_id VARCHAR(36) NOT NULL,
+    priority INTEGER NOT NULL,
+    enabled BOOLEAN NOT NULL,
+    spec TEXT NOT NULL,
+    INDEX idx_oagw_routes_tenant_upstream (tenant_id, upstream_id)
+);
+                ",
+            ],
+            sea_orm::DatabaseBackend::Sqlite => &[
+                r"
+CREATE TABLE IF NOT EXISTS oagw_upstreams (
+    id TEXT PRIMARY KEY,
+    tenant_id TEXT NOT NULL,
+    alias TEXT NOT NULL,
+    protocol TEXT NOT NULL,
+    enabled BOOLEAN NOT NULL,
+    spec TEXT NOT NULL,
+    UNIQUE (tenant_id, alias)
+);
+                ",
+                r"
+CREATE TABLE IF NOT EXISTS oagw_routes (
+    id TEXT PRIMARY KEY,
+    tenant_id TEXT NOT NULL,
+    upstream_id TEXT NOT NULL,
+    priority INTEGER NOT NULL,
+    enabled BOOLEAN NOT NULL,
+    spec TEXT NOT NULL
+);
+                ",
+                "CREATE INDEX IF NOT EXISTS idx_oagw_routes_tenant_upstream ON oagw_routes (tenant_id, upstream_id);",
+            ],
+        };
+
+        for sql in statements {
+            conn.execute_unprepared(sql).await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let conn = manager.get_connection();
+        conn.execute_unprepared("DROP TABLE IF EXISTS oagw_routes;")
+            .await?;
+        conn.execute_unprepared("DROP TABLE IF EXISTS oagw_upstreams;")
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::ConnectionTrait;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let backend = manager.get_database_backend();
+        let conn = manager.get_connection();
+
+        let sql = match backend {
+            sea_orm::DatabaseBackend::Postgres => {
+                r"
+CREATE TABLE IF NOT EXISTS oagw_tenant_rate_limits (
+    tenant_id UUID PRIMARY KEY,
+    aggregate BOOLEAN NOT NULL,
+    spec TEXT NOT NULL
+);
+                "
+            }
+            sea_orm::DatabaseBackend::MySql => {
+                r"
+CREATE TABLE IF NOT EXISTS oagw_tenant_rate_limits (
+    tenant_id VARCHAR(36) PRIMARY KEY,
+    aggregate BOOLEAN NOT NULL,
+    spec TEXT NOT NULL
+);
+                "
+            }
+            sea_orm::DatabaseBackend::Sqlite => {
+                r"
+CREATE TABLE IF NOT EXISTS oagw_tenant_rate_limits (
+    tenant_id TEXT PRIMARY KEY,
+    aggregate BOOLEAN NOT NULL,
+    spec TEXT NOT NULL
+);
+                "
+            }
+        };
+
+        conn.execute_unprepared(sql).await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let conn = manager.get_connection();
+        conn.execute_unprepared("DROP TABLE IF EXISTS oagw_tenant_rate_limits;")
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+use sea_orm_migration::prelude::*;
+
+mod m20261016_000001_initial;
+mod m20261016_000002_tenant_rate_limits;
+
+pub struct Migrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for Migrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(m20261016_000001_initial::Migration),
+            Box::new(m20261016_000002_tenant_rate_limits::Migration),
+        ]
+    }
+}
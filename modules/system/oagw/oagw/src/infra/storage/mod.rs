@@ -1,5 +1,21 @@
+pub(crate) mod db;
+pub(crate) mod entity;
+pub(crate) mod migrations;
+pub(crate) mod records;
 pub(crate) mod route_repo;
+pub(crate) mod sea_orm_route_repo;
+pub(crate) mod sea_orm_tenant_rate_limit_repo;
+pub(crate) mod sea_orm_transactions;
+pub(crate) mod sea_orm_upstream_repo;
+pub(crate) mod tenant_rate_limit_repo;
+pub(crate) mod transactions;
 pub(crate) mod upstream_repo;
 
 pub(crate) use route_repo::InMemoryRouteRepo;
+pub(crate) use sea_orm_route_repo::SeaOrmRouteRepo;
+pub(crate) use sea_orm_tenant_rate_limit_repo::SeaOrmTenantRateLimitRepo;
+pub(crate) use sea_orm_transactions::SeaOrmTransactions;
+pub(crate) use sea_orm_upstream_repo::SeaOrmUpstreamRepo;
+pub(crate) use tenant_rate_limit_repo::InMemoryTenantRateLimitRepo;
+pub(crate) use transactions::InMemoryTransactions;
 pub(crate) use upstream_repo::InMemoryUpstreamRepo;
@@ -0,0 +1,635 @@
+//! Serde records for the JSON `spec` column of persisted upstreams and routes.
+//!
+//! Domain types carry no serde derives, so the storage layer owns its own
+//! serializable mirror of the configuration tree. Field and variant names are
+//! part of the on-disk format — rename with a migration, never in place.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::model as domain;
+
+/// Declares a unit-only record enum mirroring a domain enum, with `From`
+/// conversions in both directions.
+macro_rules! record_enum {
+    ($name:ident { $($variant:ident),+ $(,)? }) => {
+        #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+        #[serde(rename_all = "snake_case")]
+        pub(crate) enum $name {
+            $($variant),+
+        }
+
+        impl From<domain::$name> for $name {
+            fn from(v: domain::$name) -> Self {
+                match v {
+                    $(domain::$name::$variant => Self::$variant),+
+                }
+            }
+        }
+
+        impl From<$name> for domain::$name {
+            fn from(v: $name) -> Self {
+                match v {
+                    $($name::$variant => Self::$variant),+
+                }
+            }
+        }
+    };
+}
+
+record_enum!(SharingMode {
+    Private,
+    Inherit,
+    Enforce
+});
+record_enum!(Scheme {
+    Http,
+    Https,
+    Wss,
+    Wt,
+    Grpc
+});
+record_enum!(PassthroughMode {
+    None,
+    Allowlist,
+    All
+});
+record_enum!(RateLimitAlgorithm {
+    TokenBucket,
+    SlidingWindow
+});
+record_enum!(RateLimitScope {
+    Global,
+    Tenant,
+    User,
+    Ip,
+    Route
+});
+record_enum!(RateLimitStrategy {
+    Reject,
+    Queue,
+    Degrade
+});
+record_enum!(CorsHttpMethod {
+    Get,
+    Post,
+    Put,
+    Delete,
+    Patch,
+    Head,
+    Options
+});
+record_enum!(HttpMethod {
+    Get,
+    Post,
+    Put,
+    Delete,
+    Patch
+});
+record_enum!(PathSuffixMode { Disabled, Append });
+
+// ---------------------------------------------------------------------------
+// Top-level specs
+// ---------------------------------------------------------------------------
+
+/// JSON document stored in `oagw_upstreams.spec`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct UpstreamSpec {
+    pub endpoints: Vec<Endpoint>,
+    pub auth: Option<AuthConfig>,
+    pub headers: Option<HeadersConfig>,
+    pub plugins: Option<PluginsConfig>,
+    pub rate_limit: Option<RateLimitConfig>,
+    pub cors: Option<CorsConfig>,
+    pub tags: Vec<String>,
+}
+
+impl From<&domain::Upstream> for UpstreamSpec {
+    fn from(u: &domain::Upstream) -> Self {
+        Self {
+            endpoints: u.server.endpoints.iter().cloned().map(Into::into).collect(),
+            auth: u.auth.clone().map(Into::into),
+            headers: u.headers.clone().map(Into::into),
+            plugins: u.plugins.clone().map(Into::into),
+            rate_limit: u.rate_limit.clone().map(Into::into),
+            cors: u.cors.clone().map(Into::into),
+            tags: u.tags.clone(),
+        }
+    }
+}
+
+/// JSON document stored in `oagw_routes.spec`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RouteSpec {
+    pub match_rules: MatchRules,
+    pub plugins: Option<PluginsConfig>,
+    pub rate_limit: Option<RateLimitConfig>,
+    pub cors: Option<CorsConfig>,
+    #[serde(default)]
+    pub sse_reconnect: Option<SseReconnectConfig>,
+    #[serde(default)]
+    pub get_coalescing: Option<GetCoalescingConfig>,
+    #[serde(default)]
+    pub request_compression: Option<RequestCompressionConfig>,
+    #[serde(default)]
+    pub response_cache: Option<ResponseCacheConfig>,
+    pub tags: Vec<String>,
+}
+
+impl From<&domain::Route> for RouteSpec {
+    fn from(r: &domain::Route) -> Self {
+        Self {
+            match_rules: r.match_rules.clone().into(),
+            plugins: r.plugins.clone().map(Into::into),
+            rate_limit: r.rate_limit.clone().map(Into::into),
+            cors: r.cors.clone().map(Into::into),
+            sse_reconnect: r.sse_reconnect.map(Into::into),
+            get_coalescing: r.get_coalescing.clone().map(Into::into),
+            request_compression: r.request_compression.map(Into::into),
+            response_cache: r.response_cache.map(Into::into),
+            tags: r.tags.clone(),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Nested records
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Endpoint {
+    pub scheme: Scheme,
+    pub host: String,
+    pub port: u16,
+}
+
+impl From<domain::Endpoint> for Endpoint {
+    fn from(v: domain::Endpoint) -> Self {
+        Self {
+            scheme: v.scheme.into(),
+            host: v.host,
+            port: v.port,
+        }
+    }
+}
+
+impl From<Endpoint> for domain::Endpoint {
+    fn from(v: Endpoint) -> Self {
+        Self {
+            scheme: v.scheme.into(),
+            host: v.host,
+            port: v.port,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct AuthConfig {
+    pub plugin_type: String,
+    pub sharing: SharingMode,
+    pub config: Option<HashMap<String, String>>,
+}
+
+impl From<domain::AuthConfig> for AuthConfig {
+    fn from(v: domain::AuthConfig) -> Self {
+        Self {
+            plugin_type: v.plugin_type,
+            sharing: v.sharing.into(),
+            config: v.config,
+        }
+    }
+}
+
+impl From<AuthConfig> for domain::AuthConfig {
+    fn from(v: AuthConfig) -> Self {
+        Self {
+            plugin_type: v.plugin_type,
+            sharing: v.sharing.into(),
+            config: v.config,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct HeadersConfig {
+    pub request: Option<RequestHeaderRules>,
+    pub response: Option<ResponseHeaderRules>,
+}
+
+impl From<domain::HeadersConfig> for HeadersConfig {
+    fn from(v: domain::HeadersConfig) -> Self {
+        Self {
+            request: v.request.map(Into::into),
+            response: v.response.map(Into::into),
+        }
+    }
+}
+
+impl From<HeadersConfig> for domain::HeadersConfig {
+    fn from(v: HeadersConfig) -> Self {
+        Self {
+            request: v.request.map(Into::into),
+            response: v.response.map(Into::into),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RequestHeaderRules {
+    pub set: HashMap<String, String>,
+    pub add: HashMap<String, String>,
+    pub remove: Vec<String>,
+    pub passthrough: PassthroughMode,
+    pub passthrough_allowlist: Vec<String>,
+}
+
+impl From<domain::RequestHeaderRules> for RequestHeaderRules {
+    fn from(v: domain::RequestHeaderRules) -> Self {
+        Self {
+            set: v.set,
+            add: v.add,
+            remove: v.remove,
+            passthrough: v.passthrough.into(),
+            passthrough_allowlist: v.passthrough_allowlist,
+        }
+    }
+}
+
+impl From<RequestHeaderRules> for domain::RequestHeaderRules {
+    fn from(v: RequestHeaderRules) -> Self {
+        Self {
+            set: v.set,
+            add: v.add,
+            remove: v.remove,
+            passthrough: v.passthrough.into(),
+            passthrough_allowlist: v.passthrough_allowlist,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ResponseHeaderRules {
+    pub set: HashMap<String, String>,
+    pub add: HashMap<String, String>,
+    pub remove: Vec<String>,
+}
+
+impl From<domain::ResponseHeaderRules> for ResponseHeaderRules {
+    fn from(v: domain::ResponseHeaderRules) -> Self {
+        Self {
+            set: v.set,
+            add: v.add,
+            remove: v.remove,
+        }
+    }
+}
+
+impl From<ResponseHeaderRules> for domain::ResponseHeaderRules {
+    fn from(v: ResponseHeaderRules) -> Self {
+        Self {
+            set: v.set,
+            add: v.add,
+            remove: v.remove,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Window {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Seconds(u32),
+}
+
+impl From<domain::Window> for Window {
+    fn from(v: domain::Window) -> Self {
+        match v {
+            domain::Window::Second => Self::Second,
+            domain::Window::Minute => Self::Minute,
+            domain::Window::Hour => Self::Hour,
+            domain::Window::Day => Self::Day,
+            domain::Window::Seconds(n) => Self::Seconds(n),
+        }
+    }
+}
+
+impl From<Window> for domain::Window {
+    fn from(v: Window) -> Self {
+        match v {
+            Window::Second => Self::Second,
+            Window::Minute => Self::Minute,
+            Window::Hour => Self::Hour,
+            Window::Day => Self::Day,
+            Window::Seconds(n) => Self::Seconds(n),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RateLimitConfig {
+    pub sharing: SharingMode,
+    pub algorithm: RateLimitAlgorithm,
+    pub rate: u32,
+    pub window: Window,
+    pub burst_capacity: Option<u32>,
+    pub scope: RateLimitScope,
+    pub strategy: RateLimitStrategy,
+    pub cost: CostStrategy,
+}
+
+/// Plain integers are fixed costs, which keeps records written before
+/// variable costs existed readable.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum CostStrategy {
+    Fixed(u32),
+    Variable(VariableCost),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub(crate) enum VariableCost {
+    PerKilobyte,
+    HeaderValue { header: String },
+}
+
+impl From<domain::CostStrategy> for CostStrategy {
+    fn from(v: domain::CostStrategy) -> Self {
+        match v {
+            domain::CostStrategy::Fixed(n) => Self::Fixed(n),
+            domain::CostStrategy::PerKilobyte => Self::Variable(VariableCost::PerKilobyte),
+            domain::CostStrategy::HeaderValue(header) => {
+                Self::Variable(VariableCost::HeaderValue { header })
+            }
+        }
+    }
+}
+
+impl From<CostStrategy> for domain::CostStrategy {
+    fn from(v: CostStrategy) -> Self {
+        match v {
+            CostStrategy::Fixed(n) => Self::Fixed(n),
+            CostStrategy::Variable(VariableCost::PerKilobyte) => Self::PerKilobyte,
+            CostStrategy::Variable(VariableCost::HeaderValue { header }) => {
+                Self::HeaderValue(header)
+            }
+        }
+    }
+}
+
+impl From<domain::RateLimitConfig> for RateLimitConfig {
+    fn from(v: domain::RateLimitConfig) -> Self {
+        Self {
+            sharing: v.sharing.into(),
+            algorithm: v.algorithm.into(),
+            rate: v.sustained.rate,
+            window: v.sustained.window.into(),
+            burst_capacity: v.burst.map(|b| b.capacity),
+            scope: v.scope.into(),
+            strategy: v.strategy.into(),
+            cost: v.cost.into(),
+        }
+    }
+}
+
+impl From<RateLimitConfig> for domain::RateLimitConfig {
+    fn from(v: RateLimitConfig) -> Self {
+        Self {
+            sharing: v.sharing.into(),
+            algorithm: v.algorithm.into(),
+            sustained: domain::SustainedRate {
+                rate: v.rate,
+                window: v.window.into(),
+            },
+            burst: v
+                .burst_capacity
+                .map(|capacity| domain::BurstConfig { capacity }),
+            scope: v.scope.into(),
+            strategy: v.strategy.into(),
+            cost: v.cost.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CorsConfig {
+    pub sharing: SharingMode,
+    pub enabled: bool,
+    pub allowed_origins: Vec<String>,
+    pub allowed_methods: Vec<CorsHttpMethod>,
+    pub expose_headers: Vec<String>,
+    pub allow_credentials: bool,
+}
+
+impl From<domain::CorsConfig> for CorsConfig {
+    fn from(v: domain::CorsConfig) -> Self {
+        Self {
+            sharing: v.sharing.into(),
+            enabled: v.enabled,
+            allowed_origins: v.allowed_origins,
+            allowed_methods: v.allowed_methods.into_iter().map(Into::into).collect(),
+            expose_headers: v.expose_headers,
+            allow_credentials: v.allow_credentials,
+        }
+    }
+}
+
+impl From<CorsConfig> for domain::CorsConfig {
+    fn from(v: CorsConfig) -> Self {
+        Self {
+            sharing: v.sharing.into(),
+            enabled: v.enabled,
+            allowed_origins: v.allowed_origins,
+            allowed_methods: v.allowed_methods.into_iter().map(Into::into).collect(),
+            expose_headers: v.expose_headers,
+            allow_credentials: v.allow_credentials,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub(crate) struct SseReconnectConfig {
+    pub max_retries: u32,
+}
+
+impl From<domain::SseReconnectConfig> for SseReconnectConfig {
+    fn from(v: domain::SseReconnectConfig) -> Self {
+        Self {
+            max_retries: v.max_retries,
+        }
+    }
+}
+
+impl From<SseReconnectConfig> for domain::SseReconnectConfig {
+    fn from(v: SseReconnectConfig) -> Self {
+        Self {
+            max_retries: v.max_retries,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct GetCoalescingConfig {
+    #[serde(default)]
+    pub vary_headers: Vec<String>,
+}
+
+impl From<domain::GetCoalescingConfig> for GetCoalescingConfig {
+    fn from(v: domain::GetCoalescingConfig) -> Self {
+        Self {
+            vary_headers: v.vary_headers,
+        }
+    }
+}
+
+impl From<GetCoalescingConfig> for domain::GetCoalescingConfig {
+    fn from(v: GetCoalescingConfig) -> Self {
+        Self {
+            vary_headers: v.vary_headers,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub(crate) struct RequestCompressionConfig {
+    pub min_bytes: u64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub(crate) struct ResponseCacheConfig {
+    pub ttl_secs: u64,
+}
+
+impl From<domain::RequestCompressionConfig> for RequestCompressionConfig {
+    fn from(v: domain::RequestCompressionConfig) -> Self {
+        Self {
+            min_bytes: v.min_bytes,
+        }
+    }
+}
+
+impl From<domain::ResponseCacheConfig> for ResponseCacheConfig {
+    fn from(v: domain::ResponseCacheConfig) -> Self {
+        Self {
+            ttl_secs: v.ttl_secs,
+        }
+    }
+}
+
+impl From<RequestCompressionConfig> for domain::RequestCompressionConfig {
+    fn from(v: RequestCompressionConfig) -> Self {
+        Self {
+            min_bytes: v.min_bytes,
+        }
+    }
+}
+
+impl From<ResponseCacheConfig> for domain::ResponseCacheConfig {
+    fn from(v: ResponseCacheConfig) -> Self {
+        Self {
+            ttl_secs: v.ttl_secs,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct PluginBinding {
+    pub plugin_ref: String,
+    pub config: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct PluginsConfig {
+    pub sharing: SharingMode,
+    pub items: Vec<PluginBinding>,
+}
+
+impl From<domain::PluginsConfig> for PluginsConfig {
+    fn from(v: domain::PluginsConfig) -> Self {
+        Self {
+            sharing: v.sharing.into(),
+            items: v
+                .items
+                .into_iter()
+                .map(|b| PluginBinding {
+                    plugin_ref: b.plugin_ref,
+                    config: b.config,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<PluginsConfig> for domain::PluginsConfig {
+    fn from(v: PluginsConfig) -> Self {
+        Self {
+            sharing: v.sharing.into(),
+            items: v
+                .items
+                .into_iter()
+                .map(|b| domain::PluginBinding {
+                    plugin_ref: b.plugin_ref,
+                    config: b.config,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct HttpMatch {
+    pub methods: Vec<HttpMethod>,
+    pub path: String,
+    pub query_allowlist: Vec<String>,
+    pub path_suffix_mode: PathSuffixMode,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct GrpcMatch {
+    pub service: String,
+    pub method: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct MatchRules {
+    pub http: Option<HttpMatch>,
+    pub grpc: Option<GrpcMatch>,
+}
+
+impl From<domain::MatchRules> for MatchRules {
+    fn from(v: domain::MatchRules) -> Self {
+        Self {
+            http: v.http.map(|h| HttpMatch {
+                methods: h.methods.into_iter().map(Into::into).collect(),
+                path: h.path,
+                query_allowlist: h.query_allowlist,
+                path_suffix_mode: h.path_suffix_mode.into(),
+            }),
+            grpc: v.grpc.map(|g| GrpcMatch {
+                service: g.service,
+                method: g.method,
+            }),
+        }
+    }
+}
+
+impl From<MatchRules> for domain::MatchRules {
+    fn from(v: MatchRules) -> Self {
+        Self {
+            http: v.http.map(|h| domain::HttpMatch {
+                methods: h.methods.into_iter().map(Into::into).collect(),
+                path: h.path,
+                query_allowlist: h.query_allowlist,
+                path_suffix_mode: h.path_suffix_mode.into(),
+            }),
+            grpc: v.grpc.map(|g| domain::GrpcMatch {
+                service: g.service,
+                method: g.method,
+            }),
+        }
+    }
+}
@@ -24,6 +24,40 @@ impl InMemoryRouteRepo {
     }
 }
 
+impl InMemoryRouteRepo {
+    /// Current state of `id` in any tenant, for transaction rollback.
+    pub(crate) fn snapshot(&self, id: Uuid) -> Option<Route> {
+        self.store.get(&id).map(|r| r.clone())
+    }
+
+    /// Current state of every route of `upstream_id`, for transaction rollback.
+    pub(crate) fn snapshot_by_upstream(&self, upstream_id: Uuid) -> Vec<Route> {
+        let ids = self
+            .upstream_index
+            .get(&upstream_id)
+            .map(|ids| ids.clone())
+            .unwrap_or_default();
+        ids.into_iter().filter_map(|id| self.snapshot(id)).collect()
+    }
+
+    /// Put `id` back into a state captured by [`Self::snapshot`], keeping the
+    /// upstream index in sync.
+    pub(crate) fn restore(&self, id: Uuid, previous: Option<Route>) {
+        if let Some((_, current)) = self.store.remove(&id)
+            && let Some(mut ids) = self.upstream_index.get_mut(&current.upstream_id)
+        {
+            ids.retain(|rid| *rid != id);
+        }
+        if let Some(route) = previous {
+            self.upstream_index
+                .entry(route.upstream_id)
+                .or_default()
+                .push(id);
+            self.store.insert(id, route);
+        }
+    }
+}
+
 impl Default for InMemoryRouteRepo {
     fn default() -> Self {
         Self::new()
@@ -111,8 +145,7 @@ impl RouteRepository for InMemoryRouteRepo {
         let request_method = parse_method(method);
 
         let mut best: Option<Route> = None;
-        let mut best_path_len = 0;
-        let mut best_priority = i32::MIN;
+        let mut best_score = (0, i32::MIN);
 
         for id in &route_ids {
             let Some(route_ref) = self.store.get(id) else {
@@ -124,33 +157,13 @@ impl RouteRepository for InMemoryRouteRepo {
             if route.tenant_id != tenant_id {
                 continue;
             }
-            // Must be enabled.
-            if !route.enabled {
-                continue;
-            }
-            // Must have HTTP match rules.
-            let Some(http_match) = &route.match_rules.http else {
-                continue;
-            };
-            // Method must match (unknown methods never match).
-            let Some(req_method) = &request_method else {
+            let Some(score) = match_score(route, request_method, path) else {
                 continue;
             };
-            if !http_match.methods.contains(req_method) {
-                continue;
-            }
-            // Path must be a prefix match.
-            if !path.starts_with(&http_match.path) {
-                continue;
-            }
-
-            let path_len = http_match.path.len();
-            let priority = route.priority;
 
             // Select by longest path prefix, then highest priority.
-            if path_len > best_path_len || (path_len == best_path_len && priority > best_priority) {
-                best_path_len = path_len;
-                best_priority = priority;
+            if score > best_score {
+                best_score = score;
                 best = Some(route.clone());
             }
         }
@@ -227,7 +240,31 @@ impl RouteRepository for InMemoryRouteRepo {
     }
 }
 
-fn parse_method(s: &str) -> Option<HttpMethod> {
+/// Score a route against a request as `(path_prefix_len, priority)`.
+///
+/// Returns `None` when the route cannot serve the request: disabled, no HTTP
+/// match rules, method not allowed (unknown methods never match), or path not
+/// a prefix match. Callers pick the route with the highest score, i.e. longest
+/// path prefix first, then highest priority.
+pub(crate) fn match_score(
+    route: &Route,
+    method: Option<HttpMethod>,
+    path: &str,
+) -> Option<(usize, i32)> {
+    if !route.enabled {
+        return None;
+    }
+    let http_match = route.match_rules.http.as_ref()?;
+    if !http_match.methods.contains(&method?) {
+        return None;
+    }
+    if !path.starts_with(&http_match.path) {
+        return None;
+    }
+    Some((http_match.path.len(), route.priority))
+}
+
+pub(crate) fn parse_method(s: &str) -> Option<HttpMethod> {
     match s.to_uppercase().as_str() {
         "GET" => Some(HttpMethod::Get),
         "POST" => Some(HttpMethod::Post),
@@ -267,6 +304,10 @@ mod tests {
             plugins: None,
             rate_limit: None,
             cors: None,
+            sse_reconnect: None,
+            get_coalescing: None,
+            request_compression: None,
+            response_cache: None,
             tags: vec![],
             priority,
             enabled: true,
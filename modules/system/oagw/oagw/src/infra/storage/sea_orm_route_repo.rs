@@ -0,0 +1,426 @@
+use crate::domain::model::{ListQuery, Route};
+use crate::domain::repo::{RepositoryError, RouteRepository};
+use async_trait::async_trait;
+use modkit_db::secure::{
+    DbTx, SecureDeleteExt, SecureEntityExt, secure_insert, secure_update_with_scope,
+};
+use modkit_db::{DBProvider, DbError};
+use modkit_security::AccessScope;
+use sea_orm::{ActiveValue, ColumnTrait, Condition, EntityTrait, Order};
+use uuid::Uuid;
+
+use super::db::{DbSource, scope_err, spec_err};
+use super::entity::route::{self, Entity as RouteEntity};
+use super::records::RouteSpec;
+use super::route_repo::{match_score, parse_method};
+
+/// SeaORM-backed route repository.
+///
+/// Match rules live in the JSON `spec` column, so `find_matching` loads the
+/// enabled routes of one upstream and ranks them in memory with the same
+/// scoring as the in-memory repository.
+///
+/// Runs on the connection pool by default, or inside an open transaction
+/// via [`SeaOrmRouteRepo::in_tx`].
+pub struct SeaOrmRouteRepo<D = DBProvider<DbError>> {
+    db: D,
+}
+
+impl SeaOrmRouteRepo {
+    #[must_use]
+    pub fn new(db: DBProvider<DbError>) -> Self {
+        Self { db }
+    }
+}
+
+impl<'t> SeaOrmRouteRepo<&'t DbTx<'t>> {
+    pub(crate) fn in_tx(tx: &'t DbTx<'t>) -> Self {
+        Self { db: tx }
+    }
+}
+
+fn to_active_model(r: &Route) -> Result<route::ActiveModel, RepositoryError> {
+    let spec = serde_json::to_string(&RouteSpec::from(r)).map_err(spec_err)?;
+    Ok(route::ActiveModel {
+        id: ActiveValue::Set(r.id),
+        tenant_id: ActiveValue::Set(r.tenant_id),
+        upstream_id: ActiveValue::Set(r.upstream_id),
+        priority: ActiveValue::Set(r.priority),
+        enabled: ActiveValue::Set(r.enabled),
+        spec: ActiveValue::Set(spec),
+    })
+}
+
+fn from_model(m: route::Model) -> Result<Route, RepositoryError> {
+    let spec: RouteSpec = serde_json::from_str(&m.spec).map_err(spec_err)?;
+    Ok(Route {
+        id: m.id,
+        tenant_id: m.tenant_id,
+        upstream_id: m.upstream_id,
+        match_rules: spec.match_rules.into(),
+        plugins: spec.plugins.map(Into::into),
+        rate_limit: spec.rate_limit.map(Into::into),
+        cors: spec.cors.map(Into::into),
+        sse_reconnect: spec.sse_reconnect.map(Into::into),
+        get_coalescing: spec.get_coalescing.map(Into::into),
+        request_compression: spec.request_compression.map(Into::into),
+        response_cache: spec.response_cache.map(Into::into),
+        tags: spec.tags,
+        priority: m.priority,
+        enabled: m.enabled,
+    })
+}
+
+#[async_trait]
+impl<D: DbSource> RouteRepository for SeaOrmRouteRepo<D> {
+    async fn create(&self, route: Route) -> Result<Route, RepositoryError> {
+        let conn = self.db.runner()?;
+        let scope = AccessScope::for_tenant(route.tenant_id);
+        let am = to_active_model(&route)?;
+
+        secure_insert::<RouteEntity>(am, &scope, &*conn)
+            .await
+            .map_err(scope_err)?;
+        Ok(route)
+    }
+
+    async fn get_by_id(&self, tenant_id: Uuid, id: Uuid) -> Result<Route, RepositoryError> {
+        let conn = self.db.runner()?;
+        let scope = AccessScope::for_tenant(tenant_id);
+
+        let model = RouteEntity::find()
+            .secure()
+            .scope_with(&scope)
+            .and_id(id)
+            .map_err(scope_err)?
+            .one(&*conn)
+            .await
+            .map_err(scope_err)?
+            .ok_or(RepositoryError::NotFound {
+                entity: "route",
+                id,
+            })?;
+        from_model(model)
+    }
+
+    async fn list(
+        &self,
+        tenant_id: Uuid,
+        upstream_id: Option<Uuid>,
+        query: &ListQuery,
+    ) -> Result<Vec<Route>, RepositoryError> {
+        let conn = self.db.runner()?;
+        let scope = AccessScope::for_tenant(tenant_id);
+
+        let mut select = RouteEntity::find().secure().scope_with(&scope);
+        if let Some(uid) = upstream_id {
+            select = select.filter(Condition::all().add(route::Column::UpstreamId.eq(uid)));
+        }
+        let models = select
+            .order_by(route::Column::Id, Order::Asc)
+            .offset(u64::from(query.skip))
+            .limit(u64::from(query.top))
+            .all(&*conn)
+            .await
+            .map_err(scope_err)?;
+        models.into_iter().map(from_model).collect()
+    }
+
+    async fn find_matching(
+        &self,
+        tenant_id: Uuid,
+        upstream_id: Uuid,
+        method: &str,
+        path: &str,
+    ) -> Result<Route, RepositoryError> {
+        let conn = self.db.runner()?;
+        let scope = AccessScope::for_tenant(tenant_id);
+
+        let models = RouteEntity::find()
+            .secure()
+            .scope_with(&scope)
+            .filter(
+                Condition::all()
+                    .add(route::Column::UpstreamId.eq(upstream_id))
+                    .add(route::Column::Enabled.eq(true)),
+            )
+            .all(&*conn)
+            .await
+            .map_err(scope_err)?;
+
+        let request_method = parse_method(method);
+
+        let mut best: Option<Route> = None;
+        let mut best_score = (0, i32::MIN);
+        for model in models {
+            let route = from_model(model)?;
+            let Some(score) = match_score(&route, request_method, path) else {
+                continue;
+            };
+            if score > best_score {
+                best_score = score;
+                best = Some(route);
+            }
+        }
+
+        best.ok_or(RepositoryError::NotFound {
+            entity: "route",
+            id: Uuid::nil(),
+        })
+    }
+
+    async fn update(&self, route: Route) -> Result<Route, RepositoryError> {
+        let conn = self.db.runner()?;
+        let scope = AccessScope::for_tenant(route.tenant_id);
+        let id = route.id;
+
+        let exists = RouteEntity::find()
+            .secure()
+            .scope_with(&scope)
+            .and_id(id)
+            .map_err(scope_err)?
+            .one(&*conn)
+            .await
+            .map_err(scope_err)?
+            .is_some();
+        if !exists {
+            return Err(RepositoryError::NotFound {
+                entity: "route",
+                id,
+            });
+        }
+
+        let am = to_active_model(&route)?;
+        secure_update_with_scope::<RouteEntity>(am, &scope, id, &*conn)
+            .await
+            .map_err(scope_err)?;
+        Ok(route)
+    }
+
+    async fn delete(&self, tenant_id: Uuid, id: Uuid) -> Result<(), RepositoryError> {
+        let conn = self.db.runner()?;
+        let scope = AccessScope::for_tenant(tenant_id);
+
+        let result = RouteEntity::delete_many()
+            .secure()
+            .scope_with(&scope)
+            .filter(Condition::all().add(route::Column::Id.eq(id)))
+            .exec(&*conn)
+            .await
+            .map_err(scope_err)?;
+        if result.rows_affected == 0 {
+            return Err(RepositoryError::NotFound {
+                entity: "route",
+                id,
+            });
+        }
+        Ok(())
+    }
+
+    async fn delete_by_upstream(
+        &self,
+        tenant_id: Uuid,
+        upstream_id: Uuid,
+    ) -> Result<u64, RepositoryError> {
+        let conn = self.db.runner()?;
+        let scope = AccessScope::for_tenant(tenant_id);
+
+        let result = RouteEntity::delete_many()
+            .secure()
+            .scope_with(&scope)
+            .filter(Condition::all().add(route::Column::UpstreamId.eq(upstream_id)))
+            .exec(&*conn)
+            .await
+            .map_err(scope_err)?;
+        Ok(result.rows_affected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::domain::model::{
+        HttpMatch, HttpMethod, MatchRules, PathSuffixMode, PluginBinding, PluginsConfig,
+        SharingMode,
+    };
+    use crate::infra::storage::db::inmem_db;
+
+    use super::*;
+
+    fn make_route(
+        tenant_id: Uuid,
+        upstream_id: Uuid,
+        path: &str,
+        methods: Vec<HttpMethod>,
+        priority: i32,
+    ) -> Route {
+        Route {
+            id: Uuid::new_v4(),
+            tenant_id,
+            upstream_id,
+            match_rules: MatchRules {
+                http: Some(HttpMatch {
+                    methods,
+                    path: path.into(),
+                    query_allowlist: vec![],
+                    path_suffix_mode: PathSuffixMode::Append,
+                }),
+                grpc: None,
+            },
+            plugins: None,
+            rate_limit: None,
+            cors: None,
+            sse_reconnect: None,
+            get_coalescing: None,
+            request_compression: None,
+            response_cache: None,
+            tags: vec![],
+            priority,
+            enabled: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn create_and_get_round_trip_preserves_spec() {
+        let repo = SeaOrmRouteRepo::new(inmem_db().await);
+        let tenant = Uuid::new_v4();
+        let mut r = make_route(
+            tenant,
+            Uuid::new_v4(),
+            "/v1/chat",
+            vec![HttpMethod::Post],
+            3,
+        );
+        r.plugins = Some(PluginsConfig {
+            sharing: SharingMode::Inherit,
+            items: vec![PluginBinding {
+                plugin_ref: "gts.x.core.oagw.transform_plugin.v1~x.core.oagw.request_id.v1".into(),
+                config: HashMap::from([("header".into(), "x-request-id".into())]),
+            }],
+        });
+        r.tags = vec!["chat".into()];
+
+        repo.create(r.clone()).await.unwrap();
+        assert_eq!(repo.get_by_id(tenant, r.id).await.unwrap(), r);
+    }
+
+    #[tokio::test]
+    async fn find_matching_longest_prefix_then_priority() {
+        let repo = SeaOrmRouteRepo::new(inmem_db().await);
+        let tenant = Uuid::new_v4();
+        let upstream = Uuid::new_v4();
+
+        let short = make_route(tenant, upstream, "/v1", vec![HttpMethod::Post], 10);
+        let long_low = make_route(tenant, upstream, "/v1/chat", vec![HttpMethod::Post], 0);
+        let long_high = make_route(tenant, upstream, "/v1/chat", vec![HttpMethod::Post], 5);
+        let mut disabled = make_route(
+            tenant,
+            upstream,
+            "/v1/chat/completions",
+            vec![HttpMethod::Post],
+            0,
+        );
+        disabled.enabled = false;
+        for r in [&short, &long_low, &long_high, &disabled] {
+            repo.create(r.clone()).await.unwrap();
+        }
+
+        let found = repo
+            .find_matching(tenant, upstream, "POST", "/v1/chat/completions")
+            .await
+            .unwrap();
+        assert_eq!(found.id, long_high.id);
+
+        assert!(matches!(
+            repo.find_matching(tenant, upstream, "GET", "/v1/chat")
+                .await,
+            Err(RepositoryError::NotFound { .. })
+        ));
+        assert!(matches!(
+            repo.find_matching(Uuid::new_v4(), upstream, "POST", "/v1/chat")
+                .await,
+            Err(RepositoryError::NotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn list_filters_by_upstream_and_tenant() {
+        let repo = SeaOrmRouteRepo::new(inmem_db().await);
+        let tenant = Uuid::new_v4();
+        let u1 = Uuid::new_v4();
+        let u2 = Uuid::new_v4();
+        repo.create(make_route(tenant, u1, "/a", vec![HttpMethod::Get], 0))
+            .await
+            .unwrap();
+        repo.create(make_route(tenant, u1, "/b", vec![HttpMethod::Get], 0))
+            .await
+            .unwrap();
+        repo.create(make_route(tenant, u2, "/c", vec![HttpMethod::Get], 0))
+            .await
+            .unwrap();
+        repo.create(make_route(
+            Uuid::new_v4(),
+            u1,
+            "/d",
+            vec![HttpMethod::Get],
+            0,
+        ))
+        .await
+        .unwrap();
+
+        let q = ListQuery::default();
+        assert_eq!(repo.list(tenant, Some(u1), &q).await.unwrap().len(), 2);
+        assert_eq!(repo.list(tenant, None, &q).await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn update_and_delete_respect_tenant() {
+        let repo = SeaOrmRouteRepo::new(inmem_db().await);
+        let tenant = Uuid::new_v4();
+        let r = make_route(tenant, Uuid::new_v4(), "/a", vec![HttpMethod::Get], 0);
+        repo.create(r.clone()).await.unwrap();
+
+        let updated = Route {
+            priority: 7,
+            ..r.clone()
+        };
+        repo.update(updated.clone()).await.unwrap();
+        assert_eq!(repo.get_by_id(tenant, r.id).await.unwrap(), updated);
+
+        let foreign = Route {
+            tenant_id: Uuid::new_v4(),
+            ..r.clone()
+        };
+        assert!(matches!(
+            repo.update(foreign).await,
+            Err(RepositoryError::NotFound { .. })
+        ));
+        assert!(matches!(
+            repo.delete(Uuid::new_v4(), r.id).await,
+            Err(RepositoryError::NotFound { .. })
+        ));
+
+        repo.delete(tenant, r.id).await.unwrap();
+        assert!(repo.get_by_id(tenant, r.id).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn delete_by_upstream_only_removes_tenant_rows() {
+        let repo = SeaOrmRouteRepo::new(inmem_db().await);
+        let tenant = Uuid::new_v4();
+        let other = Uuid::new_v4();
+        let upstream = Uuid::new_v4();
+        repo.create(make_route(tenant, upstream, "/a", vec![HttpMethod::Get], 0))
+            .await
+            .unwrap();
+        repo.create(make_route(tenant, upstream, "/b", vec![HttpMethod::Get], 0))
+            .await
+            .unwrap();
+        let foreign = make_route(other, upstream, "/c", vec![HttpMethod::Get], 0);
+        repo.create(foreign.clone()).await.unwrap();
+
+        assert_eq!(repo.delete_by_upstream(tenant, upstream).await.unwrap(), 2);
+        assert!(repo.get_by_id(other, foreign.id).await.is_ok());
+    }
+}
@@ -0,0 +1,163 @@
+use crate::domain::model::TenantRateLimit;
+use crate::domain::repo::{RepositoryError, TenantRateLimitRepository};
+use async_trait::async_trait;
+use modkit_db::secure::{SecureDeleteExt, SecureEntityExt, SecureInsertExt, SecureOnConflict};
+use modkit_db::{DBProvider, DbError};
+use modkit_security::AccessScope;
+use sea_orm::{ActiveValue, EntityTrait};
+use uuid::Uuid;
+
+use super::db::{DbSource, scope_err, spec_err};
+use super::entity::tenant_rate_limit::{self, Entity as TenantRateLimitEntity};
+use super::records::RateLimitConfig;
+
+/// SeaORM-backed tenant rate limit repository.
+///
+/// Rows are tenant-scoped through the secure ORM layer; the tenant id is the
+/// primary key, so upsert replaces the tenant's single row.
+pub struct SeaOrmTenantRateLimitRepo<D = DBProvider<DbError>> {
+    db: D,
+}
+
+impl SeaOrmTenantRateLimitRepo {
+    #[must_use]
+    pub fn new(db: DBProvider<DbError>) -> Self {
+        Self { db }
+    }
+}
+
+fn to_active_model(l: &TenantRateLimit) -> Result<tenant_rate_limit::ActiveModel, RepositoryError> {
+    let spec = serde_json::to_string(&RateLimitConfig::from(l.config.clone())).map_err(spec_err)?;
+    Ok(tenant_rate_limit::ActiveModel {
+        tenant_id: ActiveValue::Set(l.tenant_id),
+        aggregate: ActiveValue::Set(l.aggregate),
+        spec: ActiveValue::Set(spec),
+    })
+}
+
+fn from_model(m: tenant_rate_limit::Model) -> Result<TenantRateLimit, RepositoryError> {
+    let spec: RateLimitConfig = serde_json::from_str(&m.spec).map_err(spec_err)?;
+    Ok(TenantRateLimit {
+        tenant_id: m.tenant_id,
+        config: spec.into(),
+        aggregate: m.aggregate,
+    })
+}
+
+#[async_trait]
+impl<D: DbSource> TenantRateLimitRepository for SeaOrmTenantRateLimitRepo<D> {
+    async fn get(&self, tenant_id: Uuid) -> Result<Option<TenantRateLimit>, RepositoryError> {
+        let conn = self.db.runner()?;
+        let scope = AccessScope::for_tenant(tenant_id);
+
+        TenantRateLimitEntity::find()
+            .secure()
+            .scope_with(&scope)
+            .one(&*conn)
+            .await
+            .map_err(scope_err)?
+            .map(from_model)
+            .transpose()
+    }
+
+    async fn upsert(&self, limit: TenantRateLimit) -> Result<TenantRateLimit, RepositoryError> {
+        let conn = self.db.runner()?;
+        let scope = AccessScope::for_tenant(limit.tenant_id);
+        let am = to_active_model(&limit)?;
+
+        let on_conflict = SecureOnConflict::<TenantRateLimitEntity>::columns([
+            tenant_rate_limit::Column::TenantId,
+        ])
+        .update_columns([
+            tenant_rate_limit::Column::Aggregate,
+            tenant_rate_limit::Column::Spec,
+        ])
+        .map_err(scope_err)?;
+        TenantRateLimitEntity::insert(am.clone())
+            .secure()
+            .scope_with_model(&scope, &am)
+            .map_err(scope_err)?
+            .on_conflict(on_conflict)
+            .exec(&*conn)
+            .await
+            .map_err(scope_err)?;
+        Ok(limit)
+    }
+
+    async fn delete(&self, tenant_id: Uuid) -> Result<(), RepositoryError> {
+        let conn = self.db.runner()?;
+        let scope = AccessScope::for_tenant(tenant_id);
+
+        let result = TenantRateLimitEntity::delete_many()
+            .secure()
+            .scope_with(&scope)
+            .exec(&*conn)
+            .await
+            .map_err(scope_err)?;
+        if result.rows_affected == 0 {
+            return Err(RepositoryError::NotFound {
+                entity: "tenant rate limit",
+                id: tenant_id,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::{BurstConfig, CostStrategy, RateLimitConfig, SustainedRate, Window};
+    use crate::infra::storage::db::inmem_db;
+
+    use super::*;
+
+    fn make_limit(tenant_id: Uuid, rate: u32, aggregate: bool) -> TenantRateLimit {
+        TenantRateLimit {
+            tenant_id,
+            config: RateLimitConfig {
+                sharing: Default::default(),
+                algorithm: Default::default(),
+                sustained: SustainedRate {
+                    rate,
+                    window: Window::Seconds(30),
+                },
+                burst: Some(BurstConfig { capacity: rate * 2 }),
+                scope: Default::default(),
+                strategy: Default::default(),
+                cost: CostStrategy::PerKilobyte,
+            },
+            aggregate,
+        }
+    }
+
+    #[tokio::test]
+    async fn upsert_round_trips_and_replaces() {
+        let repo = SeaOrmTenantRateLimitRepo::new(inmem_db().await);
+        let tenant = Uuid::new_v4();
+        assert_eq!(repo.get(tenant).await.unwrap(), None);
+
+        let first = make_limit(tenant, 10, false);
+        repo.upsert(first.clone()).await.unwrap();
+        assert_eq!(repo.get(tenant).await.unwrap(), Some(first));
+
+        let second = make_limit(tenant, 20, true);
+        repo.upsert(second.clone()).await.unwrap();
+        assert_eq!(repo.get(tenant).await.unwrap(), Some(second));
+    }
+
+    #[tokio::test]
+    async fn tenants_are_isolated() {
+        let repo = SeaOrmTenantRateLimitRepo::new(inmem_db().await);
+        let tenant = Uuid::new_v4();
+        let other = Uuid::new_v4();
+        repo.upsert(make_limit(tenant, 10, false)).await.unwrap();
+
+        assert_eq!(repo.get(other).await.unwrap(), None);
+        assert!(matches!(
+            repo.delete(other).await,
+            Err(RepositoryError::NotFound { .. })
+        ));
+        repo.delete(tenant).await.unwrap();
+        assert_eq!(repo.get(tenant).await.unwrap(), None);
+    }
+}
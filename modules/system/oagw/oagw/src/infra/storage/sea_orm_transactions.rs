@@ -0,0 +1,186 @@
+use async_trait::async_trait;
+use modkit_db::secure::DbTx;
+use modkit_db::{DBProvider, DbError};
+
+use crate::domain::repo::{
+    RepositoryError, RepositoryTx, RouteRepository, TransactionRunner, TxWork, UpstreamRepository,
+};
+
+use super::db::db_err;
+use super::{SeaOrmRouteRepo, SeaOrmUpstreamRepo};
+
+/// Database transactions over the SeaORM-backed repositories.
+pub struct SeaOrmTransactions {
+    db: DBProvider<DbError>,
+}
+
+impl SeaOrmTransactions {
+    #[must_use]
+    pub fn new(db: DBProvider<DbError>) -> Self {
+        Self { db }
+    }
+}
+
+struct TxRepos<'t> {
+    upstreams: SeaOrmUpstreamRepo<&'t DbTx<'t>>,
+    routes: SeaOrmRouteRepo<&'t DbTx<'t>>,
+}
+
+impl RepositoryTx for TxRepos<'_> {
+    fn upstreams(&self) -> &dyn UpstreamRepository {
+        &self.upstreams
+    }
+
+    fn routes(&self) -> &dyn RouteRepository {
+        &self.routes
+    }
+}
+
+#[async_trait]
+impl TransactionRunner for SeaOrmTransactions {
+    async fn run(&self, work: TxWork) -> Result<(), RepositoryError> {
+        self.db
+            .transaction(|tx| {
+                Box::pin(async move {
+                    let repos = TxRepos {
+                        upstreams: SeaOrmUpstreamRepo::in_tx(tx),
+                        routes: SeaOrmRouteRepo::in_tx(tx),
+                    };
+                    work(&repos)
+                        .await
+                        .map_err(|e| DbError::Other(anyhow::Error::new(e)))
+                })
+            })
+            .await
+            .map_err(|e| match e {
+                DbError::Other(err) => err
+                    .downcast::<RepositoryError>()
+                    .unwrap_or_else(|err| db_err(DbError::Other(err))),
+                other => db_err(other),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::domain::model::{
+        Endpoint, HttpMatch, HttpMethod, MatchRules, PathSuffixMode, Route, Scheme, Server,
+        Upstream,
+    };
+    use crate::infra::storage::db::inmem_db;
+
+    use super::*;
+
+    fn make_upstream(tenant_id: Uuid) -> Upstream {
+        Upstream {
+            id: Uuid::new_v4(),
+            tenant_id,
+            alias: "openai".into(),
+            server: Server {
+                endpoints: vec![Endpoint {
+                    scheme: Scheme::Https,
+                    host: "api.openai.com".into(),
+                    port: 443,
+                }],
+            },
+            protocol: "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1".into(),
+            enabled: true,
+            auth: None,
+            headers: None,
+            plugins: None,
+            rate_limit: None,
+            cors: None,
+            tags: vec![],
+        }
+    }
+
+    fn make_route(tenant_id: Uuid, upstream_id: Uuid) -> Route {
+        Route {
+            id: Uuid::new_v4(),
+            tenant_id,
+            upstream_id,
+            match_rules: MatchRules {
+                http: Some(HttpMatch {
+                    methods: vec![HttpMethod::Get],
+                    path: "/v1".into(),
+                    query_allowlist: vec![],
+                    path_suffix_mode: PathSuffixMode::Append,
+                }),
+                grpc: None,
+            },
+            plugins: None,
+            rate_limit: None,
+            cors: None,
+            sse_reconnect: None,
+            get_coalescing: None,
+            request_compression: None,
+            response_cache: None,
+            tags: vec![],
+            priority: 0,
+            enabled: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn failed_cascade_rolls_back_route_deletes() {
+        let db = inmem_db().await;
+        let upstreams = SeaOrmUpstreamRepo::new(db.clone());
+        let routes = SeaOrmRouteRepo::new(db.clone());
+        let tenant = Uuid::new_v4();
+        let upstream = upstreams.create(make_upstream(tenant)).await.unwrap();
+        let route = routes
+            .create(make_route(tenant, upstream.id))
+            .await
+            .unwrap();
+
+        let tx: &dyn TransactionRunner = &SeaOrmTransactions::new(db);
+        let upstream_id = upstream.id;
+        let err = tx
+            .with_transaction(move |tx| {
+                Box::pin(async move {
+                    let deleted = tx.routes().delete_by_upstream(tenant, upstream_id).await?;
+                    assert_eq!(deleted, 1);
+                    // Fails after the routes are gone: the wrong upstream id.
+                    tx.upstreams().delete(tenant, Uuid::new_v4()).await
+                })
+            })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, RepositoryError::NotFound { .. }));
+        assert_eq!(routes.get_by_id(tenant, route.id).await.unwrap(), route);
+        assert!(upstreams.get_by_id(tenant, upstream.id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn successful_cascade_commits() {
+        let db = inmem_db().await;
+        let upstreams = SeaOrmUpstreamRepo::new(db.clone());
+        let routes = SeaOrmRouteRepo::new(db.clone());
+        let tenant = Uuid::new_v4();
+        let upstream = upstreams.create(make_upstream(tenant)).await.unwrap();
+        let route = routes
+            .create(make_route(tenant, upstream.id))
+            .await
+            .unwrap();
+
+        let tx: &dyn TransactionRunner = &SeaOrmTransactions::new(db);
+        let upstream_id = upstream.id;
+        let deleted = tx
+            .with_transaction(move |tx| {
+                Box::pin(async move {
+                    let deleted = tx.routes().delete_by_upstream(tenant, upstream_id).await?;
+                    tx.upstreams().delete(tenant, upstream_id).await?;
+                    Ok(deleted)
+                })
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(deleted, 1);
+        assert!(routes.get_by_id(tenant, route.id).await.is_err());
+        assert!(upstreams.get_by_id(tenant, upstream.id).await.is_err());
+    }
+}
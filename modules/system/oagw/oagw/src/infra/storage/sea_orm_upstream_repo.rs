@@ -0,0 +1,342 @@
+use crate::domain::model::{ListQuery, Server, Upstream};
+use crate::domain::repo::{RepositoryError, UpstreamRepository};
+use async_trait::async_trait;
+use modkit_db::secure::{
+    DbTx, SecureDeleteExt, SecureEntityExt, secure_insert, secure_update_with_scope,
+};
+use modkit_db::{DBProvider, DbError};
+use modkit_security::AccessScope;
+use sea_orm::{ActiveValue, ColumnTrait, Condition, EntityTrait, Order};
+use uuid::Uuid;
+
+use super::db::{DbSource, scope_err, spec_err};
+use super::entity::upstream::{self, Entity as UpstreamEntity};
+use super::records::UpstreamSpec;
+
+/// SeaORM-backed upstream repository.
+///
+/// Rows are tenant-scoped through the secure ORM layer; the `(tenant_id, alias)`
+/// unique constraint enforces alias uniqueness per tenant.
+///
+/// Runs on the connection pool by default, or inside an open transaction
+/// via [`SeaOrmUpstreamRepo::in_tx`].
+pub struct SeaOrmUpstreamRepo<D = DBProvider<DbError>> {
+    db: D,
+}
+
+impl SeaOrmUpstreamRepo {
+    #[must_use]
+    pub fn new(db: DBProvider<DbError>) -> Self {
+        Self { db }
+    }
+}
+
+impl<'t> SeaOrmUpstreamRepo<&'t DbTx<'t>> {
+    pub(crate) fn in_tx(tx: &'t DbTx<'t>) -> Self {
+        Self { db: tx }
+    }
+}
+
+fn to_active_model(u: &Upstream) -> Result<upstream::ActiveModel, RepositoryError> {
+    let spec = serde_json::to_string(&UpstreamSpec::from(u)).map_err(spec_err)?;
+    Ok(upstream::ActiveModel {
+        id: ActiveValue::Set(u.id),
+        tenant_id: ActiveValue::Set(u.tenant_id),
+        alias: ActiveValue::Set(u.alias.clone()),
+        protocol: ActiveValue::Set(u.protocol.clone()),
+        enabled: ActiveValue::Set(u.enabled),
+        spec: ActiveValue::Set(spec),
+    })
+}
+
+fn from_model(m: upstream::Model) -> Result<Upstream, RepositoryError> {
+    let spec: UpstreamSpec = serde_json::from_str(&m.spec).map_err(spec_err)?;
+    Ok(Upstream {
+        id: m.id,
+        tenant_id: m.tenant_id,
+        alias: m.alias,
+        server: Server {
+            endpoints: spec.endpoints.into_iter().map(Into::into).collect(),
+        },
+        protocol: m.protocol,
+        enabled: m.enabled,
+        auth: spec.auth.map(Into::into),
+        headers: spec.headers.map(Into::into),
+        plugins: spec.plugins.map(Into::into),
+        rate_limit: spec.rate_limit.map(Into::into),
+        cors: spec.cors.map(Into::into),
+        tags: spec.tags,
+    })
+}
+
+fn alias_conflict(alias: &str) -> RepositoryError {
+    RepositoryError::Conflict(format!("alias '{alias}' already exists for tenant"))
+}
+
+#[async_trait]
+impl<D: DbSource> UpstreamRepository for SeaOrmUpstreamRepo<D> {
+    async fn create(&self, upstream: Upstream) -> Result<Upstream, RepositoryError> {
+        let conn = self.db.runner()?;
+        let scope = AccessScope::for_tenant(upstream.tenant_id);
+        let am = to_active_model(&upstream)?;
+
+        match secure_insert::<UpstreamEntity>(am, &scope, &*conn).await {
+            Ok(_) => Ok(upstream),
+            Err(e) if e.is_unique_violation() => Err(alias_conflict(&upstream.alias)),
+            Err(e) => Err(scope_err(e)),
+        }
+    }
+
+    async fn get_by_id(&self, tenant_id: Uuid, id: Uuid) -> Result<Upstream, RepositoryError> {
+        let conn = self.db.runner()?;
+        let scope = AccessScope::for_tenant(tenant_id);
+
+        let model = UpstreamEntity::find()
+            .secure()
+            .scope_with(&scope)
+            .and_id(id)
+            .map_err(scope_err)?
+            .one(&*conn)
+            .await
+            .map_err(scope_err)?
+            .ok_or(RepositoryError::NotFound {
+                entity: "upstream",
+                id,
+            })?;
+        from_model(model)
+    }
+
+    async fn get_by_alias(
+        &self,
+        tenant_id: Uuid,
+        alias: &str,
+    ) -> Result<Upstream, RepositoryError> {
+        let conn = self.db.runner()?;
+        let scope = AccessScope::for_tenant(tenant_id);
+
+        let model = UpstreamEntity::find()
+            .secure()
+            .scope_with(&scope)
+            .filter(Condition::all().add(upstream::Column::Alias.eq(alias)))
+            .one(&*conn)
+            .await
+            .map_err(scope_err)?
+            .ok_or(RepositoryError::NotFound {
+                entity: "upstream",
+                id: Uuid::nil(),
+            })?;
+        from_model(model)
+    }
+
+    async fn list(
+        &self,
+        tenant_id: Uuid,
+        query: &ListQuery,
+    ) -> Result<Vec<Upstream>, RepositoryError> {
+        let conn = self.db.runner()?;
+        let scope = AccessScope::for_tenant(tenant_id);
+
+        let models = UpstreamEntity::find()
+            .secure()
+            .scope_with(&scope)
+            .order_by(upstream::Column::Id, Order::Asc)
+            .offset(u64::from(query.skip))
+            .limit(u64::from(query.top))
+            .all(&*conn)
+            .await
+            .map_err(scope_err)?;
+        models.into_iter().map(from_model).collect()
+    }
+
+    async fn update(&self, upstream: Upstream) -> Result<Upstream, RepositoryError> {
+        let conn = self.db.runner()?;
+        let scope = AccessScope::for_tenant(upstream.tenant_id);
+        let id = upstream.id;
+
+        // Resolve not-found up front so it is not reported as a scope denial.
+        let exists = UpstreamEntity::find()
+            .secure()
+            .scope_with(&scope)
+            .and_id(id)
+            .map_err(scope_err)?
+            .one(&*conn)
+            .await
+            .map_err(scope_err)?
+            .is_some();
+        if !exists {
+            return Err(RepositoryError::NotFound {
+                entity: "upstream",
+                id,
+            });
+        }
+
+        let am = to_active_model(&upstream)?;
+        match secure_update_with_scope::<UpstreamEntity>(am, &scope, id, &*conn).await {
+            Ok(_) => Ok(upstream),
+            Err(e) if e.is_unique_violation() => Err(alias_conflict(&upstream.alias)),
+            Err(e) => Err(scope_err(e)),
+        }
+    }
+
+    async fn delete(&self, tenant_id: Uuid, id: Uuid) -> Result<(), RepositoryError> {
+        let conn = self.db.runner()?;
+        let scope = AccessScope::for_tenant(tenant_id);
+
+        let result = UpstreamEntity::delete_many()
+            .secure()
+            .scope_with(&scope)
+            .filter(Condition::all().add(upstream::Column::Id.eq(id)))
+            .exec(&*conn)
+            .await
+            .map_err(scope_err)?;
+        if result.rows_affected == 0 {
+            return Err(RepositoryError::NotFound {
+                entity: "upstream",
+                id,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::{Endpoint, Scheme};
+    use crate::infra::storage::db::inmem_db;
+
+    use super::*;
+
+    fn make_upstream(tenant_id: Uuid, alias: &str) -> Upstream {
+        Upstream {
+            id: Uuid::new_v4(),
+            tenant_id,
+            alias: alias.into(),
+            server: Server {
+                endpoints: vec![Endpoint {
+                    scheme: Scheme::Https,
+                    host: "api.openai.com".into(),
+                    port: 443,
+                }],
+            },
+            protocol: "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1".into(),
+            enabled: true,
+            auth: None,
+            headers: None,
+            plugins: None,
+            rate_limit: None,
+            cors: None,
+            tags: vec!["llm".into()],
+        }
+    }
+
+    #[tokio::test]
+    async fn create_and_get_round_trip() {
+        let repo = SeaOrmUpstreamRepo::new(inmem_db().await);
+        let tenant = Uuid::new_v4();
+        let u = make_upstream(tenant, "openai");
+
+        let created = repo.create(u.clone()).await.unwrap();
+        assert_eq!(created, u);
+
+        assert_eq!(repo.get_by_id(tenant, u.id).await.unwrap(), u);
+        assert_eq!(repo.get_by_alias(tenant, "openai").await.unwrap(), u);
+    }
+
+    #[tokio::test]
+    async fn alias_conflict_within_tenant_only() {
+        let repo = SeaOrmUpstreamRepo::new(inmem_db().await);
+        let tenant = Uuid::new_v4();
+        repo.create(make_upstream(tenant, "openai")).await.unwrap();
+
+        let err = repo
+            .create(make_upstream(tenant, "openai"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, RepositoryError::Conflict(_)));
+
+        repo.create(make_upstream(Uuid::new_v4(), "openai"))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn cross_tenant_access_is_not_found() {
+        let repo = SeaOrmUpstreamRepo::new(inmem_db().await);
+        let tenant = Uuid::new_v4();
+        let other = Uuid::new_v4();
+        let u = make_upstream(tenant, "openai");
+        repo.create(u.clone()).await.unwrap();
+
+        assert!(matches!(
+            repo.get_by_id(other, u.id).await,
+            Err(RepositoryError::NotFound { .. })
+        ));
+        assert!(matches!(
+            repo.delete(other, u.id).await,
+            Err(RepositoryError::NotFound { .. })
+        ));
+        assert!(repo.get_by_id(tenant, u.id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn update_changes_alias_and_detects_conflict() {
+        let repo = SeaOrmUpstreamRepo::new(inmem_db().await);
+        let tenant = Uuid::new_v4();
+        let a = make_upstream(tenant, "a");
+        let b = make_upstream(tenant, "b");
+        repo.create(a.clone()).await.unwrap();
+        repo.create(b.clone()).await.unwrap();
+
+        let renamed = Upstream {
+            alias: "c".into(),
+            enabled: false,
+            ..a.clone()
+        };
+        repo.update(renamed.clone()).await.unwrap();
+        assert_eq!(repo.get_by_alias(tenant, "c").await.unwrap(), renamed);
+        assert!(repo.get_by_alias(tenant, "a").await.is_err());
+
+        let clash = Upstream {
+            alias: "b".into(),
+            ..renamed
+        };
+        assert!(matches!(
+            repo.update(clash).await,
+            Err(RepositoryError::Conflict(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn update_missing_is_not_found() {
+        let repo = SeaOrmUpstreamRepo::new(inmem_db().await);
+        let u = make_upstream(Uuid::new_v4(), "ghost");
+        assert!(matches!(
+            repo.update(u).await,
+            Err(RepositoryError::NotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn list_paginates_in_id_order() {
+        let repo = SeaOrmUpstreamRepo::new(inmem_db().await);
+        let tenant = Uuid::new_v4();
+        for i in 0..5 {
+            repo.create(make_upstream(tenant, &format!("u{i}")))
+                .await
+                .unwrap();
+        }
+        repo.create(make_upstream(Uuid::new_v4(), "other"))
+            .await
+            .unwrap();
+
+        let all = repo.list(tenant, &ListQuery::default()).await.unwrap();
+        assert_eq!(all.len(), 5);
+        assert!(all.windows(2).all(|w| w[0].id < w[1].id));
+
+        let page = repo
+            .list(tenant, &ListQuery { top: 2, skip: 1 })
+            .await
+            .unwrap();
+        assert_eq!(page, all[1..3].to_vec());
+    }
+}
@@ -0,0 +1,104 @@
+use crate::domain::model::TenantRateLimit;
+use crate::domain::repo::{RepositoryError, TenantRateLimitRepository};
+use async_trait::async_trait;
+use dashmap::DashMap;
+use modkit_macros::domain_model;
+use uuid::Uuid;
+
+/// In-memory tenant rate limit repository backed by `DashMap`.
+#[domain_model]
+pub struct InMemoryTenantRateLimitRepo {
+    /// tenant_id -> TenantRateLimit.
+    store: DashMap<Uuid, TenantRateLimit>,
+}
+
+impl InMemoryTenantRateLimitRepo {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            store: DashMap::new(),
+        }
+    }
+}
+
+impl Default for InMemoryTenantRateLimitRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl TenantRateLimitRepository for InMemoryTenantRateLimitRepo {
+    async fn get(&self, tenant_id: Uuid) -> Result<Option<TenantRateLimit>, RepositoryError> {
+        Ok(self.store.get(&tenant_id).map(|l| l.clone()))
+    }
+
+    async fn upsert(&self, limit: TenantRateLimit) -> Result<TenantRateLimit, RepositoryError> {
+        self.store.insert(limit.tenant_id, limit.clone());
+        Ok(limit)
+    }
+
+    async fn delete(&self, tenant_id: Uuid) -> Result<(), RepositoryError> {
+        self.store
+            .remove(&tenant_id)
+            .map(|_| ())
+            .ok_or(RepositoryError::NotFound {
+                entity: "tenant rate limit",
+                id: tenant_id,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::{RateLimitConfig, SustainedRate, Window};
+
+    use super::*;
+
+    fn make_limit(tenant_id: Uuid, rate: u32) -> TenantRateLimit {
+        TenantRateLimit {
+            tenant_id,
+            config: RateLimitConfig {
+                sharing: Default::default(),
+                algorithm: Default::default(),
+                sustained: SustainedRate {
+                    rate,
+                    window: Window::Minute,
+                },
+                burst: None,
+                scope: Default::default(),
+                strategy: Default::default(),
+                cost: Default::default(),
+            },
+            aggregate: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn upsert_replaces_and_is_tenant_scoped() {
+        let repo = InMemoryTenantRateLimitRepo::new();
+        let tenant = Uuid::new_v4();
+        assert_eq!(repo.get(tenant).await.unwrap(), None);
+
+        repo.upsert(make_limit(tenant, 10)).await.unwrap();
+        let replaced = make_limit(tenant, 20);
+        repo.upsert(replaced.clone()).await.unwrap();
+
+        assert_eq!(repo.get(tenant).await.unwrap(), Some(replaced));
+        assert_eq!(repo.get(Uuid::new_v4()).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn delete_missing_is_not_found() {
+        let repo = InMemoryTenantRateLimitRepo::new();
+        let tenant = Uuid::new_v4();
+        assert!(matches!(
+            repo.delete(tenant).await,
+            Err(RepositoryError::NotFound { .. })
+        ));
+
+        repo.upsert(make_limit(tenant, 10)).await.unwrap();
+        repo.delete(tenant).await.unwrap();
+        assert_eq!(repo.get(tenant).await.unwrap(), None);
+    }
+}
@@ -0,0 +1,390 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use uuid::Uuid;
+
+use crate::domain::model::{ListQuery, Route, Upstream};
+use crate::domain::repo::{
+    RepositoryError, RepositoryTx, RouteRepository, TransactionRunner, TxWork, UpstreamRepository,
+};
+
+use super::{InMemoryRouteRepo, InMemoryUpstreamRepo};
+
+/// Transactions over the in-memory repositories.
+///
+/// Transactions run one at a time under a lock. Writes go straight to the
+/// shared repositories while the prior state of every touched entity is kept
+/// in a rollback buffer, which is replayed in reverse if the work fails.
+/// Calls made outside a transaction are not blocked and may observe its
+/// uncommitted writes.
+pub struct InMemoryTransactions {
+    upstreams: Arc<InMemoryUpstreamRepo>,
+    routes: Arc<InMemoryRouteRepo>,
+    lock: tokio::sync::Mutex<()>,
+}
+
+impl InMemoryTransactions {
+    #[must_use]
+    pub fn new(upstreams: Arc<InMemoryUpstreamRepo>, routes: Arc<InMemoryRouteRepo>) -> Self {
+        Self {
+            upstreams,
+            routes,
+            lock: tokio::sync::Mutex::new(()),
+        }
+    }
+}
+
+/// State of one entity before the transaction first wrote it.
+enum Undo {
+    Upstream(Uuid, Option<Box<Upstream>>),
+    Route(Uuid, Option<Box<Route>>),
+}
+
+type UndoLog = Mutex<Vec<Undo>>;
+
+#[async_trait]
+impl TransactionRunner for InMemoryTransactions {
+    async fn run(&self, work: TxWork) -> Result<(), RepositoryError> {
+        let _serialized = self.lock.lock().await;
+        let undo = UndoLog::default();
+        let repos = TxRepos {
+            upstreams: TxUpstreams {
+                repo: &self.upstreams,
+                undo: &undo,
+            },
+            routes: TxRoutes {
+                repo: &self.routes,
+                undo: &undo,
+            },
+        };
+        let result = work(&repos).await;
+        if result.is_err() {
+            for entry in undo.into_inner().into_iter().rev() {
+                match entry {
+                    Undo::Upstream(id, previous) => {
+                        self.upstreams.restore(id, previous.map(|u| *u))
+                    }
+                    Undo::Route(id, previous) => self.routes.restore(id, previous.map(|r| *r)),
+                }
+            }
+        }
+        result
+    }
+}
+
+struct TxRepos<'a> {
+    upstreams: TxUpstreams<'a>,
+    routes: TxRoutes<'a>,
+}
+
+impl RepositoryTx for TxRepos<'_> {
+    fn upstreams(&self) -> &dyn UpstreamRepository {
+        &self.upstreams
+    }
+
+    fn routes(&self) -> &dyn RouteRepository {
+        &self.routes
+    }
+}
+
+/// Upstream repository that records prior state before each write.
+struct TxUpstreams<'a> {
+    repo: &'a InMemoryUpstreamRepo,
+    undo: &'a UndoLog,
+}
+
+impl TxUpstreams<'_> {
+    fn record(&self, id: Uuid) {
+        self.undo
+            .lock()
+            .push(Undo::Upstream(id, self.repo.snapshot(id).map(Box::new)));
+    }
+}
+
+#[async_trait]
+impl UpstreamRepository for TxUpstreams<'_> {
+    async fn create(&self, upstream: Upstream) -> Result<Upstream, RepositoryError> {
+        self.record(upstream.id);
+        self.repo.create(upstream).await
+    }
+
+    async fn get_by_id(&self, tenant_id: Uuid, id: Uuid) -> Result<Upstream, RepositoryError> {
+        self.repo.get_by_id(tenant_id, id).await
+    }
+
+    async fn get_by_alias(
+        &self,
+        tenant_id: Uuid,
+        alias: &str,
+    ) -> Result<Upstream, RepositoryError> {
+        self.repo.get_by_alias(tenant_id, alias).await
+    }
+
+    async fn list(
+        &self,
+        tenant_id: Uuid,
+        query: &ListQuery,
+    ) -> Result<Vec<Upstream>, RepositoryError> {
+        self.repo.list(tenant_id, query).await
+    }
+
+    async fn update(&self, upstream: Upstream) -> Result<Upstream, RepositoryError> {
+        self.record(upstream.id);
+        self.repo.update(upstream).await
+    }
+
+    async fn delete(&self, tenant_id: Uuid, id: Uuid) -> Result<(), RepositoryError> {
+        self.record(id);
+        self.repo.delete(tenant_id, id).await
+    }
+}
+
+/// Route repository that records prior state before each write.
+struct TxRoutes<'a> {
+    repo: &'a InMemoryRouteRepo,
+    undo: &'a UndoLog,
+}
+
+impl TxRoutes<'_> {
+    fn record(&self, id: Uuid) {
+        self.undo
+            .lock()
+            .push(Undo::Route(id, self.repo.snapshot(id).map(Box::new)));
+    }
+}
+
+#[async_trait]
+impl RouteRepository for TxRoutes<'_> {
+    async fn create(&self, route: Route) -> Result<Route, RepositoryError> {
+        self.record(route.id);
+        self.repo.create(route).await
+    }
+
+    async fn get_by_id(&self, tenant_id: Uuid, id: Uuid) -> Result<Route, RepositoryError> {
+        self.repo.get_by_id(tenant_id, id).await
+    }
+
+    async fn list(
+        &self,
+        tenant_id: Uuid,
+        upstream_id: Option<Uuid>,
+        query: &ListQuery,
+    ) -> Result<Vec<Route>, RepositoryError> {
+        self.repo.list(tenant_id, upstream_id, query).await
+    }
+
+    async fn find_matching(
+        &self,
+        tenant_id: Uuid,
+        upstream_id: Uuid,
+        method: &str,
+        path: &str,
+    ) -> Result<Route, RepositoryError> {
+        self.repo
+            .find_matching(tenant_id, upstream_id, method, path)
+            .await
+    }
+
+    async fn update(&self, route: Route) -> Result<Route, RepositoryError> {
+        self.record(route.id);
+        self.repo.update(route).await
+    }
+
+    async fn delete(&self, tenant_id: Uuid, id: Uuid) -> Result<(), RepositoryError> {
+        self.record(id);
+        self.repo.delete(tenant_id, id).await
+    }
+
+    async fn delete_by_upstream(
+        &self,
+        tenant_id: Uuid,
+        upstream_id: Uuid,
+    ) -> Result<u64, RepositoryError> {
+        let previous = self.repo.snapshot_by_upstream(upstream_id);
+        self.undo.lock().extend(
+            previous
+                .into_iter()
+                .map(|route| Undo::Route(route.id, Some(Box::new(route)))),
+        );
+        self.repo.delete_by_upstream(tenant_id, upstream_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::{
+        Endpoint, HttpMatch, HttpMethod, MatchRules, PathSuffixMode, Scheme, Server,
+    };
+
+    use super::*;
+
+    fn make_upstream(tenant_id: Uuid, alias: &str) -> Upstream {
+        Upstream {
+            id: Uuid::new_v4(),
+            tenant_id,
+            alias: alias.into(),
+            server: Server {
+                endpoints: vec![Endpoint {
+                    scheme: Scheme::Https,
+                    host: "api.openai.com".into(),
+                    port: 443,
+                }],
+            },
+            protocol: "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1".into(),
+            enabled: true,
+            auth: None,
+            headers: None,
+            plugins: None,
+            rate_limit: None,
+            cors: None,
+            tags: vec![],
+        }
+    }
+
+    fn make_route(tenant_id: Uuid, upstream_id: Uuid, path: &str) -> Route {
+        Route {
+            id: Uuid::new_v4(),
+            tenant_id,
+            upstream_id,
+            match_rules: MatchRules {
+                http: Some(HttpMatch {
+                    methods: vec![HttpMethod::Get],
+                    path: path.into(),
+                    query_allowlist: vec![],
+                    path_suffix_mode: PathSuffixMode::Append,
+                }),
+                grpc: None,
+            },
+            plugins: None,
+            rate_limit: None,
+            cors: None,
+            sse_reconnect: None,
+            get_coalescing: None,
+            request_compression: None,
+            response_cache: None,
+            tags: vec![],
+            priority: 0,
+            enabled: true,
+        }
+    }
+
+    struct Fixture {
+        upstreams: Arc<InMemoryUpstreamRepo>,
+        routes: Arc<InMemoryRouteRepo>,
+        tx: Arc<dyn TransactionRunner>,
+    }
+
+    fn fixture() -> Fixture {
+        let upstreams = Arc::new(InMemoryUpstreamRepo::new());
+        let routes = Arc::new(InMemoryRouteRepo::new());
+        let tx = Arc::new(InMemoryTransactions::new(
+            Arc::clone(&upstreams),
+            Arc::clone(&routes),
+        ));
+        Fixture {
+            upstreams,
+            routes,
+            tx,
+        }
+    }
+
+    #[tokio::test]
+    async fn failed_cascade_restores_routes_and_upstream() {
+        let f = fixture();
+        let tenant = Uuid::new_v4();
+        let upstream = f
+            .upstreams
+            .create(make_upstream(tenant, "openai"))
+            .await
+            .unwrap();
+        let a = f
+            .routes
+            .create(make_route(tenant, upstream.id, "/a"))
+            .await
+            .unwrap();
+        let b = f
+            .routes
+            .create(make_route(tenant, upstream.id, "/b"))
+            .await
+            .unwrap();
+
+        let upstream_id = upstream.id;
+        let err =
+            f.tx.with_transaction(move |tx| {
+                Box::pin(async move {
+                    tx.routes().delete_by_upstream(tenant, upstream_id).await?;
+                    tx.upstreams().delete(tenant, upstream_id).await?;
+                    Err::<(), _>(RepositoryError::Internal("injected failure".into()))
+                })
+            })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, RepositoryError::Internal(ref m) if m == "injected failure"));
+        assert_eq!(
+            f.upstreams.get_by_alias(tenant, "openai").await.unwrap(),
+            upstream
+        );
+        let mut restored = f
+            .routes
+            .list(tenant, Some(upstream.id), &ListQuery::default())
+            .await
+            .unwrap();
+        restored.sort_by_key(|r| r.match_rules.http.as_ref().map(|h| h.path.clone()));
+        assert_eq!(restored, vec![a, b]);
+    }
+
+    #[tokio::test]
+    async fn failed_update_restores_previous_alias() {
+        let f = fixture();
+        let tenant = Uuid::new_v4();
+        let upstream = f
+            .upstreams
+            .create(make_upstream(tenant, "old"))
+            .await
+            .unwrap();
+
+        let renamed = Upstream {
+            alias: "new".into(),
+            ..upstream.clone()
+        };
+        f.tx.with_transaction(move |tx| {
+            Box::pin(async move {
+                tx.upstreams().update(renamed).await?;
+                Err::<(), _>(RepositoryError::Conflict("injected".into()))
+            })
+        })
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            f.upstreams.get_by_alias(tenant, "old").await.unwrap(),
+            upstream
+        );
+        assert!(f.upstreams.get_by_alias(tenant, "new").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn successful_work_is_kept() {
+        let f = fixture();
+        let tenant = Uuid::new_v4();
+        let upstream = make_upstream(tenant, "openai");
+        let route = make_route(tenant, upstream.id, "/a");
+
+        let (u, r) = (upstream.clone(), route.clone());
+        let created =
+            f.tx.with_transaction(move |tx| {
+                Box::pin(async move {
+                    let u = tx.upstreams().create(u).await?;
+                    tx.routes().create(r).await?;
+                    Ok(u.id)
+                })
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(created, upstream.id);
+        assert_eq!(f.routes.get_by_id(tenant, route.id).await.unwrap(), route);
+    }
+}
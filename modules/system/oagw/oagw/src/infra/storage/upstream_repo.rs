@@ -24,6 +24,26 @@ impl InMemoryUpstreamRepo {
     }
 }
 
+impl InMemoryUpstreamRepo {
+    /// Current state of `id` in any tenant, for transaction rollback.
+    pub(crate) fn snapshot(&self, id: Uuid) -> Option<Upstream> {
+        self.store.get(&id).map(|u| u.clone())
+    }
+
+    /// Put `id` back into a state captured by [`Self::snapshot`], keeping the
+    /// alias index in sync.
+    pub(crate) fn restore(&self, id: Uuid, previous: Option<Upstream>) {
+        if let Some((_, current)) = self.store.remove(&id) {
+            self.alias_index.remove(&(current.tenant_id, current.alias));
+        }
+        if let Some(upstream) = previous {
+            self.alias_index
+                .insert((upstream.tenant_id, upstream.alias.clone()), id);
+            self.store.insert(id, upstream);
+        }
+    }
+}
+
 impl Default for InMemoryUpstreamRepo {
     fn default() -> Self {
         Self::new()
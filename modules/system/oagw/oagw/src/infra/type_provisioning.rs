@@ -30,14 +30,6 @@ fn default_true() -> bool {
     true
 }
 
-fn default_port() -> u16 {
-    443
-}
-
-fn default_cost() -> u32 {
-    1
-}
-
 #[derive(Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
 enum Scheme {
@@ -54,8 +46,9 @@ struct Endpoint {
     #[serde(default)]
     scheme: Scheme,
     host: String,
-    #[serde(default = "default_port")]
-    port: u16,
+    /// Defaults to the scheme's well-known port (80 for `http`, 443 otherwise).
+    #[serde(default)]
+    port: Option<u16>,
 }
 
 #[derive(Deserialize)]
@@ -154,6 +147,7 @@ enum Window {
     Minute,
     Hour,
     Day,
+    Seconds(u32),
 }
 
 #[derive(Deserialize)]
@@ -201,8 +195,28 @@ struct RateLimitConfig {
     scope: RateLimitScope,
     #[serde(default)]
     strategy: RateLimitStrategy,
-    #[serde(default = "default_cost")]
-    cost: u32,
+    #[serde(default)]
+    cost: CostStrategy,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum CostStrategy {
+    Fixed(u32),
+    Variable(VariableCost),
+}
+
+impl Default for CostStrategy {
+    fn default() -> Self {
+        Self::Fixed(1)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum VariableCost {
+    PerKilobyte,
+    HeaderValue { header: String },
 }
 
 #[derive(Deserialize)]
@@ -233,6 +247,27 @@ struct CorsConfig {
     allow_credentials: bool,
 }
 
+#[derive(Deserialize)]
+struct SseReconnectConfig {
+    max_retries: u32,
+}
+
+#[derive(Deserialize)]
+struct GetCoalescingConfig {
+    #[serde(default)]
+    vary_headers: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct RequestCompressionConfig {
+    min_bytes: u64,
+}
+
+#[derive(Deserialize)]
+struct ResponseCacheConfig {
+    ttl_secs: u64,
+}
+
 #[derive(Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 enum HttpMethod {
@@ -313,6 +348,14 @@ struct RoutePayload {
     #[serde(default)]
     cors: Option<CorsConfig>,
     #[serde(default)]
+    sse_reconnect: Option<SseReconnectConfig>,
+    #[serde(default)]
+    get_coalescing: Option<GetCoalescingConfig>,
+    #[serde(default)]
+    request_compression: Option<RequestCompressionConfig>,
+    #[serde(default)]
+    response_cache: Option<ResponseCacheConfig>,
+    #[serde(default)]
     tags: Vec<String>,
     #[serde(default)]
     priority: i32,
@@ -338,10 +381,11 @@ impl From<Scheme> for domain::Scheme {
 
 impl From<Endpoint> for domain::Endpoint {
     fn from(v: Endpoint) -> Self {
+        let scheme: domain::Scheme = v.scheme.into();
         Self {
-            scheme: v.scheme.into(),
+            scheme,
             host: v.host,
-            port: v.port,
+            port: v.port.unwrap_or_else(|| scheme.default_port()),
         }
     }
 }
@@ -431,6 +475,7 @@ impl From<Window> for domain::Window {
             Window::Minute => Self::Minute,
             Window::Hour => Self::Hour,
             Window::Day => Self::Day,
+            Window::Seconds(n) => Self::Seconds(n),
         }
     }
 }
@@ -474,6 +519,18 @@ impl From<RateLimitStrategy> for domain::RateLimitStrategy {
     }
 }
 
+impl From<CostStrategy> for domain::CostStrategy {
+    fn from(v: CostStrategy) -> Self {
+        match v {
+            CostStrategy::Fixed(n) => Self::Fixed(n),
+            CostStrategy::Variable(VariableCost::PerKilobyte) => Self::PerKilobyte,
+            CostStrategy::Variable(VariableCost::HeaderValue { header }) => {
+                Self::HeaderValue(header)
+            }
+        }
+    }
+}
+
 impl From<RateLimitConfig> for domain::RateLimitConfig {
     fn from(v: RateLimitConfig) -> Self {
         Self {
@@ -483,7 +540,7 @@ impl From<RateLimitConfig> for domain::RateLimitConfig {
             burst: v.burst.map(Into::into),
             scope: v.scope.into(),
             strategy: v.strategy.into(),
-            cost: v.cost,
+            cost: v.cost.into(),
         }
     }
 }
@@ -533,6 +590,38 @@ impl From<CorsConfig> for domain::CorsConfig {
     }
 }
 
+impl From<SseReconnectConfig> for domain::SseReconnectConfig {
+    fn from(v: SseReconnectConfig) -> Self {
+        Self {
+            max_retries: v.max_retries,
+        }
+    }
+}
+
+impl From<GetCoalescingConfig> for domain::GetCoalescingConfig {
+    fn from(v: GetCoalescingConfig) -> Self {
+        Self {
+            vary_headers: v.vary_headers,
+        }
+    }
+}
+
+impl From<RequestCompressionConfig> for domain::RequestCompressionConfig {
+    fn from(v: RequestCompressionConfig) -> Self {
+        Self {
+            min_bytes: v.min_bytes,
+        }
+    }
+}
+
+impl From<ResponseCacheConfig> for domain::ResponseCacheConfig {
+    fn from(v: ResponseCacheConfig) -> Self {
+        Self {
+            ttl_secs: v.ttl_secs,
+        }
+    }
+}
+
 impl From<HttpMethod> for domain::HttpMethod {
     fn from(v: HttpMethod) -> Self {
         match v {
@@ -598,6 +687,7 @@ impl UpstreamPayload {
                 cors: self.cors.map(Into::into),
                 tags: self.tags,
                 enabled: self.enabled,
+                idempotency_key: None,
             },
             gts_instance_id,
         }
@@ -614,6 +704,10 @@ impl From<RoutePayload> for ProvisionedRoute {
                 plugins: p.plugins.map(Into::into),
                 rate_limit: p.rate_limit.map(Into::into),
                 cors: p.cors.map(Into::into),
+                sse_reconnect: p.sse_reconnect.map(Into::into),
+                get_coalescing: p.get_coalescing.map(Into::into),
+                request_compression: p.request_compression.map(Into::into),
+                response_cache: p.response_cache.map(Into::into),
                 tags: p.tags,
                 priority: p.priority,
                 enabled: p.enabled,
@@ -1037,7 +1131,31 @@ mod tests {
         assert_eq!(rl.burst.as_ref().unwrap().capacity, 20);
         assert_eq!(rl.scope, domain::RateLimitScope::Tenant);
         assert_eq!(rl.strategy, domain::RateLimitStrategy::Reject);
-        assert_eq!(rl.cost, 2);
+        assert_eq!(rl.cost, domain::CostStrategy::Fixed(2));
+    }
+
+    #[test]
+    fn deserialize_endpoint_without_port_infers_it_from_scheme() {
+        let json = serde_json::json!({
+            "tenant_id": Uuid::new_v4(),
+            "server": {
+                "endpoints": [
+                    {"scheme": "http", "host": "plain.example.com"},
+                    {"scheme": "https", "host": "secure.example.com"},
+                    {"host": "default.example.com"}
+                ]
+            },
+            "protocol": "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1"
+        });
+
+        let payload: UpstreamPayload = serde_json::from_value(json).unwrap();
+        let endpoints = payload.into_provisioned(None).request.server.endpoints;
+
+        assert_eq!(endpoints[0].port, 80);
+        assert_eq!(endpoints[1].port, 443);
+        assert_eq!(endpoints[2].scheme, domain::Scheme::Https);
+        assert_eq!(endpoints[2].port, 443);
+        assert!(endpoints.iter().all(domain::Endpoint::is_standard_port));
     }
 
     #[test]
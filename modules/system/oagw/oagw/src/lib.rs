@@ -4,6 +4,9 @@ pub use oagw_sdk::{
     UpdateUpstreamRequest, Upstream, api::ServiceGatewayClientV1, error::ServiceGatewayError,
 };
 
+// === METRICS ===
+pub use domain::metrics;
+
 // === MODULE DEFINITION ===
 pub mod module;
 pub use module::OutboundApiGatewayModule;
@@ -1,7 +1,7 @@
 use std::sync::{Arc, OnceLock};
 use std::time::Duration;
 
-use crate::config::{OagwConfig, TokenCacheConfig};
+use crate::config::{CredentialBackendConfig, OagwConfig, StorageBackend, TokenCacheConfig};
 use crate::domain::type_catalog::oagw_gts_entities;
 use crate::domain::type_provisioning::TypeProvisioningService;
 use crate::infra::type_provisioning::TypeProvisioningServiceImpl;
@@ -9,21 +9,34 @@ use async_trait::async_trait;
 use authz_resolver_sdk::{AuthZResolverClient, PolicyEnforcer};
 use credstore_sdk::CredStoreClientV1;
 use modkit::api::OpenApiRegistry;
-use modkit::contracts::SystemCapability;
-use modkit::{Module, ModuleCtx, RestApiCapability};
+use modkit::contracts::{RunnableCapability, SystemCapability};
+use modkit::{DatabaseCapability, Module, ModuleCtx, RestApiCapability};
 use modkit_security::SecurityContext;
 use oagw_sdk::api::ServiceGatewayClientV1;
 use tenant_resolver_sdk::TenantResolverClient;
-use tracing::info;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
 use types_registry_sdk::{RegisterResult, RegisterSummary, TypesRegistryClient};
 
 use crate::api::rest::routes;
+use crate::domain::error::DomainError;
+use crate::domain::metrics::{Metrics, NoopMetrics};
+use crate::domain::repo::{
+    RouteRepository, TenantRateLimitRepository, TransactionRunner, UpstreamRepository,
+};
 use crate::domain::services::{
-    ControlPlaneService, ControlPlaneServiceImpl, DataPlaneService, EndpointSelector,
-    ServiceGatewayClientV1Facade,
+    ControlPlaneService, ControlPlaneServiceImpl, CredentialResolver, DataPlaneService,
+    EndpointSelector, ReadinessCheck, ServiceGatewayClientV1Facade,
+};
+use crate::infra::credential::{
+    CachingCredentialResolver, CredStoreBackend, CredentialBackend, EnvCredentialBackend,
+    VaultCredentialBackend,
 };
 use crate::infra::proxy::DataPlaneServiceImpl;
-use crate::infra::storage::{InMemoryRouteRepo, InMemoryUpstreamRepo};
+use crate::infra::storage::{
+    InMemoryRouteRepo, InMemoryTenantRateLimitRepo, InMemoryTransactions, InMemoryUpstreamRepo,
+    SeaOrmRouteRepo, SeaOrmTenantRateLimitRepo, SeaOrmTransactions, SeaOrmUpstreamRepo,
+};
 
 /// Shared application state injected into all handlers.
 #[derive(Clone)]
@@ -31,6 +44,10 @@ pub struct AppState {
     pub(crate) cp: Arc<dyn ControlPlaneService>,
     pub(crate) dp: Arc<dyn DataPlaneService>,
     pub(crate) backend_selector: Arc<dyn EndpointSelector>,
+    pub(crate) credentials: Arc<dyn CredentialResolver>,
+    /// External dependencies probed by `GET /oagw/v1/ready`, in addition to
+    /// the control plane's storage.
+    pub(crate) readiness_checks: Vec<Arc<dyn ReadinessCheck>>,
     pub(crate) config: crate::config::RuntimeConfig,
 }
 
@@ -38,12 +55,17 @@ pub struct AppState {
 #[modkit::module(
     name = "oagw",
     deps = ["types-registry", "authz-resolver", "credstore", "tenant-resolver"],
-    capabilities = [system, rest]
+    capabilities = [system, rest, db, stateful]
 )]
 pub struct OutboundApiGatewayModule {
     state: arc_swap::ArcSwapOption<AppState>,
     registry_client: OnceLock<Arc<dyn TypesRegistryClient>>,
     type_provisioning: OnceLock<Arc<dyn TypeProvisioningService>>,
+    storage: OnceLock<StorageBackend>,
+    /// How long `stop` waits for in-flight proxy requests.
+    drain_timeout: OnceLock<Duration>,
+    /// Data-plane metrics backend supplied by the host, if any.
+    metrics: Option<Arc<dyn Metrics>>,
 }
 
 impl Default for OutboundApiGatewayModule {
@@ -52,10 +74,23 @@ impl Default for OutboundApiGatewayModule {
             state: arc_swap::ArcSwapOption::from(None),
             registry_client: OnceLock::new(),
             type_provisioning: OnceLock::new(),
+            storage: OnceLock::new(),
+            drain_timeout: OnceLock::new(),
+            metrics: None,
         }
     }
 }
 
+impl OutboundApiGatewayModule {
+    /// Report data-plane proxy metrics to `metrics` instead of the default
+    /// backend.
+    #[must_use]
+    pub fn with_metrics(mut self, metrics: Arc<dyn Metrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+}
+
 #[async_trait]
 impl Module for OutboundApiGatewayModule {
     async fn init(&self, ctx: &ModuleCtx) -> anyhow::Result<()> {
@@ -65,8 +100,37 @@ impl Module for OutboundApiGatewayModule {
         info!("OAGW config: proxy_timeout_secs={}", cfg.proxy_timeout_secs);
 
         // -- Control Plane init --
-        let upstream_repo = Arc::new(InMemoryUpstreamRepo::new());
-        let route_repo = Arc::new(InMemoryRouteRepo::new());
+        let (upstream_repo, route_repo, tenant_rate_limit_repo, transactions): (
+            Arc<dyn UpstreamRepository>,
+            Arc<dyn RouteRepository>,
+            Arc<dyn TenantRateLimitRepository>,
+            Arc<dyn TransactionRunner>,
+        ) = match cfg.storage {
+            StorageBackend::InMemory => {
+                let upstreams = Arc::new(InMemoryUpstreamRepo::new());
+                let routes = Arc::new(InMemoryRouteRepo::new());
+                let transactions = Arc::new(InMemoryTransactions::new(
+                    Arc::clone(&upstreams),
+                    Arc::clone(&routes),
+                ));
+                (
+                    upstreams,
+                    routes,
+                    Arc::new(InMemoryTenantRateLimitRepo::new()),
+                    transactions,
+                )
+            }
+            StorageBackend::Database => {
+                let db = ctx.db_required()?;
+                info!("OAGW storage: database");
+                (
+                    Arc::new(SeaOrmUpstreamRepo::new(db.clone())),
+                    Arc::new(SeaOrmRouteRepo::new(db.clone())),
+                    Arc::new(SeaOrmTenantRateLimitRepo::new(db.clone())),
+                    Arc::new(SeaOrmTransactions::new(db)),
+                )
+            }
+        };
         let tenant_resolver = ctx.client_hub().get::<dyn TenantResolverClient>()?;
 
         let credstore = ctx.client_hub().get::<dyn CredStoreClientV1>()?;
@@ -75,33 +139,85 @@ impl Module for OutboundApiGatewayModule {
         let authz = ctx.client_hub().get::<dyn AuthZResolverClient>()?;
         let policy_enforcer = PolicyEnforcer::new(authz);
 
-        let cp: Arc<dyn ControlPlaneService> = Arc::new(ControlPlaneServiceImpl::new(
-            upstream_repo,
-            route_repo,
-            tenant_resolver,
-            policy_enforcer.clone(),
-            credstore.clone(),
+        let cp: Arc<dyn ControlPlaneService> = Arc::new(
+            ControlPlaneServiceImpl::new(
+                upstream_repo,
+                route_repo,
+                tenant_rate_limit_repo,
+                transactions,
+                tenant_resolver,
+                policy_enforcer.clone(),
+                credstore.clone(),
+            )
+            .with_idempotency_key_ttl(Duration::from_secs(cfg.idempotency_key_ttl_secs)),
+        );
+
+        // -- Secret resolution for auth plugins (cached) --
+        let credential_backend: Arc<dyn CredentialBackend> = match &cfg.credential_backend {
+            CredentialBackendConfig::Credstore => Arc::new(CredStoreBackend::new(credstore)),
+            CredentialBackendConfig::Env { prefix } => {
+                info!("OAGW credentials: environment (prefix {prefix})");
+                Arc::new(EnvCredentialBackend::new(prefix.clone()))
+            }
+            CredentialBackendConfig::Vault {
+                address,
+                mount,
+                field,
+                token_env,
+            } => {
+                let token = std::env::var(token_env).map_err(|_| {
+                    anyhow::anyhow!("vault credential backend: ${token_env} is not set")
+                })?;
+                info!("OAGW credentials: vault at {address}");
+                Arc::new(VaultCredentialBackend::new(
+                    modkit_http::HttpClient::new()?,
+                    address.clone(),
+                    mount.clone(),
+                    field.clone(),
+                    token,
+                ))
+            }
+        };
+        let credentials = Arc::new(CachingCredentialResolver::new(
+            credential_backend,
+            Duration::from_secs(cfg.credential_cache_ttl_secs),
         ));
 
         // -- Data Plane init (Pingora proxy engine) --
         let server_conf = Arc::new(pingora_core::server::configuration::ServerConf {
             upstream_keepalive_pool_size: 128,
+            ca_file: cfg.upstream_ca_file.clone(),
             ..Default::default()
         });
         let connect_timeout = Duration::from_secs(10);
         let read_timeout = Duration::from_secs(cfg.proxy_timeout_secs);
         let protocol_cache_ttl = Duration::from_secs(cfg.protocol_cache_ttl_secs);
+        let forward_proxy = crate::infra::proxy::forward_proxy::ForwardProxy::from_config(
+            &cfg.forward_proxy,
+            |name| std::env::var(name).ok(),
+        )
+        .map_err(|e| anyhow::anyhow!("invalid OAGW forward_proxy config: {e}"))?;
+        if let Some(fp) = &forward_proxy {
+            info!(forward_proxy = ?fp, "OAGW upstream connections use a forward proxy");
+        }
+        let resolve_overrides =
+            crate::infra::proxy::pingora_proxy::resolve_overrides(&cfg.resolve_overrides);
         let pingora_proxy = crate::infra::proxy::pingora_proxy::PingoraProxy::new(
             connect_timeout,
             read_timeout,
             protocol_cache_ttl,
-        );
+        )
+        .with_forward_proxy(forward_proxy.clone())
+        .with_resolve_overrides(resolve_overrides.clone());
         let proxy = Arc::new(crate::infra::proxy::pingora_proxy::new_http_proxy(
             &server_conf,
             pingora_proxy,
         ));
-        let backend_selector: Arc<dyn EndpointSelector> =
-            Arc::new(crate::infra::proxy::pingora_proxy::PingoraEndpointSelector::new());
+        let backend_selector: Arc<dyn EndpointSelector> = Arc::new(
+            crate::infra::proxy::pingora_proxy::PingoraEndpointSelector::new()
+                .with_resolve_overrides(resolve_overrides)
+                .with_forward_proxy(forward_proxy),
+        );
 
         let token_http_config = if cfg.allow_http_upstream {
             tracing::warn!("allow_http_upstream is enabled — HTTP token endpoints also allowed");
@@ -114,10 +230,10 @@ impl Module for OutboundApiGatewayModule {
 
         let token_cache_config = TokenCacheConfig::from(&cfg);
 
-        let dp: Arc<dyn DataPlaneService> = Arc::new(
+        let dp_impl = Arc::new(
             DataPlaneServiceImpl::new(
                 cp.clone(),
-                credstore,
+                credentials.clone(),
                 policy_enforcer,
                 token_http_config,
                 token_cache_config,
@@ -125,13 +241,21 @@ impl Module for OutboundApiGatewayModule {
                 proxy,
             )
             .with_request_timeout(Duration::from_secs(cfg.proxy_timeout_secs))
+            .with_max_request_timeout(Duration::from_secs(cfg.proxy_max_timeout_secs))
             .with_max_body_size(cfg.max_body_size_bytes)
+            .with_route_cache(
+                cfg.route_cache_capacity,
+                Duration::from_secs(cfg.route_cache_ttl_secs),
+            )
             .with_allow_http_upstream(cfg.allow_http_upstream)
             .with_websocket_idle_timeout(Duration::from_secs(cfg.websocket_idle_timeout_secs))
             .with_websocket_close_timeout(Duration::from_secs(cfg.websocket_close_timeout_secs))
             .with_websocket_max_frame_size(cfg.websocket_max_frame_size_bytes)
-            .with_streaming_idle_timeout(Duration::from_secs(cfg.streaming_idle_timeout_secs)),
+            .with_streaming_idle_timeout(Duration::from_secs(cfg.streaming_idle_timeout_secs))
+            .with_metrics(self.metrics.clone().unwrap_or_else(proxy_metrics)),
         );
+        tokio::spawn(dp_impl.clone().watch_config_changes(cp.subscribe()));
+        let dp: Arc<dyn DataPlaneService> = dp_impl;
 
         // -- Facade (for external SDK consumers) --
         let oagw: Arc<dyn ServiceGatewayClientV1> =
@@ -175,14 +299,40 @@ impl Module for OutboundApiGatewayModule {
             cp,
             dp,
             backend_selector,
+            credentials,
+            readiness_checks: Vec::new(),
             config: (&cfg).into(),
         };
 
         self.state.store(Some(Arc::new(app_state)));
+        self.storage
+            .set(cfg.storage)
+            .map_err(|_| anyhow::anyhow!("OAGW storage backend already set"))?;
+        self.drain_timeout
+            .set(Duration::from_secs(cfg.shutdown_drain_timeout_secs))
+            .map_err(|_| anyhow::anyhow!("OAGW drain timeout already set"))?;
         Ok(())
     }
 }
 
+/// Default data-plane metrics backend: the process-wide Prometheus registry
+/// when the `prometheus` feature is enabled, otherwise a no-op.
+fn proxy_metrics() -> Arc<dyn Metrics> {
+    #[cfg(feature = "prometheus")]
+    match crate::infra::prometheus_metrics::PrometheusMetrics::new(prometheus::default_registry()) {
+        Ok(metrics) => return Arc::new(metrics),
+        Err(e) => tracing::warn!(error = %e, "OAGW proxy metrics disabled"),
+    }
+    Arc::new(NoopMetrics)
+}
+
+impl DatabaseCapability for OutboundApiGatewayModule {
+    fn migrations(&self) -> Vec<Box<dyn sea_orm_migration::MigrationTrait>> {
+        use sea_orm_migration::MigratorTrait;
+        crate::infra::storage::migrations::Migrator::migrations()
+    }
+}
+
 #[async_trait]
 impl SystemCapability for OutboundApiGatewayModule {
     async fn post_init(&self, _sys: &modkit::runtime::SystemContext) -> anyhow::Result<()> {
@@ -195,7 +345,7 @@ impl SystemCapability for OutboundApiGatewayModule {
         let provisioning: Arc<dyn TypeProvisioningService> =
             Arc::new(TypeProvisioningServiceImpl::new(registry));
 
-        // -- Materialize provisioned upstreams and routes into in-memory repos --
+        // -- Materialize provisioned upstreams and routes into the repos --
         let app_state = self
             .state
             .load()
@@ -208,21 +358,40 @@ impl SystemCapability for OutboundApiGatewayModule {
         // Routes registered via types-registry reference upstreams by the
         // deterministic GTS instance UUID. OAGW assigns random UUIDs, so we
         // need to rewrite route upstream_ids before creating them.
+        // With persistent storage, upstreams provisioned on a previous start
+        // are already present: an alias conflict means "already provisioned",
+        // and routes referencing such upstreams are skipped as well.
+        let persistent = self.storage.get() == Some(&StorageBackend::Database);
         let upstreams = provisioning.list_upstreams().await?;
         let mut gts_to_oagw: std::collections::HashMap<uuid::Uuid, uuid::Uuid> =
             std::collections::HashMap::new();
+        let mut already_provisioned: std::collections::HashSet<uuid::Uuid> =
+            std::collections::HashSet::new();
         for u in &upstreams {
             let ctx = SecurityContext::builder()
                 .subject_tenant_id(u.tenant_id)
                 .subject_id(modkit_security::constants::DEFAULT_SUBJECT_ID)
                 .build()?;
-            let created = app_state
-                .cp
-                .create_upstream(&ctx, u.request.clone())
-                .await
-                .map_err(|e| {
-                    anyhow::anyhow!("Failed to provision upstream (tenant={}): {e}", u.tenant_id)
-                })?;
+            let created = match app_state.cp.create_upstream(&ctx, u.request.clone()).await {
+                Ok(created) => created,
+                Err(DomainError::Conflict { detail }) if persistent => {
+                    if let Some(gts_id) = u.gts_instance_id {
+                        already_provisioned.insert(gts_id);
+                    }
+                    info!(
+                        tenant_id = %u.tenant_id,
+                        detail = %detail,
+                        "Upstream already provisioned in storage, skipping"
+                    );
+                    continue;
+                }
+                Err(e) => {
+                    return Err(anyhow::anyhow!(
+                        "Failed to provision upstream (tenant={}): {e}",
+                        u.tenant_id
+                    ));
+                }
+            };
             if let Some(gts_id) = u.gts_instance_id {
                 gts_to_oagw.insert(gts_id, created.id);
             }
@@ -236,6 +405,9 @@ impl SystemCapability for OutboundApiGatewayModule {
 
         let routes = provisioning.list_routes().await?;
         for r in &routes {
+            if already_provisioned.contains(&r.request.upstream_id) {
+                continue;
+            }
             let ctx = SecurityContext::builder()
                 .subject_tenant_id(r.tenant_id)
                 .subject_id(modkit_security::constants::DEFAULT_SUBJECT_ID)
@@ -269,6 +441,35 @@ impl SystemCapability for OutboundApiGatewayModule {
     }
 }
 
+#[async_trait]
+impl RunnableCapability for OutboundApiGatewayModule {
+    async fn start(&self, _cancel: CancellationToken) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Refuse new proxy requests and let in-flight ones finish, for up to
+    /// `shutdown_drain_timeout_secs` or until the runtime's hard-stop deadline.
+    async fn stop(&self, deadline_token: CancellationToken) -> anyhow::Result<()> {
+        let Some(state) = self.state.load_full() else {
+            return Ok(());
+        };
+        let timeout = self.drain_timeout.get().copied().unwrap_or_default();
+        info!(?timeout, "Draining in-flight OAGW proxy requests");
+
+        tokio::select! {
+            remaining = state.dp.drain(timeout) => {
+                if remaining > 0 {
+                    warn!(remaining, "OAGW drain timed out with proxy requests in flight");
+                }
+            }
+            () = deadline_token.cancelled() => {
+                warn!("Shutdown deadline reached while draining OAGW proxy requests");
+            }
+        }
+        Ok(())
+    }
+}
+
 impl RestApiCapability for OutboundApiGatewayModule {
     fn register_rest(
         &self,
@@ -21,6 +21,10 @@ impl<'a> ApiV1<'a> {
         RequestCase::new(self.harness, Method::POST, "/oagw/v1/upstreams")
     }
 
+    pub fn validate_upstream(&self) -> RequestCase<'a> {
+        RequestCase::new(self.harness, Method::POST, "/oagw/v1/upstreams/validate")
+    }
+
     pub fn get_upstream(&self, id: &str) -> RequestCase<'a> {
         RequestCase::new(
             self.harness,
@@ -55,6 +59,10 @@ impl<'a> ApiV1<'a> {
         RequestCase::new(self.harness, Method::POST, "/oagw/v1/routes")
     }
 
+    pub fn validate_route(&self) -> RequestCase<'a> {
+        RequestCase::new(self.harness, Method::POST, "/oagw/v1/routes/validate")
+    }
+
     pub fn get_route(&self, id: &str) -> RequestCase<'a> {
         RequestCase::new(self.harness, Method::GET, format!("/oagw/v1/routes/{id}"))
     }
@@ -79,6 +87,52 @@ impl<'a> ApiV1<'a> {
         )
     }
 
+    // -- Tenant settings --
+
+    pub fn get_tenant_rate_limit(&self) -> RequestCase<'a> {
+        RequestCase::new(self.harness, Method::GET, "/oagw/v1/tenant/rate-limit")
+    }
+
+    pub fn put_tenant_rate_limit(&self) -> RequestCase<'a> {
+        RequestCase::new(self.harness, Method::PUT, "/oagw/v1/tenant/rate-limit")
+    }
+
+    pub fn delete_tenant_rate_limit(&self) -> RequestCase<'a> {
+        RequestCase::new(self.harness, Method::DELETE, "/oagw/v1/tenant/rate-limit")
+    }
+
+    // -- Credentials --
+
+    pub fn list_credential_refs(&self) -> RequestCase<'a> {
+        RequestCase::new(self.harness, Method::GET, "/oagw/v1/credentials")
+    }
+
+    pub fn invalidate_credential(&self, secret_ref: &str) -> RequestCase<'a> {
+        RequestCase::new(
+            self.harness,
+            Method::POST,
+            format!("/oagw/v1/credentials/{secret_ref}/invalidate"),
+        )
+    }
+
+    // -- Health --
+
+    pub fn health(&self) -> RequestCase<'a> {
+        RequestCase::new(self.harness, Method::GET, "/oagw/v1/health")
+    }
+
+    pub fn ready(&self) -> RequestCase<'a> {
+        RequestCase::new(self.harness, Method::GET, "/oagw/v1/ready")
+    }
+
+    pub fn upstream_health(&self, alias: &str) -> RequestCase<'a> {
+        RequestCase::new(
+            self.harness,
+            Method::GET,
+            format!("/oagw/v1/health/upstreams/{alias}"),
+        )
+    }
+
     // -- Proxy --
 
     pub fn proxy(&self, method: Method, alias: &str, path: &str) -> RequestCase<'a> {
@@ -1,5 +1,7 @@
 //! Top-level test harness that wires all components together.
 
+use std::collections::HashMap;
+use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -10,14 +12,21 @@ use oagw_sdk::api::ServiceGatewayClientV1;
 use uuid::Uuid;
 
 use crate::api::rest::routes::test_router;
+use crate::config::ForwardProxyConfig;
+use crate::domain::metrics::Metrics;
+use crate::domain::services::DataPlaneService;
 
 use super::api_v1::ApiV1;
 use super::mock::shared_mock;
-use super::{TestCpBuilder, TestDpBuilder, build_test_app_state};
+use super::{
+    ReadinessCheck, TestCpBuilder, TestCredStoreClient, TestDpBuilder, build_test_app_state,
+};
 
 /// Fully-wired test environment for OAGW integration tests.
 pub struct AppHarness {
     facade: Arc<dyn ServiceGatewayClientV1>,
+    dp: Arc<dyn DataPlaneService>,
+    credstore: Arc<TestCredStoreClient>,
     ctx: SecurityContext,
     router: axum::Router,
 }
@@ -40,6 +49,11 @@ impl AppHarness {
         &*self.facade
     }
 
+    /// The mock credstore backing secret resolution (e.g. to rotate a secret).
+    pub fn credstore(&self) -> &TestCredStoreClient {
+        &self.credstore
+    }
+
     pub fn security_context(&self) -> &SecurityContext {
         &self.ctx
     }
@@ -47,6 +61,12 @@ impl AppHarness {
     pub fn router(&self) -> &axum::Router {
         &self.router
     }
+
+    /// Start shutdown draining of the data plane, as the module's `stop` does.
+    /// Returns the number of proxy requests still in flight after `timeout`.
+    pub async fn drain(&self, timeout: Duration) -> usize {
+        self.dp.drain(timeout).await
+    }
 }
 
 /// Builder for [`AppHarness`].
@@ -57,9 +77,15 @@ pub struct AppHarnessBuilder {
     authz_client: Option<Arc<dyn AuthZResolverClient>>,
     max_body_size: Option<usize>,
     skip_upstream_tls_verify: bool,
+    upstream_ca_file: Option<String>,
+    forward_proxy: Option<ForwardProxyConfig>,
+    resolve_overrides: HashMap<String, SocketAddr>,
     websocket_idle_timeout: Option<Duration>,
     websocket_close_timeout: Option<Duration>,
     websocket_max_frame_size: Option<usize>,
+    metrics: Option<Arc<dyn Metrics>>,
+    readiness_checks: Vec<Arc<dyn ReadinessCheck>>,
+    health_check_interval: Option<Duration>,
 }
 
 impl AppHarnessBuilder {
@@ -91,6 +117,24 @@ impl AppHarnessBuilder {
         self
     }
 
+    /// Trust the CA certificates in this PEM file when verifying HTTPS upstreams.
+    pub fn with_upstream_ca_file(mut self, path: impl Into<String>) -> Self {
+        self.upstream_ca_file = Some(path.into());
+        self
+    }
+
+    /// Tunnel upstream connections through a forward proxy.
+    pub fn with_forward_proxy(mut self, config: ForwardProxyConfig) -> Self {
+        self.forward_proxy = Some(config);
+        self
+    }
+
+    /// Resolve upstream hosts to fixed addresses instead of via DNS.
+    pub fn with_resolve_override(mut self, host: &str, addr: SocketAddr) -> Self {
+        self.resolve_overrides.insert(host.to_string(), addr);
+        self
+    }
+
     /// Override the WebSocket idle timeout (useful for idle-timeout tests).
     pub fn with_websocket_idle_timeout(mut self, timeout: Duration) -> Self {
         self.websocket_idle_timeout = Some(timeout);
@@ -109,13 +153,29 @@ impl AppHarnessBuilder {
         self
     }
 
+    /// Report data-plane metrics to `metrics` (e.g. a recording fake).
+    pub fn with_metrics(mut self, metrics: Arc<dyn Metrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Add a dependency probed by `GET /oagw/v1/ready`.
+    pub fn with_readiness_check(mut self, check: Arc<dyn ReadinessCheck>) -> Self {
+        self.readiness_checks.push(check);
+        self
+    }
+
+    /// Override the interval between endpoint health checks.
+    pub fn with_health_check_interval(mut self, interval: Duration) -> Self {
+        self.health_check_interval = Some(interval);
+        self
+    }
+
     pub async fn build(self) -> AppHarness {
         let hub = ClientHub::new();
 
-        let mut cp_builder = TestCpBuilder::new();
-        if !self.credentials.is_empty() {
-            cp_builder = cp_builder.with_credentials(self.credentials);
-        }
+        let credstore = Arc::new(TestCredStoreClient::with_secrets(self.credentials));
+        let cp_builder = TestCpBuilder::new().with_credstore(credstore.clone());
 
         let mut dp_builder = TestDpBuilder::new();
         if let Some(timeout) = self.request_timeout {
@@ -128,6 +188,15 @@ impl AppHarnessBuilder {
             dp_builder = dp_builder.with_max_body_size(size);
         }
         dp_builder = dp_builder.with_skip_upstream_tls_verify(self.skip_upstream_tls_verify);
+        if let Some(path) = self.upstream_ca_file {
+            dp_builder = dp_builder.with_upstream_ca_file(path);
+        }
+        if let Some(config) = self.forward_proxy {
+            dp_builder = dp_builder.with_forward_proxy(config);
+        }
+        if !self.resolve_overrides.is_empty() {
+            dp_builder = dp_builder.with_resolve_overrides(self.resolve_overrides);
+        }
         if let Some(timeout) = self.websocket_idle_timeout {
             dp_builder = dp_builder.with_websocket_idle_timeout(timeout);
         }
@@ -137,10 +206,17 @@ impl AppHarnessBuilder {
         if let Some(size) = self.websocket_max_frame_size {
             dp_builder = dp_builder.with_websocket_max_frame_size(Some(size));
         }
+        if let Some(metrics) = self.metrics {
+            dp_builder = dp_builder.with_metrics(metrics);
+        }
+        if let Some(interval) = self.health_check_interval {
+            dp_builder = dp_builder.with_health_check_interval(interval);
+        }
         dp_builder =
             dp_builder.with_token_http_config(modkit_http::HttpClientConfig::for_testing());
 
-        let app_state = build_test_app_state(&hub, cp_builder, dp_builder);
+        let mut app_state = build_test_app_state(&hub, cp_builder, dp_builder);
+        app_state.state.readiness_checks = self.readiness_checks;
 
         let ctx = SecurityContext::builder()
             .subject_tenant_id(Uuid::new_v4())
@@ -148,10 +224,13 @@ impl AppHarnessBuilder {
             .build()
             .expect("test security context");
 
+        let dp = app_state.state.dp.clone();
         let router = test_router(app_state.state, ctx.clone());
 
         AppHarness {
             facade: app_state.facade,
+            dp,
+            credstore,
             ctx,
             router,
         }
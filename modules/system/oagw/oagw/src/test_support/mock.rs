@@ -38,6 +38,9 @@ pub enum MockBody {
     Json(Value),
     Text(String),
     Sse(Vec<String>),
+    /// Streamed as separate chunks with `Transfer-Encoding: chunked` and no
+    /// `Content-Length`.
+    Chunked(Vec<String>),
     /// Body delivery is gated on a channel signal.
     /// When the sender fires, the inner body is delivered.
     /// When the sender is dropped without firing, the handler aborts the connection.
@@ -119,6 +122,19 @@ impl MockResponse {
                 }
                 builder.body(axum::body::Body::from(sse_body)).unwrap()
             }
+            MockBody::Chunked(chunks) => {
+                let mut builder = axum::response::Response::builder()
+                    .status(StatusCode::from_u16(self.status).unwrap_or(StatusCode::OK));
+                for (k, v) in &self.headers {
+                    builder = builder.header(k.as_str(), v.as_str());
+                }
+                let stream = futures_util::stream::iter(
+                    chunks
+                        .into_iter()
+                        .map(|chunk| Ok::<_, std::convert::Infallible>(bytes::Bytes::from(chunk))),
+                );
+                builder.body(axum::body::Body::from_stream(stream)).unwrap()
+            }
         }
     }
 }
@@ -0,0 +1,183 @@
+//! E2E tests for HTTPS upstreams with certificate verification enabled.
+//!
+//! Spins up a local HTTP/1.1 TLS server whose certificate is issued by a
+//! throwaway test CA for a hostname that only exists through a resolve
+//! override. OAGW trusts the CA via `upstream_ca_file`, so the tests cover
+//! TLS dialing, SNI taken from the endpoint host, and default verification.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use http_body_util::Full;
+use hyper::body::Incoming;
+use hyper::service::service_fn;
+use hyper::{Request, Response};
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use oagw::test_support::AppHarness;
+use rcgen::{BasicConstraints, CertificateParams, DnType, IsCa, KeyPair, KeyUsagePurpose};
+use rustls::ServerConfig;
+use rustls_pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer};
+use tokio::net::TcpListener;
+use tokio::sync::Mutex;
+use tokio_rustls::TlsAcceptor;
+
+const UPSTREAM_HOST: &str = "upstream.oagw.test";
+
+/// Test CA (PEM) plus a leaf certificate for [`UPSTREAM_HOST`] signed by it.
+struct TestPki {
+    ca_pem: String,
+    leaf_der: CertificateDer<'static>,
+    leaf_key: PrivateKeyDer<'static>,
+}
+
+fn issue_test_pki() -> TestPki {
+    let ca_key = KeyPair::generate().expect("CA key");
+    let mut ca_params = CertificateParams::new(Vec::<String>::new()).expect("CA params");
+    ca_params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+    ca_params
+        .distinguished_name
+        .push(DnType::CommonName, "OAGW test CA");
+    ca_params.key_usages = vec![KeyUsagePurpose::KeyCertSign, KeyUsagePurpose::CrlSign];
+    let ca_cert = ca_params.self_signed(&ca_key).expect("CA cert");
+
+    let leaf_key = KeyPair::generate().expect("leaf key");
+    let leaf_params = CertificateParams::new(vec![UPSTREAM_HOST.to_string()]).expect("leaf params");
+    let leaf_cert = leaf_params
+        .signed_by(&leaf_key, &ca_cert, &ca_key)
+        .expect("leaf cert");
+
+    TestPki {
+        ca_pem: ca_cert.pem(),
+        leaf_der: CertificateDer::from(leaf_cert.der().to_vec()),
+        leaf_key: PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(leaf_key.serialize_der())),
+    }
+}
+
+/// Start an HTTP/1.1 TLS server that answers every request with 200 and
+/// records the SNI sent by each client. Returns (addr, recorded SNIs).
+async fn start_tls_mock(pki: &TestPki) -> (SocketAddr, Arc<Mutex<Vec<Option<String>>>>) {
+    let mut tls_config = ServerConfig::builder()
+        .with_no_client_auth()
+        .with_single_cert(vec![pki.leaf_der.clone()], pki.leaf_key.clone_key())
+        .expect("TLS config");
+    tls_config.alpn_protocols = vec![b"http/1.1".to_vec()];
+    let tls_acceptor = TlsAcceptor::from(Arc::new(tls_config));
+
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("bind TLS mock");
+    let addr = listener.local_addr().expect("local addr");
+    let snis = Arc::new(Mutex::new(Vec::new()));
+
+    let snis_clone = snis.clone();
+    tokio::spawn(async move {
+        loop {
+            let Ok((tcp_stream, _)) = listener.accept().await else {
+                continue;
+            };
+            let tls_acceptor = tls_acceptor.clone();
+            let snis = snis_clone.clone();
+
+            tokio::spawn(async move {
+                // Handshake failures are expected when the client rejects the cert.
+                let Ok(tls_stream) = tls_acceptor.accept(tcp_stream).await else {
+                    return;
+                };
+                let sni = tls_stream.get_ref().1.server_name().map(str::to_string);
+                snis.lock().await.push(sni);
+
+                let service = service_fn(|req: Request<Incoming>| async move {
+                    let body = serde_json::json!({ "path": req.uri().path() });
+                    Ok::<_, hyper::Error>(Response::new(Full::new(Bytes::from(body.to_string()))))
+                });
+                let _ = hyper_util::server::conn::auto::Builder::new(TokioExecutor::new())
+                    .serve_connection(TokioIo::new(tls_stream), service)
+                    .await;
+            });
+        }
+    });
+
+    (addr, snis)
+}
+
+/// Registers an HTTPS upstream for [`UPSTREAM_HOST`] (alias auto-derived from
+/// the host, since 443 is the standard port) with a `GET /v1/models` route.
+async fn create_https_upstream(h: &AppHarness) {
+    let resp = h
+        .api_v1()
+        .post_upstream()
+        .with_body(serde_json::json!({
+            "server": {
+                "endpoints": [{ "host": UPSTREAM_HOST, "port": 443, "scheme": "https" }]
+            },
+            "protocol": "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            "enabled": true,
+            "tags": []
+        }))
+        .expect_status(201)
+        .await;
+    let upstream_id = resp.json()["id"].as_str().unwrap().to_string();
+
+    h.api_v1()
+        .post_route()
+        .with_body(serde_json::json!({
+            "upstream_id": &upstream_id,
+            "match": { "http": { "methods": ["GET"], "path": "/v1/models" } },
+            "enabled": true,
+            "tags": [],
+            "priority": 0
+        }))
+        .expect_status(201)
+        .await;
+}
+
+/// E2E: an HTTPS upstream whose certificate chains to a trusted CA is dialed
+/// over TLS with the endpoint host as SNI, with verification left enabled.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn e2e_https_upstream_verified_with_trusted_ca() {
+    let pki = issue_test_pki();
+    let (mock_addr, snis) = start_tls_mock(&pki).await;
+    let ca_file = tempfile::NamedTempFile::new().expect("CA temp file");
+    std::fs::write(ca_file.path(), &pki.ca_pem).expect("write CA");
+
+    let h = AppHarness::builder()
+        .with_upstream_ca_file(ca_file.path().to_string_lossy())
+        .with_resolve_override(UPSTREAM_HOST, mock_addr)
+        .build()
+        .await;
+    create_https_upstream(&h).await;
+
+    let resp = h
+        .api_v1()
+        .proxy_get(UPSTREAM_HOST, "v1/models")
+        .expect_status(200)
+        .await;
+    assert_eq!(resp.json()["path"], "/v1/models");
+
+    let snis = snis.lock().await;
+    assert!(!snis.is_empty(), "upstream saw no TLS connection");
+    assert!(
+        snis.iter().all(|sni| sni.as_deref() == Some(UPSTREAM_HOST)),
+        "unexpected SNI: {snis:?}"
+    );
+}
+
+/// E2E: certificates are verified by default, so an upstream signed by a CA
+/// the gateway does not trust is rejected instead of proxied.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn e2e_https_upstream_untrusted_cert_is_rejected() {
+    let pki = issue_test_pki();
+    let (mock_addr, _snis) = start_tls_mock(&pki).await;
+
+    let h = AppHarness::builder()
+        .with_resolve_override(UPSTREAM_HOST, mock_addr)
+        .build()
+        .await;
+    create_https_upstream(&h).await;
+
+    h.api_v1()
+        .proxy_get(UPSTREAM_HOST, "v1/models")
+        .expect_status(502)
+        .await;
+}
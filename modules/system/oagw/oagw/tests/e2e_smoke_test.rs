@@ -217,6 +217,10 @@ async fn e2e_nonexistent_alias_returns_404() {
         .await;
 
     resp.assert_header("x-oagw-error-source", "gateway");
+    let body = resp.json();
+    assert_eq!(body["error_source"], "gateway");
+    assert_eq!(body["code"], "NOT_FOUND");
+    assert!(body["message"].as_str().is_some_and(|m| !m.is_empty()));
 }
 
 #[tokio::test]
@@ -293,6 +297,10 @@ async fn e2e_upstream_500_passthrough() {
         .await;
 
     resp.assert_header("x-oagw-error-source", "upstream");
+    // The upstream's JSON error passes through with its source added (ADR 0013).
+    let body = resp.json();
+    assert_eq!(body["error"]["code"], "internal_error");
+    assert_eq!(body["error_source"], "upstream");
 }
 
 // 10.4: E2E — rate limit exceeded.
@@ -357,6 +365,94 @@ async fn e2e_rate_limit_returns_429() {
         .await;
 }
 
+// Upstream Retry-After is passed through; a local rate limit uses its own.
+#[tokio::test]
+async fn e2e_upstream_retry_after_passthrough() {
+    let mut guard = MockGuard::new();
+    guard.mock(
+        "GET",
+        "/busy",
+        MockResponse {
+            status: 429,
+            headers: vec![("retry-after".into(), "30".into())],
+            body: MockBody::Json(serde_json::json!({"error": "slow down"})),
+        },
+    );
+
+    let h = AppHarness::builder().build().await;
+
+    let resp = h
+        .api_v1()
+        .post_upstream()
+        .with_body(serde_json::json!({
+            "server": {
+                "endpoints": [{"host": "127.0.0.1", "port": h.mock_port(), "scheme": "http"}]
+            },
+            "protocol": "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            "alias": "e2e-busy",
+            "enabled": true,
+            "tags": [],
+            "rate_limit": {
+                "algorithm": "token_bucket",
+                "sustained": {"rate": 1, "window": "hour"},
+                "burst": {"capacity": 1},
+                "scope": "tenant",
+                "strategy": "reject",
+                "cost": 1
+            }
+        }))
+        .expect_status(201)
+        .await;
+    let uid = resp.json()["id"].as_str().unwrap().to_string();
+
+    let route_path = guard.path("/busy");
+    h.api_v1()
+        .post_route()
+        .with_body(serde_json::json!({
+            "upstream_id": &uid,
+            "match": {
+                "http": {
+                    "methods": ["GET"],
+                    "path": route_path
+                }
+            },
+            "enabled": true,
+            "tags": [],
+            "priority": 0
+        }))
+        .expect_status(201)
+        .await;
+
+    // The upstream's own 429 reaches the client with its Retry-After.
+    let resp = h
+        .api_v1()
+        .proxy_get("e2e-busy", route_path.trim_start_matches('/'))
+        .expect_status(429)
+        .await;
+    resp.assert_header("x-oagw-error-source", "upstream");
+    resp.assert_header("retry-after", "30");
+
+    // The local limit is now exhausted: the gateway answers with its own hint.
+    let resp = h
+        .api_v1()
+        .proxy_get("e2e-busy", route_path.trim_start_matches('/'))
+        .expect_status(429)
+        .await;
+    resp.assert_header("x-oagw-error-source", "gateway");
+    let retry_after: u64 = resp
+        .headers()
+        .get("retry-after")
+        .expect("local 429 carries Retry-After")
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(
+        retry_after > 30,
+        "expected the hourly bucket's wait, got {retry_after}"
+    );
+}
+
 // 10.5: E2E — management lifecycle.
 #[tokio::test]
 async fn e2e_management_lifecycle() {
@@ -497,6 +593,58 @@ async fn e2e_invalid_content_length_returns_400() {
         .await;
 }
 
+// 8.11: Signed, overflowing or inaccurate Content-Length returns 400.
+#[tokio::test]
+async fn e2e_malformed_content_length_returns_400() {
+    let h = AppHarness::builder().build().await;
+
+    let resp = h
+        .api_v1()
+        .post_upstream()
+        .with_body(serde_json::json!({
+            "server": {
+                "endpoints": [{"host": "127.0.0.1", "port": h.mock_port(), "scheme": "http"}]
+            },
+            "protocol": "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            "alias": "e2e-cl-malformed",
+            "enabled": true,
+            "tags": []
+        }))
+        .expect_status(201)
+        .await;
+    let uid = resp.json()["id"].as_str().unwrap().to_string();
+
+    h.api_v1()
+        .post_route()
+        .with_body(serde_json::json!({
+            "upstream_id": &uid,
+            "match": {
+                "http": {
+                    "methods": ["POST"],
+                    "path": "/v1/test"
+                }
+            },
+            "enabled": true,
+            "tags": [],
+            "priority": 0
+        }))
+        .expect_status(201)
+        .await;
+
+    // The JSON body below is 13 bytes, so "5" is well-formed but wrong.
+    for content_length in ["-1", "+5", "18446744073709551616", "5"] {
+        h.api_v1()
+            .proxy_post("e2e-cl-malformed", "v1/test")
+            .with_body(serde_json::json!({"test": true}))
+            .with_header(
+                http::header::CONTENT_LENGTH,
+                http::HeaderValue::from_static(content_length),
+            )
+            .expect_status(400)
+            .await;
+    }
+}
+
 // 8.11: Content-Length exceeding 100MB returns 413.
 #[tokio::test]
 async fn e2e_body_exceeding_limit_returns_413() {
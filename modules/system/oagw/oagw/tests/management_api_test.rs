@@ -1,4 +1,9 @@
-use oagw::test_support::{AppHarness, format_upstream_gts};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use oagw::test_support::{AppHarness, MockUpstream, ReadinessCheck, format_upstream_gts};
 use uuid::Uuid;
 
 // 7.8: POST upstream with valid body -> 201 + GTS id + alias generated.
@@ -26,6 +31,65 @@ async fn create_upstream_success() {
     assert_eq!(json["alias"].as_str().unwrap(), "api.openai.com");
 }
 
+// 7.8: POST with endpoints omitting `port` -> port inferred from scheme and
+// the standard port left out of the derived alias.
+#[tokio::test]
+async fn create_upstream_infers_port_from_scheme() {
+    let h = AppHarness::builder().build().await;
+
+    for (scheme, host, port) in [
+        ("http", "plain.example.com", 80),
+        ("https", "secure.example.com", 443),
+    ] {
+        let resp = h
+            .api_v1()
+            .post_upstream()
+            .with_body(serde_json::json!({
+                "server": { "endpoints": [{"host": host, "scheme": scheme}] },
+                "protocol": "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+                "enabled": true,
+                "tags": []
+            }))
+            .expect_status(201)
+            .await;
+
+        let json = resp.json();
+        assert_eq!(json["server"]["endpoints"][0]["port"], port);
+        assert_eq!(json["alias"].as_str().unwrap(), host);
+    }
+}
+
+// 7.8: repeating a POST with the same idempotency_key -> 200 with the
+// upstream created by the first request; no duplicate is stored.
+#[tokio::test]
+async fn create_upstream_with_same_idempotency_key_returns_existing() {
+    let h = AppHarness::builder().build().await;
+    let body = serde_json::json!({
+        "server": {
+            "endpoints": [{"host": "api.openai.com", "port": 443, "scheme": "https"}]
+        },
+        "protocol": "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+        "idempotency_key": "provision-openai-1"
+    });
+
+    let first = h
+        .api_v1()
+        .post_upstream()
+        .with_body(body.clone())
+        .expect_status(201)
+        .await;
+    let second = h
+        .api_v1()
+        .post_upstream()
+        .with_body(body)
+        .expect_status(200)
+        .await;
+    assert_eq!(second.json()["id"], first.json()["id"]);
+
+    let list = h.api_v1().list_upstreams().expect_status(200).await;
+    assert_eq!(list.json().as_array().unwrap().len(), 1);
+}
+
 // 7.8: POST with missing server -> 422 (serde deserialization error).
 #[tokio::test]
 async fn create_upstream_missing_server_returns_422() {
@@ -263,6 +327,180 @@ async fn list_upstreams_with_pagination() {
     assert_eq!(arr.len(), 2);
 }
 
+// Custom rate-limit windows round-trip; zero-length windows are rejected.
+#[tokio::test]
+async fn create_upstream_custom_rate_limit_window() {
+    let h = AppHarness::builder().build().await;
+    let body = |alias: &str, window: serde_json::Value| {
+        serde_json::json!({
+            "server": {
+                "endpoints": [{"host": "10.0.0.1", "port": 443, "scheme": "https"}]
+            },
+            "protocol": "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            "alias": alias,
+            "enabled": true,
+            "tags": [],
+            "rate_limit": {"sustained": {"rate": 5, "window": window}}
+        })
+    };
+
+    let resp = h
+        .api_v1()
+        .post_upstream()
+        .with_body(body("ten-seconds", serde_json::json!({"seconds": 10})))
+        .expect_status(201)
+        .await;
+    assert_eq!(
+        resp.json()["rate_limit"]["sustained"]["window"],
+        serde_json::json!({"seconds": 10})
+    );
+
+    h.api_v1()
+        .post_upstream()
+        .with_body(body("zero-seconds", serde_json::json!({"seconds": 0})))
+        .expect_status(400)
+        .await;
+}
+
+/// Fields of the issues in a dry-run validation 422, in report order.
+fn issue_fields(resp: &oagw::test_support::TestResponse) -> Vec<String> {
+    resp.json()["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["field"].as_str().unwrap().to_string())
+        .collect()
+}
+
+// Validating a valid upstream reports success and persists nothing.
+#[tokio::test]
+async fn validate_upstream_accepts_valid_payload() {
+    let h = AppHarness::builder().build().await;
+
+    let resp = h
+        .api_v1()
+        .validate_upstream()
+        .with_body(serde_json::json!({
+            "server": {
+                "endpoints": [{"host": "api.openai.com", "port": 443, "scheme": "https"}]
+            },
+            "protocol": "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            "enabled": true,
+            "tags": []
+        }))
+        .expect_status(200)
+        .await;
+    assert_eq!(resp.json()["valid"], true);
+
+    let resp = h.api_v1().list_upstreams().expect_status(200).await;
+    assert!(resp.json().as_array().unwrap().is_empty());
+}
+
+// Every problem with an upstream payload is reported in one 422.
+#[tokio::test]
+async fn validate_upstream_reports_all_issues() {
+    let h = AppHarness::builder().build().await;
+    h.api_v1()
+        .post_upstream()
+        .with_body(serde_json::json!({
+            "server": {
+                "endpoints": [{"host": "10.0.0.1", "port": 443, "scheme": "https"}]
+            },
+            "protocol": "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            "alias": "openai",
+            "enabled": true,
+            "tags": []
+        }))
+        .expect_status(201)
+        .await;
+
+    let resp = h
+        .api_v1()
+        .validate_upstream()
+        .with_body(serde_json::json!({
+            "server": {
+                "endpoints": [{"host": "10.0.0.2", "port": 443, "scheme": "https"}]
+            },
+            "protocol": "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            "alias": "openai",
+            "enabled": true,
+            "tags": [],
+            "rate_limit": {"sustained": {"rate": 5, "window": {"seconds": 0}}},
+            "plugins": {"items": [{"plugin_ref": "not-a-plugin"}]}
+        }))
+        .expect_status(422)
+        .await;
+
+    resp.assert_header("content-type", "application/problem+json");
+    assert_eq!(issue_fields(&resp), ["rate_limit", "plugins", "alias"]);
+    let errors = resp.json()["errors"].clone();
+    assert_eq!(errors[2]["code"], "CONFLICT");
+    assert!(
+        errors[1]["message"]
+            .as_str()
+            .unwrap()
+            .contains("not-a-plugin")
+    );
+
+    let resp = h.api_v1().list_upstreams().expect_status(200).await;
+    assert_eq!(resp.json().as_array().unwrap().len(), 1);
+}
+
+// Route validation checks the upstream reference, match rules and limits
+// together, and persists nothing.
+#[tokio::test]
+async fn validate_route_reports_all_issues() {
+    let h = AppHarness::builder().build().await;
+    let resp = h
+        .api_v1()
+        .post_upstream()
+        .with_body(serde_json::json!({
+            "server": {
+                "endpoints": [{"host": "10.0.0.1", "port": 443, "scheme": "https"}]
+            },
+            "protocol": "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            "alias": "openai",
+            "enabled": true,
+            "tags": []
+        }))
+        .expect_status(201)
+        .await;
+    let upstream_id = resp.json()["id"].as_str().unwrap().to_string();
+    let route = |upstream_id: &str| {
+        serde_json::json!({
+            "upstream_id": upstream_id,
+            "match": {"http": {"methods": ["POST"], "path": "/v1/chat/completions"}},
+            "enabled": true,
+            "tags": [],
+            "priority": 0
+        })
+    };
+
+    let resp = h
+        .api_v1()
+        .validate_route()
+        .with_body(route(&upstream_id))
+        .expect_status(200)
+        .await;
+    assert_eq!(resp.json()["valid"], true);
+    let resp = h.api_v1().list_routes(None).expect_status(200).await;
+    assert!(resp.json().as_array().unwrap().is_empty());
+
+    let mut invalid = route(&format_upstream_gts(Uuid::new_v4()));
+    invalid["match"] = serde_json::json!({});
+    invalid["rate_limit"] = serde_json::json!({
+        "sustained": {"rate": 5, "window": "minute"},
+        "cost": {"type": "header_value", "header": "bad header"}
+    });
+    let resp = h
+        .api_v1()
+        .validate_route()
+        .with_body(invalid)
+        .expect_status(422)
+        .await;
+    assert_eq!(issue_fields(&resp), ["rate_limit", "upstream_id", "match"]);
+}
+
 // 7.13: Error mapper produces correct Problem Details.
 #[tokio::test]
 async fn error_mapper_produces_problem_details() {
@@ -510,3 +748,115 @@ async fn list_routes_filters_by_upstream() {
         assert_eq!(route["upstream_id"].as_str().unwrap(), upstream_gts_ids[0]);
     }
 }
+
+// Liveness does not depend on anything.
+#[tokio::test]
+async fn health_returns_200() {
+    let h = AppHarness::builder().build().await;
+    let resp = h.api_v1().health().expect_status(200).await;
+    assert_eq!(resp.json()["status"], "healthy");
+}
+
+/// Dependency whose reachability the test toggles.
+struct ToggleDependency(AtomicBool);
+
+#[async_trait]
+impl ReadinessCheck for ToggleDependency {
+    fn name(&self) -> &str {
+        "redis"
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        if self.0.load(Ordering::SeqCst) {
+            Ok(())
+        } else {
+            Err("connection refused".into())
+        }
+    }
+}
+
+// Readiness follows the configured dependencies: 503 while one is down.
+#[tokio::test]
+async fn ready_reflects_dependency_reachability() {
+    let redis = Arc::new(ToggleDependency(AtomicBool::new(true)));
+    let h = AppHarness::builder()
+        .with_readiness_check(redis.clone())
+        .build()
+        .await;
+
+    let resp = h.api_v1().ready().expect_status(200).await;
+    assert_eq!(resp.json()["status"], "ready");
+
+    redis.0.store(false, Ordering::SeqCst);
+    let resp = h.api_v1().ready().expect_status(503).await;
+    let detail = resp.json()["detail"].as_str().unwrap().to_string();
+    assert!(detail.contains("redis"), "got: {detail}");
+    assert!(!detail.contains("connection refused"), "got: {detail}");
+
+    redis.0.store(true, Ordering::SeqCst);
+    h.api_v1().ready().expect_status(200).await;
+}
+
+/// Poll the health of `alias`'s only endpoint until it reports `status`.
+async fn wait_for_endpoint_status(h: &AppHarness, alias: &str, status: &str) -> serde_json::Value {
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+    loop {
+        let resp = h.api_v1().upstream_health(alias).expect_status(200).await;
+        let endpoint = resp.json()[0].clone();
+        if endpoint["status"] == status {
+            return endpoint;
+        }
+        assert!(
+            tokio::time::Instant::now() < deadline,
+            "endpoint never became {status}: {endpoint}"
+        );
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+}
+
+// Endpoint health follows a flapping upstream through the health API.
+#[tokio::test]
+async fn upstream_health_reflects_flapping_endpoint() {
+    let h = AppHarness::builder()
+        .with_health_check_interval(Duration::from_millis(100))
+        .build()
+        .await;
+    let mock = MockUpstream::start().await;
+    let addr = mock.addr();
+    h.api_v1()
+        .post_upstream()
+        .with_body(serde_json::json!({
+            "server": {
+                "endpoints": [{"host": "127.0.0.1", "port": addr.port(), "scheme": "http"}]
+            },
+            "protocol": "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            "alias": "flappy",
+            "enabled": true,
+            "tags": []
+        }))
+        .expect_status(201)
+        .await;
+
+    let endpoint = wait_for_endpoint_status(&h, "flappy", "healthy").await;
+    assert_eq!(endpoint["endpoint"]["port"], addr.port());
+    assert_eq!(endpoint["consecutive_failures"], 0);
+    assert!(endpoint["last_checked_at"].is_string());
+
+    mock.stop().await;
+    let endpoint = wait_for_endpoint_status(&h, "flappy", "unhealthy").await;
+    assert!(endpoint["consecutive_failures"].as_u64().unwrap() >= 1);
+
+    let _mock = MockUpstream::start_on(&addr.to_string()).await;
+    let endpoint = wait_for_endpoint_status(&h, "flappy", "healthy").await;
+    assert_eq!(endpoint["consecutive_failures"], 0);
+}
+
+// Health of an alias that resolves to no upstream is a 404.
+#[tokio::test]
+async fn upstream_health_unknown_alias_returns_404() {
+    let h = AppHarness::builder().build().await;
+    h.api_v1()
+        .upstream_health("nonexistent")
+        .expect_status(404)
+        .await;
+}
@@ -1,18 +1,28 @@
 use std::collections::HashMap;
+use std::sync::Arc;
 
+use futures_util::StreamExt as _;
 use http::{Method, StatusCode};
+use oagw::metrics::{
+    LABEL_STATUS_CLASS, LABEL_UPSTREAM, Metrics, PROXY_RATE_LIMITED_TOTAL,
+    PROXY_REQUEST_BODY_BYTES_TOTAL, PROXY_REQUEST_DURATION_SECONDS, PROXY_REQUESTS_TOTAL,
+    PROXY_RESPONSE_BODY_BYTES_TOTAL, PROXY_RESPONSES_TOTAL,
+};
 use oagw::test_support::{
-    APIKEY_AUTH_PLUGIN_ID, AppHarness, MockBody, MockGuard, MockResponse, MockUpstream,
-    OAUTH2_CLIENT_CRED_AUTH_PLUGIN_ID,
+    APIKEY_AUTH_PLUGIN_ID, AppHarness, KEY_POOL_AUTH_PLUGIN_ID, MockBody, MockGuard, MockResponse,
+    MockUpstream, OAUTH2_CLIENT_CRED_AUTH_PLUGIN_ID, shared_mock,
 };
 use oagw_sdk::Body;
 use oagw_sdk::api::ErrorSource;
+use oagw_sdk::sse::{ServerEvent, ServerEventsResponse, ServerEventsStream};
+use oagw_sdk::ws::{WebSocketCloseFrame, WebSocketMessage, WebSocketUpgrade};
 use oagw_sdk::{
-    BurstConfig, CorsConfig, CorsHttpMethod, CreateRouteRequest, CreateUpstreamRequest, Endpoint,
-    HeadersConfig, HttpMatch, HttpMethod, MatchRules, PassthroughMode, PathSuffixMode,
-    PluginBinding, PluginsConfig, RateLimitAlgorithm, RateLimitConfig, RateLimitScope,
-    RateLimitStrategy, RequestHeaderRules, ResponseHeaderRules, Scheme, Server, SharingMode,
-    SustainedRate, Window,
+    BurstConfig, CorsConfig, CorsHttpMethod, CostStrategy, CreateRouteRequest,
+    CreateUpstreamRequest, Endpoint, GetCoalescingConfig, HeadersConfig, HttpMatch, HttpMethod,
+    MatchRules, PassthroughMode, PathSuffixMode, PluginBinding, PluginsConfig, RateLimitAlgorithm,
+    RateLimitConfig, RateLimitScope, RateLimitStrategy, RequestCompressionConfig,
+    RequestHeaderRules, ResponseCacheConfig, ResponseHeaderRules, Scheme, Server, SharingMode,
+    SseReconnectConfig, SustainedRate, UpdateRouteRequest, Window,
 };
 use serde_json::json;
 
@@ -201,27 +211,25 @@ async fn proxy_injects_auth_header() {
     assert_eq!(auth_header, "Bearer sk-test123");
 }
 
-// 6.14: SSE streaming — proxy to dynamic SSE mock via MockGuard.
+// Credential rotation: a rotated secret is served from cache until it is
+// invalidated through the management API, then re-fetched.
 #[tokio::test]
-async fn proxy_sse_streaming() {
+async fn proxy_picks_up_rotated_secret_after_invalidate() {
     let mut guard = MockGuard::new();
-
-    let chunks: Vec<String> = vec![
-        json!({"id":"chatcmpl-mock-stream","object":"chat.completion.chunk","choices":[{"index":0,"delta":{"role":"assistant","content":"Hello"},"finish_reason":null}]}).to_string(),
-        json!({"id":"chatcmpl-mock-stream","object":"chat.completion.chunk","choices":[{"index":0,"delta":{},"finish_reason":"stop"}]}).to_string(),
-        "[DONE]".to_string(),
-    ];
     guard.mock(
-        "POST",
-        "/v1/chat/completions/stream",
+        "GET",
+        "/v1/models",
         MockResponse {
             status: 200,
-            headers: vec![("content-type".into(), "text/event-stream".into())],
-            body: MockBody::Sse(chunks),
+            headers: vec![("content-type".into(), "application/json".into())],
+            body: MockBody::Json(json!({"data": []})),
         },
     );
 
-    let h = AppHarness::builder().build().await;
+    let h = AppHarness::builder()
+        .with_credentials(vec![("cred://rotating-key".into(), "sk-old".into())])
+        .build()
+        .await;
     let ctx = h.security_context().clone();
 
     let upstream = h
@@ -238,7 +246,20 @@ async fn proxy_sse_streaming() {
                 },
                 "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
             )
-            .alias("sse-test")
+            .alias("rotating-auth")
+            .auth(oagw_sdk::AuthConfig {
+                plugin_type: APIKEY_AUTH_PLUGIN_ID.into(),
+                sharing: SharingMode::Private,
+                config: Some(
+                    [
+                        ("header".into(), "authorization".into()),
+                        ("prefix".into(), "Bearer ".into()),
+                        ("secret_ref".into(), "cred://rotating-key".into()),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ),
+            })
             .build(),
         )
         .await
@@ -251,8 +272,8 @@ async fn proxy_sse_streaming() {
                 upstream.id,
                 MatchRules {
                     http: Some(HttpMatch {
-                        methods: vec![HttpMethod::Post],
-                        path: guard.path("/v1/chat/completions/stream"),
+                        methods: vec![HttpMethod::Get],
+                        path: guard.path("/v1/models"),
                         query_allowlist: vec![],
                         path_suffix_mode: PathSuffixMode::Disabled,
                     }),
@@ -264,121 +285,60 @@ async fn proxy_sse_streaming() {
         .await
         .unwrap();
 
-    let req = http::Request::builder()
-        .method(Method::POST)
-        .uri(format!(
-            "/sse-test{}",
-            guard.path("/v1/chat/completions/stream")
-        ))
-        .header(http::header::CONTENT_TYPE, "application/json")
-        .body(Body::from(r#"{"model":"gpt-4","stream":true}"#))
-        .unwrap();
-    let response = h.facade().proxy_request(ctx, req).await.unwrap();
-
-    let ct = response
-        .headers()
-        .get("content-type")
-        .unwrap()
-        .to_str()
-        .unwrap();
-    assert!(ct.contains("text/event-stream"), "got content-type: {ct}");
-
-    let body_bytes = response.into_body().into_bytes().await.unwrap();
-    let body_str = String::from_utf8(body_bytes.to_vec()).unwrap();
-    assert!(body_str.contains("data: [DONE]"));
-}
-
-// 6.15: Upstream 500 error passthrough.
-#[tokio::test]
-async fn proxy_upstream_500_passthrough() {
-    let h = setup_openai_mock().await;
-
-    let req = http::Request::builder()
-        .method(Method::GET)
-        .uri("/mock-upstream/error/500")
-        .body(Body::Empty)
-        .unwrap();
-    let response = h
-        .facade()
-        .proxy_request(h.security_context().clone(), req)
-        .await
-        .unwrap();
+    let send = || async {
+        let req = http::Request::builder()
+            .method(Method::GET)
+            .uri(format!("/rotating-auth{}", guard.path("/v1/models")))
+            .body(Body::Empty)
+            .unwrap();
+        let response = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    };
+    let last_auth = || async {
+        let recorded = guard.recorded_requests().await;
+        recorded
+            .last()
+            .and_then(|r| r.headers.iter().find(|(k, _)| k == "authorization"))
+            .map(|(_, v)| v.clone())
+            .expect("authorization header missing")
+    };
 
-    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
-    assert_eq!(
-        response.extensions().get::<ErrorSource>().copied(),
-        Some(ErrorSource::Upstream)
-    );
-}
+    send().await;
+    assert_eq!(last_auth().await, "Bearer sk-old");
 
-// 6.17: Pipeline abort — nonexistent alias returns 404 without calling mock.
-#[tokio::test]
-async fn proxy_nonexistent_alias_returns_404() {
-    let h = setup_openai_mock().await;
+    // Rotated in the store, but still cached.
+    h.credstore().set_secret("rotating-key", "sk-new");
+    send().await;
+    assert_eq!(last_auth().await, "Bearer sk-old");
 
-    let req = http::Request::builder()
-        .method(Method::GET)
-        .uri("/nonexistent/v1/test")
-        .body(Body::Empty)
-        .unwrap();
-    match h
-        .facade()
-        .proxy_request(h.security_context().clone(), req)
-        .await
-    {
-        Err(err) => assert!(matches!(
-            err,
-            oagw_sdk::error::ServiceGatewayError::NotFound { .. }
-        )),
-        Ok(_) => panic!("expected error"),
-    }
+    h.api_v1()
+        .invalidate_credential("rotating-key")
+        .expect_status(204)
+        .await;
+    send().await;
+    assert_eq!(last_auth().await, "Bearer sk-new");
 }
 
-// 6.17: Pipeline abort — disabled upstream returns 503.
+// Credential audit: the management API lists the secret refs configured on
+// the tenant's upstreams, whether or not they were resolved yet, without
+// exposing their values.
 #[tokio::test]
-async fn proxy_disabled_upstream_returns_503() {
-    let h = setup_openai_mock().await;
-    let ctx = h.security_context().clone();
-
-    let _upstream = h
-        .facade()
-        .create_upstream(
-            ctx.clone(),
-            CreateUpstreamRequest::builder(
-                Server {
-                    endpoints: vec![Endpoint {
-                        scheme: Scheme::Http,
-                        host: "127.0.0.1".into(),
-                        port: 9999,
-                    }],
-                },
-                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
-            )
-            .alias("disabled-upstream")
-            .enabled(false)
-            .build(),
-        )
-        .await
-        .unwrap();
-
-    let req = http::Request::builder()
-        .method(Method::GET)
-        .uri("/disabled-upstream/test")
-        .body(Body::Empty)
-        .unwrap();
-    match h.facade().proxy_request(ctx.clone(), req).await {
-        Err(err) => assert!(matches!(
-            err,
-            oagw_sdk::error::ServiceGatewayError::UpstreamDisabled { .. }
-        )),
-        Ok(_) => panic!("expected error"),
-    }
-}
+async fn list_credential_refs_omits_secret_values() {
+    let mut guard = MockGuard::new();
+    guard.mock(
+        "GET",
+        "/v1/models",
+        MockResponse {
+            status: 200,
+            headers: vec![("content-type".into(), "application/json".into())],
+            body: MockBody::Json(json!({"data": []})),
+        },
+    );
 
-// 6.17: Pipeline abort — rate limit exceeded returns 429.
-#[tokio::test]
-async fn proxy_rate_limit_exceeded_returns_429() {
-    let h = AppHarness::builder().build().await;
+    let h = AppHarness::builder()
+        .with_credentials(vec![("cred://audited-key".into(), "sk-top-secret".into())])
+        .build()
+        .await;
     let ctx = h.security_context().clone();
 
     let upstream = h
@@ -395,18 +355,19 @@ async fn proxy_rate_limit_exceeded_returns_429() {
                 },
                 "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
             )
-            .alias("rate-limited")
-            .rate_limit(RateLimitConfig {
+            .alias("audited-auth")
+            .auth(oagw_sdk::AuthConfig {
+                plugin_type: APIKEY_AUTH_PLUGIN_ID.into(),
                 sharing: SharingMode::Private,
-                algorithm: RateLimitAlgorithm::TokenBucket,
-                sustained: SustainedRate {
-                    rate: 1,
-                    window: Window::Minute,
-                },
-                burst: Some(BurstConfig { capacity: 1 }),
-                scope: RateLimitScope::Tenant,
-                strategy: RateLimitStrategy::Reject,
-                cost: 1,
+                config: Some(
+                    [
+                        ("header".into(), "authorization".into()),
+                        ("prefix".into(), "Bearer ".into()),
+                        ("secret_ref".into(), "cred://audited-key".into()),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ),
             })
             .build(),
         )
@@ -421,9 +382,9 @@ async fn proxy_rate_limit_exceeded_returns_429() {
                 MatchRules {
                     http: Some(HttpMatch {
                         methods: vec![HttpMethod::Get],
-                        path: "/v1/models".into(),
+                        path: guard.path("/v1/models"),
                         query_allowlist: vec![],
-                        path_suffix_mode: PathSuffixMode::Append,
+                        path_suffix_mode: PathSuffixMode::Disabled,
                     }),
                     grpc: None,
                 },
@@ -433,51 +394,52 @@ async fn proxy_rate_limit_exceeded_returns_429() {
         .await
         .unwrap();
 
-    // First request should succeed.
+    let resp = h.api_v1().list_credential_refs().expect_status(200).await;
+    assert_eq!(resp.json(), json!({"secret_refs": ["audited-key"]}));
+
     let req = http::Request::builder()
         .method(Method::GET)
-        .uri("/rate-limited/v1/models")
+        .uri(format!("/audited-auth{}", guard.path("/v1/models")))
         .body(Body::Empty)
         .unwrap();
-    let response = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
+    let response = h.facade().proxy_request(ctx, req).await.unwrap();
     assert_eq!(response.status(), StatusCode::OK);
 
-    // Second request should be rate limited.
-    let req = http::Request::builder()
-        .method(Method::GET)
-        .uri("/rate-limited/v1/models")
-        .body(Body::Empty)
-        .unwrap();
-    match h.facade().proxy_request(ctx.clone(), req).await {
-        Err(err) => assert!(matches!(
-            err,
-            oagw_sdk::error::ServiceGatewayError::RateLimitExceeded { .. }
-        )),
-        Ok(_) => panic!("expected rate limit error"),
-    }
+    let resp = h.api_v1().list_credential_refs().expect_status(200).await;
+    assert_eq!(resp.json(), json!({"secret_refs": ["audited-key"]}));
+    assert!(!resp.text().contains("sk-top-secret"));
 }
 
-// 6.16: Upstream timeout — proxy to gated mock that never responds, assert 504.
-// Uses multi_thread runtime so the timer driver runs on a dedicated thread,
-// preventing stalls when other test binaries compete for CPU.
-#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn proxy_upstream_timeout_returns_504() {
+#[tokio::test]
+async fn invalidate_credential_rejects_malformed_ref() {
+    let h = AppHarness::builder().build().await;
+    h.api_v1()
+        .invalidate_credential("bad:ref")
+        .expect_status(400)
+        .await;
+}
+
+// 6.14: SSE streaming — proxy to dynamic SSE mock via MockGuard.
+#[tokio::test]
+async fn proxy_sse_streaming() {
     let mut guard = MockGuard::new();
-    // Register a gated route that will never respond (sender kept alive but not signaled).
-    let _gate = guard.mock_gated(
-        "GET",
-        "/timeout",
+
+    let chunks: Vec<String> = vec![
+        json!({"id":"chatcmpl-mock-stream","object":"chat.completion.chunk","choices":[{"index":0,"delta":{"role":"assistant","content":"Hello"},"finish_reason":null}]}).to_string(),
+        json!({"id":"chatcmpl-mock-stream","object":"chat.completion.chunk","choices":[{"index":0,"delta":{},"finish_reason":"stop"}]}).to_string(),
+        "[DONE]".to_string(),
+    ];
+    guard.mock(
+        "POST",
+        "/v1/chat/completions/stream",
         MockResponse {
             status: 200,
-            headers: vec![],
-            body: MockBody::Json(json!({"ok": true})),
+            headers: vec![("content-type".into(), "text/event-stream".into())],
+            body: MockBody::Sse(chunks),
         },
     );
 
-    let h = AppHarness::builder()
-        .with_request_timeout(std::time::Duration::from_millis(500))
-        .build()
-        .await;
+    let h = AppHarness::builder().build().await;
     let ctx = h.security_context().clone();
 
     let upstream = h
@@ -494,7 +456,7 @@ async fn proxy_upstream_timeout_returns_504() {
                 },
                 "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
             )
-            .alias("timeout-upstream")
+            .alias("sse-test")
             .build(),
         )
         .await
@@ -507,8 +469,8 @@ async fn proxy_upstream_timeout_returns_504() {
                 upstream.id,
                 MatchRules {
                     http: Some(HttpMatch {
-                        methods: vec![HttpMethod::Get],
-                        path: guard.path("/timeout"),
+                        methods: vec![HttpMethod::Post],
+                        path: guard.path("/v1/chat/completions/stream"),
                         query_allowlist: vec![],
                         path_suffix_mode: PathSuffixMode::Disabled,
                     }),
@@ -521,22 +483,2400 @@ async fn proxy_upstream_timeout_returns_504() {
         .unwrap();
 
     let req = http::Request::builder()
-        .method(Method::GET)
-        .uri(format!("/timeout-upstream{}", guard.path("/timeout")))
-        .body(Body::Empty)
+        .method(Method::POST)
+        .uri(format!(
+            "/sse-test{}",
+            guard.path("/v1/chat/completions/stream")
+        ))
+        .header(http::header::CONTENT_TYPE, "application/json")
+        .body(Body::from(r#"{"model":"gpt-4","stream":true}"#))
+        .unwrap();
+    let response = h.facade().proxy_request(ctx, req).await.unwrap();
+
+    let ct = response
+        .headers()
+        .get("content-type")
+        .unwrap()
+        .to_str()
+        .unwrap();
+    assert!(ct.contains("text/event-stream"), "got content-type: {ct}");
+
+    let body_bytes = response.into_body().into_bytes().await.unwrap();
+    let body_str = String::from_utf8(body_bytes.to_vec()).unwrap();
+    assert!(body_str.contains("data: [DONE]"));
+}
+
+// Named SSE events (Anthropic style) pass through with `event:` lines intact,
+// both on plain routes and on routes that buffer events for resumption.
+#[tokio::test]
+async fn proxy_sse_named_events_round_trip() {
+    const WIRE: &str = concat!(
+        "event: message_start\n",
+        "data: {\"type\":\"message_start\"}\n\n",
+        "event: content_block_delta\n",
+        "data: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"text_delta\",\"text\":\"Hi\"}}\n\n",
+        ": keep-alive comment\n\n",
+        "event: message_stop\n",
+        "data: {\"type\":\"message_stop\"}\n\n",
+    );
+    // Split inside an `event:` line and between an event's lines.
+    let chunks = vec![
+        WIRE[..10].to_string(),
+        WIRE[10..70].to_string(),
+        WIRE[70..].to_string(),
+    ];
+
+    let mut guard = MockGuard::new();
+    guard.mock(
+        "GET",
+        "/v1/messages",
+        MockResponse {
+            status: 200,
+            headers: vec![("content-type".into(), "text/event-stream".into())],
+            body: MockBody::Chunked(chunks),
+        },
+    );
+
+    let h = AppHarness::builder().build().await;
+    let ctx = h.security_context().clone();
+
+    for (alias, sse_reconnect) in [
+        ("sse-named", None),
+        (
+            "sse-named-resumable",
+            Some(SseReconnectConfig { max_retries: 1 }),
+        ),
+    ] {
+        let upstream = h
+            .facade()
+            .create_upstream(
+                ctx.clone(),
+                CreateUpstreamRequest::builder(
+                    Server {
+                        endpoints: vec![Endpoint {
+                            scheme: Scheme::Http,
+                            host: "127.0.0.1".into(),
+                            port: h.mock_port(),
+                        }],
+                    },
+                    "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+                )
+                .alias(alias)
+                .build(),
+            )
+            .await
+            .unwrap();
+
+        let mut route = CreateRouteRequest::builder(
+            upstream.id,
+            MatchRules {
+                http: Some(HttpMatch {
+                    methods: vec![HttpMethod::Get],
+                    path: guard.path("/v1/messages"),
+                    query_allowlist: vec![],
+                    path_suffix_mode: PathSuffixMode::Disabled,
+                }),
+                grpc: None,
+            },
+        );
+        if let Some(config) = sse_reconnect {
+            route = route.sse_reconnect(config);
+        }
+        h.facade()
+            .create_route(ctx.clone(), route.build())
+            .await
+            .unwrap();
+
+        let req = http::Request::builder()
+            .method(Method::GET)
+            .uri(format!("/{alias}{}", guard.path("/v1/messages")))
+            .body(Body::Empty)
+            .unwrap();
+        let response = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
+        let ServerEventsResponse::Events(events) =
+            ServerEventsStream::from_response::<ServerEvent>(response)
+        else {
+            panic!("{alias}: expected an event stream");
+        };
+        let events: Vec<ServerEvent> = events.map(Result::unwrap).collect().await;
+        let names: Vec<_> = events.iter().map(|e| e.event.as_deref()).collect();
+        assert_eq!(
+            names,
+            [
+                Some("message_start"),
+                Some("content_block_delta"),
+                Some("message_stop")
+            ],
+            "{alias}"
+        );
+        assert_eq!(
+            events[1].json::<serde_json::Value>().unwrap()["delta"]["text"],
+            "Hi"
+        );
+    }
+
+    // The raw bytes are forwarded unchanged, comment line included.
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri(format!("/sse-named{}", guard.path("/v1/messages")))
+        .body(Body::Empty)
+        .unwrap();
+    let response = h.facade().proxy_request(ctx, req).await.unwrap();
+    let body = response.into_body().into_bytes().await.unwrap();
+    assert_eq!(std::str::from_utf8(&body).unwrap(), WIRE);
+}
+
+/// Start an SSE upstream that drops the first stream mid-event and resumes
+/// from `Last-Event-ID` on the next connection. Returns the port and the
+/// `Last-Event-ID` header (if any) of every request received.
+async fn start_flaky_sse_upstream() -> (u16, Arc<tokio::sync::Mutex<Vec<Option<String>>>>) {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let seen = Arc::new(tokio::sync::Mutex::new(Vec::new()));
+
+    let seen_clone = seen.clone();
+    tokio::spawn(async move {
+        loop {
+            let Ok((mut socket, _)) = listener.accept().await else {
+                continue;
+            };
+            let mut head = Vec::new();
+            let mut byte = [0u8; 1];
+            while !head.ends_with(b"\r\n\r\n") {
+                if socket.read(&mut byte).await.unwrap_or(0) == 0 {
+                    break;
+                }
+                head.push(byte[0]);
+            }
+            let head = String::from_utf8_lossy(&head).to_lowercase();
+            let last_event_id = head
+                .lines()
+                .find_map(|l| l.strip_prefix("last-event-id:"))
+                .map(|v| v.trim().to_string());
+            let first = seen_clone.lock().await.is_empty();
+            seen_clone.lock().await.push(last_event_id);
+
+            let chunk = |data: &str| format!("{:x}\r\n{data}\r\n", data.len());
+            let mut resp = String::from(
+                "HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\n\
+                 transfer-encoding: chunked\r\n\r\n",
+            );
+            if first {
+                // One full event, then half of the next before the drop.
+                resp += &chunk("id: 1\ndata: one\n\n");
+                resp += &chunk("id: 2\ndata: tw");
+            } else {
+                resp += &chunk("id: 2\ndata: two\n\n");
+                resp += &chunk("id: 3\ndata: three\n\n");
+                resp += "0\r\n\r\n";
+            }
+            let _ = socket.write_all(resp.as_bytes()).await;
+            let _ = socket.flush().await;
+            drop(socket);
+        }
+    });
+
+    (port, seen)
+}
+
+// SSE reconnection: a dropped upstream stream is resumed from the last event id
+// and the client receives every event exactly once.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn proxy_sse_reconnects_with_last_event_id() {
+    let (port, seen) = start_flaky_sse_upstream().await;
+    let h = AppHarness::builder().build().await;
+    let ctx = h.security_context().clone();
+
+    let upstream = h
+        .facade()
+        .create_upstream(
+            ctx.clone(),
+            CreateUpstreamRequest::builder(
+                Server {
+                    endpoints: vec![Endpoint {
+                        scheme: Scheme::Http,
+                        host: "127.0.0.1".into(),
+                        port,
+                    }],
+                },
+                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            )
+            .alias("sse-resume")
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    h.facade()
+        .create_route(
+            ctx.clone(),
+            CreateRouteRequest::builder(
+                upstream.id,
+                MatchRules {
+                    http: Some(HttpMatch {
+                        methods: vec![HttpMethod::Get],
+                        path: "/events".into(),
+                        query_allowlist: vec![],
+                        path_suffix_mode: PathSuffixMode::Disabled,
+                    }),
+                    grpc: None,
+                },
+            )
+            .sse_reconnect(SseReconnectConfig { max_retries: 2 })
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri("/sse-resume/events")
+        .body(Body::Empty)
+        .unwrap();
+    let response = h.facade().proxy_request(ctx, req).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_bytes = response.into_body().into_bytes().await.unwrap();
+    assert_eq!(
+        String::from_utf8(body_bytes.to_vec()).unwrap(),
+        "id: 1\ndata: one\n\nid: 2\ndata: two\n\nid: 3\ndata: three\n\n"
+    );
+    assert_eq!(*seen.lock().await, [None, Some("1".to_string())]);
+}
+
+// 6.15: Upstream 500 error passthrough.
+#[tokio::test]
+async fn proxy_upstream_500_passthrough() {
+    let h = setup_openai_mock().await;
+
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri("/mock-upstream/error/500")
+        .body(Body::Empty)
+        .unwrap();
+    let response = h
+        .facade()
+        .proxy_request(h.security_context().clone(), req)
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(
+        response.extensions().get::<ErrorSource>().cloned(),
+        Some(ErrorSource::Upstream)
+    );
+}
+
+// Chunked upstream response without Content-Length is streamed through.
+#[tokio::test]
+async fn proxy_chunked_upstream_response_streams_through() {
+    let mut guard = MockGuard::new();
+    guard.mock(
+        "GET",
+        "/chunked",
+        MockResponse {
+            status: 200,
+            headers: vec![("content-type".into(), "text/plain".into())],
+            body: MockBody::Chunked(vec!["alpha,".into(), "beta,".into(), "gamma".into()]),
+        },
+    );
+
+    let h = AppHarness::builder().build().await;
+    let ctx = h.security_context().clone();
+    create_mock_route(&h, &guard, "chunked-upstream", "/chunked").await;
+
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri(format!("/chunked-upstream{}", guard.path("/chunked")))
+        .body(Body::Empty)
+        .unwrap();
+    let response = h.facade().proxy_request(ctx, req).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().get("content-length").is_none());
+    assert!(response.headers().get("transfer-encoding").is_none());
+    assert!(response.headers().get("connection").is_none());
+    assert!(
+        matches!(response.body(), Body::Stream(_)),
+        "chunked body must be streamed, not buffered"
+    );
+
+    let body_bytes = response.into_body().into_bytes().await.unwrap();
+    assert_eq!(&body_bytes[..], b"alpha,beta,gamma");
+}
+
+// 6.17: Pipeline abort — nonexistent alias returns 404 without calling mock.
+#[tokio::test]
+async fn proxy_nonexistent_alias_returns_404() {
+    let h = setup_openai_mock().await;
+
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri("/nonexistent/v1/test")
+        .body(Body::Empty)
+        .unwrap();
+    match h
+        .facade()
+        .proxy_request(h.security_context().clone(), req)
+        .await
+    {
+        Err(err) => assert!(matches!(
+            err,
+            oagw_sdk::error::ServiceGatewayError::NotFound { .. }
+        )),
+        Ok(_) => panic!("expected error"),
+    }
+}
+
+// 6.17: Pipeline abort — disabled upstream returns 503.
+#[tokio::test]
+async fn proxy_disabled_upstream_returns_503() {
+    let h = setup_openai_mock().await;
+    let ctx = h.security_context().clone();
+
+    let _upstream = h
+        .facade()
+        .create_upstream(
+            ctx.clone(),
+            CreateUpstreamRequest::builder(
+                Server {
+                    endpoints: vec![Endpoint {
+                        scheme: Scheme::Http,
+                        host: "127.0.0.1".into(),
+                        port: 9999,
+                    }],
+                },
+                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            )
+            .alias("disabled-upstream")
+            .enabled(false)
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri("/disabled-upstream/test")
+        .body(Body::Empty)
+        .unwrap();
+    match h.facade().proxy_request(ctx.clone(), req).await {
+        Err(err) => assert!(matches!(
+            err,
+            oagw_sdk::error::ServiceGatewayError::UpstreamDisabled { .. }
+        )),
+        Ok(_) => panic!("expected error"),
+    }
+}
+
+// Route cache: a route update takes effect for an alias already served.
+#[tokio::test]
+async fn proxy_route_update_invalidates_cached_resolution() {
+    let h = setup_openai_mock().await;
+    let ctx = h.security_context().clone();
+    let post_completions = || {
+        http::Request::builder()
+            .method(Method::POST)
+            .uri("/mock-upstream/v1/chat/completions")
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .body(Body::from(r#"{"model":"gpt-4","messages":[]}"#))
+            .unwrap()
+    };
+
+    // Warm the cache.
+    for _ in 0..2 {
+        let resp = h
+            .facade()
+            .proxy_request(ctx.clone(), post_completions())
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    let route = h
+        .facade()
+        .list_routes(ctx.clone(), None, &oagw_sdk::ListQuery::default())
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|r| {
+            r.match_rules
+                .http
+                .as_ref()
+                .is_some_and(|m| m.path == "/v1/chat/completions")
+        })
+        .unwrap();
+    h.facade()
+        .update_route(
+            ctx.clone(),
+            route.id,
+            UpdateRouteRequest::builder(MatchRules {
+                http: Some(HttpMatch {
+                    methods: vec![HttpMethod::Get],
+                    path: "/v1/chat/completions".into(),
+                    query_allowlist: vec![],
+                    path_suffix_mode: PathSuffixMode::Append,
+                }),
+                grpc: None,
+            })
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    // Invalidation is delivered asynchronously; allow it a moment to land.
+    let deadline = tokio::time::Instant::now() + std::time::Duration::from_secs(2);
+    loop {
+        match h
+            .facade()
+            .proxy_request(ctx.clone(), post_completions())
+            .await
+        {
+            Err(oagw_sdk::error::ServiceGatewayError::NotFound { .. }) => break,
+            other => assert!(
+                tokio::time::Instant::now() < deadline,
+                "POST still routed after update: {:?}",
+                other.map(|r| r.status())
+            ),
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+}
+
+// Key pool auth: consecutive requests rotate through every pooled secret.
+#[tokio::test]
+async fn proxy_key_pool_rotates_credentials() {
+    let h = AppHarness::builder()
+        .with_credentials(vec![
+            ("cred://pool-a".into(), "sk-a".into()),
+            ("cred://pool-b".into(), "sk-b".into()),
+            ("cred://pool-c".into(), "sk-c".into()),
+        ])
+        .build()
+        .await;
+
+    let resp = h
+        .api_v1()
+        .post_upstream()
+        .with_body(json!({
+            "server": {
+                "endpoints": [{"host": "127.0.0.1", "port": h.mock_port(), "scheme": "http"}]
+            },
+            "protocol": "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            "alias": "key-pool",
+            "enabled": true,
+            "tags": [],
+            "auth": {
+                "type": KEY_POOL_AUTH_PLUGIN_ID,
+                "config": {
+                    "header": "authorization",
+                    "prefix": "Bearer ",
+                    "secret_refs": "cred://pool-a,cred://pool-b,cred://pool-c"
+                }
+            }
+        }))
+        .expect_status(201)
+        .await;
+    let upstream_id = resp.json()["id"].as_str().unwrap().to_string();
+
+    h.api_v1()
+        .post_route()
+        .with_body(json!({
+            "upstream_id": &upstream_id,
+            "match": {"http": {"methods": ["POST"], "path": "/echo"}},
+            "enabled": true,
+            "tags": [],
+            "priority": 0
+        }))
+        .expect_status(201)
+        .await;
+
+    let mut seen = Vec::new();
+    for _ in 0..6 {
+        let resp = h
+            .api_v1()
+            .proxy_post("key-pool", "echo")
+            .with_body(json!({}))
+            .expect_status(200)
+            .await;
+        seen.push(
+            resp.json()["headers"]["authorization"]
+                .as_str()
+                .unwrap()
+                .to_string(),
+        );
+    }
+    assert_eq!(
+        seen,
+        [
+            "Bearer sk-a",
+            "Bearer sk-b",
+            "Bearer sk-c",
+            "Bearer sk-a",
+            "Bearer sk-b",
+            "Bearer sk-c"
+        ]
+    );
+}
+
+// 6.17: Pipeline abort — rate limit exceeded returns 429.
+#[tokio::test]
+async fn proxy_rate_limit_exceeded_returns_429() {
+    let h = AppHarness::builder().build().await;
+    let ctx = h.security_context().clone();
+
+    let upstream = h
+        .facade()
+        .create_upstream(
+            ctx.clone(),
+            CreateUpstreamRequest::builder(
+                Server {
+                    endpoints: vec![Endpoint {
+                        scheme: Scheme::Http,
+                        host: "127.0.0.1".into(),
+                        port: h.mock_port(),
+                    }],
+                },
+                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            )
+            .alias("rate-limited")
+            .rate_limit(RateLimitConfig {
+                sharing: SharingMode::Private,
+                algorithm: RateLimitAlgorithm::TokenBucket,
+                sustained: SustainedRate {
+                    rate: 1,
+                    window: Window::Minute,
+                },
+                burst: Some(BurstConfig { capacity: 1 }),
+                scope: RateLimitScope::Tenant,
+                strategy: RateLimitStrategy::Reject,
+                cost: CostStrategy::Fixed(1),
+            })
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    h.facade()
+        .create_route(
+            ctx.clone(),
+            CreateRouteRequest::builder(
+                upstream.id,
+                MatchRules {
+                    http: Some(HttpMatch {
+                        methods: vec![HttpMethod::Get],
+                        path: "/v1/models".into(),
+                        query_allowlist: vec![],
+                        path_suffix_mode: PathSuffixMode::Append,
+                    }),
+                    grpc: None,
+                },
+            )
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    // First request should succeed.
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri("/rate-limited/v1/models")
+        .body(Body::Empty)
+        .unwrap();
+    let response = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Second request should be rate limited.
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri("/rate-limited/v1/models")
+        .body(Body::Empty)
+        .unwrap();
+    match h.facade().proxy_request(ctx.clone(), req).await {
+        Err(err) => assert!(matches!(
+            err,
+            oagw_sdk::error::ServiceGatewayError::RateLimitExceeded { .. }
+        )),
+        Ok(_) => panic!("expected rate limit error"),
+    }
+}
+
+/// One recorded metric call.
+struct Sample {
+    name: &'static str,
+    labels: Vec<(&'static str, String)>,
+    value: f64,
+}
+
+/// Records every metric call for assertions.
+#[derive(Default)]
+struct RecordingMetrics {
+    counters: std::sync::Mutex<Vec<Sample>>,
+    histograms: std::sync::Mutex<Vec<Sample>>,
+}
+
+fn sample(name: &'static str, labels: &[(&'static str, &str)], value: f64) -> Sample {
+    Sample {
+        name,
+        labels: labels.iter().map(|(k, v)| (*k, (*v).to_string())).collect(),
+        value,
+    }
+}
+
+impl Metrics for RecordingMetrics {
+    fn increment_counter(&self, name: &'static str, labels: &[(&'static str, &str)]) {
+        self.counters
+            .lock()
+            .unwrap()
+            .push(sample(name, labels, 1.0));
+    }
+
+    fn add_to_counter(&self, name: &'static str, labels: &[(&'static str, &str)], value: u64) {
+        self.counters
+            .lock()
+            .unwrap()
+            .push(sample(name, labels, value as f64));
+    }
+
+    fn record_histogram(&self, name: &'static str, labels: &[(&'static str, &str)], value: f64) {
+        self.histograms
+            .lock()
+            .unwrap()
+            .push(sample(name, labels, value));
+    }
+}
+
+impl RecordingMetrics {
+    fn matching(&self, name: &str, labels: &[(&str, &str)]) -> Vec<f64> {
+        self.counters
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|s| {
+                s.name == name
+                    && s.labels.len() == labels.len()
+                    && labels
+                        .iter()
+                        .all(|(k, v)| s.labels.iter().any(|(sk, sv)| sk == k && sv == v))
+            })
+            .map(|s| s.value)
+            .collect()
+    }
+
+    fn count(&self, name: &str, labels: &[(&str, &str)]) -> usize {
+        self.matching(name, labels).len()
+    }
+
+    fn total(&self, name: &str, labels: &[(&str, &str)]) -> f64 {
+        self.matching(name, labels).iter().sum()
+    }
+}
+
+// Proxied requests are counted per upstream, by status class and in the
+// latency histogram; rate-limit rejections get their own counter.
+#[tokio::test]
+async fn proxy_records_request_metrics() {
+    let metrics = Arc::new(RecordingMetrics::default());
+    let h = AppHarness::builder()
+        .with_metrics(metrics.clone())
+        .build()
+        .await;
+    let ctx = h.security_context().clone();
+
+    let upstream = h
+        .facade()
+        .create_upstream(
+            ctx.clone(),
+            CreateUpstreamRequest::builder(
+                Server {
+                    endpoints: vec![Endpoint {
+                        scheme: Scheme::Http,
+                        host: "127.0.0.1".into(),
+                        port: h.mock_port(),
+                    }],
+                },
+                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            )
+            .alias("metered")
+            .rate_limit(RateLimitConfig {
+                sharing: SharingMode::Private,
+                algorithm: RateLimitAlgorithm::TokenBucket,
+                sustained: SustainedRate {
+                    rate: 1,
+                    window: Window::Minute,
+                },
+                burst: Some(BurstConfig { capacity: 1 }),
+                scope: RateLimitScope::Tenant,
+                strategy: RateLimitStrategy::Reject,
+                cost: CostStrategy::Fixed(1),
+            })
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    h.facade()
+        .create_route(
+            ctx.clone(),
+            CreateRouteRequest::builder(
+                upstream.id,
+                MatchRules {
+                    http: Some(HttpMatch {
+                        methods: vec![HttpMethod::Get],
+                        path: "/v1/models".into(),
+                        query_allowlist: vec![],
+                        path_suffix_mode: PathSuffixMode::Append,
+                    }),
+                    grpc: None,
+                },
+            )
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    for _ in 0..2 {
+        let req = http::Request::builder()
+            .method(Method::GET)
+            .uri("/metered/v1/models")
+            .body(Body::Empty)
+            .unwrap();
+        let _ = h.facade().proxy_request(ctx.clone(), req).await;
+    }
+
+    // Series are keyed by upstream id: aliases can repeat across tenants.
+    let upstream_id = upstream.id.to_string();
+    let upstream = [(LABEL_UPSTREAM, upstream_id.as_str())];
+    assert_eq!(metrics.count(PROXY_REQUESTS_TOTAL, &upstream), 2);
+    assert_eq!(
+        metrics.count(PROXY_REQUESTS_TOTAL, &[(LABEL_UPSTREAM, "metered")]),
+        0
+    );
+    assert_eq!(
+        metrics.count(
+            PROXY_RESPONSES_TOTAL,
+            &[(LABEL_UPSTREAM, &upstream_id), (LABEL_STATUS_CLASS, "2xx")]
+        ),
+        1
+    );
+    assert_eq!(
+        metrics.count(
+            PROXY_RESPONSES_TOTAL,
+            &[(LABEL_UPSTREAM, &upstream_id), (LABEL_STATUS_CLASS, "4xx")]
+        ),
+        1
+    );
+    assert_eq!(metrics.count(PROXY_RATE_LIMITED_TOTAL, &upstream), 1);
+
+    let histograms = metrics.histograms.lock().unwrap();
+    assert_eq!(histograms.len(), 2);
+    assert!(histograms.iter().all(|s| {
+        s.name == PROXY_REQUEST_DURATION_SECONDS
+            && s.labels == [(LABEL_UPSTREAM, upstream_id.clone())]
+            && s.value >= 0.0
+    }));
+}
+
+// Request and response body bytes are counted per upstream for both buffered
+// and streaming request bodies.
+#[tokio::test]
+async fn proxy_records_body_byte_metrics() {
+    let mut guard = MockGuard::new();
+    guard.mock(
+        "POST",
+        "/v1/upload",
+        MockResponse {
+            status: 200,
+            headers: vec![],
+            body: MockBody::Text("x".repeat(4096)),
+        },
+    );
+
+    let metrics = Arc::new(RecordingMetrics::default());
+    let h = AppHarness::builder()
+        .with_metrics(metrics.clone())
+        .build()
+        .await;
+    let ctx = h.security_context().clone();
+
+    let upstream = h
+        .facade()
+        .create_upstream(
+            ctx.clone(),
+            CreateUpstreamRequest::builder(
+                Server {
+                    endpoints: vec![Endpoint {
+                        scheme: Scheme::Http,
+                        host: "127.0.0.1".into(),
+                        port: h.mock_port(),
+                    }],
+                },
+                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            )
+            .alias("byte-metered")
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    h.facade()
+        .create_route(
+            ctx.clone(),
+            CreateRouteRequest::builder(
+                upstream.id,
+                MatchRules {
+                    http: Some(HttpMatch {
+                        methods: vec![HttpMethod::Post],
+                        path: guard.path("/v1/upload"),
+                        query_allowlist: vec![],
+                        path_suffix_mode: PathSuffixMode::Disabled,
+                    }),
+                    grpc: None,
+                },
+            )
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    let uri = format!("/byte-metered{}/v1/upload", guard.prefix());
+    let chunks: Vec<Result<bytes::Bytes, oagw_sdk::body::BoxError>> = vec![
+        Ok(bytes::Bytes::from(vec![b'a'; 700])),
+        Ok(bytes::Bytes::from(vec![b'b'; 300])),
+    ];
+    let stream: oagw_sdk::body::BodyStream = Box::pin(futures_util::stream::iter(chunks));
+    let mut received = 0;
+    for body in [Body::Bytes(vec![b'z'; 1500].into()), Body::Stream(stream)] {
+        let req = http::Request::builder()
+            .method(Method::POST)
+            .uri(&uri)
+            .header(http::header::CONTENT_TYPE, "application/octet-stream")
+            .body(body)
+            .unwrap();
+        let resp = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        received += resp.into_body().into_bytes().await.unwrap().len();
+    }
+
+    let upstream_id = upstream.id.to_string();
+    let upstream = [(LABEL_UPSTREAM, upstream_id.as_str())];
+    assert_eq!(
+        metrics.total(PROXY_REQUEST_BODY_BYTES_TOTAL, &upstream),
+        2500.0
+    );
+    assert_eq!(received, 2 * 4096);
+    assert_eq!(
+        metrics.total(PROXY_RESPONSE_BODY_BYTES_TOTAL, &upstream),
+        received as f64
+    );
+}
+
+// Per-kilobyte cost: a large body drains the bucket faster than small ones.
+#[tokio::test]
+async fn proxy_rate_limit_per_kilobyte_cost() {
+    let h = AppHarness::builder().build().await;
+
+    let resp = h
+        .api_v1()
+        .post_upstream()
+        .with_body(serde_json::json!({
+            "server": {
+                "endpoints": [{"host": "127.0.0.1", "port": h.mock_port(), "scheme": "http"}]
+            },
+            "protocol": "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            "alias": "kb-cost",
+            "enabled": true,
+            "tags": [],
+            "rate_limit": {
+                "sustained": {"rate": 4, "window": "minute"},
+                "cost": {"type": "per_kilobyte"}
+            }
+        }))
+        .expect_status(201)
+        .await;
+    assert_eq!(
+        resp.json()["rate_limit"]["cost"],
+        json!({"type": "per_kilobyte"})
+    );
+    let upstream_id = resp.json()["id"].as_str().unwrap().to_string();
+
+    h.api_v1()
+        .post_route()
+        .with_body(serde_json::json!({
+            "upstream_id": &upstream_id,
+            "match": {"http": {"methods": ["POST"], "path": "/echo"}},
+            "enabled": true,
+            "tags": [],
+            "priority": 0
+        }))
+        .expect_status(201)
+        .await;
+
+    // Two small bodies cost one token each, leaving 2 of 4.
+    for _ in 0..2 {
+        h.api_v1()
+            .proxy_post("kb-cost", "echo")
+            .with_body("x".repeat(100))
+            .expect_status(200)
+            .await;
+    }
+    // A 3 KB body costs 3 tokens and is rejected.
+    h.api_v1()
+        .proxy_post("kb-cost", "echo")
+        .with_body("x".repeat(3 * 1024))
+        .expect_status(429)
+        .await;
+    // The remaining tokens still admit small requests.
+    h.api_v1()
+        .proxy_post("kb-cost", "echo")
+        .with_body("x".repeat(100))
+        .expect_status(200)
+        .await;
+}
+
+// Per-kilobyte cost of a streamed body without Content-Length: admitted at
+// one token, then charged for the bytes actually forwarded.
+#[tokio::test]
+async fn proxy_rate_limit_per_kilobyte_charges_chunked_body() {
+    let h = AppHarness::builder().build().await;
+
+    let resp = h
+        .api_v1()
+        .post_upstream()
+        .with_body(serde_json::json!({
+            "server": {
+                "endpoints": [{"host": "127.0.0.1", "port": h.mock_port(), "scheme": "http"}]
+            },
+            "protocol": "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            "alias": "kb-chunked",
+            "enabled": true,
+            "tags": [],
+            "rate_limit": {
+                "sustained": {"rate": 5, "window": "minute"},
+                "cost": {"type": "per_kilobyte"}
+            }
+        }))
+        .expect_status(201)
+        .await;
+    let upstream_id = resp.json()["id"].as_str().unwrap().to_string();
+
+    h.api_v1()
+        .post_route()
+        .with_body(serde_json::json!({
+            "upstream_id": &upstream_id,
+            "match": {"http": {"methods": ["POST"], "path": "/echo"}},
+            "enabled": true,
+            "tags": [],
+            "priority": 0
+        }))
+        .expect_status(201)
+        .await;
+
+    // A 3 KB chunked body costs 3 tokens, leaving 2 of 5.
+    let chunks: Vec<Result<bytes::Bytes, oagw_sdk::body::BoxError>> = (0..3)
+        .map(|_| Ok(bytes::Bytes::from(vec![b'c'; 1024])))
+        .collect();
+    let stream: oagw_sdk::body::BodyStream = Box::pin(futures_util::stream::iter(chunks));
+    let req = http::Request::builder()
+        .method(Method::POST)
+        .uri("/kb-chunked/echo")
+        .body(Body::Stream(stream))
+        .unwrap();
+    let resp = h
+        .facade()
+        .proxy_request(h.security_context().clone(), req)
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    resp.into_body().into_bytes().await.unwrap();
+
+    for _ in 0..2 {
+        h.api_v1()
+            .proxy_post("kb-chunked", "echo")
+            .with_body("x".repeat(100))
+            .expect_status(200)
+            .await;
+    }
+    h.api_v1()
+        .proxy_post("kb-chunked", "echo")
+        .with_body("x".repeat(100))
+        .expect_status(429)
+        .await;
+}
+
+/// Create an upstream to the mock server with a catch-all GET route and no
+/// rate limit.
+async fn create_unlimited_upstream(h: &AppHarness, alias: &str) {
+    let resp = h
+        .api_v1()
+        .post_upstream()
+        .with_body(serde_json::json!({
+            "server": {
+                "endpoints": [{"host": "127.0.0.1", "port": h.mock_port(), "scheme": "http"}]
+            },
+            "protocol": "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            "alias": alias,
+            "enabled": true,
+            "tags": []
+        }))
+        .expect_status(201)
+        .await;
+    let upstream_id = resp.json()["id"].as_str().unwrap().to_string();
+
+    h.api_v1()
+        .post_route()
+        .with_body(serde_json::json!({
+            "upstream_id": &upstream_id,
+            "match": {"http": {"methods": ["GET"], "path": "/v1/models"}},
+            "enabled": true,
+            "tags": [],
+            "priority": 0
+        }))
+        .expect_status(201)
+        .await;
+}
+
+// Tenant default rate limit applies to an upstream with no explicit limit,
+// through one bucket shared by all such upstreams.
+#[tokio::test]
+async fn proxy_tenant_default_rate_limit_applies_to_unlimited_upstream() {
+    let h = AppHarness::builder().build().await;
+    create_unlimited_upstream(&h, "no-limit-a").await;
+    create_unlimited_upstream(&h, "no-limit-b").await;
+
+    h.api_v1().get_tenant_rate_limit().expect_status(404).await;
+    let resp = h
+        .api_v1()
+        .put_tenant_rate_limit()
+        .with_body(serde_json::json!({
+            "rate_limit": {"sustained": {"rate": 2, "window": "minute"}}
+        }))
+        .expect_status(200)
+        .await;
+    assert_eq!(resp.json()["aggregate"], json!(false));
+    h.api_v1().get_tenant_rate_limit().expect_status(200).await;
+    // The change notification resets the tenant bucket asynchronously; let
+    // it land before draining the bucket.
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+    h.api_v1()
+        .proxy_get("no-limit-a", "v1/models")
+        .expect_status(200)
+        .await;
+    h.api_v1()
+        .proxy_get("no-limit-b", "v1/models")
+        .expect_status(200)
+        .await;
+    // Both upstreams drew from the same tenant bucket, which is now empty.
+    h.api_v1()
+        .proxy_get("no-limit-a", "v1/models")
+        .expect_status(429)
+        .await;
+
+    // Removing the tenant limit lifts it.
+    h.api_v1()
+        .delete_tenant_rate_limit()
+        .expect_status(204)
+        .await;
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    h.api_v1()
+        .proxy_get("no-limit-a", "v1/models")
+        .expect_status(200)
+        .await;
+}
+
+// A non-aggregate tenant limit leaves explicitly limited upstreams alone; an
+// aggregate one caps them too.
+#[tokio::test]
+async fn proxy_tenant_aggregate_rate_limit_caps_limited_upstreams() {
+    let h = AppHarness::builder().build().await;
+    let resp = h
+        .api_v1()
+        .post_upstream()
+        .with_body(serde_json::json!({
+            "server": {
+                "endpoints": [{"host": "127.0.0.1", "port": h.mock_port(), "scheme": "http"}]
+            },
+            "protocol": "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            "alias": "own-limit",
+            "enabled": true,
+            "tags": [],
+            "rate_limit": {"sustained": {"rate": 100, "window": "minute"}}
+        }))
+        .expect_status(201)
+        .await;
+    let upstream_id = resp.json()["id"].as_str().unwrap().to_string();
+    h.api_v1()
+        .post_route()
+        .with_body(serde_json::json!({
+            "upstream_id": &upstream_id,
+            "match": {"http": {"methods": ["GET"], "path": "/v1/models"}},
+            "enabled": true,
+            "tags": [],
+            "priority": 0
+        }))
+        .expect_status(201)
+        .await;
+
+    h.api_v1()
+        .put_tenant_rate_limit()
+        .with_body(serde_json::json!({
+            "rate_limit": {"sustained": {"rate": 1, "window": "minute"}}
+        }))
+        .expect_status(200)
+        .await;
+    for _ in 0..3 {
+        h.api_v1()
+            .proxy_get("own-limit", "v1/models")
+            .expect_status(200)
+            .await;
+    }
+
+    h.api_v1()
+        .put_tenant_rate_limit()
+        .with_body(serde_json::json!({
+            "rate_limit": {"sustained": {"rate": 1, "window": "minute"}},
+            "aggregate": true
+        }))
+        .expect_status(200)
+        .await;
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    h.api_v1()
+        .proxy_get("own-limit", "v1/models")
+        .expect_status(200)
+        .await;
+    h.api_v1()
+        .proxy_get("own-limit", "v1/models")
+        .expect_status(429)
+        .await;
+}
+
+// 6.16: Upstream timeout — proxy to gated mock that never responds, assert 504.
+// Uses multi_thread runtime so the timer driver runs on a dedicated thread,
+// preventing stalls when other test binaries compete for CPU.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn proxy_upstream_timeout_returns_504() {
+    let mut guard = MockGuard::new();
+    // Register a gated route that will never respond (sender kept alive but not signaled).
+    let _gate = guard.mock_gated(
+        "GET",
+        "/timeout",
+        MockResponse {
+            status: 200,
+            headers: vec![],
+            body: MockBody::Json(json!({"ok": true})),
+        },
+    );
+
+    let h = AppHarness::builder()
+        .with_request_timeout(std::time::Duration::from_millis(500))
+        .build()
+        .await;
+    let ctx = h.security_context().clone();
+
+    let upstream = h
+        .facade()
+        .create_upstream(
+            ctx.clone(),
+            CreateUpstreamRequest::builder(
+                Server {
+                    endpoints: vec![Endpoint {
+                        scheme: Scheme::Http,
+                        host: "127.0.0.1".into(),
+                        port: h.mock_port(),
+                    }],
+                },
+                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            )
+            .alias("timeout-upstream")
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    h.facade()
+        .create_route(
+            ctx.clone(),
+            CreateRouteRequest::builder(
+                upstream.id,
+                MatchRules {
+                    http: Some(HttpMatch {
+                        methods: vec![HttpMethod::Get],
+                        path: guard.path("/timeout"),
+                        query_allowlist: vec![],
+                        path_suffix_mode: PathSuffixMode::Disabled,
+                    }),
+                    grpc: None,
+                },
+            )
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri(format!("/timeout-upstream{}", guard.path("/timeout")))
+        .body(Body::Empty)
+        .unwrap();
+    match h.facade().proxy_request(ctx.clone(), req).await {
+        Err(err) => assert!(matches!(
+            err,
+            oagw_sdk::error::ServiceGatewayError::RequestTimeout { .. }
+        )),
+        Ok(_) => panic!("expected timeout error"),
+    }
+}
+
+// Per-request timeout override — a short X-OAGW-Timeout-Ms beats the default.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn proxy_timeout_override_header_returns_504() {
+    let mut guard = MockGuard::new();
+    let _gate = guard.mock_gated(
+        "GET",
+        "/slow",
+        MockResponse {
+            status: 200,
+            headers: vec![],
+            body: MockBody::Json(json!({"ok": true})),
+        },
+    );
+
+    // Default timeout is far longer than the override.
+    let h = AppHarness::builder()
+        .with_request_timeout(std::time::Duration::from_secs(30))
+        .build()
+        .await;
+    let ctx = h.security_context().clone();
+    create_mock_route(&h, &guard, "override-upstream", "/slow").await;
+
+    let started = std::time::Instant::now();
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri(format!("/override-upstream{}", guard.path("/slow")))
+        .header("x-oagw-timeout-ms", "200")
+        .body(Body::Empty)
+        .unwrap();
+    match h.facade().proxy_request(ctx, req).await {
+        Err(err) => {
+            assert_eq!(err.status_code(), StatusCode::GATEWAY_TIMEOUT, "{err:?}");
+        }
+        Ok(_) => panic!("expected timeout error"),
+    }
+    assert!(started.elapsed() < std::time::Duration::from_secs(10));
+}
+
+#[tokio::test]
+async fn proxy_malformed_timeout_override_returns_400() {
+    let mut guard = MockGuard::new();
+    guard.mock(
+        "GET",
+        "/fast",
+        MockResponse {
+            status: 200,
+            headers: vec![],
+            body: MockBody::Json(json!({"ok": true})),
+        },
+    );
+
+    let h = AppHarness::builder().build().await;
+    let ctx = h.security_context().clone();
+    create_mock_route(&h, &guard, "bad-override-upstream", "/fast").await;
+
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri(format!("/bad-override-upstream{}", guard.path("/fast")))
+        .header("x-oagw-timeout-ms", "soon")
+        .body(Body::Empty)
+        .unwrap();
+    match h.facade().proxy_request(ctx, req).await {
+        Err(err) => assert_eq!(err.status_code(), StatusCode::BAD_REQUEST, "{err:?}"),
+        Ok(_) => panic!("expected validation error"),
+    }
+}
+
+// Conflicting inbound Content-Length values are rejected; identical
+// duplicates collapse to one.
+#[tokio::test]
+async fn proxy_conflicting_content_length_returns_400() {
+    let mut guard = MockGuard::new();
+    guard.mock(
+        "GET",
+        "/v1/items",
+        MockResponse {
+            status: 200,
+            headers: vec![],
+            body: MockBody::Json(json!({"ok": true})),
+        },
+    );
+
+    let h = AppHarness::builder().build().await;
+    let ctx = h.security_context().clone();
+    create_mock_route(&h, &guard, "cl-conflict", "/v1/items").await;
+
+    let request = |lengths: [&'static str; 2]| {
+        let mut req = http::Request::builder()
+            .method(Method::GET)
+            .uri(format!("/cl-conflict{}", guard.path("/v1/items")))
+            .body(Body::Empty)
+            .unwrap();
+        for length in lengths {
+            req.headers_mut().append(
+                http::header::CONTENT_LENGTH,
+                http::HeaderValue::from_static(length),
+            );
+        }
+        req
+    };
+
+    match h
+        .facade()
+        .proxy_request(ctx.clone(), request(["0", "7"]))
+        .await
+    {
+        Err(err) => {
+            assert_eq!(err.status_code(), StatusCode::BAD_REQUEST, "{err:?}");
+            assert_eq!(err.error_source(), ErrorSource::Gateway);
+        }
+        Ok(resp) => panic!("expected 400, got {}", resp.status()),
+    }
+    assert!(guard.recorded_requests().await.is_empty());
+
+    let resp = h
+        .facade()
+        .proxy_request(ctx, request(["0", "0"]))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+}
+
+// An upstream response with conflicting Content-Length values is not relayed.
+#[tokio::test]
+async fn proxy_conflicting_upstream_content_length_returns_502() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let mut buf = [0u8; 4096];
+            let _ = socket.read(&mut buf).await;
+            let _ = socket
+                .write_all(
+                    b"HTTP/1.1 200 OK\r\ncontent-length: 5\r\n\
+                      content-length: 11\r\n\r\nhello world",
+                )
+                .await;
+        }
+    });
+
+    let h = AppHarness::builder().build().await;
+    let ctx = h.security_context().clone();
+    let upstream = h
+        .facade()
+        .create_upstream(
+            ctx.clone(),
+            CreateUpstreamRequest::builder(
+                Server {
+                    endpoints: vec![Endpoint {
+                        scheme: Scheme::Http,
+                        host: "127.0.0.1".into(),
+                        port,
+                    }],
+                },
+                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            )
+            .alias("cl-conflict-upstream")
+            .build(),
+        )
+        .await
+        .unwrap();
+    h.facade()
+        .create_route(
+            ctx.clone(),
+            CreateRouteRequest::builder(
+                upstream.id,
+                MatchRules {
+                    http: Some(HttpMatch {
+                        methods: vec![HttpMethod::Get],
+                        path: "/v1/items".into(),
+                        query_allowlist: vec![],
+                        path_suffix_mode: PathSuffixMode::Disabled,
+                    }),
+                    grpc: None,
+                },
+            )
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri("/cl-conflict-upstream/v1/items")
+        .body(Body::Empty)
+        .unwrap();
+    let resp = h.facade().proxy_request(ctx, req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::BAD_GATEWAY);
+    assert_eq!(
+        resp.extensions().get::<ErrorSource>(),
+        Some(&ErrorSource::Gateway)
+    );
+}
+
+#[tokio::test]
+async fn proxy_rejects_traversal_and_control_characters_in_path() {
+    let h = setup_openai_mock().await;
+
+    for uri in [
+        "/mock-upstream/v1/../../admin/secret",
+        "/mock-upstream/v1/%2e%2e/admin",
+        "/mock-upstream/v1/chat%0d%0aX-Injected:%201",
+    ] {
+        let req = http::Request::builder()
+            .method(Method::GET)
+            .uri(uri)
+            .body(Body::Empty)
+            .unwrap();
+        match h
+            .facade()
+            .proxy_request(h.security_context().clone(), req)
+            .await
+        {
+            Err(err) => {
+                assert_eq!(err.status_code(), StatusCode::BAD_REQUEST, "{uri}: {err:?}");
+                assert_eq!(err.error_source(), ErrorSource::Gateway, "{uri}");
+            }
+            Ok(resp) => panic!("{uri}: expected 400, got {}", resp.status()),
+        }
+    }
+}
+
+async fn create_mock_route(h: &AppHarness, guard: &MockGuard, alias: &str, path: &str) {
+    let ctx = h.security_context().clone();
+    let upstream = h
+        .facade()
+        .create_upstream(
+            ctx.clone(),
+            CreateUpstreamRequest::builder(
+                Server {
+                    endpoints: vec![Endpoint {
+                        scheme: Scheme::Http,
+                        host: "127.0.0.1".into(),
+                        port: h.mock_port(),
+                    }],
+                },
+                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            )
+            .alias(alias)
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    h.facade()
+        .create_route(
+            ctx,
+            CreateRouteRequest::builder(
+                upstream.id,
+                MatchRules {
+                    http: Some(HttpMatch {
+                        methods: vec![HttpMethod::Get],
+                        path: guard.path(path),
+                        query_allowlist: vec![],
+                        path_suffix_mode: PathSuffixMode::Disabled,
+                    }),
+                    grpc: None,
+                },
+            )
+            .build(),
+        )
+        .await
+        .unwrap();
+}
+
+// 8.9: Query allowlist enforcement.
+#[tokio::test]
+async fn proxy_query_allowlist_allowed_param_succeeds() {
+    let h = AppHarness::builder().build().await;
+    let ctx = h.security_context().clone();
+
+    let upstream = h
+        .facade()
+        .create_upstream(
+            ctx.clone(),
+            CreateUpstreamRequest::builder(
+                Server {
+                    endpoints: vec![Endpoint {
+                        scheme: Scheme::Http,
+                        host: "127.0.0.1".into(),
+                        port: h.mock_port(),
+                    }],
+                },
+                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            )
+            .alias("ql-test")
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    h.facade()
+        .create_route(
+            ctx.clone(),
+            CreateRouteRequest::builder(
+                upstream.id,
+                MatchRules {
+                    http: Some(HttpMatch {
+                        methods: vec![HttpMethod::Get],
+                        path: "/v1/models".into(),
+                        query_allowlist: vec!["version".into()],
+                        path_suffix_mode: PathSuffixMode::Append,
+                    }),
+                    grpc: None,
+                },
+            )
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri("/ql-test/v1/models?version=2")
+        .body(Body::Empty)
+        .unwrap();
+    let response = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn proxy_query_allowlist_unknown_param_rejected() {
+    let h = AppHarness::builder().build().await;
+    let ctx = h.security_context().clone();
+
+    let upstream = h
+        .facade()
+        .create_upstream(
+            ctx.clone(),
+            CreateUpstreamRequest::builder(
+                Server {
+                    endpoints: vec![Endpoint {
+                        scheme: Scheme::Http,
+                        host: "127.0.0.1".into(),
+                        port: h.mock_port(),
+                    }],
+                },
+                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            )
+            .alias("ql-reject")
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    h.facade()
+        .create_route(
+            ctx.clone(),
+            CreateRouteRequest::builder(
+                upstream.id,
+                MatchRules {
+                    http: Some(HttpMatch {
+                        methods: vec![HttpMethod::Get],
+                        path: "/v1/models".into(),
+                        query_allowlist: vec!["version".into()],
+                        path_suffix_mode: PathSuffixMode::Append,
+                    }),
+                    grpc: None,
+                },
+            )
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri("/ql-reject/v1/models?version=2&debug=true")
+        .body(Body::Empty)
+        .unwrap();
+    match h.facade().proxy_request(ctx.clone(), req).await {
+        Err(err) => assert!(matches!(
+            err,
+            oagw_sdk::error::ServiceGatewayError::ValidationError { .. }
+        )),
+        Ok(_) => panic!("expected validation error"),
+    }
+}
+
+// 13.5: Non-existent auth plugin ID returns error through proxy pipeline.
+#[tokio::test]
+async fn proxy_nonexistent_auth_plugin_returns_error() {
+    let h = AppHarness::builder().build().await;
+    let ctx = h.security_context().clone();
+
+    let upstream = h
+        .facade()
+        .create_upstream(
+            ctx.clone(),
+            CreateUpstreamRequest::builder(
+                Server {
+                    endpoints: vec![Endpoint {
+                        scheme: Scheme::Http,
+                        host: "127.0.0.1".into(),
+                        port: h.mock_port(),
+                    }],
+                },
+                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            )
+            .alias("bad-auth")
+            .auth(oagw_sdk::AuthConfig {
+                plugin_type: "gts.x.core.oagw.auth.v1~nonexistent.plugin.v1".into(),
+                sharing: SharingMode::Private,
+                config: None,
+            })
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    h.facade()
+        .create_route(
+            ctx.clone(),
+            CreateRouteRequest::builder(
+                upstream.id,
+                MatchRules {
+                    http: Some(HttpMatch {
+                        methods: vec![HttpMethod::Get],
+                        path: "/v1/test".into(),
+                        query_allowlist: vec![],
+                        path_suffix_mode: PathSuffixMode::Append,
+                    }),
+                    grpc: None,
+                },
+            )
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri("/bad-auth/v1/test")
+        .body(Body::Empty)
+        .unwrap();
+    match h.facade().proxy_request(ctx.clone(), req).await {
+        Err(err) => assert!(matches!(
+            err,
+            oagw_sdk::error::ServiceGatewayError::AuthenticationFailed { .. }
+        )),
+        Ok(_) => panic!("expected authentication error for non-existent plugin"),
+    }
+}
+
+// An auth plugin that fails (here: its secret is missing) is reported as the
+// error's source, with the plugin's id and the status of the inner failure.
+#[tokio::test]
+async fn proxy_auth_plugin_failure_is_attributed_to_plugin() {
+    let h = AppHarness::builder().build().await;
+    let ctx = h.security_context().clone();
+
+    let upstream = h
+        .facade()
+        .create_upstream(
+            ctx.clone(),
+            CreateUpstreamRequest::builder(
+                Server {
+                    endpoints: vec![Endpoint {
+                        scheme: Scheme::Http,
+                        host: "127.0.0.1".into(),
+                        port: h.mock_port(),
+                    }],
+                },
+                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            )
+            .alias("missing-secret")
+            .auth(oagw_sdk::AuthConfig {
+                plugin_type: APIKEY_AUTH_PLUGIN_ID.into(),
+                sharing: SharingMode::Private,
+                config: Some(
+                    [
+                        ("header".into(), "authorization".into()),
+                        ("secret_ref".into(), "cred://does-not-exist".into()),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ),
+            })
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    h.facade()
+        .create_route(
+            ctx.clone(),
+            CreateRouteRequest::builder(
+                upstream.id,
+                MatchRules {
+                    http: Some(HttpMatch {
+                        methods: vec![HttpMethod::Get],
+                        path: "/v1/test".into(),
+                        query_allowlist: vec![],
+                        path_suffix_mode: PathSuffixMode::Append,
+                    }),
+                    grpc: None,
+                },
+            )
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri("/missing-secret/v1/test")
+        .body(Body::Empty)
+        .unwrap();
+    let err = h
+        .facade()
+        .proxy_request(ctx, req)
+        .await
+        .expect_err("missing secret should fail the auth plugin");
+
+    assert_eq!(err.error_source(), ErrorSource::Plugin);
+    assert_eq!(err.plugin_id(), Some(APIKEY_AUTH_PLUGIN_ID));
+    assert_eq!(err.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+    match err {
+        oagw_sdk::error::ServiceGatewayError::PluginFailed { error, .. } => assert!(matches!(
+            *error,
+            oagw_sdk::error::ServiceGatewayError::SecretNotFound { .. }
+        )),
+        other => panic!("expected PluginFailed, got: {other:?}"),
+    }
+}
+
+// 13.6: Assert on recorded_requests() URI and body content.
+#[tokio::test]
+async fn proxy_recorded_request_has_correct_uri_and_body() {
+    let mut guard = MockGuard::new();
+    guard.mock(
+        "POST",
+        "/v1/chat/completions",
+        MockResponse {
+            status: 200,
+            headers: vec![("content-type".into(), "application/json".into())],
+            body: MockBody::Json(json!({
+                "id": "chatcmpl-rec-test",
+                "object": "chat.completion",
+                "choices": [{"index": 0, "message": {"role": "assistant", "content": "ok"}, "finish_reason": "stop"}]
+            })),
+        },
+    );
+
+    let h = AppHarness::builder().build().await;
+    let ctx = h.security_context().clone();
+
+    let upstream = h
+        .facade()
+        .create_upstream(
+            ctx.clone(),
+            CreateUpstreamRequest::builder(
+                Server {
+                    endpoints: vec![Endpoint {
+                        scheme: Scheme::Http,
+                        host: "127.0.0.1".into(),
+                        port: h.mock_port(),
+                    }],
+                },
+                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            )
+            .alias("rec-test")
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    h.facade()
+        .create_route(
+            ctx.clone(),
+            CreateRouteRequest::builder(
+                upstream.id,
+                MatchRules {
+                    http: Some(HttpMatch {
+                        methods: vec![HttpMethod::Post],
+                        path: guard.path("/v1/chat/completions"),
+                        query_allowlist: vec![],
+                        path_suffix_mode: PathSuffixMode::Disabled,
+                    }),
+                    grpc: None,
+                },
+            )
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    let body_payload = r#"{"model":"gpt-4","messages":[{"role":"user","content":"Hello"}]}"#;
+    let req = http::Request::builder()
+        .method(Method::POST)
+        .uri(format!("/rec-test{}", guard.path("/v1/chat/completions")))
+        .header(http::header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body_payload))
+        .unwrap();
+    let response = h.facade().proxy_request(ctx, req).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let recorded = guard.recorded_requests().await;
+    assert_eq!(recorded.len(), 1);
+    assert!(recorded[0].uri.ends_with("/v1/chat/completions"));
+    assert_eq!(recorded[0].method, "POST");
+
+    let body_str = String::from_utf8(recorded[0].body.clone()).unwrap();
+    assert!(body_str.contains("gpt-4"));
+    assert!(body_str.contains("Hello"));
+}
+
+// Resolve overrides: an unresolvable hostname is mapped to the mock's loopback address.
+#[tokio::test]
+async fn proxy_resolve_override_routes_fake_hostname() {
+    let mut guard = MockGuard::new();
+    guard.mock(
+        "GET",
+        "/resolved",
+        MockResponse {
+            status: 200,
+            headers: vec![("content-type".into(), "application/json".into())],
+            body: MockBody::Json(json!({"resolved": true})),
+        },
+    );
+
+    let fake_host = "api.resolve-override.invalid";
+    let h = AppHarness::builder()
+        .with_resolve_override(
+            fake_host,
+            std::net::SocketAddr::from(([127, 0, 0, 1], shared_mock().port())),
+        )
+        .build()
+        .await;
+    let ctx = h.security_context().clone();
+
+    let upstream = h
+        .facade()
+        .create_upstream(
+            ctx.clone(),
+            CreateUpstreamRequest::builder(
+                Server {
+                    endpoints: vec![Endpoint {
+                        scheme: Scheme::Http,
+                        host: fake_host.into(),
+                        port: 80,
+                    }],
+                },
+                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            )
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    h.facade()
+        .create_route(
+            ctx.clone(),
+            CreateRouteRequest::builder(
+                upstream.id,
+                MatchRules {
+                    http: Some(HttpMatch {
+                        methods: vec![HttpMethod::Get],
+                        path: guard.path("/resolved"),
+                        query_allowlist: vec![],
+                        path_suffix_mode: PathSuffixMode::Disabled,
+                    }),
+                    grpc: None,
+                },
+            )
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri(format!("/{}{}", upstream.alias, guard.path("/resolved")))
+        .body(Body::Empty)
+        .unwrap();
+    let response = h.facade().proxy_request(ctx, req).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let recorded = guard.recorded_requests().await;
+    assert_eq!(recorded.len(), 1);
+    let host = recorded[0]
+        .headers
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case("host"))
+        .map(|(_, v)| v.as_str());
+    assert_eq!(host, Some(fake_host), "Host header keeps the original name");
+}
+
+// Request header sanitization: hop-by-hop and Connection-nominated headers are not forwarded upstream.
+#[tokio::test]
+async fn proxy_request_hop_by_hop_headers_stripped() {
+    let mut guard = MockGuard::new();
+    guard.mock(
+        "GET",
+        "/hop-by-hop",
+        MockResponse {
+            status: 200,
+            headers: vec![("content-type".into(), "application/json".into())],
+            body: MockBody::Json(json!({"ok": true})),
+        },
+    );
+
+    let h = AppHarness::builder().build().await;
+    let ctx = h.security_context().clone();
+
+    let upstream = h
+        .facade()
+        .create_upstream(
+            ctx.clone(),
+            CreateUpstreamRequest::builder(
+                Server {
+                    endpoints: vec![Endpoint {
+                        scheme: Scheme::Http,
+                        host: "127.0.0.1".into(),
+                        port: h.mock_port(),
+                    }],
+                },
+                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            )
+            .alias("hop-req-test")
+            .headers(HeadersConfig {
+                request: Some(RequestHeaderRules {
+                    passthrough: PassthroughMode::All,
+                    ..Default::default()
+                }),
+                response: None,
+            })
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    h.facade()
+        .create_route(
+            ctx.clone(),
+            CreateRouteRequest::builder(
+                upstream.id,
+                MatchRules {
+                    http: Some(HttpMatch {
+                        methods: vec![HttpMethod::Get],
+                        path: guard.path("/hop-by-hop"),
+                        query_allowlist: vec![],
+                        path_suffix_mode: PathSuffixMode::Disabled,
+                    }),
+                    grpc: None,
+                },
+            )
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri(format!("/hop-req-test{}", guard.path("/hop-by-hop")))
+        .header("connection", "keep-alive")
+        .header("connection", "X-Hop-Secret")
+        .header("keep-alive", "timeout=5")
+        .header("proxy-authorization", "Basic Zm9vOmJhcg==")
+        .header("te", "trailers")
+        .header("x-hop-secret", "do-not-forward")
+        .header("x-end-to-end", "forward-me")
+        .body(Body::Empty)
+        .unwrap();
+    let response = h.facade().proxy_request(ctx, req).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let recorded = guard.recorded_requests().await;
+    assert_eq!(recorded.len(), 1);
+    let forwarded = |name: &str| {
+        recorded[0]
+            .headers
+            .iter()
+            .any(|(k, _)| k.eq_ignore_ascii_case(name))
+    };
+    for name in ["keep-alive", "proxy-authorization", "te", "x-hop-secret"] {
+        assert!(!forwarded(name), "{name} should not be forwarded upstream");
+    }
+    assert!(
+        forwarded("x-end-to-end"),
+        "end-to-end header should be forwarded"
+    );
+}
+
+// Allowlist passthrough: only listed headers (matched case-insensitively) reach
+// the upstream, alongside headers injected by the auth plugin.
+#[tokio::test]
+async fn proxy_request_passthrough_allowlist() {
+    let mut guard = MockGuard::new();
+    guard.mock(
+        "GET",
+        "/allowlist",
+        MockResponse {
+            status: 200,
+            headers: vec![("content-type".into(), "application/json".into())],
+            body: MockBody::Json(json!({"ok": true})),
+        },
+    );
+
+    let h = AppHarness::builder()
+        .with_credentials(vec![("cred://allowlist-key".into(), "sk-allow".into())])
+        .build()
+        .await;
+    let ctx = h.security_context().clone();
+
+    let upstream = h
+        .facade()
+        .create_upstream(
+            ctx.clone(),
+            CreateUpstreamRequest::builder(
+                Server {
+                    endpoints: vec![Endpoint {
+                        scheme: Scheme::Http,
+                        host: "127.0.0.1".into(),
+                        port: h.mock_port(),
+                    }],
+                },
+                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            )
+            .alias("allowlist-test")
+            .auth(oagw_sdk::AuthConfig {
+                plugin_type: APIKEY_AUTH_PLUGIN_ID.into(),
+                sharing: SharingMode::Private,
+                config: Some(HashMap::from([
+                    ("header".into(), "authorization".into()),
+                    ("prefix".into(), "Bearer ".into()),
+                    ("secret_ref".into(), "cred://allowlist-key".into()),
+                ])),
+            })
+            .headers(HeadersConfig {
+                request: Some(RequestHeaderRules {
+                    passthrough: PassthroughMode::Allowlist,
+                    passthrough_allowlist: vec!["X-Allowed".into()],
+                    ..Default::default()
+                }),
+                response: None,
+            })
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    h.facade()
+        .create_route(
+            ctx.clone(),
+            CreateRouteRequest::builder(
+                upstream.id,
+                MatchRules {
+                    http: Some(HttpMatch {
+                        methods: vec![HttpMethod::Get],
+                        path: guard.path("/allowlist"),
+                        query_allowlist: vec![],
+                        path_suffix_mode: PathSuffixMode::Disabled,
+                    }),
+                    grpc: None,
+                },
+            )
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri(format!("/allowlist-test{}", guard.path("/allowlist")))
+        .header("x-allowed", "keep-me")
+        .header("x-blocked", "drop-me")
+        .body(Body::Empty)
+        .unwrap();
+    let response = h.facade().proxy_request(ctx, req).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let recorded = guard.recorded_requests().await;
+    assert_eq!(recorded.len(), 1);
+    let header = |name: &str| {
+        recorded[0]
+            .headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    };
+    assert_eq!(header("x-allowed"), Some("keep-me"));
+    assert_eq!(header("x-blocked"), None);
+    assert_eq!(header("authorization"), Some("Bearer sk-allow"));
+}
+
+// Response header sanitization: hop-by-hop and x-oagw-* headers stripped from upstream response.
+#[tokio::test]
+async fn proxy_response_headers_sanitized() {
+    let h = AppHarness::builder().build().await;
+    let ctx = h.security_context().clone();
+
+    let upstream = h
+        .facade()
+        .create_upstream(
+            ctx.clone(),
+            CreateUpstreamRequest::builder(
+                Server {
+                    endpoints: vec![Endpoint {
+                        scheme: Scheme::Http,
+                        host: "127.0.0.1".into(),
+                        port: h.mock_port(),
+                    }],
+                },
+                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            )
+            .alias("resp-hdr-test")
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    h.facade()
+        .create_route(
+            ctx.clone(),
+            CreateRouteRequest::builder(
+                upstream.id,
+                MatchRules {
+                    http: Some(HttpMatch {
+                        methods: vec![HttpMethod::Get],
+                        path: "/response-headers".into(),
+                        query_allowlist: vec![],
+                        path_suffix_mode: PathSuffixMode::Append,
+                    }),
+                    grpc: None,
+                },
+            )
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri("/resp-hdr-test/response-headers")
+        .body(Body::Empty)
+        .unwrap();
+    let response = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let resp_headers = response.headers();
+
+    // Safe headers should be preserved.
+    assert_eq!(
+        resp_headers.get("x-custom-safe").unwrap(),
+        "keep-me",
+        "safe custom header should be forwarded"
+    );
+    assert!(
+        resp_headers.get("content-type").is_some(),
+        "content-type should be preserved"
+    );
+
+    // Hop-by-hop headers should be stripped.
+    assert!(
+        resp_headers.get("proxy-authenticate").is_none(),
+        "proxy-authenticate should be stripped from response"
+    );
+    assert!(
+        resp_headers.get("trailer").is_none(),
+        "trailer should be stripped from response"
+    );
+    assert!(
+        resp_headers.get("upgrade").is_none(),
+        "upgrade should be stripped from response"
+    );
+
+    // Internal x-oagw-* headers should be stripped.
+    assert!(
+        resp_headers.get("x-oagw-debug").is_none(),
+        "x-oagw-debug should be stripped from response"
+    );
+    assert!(
+        resp_headers.get("x-oagw-trace-id").is_none(),
+        "x-oagw-trace-id should be stripped from response"
+    );
+}
+
+// 8.10: path_suffix_mode=disabled rejects suffix; append succeeds.
+#[tokio::test]
+async fn proxy_path_suffix_disabled_rejects_extra_path() {
+    let h = AppHarness::builder().build().await;
+    let ctx = h.security_context().clone();
+
+    let upstream = h
+        .facade()
+        .create_upstream(
+            ctx.clone(),
+            CreateUpstreamRequest::builder(
+                Server {
+                    endpoints: vec![Endpoint {
+                        scheme: Scheme::Http,
+                        host: "127.0.0.1".into(),
+                        port: h.mock_port(),
+                    }],
+                },
+                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            )
+            .alias("psm-test")
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    h.facade()
+        .create_route(
+            ctx.clone(),
+            CreateRouteRequest::builder(
+                upstream.id,
+                MatchRules {
+                    http: Some(HttpMatch {
+                        methods: vec![HttpMethod::Get],
+                        path: "/v1/models".into(),
+                        query_allowlist: vec![],
+                        path_suffix_mode: PathSuffixMode::Disabled,
+                    }),
+                    grpc: None,
+                },
+            )
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    // Exact path succeeds.
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri("/psm-test/v1/models")
+        .body(Body::Empty)
+        .unwrap();
+    let response = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Extra suffix rejected with 400.
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri("/psm-test/v1/models/gpt-4")
+        .body(Body::Empty)
         .unwrap();
     match h.facade().proxy_request(ctx.clone(), req).await {
         Err(err) => assert!(matches!(
             err,
-            oagw_sdk::error::ServiceGatewayError::RequestTimeout { .. }
+            oagw_sdk::error::ServiceGatewayError::ValidationError { .. }
         )),
-        Ok(_) => panic!("expected timeout error"),
+        Ok(_) => panic!("expected validation error for disabled path_suffix_mode"),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Multi-endpoint load balancing integration tests
+// ---------------------------------------------------------------------------
+
+// positive-2.1 (custom-header-routing), positive-2.10 (upstreams): Round-robin distribution across 2 endpoints.
+//
+// Uses a single mock on 127.0.0.1 with two identical endpoint entries so the
+// test works on all platforms (macOS only has 127.0.0.1 on loopback by default).
+// Actual round-robin distribution across distinct backends is covered by unit
+// tests in pingora_proxy.rs (`select_round_robin_distribution`) and service.rs
+// (`select_endpoint_round_robin_fallback`).  This integration test verifies the
+// full proxy pipeline succeeds with a multi-endpoint upstream configuration.
+#[tokio::test]
+async fn proxy_multi_endpoint_round_robin() {
+    let mock = MockUpstream::start().await;
+    let port = mock.addr().port();
+
+    let h = AppHarness::builder().build().await;
+    let ctx = h.security_context().clone();
+
+    let upstream = h
+        .facade()
+        .create_upstream(
+            ctx.clone(),
+            CreateUpstreamRequest::builder(
+                Server {
+                    endpoints: vec![
+                        Endpoint {
+                            scheme: Scheme::Http,
+                            host: "127.0.0.1".into(),
+                            port,
+                        },
+                        Endpoint {
+                            scheme: Scheme::Http,
+                            host: "127.0.0.1".into(),
+                            port,
+                        },
+                    ],
+                },
+                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            )
+            .alias("rr-test")
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    h.facade()
+        .create_route(
+            ctx.clone(),
+            CreateRouteRequest::builder(
+                upstream.id,
+                MatchRules {
+                    http: Some(HttpMatch {
+                        methods: vec![HttpMethod::Get],
+                        path: "/v1/models".into(),
+                        query_allowlist: vec![],
+                        path_suffix_mode: PathSuffixMode::Append,
+                    }),
+                    grpc: None,
+                },
+            )
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    // Send 4 requests — all reach the single mock via the multi-endpoint pool.
+    for _ in 0..4 {
+        let req = http::Request::builder()
+            .method(Method::GET)
+            .uri("/rr-test/v1/models")
+            .body(Body::Empty)
+            .unwrap();
+        let response = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
     }
+
+    assert_eq!(
+        mock.recorded_requests().await.len(),
+        4,
+        "mock should have received all 4 requests"
+    );
 }
 
-// 8.9: Query allowlist enforcement.
+// positive-2.2 (custom-header-routing): X-OAGW-Target-Host explicit selection.
+//
+// Uses a single mock on 127.0.0.1 with two identical endpoint entries so the
+// test works on all platforms (macOS only has 127.0.0.1 on loopback by default).
+// This test verifies the header is accepted without error; actual host-based
+// routing is covered by the unit test `select_endpoint_target_host_matches` in
+// service.rs.
 #[tokio::test]
-async fn proxy_query_allowlist_allowed_param_succeeds() {
+async fn proxy_target_host_header_selects_endpoint() {
+    let mock = MockUpstream::start().await;
+    let port = mock.addr().port();
+
     let h = AppHarness::builder().build().await;
     let ctx = h.security_context().clone();
 
@@ -546,15 +2886,22 @@ async fn proxy_query_allowlist_allowed_param_succeeds() {
             ctx.clone(),
             CreateUpstreamRequest::builder(
                 Server {
-                    endpoints: vec![Endpoint {
-                        scheme: Scheme::Http,
-                        host: "127.0.0.1".into(),
-                        port: h.mock_port(),
-                    }],
+                    endpoints: vec![
+                        Endpoint {
+                            scheme: Scheme::Http,
+                            host: "127.0.0.1".into(),
+                            port,
+                        },
+                        Endpoint {
+                            scheme: Scheme::Http,
+                            host: "127.0.0.1".into(),
+                            port,
+                        },
+                    ],
                 },
                 "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
             )
-            .alias("ql-test")
+            .alias("target-host-test")
             .build(),
         )
         .await
@@ -569,7 +2916,7 @@ async fn proxy_query_allowlist_allowed_param_succeeds() {
                     http: Some(HttpMatch {
                         methods: vec![HttpMethod::Get],
                         path: "/v1/models".into(),
-                        query_allowlist: vec!["version".into()],
+                        query_allowlist: vec![],
                         path_suffix_mode: PathSuffixMode::Append,
                     }),
                     grpc: None,
@@ -580,17 +2927,20 @@ async fn proxy_query_allowlist_allowed_param_succeeds() {
         .await
         .unwrap();
 
+    // Send request with X-OAGW-Target-Host header selecting endpoint host.
     let req = http::Request::builder()
         .method(Method::GET)
-        .uri("/ql-test/v1/models?version=2")
+        .uri("/target-host-test/v1/models")
+        .header("x-oagw-target-host", "127.0.0.1")
         .body(Body::Empty)
         .unwrap();
     let response = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
     assert_eq!(response.status(), StatusCode::OK);
 }
 
+// negative-2.1 (custom-header-routing): X-OAGW-Target-Host validation — unknown host returns error.
 #[tokio::test]
-async fn proxy_query_allowlist_unknown_param_rejected() {
+async fn proxy_target_host_unknown_returns_error() {
     let h = AppHarness::builder().build().await;
     let ctx = h.security_context().clone();
 
@@ -608,7 +2958,7 @@ async fn proxy_query_allowlist_unknown_param_rejected() {
                 },
                 "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
             )
-            .alias("ql-reject")
+            .alias("target-host-unknown")
             .build(),
         )
         .await
@@ -623,7 +2973,7 @@ async fn proxy_query_allowlist_unknown_param_rejected() {
                     http: Some(HttpMatch {
                         methods: vec![HttpMethod::Get],
                         path: "/v1/models".into(),
-                        query_allowlist: vec!["version".into()],
+                        query_allowlist: vec![],
                         path_suffix_mode: PathSuffixMode::Append,
                     }),
                     grpc: None,
@@ -636,97 +2986,208 @@ async fn proxy_query_allowlist_unknown_param_rejected() {
 
     let req = http::Request::builder()
         .method(Method::GET)
-        .uri("/ql-reject/v1/models?version=2&debug=true")
+        .uri("/target-host-unknown/v1/models")
+        .header("x-oagw-target-host", "unknown.com")
         .body(Body::Empty)
         .unwrap();
+
     match h.facade().proxy_request(ctx.clone(), req).await {
-        Err(err) => assert!(matches!(
-            err,
-            oagw_sdk::error::ServiceGatewayError::ValidationError { .. }
-        )),
-        Ok(_) => panic!("expected validation error"),
+        Err(err) => assert!(
+            matches!(
+                err,
+                oagw_sdk::error::ServiceGatewayError::UnknownTargetHost { .. }
+            ),
+            "expected UnknownTargetHost, got: {err:?}"
+        ),
+        Ok(_) => panic!("expected error for unknown target host"),
     }
 }
 
-// 13.5: Non-existent auth plugin ID returns error through proxy pipeline.
+// negative-2.10 (upstreams): All backends unreachable returns connection error (502).
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn proxy_all_backends_unreachable() {
+    let h = AppHarness::builder()
+        .with_request_timeout(std::time::Duration::from_secs(5))
+        .build()
+        .await;
+    let ctx = h.security_context().clone();
+
+    // Ports 19991/19992 are unlikely to be listening.
+    let upstream = h
+        .facade()
+        .create_upstream(
+            ctx.clone(),
+            CreateUpstreamRequest::builder(
+                Server {
+                    endpoints: vec![Endpoint {
+                        scheme: Scheme::Http,
+                        host: "127.0.0.1".into(),
+                        port: 19991,
+                    }],
+                },
+                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            )
+            .alias("unreachable-test")
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    h.facade()
+        .create_route(
+            ctx.clone(),
+            CreateRouteRequest::builder(
+                upstream.id,
+                MatchRules {
+                    http: Some(HttpMatch {
+                        methods: vec![HttpMethod::Get],
+                        path: "/v1/models".into(),
+                        query_allowlist: vec![],
+                        path_suffix_mode: PathSuffixMode::Append,
+                    }),
+                    grpc: None,
+                },
+            )
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri("/unreachable-test/v1/models")
+        .body(Body::Empty)
+        .unwrap();
+
+    match h.facade().proxy_request(ctx.clone(), req).await {
+        Err(err) => assert!(
+            matches!(
+                err,
+                oagw_sdk::error::ServiceGatewayError::DownstreamError { .. }
+            ),
+            "expected DownstreamError for unreachable backend, got: {err:?}"
+        ),
+        Ok(resp) => {
+            // Pingora may return a 502 response directly via fail_to_proxy.
+            assert!(
+                resp.status() == StatusCode::BAD_GATEWAY
+                    || resp.status() == StatusCode::GATEWAY_TIMEOUT,
+                "expected 502 or 504, got: {}",
+                resp.status()
+            );
+        }
+    }
+}
+
+// positive-2.13 (upstreams): CRUD invalidation — update upstream endpoints, verify new endpoint used.
 #[tokio::test]
-async fn proxy_nonexistent_auth_plugin_returns_error() {
+async fn proxy_crud_invalidation_after_update() {
+    let mock_a = MockUpstream::start().await;
+    let mock_b = MockUpstream::start().await;
+    let port_a = mock_a.addr().port();
+    let port_b = mock_b.addr().port();
+
     let h = AppHarness::builder().build().await;
     let ctx = h.security_context().clone();
 
-    let upstream = h
-        .facade()
-        .create_upstream(
-            ctx.clone(),
-            CreateUpstreamRequest::builder(
-                Server {
-                    endpoints: vec![Endpoint {
-                        scheme: Scheme::Http,
-                        host: "127.0.0.1".into(),
-                        port: h.mock_port(),
-                    }],
-                },
-                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
-            )
-            .alias("bad-auth")
-            .auth(oagw_sdk::AuthConfig {
-                plugin_type: "gts.x.core.oagw.auth.v1~nonexistent.plugin.v1".into(),
-                sharing: SharingMode::Private,
-                config: None,
-            })
-            .build(),
-        )
-        .await
+    // Create upstream pointing to mock_a only.
+    let resp = h
+        .api_v1()
+        .post_upstream()
+        .with_body(json!({
+            "server": {
+                "endpoints": [{"host": "127.0.0.1", "port": port_a, "scheme": "http"}]
+            },
+            "protocol": "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            "alias": "crud-invalidation",
+            "enabled": true,
+            "tags": []
+        }))
+        .expect_status(201)
+        .await;
+    let upstream_id = resp.json()["id"].as_str().unwrap().to_string();
+
+    h.api_v1()
+        .post_route()
+        .with_body(json!({
+            "upstream_id": &upstream_id,
+            "match": {
+                "http": {
+                    "methods": ["GET"],
+                    "path": "/v1/models"
+                }
+            },
+            "enabled": true,
+            "tags": [],
+            "priority": 0
+        }))
+        .expect_status(201)
+        .await;
+
+    // Proxy to mock_a.
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri("/crud-invalidation/v1/models")
+        .body(Body::Empty)
         .unwrap();
+    let response = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        mock_a.recorded_requests().await.len(),
+        1,
+        "mock_a should have received 1 request"
+    );
+    assert_eq!(
+        mock_b.recorded_requests().await.len(),
+        0,
+        "mock_b should have received 0 requests"
+    );
 
-    h.facade()
-        .create_route(
-            ctx.clone(),
-            CreateRouteRequest::builder(
-                upstream.id,
-                MatchRules {
-                    http: Some(HttpMatch {
-                        methods: vec![HttpMethod::Get],
-                        path: "/v1/test".into(),
-                        query_allowlist: vec![],
-                        path_suffix_mode: PathSuffixMode::Append,
-                    }),
-                    grpc: None,
-                },
-            )
-            .build(),
-        )
-        .await
-        .unwrap();
+    // Update upstream to point to mock_b via REST API (triggers invalidation).
+    h.api_v1()
+        .put_upstream(&upstream_id)
+        .with_body(json!({
+            "server": {
+                "endpoints": [{"host": "127.0.0.1", "port": port_b, "scheme": "http"}]
+            },
+            "protocol": "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            "alias": "crud-invalidation",
+            "enabled": true,
+            "tags": []
+        }))
+        .expect_status(200)
+        .await;
 
+    // Proxy again — should now go to mock_b (cache was invalidated).
     let req = http::Request::builder()
         .method(Method::GET)
-        .uri("/bad-auth/v1/test")
+        .uri("/crud-invalidation/v1/models")
         .body(Body::Empty)
         .unwrap();
-    match h.facade().proxy_request(ctx.clone(), req).await {
-        Err(err) => assert!(matches!(
-            err,
-            oagw_sdk::error::ServiceGatewayError::AuthenticationFailed { .. }
-        )),
-        Ok(_) => panic!("expected authentication error for non-existent plugin"),
-    }
+    let response = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(
+        !mock_b.recorded_requests().await.is_empty(),
+        "mock_b should have received at least 1 request after update"
+    );
 }
 
-// 13.6: Assert on recorded_requests() URI and body content.
+// Demonstrate MockGuard pattern for custom per-test responses
 #[tokio::test]
-async fn proxy_recorded_request_has_correct_uri_and_body() {
+async fn proxy_with_mock_guard_custom_response() {
+    // Create a MockGuard for test-isolated mock responses
     let mut guard = MockGuard::new();
+
+    // Register a custom response at a unique path
     guard.mock(
         "POST",
-        "/v1/chat/completions",
+        "/custom/endpoint",
         MockResponse {
             status: 200,
             headers: vec![("content-type".into(), "application/json".into())],
             body: MockBody::Json(json!({
-                "id": "chatcmpl-rec-test",
-                "object": "chat.completion",
-                "choices": [{"index": 0, "message": {"role": "assistant", "content": "ok"}, "finish_reason": "stop"}]
+                "custom": "response",
+                "test": "data"
             })),
         },
     );
@@ -734,6 +3195,7 @@ async fn proxy_recorded_request_has_correct_uri_and_body() {
     let h = AppHarness::builder().build().await;
     let ctx = h.security_context().clone();
 
+    // Create upstream pointing to mock server
     let upstream = h
         .facade()
         .create_upstream(
@@ -748,12 +3210,13 @@ async fn proxy_recorded_request_has_correct_uri_and_body() {
                 },
                 "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
             )
-            .alias("rec-test")
+            .alias("guard-test")
             .build(),
         )
         .await
         .unwrap();
 
+    // Create route using the guard's prefixed path
     h.facade()
         .create_route(
             ctx.clone(),
@@ -762,7 +3225,7 @@ async fn proxy_recorded_request_has_correct_uri_and_body() {
                 MatchRules {
                     http: Some(HttpMatch {
                         methods: vec![HttpMethod::Post],
-                        path: guard.path("/v1/chat/completions"),
+                        path: guard.path("/custom/endpoint"),
                         query_allowlist: vec![],
                         path_suffix_mode: PathSuffixMode::Disabled,
                     }),
@@ -774,29 +3237,31 @@ async fn proxy_recorded_request_has_correct_uri_and_body() {
         .await
         .unwrap();
 
-    let body_payload = r#"{"model":"gpt-4","messages":[{"role":"user","content":"Hello"}]}"#;
+    // Make request to the prefixed path
     let req = http::Request::builder()
         .method(Method::POST)
-        .uri(format!("/rec-test{}", guard.path("/v1/chat/completions")))
+        .uri(format!("/guard-test{}", guard.path("/custom/endpoint")))
         .header(http::header::CONTENT_TYPE, "application/json")
-        .body(Body::from(body_payload))
+        .body(Body::from(r#"{"test":"input"}"#))
         .unwrap();
-    let response = h.facade().proxy_request(ctx, req).await.unwrap();
+
+    let response = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
     assert_eq!(response.status(), StatusCode::OK);
 
+    let body_bytes = response.into_body().into_bytes().await.unwrap();
+    let body_json: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+    assert_eq!(body_json["custom"], "response");
+    assert_eq!(body_json["test"], "data");
+
+    // Verify request was recorded (filtered by guard prefix)
     let recorded = guard.recorded_requests().await;
     assert_eq!(recorded.len(), 1);
-    assert!(recorded[0].uri.ends_with("/v1/chat/completions"));
-    assert_eq!(recorded[0].method, "POST");
-
-    let body_str = String::from_utf8(recorded[0].body.clone()).unwrap();
-    assert!(body_str.contains("gpt-4"));
-    assert!(body_str.contains("Hello"));
+    assert!(recorded[0].uri.contains("/custom/endpoint"));
 }
 
-// Response header sanitization: hop-by-hop and x-oagw-* headers stripped from upstream response.
-#[tokio::test]
-async fn proxy_response_headers_sanitized() {
+// WebSocket upgrade request returns 101 Switching Protocols when upstream accepts.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn proxy_websocket_upgrade_returns_101() {
     let h = AppHarness::builder().build().await;
     let ctx = h.security_context().clone();
 
@@ -814,7 +3279,7 @@ async fn proxy_response_headers_sanitized() {
                 },
                 "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
             )
-            .alias("resp-hdr-test")
+            .alias("ws-echo-test")
             .build(),
         )
         .await
@@ -828,7 +3293,7 @@ async fn proxy_response_headers_sanitized() {
                 MatchRules {
                     http: Some(HttpMatch {
                         methods: vec![HttpMethod::Get],
-                        path: "/response-headers".into(),
+                        path: "/ws/echo".into(),
                         query_allowlist: vec![],
                         path_suffix_mode: PathSuffixMode::Append,
                     }),
@@ -840,164 +3305,123 @@ async fn proxy_response_headers_sanitized() {
         .await
         .unwrap();
 
+    // Send a WebSocket upgrade request through the data plane.
+    // The mock server has a ws_echo handler at /ws/echo.
     let req = http::Request::builder()
         .method(Method::GET)
-        .uri("/resp-hdr-test/response-headers")
+        .uri("/ws-echo-test/ws/echo")
+        .header("upgrade", "websocket")
+        .header("connection", "Upgrade")
+        .header("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ==")
+        .header("sec-websocket-version", "13")
         .body(Body::Empty)
         .unwrap();
-    let response = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
-    assert_eq!(response.status(), StatusCode::OK);
 
-    let resp_headers = response.headers();
+    let resp = h
+        .facade()
+        .proxy_request(ctx.clone(), req)
+        .await
+        .expect("WebSocket upgrade should succeed");
 
-    // Safe headers should be preserved.
     assert_eq!(
-        resp_headers.get("x-custom-safe").unwrap(),
-        "keep-me",
-        "safe custom header should be forwarded"
-    );
-    assert!(
-        resp_headers.get("content-type").is_some(),
-        "content-type should be preserved"
-    );
-
-    // Hop-by-hop headers should be stripped.
-    assert!(
-        resp_headers.get("proxy-authenticate").is_none(),
-        "proxy-authenticate should be stripped from response"
-    );
-    assert!(
-        resp_headers.get("trailer").is_none(),
-        "trailer should be stripped from response"
-    );
-    assert!(
-        resp_headers.get("upgrade").is_none(),
-        "upgrade should be stripped from response"
-    );
-
-    // Internal x-oagw-* headers should be stripped.
-    assert!(
-        resp_headers.get("x-oagw-debug").is_none(),
-        "x-oagw-debug should be stripped from response"
+        resp.status(),
+        http::StatusCode::SWITCHING_PROTOCOLS,
+        "expected 101, got {}",
+        resp.status()
     );
-    assert!(
-        resp_headers.get("x-oagw-trace-id").is_none(),
-        "x-oagw-trace-id should be stripped from response"
+    assert_eq!(
+        resp.headers().get("upgrade").and_then(|v| v.to_str().ok()),
+        Some("websocket"),
+        "response should contain Upgrade: websocket header"
     );
 }
 
-// 8.10: path_suffix_mode=disabled rejects suffix; append succeeds.
-#[tokio::test]
-async fn proxy_path_suffix_disabled_rejects_extra_path() {
+// In-process callers exchange messages with the upstream through the upgrade handle.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn proxy_websocket_in_process_message_stream() {
     let h = AppHarness::builder().build().await;
     let ctx = h.security_context().clone();
+    setup_ws_upstream(&h, "ws-in-process").await;
 
-    let upstream = h
-        .facade()
-        .create_upstream(
-            ctx.clone(),
-            CreateUpstreamRequest::builder(
-                Server {
-                    endpoints: vec![Endpoint {
-                        scheme: Scheme::Http,
-                        host: "127.0.0.1".into(),
-                        port: h.mock_port(),
-                    }],
-                },
-                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
-            )
-            .alias("psm-test")
-            .build(),
-        )
-        .await
-        .unwrap();
-
-    h.facade()
-        .create_route(
-            ctx.clone(),
-            CreateRouteRequest::builder(
-                upstream.id,
-                MatchRules {
-                    http: Some(HttpMatch {
-                        methods: vec![HttpMethod::Get],
-                        path: "/v1/models".into(),
-                        query_allowlist: vec![],
-                        path_suffix_mode: PathSuffixMode::Disabled,
-                    }),
-                    grpc: None,
-                },
-            )
-            .build(),
-        )
-        .await
-        .unwrap();
-
-    // Exact path succeeds.
-    let req = http::Request::builder()
-        .method(Method::GET)
-        .uri("/psm-test/v1/models")
-        .body(Body::Empty)
-        .unwrap();
-    let response = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
-    assert_eq!(response.status(), StatusCode::OK);
-
-    // Extra suffix rejected with 400.
     let req = http::Request::builder()
         .method(Method::GET)
-        .uri("/psm-test/v1/models/gpt-4")
+        .uri("/ws-in-process/ws/echo")
+        .header("upgrade", "websocket")
+        .header("connection", "Upgrade")
+        .header("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ==")
+        .header("sec-websocket-version", "13")
         .body(Body::Empty)
         .unwrap();
-    match h.facade().proxy_request(ctx.clone(), req).await {
-        Err(err) => assert!(matches!(
-            err,
-            oagw_sdk::error::ServiceGatewayError::ValidationError { .. }
-        )),
-        Ok(_) => panic!("expected validation error for disabled path_suffix_mode"),
-    }
-}
+    let resp = h.facade().proxy_request(ctx, req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::SWITCHING_PROTOCOLS);
 
-// ---------------------------------------------------------------------------
-// Multi-endpoint load balancing integration tests
-// ---------------------------------------------------------------------------
+    let upgrade = resp
+        .extensions()
+        .get::<WebSocketUpgrade>()
+        .expect("101 response should carry the upgraded connection");
+    let ws = upgrade.take().expect("connection should be available");
+    assert!(
+        upgrade.take().is_none(),
+        "connection can only be taken once"
+    );
 
-// positive-2.1 (custom-header-routing), positive-2.10 (upstreams): Round-robin distribution across 2 endpoints.
-//
-// Uses a single mock on 127.0.0.1 with two identical endpoint entries so the
-// test works on all platforms (macOS only has 127.0.0.1 on loopback by default).
-// Actual round-robin distribution across distinct backends is covered by unit
-// tests in pingora_proxy.rs (`select_round_robin_distribution`) and service.rs
-// (`select_endpoint_round_robin_fallback`).  This integration test verifies the
-// full proxy pipeline succeeds with a multi-endpoint upstream configuration.
-#[tokio::test]
-async fn proxy_multi_endpoint_round_robin() {
-    let mock = MockUpstream::start().await;
-    let port = mock.addr().port();
+    let (mut tx, mut rx) = ws.split();
+    tx.send(&WebSocketMessage::Text("hello".into()))
+        .await
+        .unwrap();
+    assert_eq!(
+        rx.recv().await.unwrap().unwrap(),
+        WebSocketMessage::Text("hello".into())
+    );
+    tx.send(&WebSocketMessage::Binary(vec![0, 1, 2]))
+        .await
+        .unwrap();
+    assert_eq!(
+        rx.recv().await.unwrap().unwrap(),
+        WebSocketMessage::Binary(vec![0, 1, 2])
+    );
+
+    // Closing ends the receive side once the upstream answers the handshake.
+    tx.send(&WebSocketMessage::Close(Some(WebSocketCloseFrame {
+        code: 1000,
+        reason: "done".into(),
+    })))
+    .await
+    .unwrap();
+    let end = tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+        .await
+        .expect("close handshake should complete");
+    assert!(end.is_none(), "expected end of stream, got {end:?}");
+    assert!(
+        tx.send(&WebSocketMessage::Text("late".into()))
+            .await
+            .is_err(),
+        "nothing may be sent after Close"
+    );
+}
 
+// WebSocket upgrade rejected by upstream returns 502 ProtocolError with gateway error source.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn proxy_websocket_upgrade_rejected_returns_502_protocol_error() {
     let h = AppHarness::builder().build().await;
     let ctx = h.security_context().clone();
 
+    // Point at /v1/models — a plain GET endpoint, not a WebSocket handler.
     let upstream = h
         .facade()
         .create_upstream(
             ctx.clone(),
             CreateUpstreamRequest::builder(
                 Server {
-                    endpoints: vec![
-                        Endpoint {
-                            scheme: Scheme::Http,
-                            host: "127.0.0.1".into(),
-                            port,
-                        },
-                        Endpoint {
-                            scheme: Scheme::Http,
-                            host: "127.0.0.1".into(),
-                            port,
-                        },
-                    ],
+                    endpoints: vec![Endpoint {
+                        scheme: Scheme::Http,
+                        host: "127.0.0.1".into(),
+                        port: h.mock_port(),
+                    }],
                 },
                 "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
             )
-            .alias("rr-test")
+            .alias("ws-reject-test")
             .build(),
         )
         .await
@@ -1023,37 +3447,79 @@ async fn proxy_multi_endpoint_round_robin() {
         .await
         .unwrap();
 
-    // Send 4 requests — all reach the single mock via the multi-endpoint pool.
-    for _ in 0..4 {
-        let req = http::Request::builder()
-            .method(Method::GET)
-            .uri("/rr-test/v1/models")
-            .body(Body::Empty)
-            .unwrap();
-        let response = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
-        assert_eq!(response.status(), StatusCode::OK);
-    }
+    // Send a WebSocket upgrade request to the non-WebSocket upstream.
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri("/ws-reject-test/v1/models")
+        .header("upgrade", "websocket")
+        .header("connection", "Upgrade")
+        .header("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ==")
+        .header("sec-websocket-version", "13")
+        .body(Body::Empty)
+        .unwrap();
 
-    assert_eq!(
-        mock.recorded_requests().await.len(),
-        4,
-        "mock should have received all 4 requests"
+    let err = h
+        .facade()
+        .proxy_request(ctx, req)
+        .await
+        .expect_err("expected ProtocolError for rejected WebSocket upgrade");
+
+    assert!(
+        matches!(
+            err,
+            oagw_sdk::error::ServiceGatewayError::ProtocolError { .. }
+        ),
+        "expected ProtocolError, got {err:?}"
     );
 }
 
-// positive-2.2 (custom-header-routing): X-OAGW-Target-Host explicit selection.
-//
-// Uses a single mock on 127.0.0.1 with two identical endpoint entries so the
-// test works on all platforms (macOS only has 127.0.0.1 on loopback by default).
-// This test verifies the header is accepted without error; actual host-based
-// routing is covered by the unit test `select_endpoint_target_host_matches` in
-// service.rs.
-#[tokio::test]
-async fn proxy_target_host_header_selects_endpoint() {
-    let mock = MockUpstream::start().await;
-    let port = mock.addr().port();
+// WebSocket E2E: upgrade through real TCP, send text frame, receive echo.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn proxy_websocket_e2e_echo() {
+    use tokio::io::AsyncWriteExt;
 
     let h = AppHarness::builder().build().await;
+    setup_ws_upstream(&h, "ws-e2e").await;
+    let (addr, server_handle) = start_oagw_server(&h).await;
+
+    let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+    ws_handshake(&mut stream, "/oagw/v1/proxy/ws-e2e/ws/echo").await;
+
+    // Send a masked text frame containing "hello" and read the echo.
+    let frame = build_masked_frame(0x1, b"hello");
+    stream.write_all(&frame).await.unwrap();
+    let (opcode, payload) = read_ws_frame(&mut stream).await.expect("echo frame");
+    assert_eq!(opcode, 0x1, "expected text frame opcode");
+    assert_eq!(payload, b"hello", "echoed payload mismatch");
+
+    // Send a close frame with code 1000 (Normal Closure).
+    let close_frame = build_masked_frame(0x8, &1000u16.to_be_bytes());
+    stream.write_all(&close_frame).await.unwrap();
+    if let Some((op, _)) = read_ws_frame(&mut stream).await {
+        assert_eq!(op, 0x8, "expected close frame opcode");
+    }
+
+    server_handle.abort();
+}
+
+// 14.2: Auth credentials are injected into the outbound WebSocket upgrade request.
+#[tokio::test]
+async fn proxy_websocket_auth_injected_during_handshake() {
+    let mut guard = MockGuard::new();
+    guard.mock(
+        "GET",
+        "/ws/echo",
+        MockResponse {
+            status: 200,
+            headers: vec![("content-type".into(), "application/json".into())],
+            body: MockBody::Json(json!({"ok": true})),
+        },
+    );
+
+    let h = AppHarness::builder()
+        .with_credentials(vec![("cred://ws-key".into(), "sk-ws-secret".into())])
+        .build()
+        .await;
     let ctx = h.security_context().clone();
 
     let upstream = h
@@ -1062,22 +3528,28 @@ async fn proxy_target_host_header_selects_endpoint() {
             ctx.clone(),
             CreateUpstreamRequest::builder(
                 Server {
-                    endpoints: vec![
-                        Endpoint {
-                            scheme: Scheme::Http,
-                            host: "127.0.0.1".into(),
-                            port,
-                        },
-                        Endpoint {
-                            scheme: Scheme::Http,
-                            host: "127.0.0.1".into(),
-                            port,
-                        },
-                    ],
+                    endpoints: vec![Endpoint {
+                        scheme: Scheme::Http,
+                        host: "127.0.0.1".into(),
+                        port: h.mock_port(),
+                    }],
                 },
                 "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
             )
-            .alias("target-host-test")
+            .alias("ws-auth-test")
+            .auth(oagw_sdk::AuthConfig {
+                plugin_type: APIKEY_AUTH_PLUGIN_ID.into(),
+                sharing: SharingMode::Private,
+                config: Some(
+                    [
+                        ("header".into(), "authorization".into()),
+                        ("prefix".into(), "Bearer ".into()),
+                        ("secret_ref".into(), "cred://ws-key".into()),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ),
+            })
             .build(),
         )
         .await
@@ -1091,7 +3563,7 @@ async fn proxy_target_host_header_selects_endpoint() {
                 MatchRules {
                     http: Some(HttpMatch {
                         methods: vec![HttpMethod::Get],
-                        path: "/v1/models".into(),
+                        path: guard.path("/ws/echo"),
                         query_allowlist: vec![],
                         path_suffix_mode: PathSuffixMode::Append,
                     }),
@@ -1103,20 +3575,65 @@ async fn proxy_target_host_header_selects_endpoint() {
         .await
         .unwrap();
 
-    // Send request with X-OAGW-Target-Host header selecting endpoint host.
     let req = http::Request::builder()
         .method(Method::GET)
-        .uri("/target-host-test/v1/models")
-        .header("x-oagw-target-host", "127.0.0.1")
+        .uri(format!("/ws-auth-test{}", guard.path("/ws/echo")))
+        .header("upgrade", "websocket")
+        .header("connection", "Upgrade")
+        .header("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ==")
+        .header("sec-websocket-version", "13")
         .body(Body::Empty)
         .unwrap();
-    let response = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
-    assert_eq!(response.status(), StatusCode::OK);
+
+    // The MockGuard returns 200 (it can't do a real WebSocket handshake),
+    // so the proxy correctly returns ProtocolError. The upgrade request was
+    // already sent to upstream before the response is checked, so
+    // guard.recorded_requests() still captures the outbound headers we need.
+    let err = h
+        .facade()
+        .proxy_request(ctx, req)
+        .await
+        .expect_err("mock returns 200, so upgrade should fail with ProtocolError");
+    assert!(
+        matches!(
+            err,
+            oagw_sdk::error::ServiceGatewayError::ProtocolError { .. }
+        ),
+        "expected ProtocolError, got {err:?}"
+    );
+
+    let recorded = guard.recorded_requests().await;
+    assert_eq!(recorded.len(), 1, "expected exactly 1 recorded request");
+
+    // Verify the auth plugin injected credentials into the upgrade request.
+    let auth_header = recorded[0]
+        .headers
+        .iter()
+        .find(|(k, _)| k == "authorization")
+        .map(|(_, v)| v.as_str())
+        .expect("authorization header missing from upgrade request");
+    assert_eq!(auth_header, "Bearer sk-ws-secret");
+
+    // Verify WebSocket-specific headers were forwarded (not stripped by auth pipeline).
+    let has_upgrade = recorded[0]
+        .headers
+        .iter()
+        .any(|(k, v)| k == "upgrade" && v == "websocket");
+    assert!(has_upgrade, "Upgrade header missing from outbound request");
+
+    let has_ws_key = recorded[0]
+        .headers
+        .iter()
+        .any(|(k, _)| k == "sec-websocket-key");
+    assert!(
+        has_ws_key,
+        "Sec-WebSocket-Key header missing from outbound request"
+    );
 }
 
-// negative-2.1 (custom-header-routing): X-OAGW-Target-Host validation — unknown host returns error.
+// 14.3: Rate limiting applies to WebSocket connection establishment.
 #[tokio::test]
-async fn proxy_target_host_unknown_returns_error() {
+async fn proxy_websocket_rate_limit_on_handshake() {
     let h = AppHarness::builder().build().await;
     let ctx = h.security_context().clone();
 
@@ -1132,9 +3649,21 @@ async fn proxy_target_host_unknown_returns_error() {
                         port: h.mock_port(),
                     }],
                 },
-                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
-            )
-            .alias("target-host-unknown")
+                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            )
+            .alias("ws-rate-limit")
+            .rate_limit(RateLimitConfig {
+                sharing: SharingMode::Private,
+                algorithm: RateLimitAlgorithm::TokenBucket,
+                sustained: SustainedRate {
+                    rate: 1,
+                    window: Window::Minute,
+                },
+                burst: Some(BurstConfig { capacity: 1 }),
+                scope: RateLimitScope::Tenant,
+                strategy: RateLimitStrategy::Reject,
+                cost: CostStrategy::Fixed(1),
+            })
             .build(),
         )
         .await
@@ -1148,7 +3677,7 @@ async fn proxy_target_host_unknown_returns_error() {
                 MatchRules {
                     http: Some(HttpMatch {
                         methods: vec![HttpMethod::Get],
-                        path: "/v1/models".into(),
+                        path: "/ws/echo".into(),
                         query_allowlist: vec![],
                         path_suffix_mode: PathSuffixMode::Append,
                     }),
@@ -1160,35 +3689,93 @@ async fn proxy_target_host_unknown_returns_error() {
         .await
         .unwrap();
 
+    // First WebSocket upgrade should succeed.
     let req = http::Request::builder()
         .method(Method::GET)
-        .uri("/target-host-unknown/v1/models")
-        .header("x-oagw-target-host", "unknown.com")
+        .uri("/ws-rate-limit/ws/echo")
+        .header("upgrade", "websocket")
+        .header("connection", "Upgrade")
+        .header("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ==")
+        .header("sec-websocket-version", "13")
         .body(Body::Empty)
         .unwrap();
+    let response = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
+    assert_eq!(
+        response.status(),
+        StatusCode::SWITCHING_PROTOCOLS,
+        "first WS upgrade should succeed with 101"
+    );
 
-    match h.facade().proxy_request(ctx.clone(), req).await {
+    // Second WebSocket upgrade within the rate window should be rejected.
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri("/ws-rate-limit/ws/echo")
+        .header("upgrade", "websocket")
+        .header("connection", "Upgrade")
+        .header("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ==")
+        .header("sec-websocket-version", "13")
+        .body(Body::Empty)
+        .unwrap();
+    match h.facade().proxy_request(ctx, req).await {
         Err(err) => assert!(
             matches!(
                 err,
-                oagw_sdk::error::ServiceGatewayError::UnknownTargetHost { .. }
+                oagw_sdk::error::ServiceGatewayError::RateLimitExceeded { .. }
             ),
-            "expected UnknownTargetHost, got: {err:?}"
+            "expected RateLimitExceeded, got: {err:?}"
+        ),
+        Ok(resp) => panic!(
+            "expected rate limit error on second WS upgrade, got status {}",
+            resp.status()
         ),
-        Ok(_) => panic!("expected error for unknown target host"),
     }
 }
 
-// negative-2.10 (upstreams): All backends unreachable returns connection error (502).
+// 14.4: WebSocket idle timeout — gateway closes the connection after inactivity.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn proxy_websocket_idle_timeout_closes_connection() {
+    use tokio::io::AsyncWriteExt;
+
+    // 1000ms instead of 200ms: the readiness probe (Ping→Pong roundtrip
+    // through proxy→upstream→proxy) can exceed 200ms under CI resource
+    // pressure, causing the idle timer to fire before the probe completes.
+    let h = AppHarness::builder()
+        .with_websocket_idle_timeout(std::time::Duration::from_secs(1))
+        .build()
+        .await;
+    setup_ws_upstream(&h, "ws-idle-timeout").await;
+    let (addr, server_handle) = start_oagw_server(&h).await;
+
+    let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+    ws_handshake(&mut stream, "/oagw/v1/proxy/ws-idle-timeout/ws/echo").await;
+
+    // Send a text frame to prove the connection is alive.
+    let frame = build_masked_frame(0x1, b"ping");
+    stream.write_all(&frame).await.unwrap();
+    let (opcode, _) = read_ws_frame(&mut stream).await.expect("echo frame");
+    assert_eq!(opcode, 0x1, "expected text frame opcode");
+
+    // Wait longer than the idle timeout (1s).
+    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+
+    // Expect EOF or close frame indicating gateway closed the connection.
+    if let Some((op, _)) = read_ws_frame(&mut stream).await {
+        // Close frame received; EOF (None) is also valid — gateway closed TCP.
+        assert_eq!(op, 0x8, "expected close frame after idle timeout");
+    }
+
+    server_handle.abort();
+}
+
+// P4 #18: Pingora fail_to_proxy produces valid RFC 9457 Problem body with correct GTS type.
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn proxy_all_backends_unreachable() {
+async fn proxy_unreachable_backend_returns_rfc9457_problem_body() {
     let h = AppHarness::builder()
         .with_request_timeout(std::time::Duration::from_secs(5))
         .build()
         .await;
     let ctx = h.security_context().clone();
 
-    // Ports 19991/19992 are unlikely to be listening.
     let upstream = h
         .facade()
         .create_upstream(
@@ -1198,12 +3785,12 @@ async fn proxy_all_backends_unreachable() {
                     endpoints: vec![Endpoint {
                         scheme: Scheme::Http,
                         host: "127.0.0.1".into(),
-                        port: 19991,
+                        port: 19993,
                     }],
                 },
                 "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
             )
-            .alias("unreachable-test")
+            .alias("rfc9457-body-test")
             .build(),
         )
         .await
@@ -1217,7 +3804,7 @@ async fn proxy_all_backends_unreachable() {
                 MatchRules {
                     http: Some(HttpMatch {
                         methods: vec![HttpMethod::Get],
-                        path: "/v1/models".into(),
+                        path: "/v1/test".into(),
                         query_allowlist: vec![],
                         path_suffix_mode: PathSuffixMode::Append,
                     }),
@@ -1231,147 +3818,81 @@ async fn proxy_all_backends_unreachable() {
 
     let req = http::Request::builder()
         .method(Method::GET)
-        .uri("/unreachable-test/v1/models")
+        .uri("/rfc9457-body-test/v1/test")
         .body(Body::Empty)
         .unwrap();
 
     match h.facade().proxy_request(ctx.clone(), req).await {
-        Err(err) => assert!(
-            matches!(
-                err,
-                oagw_sdk::error::ServiceGatewayError::DownstreamError { .. }
-            ),
-            "expected DownstreamError for unreachable backend, got: {err:?}"
-        ),
-        Ok(resp) => {
-            // Pingora may return a 502 response directly via fail_to_proxy.
+        Err(err) => {
+            // Went through DomainError path — already covered by other tests.
+            // Still verify the variant is one of the two expected for an
+            // unreachable backend (not e.g. ValidationError which would be a bug).
             assert!(
-                resp.status() == StatusCode::BAD_GATEWAY
-                    || resp.status() == StatusCode::GATEWAY_TIMEOUT,
-                "expected 502 or 504, got: {}",
-                resp.status()
+                matches!(
+                    err,
+                    oagw_sdk::error::ServiceGatewayError::DownstreamError { .. }
+                        | oagw_sdk::error::ServiceGatewayError::ConnectionTimeout { .. }
+                        | oagw_sdk::error::ServiceGatewayError::RequestTimeout { .. }
+                ),
+                "expected DownstreamError, ConnectionTimeout, or RequestTimeout for unreachable backend, got: {err:?}"
             );
         }
-    }
-}
-
-// positive-2.13 (upstreams): CRUD invalidation — update upstream endpoints, verify new endpoint used.
-#[tokio::test]
-async fn proxy_crud_invalidation_after_update() {
-    let mock_a = MockUpstream::start().await;
-    let mock_b = MockUpstream::start().await;
-    let port_a = mock_a.addr().port();
-    let port_b = mock_b.addr().port();
-
-    let h = AppHarness::builder().build().await;
-    let ctx = h.security_context().clone();
-
-    // Create upstream pointing to mock_a only.
-    let resp = h
-        .api_v1()
-        .post_upstream()
-        .with_body(json!({
-            "server": {
-                "endpoints": [{"host": "127.0.0.1", "port": port_a, "scheme": "http"}]
-            },
-            "protocol": "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
-            "alias": "crud-invalidation",
-            "enabled": true,
-            "tags": []
-        }))
-        .expect_status(201)
-        .await;
-    let upstream_id = resp.json()["id"].as_str().unwrap().to_string();
+        Ok(resp) => {
+            // Pingora wrote the response directly via fail_to_proxy.
+            let status = resp.status();
+            assert!(
+                status == StatusCode::BAD_GATEWAY || status == StatusCode::GATEWAY_TIMEOUT,
+                "expected 502 or 504, got: {status}"
+            );
 
-    h.api_v1()
-        .post_route()
-        .with_body(json!({
-            "upstream_id": &upstream_id,
-            "match": {
-                "http": {
-                    "methods": ["GET"],
-                    "path": "/v1/models"
-                }
-            },
-            "enabled": true,
-            "tags": [],
-            "priority": 0
-        }))
-        .expect_status(201)
-        .await;
+            let body_bytes = resp.into_body().into_bytes().await.unwrap();
+            let body: serde_json::Value = serde_json::from_slice(&body_bytes)
+                .expect("fail_to_proxy response body should be valid JSON");
 
-    // Proxy to mock_a.
-    let req = http::Request::builder()
-        .method(Method::GET)
-        .uri("/crud-invalidation/v1/models")
-        .body(Body::Empty)
-        .unwrap();
-    let response = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
-    assert_eq!(response.status(), StatusCode::OK);
-    assert_eq!(
-        mock_a.recorded_requests().await.len(),
-        1,
-        "mock_a should have received 1 request"
-    );
-    assert_eq!(
-        mock_b.recorded_requests().await.len(),
-        0,
-        "mock_b should have received 0 requests"
-    );
+            // Must have all RFC 9457 Problem fields.
+            assert!(body.get("type").is_some(), "missing 'type' field");
+            assert!(body.get("title").is_some(), "missing 'title' field");
+            assert!(body.get("status").is_some(), "missing 'status' field");
+            assert!(body.get("detail").is_some(), "missing 'detail' field");
+            assert!(body.get("instance").is_some(), "missing 'instance' field");
 
-    // Update upstream to point to mock_b via REST API (triggers invalidation).
-    h.api_v1()
-        .put_upstream(&upstream_id)
-        .with_body(json!({
-            "server": {
-                "endpoints": [{"host": "127.0.0.1", "port": port_b, "scheme": "http"}]
-            },
-            "protocol": "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
-            "alias": "crud-invalidation",
-            "enabled": true,
-            "tags": []
-        }))
-        .expect_status(200)
-        .await;
+            // GTS type must not be about:blank.
+            let gts_type = body["type"].as_str().unwrap();
+            assert!(
+                gts_type.starts_with("gts."),
+                "expected GTS error type, got: {gts_type}"
+            );
 
-    // Proxy again — should now go to mock_b (cache was invalidated).
-    let req = http::Request::builder()
-        .method(Method::GET)
-        .uri("/crud-invalidation/v1/models")
-        .body(Body::Empty)
-        .unwrap();
-    let response = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
-    assert_eq!(response.status(), StatusCode::OK);
-    assert!(
-        !mock_b.recorded_requests().await.is_empty(),
-        "mock_b should have received at least 1 request after update"
-    );
+            // Status field in body must match HTTP status.
+            assert_eq!(
+                body["status"].as_u64().unwrap(),
+                status.as_u16() as u64,
+                "body status must match HTTP status"
+            );
+        }
+    }
 }
 
-// Demonstrate MockGuard pattern for custom per-test responses
-#[tokio::test]
-async fn proxy_with_mock_guard_custom_response() {
-    // Create a MockGuard for test-isolated mock responses
+// negative-8.1 (body-validation): Streaming body exceeding max_body_size returns 413 PayloadTooLarge.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn proxy_streaming_body_exceeding_limit_returns_413() {
     let mut guard = MockGuard::new();
-
-    // Register a custom response at a unique path
     guard.mock(
         "POST",
-        "/custom/endpoint",
-        MockResponse {
-            status: 200,
-            headers: vec![("content-type".into(), "application/json".into())],
-            body: MockBody::Json(json!({
-                "custom": "response",
-                "test": "data"
-            })),
+        "/v1/upload",
+        MockResponse {
+            status: 200,
+            headers: vec![],
+            body: MockBody::Json(serde_json::json!({"ok": true})),
         },
     );
 
-    let h = AppHarness::builder().build().await;
+    let h = AppHarness::builder()
+        .with_max_body_size(64) // tiny limit for testing
+        .build()
+        .await;
     let ctx = h.security_context().clone();
 
-    // Create upstream pointing to mock server
     let upstream = h
         .facade()
         .create_upstream(
@@ -1386,13 +3907,12 @@ async fn proxy_with_mock_guard_custom_response() {
                 },
                 "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
             )
-            .alias("guard-test")
+            .alias("body-limit-test")
             .build(),
         )
         .await
         .unwrap();
 
-    // Create route using the guard's prefixed path
     h.facade()
         .create_route(
             ctx.clone(),
@@ -1401,7 +3921,7 @@ async fn proxy_with_mock_guard_custom_response() {
                 MatchRules {
                     http: Some(HttpMatch {
                         methods: vec![HttpMethod::Post],
-                        path: guard.path("/custom/endpoint"),
+                        path: guard.path("/v1/upload"),
                         query_allowlist: vec![],
                         path_suffix_mode: PathSuffixMode::Disabled,
                     }),
@@ -1413,31 +3933,51 @@ async fn proxy_with_mock_guard_custom_response() {
         .await
         .unwrap();
 
-    // Make request to the prefixed path
+    // Build a streaming body that exceeds the 64-byte limit.
+    let chunks: Vec<Result<bytes::Bytes, oagw_sdk::body::BoxError>> = vec![
+        Ok(bytes::Bytes::from(vec![b'A'; 40])),
+        Ok(bytes::Bytes::from(vec![b'B'; 40])), // total = 80 > 64
+    ];
+    let stream: oagw_sdk::body::BodyStream = Box::pin(futures_util::stream::iter(chunks));
+    let body = Body::Stream(stream);
+
     let req = http::Request::builder()
         .method(Method::POST)
-        .uri(format!("/guard-test{}", guard.path("/custom/endpoint")))
-        .header(http::header::CONTENT_TYPE, "application/json")
-        .body(Body::from(r#"{"test":"input"}"#))
+        .uri(format!("/body-limit-test{}/v1/upload", guard.prefix()))
+        .header(http::header::CONTENT_TYPE, "application/octet-stream")
+        .body(body)
         .unwrap();
 
-    let response = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
-    assert_eq!(response.status(), StatusCode::OK);
-
-    let body_bytes = response.into_body().into_bytes().await.unwrap();
-    let body_json: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
-    assert_eq!(body_json["custom"], "response");
-    assert_eq!(body_json["test"], "data");
-
-    // Verify request was recorded (filtered by guard prefix)
-    let recorded = guard.recorded_requests().await;
-    assert_eq!(recorded.len(), 1);
-    assert!(recorded[0].uri.contains("/custom/endpoint"));
+    match h.facade().proxy_request(ctx.clone(), req).await {
+        Err(err) => assert!(
+            matches!(
+                err,
+                oagw_sdk::error::ServiceGatewayError::PayloadTooLarge { .. }
+            ),
+            "expected PayloadTooLarge, got: {err:?}"
+        ),
+        Ok(resp) => panic!(
+            "expected PayloadTooLarge error, got response with status {}",
+            resp.status()
+        ),
+    }
 }
 
-// WebSocket upgrade request returns 101 Switching Protocols when upstream accepts.
+// Body::Stream POST must reach the upstream intact via chunked transfer
+// encoding on the internal Pingora bridge.
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn proxy_websocket_upgrade_returns_101() {
+async fn proxy_streaming_body_post_arrives_intact() {
+    let mut guard = MockGuard::new();
+    guard.mock(
+        "POST",
+        "/v1/upload",
+        MockResponse {
+            status: 200,
+            headers: vec![],
+            body: MockBody::Json(serde_json::json!({"received": true})),
+        },
+    );
+
     let h = AppHarness::builder().build().await;
     let ctx = h.security_context().clone();
 
@@ -1455,7 +3995,7 @@ async fn proxy_websocket_upgrade_returns_101() {
                 },
                 "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
             )
-            .alias("ws-echo-test")
+            .alias("stream-body-test")
             .build(),
         )
         .await
@@ -1468,10 +4008,10 @@ async fn proxy_websocket_upgrade_returns_101() {
                 upstream.id,
                 MatchRules {
                     http: Some(HttpMatch {
-                        methods: vec![HttpMethod::Get],
-                        path: "/ws/echo".into(),
+                        methods: vec![HttpMethod::Post],
+                        path: guard.path("/v1/upload"),
                         query_allowlist: vec![],
-                        path_suffix_mode: PathSuffixMode::Append,
+                        path_suffix_mode: PathSuffixMode::Disabled,
                     }),
                     grpc: None,
                 },
@@ -1481,157 +4021,56 @@ async fn proxy_websocket_upgrade_returns_101() {
         .await
         .unwrap();
 
-    // Send a WebSocket upgrade request through the data plane.
-    // The mock server has a ws_echo handler at /ws/echo.
+    // Build a multi-chunk streaming body (simulates multipart/streaming upload).
+    let chunk_a = bytes::Bytes::from_static(b"hello ");
+    let chunk_b = bytes::Bytes::from_static(b"streamed ");
+    let chunk_c = bytes::Bytes::from_static(b"world");
+    let chunks: Vec<Result<bytes::Bytes, oagw_sdk::body::BoxError>> =
+        vec![Ok(chunk_a), Ok(chunk_b), Ok(chunk_c)];
+    let stream: oagw_sdk::body::BodyStream = Box::pin(futures_util::stream::iter(chunks));
+    let body = Body::Stream(stream);
+
     let req = http::Request::builder()
-        .method(Method::GET)
-        .uri("/ws-echo-test/ws/echo")
-        .header("upgrade", "websocket")
-        .header("connection", "Upgrade")
-        .header("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ==")
-        .header("sec-websocket-version", "13")
-        .body(Body::Empty)
+        .method(Method::POST)
+        .uri(format!("/stream-body-test{}/v1/upload", guard.prefix()))
+        .header(http::header::CONTENT_TYPE, "application/octet-stream")
+        .body(body)
         .unwrap();
 
-    let resp = h
-        .facade()
-        .proxy_request(ctx.clone(), req)
-        .await
-        .expect("WebSocket upgrade should succeed");
-
+    let resp = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
     assert_eq!(
         resp.status(),
-        http::StatusCode::SWITCHING_PROTOCOLS,
-        "expected 101, got {}",
-        resp.status()
-    );
-    assert_eq!(
-        resp.headers().get("upgrade").and_then(|v| v.to_str().ok()),
-        Some("websocket"),
-        "response should contain Upgrade: websocket header"
+        StatusCode::OK,
+        "streaming POST should succeed"
     );
-}
-
-// WebSocket upgrade rejected by upstream returns 502 ProtocolError with gateway error source.
-#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn proxy_websocket_upgrade_rejected_returns_502_protocol_error() {
-    let h = AppHarness::builder().build().await;
-    let ctx = h.security_context().clone();
-
-    // Point at /v1/models — a plain GET endpoint, not a WebSocket handler.
-    let upstream = h
-        .facade()
-        .create_upstream(
-            ctx.clone(),
-            CreateUpstreamRequest::builder(
-                Server {
-                    endpoints: vec![Endpoint {
-                        scheme: Scheme::Http,
-                        host: "127.0.0.1".into(),
-                        port: h.mock_port(),
-                    }],
-                },
-                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
-            )
-            .alias("ws-reject-test")
-            .build(),
-        )
-        .await
-        .unwrap();
-
-    h.facade()
-        .create_route(
-            ctx.clone(),
-            CreateRouteRequest::builder(
-                upstream.id,
-                MatchRules {
-                    http: Some(HttpMatch {
-                        methods: vec![HttpMethod::Get],
-                        path: "/v1/models".into(),
-                        query_allowlist: vec![],
-                        path_suffix_mode: PathSuffixMode::Append,
-                    }),
-                    grpc: None,
-                },
-            )
-            .build(),
-        )
-        .await
-        .unwrap();
-
-    // Send a WebSocket upgrade request to the non-WebSocket upstream.
-    let req = http::Request::builder()
-        .method(Method::GET)
-        .uri("/ws-reject-test/v1/models")
-        .header("upgrade", "websocket")
-        .header("connection", "Upgrade")
-        .header("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ==")
-        .header("sec-websocket-version", "13")
-        .body(Body::Empty)
-        .unwrap();
-
-    let err = h
-        .facade()
-        .proxy_request(ctx, req)
-        .await
-        .expect_err("expected ProtocolError for rejected WebSocket upgrade");
 
-    assert!(
-        matches!(
-            err,
-            oagw_sdk::error::ServiceGatewayError::ProtocolError { .. }
-        ),
-        "expected ProtocolError, got {err:?}"
+    // Verify the upstream mock received the complete, reassembled body.
+    let recorded = guard.recorded_requests().await;
+    assert_eq!(recorded.len(), 1, "expected exactly one recorded request");
+    assert_eq!(
+        recorded[0].body, b"hello streamed world",
+        "upstream must receive the full concatenated streaming body"
     );
 }
 
-// WebSocket E2E: upgrade through real TCP, send text frame, receive echo.
-#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
-async fn proxy_websocket_e2e_echo() {
-    use tokio::io::AsyncWriteExt;
-
-    let h = AppHarness::builder().build().await;
-    setup_ws_upstream(&h, "ws-e2e").await;
-    let (addr, server_handle) = start_oagw_server(&h).await;
-
-    let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
-    ws_handshake(&mut stream, "/oagw/v1/proxy/ws-e2e/ws/echo").await;
-
-    // Send a masked text frame containing "hello" and read the echo.
-    let frame = build_masked_frame(0x1, b"hello");
-    stream.write_all(&frame).await.unwrap();
-    let (opcode, payload) = read_ws_frame(&mut stream).await.expect("echo frame");
-    assert_eq!(opcode, 0x1, "expected text frame opcode");
-    assert_eq!(payload, b"hello", "echoed payload mismatch");
-
-    // Send a close frame with code 1000 (Normal Closure).
-    let close_frame = build_masked_frame(0x8, &1000u16.to_be_bytes());
-    stream.write_all(&close_frame).await.unwrap();
-    if let Some((op, _)) = read_ws_frame(&mut stream).await {
-        assert_eq!(op, 0x8, "expected close frame opcode");
-    }
-
-    server_handle.abort();
-}
-
-// 14.2: Auth credentials are injected into the outbound WebSocket upgrade request.
+// Routes with request_compression gzip buffered bodies above the threshold;
+// small bodies and bodies the client already encoded pass through unchanged.
 #[tokio::test]
-async fn proxy_websocket_auth_injected_during_handshake() {
+async fn proxy_gzips_large_request_bodies() {
+    use std::io::Read as _;
+
     let mut guard = MockGuard::new();
     guard.mock(
-        "GET",
-        "/ws/echo",
+        "POST",
+        "/v1/compress",
         MockResponse {
             status: 200,
-            headers: vec![("content-type".into(), "application/json".into())],
-            body: MockBody::Json(json!({"ok": true})),
+            headers: vec![],
+            body: MockBody::Json(serde_json::json!({"received": true})),
         },
     );
 
-    let h = AppHarness::builder()
-        .with_credentials(vec![("cred://ws-key".into(), "sk-ws-secret".into())])
-        .build()
-        .await;
+    let h = AppHarness::builder().build().await;
     let ctx = h.security_context().clone();
 
     let upstream = h
@@ -1644,23 +4083,17 @@ async fn proxy_websocket_auth_injected_during_handshake() {
                         scheme: Scheme::Http,
                         host: "127.0.0.1".into(),
                         port: h.mock_port(),
-                    }],
-                },
-                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
-            )
-            .alias("ws-auth-test")
-            .auth(oagw_sdk::AuthConfig {
-                plugin_type: APIKEY_AUTH_PLUGIN_ID.into(),
-                sharing: SharingMode::Private,
-                config: Some(
-                    [
-                        ("header".into(), "authorization".into()),
-                        ("prefix".into(), "Bearer ".into()),
-                        ("secret_ref".into(), "cred://ws-key".into()),
-                    ]
-                    .into_iter()
-                    .collect(),
-                ),
+                    }],
+                },
+                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            )
+            .alias("gzip-body-test")
+            .headers(HeadersConfig {
+                request: Some(RequestHeaderRules {
+                    passthrough: PassthroughMode::All,
+                    ..Default::default()
+                }),
+                response: None,
             })
             .build(),
         )
@@ -1674,78 +4107,83 @@ async fn proxy_websocket_auth_injected_during_handshake() {
                 upstream.id,
                 MatchRules {
                     http: Some(HttpMatch {
-                        methods: vec![HttpMethod::Get],
-                        path: guard.path("/ws/echo"),
+                        methods: vec![HttpMethod::Post],
+                        path: guard.path("/v1/compress"),
                         query_allowlist: vec![],
-                        path_suffix_mode: PathSuffixMode::Append,
+                        path_suffix_mode: PathSuffixMode::Disabled,
                     }),
                     grpc: None,
                 },
             )
+            .request_compression(RequestCompressionConfig { min_bytes: 1024 })
             .build(),
         )
         .await
         .unwrap();
 
-    let req = http::Request::builder()
-        .method(Method::GET)
-        .uri(format!("/ws-auth-test{}", guard.path("/ws/echo")))
-        .header("upgrade", "websocket")
-        .header("connection", "Upgrade")
-        .header("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ==")
-        .header("sec-websocket-version", "13")
-        .body(Body::Empty)
-        .unwrap();
-
-    // The MockGuard returns 200 (it can't do a real WebSocket handshake),
-    // so the proxy correctly returns ProtocolError. The upgrade request was
-    // already sent to upstream before the response is checked, so
-    // guard.recorded_requests() still captures the outbound headers we need.
-    let err = h
-        .facade()
-        .proxy_request(ctx, req)
-        .await
-        .expect_err("mock returns 200, so upgrade should fail with ProtocolError");
-    assert!(
-        matches!(
-            err,
-            oagw_sdk::error::ServiceGatewayError::ProtocolError { .. }
-        ),
-        "expected ProtocolError, got {err:?}"
-    );
+    let large = json!({"prompt": "lorem ipsum ".repeat(200)}).to_string();
+    let cases: [(&str, Option<&str>); 3] = [
+        (large.as_str(), None),
+        (r#"{"prompt":"hi"}"#, None),
+        (large.as_str(), Some("deflate")),
+    ];
+    for (body, encoding) in cases {
+        let mut req = http::Request::builder()
+            .method(Method::POST)
+            .uri(format!("/gzip-body-test{}/v1/compress", guard.prefix()))
+            .header(http::header::CONTENT_TYPE, "application/json");
+        if let Some(encoding) = encoding {
+            req = req.header(http::header::CONTENT_ENCODING, encoding);
+        }
+        let resp = h
+            .facade()
+            .proxy_request(ctx.clone(), req.body(Body::from(body.to_owned())).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
 
     let recorded = guard.recorded_requests().await;
-    assert_eq!(recorded.len(), 1, "expected exactly 1 recorded request");
+    assert_eq!(recorded.len(), 3);
+    let content_encoding = |i: usize| {
+        recorded[i]
+            .headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case("content-encoding"))
+            .map(|(_, v)| v.as_str())
+    };
 
-    // Verify the auth plugin injected credentials into the upgrade request.
-    let auth_header = recorded[0]
-        .headers
-        .iter()
-        .find(|(k, _)| k == "authorization")
-        .map(|(_, v)| v.as_str())
-        .expect("authorization header missing from upgrade request");
-    assert_eq!(auth_header, "Bearer sk-ws-secret");
+    assert_eq!(content_encoding(0), Some("gzip"));
+    assert!(recorded[0].body.len() < large.len());
+    let mut decoded = String::new();
+    flate2::read::GzDecoder::new(recorded[0].body.as_slice())
+        .read_to_string(&mut decoded)
+        .unwrap();
+    assert_eq!(decoded, large);
 
-    // Verify WebSocket-specific headers were forwarded (not stripped by auth pipeline).
-    let has_upgrade = recorded[0]
-        .headers
-        .iter()
-        .any(|(k, v)| k == "upgrade" && v == "websocket");
-    assert!(has_upgrade, "Upgrade header missing from outbound request");
+    assert_eq!(content_encoding(1), None);
+    assert_eq!(recorded[1].body, br#"{"prompt":"hi"}"#);
 
-    let has_ws_key = recorded[0]
-        .headers
-        .iter()
-        .any(|(k, _)| k == "sec-websocket-key");
-    assert!(
-        has_ws_key,
-        "Sec-WebSocket-Key header missing from outbound request"
-    );
+    assert_eq!(content_encoding(2), Some("deflate"));
+    assert_eq!(recorded[2].body, large.as_bytes());
 }
 
-// 14.3: Rate limiting applies to WebSocket connection establishment.
-#[tokio::test]
-async fn proxy_websocket_rate_limit_on_handshake() {
+// Identical concurrent GETs on a coalescing route share one upstream call.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn proxy_coalesces_identical_concurrent_gets() {
+    const CALLERS: usize = 8;
+
+    let mut guard = MockGuard::new();
+    let gate = guard.mock_gated(
+        "GET",
+        "/v1/slow",
+        MockResponse {
+            status: 200,
+            headers: vec![],
+            body: MockBody::Json(json!({"data": "shared"})),
+        },
+    );
+
     let h = AppHarness::builder().build().await;
     let ctx = h.security_context().clone();
 
@@ -1763,19 +4201,7 @@ async fn proxy_websocket_rate_limit_on_handshake() {
                 },
                 "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
             )
-            .alias("ws-rate-limit")
-            .rate_limit(RateLimitConfig {
-                sharing: SharingMode::Private,
-                algorithm: RateLimitAlgorithm::TokenBucket,
-                sustained: SustainedRate {
-                    rate: 1,
-                    window: Window::Minute,
-                },
-                burst: Some(BurstConfig { capacity: 1 }),
-                scope: RateLimitScope::Tenant,
-                strategy: RateLimitStrategy::Reject,
-                cost: 1,
-            })
+            .alias("coalesce-test")
             .build(),
         )
         .await
@@ -1789,103 +4215,59 @@ async fn proxy_websocket_rate_limit_on_handshake() {
                 MatchRules {
                     http: Some(HttpMatch {
                         methods: vec![HttpMethod::Get],
-                        path: "/ws/echo".into(),
+                        path: guard.path("/v1/slow"),
                         query_allowlist: vec![],
-                        path_suffix_mode: PathSuffixMode::Append,
+                        path_suffix_mode: PathSuffixMode::Disabled,
                     }),
                     grpc: None,
                 },
             )
+            .get_coalescing(GetCoalescingConfig::default())
             .build(),
         )
         .await
         .unwrap();
 
-    // First WebSocket upgrade should succeed.
-    let req = http::Request::builder()
-        .method(Method::GET)
-        .uri("/ws-rate-limit/ws/echo")
-        .header("upgrade", "websocket")
-        .header("connection", "Upgrade")
-        .header("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ==")
-        .header("sec-websocket-version", "13")
-        .body(Body::Empty)
-        .unwrap();
-    let response = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
-    assert_eq!(
-        response.status(),
-        StatusCode::SWITCHING_PROTOCOLS,
-        "first WS upgrade should succeed with 101"
-    );
-
-    // Second WebSocket upgrade within the rate window should be rejected.
-    let req = http::Request::builder()
-        .method(Method::GET)
-        .uri("/ws-rate-limit/ws/echo")
-        .header("upgrade", "websocket")
-        .header("connection", "Upgrade")
-        .header("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ==")
-        .header("sec-websocket-version", "13")
-        .body(Body::Empty)
-        .unwrap();
-    match h.facade().proxy_request(ctx, req).await {
-        Err(err) => assert!(
-            matches!(
-                err,
-                oagw_sdk::error::ServiceGatewayError::RateLimitExceeded { .. }
-            ),
-            "expected RateLimitExceeded, got: {err:?}"
-        ),
-        Ok(resp) => panic!(
-            "expected rate limit error on second WS upgrade, got status {}",
-            resp.status()
-        ),
+    let uri = format!("/coalesce-test{}/v1/slow", guard.prefix());
+    let requests = (0..CALLERS).map(|_| {
+        let req = http::Request::builder()
+            .method(Method::GET)
+            .uri(&uri)
+            .body(Body::Empty)
+            .unwrap();
+        h.facade().proxy_request(ctx.clone(), req)
+    });
+    let release = async {
+        // Let every caller reach the in-flight call before the upstream answers.
+        tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+        gate.send(()).unwrap();
+    };
+    let (responses, ()) = tokio::join!(futures_util::future::join_all(requests), release);
+
+    for resp in responses {
+        let resp = resp.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: serde_json::Value =
+            serde_json::from_slice(&resp.into_body().into_bytes().await.unwrap()).unwrap();
+        assert_eq!(body, json!({"data": "shared"}));
     }
+    assert_eq!(guard.recorded_requests().await.len(), 1);
 }
 
-// 14.4: WebSocket idle timeout — gateway closes the connection after inactivity.
-#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
-async fn proxy_websocket_idle_timeout_closes_connection() {
-    use tokio::io::AsyncWriteExt;
-
-    // 1000ms instead of 200ms: the readiness probe (Ping→Pong roundtrip
-    // through proxy→upstream→proxy) can exceed 200ms under CI resource
-    // pressure, causing the idle timer to fire before the probe completes.
-    let h = AppHarness::builder()
-        .with_websocket_idle_timeout(std::time::Duration::from_secs(1))
-        .build()
-        .await;
-    setup_ws_upstream(&h, "ws-idle-timeout").await;
-    let (addr, server_handle) = start_oagw_server(&h).await;
-
-    let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
-    ws_handshake(&mut stream, "/oagw/v1/proxy/ws-idle-timeout/ws/echo").await;
-
-    // Send a text frame to prove the connection is alive.
-    let frame = build_masked_frame(0x1, b"ping");
-    stream.write_all(&frame).await.unwrap();
-    let (opcode, _) = read_ws_frame(&mut stream).await.expect("echo frame");
-    assert_eq!(opcode, 0x1, "expected text frame opcode");
-
-    // Wait longer than the idle timeout (1s).
-    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
-
-    // Expect EOF or close frame indicating gateway closed the connection.
-    if let Some((op, _)) = read_ws_frame(&mut stream).await {
-        // Close frame received; EOF (None) is also valid — gateway closed TCP.
-        assert_eq!(op, 0x8, "expected close frame after idle timeout");
-    }
-
-    server_handle.abort();
-}
+/// Harness with a GET route on `/v1/cached` that caches responses for a
+/// minute, and the proxy path of that route.
+async fn response_cache_harness(guard: &mut MockGuard) -> (AppHarness, String) {
+    guard.mock(
+        "GET",
+        "/v1/cached",
+        MockResponse {
+            status: 200,
+            headers: vec![],
+            body: MockBody::Json(json!({"data": "cached"})),
+        },
+    );
 
-// P4 #18: Pingora fail_to_proxy produces valid RFC 9457 Problem body with correct GTS type.
-#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn proxy_unreachable_backend_returns_rfc9457_problem_body() {
-    let h = AppHarness::builder()
-        .with_request_timeout(std::time::Duration::from_secs(5))
-        .build()
-        .await;
+    let h = AppHarness::builder().build().await;
     let ctx = h.security_context().clone();
 
     let upstream = h
@@ -1897,12 +4279,12 @@ async fn proxy_unreachable_backend_returns_rfc9457_problem_body() {
                     endpoints: vec![Endpoint {
                         scheme: Scheme::Http,
                         host: "127.0.0.1".into(),
-                        port: 19993,
+                        port: h.mock_port(),
                     }],
                 },
                 "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
             )
-            .alias("rfc9457-body-test")
+            .alias("cache-test")
             .build(),
         )
         .await
@@ -1910,99 +4292,116 @@ async fn proxy_unreachable_backend_returns_rfc9457_problem_body() {
 
     h.facade()
         .create_route(
-            ctx.clone(),
+            ctx,
             CreateRouteRequest::builder(
                 upstream.id,
                 MatchRules {
                     http: Some(HttpMatch {
                         methods: vec![HttpMethod::Get],
-                        path: "/v1/test".into(),
+                        path: guard.path("/v1/cached"),
                         query_allowlist: vec![],
-                        path_suffix_mode: PathSuffixMode::Append,
+                        path_suffix_mode: PathSuffixMode::Disabled,
                     }),
                     grpc: None,
                 },
             )
+            .response_cache(ResponseCacheConfig { ttl_secs: 60 })
             .build(),
         )
         .await
         .unwrap();
 
-    let req = http::Request::builder()
-        .method(Method::GET)
-        .uri("/rfc9457-body-test/v1/test")
-        .body(Body::Empty)
+    let uri = format!("/cache-test{}/v1/cached", guard.prefix());
+    (h, uri)
+}
+
+/// Send a GET through the proxy and return `(X-Cache, Age, X-Cache-Key)`.
+async fn cached_get(
+    h: &AppHarness,
+    uri: &str,
+    cache_control: Option<&str>,
+) -> (String, u64, String) {
+    let mut req = http::Request::builder().method(Method::GET).uri(uri);
+    if let Some(cache_control) = cache_control {
+        req = req.header(http::header::CACHE_CONTROL, cache_control);
+    }
+    let req = req.body(Body::Empty).unwrap();
+    let resp = h
+        .facade()
+        .proxy_request(h.security_context().clone(), req)
+        .await
         .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let header = |name: &str| resp.headers()[name].to_str().unwrap().to_owned();
+    let result = (
+        header("x-cache"),
+        header("age").parse().unwrap(),
+        header("x-cache-key"),
+    );
+    let body: serde_json::Value =
+        serde_json::from_slice(&resp.into_body().into_bytes().await.unwrap()).unwrap();
+    assert_eq!(body, json!({"data": "cached"}));
+    result
+}
 
-    match h.facade().proxy_request(ctx.clone(), req).await {
-        Err(err) => {
-            // Went through DomainError path — already covered by other tests.
-            // Still verify the variant is one of the two expected for an
-            // unreachable backend (not e.g. ValidationError which would be a bug).
-            assert!(
-                matches!(
-                    err,
-                    oagw_sdk::error::ServiceGatewayError::DownstreamError { .. }
-                        | oagw_sdk::error::ServiceGatewayError::ConnectionTimeout { .. }
-                        | oagw_sdk::error::ServiceGatewayError::RequestTimeout { .. }
-                ),
-                "expected DownstreamError, ConnectionTimeout, or RequestTimeout for unreachable backend, got: {err:?}"
-            );
-        }
-        Ok(resp) => {
-            // Pingora wrote the response directly via fail_to_proxy.
-            let status = resp.status();
-            assert!(
-                status == StatusCode::BAD_GATEWAY || status == StatusCode::GATEWAY_TIMEOUT,
-                "expected 502 or 504, got: {status}"
-            );
+// Routes with response_cache answer repeated GETs from the stored response,
+// whose Age grows until the TTL lapses.
+#[tokio::test]
+async fn response_cache_hit_reports_growing_age() {
+    let mut guard = MockGuard::new();
+    let (h, uri) = response_cache_harness(&mut guard).await;
 
-            let body_bytes = resp.into_body().into_bytes().await.unwrap();
-            let body: serde_json::Value = serde_json::from_slice(&body_bytes)
-                .expect("fail_to_proxy response body should be valid JSON");
+    let (outcome, age, key) = cached_get(&h, &uri, None).await;
+    assert_eq!((outcome.as_str(), age), ("MISS", 0));
+    assert_eq!(key.len(), 16);
+    assert!(key.chars().all(|c| c.is_ascii_hexdigit()));
 
-            // Must have all RFC 9457 Problem fields.
-            assert!(body.get("type").is_some(), "missing 'type' field");
-            assert!(body.get("title").is_some(), "missing 'title' field");
-            assert!(body.get("status").is_some(), "missing 'status' field");
-            assert!(body.get("detail").is_some(), "missing 'detail' field");
-            assert!(body.get("instance").is_some(), "missing 'instance' field");
+    let (outcome, first_age, hit_key) = cached_get(&h, &uri, None).await;
+    assert_eq!(outcome, "HIT");
+    assert_eq!(hit_key, key);
+
+    tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+    let (outcome, later_age, _) = cached_get(&h, &uri, None).await;
+    assert_eq!(outcome, "HIT");
+    assert!(later_age > first_age, "{later_age} <= {first_age}");
+
+    assert_eq!(guard.recorded_requests().await.len(), 1);
+}
+
+// Cache-Control: no-cache or max-age=0 from the client bypasses the stored
+// response; the fresh response replaces it.
+#[tokio::test]
+async fn response_cache_client_no_cache_forces_miss() {
+    let mut guard = MockGuard::new();
+    let (h, uri) = response_cache_harness(&mut guard).await;
 
-            // GTS type must not be about:blank.
-            let gts_type = body["type"].as_str().unwrap();
-            assert!(
-                gts_type.starts_with("gts."),
-                "expected GTS error type, got: {gts_type}"
-            );
+    assert_eq!(cached_get(&h, &uri, None).await.0, "MISS");
+    assert_eq!(cached_get(&h, &uri, None).await.0, "HIT");
+    assert_eq!(cached_get(&h, &uri, Some("no-cache")).await.0, "MISS");
+    assert_eq!(cached_get(&h, &uri, Some("max-age=0")).await.0, "MISS");
+    assert_eq!(guard.recorded_requests().await.len(), 3);
 
-            // Status field in body must match HTTP status.
-            assert_eq!(
-                body["status"].as_u64().unwrap(),
-                status.as_u16() as u64,
-                "body status must match HTTP status"
-            );
-        }
-    }
+    let (outcome, age, _) = cached_get(&h, &uri, None).await;
+    assert_eq!((outcome.as_str(), age), ("HIT", 0));
+    assert_eq!(guard.recorded_requests().await.len(), 3);
 }
 
-// negative-8.1 (body-validation): Streaming body exceeding max_body_size returns 413 PayloadTooLarge.
+// Draining on shutdown lets a slow in-flight request finish while new
+// requests are refused.
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn proxy_streaming_body_exceeding_limit_returns_413() {
+async fn proxy_drains_in_flight_requests_on_shutdown() {
     let mut guard = MockGuard::new();
-    guard.mock(
-        "POST",
-        "/v1/upload",
+    let gate = guard.mock_gated(
+        "GET",
+        "/v1/slow",
         MockResponse {
             status: 200,
             headers: vec![],
-            body: MockBody::Json(serde_json::json!({"ok": true})),
+            body: MockBody::Json(json!({"data": "done"})),
         },
     );
 
-    let h = AppHarness::builder()
-        .with_max_body_size(64) // tiny limit for testing
-        .build()
-        .await;
+    let h = AppHarness::builder().build().await;
     let ctx = h.security_context().clone();
 
     let upstream = h
@@ -2019,7 +4418,7 @@ async fn proxy_streaming_body_exceeding_limit_returns_413() {
                 },
                 "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
             )
-            .alias("body-limit-test")
+            .alias("drain-test")
             .build(),
         )
         .await
@@ -2032,8 +4431,8 @@ async fn proxy_streaming_body_exceeding_limit_returns_413() {
                 upstream.id,
                 MatchRules {
                     http: Some(HttpMatch {
-                        methods: vec![HttpMethod::Post],
-                        path: guard.path("/v1/upload"),
+                        methods: vec![HttpMethod::Get],
+                        path: guard.path("/v1/slow"),
                         query_allowlist: vec![],
                         path_suffix_mode: PathSuffixMode::Disabled,
                     }),
@@ -2045,48 +4444,66 @@ async fn proxy_streaming_body_exceeding_limit_returns_413() {
         .await
         .unwrap();
 
-    // Build a streaming body that exceeds the 64-byte limit.
-    let chunks: Vec<Result<bytes::Bytes, oagw_sdk::body::BoxError>> = vec![
-        Ok(bytes::Bytes::from(vec![b'A'; 40])),
-        Ok(bytes::Bytes::from(vec![b'B'; 40])), // total = 80 > 64
-    ];
-    let stream: oagw_sdk::body::BodyStream = Box::pin(futures_util::stream::iter(chunks));
-    let body = Body::Stream(stream);
+    let uri = format!("/drain-test{}/v1/slow", guard.prefix());
+    let get = || {
+        http::Request::builder()
+            .method(Method::GET)
+            .uri(&uri)
+            .body(Body::Empty)
+            .unwrap()
+    };
 
-    let req = http::Request::builder()
-        .method(Method::POST)
-        .uri(format!("/body-limit-test{}/v1/upload", guard.prefix()))
-        .header(http::header::CONTENT_TYPE, "application/octet-stream")
-        .body(body)
-        .unwrap();
+    let started = std::time::Instant::now();
+    let in_flight = async {
+        let resp = h.facade().proxy_request(ctx.clone(), get()).await.unwrap();
+        let status = resp.status();
+        (status, resp.into_body().into_bytes().await.unwrap())
+    };
+    let shutdown = async {
+        while guard.recorded_requests().await.is_empty() {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        let refuse_then_release = async {
+            let refused = h.facade().proxy_request(ctx.clone(), get()).await;
+            gate.send(()).unwrap();
+            refused
+        };
+        tokio::join!(
+            h.drain(std::time::Duration::from_secs(5)),
+            refuse_then_release
+        )
+    };
+    let ((status, body), (remaining, refused)) = tokio::join!(in_flight, shutdown);
 
-    match h.facade().proxy_request(ctx.clone(), req).await {
-        Err(err) => assert!(
-            matches!(
-                err,
-                oagw_sdk::error::ServiceGatewayError::PayloadTooLarge { .. }
-            ),
-            "expected PayloadTooLarge, got: {err:?}"
-        ),
-        Ok(resp) => panic!(
-            "expected PayloadTooLarge error, got response with status {}",
-            resp.status()
-        ),
-    }
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        serde_json::from_slice::<serde_json::Value>(&body).unwrap(),
+        json!({"data": "done"})
+    );
+    assert_eq!(remaining, 0);
+    assert!(started.elapsed() < std::time::Duration::from_secs(5));
+    assert!(matches!(
+        refused,
+        Err(oagw_sdk::error::ServiceGatewayError::LinkUnavailable { .. })
+    ));
+    assert_eq!(guard.recorded_requests().await.len(), 1);
 }
 
-// Body::Stream POST must reach the upstream intact via chunked transfer
-// encoding on the internal Pingora bridge.
-#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn proxy_streaming_body_post_arrives_intact() {
+// Range requests reach the upstream even without header passthrough, and
+// the 206 partial response is relayed with its range headers intact.
+#[tokio::test]
+async fn proxy_passes_range_requests_through() {
     let mut guard = MockGuard::new();
     guard.mock(
-        "POST",
-        "/v1/upload",
+        "GET",
+        "/v1/artifact",
         MockResponse {
-            status: 200,
-            headers: vec![],
-            body: MockBody::Json(serde_json::json!({"received": true})),
+            status: 206,
+            headers: vec![
+                ("accept-ranges".into(), "bytes".into()),
+                ("content-range".into(), "bytes 100-109/5000".into()),
+            ],
+            body: MockBody::Text("0123456789".into()),
         },
     );
 
@@ -2107,7 +4524,7 @@ async fn proxy_streaming_body_post_arrives_intact() {
                 },
                 "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
             )
-            .alias("stream-body-test")
+            .alias("range-test")
             .build(),
         )
         .await
@@ -2120,8 +4537,8 @@ async fn proxy_streaming_body_post_arrives_intact() {
                 upstream.id,
                 MatchRules {
                     http: Some(HttpMatch {
-                        methods: vec![HttpMethod::Post],
-                        path: guard.path("/v1/upload"),
+                        methods: vec![HttpMethod::Get],
+                        path: guard.path("/v1/artifact"),
                         query_allowlist: vec![],
                         path_suffix_mode: PathSuffixMode::Disabled,
                     }),
@@ -2133,36 +4550,35 @@ async fn proxy_streaming_body_post_arrives_intact() {
         .await
         .unwrap();
 
-    // Build a multi-chunk streaming body (simulates multipart/streaming upload).
-    let chunk_a = bytes::Bytes::from_static(b"hello ");
-    let chunk_b = bytes::Bytes::from_static(b"streamed ");
-    let chunk_c = bytes::Bytes::from_static(b"world");
-    let chunks: Vec<Result<bytes::Bytes, oagw_sdk::body::BoxError>> =
-        vec![Ok(chunk_a), Ok(chunk_b), Ok(chunk_c)];
-    let stream: oagw_sdk::body::BodyStream = Box::pin(futures_util::stream::iter(chunks));
-    let body = Body::Stream(stream);
-
     let req = http::Request::builder()
-        .method(Method::POST)
-        .uri(format!("/stream-body-test{}/v1/upload", guard.prefix()))
-        .header(http::header::CONTENT_TYPE, "application/octet-stream")
-        .body(body)
+        .method(Method::GET)
+        .uri(format!("/range-test{}/v1/artifact", guard.prefix()))
+        .header(http::header::RANGE, "bytes=100-109")
+        .header(http::header::IF_RANGE, "\"v1\"")
+        .body(Body::Empty)
         .unwrap();
-
     let resp = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
+
+    assert_eq!(resp.status(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(resp.headers()[http::header::ACCEPT_RANGES], "bytes");
     assert_eq!(
-        resp.status(),
-        StatusCode::OK,
-        "streaming POST should succeed"
+        resp.headers()[http::header::CONTENT_RANGE],
+        "bytes 100-109/5000"
     );
+    let body = resp.into_body().into_bytes().await.unwrap();
+    assert_eq!(&body[..], b"0123456789");
 
-    // Verify the upstream mock received the complete, reassembled body.
     let recorded = guard.recorded_requests().await;
-    assert_eq!(recorded.len(), 1, "expected exactly one recorded request");
-    assert_eq!(
-        recorded[0].body, b"hello streamed world",
-        "upstream must receive the full concatenated streaming body"
-    );
+    assert_eq!(recorded.len(), 1);
+    let header = |name: &str| {
+        recorded[0]
+            .headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    };
+    assert_eq!(header("range"), Some("bytes=100-109"));
+    assert_eq!(header("if-range"), Some("\"v1\""));
 }
 
 // Empty chunks in a Body::Stream must be silently skipped — writing a
@@ -2825,12 +5241,18 @@ async fn proxy_guard_rejects_missing_required_header() {
         .await
         .expect_err("guard should reject missing required header");
 
+    assert_eq!(err.error_source(), ErrorSource::Plugin);
+    assert_eq!(err.plugin_id(), Some(REQUIRED_HEADERS_GUARD_PLUGIN_ID));
     match err {
         oagw_sdk::error::ServiceGatewayError::GuardRejected {
-            status, error_code, ..
+            status,
+            error_code,
+            plugin_id,
+            ..
         } => {
             assert_eq!(status, 400);
             assert_eq!(error_code, "REQUIRED_HEADER_MISSING");
+            assert_eq!(plugin_id, REQUIRED_HEADERS_GUARD_PLUGIN_ID);
         }
         other => panic!("expected GuardRejected, got: {other:?}"),
     }
@@ -2843,6 +5265,130 @@ async fn proxy_guard_rejects_missing_required_header() {
     );
 }
 
+const CONTENT_TYPE_GUARD_PLUGIN_ID: &str =
+    "gts.x.core.oagw.guard_plugin.v1~x.core.oagw.content_type.v1";
+
+/// Verify that the ContentTypeGuardPlugin lets allowed media types and
+/// bodyless requests through, and rejects other media types with 415.
+#[tokio::test]
+async fn proxy_content_type_guard_enforces_allowlist() {
+    let mut guard = MockGuard::new();
+    for method in ["GET", "POST"] {
+        guard.mock(
+            method,
+            "/guard-ct",
+            MockResponse {
+                status: 200,
+                headers: vec![("content-type".into(), "application/json".into())],
+                body: MockBody::Json(json!({"ok": true})),
+            },
+        );
+    }
+
+    let h = AppHarness::builder().build().await;
+    let ctx = h.security_context().clone();
+
+    let upstream = h
+        .facade()
+        .create_upstream(
+            ctx.clone(),
+            CreateUpstreamRequest::builder(
+                Server {
+                    endpoints: vec![Endpoint {
+                        scheme: Scheme::Http,
+                        host: "127.0.0.1".into(),
+                        port: h.mock_port(),
+                    }],
+                },
+                "gts.x.core.oagw.protocol.v1~x.core.oagw.http.v1",
+            )
+            .alias("guard-ct")
+            .plugins(PluginsConfig {
+                sharing: SharingMode::Private,
+                items: vec![PluginBinding {
+                    plugin_ref: CONTENT_TYPE_GUARD_PLUGIN_ID.to_string(),
+                    config: [("allowed_content_types".into(), "application/json".into())].into(),
+                }],
+            })
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    h.facade()
+        .create_route(
+            ctx.clone(),
+            CreateRouteRequest::builder(
+                upstream.id,
+                MatchRules {
+                    http: Some(HttpMatch {
+                        methods: vec![HttpMethod::Get, HttpMethod::Post],
+                        path: guard.path("/guard-ct"),
+                        query_allowlist: vec![],
+                        path_suffix_mode: PathSuffixMode::Disabled,
+                    }),
+                    grpc: None,
+                },
+            )
+            .build(),
+        )
+        .await
+        .unwrap();
+
+    let uri = format!("/guard-ct{}", guard.path("/guard-ct"));
+
+    // Allowed media type (parameters are ignored).
+    let req = http::Request::builder()
+        .method(Method::POST)
+        .uri(&uri)
+        .header(
+            http::header::CONTENT_TYPE,
+            "application/json; charset=utf-8",
+        )
+        .body(Body::from(r#"{"test": true}"#))
+        .unwrap();
+    let response = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Bodyless GET without a Content-Type.
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri(&uri)
+        .body(Body::Empty)
+        .unwrap();
+    let response = h.facade().proxy_request(ctx.clone(), req).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Disallowed media type.
+    let req = http::Request::builder()
+        .method(Method::POST)
+        .uri(&uri)
+        .header(http::header::CONTENT_TYPE, "text/plain")
+        .body(Body::from("hello"))
+        .unwrap();
+    let err = h
+        .facade()
+        .proxy_request(ctx.clone(), req)
+        .await
+        .expect_err("guard should reject text/plain");
+    assert!(
+        matches!(
+            err,
+            oagw_sdk::error::ServiceGatewayError::UnsupportedMediaType { .. }
+        ),
+        "expected UnsupportedMediaType, got: {err:?}"
+    );
+    assert_eq!(err.status_code(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    assert_eq!(err.error_source(), ErrorSource::Gateway);
+    assert_eq!(err.plugin_id(), None);
+
+    assert_eq!(
+        guard.recorded_requests().await.len(),
+        2,
+        "rejected request should not reach upstream"
+    );
+}
+
 /// Verify that an unconfigured RequiredHeadersGuardPlugin allows all requests.
 #[tokio::test]
 async fn proxy_guard_allows_unconfigured() {
@@ -3814,6 +6360,106 @@ async fn cors_multiple_specific_origins() {
     );
 }
 
+// CORS: Preflight is answered locally with 204 and never reaches the upstream.
+#[tokio::test]
+async fn cors_preflight_answered_locally() {
+    let guard = MockGuard::new();
+    let h = AppHarness::builder().build().await;
+    setup_cors_upstream(&h, &guard, "cors-preflight", Some(test_cors_config())).await;
+
+    let path = guard.path("/api/data");
+    let resp = h
+        .api_v1()
+        .proxy(
+            Method::OPTIONS,
+            "cors-preflight",
+            path.trim_start_matches('/'),
+        )
+        .with_header(
+            http::header::ORIGIN,
+            http::HeaderValue::from_static("https://example.com"),
+        )
+        .with_header(
+            http::header::ACCESS_CONTROL_REQUEST_METHOD,
+            http::HeaderValue::from_static("POST"),
+        )
+        .with_header(
+            http::header::ACCESS_CONTROL_REQUEST_HEADERS,
+            http::HeaderValue::from_static("content-type"),
+        )
+        .expect_status(204)
+        .await;
+
+    resp.assert_header("access-control-allow-origin", "https://example.com");
+    resp.assert_header("access-control-allow-methods", "POST");
+    resp.assert_header("access-control-allow-headers", "content-type");
+    assert!(resp.headers().contains_key("access-control-max-age"));
+    assert!(guard.recorded_requests().await.is_empty());
+}
+
+// CORS: Subdomain wildcard origins admit subdomains and reflect the origin.
+#[tokio::test]
+async fn cors_subdomain_wildcard_origin() {
+    let mut guard = MockGuard::new();
+    guard.mock(
+        "GET",
+        "/api/data",
+        MockResponse {
+            status: 200,
+            headers: vec![("content-type".into(), "application/json".into())],
+            body: MockBody::Json(json!({"ok": true})),
+        },
+    );
+
+    let h = AppHarness::builder().build().await;
+    let cors = CorsConfig {
+        allowed_origins: vec!["https://*.example.com".to_string()],
+        allow_credentials: true,
+        ..test_cors_config()
+    };
+    setup_cors_upstream(&h, &guard, "cors-subdomain", Some(cors)).await;
+
+    let request = |origin: &str| {
+        http::Request::builder()
+            .method(Method::GET)
+            .uri(format!("/cors-subdomain{}", guard.path("/api/data")))
+            .header("origin", origin)
+            .body(Body::Empty)
+            .unwrap()
+    };
+
+    let response = h
+        .facade()
+        .proxy_request(
+            h.security_context().clone(),
+            request("https://app.example.com"),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response
+            .headers()
+            .get("access-control-allow-origin")
+            .unwrap(),
+        "https://app.example.com"
+    );
+
+    // The bare suffix is not a subdomain.
+    let response = h
+        .facade()
+        .proxy_request(h.security_context().clone(), request("https://example.com"))
+        .await;
+    assert!(
+        matches!(
+            response,
+            Err(oagw_sdk::error::ServiceGatewayError::Forbidden { .. })
+        ),
+        "bare suffix should be rejected"
+    );
+    assert_eq!(guard.recorded_requests().await.len(), 1);
+}
+
 // ---------------------------------------------------------------------------
 // WebSocket frame-aware relay integration tests
 // ---------------------------------------------------------------------------